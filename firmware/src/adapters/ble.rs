@@ -19,9 +19,12 @@
 //! | Configuration        | `4a650020-…-5f6c9a1d7e3a`               | Read+Write  |
 //! | PSK Pairing          | `4a650030-…-5f6c9a1d7e3a`               | Write       |
 
+use super::ble_transport::BleTransportError;
 use super::utils::is_printable_ascii;
 use core::fmt;
-use log::{error, info, warn};
+#[cfg(target_os = "espidf")]
+use log::error;
+use log::{info, warn};
 
 // ───────────────────────────────────────────────────────────────
 // Constants
@@ -80,7 +83,9 @@ pub trait ProvisioningPort {
     fn is_active(&self) -> bool;
     fn take_pending_credentials(&mut self) -> Option<(heapless::String<32>, heapless::String<64>)>;
     fn take_pending_psk(&mut self) -> Option<[u8; PSK_LEN]>;
-    fn update_status(&mut self, state_name: &str, nh3_ppm: f32, faults: u8);
+    /// Publish a status snapshot.  Each call advances the heartbeat
+    /// counter, so a central can tell a live main loop from a hung one.
+    fn update_status(&mut self, state_name: &str, nh3_ppm: f32, faults: u8, uptime_secs: u32);
 }
 
 // ───────────────────────────────────────────────────────────────
@@ -399,8 +404,15 @@ unsafe extern "C" fn ble_gatts_event_handler(
             log::info!("BLE GATTS: client connected (conn_id={})", p.conn_id);
             crate::events::push_event(crate::events::Event::BleConnected);
         }
+        esp_gatts_cb_event_t_ESP_GATTS_MTU_EVT => {
+            let p = unsafe { &(*param).mtu };
+            log::info!("BLE GATTS: MTU exchanged ({})", p.mtu);
+            super::ble_transport::record_negotiated_mtu(p.mtu as usize);
+            crate::rpc::io_task::ble_set_mtu(p.mtu as usize);
+        }
         esp_gatts_cb_event_t_ESP_GATTS_DISCONNECT_EVT => {
             BLE_CONN_ID.store(0, AtomicOrdering::Relaxed);
+            super::ble_transport::reset_negotiated_mtu();
             log::info!("BLE GATTS: client disconnected");
             crate::events::push_event(crate::events::Event::BleDisconnected);
             // Restart advertising after disconnect.
@@ -452,6 +464,12 @@ pub struct BleAdapter {
     pending_password: Option<heapless::String<64>>,
     pending_psk: Option<[u8; PSK_LEN]>,
     status_buf: heapless::String<64>,
+    /// Liveness counter carried in every status payload (wraps at `u16`,
+    /// which keeps the worst-case payload within `MAX_STATUS_BYTES`).
+    heartbeat: u16,
+    /// Uptime of the last main-loop status refresh; cleared on connect
+    /// so a new central gets one straight away.
+    last_status_ms: Option<u64>,
     device_name: heapless::String<24>,
     /// Simulation: tracks provisioning write sequence (0=idle, 1=ssid, 2=pass, 3=psk).
     /// Used to enforce correct SSID→Password→PSK order and detect timeouts.
@@ -470,6 +488,8 @@ impl BleAdapter {
             pending_password: None,
             pending_psk: None,
             status_buf: heapless::String::new(),
+            heartbeat: 0,
+            last_status_ms: None,
             #[cfg(not(target_os = "espidf"))]
             sim_provision_step: 0,
             #[cfg(not(target_os = "espidf"))]
@@ -482,6 +502,31 @@ impl BleAdapter {
         self.state
     }
 
+    /// The most recent status payload.
+    pub fn status_payload(&self) -> &str {
+        &self.status_buf
+    }
+
+    /// Whether the main loop should refresh the status characteristic
+    /// at `now_ms`: a central is connected and `interval_secs` have
+    /// passed since the last refresh (0 = never).  A `true` answer
+    /// starts the next interval, so callers must follow it with
+    /// [`update_status`](ProvisioningPort::update_status).
+    pub fn status_refresh_due(&mut self, now_ms: u64, interval_secs: u16) -> bool {
+        if self.state != BleState::Connected || interval_secs == 0 {
+            return false;
+        }
+        let interval_ms = u64::from(interval_secs) * 1000;
+        if self
+            .last_status_ms
+            .is_some_and(|last| now_ms.saturating_sub(last) < interval_ms)
+        {
+            return false;
+        }
+        self.last_status_ms = Some(now_ms);
+        true
+    }
+
     pub fn on_ssid_write(&mut self, raw: &[u8]) -> Result<(), ProvisioningError> {
         let s = sanitize_ble_string(raw, MAX_SSID_LEN)?;
         validate_ssid(s)?;
@@ -547,6 +592,7 @@ impl BleAdapter {
     pub fn on_central_connected(&mut self) {
         info!("BLE: central connected");
         self.state = BleState::Connected;
+        self.last_status_ms = None;
         #[cfg(not(target_os = "espidf"))]
         {
             self.sim_provision_step = 0;
@@ -580,9 +626,15 @@ impl BleAdapter {
         }
     }
 
-    /// Send RPC response bytes over the BLE notify characteristic.
+    /// Send RPC response bytes over the BLE notify characteristic, split
+    /// into fragments that fit the negotiated ATT MTU.
     pub fn send_rpc_response(&mut self, payload: &[u8]) {
-        self.platform_send_rpc_notify(payload);
+        let sent = crate::rpc::io_task::ble_send_fragmented(payload, |frag| {
+            self.platform_send_rpc_notify(frag)
+        });
+        if let Err(e) = sent {
+            warn!("BLE: RPC response not sent: {}", e);
+        }
     }
 
     // ── Platform-specific ─────────────────────────────────────
@@ -713,27 +765,34 @@ impl BleAdapter {
     }
 
     #[cfg(target_os = "espidf")]
-    fn platform_send_rpc_notify(&mut self, payload: &[u8]) {
+    fn platform_send_rpc_notify(&mut self, payload: &[u8]) -> Result<(), BleTransportError> {
         use esp_idf_svc::sys::*;
-        unsafe {
-            let handle = BLE_RPC_NOTIFY_CHAR_HANDLE.load(core::sync::atomic::Ordering::Relaxed);
-            let conn = BLE_CONN_ID.load(core::sync::atomic::Ordering::Relaxed);
-            if handle != 0 && conn != 0 {
-                let _ = esp_ble_gatts_send_indicate(
-                    BLE_GATTS_IF.load(core::sync::atomic::Ordering::Relaxed) as u8,
-                    conn as u16,
-                    handle as u16,
-                    payload.len() as u16,
-                    payload.as_ptr() as *mut u8,
-                    false,
-                );
-            }
+        let handle = BLE_RPC_NOTIFY_CHAR_HANDLE.load(core::sync::atomic::Ordering::Relaxed);
+        let conn = BLE_CONN_ID.load(core::sync::atomic::Ordering::Relaxed);
+        if handle == 0 || conn == 0 {
+            return Err(BleTransportError::NotConnected);
+        }
+        let ret = unsafe {
+            esp_ble_gatts_send_indicate(
+                BLE_GATTS_IF.load(core::sync::atomic::Ordering::Relaxed) as u8,
+                conn as u16,
+                handle as u16,
+                payload.len() as u16,
+                payload.as_ptr() as *mut u8,
+                false,
+            )
+        };
+        if ret != ESP_OK as i32 {
+            return Err(BleTransportError::Io);
         }
+        Ok(())
     }
 
     #[cfg(not(target_os = "espidf"))]
-    fn platform_send_rpc_notify(&mut self, payload: &[u8]) {
+    #[allow(clippy::unnecessary_wraps)] // mirrors the fallible espidf variant
+    fn platform_send_rpc_notify(&mut self, payload: &[u8]) -> Result<(), BleTransportError> {
         info!("BLE(sim): rpc notify {} bytes", payload.len());
+        Ok(())
     }
 
     #[cfg(target_os = "espidf")]
@@ -800,13 +859,14 @@ impl ProvisioningPort for BleAdapter {
         self.pending_psk.take()
     }
 
-    fn update_status(&mut self, state_name: &str, nh3_ppm: f32, faults: u8) {
+    fn update_status(&mut self, state_name: &str, nh3_ppm: f32, faults: u8, uptime_secs: u32) {
+        self.heartbeat = self.heartbeat.wrapping_add(1);
         self.status_buf.clear();
         use core::fmt::Write;
         let _ = write!(
             self.status_buf,
-            "{{\"s\":\"{}\",\"nh3\":{:.1},\"f\":{}}}",
-            state_name, nh3_ppm, faults,
+            "{{\"s\":\"{}\",\"nh3\":{:.1},\"f\":{},\"hb\":{},\"up\":{}}}",
+            state_name, nh3_ppm, faults, self.heartbeat, uptime_secs,
         );
 
         let payload_str = self.status_buf.clone();
@@ -971,6 +1031,56 @@ mod tests {
     fn update_status_payload() {
         let mut adapter = make_adapter();
         adapter.start();
-        adapter.update_status("Scrubbing", 12.5, 0);
+        adapter.update_status("Active", 12.5, 0, 90);
+        assert_eq!(
+            adapter.status_payload(),
+            r#"{"s":"Active","nh3":12.5,"f":0,"hb":1,"up":90}"#
+        );
+    }
+
+    fn heartbeat_of(payload: &str) -> u16 {
+        let rest = &payload[payload.find("\"hb\":").unwrap() + 5..];
+        rest[..rest.find(',').unwrap()].parse().unwrap()
+    }
+
+    #[test]
+    fn update_status_heartbeat_increases() {
+        let mut adapter = make_adapter();
+        adapter.start();
+        let mut last = 0;
+        for up in 0..20 {
+            adapter.update_status("Idle", 0.0, 0, up);
+            let hb = heartbeat_of(adapter.status_payload());
+            assert!(hb > last, "heartbeat {hb} did not advance past {last}");
+            last = hb;
+        }
+    }
+
+    #[test]
+    fn worst_case_status_fits_characteristic() {
+        let mut adapter = make_adapter();
+        adapter.heartbeat = u16::MAX - 1;
+        adapter.update_status("Sensing", 9999.9, u8::MAX, u32::MAX);
+        assert!(adapter.status_payload().len() <= MAX_STATUS_BYTES);
+        assert!(adapter.status_payload().ends_with('}'));
+    }
+
+    #[test]
+    fn status_refresh_is_throttled_while_connected() {
+        let mut adapter = make_adapter();
+        adapter.start();
+        assert!(!adapter.status_refresh_due(0, 5));
+
+        adapter.on_central_connected();
+        assert!(adapter.status_refresh_due(0, 5));
+        assert!(!adapter.status_refresh_due(4_999, 5));
+        assert!(adapter.status_refresh_due(5_000, 5));
+        assert!(!adapter.status_refresh_due(6_000, 0));
+
+        // A reconnecting central does not wait out the old interval.
+        adapter.on_central_disconnected();
+        assert!(!adapter.status_refresh_due(20_000, 5));
+        adapter.on_central_connected();
+        assert!(adapter.status_refresh_due(6_000, 5));
     }
 }
@@ -20,6 +20,10 @@
 //!
 //! Flags: bit 0 = "more fragments", bit 1 = "first fragment"
 //!
+//! The ATT MTU is learned from the GATTS MTU-exchange event and recorded
+//! with [`record_negotiated_mtu`]; the transport fragments to it, clamped
+//! to [`MIN_ATT_MTU`]..=[`MAX_ATT_MTU`].
+//!
 //! ## GATT Service Layout
 //!
 //! | Characteristic | UUID                                   | Perms       |
@@ -27,6 +31,8 @@
 //! | RPC Write     | `4a650040-b7e4-4b91-a032-5f6c9a1d7e3a` | Write       |
 //! | RPC Notify    | `4a650041-b7e4-4b91-a032-5f6c9a1d7e3a` | Notify      |
 
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 use log::info;
 
 use crate::rpc::auth::ClientId;
@@ -37,7 +43,12 @@ use crate::rpc::transport::Transport;
 pub const CHAR_RPC_WRITE: u128 = 0x4a650040_b7e4_4b91_a032_5f6c9a1d7e3a;
 pub const CHAR_RPC_NOTIFY: u128 = 0x4a650041_b7e4_4b91_a032_5f6c9a1d7e3a;
 
-const DEFAULT_MTU: usize = 23;
+/// Smallest ATT MTU every BLE central must support.
+pub const MIN_ATT_MTU: usize = 23;
+/// Largest ATT MTU allowed by the Bluetooth spec.
+pub const MAX_ATT_MTU: usize = 517;
+/// ATT notification opcode + handle, carried inside every MTU.
+const ATT_NOTIFY_OVERHEAD: usize = 3;
 const FRAG_HEADER_SIZE: usize = 2;
 const MAX_REASSEMBLY_SIZE: usize = 4096;
 
@@ -46,6 +57,29 @@ const FRAG_MORE: u8 = 0x01;
 /// Fragment flag: this is the first fragment.
 const FRAG_FIRST: u8 = 0x02;
 
+/// ATT MTU from the most recent MTU exchange.  Written from the
+/// Bluedroid GATTS callback, read when a central connects.
+static NEGOTIATED_MTU: AtomicUsize = AtomicUsize::new(MIN_ATT_MTU);
+
+/// Record the ATT MTU agreed in an MTU exchange (clamped).
+pub fn record_negotiated_mtu(mtu: usize) {
+    NEGOTIATED_MTU.store(clamp_mtu(mtu), Ordering::Relaxed);
+}
+
+/// ATT MTU from the last exchange, or [`MIN_ATT_MTU`] if none happened.
+pub fn negotiated_mtu() -> usize {
+    NEGOTIATED_MTU.load(Ordering::Relaxed)
+}
+
+/// Reset to [`MIN_ATT_MTU`]; the next central renegotiates.
+pub fn reset_negotiated_mtu() {
+    NEGOTIATED_MTU.store(MIN_ATT_MTU, Ordering::Relaxed);
+}
+
+fn clamp_mtu(mtu: usize) -> usize {
+    mtu.clamp(MIN_ATT_MTU, MAX_ATT_MTU)
+}
+
 // ── Error type ───────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -158,7 +192,7 @@ impl BleTransport {
         Self {
             connected: false,
             client_id: 0,
-            mtu: DEFAULT_MTU,
+            mtu: MIN_ATT_MTU,
             reassembly: ReassemblyBuffer::new(),
             read_buf: [0; MAX_REASSEMBLY_SIZE],
             read_len: 0,
@@ -167,19 +201,25 @@ impl BleTransport {
         }
     }
 
+    /// Apply a renegotiated ATT MTU to an existing connection.
     pub fn set_mtu(&mut self, mtu: usize) {
-        self.mtu = mtu.max(FRAG_HEADER_SIZE + 1);
+        self.mtu = clamp_mtu(mtu);
+        info!("BLE RPC: MTU now {}", self.mtu);
+    }
+
+    pub fn mtu(&self) -> usize {
+        self.mtu
     }
 
     pub fn connect(&mut self, client_id: ClientId, mtu: usize) {
         self.connected = true;
         self.client_id = client_id;
-        self.mtu = mtu.max(FRAG_HEADER_SIZE + 1);
+        self.mtu = clamp_mtu(mtu);
         self.reassembly.reset();
         self.read_len = 0;
         self.read_pos = 0;
         self.write_seq = 0;
-        info!("BLE RPC: client {} connected (MTU={})", client_id, mtu);
+        info!("BLE RPC: client {} connected (MTU={})", client_id, self.mtu);
     }
 
     pub fn disconnect(&mut self) {
@@ -232,7 +272,9 @@ impl BleTransport {
     where
         F: FnMut(&[u8]) -> Result<(), BleTransportError>,
     {
-        let max_payload = self.mtu.saturating_sub(3 + FRAG_HEADER_SIZE);
+        let max_payload = self
+            .mtu
+            .saturating_sub(ATT_NOTIFY_OVERHEAD + FRAG_HEADER_SIZE);
         if max_payload == 0 {
             return Err(BleTransportError::MtuExceeded);
         }
@@ -242,7 +284,7 @@ impl BleTransport {
         let total = chunks.len();
 
         for (i, chunk) in chunks.iter().enumerate() {
-            let mut frag = [0u8; 512]; // max BLE packet
+            let mut frag = [0u8; MAX_ATT_MTU - ATT_NOTIFY_OVERHEAD];
             frag[0] = self.write_seq;
 
             let mut flags = 0u8;
@@ -368,9 +410,9 @@ mod tests {
     #[test]
     fn fragmented_send() {
         let mut bt = BleTransport::new();
-        bt.connect(0, 10); // tiny MTU
+        bt.connect(0, MIN_ATT_MTU);
 
-        let data = b"abcdefghij"; // 10 bytes
+        let data = b"abcdefghijklmnopqrstuvwxyz"; // 26 bytes > 18-byte payload
         let mut sent = Vec::new();
 
         bt.send_fragmented(data, |frag| {
@@ -383,4 +425,43 @@ mod tests {
         assert!(sent[0][1] & FRAG_FIRST != 0);
         assert!(sent.last().unwrap()[1] & FRAG_MORE == 0);
     }
+
+    #[test]
+    fn fragment_sizes_track_configured_mtu() {
+        let data: Vec<u8> = (0..1200u32).map(|i| i as u8).collect();
+        for mtu in [MIN_ATT_MTU, 128, 247, MAX_ATT_MTU] {
+            let mut bt = BleTransport::new();
+            bt.connect(0, mtu);
+
+            let mut sent = Vec::new();
+            bt.send_fragmented(&data, |frag| {
+                sent.push(frag.to_vec());
+                Ok(())
+            })
+            .unwrap();
+
+            let full = mtu - ATT_NOTIFY_OVERHEAD;
+            let (last, rest) = sent.split_last().unwrap();
+            assert!(rest.iter().all(|f| f.len() == full), "MTU {mtu}");
+            assert!(last.len() <= full);
+            assert_eq!(sent.len(), data.len().div_ceil(full - FRAG_HEADER_SIZE));
+
+            let joined: Vec<u8> = sent
+                .iter()
+                .flat_map(|f| f[FRAG_HEADER_SIZE..].iter().copied())
+                .collect();
+            assert_eq!(joined, data);
+        }
+    }
+
+    #[test]
+    fn mtu_is_clamped_to_att_limits() {
+        let mut bt = BleTransport::new();
+        bt.connect(0, 10);
+        assert_eq!(bt.mtu(), MIN_ATT_MTU);
+        bt.set_mtu(4096);
+        assert_eq!(bt.mtu(), MAX_ATT_MTU);
+        bt.set_mtu(247);
+        assert_eq!(bt.mtu(), 247);
+    }
 }
@@ -12,6 +12,14 @@
 //! | `server_cert`    | PEM-encoded server certificate       |
 //! | `server_key`     | PEM-encoded private key              |
 //! | `ca_cert`        | PEM-encoded CA certificate chain     |
+//! | `client_pins`    | Pinned client SHA-256s (32 B each)   |
+//!
+//! ## Client certificate pinning
+//!
+//! The CA chain admits any client certificate it signed.  Pinning
+//! narrows that to specific certificates: while [`ClientCertPins`] is
+//! non-empty, the TLS verify callback rejects a client whose certificate
+//! fingerprint (SHA-256 of the DER encoding) is not listed.
 
 use log::{info, warn};
 
@@ -21,6 +29,20 @@ const MAX_CERT_SIZE: usize = 4096;
 /// Maximum private key size.
 const MAX_KEY_SIZE: usize = 2048;
 
+/// Most client certificates that can be pinned at once.
+pub const MAX_CLIENT_CERT_PINS: usize = 8;
+
+/// NVS key holding the pinned fingerprints, concatenated.
+const CLIENT_PINS_KEY: &str = "client_pins";
+
+/// SHA-256 of a certificate's DER encoding.
+pub type CertFingerprint = [u8; 32];
+
+/// Fingerprint of a DER-encoded certificate.
+pub fn fingerprint(der: &[u8]) -> CertFingerprint {
+    hmac_sha256::Hash::hash(der)
+}
+
 /// TLS authentication mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TlsMode {
@@ -41,6 +63,8 @@ pub struct CertBundle {
     pub server_key: heapless::Vec<u8, MAX_KEY_SIZE>,
     /// CA certificate chain (PEM-encoded, NUL-terminated for mbedTLS).
     pub ca_cert: heapless::Vec<u8, MAX_CERT_SIZE>,
+    /// Client certificates allowed to connect (empty = any the CA signed).
+    pub client_pins: ClientCertPins,
 }
 
 impl CertBundle {
@@ -49,14 +73,86 @@ impl CertBundle {
     }
 }
 
+/// Allow-list of client certificates by fingerprint.
+///
+/// Empty means no pinning: every client certificate the CA chain
+/// accepts is let in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientCertPins {
+    pins: heapless::Vec<CertFingerprint, MAX_CLIENT_CERT_PINS>,
+}
+
+impl ClientCertPins {
+    pub fn is_empty(&self) -> bool {
+        self.pins.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pins.len()
+    }
+
+    /// Pin `fp`.  `Ok(false)` if it was already pinned.
+    pub fn add(&mut self, fp: CertFingerprint) -> Result<bool, CertStoreError> {
+        if self.pins.contains(&fp) {
+            return Ok(false);
+        }
+        self.pins
+            .push(fp)
+            .map_err(|_| CertStoreError::PinListFull)?;
+        Ok(true)
+    }
+
+    /// Unpin `fp`.  `false` if it was not pinned.
+    pub fn remove(&mut self, fp: &CertFingerprint) -> bool {
+        match self.pins.iter().position(|p| p == fp) {
+            Some(i) => {
+                self.pins.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether a client presenting the DER certificate `der` may connect.
+    pub fn permits(&self, der: &[u8]) -> bool {
+        self.is_empty() || self.pins.contains(&fingerprint(der))
+    }
+
+    fn to_bytes(&self) -> heapless::Vec<u8, { MAX_CLIENT_CERT_PINS * 32 }> {
+        let mut out = heapless::Vec::new();
+        for fp in &self.pins {
+            let _ = out.extend_from_slice(fp);
+        }
+        out
+    }
+
+    /// Rebuild from the stored form; a trailing partial entry is dropped.
+    #[cfg_attr(not(target_os = "espidf"), allow(dead_code))]
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut pins = Self::default();
+        for chunk in bytes.chunks_exact(32) {
+            let mut fp = [0u8; 32];
+            fp.copy_from_slice(chunk);
+            if pins.add(fp).is_err() {
+                break;
+            }
+        }
+        pins
+    }
+}
+
 /// Certificate store adapter.
 pub struct CertStore {
     mode: TlsMode,
+    client_pins: ClientCertPins,
 }
 
 impl CertStore {
     pub fn new(mode: TlsMode) -> Self {
-        Self { mode }
+        Self {
+            mode,
+            client_pins: Self::platform_load_pins(),
+        }
     }
 
     pub fn mode(&self) -> TlsMode {
@@ -75,7 +171,8 @@ impl CertStore {
             return None;
         }
 
-        let bundle = self.platform_load()?;
+        let mut bundle = self.platform_load()?;
+        bundle.client_pins = self.client_pins.clone();
 
         if !bundle.is_complete() {
             warn!("CertStore: incomplete certificate bundle");
@@ -92,6 +189,34 @@ impl CertStore {
         Some(bundle)
     }
 
+    pub fn client_pins(&self) -> &ClientCertPins {
+        &self.client_pins
+    }
+
+    /// Pin a client certificate and persist the list.  `Ok(false)` if
+    /// it was already pinned.
+    pub fn add_client_pin(&mut self, fp: CertFingerprint) -> Result<bool, CertStoreError> {
+        let mut pins = self.client_pins.clone();
+        if !pins.add(fp)? {
+            return Ok(false);
+        }
+        self.store_cert(CLIENT_PINS_KEY, &pins.to_bytes())?;
+        self.client_pins = pins;
+        Ok(true)
+    }
+
+    /// Unpin a client certificate and persist the list.  `Ok(false)` if
+    /// it was not pinned.
+    pub fn remove_client_pin(&mut self, fp: &CertFingerprint) -> Result<bool, CertStoreError> {
+        let mut pins = self.client_pins.clone();
+        if !pins.remove(fp) {
+            return Ok(false);
+        }
+        self.store_cert(CLIENT_PINS_KEY, &pins.to_bytes())?;
+        self.client_pins = pins;
+        Ok(true)
+    }
+
     /// Store a certificate component. Used during RPC-based cert provisioning.
     #[cfg(target_os = "espidf")]
     pub fn store_cert(&self, key: &str, data: &[u8]) -> Result<(), CertStoreError> {
@@ -126,6 +251,7 @@ impl CertStore {
             server_cert: heapless::Vec::new(),
             server_key: heapless::Vec::new(),
             ca_cert: heapless::Vec::new(),
+            client_pins: ClientCertPins::default(),
         };
 
         let mut buf = [0u8; MAX_CERT_SIZE];
@@ -151,14 +277,37 @@ impl CertStore {
         info!("CertStore(sim): platform_load returning None (certs not available in simulation)");
         None
     }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_load_pins() -> ClientCertPins {
+        use esp_idf_svc::nvs::{EspNvs, EspNvsPartition, NvsDefault};
+
+        let Some(nvs) = EspNvsPartition::<NvsDefault>::take()
+            .ok()
+            .and_then(|p| EspNvs::new(p, "certs", false).ok())
+        else {
+            return ClientCertPins::default();
+        };
+        let mut buf = [0u8; MAX_CLIENT_CERT_PINS * 32];
+        match nvs.get_blob(CLIENT_PINS_KEY, &mut buf) {
+            Ok(Some(data)) => ClientCertPins::from_bytes(data),
+            _ => ClientCertPins::default(),
+        }
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_load_pins() -> ClientCertPins {
+        ClientCertPins::default()
+    }
 }
 
 /// Errors from the certificate store.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum CertStoreError {
     PartitionNotFound,
     NvsError,
     WriteFailed,
+    PinListFull,
 }
 
 impl core::fmt::Display for CertStoreError {
@@ -167,6 +316,7 @@ impl core::fmt::Display for CertStoreError {
             Self::PartitionNotFound => write!(f, "cert partition not found"),
             Self::NvsError => write!(f, "NVS initialization error"),
             Self::WriteFailed => write!(f, "cert write failed"),
+            Self::PinListFull => write!(f, "client cert pin list full"),
         }
     }
 }
@@ -194,6 +344,7 @@ mod tests {
             server_cert: heapless::Vec::new(),
             server_key: heapless::Vec::new(),
             ca_cert: heapless::Vec::new(),
+            client_pins: ClientCertPins::default(),
         };
         assert!(!bundle.is_complete());
 
@@ -204,4 +355,65 @@ mod tests {
         let _ = bundle.ca_cert.extend_from_slice(b"ca");
         assert!(bundle.is_complete());
     }
+    const CLIENT_A: &[u8] = b"client-a certificate DER";
+    const CLIENT_B: &[u8] = b"client-b certificate DER";
+
+    #[test]
+    fn no_pins_permits_any_client() {
+        let pins = ClientCertPins::default();
+        assert!(pins.permits(CLIENT_A));
+        assert!(pins.permits(CLIENT_B));
+    }
+
+    #[test]
+    fn pinned_fingerprint_allows_only_listed_clients() {
+        let mut pins = ClientCertPins::default();
+        assert_eq!(pins.add(fingerprint(CLIENT_A)), Ok(true));
+        assert!(pins.permits(CLIENT_A));
+        assert!(!pins.permits(CLIENT_B));
+
+        assert_eq!(pins.add(fingerprint(CLIENT_A)), Ok(false));
+        assert_eq!(pins.len(), 1);
+
+        assert!(pins.remove(&fingerprint(CLIENT_A)));
+        assert!(!pins.remove(&fingerprint(CLIENT_A)));
+        assert!(pins.permits(CLIENT_B), "unpinning the last entry reopens");
+    }
+
+    #[test]
+    fn pin_list_is_bounded() {
+        let mut pins = ClientCertPins::default();
+        for i in 0..MAX_CLIENT_CERT_PINS {
+            assert_eq!(pins.add([i as u8; 32]), Ok(true));
+        }
+        assert_eq!(pins.add([0xFF; 32]), Err(CertStoreError::PinListFull));
+        assert!(!pins.permits(CLIENT_A));
+    }
+
+    #[test]
+    fn pins_survive_the_stored_form() {
+        let mut pins = ClientCertPins::default();
+        pins.add(fingerprint(CLIENT_A)).unwrap();
+        pins.add(fingerprint(CLIENT_B)).unwrap();
+        let bytes = pins.to_bytes();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(ClientCertPins::from_bytes(&bytes), pins);
+        assert_eq!(ClientCertPins::from_bytes(&bytes[..40]).len(), 1);
+    }
+
+    #[test]
+    fn store_tracks_pins_for_the_bundle() {
+        let mut store = CertStore::new(TlsMode::PskAndCert);
+        assert_eq!(store.add_client_pin(fingerprint(CLIENT_A)).ok(), Some(true));
+        assert!(!store.client_pins().permits(CLIENT_B));
+        assert_eq!(
+            store.remove_client_pin(&fingerprint(CLIENT_B)).ok(),
+            Some(false)
+        );
+        assert_eq!(
+            store.remove_client_pin(&fingerprint(CLIENT_A)).ok(),
+            Some(true)
+        );
+        assert!(store.client_pins().is_empty());
+    }
 }
@@ -9,15 +9,16 @@ use crate::app::ports::{ActuatorPort, SensorPort};
 use crate::drivers::pump::{Direction, PumpDriver};
 use crate::drivers::status_led::StatusLed;
 use crate::drivers::uvc::UvcDriver;
-use crate::fsm::context::SensorSnapshot;
-use crate::sensors::SensorHub;
+use crate::fsm::context::{SensorSnapshot, Tank};
+use crate::sensors::{SelfTestReport, SensorHub};
 
 /// Concrete adapter that combines all hardware behind port traits.
 pub struct HardwareAdapter {
     sensor_hub: SensorHub,
     pump: PumpDriver,
     uvc: UvcDriver,
-    led: StatusLed,
+    /// `None` on a second filter loop: the status LED belongs to zone 0.
+    led: Option<StatusLed>,
 }
 
 impl HardwareAdapter {
@@ -26,7 +27,17 @@ impl HardwareAdapter {
             sensor_hub,
             pump,
             uvc,
-            led,
+            led: Some(led),
+        }
+    }
+
+    /// Hardware of a second filter loop, which has no status LED.
+    pub fn secondary(sensor_hub: SensorHub, pump: PumpDriver, uvc: UvcDriver) -> Self {
+        Self {
+            sensor_hub,
+            pump,
+            uvc,
+            led: None,
         }
     }
 }
@@ -38,8 +49,30 @@ impl SensorPort for HardwareAdapter {
         self.sensor_hub.read_all(elapsed_secs)
     }
 
-    fn read_ammonia_fast(&mut self) -> f32 {
-        self.sensor_hub.ammonia.read().ppm
+    fn read_ammonia_fast(&mut self) -> Option<f32> {
+        self.sensor_hub.ammonia.sample()
+    }
+
+    fn set_nh3_oversample(&mut self, samples: u8, fast_ticks_per_interval: u32) {
+        self.sensor_hub
+            .ammonia
+            .set_oversample(samples, fast_ticks_per_interval);
+    }
+
+    fn set_nh3_adc_samples(&mut self, samples: u8) {
+        self.sensor_hub.ammonia.set_adc_samples(samples);
+    }
+
+    fn set_flow_glitch_filter_us(&mut self, min_pulse_us: u16) {
+        self.sensor_hub.flow.set_glitch_filter_us(min_pulse_us);
+    }
+
+    fn set_interlock_active_low(&mut self, active_low: bool) {
+        crate::sensors::set_interlock_active_low(active_low);
+    }
+
+    fn self_test(&mut self) -> SelfTestReport {
+        self.sensor_hub.self_test()
     }
 }
 
@@ -59,6 +92,10 @@ impl ActuatorPort for HardwareAdapter {
         self.pump.stop();
     }
 
+    fn select_tank(&mut self, tank: Tank) {
+        self.pump.set_source(tank);
+    }
+
     fn enable_uvc(&mut self, duty: u8) {
         self.uvc.enable(duty);
     }
@@ -76,12 +113,16 @@ impl ActuatorPort for HardwareAdapter {
     }
 
     fn set_led(&mut self, r: u8, g: u8, b: u8) {
-        self.led.set_colour(r, g, b);
+        if let Some(led) = &mut self.led {
+            led.set_colour(r, g, b);
+        }
     }
 
     fn all_off(&mut self) {
         self.pump.stop();
         self.uvc.disable();
-        self.led.off();
+        if let Some(led) = &mut self.led {
+            led.off();
+        }
     }
 }
@@ -0,0 +1,155 @@
+//! Persistent install identity.
+//!
+//! The serial number in `DeviceInfoResponse` is derived from the MAC
+//! (see [`device_id`](super::device_id)), so it changes with the board.
+//! The install id is a random UUID generated on first boot and kept in
+//! NVS; an owner replacing a board can copy the old one across with
+//! `SetInstallIdRequest` so home-automation integrations keep seeing the
+//! same device.
+
+use log::{info, warn};
+
+use crate::app::ports::{StorageError, StoragePort};
+
+const INSTALL_ID_NAMESPACE: &str = "device";
+const INSTALL_ID_KEY: &str = "install_id";
+
+/// Raw 16-byte UUID.
+pub type InstallId = [u8; 16];
+
+/// Hyphenated UUID text: `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+pub type InstallIdString = heapless::String<36>;
+
+/// Byte offsets after which the textual form carries a hyphen.
+const HYPHEN_AFTER: [usize; 4] = [4, 6, 8, 10];
+
+/// A fresh random (version 4, RFC 4122 variant) UUID.
+pub fn generate() -> InstallId {
+    let mut id = [0u8; 16];
+    crate::rpc::auth::fill_random(&mut id);
+    id[6] = (id[6] & 0x0F) | 0x40;
+    id[8] = (id[8] & 0x3F) | 0x80;
+    id
+}
+
+/// The stored install id, or a newly generated one written to NVS if
+/// there is none yet.  A failed write is logged and the id still used
+/// for this boot; the next boot tries again with a new one.
+pub fn load_or_create(nvs: &mut dyn StoragePort) -> InstallId {
+    let mut buf = [0u8; 17];
+    match nvs.read(INSTALL_ID_NAMESPACE, INSTALL_ID_KEY, &mut buf) {
+        Ok(16) => {
+            let mut id = [0u8; 16];
+            id.copy_from_slice(&buf[..16]);
+            return id;
+        }
+        Ok(len) => warn!("install id: ignoring stored value of {} bytes", len),
+        Err(StorageError::NotFound) => {}
+        Err(e) => warn!("install id: read failed: {:?}", e),
+    }
+    let id = generate();
+    match store(nvs, &id) {
+        Ok(()) => info!("install id: generated {}", format(&id)),
+        Err(e) => warn!("install id: could not persist new id: {:?}", e),
+    }
+    id
+}
+
+/// Persist `id`, replacing any stored install id.
+pub fn store(nvs: &mut dyn StoragePort, id: &InstallId) -> Result<(), StorageError> {
+    nvs.write(INSTALL_ID_NAMESPACE, INSTALL_ID_KEY, id)
+}
+
+/// Lowercase hyphenated form.
+pub fn format(id: &InstallId) -> InstallIdString {
+    use core::fmt::Write;
+    let mut s = InstallIdString::new();
+    for (i, b) in id.iter().enumerate() {
+        if HYPHEN_AFTER.contains(&i) {
+            let _ = s.push('-');
+        }
+        let _ = write!(s, "{:02x}", b);
+    }
+    s
+}
+
+/// Parse the hyphenated form (either case).  The nil UUID is refused,
+/// as is anything not exactly 36 characters.
+pub fn parse(s: &str) -> Option<InstallId> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 36 {
+        return None;
+    }
+    let mut id = [0u8; 16];
+    let mut pos = 0;
+    for (i, out) in id.iter_mut().enumerate() {
+        if HYPHEN_AFTER.contains(&i) {
+            if bytes[pos] != b'-' {
+                return None;
+            }
+            pos += 1;
+        }
+        let hi = char::from(bytes[pos]).to_digit(16)?;
+        let lo = char::from(bytes[pos + 1]).to_digit(16)?;
+        *out = ((hi << 4) | lo) as u8;
+        pos += 2;
+    }
+    (id != [0u8; 16]).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::nvs::NvsAdapter;
+
+    const TEXT: &str = "0f8e3a52-6c1d-4b7a-9e20-5d4c3b2a1908";
+
+    #[test]
+    fn generated_ids_are_v4_and_distinct() {
+        let a = generate();
+        let b = generate();
+        assert_ne!(a, b);
+        assert_eq!(a[6] >> 4, 4);
+        assert_eq!(a[8] & 0xC0, 0x80);
+    }
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        let id = parse(TEXT).unwrap();
+        assert_eq!(id[0], 0x0f);
+        assert_eq!(id[15], 0x08);
+        assert_eq!(format(&id).as_str(), TEXT);
+        assert_eq!(parse(&TEXT.to_uppercase()), Some(id));
+    }
+
+    #[test]
+    fn malformed_text_is_refused() {
+        for bad in [
+            "",
+            "0f8e3a52-6c1d-4b7a-9e20-5d4c3b2a190",
+            "0f8e3a52-6c1d-4b7a-9e20-5d4c3b2a19080",
+            "0f8e3a526c1d-4b7a-9e20-5d4c3b2a19080",
+            "0f8e3a52-6c1d-4b7a-9e20-5d4c3b2a19zz",
+            "+f8e3a52-6c1d-4b7a-9e20-5d4c3b2a1908",
+            "00000000-0000-0000-0000-000000000000",
+        ] {
+            assert_eq!(parse(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn first_boot_generates_and_later_boots_reuse() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        let first = load_or_create(&mut nvs);
+        assert_eq!(load_or_create(&mut nvs), first);
+    }
+
+    #[test]
+    fn stored_id_replaces_generated_one() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        load_or_create(&mut nvs);
+        let id = parse(TEXT).unwrap();
+        store(&mut nvs, &id).unwrap();
+        assert_eq!(load_or_create(&mut nvs), id);
+    }
+}
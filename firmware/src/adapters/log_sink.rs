@@ -3,47 +3,105 @@
 //! Implements [`EventSink`] by writing structured application events to
 //! the ESP-IDF logger (which goes to UART / USB-CDC in production).
 //! A future MQTT or BLE adapter would implement the same trait.
+//!
+//! Two output formats are supported:
+//!
+//! - [`LogFormat::Text`] — human-readable lines (default).
+//! - [`LogFormat::Json`] — one compact JSON object per event for log
+//!   aggregators.  Every object carries `ts` (ms since boot) and
+//!   `event`; the remaining keys depend on the event kind and are
+//!   stable across releases.  Build with the `json-logs` feature to
+//!   make this the default.
 
-use log::info;
+use log::{info, warn};
+use serde_json::{Value, json};
 
+use crate::adapters::time::Esp32TimeAdapter;
 use crate::app::events::AppEvent;
 use crate::app::ports::EventSink;
 
+/// Output format of [`LogEventSink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        if cfg!(feature = "json-logs") {
+            Self::Json
+        } else {
+            Self::Text
+        }
+    }
+}
+
 /// Adapter that logs every [`AppEvent`] to the serial console.
 #[derive(Default)]
-pub struct LogEventSink;
+pub struct LogEventSink {
+    format: LogFormat,
+    clock: Esp32TimeAdapter,
+}
 
 impl LogEventSink {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
-}
 
-impl EventSink for LogEventSink {
-    fn emit(&mut self, event: &AppEvent) {
+    pub fn with_format(format: LogFormat) -> Self {
+        Self {
+            format,
+            clock: Esp32TimeAdapter::new(),
+        }
+    }
+
+    pub fn format(&self) -> LogFormat {
+        self.format
+    }
+
+    fn emit_text(event: &AppEvent) {
         match event {
             AppEvent::Telemetry(t) => {
                 info!(
-                    "TELEM | state={:?} | NH3={:.1}/{:.1}ppm | flow={:.0}mL/min | \
-                     T={:.1}\u{00b0}C | tank_a={} tank_b={} | pump={}% uvc={}% | \
-                     faults=0b{:08b}",
+                    "TELEM | zone={} state={:?} | NH3={:.1}/{:.1}ppm{}{} | flow={:.0}mL/min | \
+                     T={:.1}\u{00b0}C | tank_a={} tank_b={} src={:?} | pump={}%{} uvc={}%{} | \
+                     faults=0b{:08b}{} advisories=0b{:08b}",
+                    t.zone,
                     t.state,
                     t.nh3_ppm,
                     t.nh3_avg_ppm,
+                    if t.nh3_injected {
+                        " (injected)"
+                    } else if t.sensor_warming {
+                        " (warming)"
+                    } else {
+                        ""
+                    },
+                    if t.nh3_alarm { " ALARM" } else { "" },
                     t.flow_ml_per_min,
                     t.temperature_c,
                     if t.tank_a_ok { "OK" } else { "LOW" },
                     if t.tank_b_ok { "OK" } else { "LOW" },
+                    t.source_tank,
                     t.pump_duty,
+                    if t.priming { " (priming)" } else { "" },
                     t.uvc_duty,
+                    if t.uvc_warming { " (warming)" } else { "" },
                     t.fault_flags,
+                    if t.fault_test { " (test)" } else { "" },
+                    t.advisory_flags,
                 );
             }
             AppEvent::StateChanged { from, to } => {
                 info!("STATE | {:?} -> {:?}", from, to);
             }
-            AppEvent::FaultDetected(flags) => {
-                info!("FAULT | detected, flags=0b{:08b}", flags);
+            AppEvent::FaultDetected { flags, is_test } => {
+                info!(
+                    "FAULT | detected, flags=0b{:08b}{}",
+                    flags,
+                    if *is_test { " (test)" } else { "" }
+                );
             }
             AppEvent::FaultCleared => {
                 info!("FAULT | all cleared");
@@ -51,6 +109,313 @@ impl EventSink for LogEventSink {
             AppEvent::Started(state) => {
                 info!("START | initial_state={:?}", state);
             }
+            AppEvent::SelfTestFailed(report) => {
+                warn!(
+                    "SELFTEST | nh3={:?} temp={:?} flow={:?} interlock={:?}",
+                    report.ammonia, report.temperature, report.flow, report.interlock
+                );
+            }
+        }
+    }
+}
+
+impl EventSink for LogEventSink {
+    fn emit(&mut self, event: &AppEvent) {
+        match self.format {
+            LogFormat::Text => Self::emit_text(event),
+            LogFormat::Json => {
+                let line = event_to_json(event, self.clock.uptime_us() / 1000).to_string();
+                if matches!(event, AppEvent::SelfTestFailed(_)) {
+                    warn!("{}", line);
+                } else {
+                    info!("{}", line);
+                }
+            }
         }
     }
 }
+
+/// Structured form of `event` as logged in [`LogFormat::Json`] mode.
+pub fn event_to_json(event: &AppEvent, ts_ms: u64) -> Value {
+    match event {
+        AppEvent::Telemetry(t) => json!({
+            "ts": ts_ms,
+            "event": "telemetry",
+            "zone": t.zone,
+            "state": format!("{:?}", t.state),
+            "nh3": round2(t.nh3_ppm),
+            "nh3_avg": round2(t.nh3_avg_ppm),
+            "flow": round2(t.flow_ml_per_min),
+            "temp": round2(t.temperature_c),
+            "tank_a": t.tank_a_ok,
+            "tank_b": t.tank_b_ok,
+            "source_tank": format!("{:?}", t.source_tank),
+            "pump": t.pump_duty,
+            "uvc": t.uvc_duty,
+            "uvc_warming": t.uvc_warming,
+            "sensor_warming": t.sensor_warming,
+            "nh3_injected": t.nh3_injected,
+            "nh3_alarm": t.nh3_alarm,
+            "priming": t.priming,
+            "faults": t.fault_flags,
+            "fault_test": t.fault_test,
+            "advisories": t.advisory_flags,
+            "purge_ml": round2(t.purge_volume_ml),
+            "drain_ml": round2(t.drain_volume_ml),
+            "drain_done": t.drain_complete,
+            "recovering": t.recovering,
+            "profile": format!("{:?}", t.power_profile),
+            "rssi": t.wifi_rssi,
+            "source": t.scrub_source.map(|s| format!("{:?}", s)),
+        }),
+        AppEvent::StateChanged { from, to } => json!({
+            "ts": ts_ms,
+            "event": "state_change",
+            "from": format!("{:?}", from),
+            "to": format!("{:?}", to),
+        }),
+        AppEvent::FaultDetected { flags, is_test } => json!({
+            "ts": ts_ms,
+            "event": "fault",
+            "faults": flags,
+            "test": is_test,
+        }),
+        AppEvent::FaultCleared => json!({
+            "ts": ts_ms,
+            "event": "fault_cleared",
+            "faults": 0,
+        }),
+        AppEvent::Started(state) => json!({
+            "ts": ts_ms,
+            "event": "started",
+            "state": format!("{:?}", state),
+        }),
+        AppEvent::SelfTestFailed(report) => {
+            let check = |r: &Result<(), crate::error::SensorError>| match r {
+                Ok(()) => "ok".to_string(),
+                Err(e) => format!("{:?}", e),
+            };
+            json!({
+                "ts": ts_ms,
+                "event": "self_test_failed",
+                "ammonia": check(&report.ammonia),
+                "temperature": check(&report.temperature),
+                "flow": check(&report.flow),
+                "interlock": check(&report.interlock),
+            })
+        }
+    }
+}
+
+/// Two decimal places, so f32 noise doesn't leak into the JSON.
+fn round2(v: f32) -> f64 {
+    (f64::from(v) * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::commands::CommandSource;
+    use crate::app::events::TelemetryData;
+    use crate::config::PowerProfile;
+    use crate::fsm::StateId;
+    use crate::fsm::context::Tank;
+    use crate::sensors::{SelfTestReport, check_interlock};
+
+    fn roundtrip(event: &AppEvent) -> Value {
+        let line = event_to_json(event, 1234).to_string();
+        assert!(!line.contains('\n'));
+        let parsed: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["ts"], 1234);
+        parsed
+    }
+
+    fn keys(v: &Value) -> Vec<&str> {
+        let mut k: Vec<&str> = v.as_object().unwrap().keys().map(String::as_str).collect();
+        k.sort_unstable();
+        k
+    }
+
+    #[test]
+    fn default_format_follows_feature() {
+        let expected = if cfg!(feature = "json-logs") {
+            LogFormat::Json
+        } else {
+            LogFormat::Text
+        };
+        assert_eq!(LogEventSink::new().format(), expected);
+        assert_eq!(
+            LogEventSink::with_format(LogFormat::Json).format(),
+            LogFormat::Json
+        );
+    }
+
+    #[test]
+    fn telemetry_json_shape() {
+        let event = AppEvent::Telemetry(TelemetryData {
+            zone: 1,
+            state: StateId::Active,
+            nh3_ppm: 12.3,
+            nh3_avg_ppm: 11.0,
+            flow_ml_per_min: 950.0,
+            temperature_c: 41.5,
+            tank_a_ok: true,
+            tank_b_ok: false,
+            source_tank: Tank::A,
+            pump_duty: 70,
+            uvc_duty: 100,
+            fault_flags: 0,
+            wifi_rssi: Some(-60),
+            scrub_source: Some(CommandSource::Button),
+            uvc_warming: true,
+            sensor_warming: false,
+            nh3_injected: false,
+            fault_test: false,
+            priming: false,
+            advisory_flags: 0,
+            purge_volume_ml: 0.0,
+            drain_volume_ml: 0.0,
+            drain_complete: false,
+            recovering: false,
+            power_profile: PowerProfile::Balanced,
+            nh3_alarm: true,
+        });
+        let v = roundtrip(&event);
+        assert_eq!(
+            keys(&v),
+            [
+                "advisories",
+                "drain_done",
+                "drain_ml",
+                "event",
+                "fault_test",
+                "faults",
+                "flow",
+                "nh3",
+                "nh3_alarm",
+                "nh3_avg",
+                "nh3_injected",
+                "priming",
+                "profile",
+                "pump",
+                "purge_ml",
+                "recovering",
+                "rssi",
+                "sensor_warming",
+                "source",
+                "source_tank",
+                "state",
+                "tank_a",
+                "tank_b",
+                "temp",
+                "ts",
+                "uvc",
+                "uvc_warming",
+                "zone"
+            ]
+        );
+        assert_eq!(v["event"], "telemetry");
+        assert_eq!(v["state"], "Active");
+        assert_eq!(v["nh3"], 12.3);
+        assert_eq!(v["nh3_alarm"], true);
+        assert_eq!(v["tank_b"], false);
+        assert_eq!(v["source_tank"], "A");
+        assert_eq!(v["rssi"], -60);
+        assert_eq!(v["source"], "Button");
+        assert_eq!(v["zone"], 1);
+    }
+
+    #[test]
+    fn telemetry_json_nulls_for_absent_fields() {
+        let event = AppEvent::Telemetry(TelemetryData {
+            zone: 1,
+            state: StateId::Idle,
+            nh3_ppm: 0.0,
+            nh3_avg_ppm: 0.0,
+            flow_ml_per_min: 0.0,
+            temperature_c: 25.0,
+            tank_a_ok: true,
+            tank_b_ok: true,
+            source_tank: Tank::B,
+            pump_duty: 0,
+            uvc_duty: 0,
+            fault_flags: 0,
+            wifi_rssi: None,
+            scrub_source: None,
+            uvc_warming: false,
+            sensor_warming: false,
+            nh3_injected: false,
+            fault_test: false,
+            priming: false,
+            advisory_flags: 0,
+            purge_volume_ml: 0.0,
+            drain_volume_ml: 0.0,
+            drain_complete: false,
+            recovering: false,
+            power_profile: PowerProfile::Balanced,
+            nh3_alarm: false,
+        });
+        let v = roundtrip(&event);
+        assert!(v["rssi"].is_null());
+        assert!(v["source"].is_null());
+    }
+
+    #[test]
+    fn state_change_json_shape() {
+        let v = roundtrip(&AppEvent::StateChanged {
+            from: StateId::Idle,
+            to: StateId::Sensing,
+        });
+        assert_eq!(keys(&v), ["event", "from", "to", "ts"]);
+        assert_eq!(v["event"], "state_change");
+        assert_eq!(v["from"], "Idle");
+        assert_eq!(v["to"], "Sensing");
+    }
+
+    #[test]
+    fn fault_json_shapes() {
+        let v = roundtrip(&AppEvent::FaultDetected {
+            flags: 0b0000_0101,
+            is_test: false,
+        });
+        assert_eq!(keys(&v), ["event", "faults", "test", "ts"]);
+        assert_eq!(v["event"], "fault");
+        assert_eq!(v["faults"], 5);
+        assert_eq!(v["test"], false);
+
+        let v = roundtrip(&AppEvent::FaultDetected {
+            flags: 0b0000_0100,
+            is_test: true,
+        });
+        assert_eq!(v["test"], true);
+
+        let v = roundtrip(&AppEvent::FaultCleared);
+        assert_eq!(keys(&v), ["event", "faults", "ts"]);
+        assert_eq!(v["event"], "fault_cleared");
+        assert_eq!(v["faults"], 0);
+    }
+
+    #[test]
+    fn started_json_shape() {
+        let v = roundtrip(&AppEvent::Started(StateId::Idle));
+        assert_eq!(keys(&v), ["event", "state", "ts"]);
+        assert_eq!(v["event"], "started");
+        assert_eq!(v["state"], "Idle");
+    }
+
+    #[test]
+    fn self_test_failed_json_shape() {
+        let report = SelfTestReport {
+            interlock: check_interlock(false, true),
+            ..Default::default()
+        };
+        let v = roundtrip(&AppEvent::SelfTestFailed(report));
+        assert_eq!(
+            keys(&v),
+            ["ammonia", "event", "flow", "interlock", "temperature", "ts"]
+        );
+        assert_eq!(v["event"], "self_test_failed");
+        assert_eq!(v["ammonia"], "ok");
+        assert_ne!(v["interlock"], "ok");
+    }
+}
@@ -7,30 +7,100 @@
 //! - Config validation: all fields are range-checked before persistence.
 //! - Encrypted NVS: on ESP32, the "auth" namespace lives on the encrypted
 //!   NVS partition. The simulation backend uses plaintext (dev/test only).
+//! - Credential integrity: every credential blob carries an HMAC-SHA256
+//!   tag keyed by the device MAC, so corruption or tampering is detected
+//!   on read even when partition encryption is off.  Blobs written by
+//!   firmware that predates the tag are re-stored tagged once, at boot
+//!   (see [`NvsAdapter::migrate_untagged_credentials`]).
 //! - Namespace isolation: each subsystem uses its own namespace prefix.
 //! - Atomic writes: ESP-IDF NVS commits are atomic per nvs_commit().
+//!
+//! # Partition full
+//!
+//! When a write fails with `ESP_ERR_NVS_NOT_ENOUGH_SPACE`, entries in the
+//! non-critical namespaces listed in [`EVICTABLE_NAMESPACES`] are evicted
+//! least-recently-written first and the write is retried once.  The ring
+//! indices of those logs are never evicted.  Critical
+//! namespaces (auth, config) are never evicted; writes to them surface
+//! [`StorageError::Full`] / [`ConfigError::StorageFull`] instead.
+//!
+//! # Config safe mode
+//!
+//! Every boot records whether the config loaded.  After more than
+//! [`CONFIG_SAFE_MODE_AFTER`] consecutive failures the adapter stops
+//! persisting config, so the corrupt blob is left for inspection rather
+//! than overwritten with whatever the device runs on meanwhile.  The
+//! counter resets on the first boot that loads successfully.
 
 use crate::app::ports::{ConfigError, ConfigPort, StorageError, StoragePort};
-use crate::config::SystemConfig;
+use crate::app::service::ZoneId;
+use crate::app::zones::{MAX_ZONES, zone_key};
+use crate::config::{self, SystemConfig};
+use crate::fsm::StateId;
+use crate::sensors::ammonia::{MAX_NH3_ADC_SAMPLES, MAX_NH3_OVERSAMPLE};
 use log::{info, warn};
 
-#[cfg(not(target_os = "espidf"))]
+use core::cell::{Cell, RefCell};
 use std::collections::HashMap;
 
 #[cfg(target_os = "espidf")]
 use esp_idf_svc::sys::*;
 
-#[cfg(target_os = "espidf")]
-
 const CONFIG_NAMESPACE: &str = "petfilter";
 const CONFIG_KEY: &str = "syscfg";
+/// Consecutive boots on which the config failed to load.
+const CONFIG_FAILURES_KEY: &str = "cfg_fails";
+
+/// Failed config loads tolerated before the adapter enters safe mode.
+pub const CONFIG_SAFE_MODE_AFTER: u8 = 3;
 
 const MAX_BLOB_SIZE: usize = 4000;
 const CRED_NAMESPACE: &str = "auth";
+/// Length of the HMAC-SHA256 tag appended to every credential blob.
+const CRED_TAG_LEN: usize = 32;
+/// HKDF salt separating the credential MAC key from other uses of the
+/// device MAC.
+const CRED_KEY_SALT: &[u8] = b"petfilter-cred-v1";
+/// Credentials that firmware before the integrity tag stored untagged.
+const LEGACY_CREDENTIAL_KEYS: [&str; 3] = ["wifi_ssid", "wifi_pass", "psk"];
+/// Longest untagged credential those builds wrote (the WiFi password).
+const LEGACY_CREDENTIAL_MAX: usize = 64;
+/// Set in the "auth" namespace once untagged credentials are migrated.
+const CRED_TAGGED_MARKER: &str = "tagged_v1";
+
+/// Namespaces whose entries may be discarded to make room when the
+/// partition is full.  Only diagnostic history belongs here — never
+/// credentials, config or OTA state.
+const EVICTABLE_NAMESPACES: &[&str] = &[
+    crate::diagnostics::CRASH_KEY_PREFIX,
+    crate::diagnostics::AUDIT_KEY_PREFIX,
+];
+
+/// Entries of evictable namespaces that are kept all the same: a ring
+/// that loses its index restarts at slot 0 and overwrites live entries.
+const EVICTION_PROTECTED: &[(&str, &str)] = &[
+    (
+        crate::diagnostics::CRASH_KEY_PREFIX,
+        crate::diagnostics::CRASH_INDEX_KEY,
+    ),
+    (
+        crate::diagnostics::AUDIT_KEY_PREFIX,
+        crate::diagnostics::AUDIT_INDEX_KEY,
+    ),
+];
 
 pub struct NvsAdapter {
     #[cfg(not(target_os = "espidf"))]
-    store: std::cell::RefCell<HashMap<String, Vec<u8>>>,
+    store: RefCell<HashMap<String, Vec<u8>>>,
+    /// Simulated partition size in bytes (`None` = unbounded).
+    #[cfg(not(target_os = "espidf"))]
+    capacity: Option<usize>,
+    /// Write sequence number of each key written this boot, used to pick
+    /// the least recently written entry for eviction.
+    last_write: RefCell<HashMap<String, u64>>,
+    write_clock: Cell<u64>,
+    /// Config saves are dropped (see [`NvsAdapter::record_config_load`]).
+    config_safe_mode: Cell<bool>,
 }
 
 impl NvsAdapter {
@@ -64,10 +134,29 @@ impl NvsAdapter {
         #[cfg(not(target_os = "espidf"))]
         info!("NvsAdapter: simulation backend");
 
-        Ok(Self {
+        Ok(Self::empty())
+    }
+
+    /// Simulation backend that reports `StorageError::Full` once the total
+    /// size of stored values would exceed `capacity` bytes.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::empty()
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
             #[cfg(not(target_os = "espidf"))]
-            store: std::cell::RefCell::new(HashMap::new()),
-        })
+            store: RefCell::new(HashMap::new()),
+            #[cfg(not(target_os = "espidf"))]
+            capacity: None,
+            last_write: RefCell::new(HashMap::new()),
+            write_clock: Cell::new(0),
+            config_safe_mode: Cell::new(false),
+        }
     }
 
     fn composite_key(namespace: &str, key: &str) -> String {
@@ -103,9 +192,209 @@ impl NvsAdapter {
         }
         result
     }
+
+    /// Write a blob, evicting the least recently written entry of an
+    /// evictable namespace and retrying once if the partition is full.
+    fn write_with_eviction(
+        &self,
+        namespace: &str,
+        key: &str,
+        data: &[u8],
+    ) -> Result<(), StorageError> {
+        let mut result = self.platform_write(namespace, key, data);
+        if matches!(result, Err(StorageError::Full)) && EVICTABLE_NAMESPACES.contains(&namespace) {
+            if let Some(victim) = self.evict_oldest(namespace, key) {
+                warn!(
+                    "NvsAdapter: partition full, evicted {} to write {}::{}",
+                    victim, namespace, key
+                );
+                result = self.platform_write(namespace, key, data);
+            }
+        }
+
+        match result {
+            Ok(()) => {
+                let seq = self.write_clock.get() + 1;
+                self.write_clock.set(seq);
+                self.last_write
+                    .borrow_mut()
+                    .insert(Self::composite_key(namespace, key), seq);
+            }
+            Err(StorageError::Full) => {
+                warn!(
+                    "NvsAdapter: partition full, {}::{} not written",
+                    namespace, key
+                );
+            }
+            Err(_) => {}
+        }
+        result
+    }
+
+    /// Erase the least recently written entry across all evictable
+    /// namespaces, skipping the key about to be written and the
+    /// [`EVICTION_PROTECTED`] ring indices.  Entries not written since
+    /// boot count as oldest.  Returns the evicted key.
+    fn evict_oldest(&self, skip_ns: &str, skip_key: &str) -> Option<String> {
+        let skip = Self::composite_key(skip_ns, skip_key);
+        let victim = {
+            let last_write = self.last_write.borrow();
+            EVICTABLE_NAMESPACES
+                .iter()
+                .flat_map(|ns| {
+                    self.platform_list_keys(ns)
+                        .into_iter()
+                        .map(move |k| (*ns, k))
+                })
+                .filter(|(ns, k)| Self::composite_key(ns, k) != skip)
+                .filter(|(ns, k)| !EVICTION_PROTECTED.contains(&(*ns, k.as_str())))
+                .min_by_key(|(ns, k)| {
+                    last_write
+                        .get(&Self::composite_key(ns, k))
+                        .copied()
+                        .unwrap_or(0)
+                })
+        };
+
+        let (ns, key) = victim?;
+        self.platform_delete(ns, &key).ok()?;
+        let composite = Self::composite_key(ns, &key);
+        self.last_write.borrow_mut().remove(&composite);
+        Some(composite)
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_write(&self, namespace: &str, key: &str, data: &[u8]) -> Result<(), StorageError> {
+        if data.len() > MAX_BLOB_SIZE {
+            return Err(StorageError::IoError);
+        }
+        let composite = Self::composite_key(namespace, key);
+        let mut store = self.store.borrow_mut();
+        if let Some(capacity) = self.capacity {
+            let used: usize = store.values().map(Vec::len).sum();
+            let replaced = store.get(&composite).map_or(0, Vec::len);
+            if used - replaced + data.len() > capacity {
+                return Err(StorageError::Full);
+            }
+        }
+        store.insert(composite, data.to_vec());
+        Ok(())
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_write(&self, namespace: &str, key: &str, data: &[u8]) -> Result<(), StorageError> {
+        let result = Self::with_nvs_handle(namespace, true, |handle| {
+            let mut key_buf = [0u8; 16];
+            let kb = key.as_bytes();
+            let kl = kb.len().min(15);
+            key_buf[..kl].copy_from_slice(&kb[..kl]);
+
+            let ret = unsafe {
+                nvs_set_blob(
+                    handle,
+                    key_buf.as_ptr() as *const _,
+                    data.as_ptr() as *const _,
+                    data.len(),
+                )
+            };
+            if ret != ESP_OK {
+                return Err(ret);
+            }
+            let ret = unsafe { nvs_commit(handle) };
+            if ret != ESP_OK {
+                return Err(ret);
+            }
+            Ok(())
+        });
+        result.map_err(|e| {
+            if e == ESP_ERR_NVS_NOT_ENOUGH_SPACE {
+                StorageError::Full
+            } else {
+                StorageError::IoError
+            }
+        })
+    }
+
+    // Fallible to match the ESP-IDF backend.
+    #[cfg(not(target_os = "espidf"))]
+    #[allow(clippy::unnecessary_wraps)]
+    fn platform_delete(&self, namespace: &str, key: &str) -> Result<(), StorageError> {
+        let composite = Self::composite_key(namespace, key);
+        self.store.borrow_mut().remove(&composite);
+        Ok(())
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_delete(&self, namespace: &str, key: &str) -> Result<(), StorageError> {
+        let result = Self::with_nvs_handle(namespace, true, |handle| {
+            let mut key_buf = [0u8; 16];
+            let kb = key.as_bytes();
+            let kl = kb.len().min(15);
+            key_buf[..kl].copy_from_slice(&kb[..kl]);
+
+            let ret = unsafe { nvs_erase_key(handle, key_buf.as_ptr() as *const _) };
+            if ret != ESP_OK && ret != ESP_ERR_NVS_NOT_FOUND {
+                return Err(ret);
+            }
+            let ret = unsafe { nvs_commit(handle) };
+            if ret != ESP_OK {
+                return Err(ret);
+            }
+            Ok(())
+        });
+        result.map_err(|_| StorageError::IoError)
+    }
+
+    /// Keys currently stored in `namespace`.
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_list_keys(&self, namespace: &str) -> Vec<String> {
+        let prefix = format!("{}::", namespace);
+        self.store
+            .borrow()
+            .keys()
+            .filter_map(|k| k.strip_prefix(&prefix).map(String::from))
+            .collect()
+    }
+
+    /// Keys currently stored in `namespace`.
+    #[cfg(target_os = "espidf")]
+    fn platform_list_keys(&self, namespace: &str) -> Vec<String> {
+        let mut ns_buf = [0u8; 16];
+        let ns_bytes = namespace.as_bytes();
+        let len = ns_bytes.len().min(15);
+        ns_buf[..len].copy_from_slice(&ns_bytes[..len]);
+
+        let mut keys = Vec::new();
+        let mut it: nvs_iterator_t = core::ptr::null_mut();
+        // SAFETY: the iterator is released on every exit path below; the
+        // partition label is the default NVS partition.
+        let mut ret = unsafe {
+            nvs_entry_find(
+                b"nvs\0".as_ptr() as *const _,
+                ns_buf.as_ptr() as *const _,
+                nvs_type_t_NVS_TYPE_ANY,
+                &mut it,
+            )
+        };
+        while ret == ESP_OK {
+            let mut info: nvs_entry_info_t = unsafe { core::mem::zeroed() };
+            unsafe { nvs_entry_info(it, &mut info) };
+            let raw = &info.key;
+            let end = raw.iter().position(|&c| c == 0).unwrap_or(raw.len());
+            let bytes: Vec<u8> = raw[..end].iter().map(|&c| c as u8).collect();
+            if let Ok(key) = String::from_utf8(bytes) {
+                keys.push(key);
+            }
+            ret = unsafe { nvs_entry_next(&mut it) };
+        }
+        unsafe { nvs_release_iterator(it) };
+        keys
+    }
 }
 
-fn validate_config(cfg: &SystemConfig) -> Result<(), ConfigError> {
+/// Range-check every field of `cfg`.  The error names the first
+/// offending field and its allowed range.
+pub fn validate_config(cfg: &SystemConfig) -> Result<(), ConfigError> {
     if !(1.0..=200.0).contains(&cfg.nh3_activate_threshold_ppm) {
         return Err(ConfigError::ValidationFailed(
             "nh3_activate_threshold_ppm must be 1.0–200.0",
@@ -121,6 +410,53 @@ fn validate_config(cfg: &SystemConfig) -> Result<(), ConfigError> {
             "nh3_deactivate_threshold_ppm must be < nh3_activate_threshold_ppm",
         ));
     }
+    if !(2.0..=500.0).contains(&cfg.nh3_urgent_threshold_ppm) {
+        return Err(ConfigError::ValidationFailed(
+            "nh3_urgent_threshold_ppm must be 2.0–500.0",
+        ));
+    }
+    if cfg.nh3_urgent_threshold_ppm <= cfg.nh3_activate_threshold_ppm {
+        return Err(ConfigError::ValidationFailed(
+            "nh3_urgent_threshold_ppm must be > nh3_activate_threshold_ppm",
+        ));
+    }
+    if !(1..=MAX_NH3_OVERSAMPLE).contains(&cfg.nh3_oversample_count) {
+        return Err(ConfigError::ValidationFailed(
+            "nh3_oversample_count must be 1–10",
+        ));
+    }
+    if !(1..=MAX_NH3_ADC_SAMPLES).contains(&cfg.nh3_adc_samples) {
+        return Err(ConfigError::ValidationFailed("nh3_adc_samples must be 1–9"));
+    }
+    let rate = cfg.nh3_rate_threshold_ppm_per_sec;
+    if rate != 0.0 && !(0.1..=100.0).contains(&rate) {
+        return Err(ConfigError::ValidationFailed(
+            "nh3_rate_threshold_ppm_per_sec must be 0 or 0.1–100.0",
+        ));
+    }
+    if cfg.nh3_warmup_secs > 900 {
+        return Err(ConfigError::ValidationFailed(
+            "nh3_warmup_secs must be 0–900",
+        ));
+    }
+    let alarm_high = cfg.nh3_alarm_high_ppm;
+    if alarm_high != 0.0 {
+        if !(1.0..=500.0).contains(&alarm_high) {
+            return Err(ConfigError::ValidationFailed(
+                "nh3_alarm_high_ppm must be 0 or 1.0–500.0",
+            ));
+        }
+        if !(0.5..=499.0).contains(&cfg.nh3_alarm_clear_ppm) {
+            return Err(ConfigError::ValidationFailed(
+                "nh3_alarm_clear_ppm must be 0.5–499.0",
+            ));
+        }
+        if cfg.nh3_alarm_clear_ppm >= alarm_high {
+            return Err(ConfigError::ValidationFailed(
+                "nh3_alarm_clear_ppm must be < nh3_alarm_high_ppm",
+            ));
+        }
+    }
     if cfg.pump_duty_percent > 100 {
         return Err(ConfigError::ValidationFailed(
             "pump_duty_percent must be 0–100",
@@ -131,16 +467,136 @@ fn validate_config(cfg: &SystemConfig) -> Result<(), ConfigError> {
             "uvc_duty_percent must be 0–100",
         ));
     }
+    if cfg.prime_duration_secs > 60 {
+        return Err(ConfigError::ValidationFailed(
+            "prime_duration_secs must be 0–60",
+        ));
+    }
+    if !(1..=100).contains(&cfg.prime_duty_percent) {
+        return Err(ConfigError::ValidationFailed(
+            "prime_duty_percent must be 1–100",
+        ));
+    }
+    if cfg.soft_stop_ticks > 10 {
+        return Err(ConfigError::ValidationFailed(
+            "soft_stop_ticks must be 0–10",
+        ));
+    }
+    let gains = [cfg.pump_pid_kp, cfg.pump_pid_ki, cfg.pump_pid_kd];
+    if !gains.iter().all(|g| (0.0..=1000.0).contains(g)) {
+        return Err(ConfigError::ValidationFailed(
+            "pump_pid_kp/ki/kd must be 0.0–1000.0",
+        ));
+    }
+    // The YF-S201 peaks near 125 Hz (8 ms); stay well under half that.
+    if cfg.flow_glitch_filter_us > 4000 {
+        return Err(ConfigError::ValidationFailed(
+            "flow_glitch_filter_us must be 0–4000",
+        ));
+    }
+    if cfg.uvc_warmup_secs > 300 {
+        return Err(ConfigError::ValidationFailed(
+            "uvc_warmup_secs must be 0–300",
+        ));
+    }
     if !(40.0..=120.0).contains(&cfg.max_temperature_c) {
         return Err(ConfigError::ValidationFailed(
             "max_temperature_c must be 40.0–120.0",
         ));
     }
+    // Evaluated once per control tick; more than ~10 s of unacted
+    // overheat is not a debounce any more.
+    if !(1..=10).contains(&cfg.over_temp_debounce_samples) {
+        return Err(ConfigError::ValidationFailed(
+            "over_temp_debounce_samples must be 1–10",
+        ));
+    }
+    // Derating is a soft limit under the over-temperature fault, so it
+    // has to start below it.
+    let derate_start = cfg.pump_derate_start_c;
+    if derate_start != 0.0 && !(20.0..cfg.max_temperature_c).contains(&derate_start) {
+        return Err(ConfigError::ValidationFailed(
+            "pump_derate_start_c must be 0 or 20.0 up to max_temperature_c",
+        ));
+    }
+    // The flow PID never drives the pump below 10 %.
+    if !(10..=100).contains(&cfg.pump_derate_min_duty_percent) {
+        return Err(ConfigError::ValidationFailed(
+            "pump_derate_min_duty_percent must be 10–100",
+        ));
+    }
     if !(10..=600).contains(&cfg.purge_duration_secs) {
         return Err(ConfigError::ValidationFailed(
             "purge_duration_secs must be 10–600",
         ));
     }
+    if !(50..=20_000).contains(&cfg.purge_target_volume_ml) {
+        return Err(ConfigError::ValidationFailed(
+            "purge_target_volume_ml must be 50–20000",
+        ));
+    }
+    if !(10..=1800).contains(&cfg.purge_max_duration_secs) {
+        return Err(ConfigError::ValidationFailed(
+            "purge_max_duration_secs must be 10–1800",
+        ));
+    }
+    if cfg.max_active_duration_secs != 0 && !(300..=86_400).contains(&cfg.max_active_duration_secs)
+    {
+        return Err(ConfigError::ValidationFailed(
+            "max_active_duration_secs must be 0 or 300–86400",
+        ));
+    }
+    if cfg.min_active_secs > 1800 {
+        return Err(ConfigError::ValidationFailed(
+            "min_active_secs must be 0–1800",
+        ));
+    }
+    if cfg.max_active_duration_secs != 0
+        && u32::from(cfg.min_active_secs) >= cfg.max_active_duration_secs
+    {
+        return Err(ConfigError::ValidationFailed(
+            "min_active_secs must be < max_active_duration_secs",
+        ));
+    }
+    for (i, &max_secs) in cfg.state_max_secs.iter().enumerate() {
+        if max_secs == 0 {
+            continue;
+        }
+        if StateId::from_index(i).timeout_escape().is_none() {
+            return Err(ConfigError::ValidationFailed(
+                "state_max_secs must be 0 for Idle and Error",
+            ));
+        }
+        if max_secs > 86_400 {
+            return Err(ConfigError::ValidationFailed(
+                "state_max_secs must be 0–86400",
+            ));
+        }
+    }
+    // A Sensing watchdog inside the confirmation window would never let
+    // a scrub start on a confirmed reading.
+    let sensing_max = cfg.state_max_secs[StateId::Sensing as usize];
+    if sensing_max != 0 && sensing_max <= u32::from(cfg.nh3_confirm_duration_secs) {
+        return Err(ConfigError::ValidationFailed(
+            "Sensing state_max_secs must be > nh3_confirm_duration_secs",
+        ));
+    }
+    if cfg.fault_recovery_cooldown_secs > 600 {
+        return Err(ConfigError::ValidationFailed(
+            "fault_recovery_cooldown_secs must be 0–600",
+        ));
+    }
+    if cfg.interlock_grace_secs > 30 {
+        return Err(ConfigError::ValidationFailed(
+            "interlock_grace_secs must be 0–30",
+        ));
+    }
+    // Below a few seconds an Eco-profile control tick alone looks stale.
+    if cfg.sensor_stale_after_secs != 0 && !(5..=600).contains(&cfg.sensor_stale_after_secs) {
+        return Err(ConfigError::ValidationFailed(
+            "sensor_stale_after_secs must be 0 or 5–600",
+        ));
+    }
     if !(100..=5000).contains(&cfg.control_loop_interval_ms) {
         return Err(ConfigError::ValidationFailed(
             "control_loop_interval_ms must be 100–5000",
@@ -151,19 +607,167 @@ fn validate_config(cfg: &SystemConfig) -> Result<(), ConfigError> {
             "telemetry_interval_secs must be 5–3600",
         ));
     }
+    if cfg.ble_status_interval_secs > 600 {
+        return Err(ConfigError::ValidationFailed(
+            "ble_status_interval_secs must be 0–600",
+        ));
+    }
+    if cfg.max_control_dt_ms != 0 && !(100..=60_000).contains(&cfg.max_control_dt_ms) {
+        return Err(ConfigError::ValidationFailed(
+            "max_control_dt_ms must be 0 or 100–60000",
+        ));
+    }
+    if cfg.flow_filter_tau_ms > 10_000 {
+        return Err(ConfigError::ValidationFailed(
+            "flow_filter_tau_ms must be 0–10000",
+        ));
+    }
+    if cfg.psk_grace_secs > 7 * 24 * 3600 {
+        return Err(ConfigError::ValidationFailed(
+            "psk_grace_secs must be at most 7 days",
+        ));
+    }
+    if !(-100..=-30).contains(&cfg.wifi_roam_rssi_dbm) {
+        return Err(ConfigError::ValidationFailed(
+            "wifi_roam_rssi_dbm must be -100 to -30",
+        ));
+    }
+    if cfg.wifi_roam_samples == 0 {
+        return Err(ConfigError::ValidationFailed(
+            "wifi_roam_samples must be 1–255",
+        ));
+    }
+    if cfg.tcp_idle_timeout_secs != 0 && !(10..=3600).contains(&cfg.tcp_idle_timeout_secs) {
+        return Err(ConfigError::ValidationFailed(
+            "tcp_idle_timeout_secs must be 0 or 10–3600",
+        ));
+    }
+    if let Some(q) = cfg.quiet_hours {
+        if q.start_hour > 23 || q.end_hour > 23 {
+            return Err(ConfigError::ValidationFailed("quiet hours must be 0–23"));
+        }
+        if q.start_hour == q.end_hour {
+            return Err(ConfigError::ValidationFailed(
+                "quiet hours start and end must differ",
+            ));
+        }
+    }
+    if cfg.stop_lockout_secs > 60 {
+        return Err(ConfigError::ValidationFailed(
+            "stop_lockout_secs must be 0–60",
+        ));
+    }
+    let order = &cfg.led_overlay_priority;
+    if order
+        .iter()
+        .enumerate()
+        .any(|(i, o)| order[..i].contains(o))
+    {
+        return Err(ConfigError::ValidationFailed(
+            "led_overlay_priority must list each overlay once",
+        ));
+    }
     Ok(())
 }
 
-impl ConfigPort for NvsAdapter {
-    fn load(&self) -> Result<SystemConfig, ConfigError> {
+impl NvsAdapter {
+    /// Decode a stored config blob, migrating and re-saving it if it was
+    /// written by an older schema version.
+    fn decode_config(&self, key: &str, bytes: &[u8]) -> Result<SystemConfig, ConfigError> {
+        let (cfg, version) = config::decode_stored(bytes).ok_or(ConfigError::Corrupted)?;
+        if version < config::CONFIG_SCHEMA_VERSION {
+            info!(
+                "NvsAdapter: migrating config schema v{} -> v{}",
+                version,
+                config::CONFIG_SCHEMA_VERSION
+            );
+            if let Err(e) = self.save_config(key, &cfg) {
+                warn!("NvsAdapter: re-save after migration failed: {:?}", e);
+            }
+        }
+        Ok(cfg)
+    }
+
+    /// Record the outcome of this boot's config load and return whether
+    /// the adapter is now in config safe mode.
+    ///
+    /// A failure to read or update the counter is logged and treated as
+    /// a count of zero: safe mode needs the failures to be on record.
+    pub fn record_config_load(&mut self, loaded: bool) -> bool {
+        let mut buf = [0u8; 1];
+        let previous = match self.read(CONFIG_NAMESPACE, CONFIG_FAILURES_KEY, &mut buf) {
+            Ok(1) => buf[0],
+            Ok(_) | Err(StorageError::NotFound) => 0,
+            Err(e) => {
+                warn!("NvsAdapter: config failure counter unreadable: {:?}", e);
+                0
+            }
+        };
+        let failures = next_config_failures(previous, loaded);
+        if failures != previous {
+            if let Err(e) = self.write(CONFIG_NAMESPACE, CONFIG_FAILURES_KEY, &[failures]) {
+                warn!("NvsAdapter: config failure counter not saved: {:?}", e);
+            }
+        }
+        let safe_mode = config_safe_mode(failures);
+        if safe_mode {
+            warn!(
+                "NvsAdapter: config failed to load on {} consecutive boots, entering safe mode",
+                failures
+            );
+        }
+        self.config_safe_mode.set(safe_mode);
+        safe_mode
+    }
+
+    /// Whether config saves are being dropped.
+    pub fn in_config_safe_mode(&self) -> bool {
+        self.config_safe_mode.get()
+    }
+
+    /// Remove the stored configs and the failure counter, so the next
+    /// boot starts from defaults and leaves safe mode.
+    pub fn discard_config(&mut self) -> Result<(), StorageError> {
+        for zone in 0..MAX_ZONES as ZoneId {
+            self.delete(CONFIG_NAMESPACE, &zone_key(CONFIG_KEY, zone))?;
+        }
+        self.delete(CONFIG_NAMESPACE, CONFIG_FAILURES_KEY)
+    }
+}
+
+/// Consecutive-failure count after a boot whose config load succeeded
+/// (`loaded`) or failed.
+pub fn next_config_failures(previous: u8, loaded: bool) -> u8 {
+    if loaded {
+        0
+    } else {
+        previous.saturating_add(1)
+    }
+}
+
+/// Whether `failures` consecutive failed loads warrant safe mode.
+pub fn config_safe_mode(failures: u8) -> bool {
+    failures > CONFIG_SAFE_MODE_AFTER
+}
+
+impl NvsAdapter {
+    /// Config store of filter loop `zone`.  Zone 0's is the adapter's
+    /// own [`ConfigPort`]; the others sit under their own keys.
+    pub fn zone_config(&self, zone: ZoneId) -> ZoneConfigStore<'_> {
+        ZoneConfigStore {
+            nvs: self,
+            key: zone_key(CONFIG_KEY, zone),
+        }
+    }
+
+    fn load_config(&self, key: &str) -> Result<SystemConfig, ConfigError> {
         #[cfg(not(target_os = "espidf"))]
         {
-            let key = Self::composite_key(CONFIG_NAMESPACE, CONFIG_KEY);
-            if let Some(bytes) = self.store.borrow().get(&key) {
-                let cfg: SystemConfig =
-                    postcard::from_bytes(bytes).map_err(|_| ConfigError::Corrupted)?;
+            let composite = Self::composite_key(CONFIG_NAMESPACE, key);
+            let stored = self.store.borrow().get(&composite).cloned();
+            if let Some(bytes) = stored {
                 info!("NvsAdapter: loaded config from store");
-                Ok(cfg)
+                self.decode_config(key, &bytes)
             } else {
                 info!("NvsAdapter: no stored config, using defaults");
                 Ok(SystemConfig::default())
@@ -173,7 +777,9 @@ impl ConfigPort for NvsAdapter {
         #[cfg(target_os = "espidf")]
         {
             let result = Self::with_nvs_handle(CONFIG_NAMESPACE, false, |handle| {
-                let key_cstr = b"syscfg\0";
+                let mut key_cstr = [0u8; 16];
+                let kl = key.len().min(15);
+                key_cstr[..kl].copy_from_slice(&key.as_bytes()[..kl]);
                 let mut size: usize = 0;
 
                 // First call: get size
@@ -210,10 +816,8 @@ impl ConfigPort for NvsAdapter {
 
             match result {
                 Ok(bytes) => {
-                    let cfg: SystemConfig =
-                        postcard::from_bytes(&bytes).map_err(|_| ConfigError::Corrupted)?;
                     info!("NvsAdapter: loaded config from NVS ({} bytes)", bytes.len());
-                    Ok(cfg)
+                    self.decode_config(key, &bytes)
                 }
                 Err(e) if e == ESP_ERR_NVS_NOT_FOUND => {
                     info!("NvsAdapter: no stored config, using defaults");
@@ -227,56 +831,56 @@ impl ConfigPort for NvsAdapter {
         }
     }
 
-    fn save(&self, config: &SystemConfig) -> Result<(), ConfigError> {
+    fn save_config(&self, key: &str, config: &SystemConfig) -> Result<(), ConfigError> {
         validate_config(config)?;
-
-        #[cfg(not(target_os = "espidf"))]
-        {
-            let key = Self::composite_key(CONFIG_NAMESPACE, CONFIG_KEY);
-            let bytes = postcard::to_allocvec(config).map_err(|_| ConfigError::IoError)?;
-            self.store.borrow_mut().insert(key, bytes);
-            info!("NvsAdapter: config saved (simulation)");
-            Ok(())
+        if self.config_safe_mode.get() {
+            warn!("NvsAdapter: safe mode, config not persisted");
+            return Err(ConfigError::SafeMode);
         }
 
-        #[cfg(target_os = "espidf")]
-        {
-            let bytes = postcard::to_allocvec(config).map_err(|_| ConfigError::IoError)?;
-            let result = Self::with_nvs_handle(CONFIG_NAMESPACE, true, |handle| {
-                let key_cstr = b"syscfg\0";
-                let ret = unsafe {
-                    nvs_set_blob(
-                        handle,
-                        key_cstr.as_ptr() as *const _,
-                        bytes.as_ptr() as *const _,
-                        bytes.len(),
-                    )
-                };
-                if ret != ESP_OK {
-                    return Err(ret);
-                }
-                let ret = unsafe { nvs_commit(handle) };
-                if ret != ESP_OK {
-                    return Err(ret);
-                }
+        let bytes = config::encode_stored(config).map_err(|_| ConfigError::IoError)?;
+        match self.write_with_eviction(CONFIG_NAMESPACE, key, &bytes) {
+            Ok(()) => {
+                info!("NvsAdapter: config saved ({} bytes)", bytes.len());
                 Ok(())
-            });
-            match result {
-                Ok(()) => {
-                    info!("NvsAdapter: config saved to NVS ({} bytes)", bytes.len());
-                    Ok(())
-                }
-                Err(e) => {
-                    warn!("NvsAdapter: NVS write error {}", e);
-                    Err(ConfigError::IoError)
-                }
+            }
+            Err(StorageError::Full) => Err(ConfigError::StorageFull),
+            Err(e) => {
+                warn!("NvsAdapter: config write error {:?}", e);
+                Err(ConfigError::IoError)
             }
         }
     }
 }
 
-impl StoragePort for NvsAdapter {
-    fn read(&self, namespace: &str, key: &str, buf: &mut [u8]) -> Result<usize, StorageError> {
+impl ConfigPort for NvsAdapter {
+    fn load(&self) -> Result<SystemConfig, ConfigError> {
+        self.load_config(CONFIG_KEY)
+    }
+
+    fn save(&self, config: &SystemConfig) -> Result<(), ConfigError> {
+        self.save_config(CONFIG_KEY, config)
+    }
+}
+
+/// The config of one filter loop of a multi-zone unit.
+pub struct ZoneConfigStore<'a> {
+    nvs: &'a NvsAdapter,
+    key: heapless::String<15>,
+}
+
+impl ConfigPort for ZoneConfigStore<'_> {
+    fn load(&self) -> Result<SystemConfig, ConfigError> {
+        self.nvs.load_config(&self.key)
+    }
+
+    fn save(&self, config: &SystemConfig) -> Result<(), ConfigError> {
+        self.nvs.save_config(&self.key, config)
+    }
+}
+
+impl StoragePort for NvsAdapter {
+    fn read(&self, namespace: &str, key: &str, buf: &mut [u8]) -> Result<usize, StorageError> {
         #[cfg(not(target_os = "espidf"))]
         {
             let composite = Self::composite_key(namespace, key);
@@ -324,73 +928,15 @@ impl StoragePort for NvsAdapter {
     }
 
     fn write(&mut self, namespace: &str, key: &str, data: &[u8]) -> Result<(), StorageError> {
-        #[cfg(not(target_os = "espidf"))]
-        {
-            if data.len() > MAX_BLOB_SIZE {
-                return Err(StorageError::IoError);
-            }
-            let composite = Self::composite_key(namespace, key);
-            self.store.borrow_mut().insert(composite, data.to_vec());
-            Ok(())
-        }
-
-        #[cfg(target_os = "espidf")]
-        {
-            let result = Self::with_nvs_handle(namespace, true, |handle| {
-                let mut key_buf = [0u8; 16];
-                let kb = key.as_bytes();
-                let kl = kb.len().min(15);
-                key_buf[..kl].copy_from_slice(&kb[..kl]);
-
-                let ret = unsafe {
-                    nvs_set_blob(
-                        handle,
-                        key_buf.as_ptr() as *const _,
-                        data.as_ptr() as *const _,
-                        data.len(),
-                    )
-                };
-                if ret != ESP_OK {
-                    return Err(ret);
-                }
-                let ret = unsafe { nvs_commit(handle) };
-                if ret != ESP_OK {
-                    return Err(ret);
-                }
-                Ok(())
-            });
-            result.map_err(|_| StorageError::IoError)
-        }
+        self.write_with_eviction(namespace, key, data)
     }
 
     fn delete(&mut self, namespace: &str, key: &str) -> Result<(), StorageError> {
-        #[cfg(not(target_os = "espidf"))]
-        {
-            let composite = Self::composite_key(namespace, key);
-            self.store.borrow_mut().remove(&composite);
-            Ok(())
-        }
-
-        #[cfg(target_os = "espidf")]
-        {
-            let result = Self::with_nvs_handle(namespace, true, |handle| {
-                let mut key_buf = [0u8; 16];
-                let kb = key.as_bytes();
-                let kl = kb.len().min(15);
-                key_buf[..kl].copy_from_slice(&kb[..kl]);
-
-                let ret = unsafe { nvs_erase_key(handle, key_buf.as_ptr() as *const _) };
-                if ret != ESP_OK && ret != ESP_ERR_NVS_NOT_FOUND {
-                    return Err(ret);
-                }
-                let ret = unsafe { nvs_commit(handle) };
-                if ret != ESP_OK {
-                    return Err(ret);
-                }
-                Ok(())
-            });
-            result.map_err(|_| StorageError::IoError)
-        }
+        self.platform_delete(namespace, key)?;
+        self.last_write
+            .borrow_mut()
+            .remove(&Self::composite_key(namespace, key));
+        Ok(())
     }
 
     fn exists(&self, namespace: &str, key: &str) -> bool {
@@ -429,6 +975,12 @@ impl StoragePort for NvsAdapter {
 //
 // For development: encryption is transparent — the same read/write/delete
 // API works whether encryption is on or off.
+//
+// Each blob is stored as `data ‖ HMAC-SHA256(k, key ‖ 0 ‖ data)`, where
+// `k` is derived from the factory MAC.  Binding the key name stops a
+// valid blob from being replayed under another credential; the tag
+// turns a flipped bit on an unencrypted dev partition into
+// `StorageError::IntegrityFailure` rather than a silently wrong secret.
 impl NvsAdapter {
     /// Store a credential blob in the encrypted "auth" namespace.
     pub fn store_credential(
@@ -436,16 +988,66 @@ impl NvsAdapter {
         key: &str,
         data: &[u8],
     ) -> Result<(), crate::app::ports::StorageError> {
-        self.write(CRED_NAMESPACE, key, data)
+        let mut blob = Vec::with_capacity(data.len() + CRED_TAG_LEN);
+        blob.extend_from_slice(data);
+        blob.extend_from_slice(&credential_mac(key, data).finalize());
+        let result = self.write(CRED_NAMESPACE, key, &blob);
+        blob.fill(0);
+        result
     }
 
     /// Read a credential blob from the encrypted "auth" namespace.
+    ///
+    /// Returns [`StorageError::IntegrityFailure`] if the stored tag does
+    /// not match, including blobs written before tagging was introduced
+    /// that [`migrate_untagged_credentials`](Self::migrate_untagged_credentials)
+    /// has not yet re-stored.
     pub fn read_credential(
         &self,
         key: &str,
         buf: &mut [u8],
     ) -> Result<usize, crate::app::ports::StorageError> {
-        self.read(CRED_NAMESPACE, key, buf)
+        let mut blob = vec![0u8; buf.len() + CRED_TAG_LEN];
+        let result = self
+            .read(CRED_NAMESPACE, key, &mut blob)
+            .and_then(|n| verify_credential(key, &blob[..n]))
+            .map(|data| {
+                buf[..data.len()].copy_from_slice(data);
+                data.len()
+            });
+        blob.fill(0);
+        result
+    }
+
+    /// Re-store credentials written before the integrity tag existed,
+    /// so an OTA from such firmware keeps its WiFi and PSK.  Runs once:
+    /// afterwards a marker is set and untagged blobs fail as corrupt.
+    /// Call at boot before the first [`read_credential`](Self::read_credential).
+    /// Returns how many credentials were re-stored.
+    pub fn migrate_untagged_credentials(&mut self) -> usize {
+        if self.exists(CRED_NAMESPACE, CRED_TAGGED_MARKER) {
+            return 0;
+        }
+        let mut migrated = 0;
+        let mut raw = [0u8; LEGACY_CREDENTIAL_MAX + CRED_TAG_LEN];
+        for key in LEGACY_CREDENTIAL_KEYS {
+            let Ok(n) = self.read(CRED_NAMESPACE, key, &mut raw) else {
+                continue;
+            };
+            if n > LEGACY_CREDENTIAL_MAX || verify_credential_quiet(key, &raw[..n]) {
+                continue;
+            }
+            let legacy = raw[..n].to_vec();
+            match self.store_credential(key, &legacy) {
+                Ok(()) => migrated += 1,
+                Err(e) => warn!("NVS: could not re-store credential '{}': {:?}", key, e),
+            }
+        }
+        raw.fill(0);
+        if let Err(e) = self.write(CRED_NAMESPACE, CRED_TAGGED_MARKER, &[1]) {
+            warn!("NVS: credential migration marker not saved: {:?}", e);
+        }
+        migrated
     }
 
     /// Delete a credential from the encrypted "auth" namespace.
@@ -483,21 +1085,67 @@ impl NvsAdapter {
             .retain(|k: &String, _: &mut Vec<u8>| !k.starts_with(&prefix));
         Ok(())
     }
+
+    /// Factory reset, shared by the button and the RPC: erase every
+    /// credential and, in config safe mode, the stored config that keeps
+    /// failing to load.  Best effort; the caller restarts afterwards.
+    pub fn factory_reset(&mut self) {
+        if let Err(e) = self.erase_credentials() {
+            warn!("Factory reset: credential erase failed: {:?}", e);
+        }
+        let _ = self.delete(CRED_NAMESPACE, "wifi_ssid");
+        let _ = self.delete(CRED_NAMESPACE, "wifi_pass");
+        if self.in_config_safe_mode() {
+            let _ = self.discard_config();
+        }
+    }
+}
+
+/// HMAC over a credential's key name and payload, keyed per device.
+fn credential_mac(key: &str, data: &[u8]) -> hmac_sha256::HMAC {
+    let mac_key = hmac_sha256::HKDF::extract(CRED_KEY_SALT, crate::adapters::device_id::read_mac());
+    let mut mac = hmac_sha256::HMAC::new(mac_key);
+    mac.update(key.as_bytes());
+    mac.update([0]);
+    mac.update(data);
+    mac
+}
+
+/// Split a stored credential blob into payload and tag and check the tag
+/// in constant time.
+fn verify_credential<'a>(key: &str, blob: &'a [u8]) -> Result<&'a [u8], StorageError> {
+    if verify_credential_quiet(key, blob) {
+        Ok(&blob[..blob.len() - CRED_TAG_LEN])
+    } else {
+        warn!("NVS: credential '{}' failed integrity check", key);
+        Err(StorageError::IntegrityFailure)
+    }
+}
+
+/// Whether `blob` ends in a valid tag for `key`, without logging.
+fn verify_credential_quiet(key: &str, blob: &[u8]) -> bool {
+    let Some(split) = blob.len().checked_sub(CRED_TAG_LEN) else {
+        return false;
+    };
+    let (data, tag) = blob.split_at(split);
+    let Ok(tag) = <&[u8; CRED_TAG_LEN]>::try_from(tag) else {
+        return false;
+    };
+    credential_mac(key, data).finalize_verify(tag)
 }
 
 impl Default for NvsAdapter {
     fn default() -> Self {
         // Panics if NVS is unavailable — only use as a last-resort fallback.
-        Self::new().unwrap_or_else(|_| Self {
-            #[cfg(not(target_os = "espidf"))]
-            store: std::cell::RefCell::new(HashMap::new()),
-        })
+        Self::new().unwrap_or_else(|_| Self::empty())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::PurgeMode;
+    use crate::scheduler::QuietHours;
 
     #[test]
     fn default_config_passes_validation() {
@@ -530,6 +1178,419 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn rejects_urgent_not_above_activate() {
+        let cfg = SystemConfig {
+            nh3_activate_threshold_ppm: 20.0,
+            nh3_urgent_threshold_ppm: 20.0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_config(&cfg),
+            Err(ConfigError::ValidationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_quiet_hours() {
+        let bad_hour = SystemConfig {
+            quiet_hours: Some(QuietHours {
+                start_hour: 24,
+                end_hour: 6,
+            }),
+            ..Default::default()
+        };
+        assert!(validate_config(&bad_hour).is_err());
+
+        let empty_window = SystemConfig {
+            quiet_hours: Some(QuietHours {
+                start_hour: 7,
+                end_hour: 7,
+            }),
+            ..Default::default()
+        };
+        assert!(validate_config(&empty_window).is_err());
+    }
+
+    #[test]
+    fn active_duration_limit_is_zero_or_sane() {
+        let unlimited = SystemConfig {
+            max_active_duration_secs: 0,
+            ..Default::default()
+        };
+        assert!(validate_config(&unlimited).is_ok());
+
+        let too_short = SystemConfig {
+            max_active_duration_secs: 60,
+            ..Default::default()
+        };
+        assert!(validate_config(&too_short).is_err());
+    }
+
+    #[test]
+    fn led_overlay_priority_must_be_a_permutation() {
+        use crate::drivers::led_patterns::LedOverlay;
+
+        let reordered = SystemConfig {
+            led_overlay_priority: [
+                LedOverlay::LowWater,
+                LedOverlay::Ota,
+                LedOverlay::Ble,
+                LedOverlay::Wifi,
+            ],
+            ..Default::default()
+        };
+        assert!(validate_config(&reordered).is_ok());
+
+        let repeated = SystemConfig {
+            led_overlay_priority: [
+                LedOverlay::LowWater,
+                LedOverlay::LowWater,
+                LedOverlay::Wifi,
+                LedOverlay::Ble,
+            ],
+            ..Default::default()
+        };
+        assert!(validate_config(&repeated).is_err());
+    }
+
+    #[test]
+    fn min_active_runtime_is_bounded_below_the_limit() {
+        for (min, max, ok) in [
+            (0, 3600, true),
+            (600, 3600, true),
+            (1800, 0, true),
+            (1801, 0, false),
+            (600, 600, false),
+        ] {
+            let cfg = SystemConfig {
+                min_active_secs: min,
+                max_active_duration_secs: max,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "min={min} max={max}");
+        }
+    }
+
+    #[test]
+    fn state_watchdogs_only_apply_to_states_with_an_escape() {
+        let mut cfg = SystemConfig::default();
+        cfg.state_max_secs[StateId::Sensing as usize] = 120;
+        cfg.state_max_secs[StateId::Active as usize] = 7200;
+        cfg.state_max_secs[StateId::Purging as usize] = 900;
+        cfg.state_max_secs[StateId::Draining as usize] = 600;
+        assert!(validate_config(&cfg).is_ok());
+
+        for state in [StateId::Idle, StateId::Error] {
+            let mut bad = cfg.clone();
+            bad.state_max_secs[state as usize] = 60;
+            assert!(validate_config(&bad).is_err(), "{state:?}");
+        }
+
+        let mut too_long = cfg.clone();
+        too_long.state_max_secs[StateId::Active as usize] = 86_401;
+        assert!(validate_config(&too_long).is_err());
+    }
+
+    #[test]
+    fn sensing_watchdog_must_outlast_confirmation() {
+        let mut cfg = SystemConfig::default();
+        cfg.state_max_secs[StateId::Sensing as usize] = u32::from(cfg.nh3_confirm_duration_secs);
+        assert!(validate_config(&cfg).is_err());
+        cfg.state_max_secs[StateId::Sensing as usize] += 1;
+        assert!(validate_config(&cfg).is_ok());
+    }
+
+    #[test]
+    fn purge_volume_settings_are_bounded() {
+        let volume = SystemConfig {
+            purge_mode: PurgeMode::Volume,
+            purge_target_volume_ml: 2000,
+            purge_max_duration_secs: 600,
+            ..Default::default()
+        };
+        assert!(validate_config(&volume).is_ok());
+
+        let no_volume = SystemConfig {
+            purge_target_volume_ml: 0,
+            ..volume.clone()
+        };
+        assert!(validate_config(&no_volume).is_err());
+
+        let no_cap = SystemConfig {
+            purge_max_duration_secs: 0,
+            ..volume
+        };
+        assert!(validate_config(&no_cap).is_err());
+    }
+
+    #[test]
+    fn nh3_oversample_count_is_bounded() {
+        for (count, ok) in [(0, false), (1, true), (10, true), (11, false)] {
+            let cfg = SystemConfig {
+                nh3_oversample_count: count,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "count={count}");
+        }
+    }
+
+    #[test]
+    fn nh3_adc_samples_is_bounded() {
+        for (samples, ok) in [(0, false), (1, true), (9, true), (10, false)] {
+            let cfg = SystemConfig {
+                nh3_adc_samples: samples,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "samples={samples}");
+        }
+    }
+
+    #[test]
+    fn fault_recovery_cooldown_is_bounded() {
+        for (secs, ok) in [(0, true), (600, true), (601, false)] {
+            let cfg = SystemConfig {
+                fault_recovery_cooldown_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn nh3_rate_threshold_is_zero_or_in_range() {
+        for (rate, ok) in [
+            (0.0, true),
+            (0.05, false),
+            (0.1, true),
+            (100.0, true),
+            (100.5, false),
+            (-1.0, false),
+            (f32::NAN, false),
+        ] {
+            let cfg = SystemConfig {
+                nh3_rate_threshold_ppm_per_sec: rate,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "rate={rate}");
+        }
+    }
+
+    #[test]
+    fn nh3_warmup_is_bounded() {
+        for (secs, ok) in [(0, true), (120, true), (900, true), (901, false)] {
+            let cfg = SystemConfig {
+                nh3_warmup_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn nh3_alarm_band_is_off_or_ordered() {
+        for (high, clear, ok) in [
+            (0.0, 0.0, true),
+            (0.0, 40.0, true),
+            (25.0, 20.0, true),
+            (25.0, 25.0, false),
+            (25.0, 30.0, false),
+            (25.0, 0.0, false),
+            (0.5, 0.4, false),
+            (500.0, 499.0, true),
+            (501.0, 20.0, false),
+            (f32::NAN, 20.0, false),
+        ] {
+            let cfg = SystemConfig {
+                nh3_alarm_high_ppm: high,
+                nh3_alarm_clear_ppm: clear,
+                ..Default::default()
+            };
+            assert_eq!(
+                validate_config(&cfg).is_ok(),
+                ok,
+                "high={high} clear={clear}"
+            );
+        }
+    }
+
+    #[test]
+    fn over_temp_debounce_is_bounded() {
+        for (samples, ok) in [(0, false), (1, true), (10, true), (11, false)] {
+            let cfg = SystemConfig {
+                over_temp_debounce_samples: samples,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "samples={samples}");
+        }
+    }
+
+    #[test]
+    fn pump_derate_band_sits_below_the_temperature_limit() {
+        for (start, min_duty, ok) in [
+            (0.0, 30, true),
+            (60.0, 30, true),
+            (79.5, 10, true),
+            (19.5, 30, false),
+            (80.0, 30, false),
+            (f32::NAN, 30, false),
+            (60.0, 9, false),
+            (60.0, 100, true),
+            (60.0, 101, false),
+        ] {
+            let cfg = SystemConfig {
+                max_temperature_c: 80.0,
+                pump_derate_start_c: start,
+                pump_derate_min_duty_percent: min_duty,
+                ..Default::default()
+            };
+            assert_eq!(
+                validate_config(&cfg).is_ok(),
+                ok,
+                "start={start} min_duty={min_duty}"
+            );
+        }
+    }
+
+    #[test]
+    fn soft_stop_ticks_is_bounded() {
+        for (ticks, ok) in [(0, true), (10, true), (11, false)] {
+            let cfg = SystemConfig {
+                soft_stop_ticks: ticks,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "ticks={ticks}");
+        }
+    }
+
+    #[test]
+    fn flow_glitch_filter_is_bounded() {
+        for (us, ok) in [(0, true), (4000, true), (4001, false)] {
+            let cfg = SystemConfig {
+                flow_glitch_filter_us: us,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "us={us}");
+        }
+    }
+
+    #[test]
+    fn staleness_threshold_is_zero_or_bounded() {
+        for (secs, ok) in [(0, true), (4, false), (5, true), (600, true), (601, false)] {
+            let cfg = SystemConfig {
+                sensor_stale_after_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn pump_pid_gains_are_bounded() {
+        for (kd, ok) in [
+            (0.0, true),
+            (1000.0, true),
+            (-0.1, false),
+            (f32::NAN, false),
+        ] {
+            let cfg = SystemConfig {
+                pump_pid_kd: kd,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "kd={kd}");
+        }
+    }
+
+    #[test]
+    fn ble_status_interval_is_bounded() {
+        for (secs, ok) in [(0, true), (1, true), (600, true), (601, false)] {
+            let cfg = SystemConfig {
+                ble_status_interval_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn jitter_compensation_settings_are_bounded() {
+        for (ms, ok) in [(0, true), (99, false), (100, true), (60_001, false)] {
+            let cfg = SystemConfig {
+                max_control_dt_ms: ms,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "max_dt={ms}");
+        }
+        for (ms, ok) in [(0, true), (10_000, true), (10_001, false)] {
+            let cfg = SystemConfig {
+                flow_filter_tau_ms: ms,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "tau={ms}");
+        }
+    }
+
+    #[test]
+    fn psk_grace_is_at_most_a_week() {
+        for (secs, ok) in [(0, true), (604_800, true), (604_801, false)] {
+            let cfg = SystemConfig {
+                psk_grace_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn wifi_roam_trigger_is_bounded() {
+        for (rssi, samples, ok) in [
+            (-100, 1, true),
+            (-30, 255, true),
+            (-101, 8, false),
+            (-29, 8, false),
+            (-75, 0, false),
+        ] {
+            let cfg = SystemConfig {
+                wifi_roam_rssi_dbm: rssi,
+                wifi_roam_samples: samples,
+                ..Default::default()
+            };
+            assert_eq!(
+                validate_config(&cfg).is_ok(),
+                ok,
+                "rssi={rssi} samples={samples}"
+            );
+        }
+    }
+
+    #[test]
+    fn tcp_idle_timeout_is_off_or_bounded() {
+        for (secs, ok) in [
+            (0, true),
+            (9, false),
+            (10, true),
+            (3600, true),
+            (3601, false),
+        ] {
+            let cfg = SystemConfig {
+                tcp_idle_timeout_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
+    #[test]
+    fn interlock_grace_is_bounded() {
+        for (secs, ok) in [(0, true), (30, true), (31, false)] {
+            let cfg = SystemConfig {
+                interlock_grace_secs: secs,
+                ..Default::default()
+            };
+            assert_eq!(validate_config(&cfg).is_ok(), ok, "secs={secs}");
+        }
+    }
+
     #[test]
     fn rejects_duty_over_100() {
         let cfg = SystemConfig {
@@ -542,6 +1603,155 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn legacy_config_is_migrated_and_resaved() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        // v1 layout: the unprefixed field sequence of the original struct.
+        let v1 = postcard::to_allocvec(&(
+            900u16, 60u8, 15.0f32, 6.0f32, 20u16, 80u8, 70.0f32, 90u16, 25u8, 100u32, 1000u32,
+            60u32,
+        ))
+        .unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &v1).unwrap();
+
+        let cfg = nvs.load().unwrap();
+        assert_eq!(cfg.pump_flow_ml_per_min, 900);
+        assert_eq!(cfg.pump_duty_percent, 60);
+        assert_eq!(cfg.purge_duration_secs, 90);
+        assert_eq!(cfg.led_brightness, SystemConfig::default().led_brightness);
+
+        let mut buf = [0u8; 256];
+        let n = nvs.read(CONFIG_NAMESPACE, CONFIG_KEY, &mut buf).unwrap();
+        assert_eq!(&buf[..2], &config::CONFIG_SCHEMA_VERSION.to_le_bytes());
+        let (resaved, version) = config::decode_stored(&buf[..n]).unwrap();
+        assert_eq!(version, config::CONFIG_SCHEMA_VERSION);
+        assert_eq!(resaved.pump_flow_ml_per_min, 900);
+    }
+
+    #[test]
+    fn each_zone_keeps_its_own_config() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        let zone_b = SystemConfig {
+            pump_duty_percent: 55,
+            ..Default::default()
+        };
+        nvs.zone_config(1).save(&zone_b).unwrap();
+        assert!(!nvs.exists(CONFIG_NAMESPACE, CONFIG_KEY));
+        assert_eq!(nvs.zone_config(1).load().unwrap().pump_duty_percent, 55);
+
+        let duty = SystemConfig::default().pump_duty_percent;
+        nvs.save(&SystemConfig::default()).unwrap();
+        assert_eq!(nvs.zone_config(0).load().unwrap().pump_duty_percent, duty);
+        assert_eq!(nvs.zone_config(1).load().unwrap().pump_duty_percent, 55);
+
+        nvs.discard_config().unwrap();
+        assert_eq!(nvs.zone_config(1).load().unwrap().pump_duty_percent, duty);
+    }
+
+    #[test]
+    fn corrupted_config_blob_is_reported() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        assert!(matches!(nvs.load(), Err(ConfigError::Corrupted)));
+    }
+
+    #[test]
+    fn failure_count_resets_on_success_and_saturates() {
+        assert_eq!(next_config_failures(0, false), 1);
+        assert_eq!(next_config_failures(2, false), 3);
+        assert_eq!(next_config_failures(7, true), 0);
+        assert_eq!(next_config_failures(u8::MAX, false), u8::MAX);
+    }
+
+    #[test]
+    fn safe_mode_only_past_the_threshold() {
+        assert!(!config_safe_mode(0));
+        assert!(!config_safe_mode(CONFIG_SAFE_MODE_AFTER));
+        assert!(config_safe_mode(CONFIG_SAFE_MODE_AFTER + 1));
+    }
+
+    /// Boot `n` times against a corrupt blob; returns the last verdict.
+    fn boot_with_corrupt_config(nvs: &mut NvsAdapter, n: u8) -> bool {
+        let mut safe_mode = false;
+        for _ in 0..n {
+            let loaded = nvs.load().is_ok();
+            safe_mode = nvs.record_config_load(loaded);
+        }
+        safe_mode
+    }
+
+    #[test]
+    fn repeated_corrupt_loads_enter_safe_mode() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+
+        assert!(!boot_with_corrupt_config(&mut nvs, CONFIG_SAFE_MODE_AFTER));
+        assert!(!nvs.in_config_safe_mode());
+        assert!(boot_with_corrupt_config(&mut nvs, 1));
+        assert!(nvs.in_config_safe_mode());
+    }
+
+    #[test]
+    fn safe_mode_stops_persisting_config() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        assert!(boot_with_corrupt_config(
+            &mut nvs,
+            CONFIG_SAFE_MODE_AFTER + 1
+        ));
+
+        assert!(matches!(
+            nvs.save(&SystemConfig::default()),
+            Err(ConfigError::SafeMode)
+        ));
+        let mut buf = [0u8; 8];
+        let n = nvs.read(CONFIG_NAMESPACE, CONFIG_KEY, &mut buf).unwrap();
+        assert_eq!(&buf[..n], &[0xFF; 4]);
+    }
+
+    #[test]
+    fn successful_load_resets_the_counter() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        boot_with_corrupt_config(&mut nvs, CONFIG_SAFE_MODE_AFTER);
+
+        nvs.delete(CONFIG_NAMESPACE, CONFIG_KEY).unwrap();
+        assert!(!nvs.record_config_load(nvs.load().is_ok()));
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        assert!(!boot_with_corrupt_config(&mut nvs, CONFIG_SAFE_MODE_AFTER));
+    }
+
+    #[test]
+    fn discarding_config_leaves_safe_mode_next_boot() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        assert!(boot_with_corrupt_config(
+            &mut nvs,
+            CONFIG_SAFE_MODE_AFTER + 1
+        ));
+
+        nvs.discard_config().unwrap();
+        assert!(nvs.load().is_ok());
+        assert!(!nvs.record_config_load(true));
+        assert!(!nvs.in_config_safe_mode());
+    }
+
+    #[test]
+    fn factory_reset_clears_credentials_and_safe_mode_config() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.store_credential("psk", &[0x01; 32]).unwrap();
+        nvs.write(CONFIG_NAMESPACE, CONFIG_KEY, &[0xFF; 4]).unwrap();
+        assert!(boot_with_corrupt_config(
+            &mut nvs,
+            CONFIG_SAFE_MODE_AFTER + 1
+        ));
+
+        nvs.factory_reset();
+        assert!(!nvs.credential_exists("psk"));
+        assert!(!nvs.exists(CONFIG_NAMESPACE, CONFIG_KEY));
+        assert!(!nvs.record_config_load(nvs.load().is_ok()));
+    }
+
     #[test]
     fn storage_round_trip() {
         let mut nvs = NvsAdapter::new().unwrap();
@@ -582,6 +1792,76 @@ mod tests {
         assert!(!nvs.credential_exists("psk"));
     }
 
+    #[test]
+    fn corrupted_credential_fails_integrity() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.store_credential("wifi_pass", b"hunter22").unwrap();
+
+        let mut raw = [0u8; 64];
+        let n = nvs.read(CRED_NAMESPACE, "wifi_pass", &mut raw).unwrap();
+        assert_eq!(n, 8 + CRED_TAG_LEN);
+        raw[3] ^= 0x01;
+        nvs.write(CRED_NAMESPACE, "wifi_pass", &raw[..n]).unwrap();
+
+        let mut buf = [0u8; 64];
+        assert!(matches!(
+            nvs.read_credential("wifi_pass", &mut buf),
+            Err(StorageError::IntegrityFailure)
+        ));
+    }
+
+    #[test]
+    fn credential_blob_is_bound_to_its_key() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.store_credential("wifi_ssid", b"home").unwrap();
+        let mut raw = [0u8; 64];
+        let n = nvs.read(CRED_NAMESPACE, "wifi_ssid", &mut raw).unwrap();
+        nvs.write(CRED_NAMESPACE, "wifi_pass", &raw[..n]).unwrap();
+
+        let mut buf = [0u8; 64];
+        assert_eq!(nvs.read_credential("wifi_ssid", &mut buf).unwrap(), 4);
+        assert!(matches!(
+            nvs.read_credential("wifi_pass", &mut buf),
+            Err(StorageError::IntegrityFailure)
+        ));
+    }
+
+    #[test]
+    fn untagged_credential_fails_integrity() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CRED_NAMESPACE, "psk", &[0xAA; 16]).unwrap();
+        let mut buf = [0u8; 64];
+        assert!(matches!(
+            nvs.read_credential("psk", &mut buf),
+            Err(StorageError::IntegrityFailure)
+        ));
+    }
+
+    #[test]
+    fn untagged_credentials_are_migrated_once() {
+        let mut nvs = NvsAdapter::new().unwrap();
+        nvs.write(CRED_NAMESPACE, "wifi_ssid", b"home").unwrap();
+        nvs.write(CRED_NAMESPACE, "wifi_pass", b"hunter22").unwrap();
+        nvs.store_credential("psk", &[0x42; 32]).unwrap();
+
+        assert_eq!(nvs.migrate_untagged_credentials(), 2);
+        let mut buf = [0u8; 64];
+        let n = nvs.read_credential("wifi_ssid", &mut buf).unwrap();
+        assert_eq!(&buf[..n], b"home");
+        let n = nvs.read_credential("wifi_pass", &mut buf).unwrap();
+        assert_eq!(&buf[..n], b"hunter22");
+        let n = nvs.read_credential("psk", &mut buf).unwrap();
+        assert_eq!(&buf[..n], &[0x42; 32]);
+
+        // Later untagged blobs are corruption, not legacy data.
+        nvs.write(CRED_NAMESPACE, "wifi_pass", b"hunter22").unwrap();
+        assert_eq!(nvs.migrate_untagged_credentials(), 0);
+        assert!(matches!(
+            nvs.read_credential("wifi_pass", &mut buf),
+            Err(StorageError::IntegrityFailure)
+        ));
+    }
+
     #[test]
     fn erase_credentials_clears_all() {
         let mut nvs = NvsAdapter::new().unwrap();
@@ -605,4 +1885,125 @@ mod tests {
         let len = nvs.read("ns_b", "key", &mut buf).unwrap();
         assert_eq!(&buf[..len], b"bravo");
     }
+
+    // ── Partition full ───────────────────────────────────────────
+
+    const CRASH_NS: &str = crate::diagnostics::CRASH_KEY_PREFIX;
+    const AUDIT_NS: &str = crate::diagnostics::AUDIT_KEY_PREFIX;
+
+    #[test]
+    fn full_store_evicts_oldest_crash_entry_and_retries() {
+        let mut nvs = NvsAdapter::with_capacity(300);
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[1; 100]).unwrap();
+        nvs.write(CRASH_NS, "e2", &[2; 100]).unwrap();
+
+        nvs.write(CRASH_NS, "e3", &[3; 100]).unwrap();
+
+        assert!(!nvs.exists(CRASH_NS, "e0"));
+        assert!(nvs.exists(CRASH_NS, "e1"));
+        assert!(nvs.exists(CRASH_NS, "e2"));
+        assert!(nvs.exists(CRASH_NS, "e3"));
+    }
+
+    #[test]
+    fn eviction_picks_least_recently_written() {
+        let mut nvs = NvsAdapter::with_capacity(300);
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[1; 100]).unwrap();
+        nvs.write(CRASH_NS, "e2", &[2; 100]).unwrap();
+        // Rewriting e0 makes e1 the oldest entry.
+        nvs.write(CRASH_NS, "e0", &[9; 100]).unwrap();
+
+        nvs.write(CRASH_NS, "e3", &[3; 100]).unwrap();
+
+        assert!(nvs.exists(CRASH_NS, "e0"));
+        assert!(!nvs.exists(CRASH_NS, "e1"));
+    }
+
+    #[test]
+    fn eviction_spans_every_evictable_namespace() {
+        let mut nvs = NvsAdapter::with_capacity(300);
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(AUDIT_NS, "a0", &[1; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[2; 100]).unwrap();
+
+        // An audit write makes room by evicting the oldest crash entry,
+        // and a crash write by evicting the oldest audit entry.
+        nvs.write(AUDIT_NS, "a1", &[3; 100]).unwrap();
+        assert!(!nvs.exists(CRASH_NS, "e0"));
+        nvs.write(CRASH_NS, "e2", &[4; 100]).unwrap();
+        assert!(!nvs.exists(AUDIT_NS, "a0"));
+        assert!(nvs.exists(CRASH_NS, "e1"));
+        assert!(nvs.exists(AUDIT_NS, "a1"));
+    }
+
+    #[test]
+    fn ring_indices_are_never_evicted() {
+        let crash_idx = crate::diagnostics::CRASH_INDEX_KEY;
+        let audit_idx = crate::diagnostics::AUDIT_INDEX_KEY;
+        let mut nvs = NvsAdapter::with_capacity(208);
+        // Written first, so the least recently written entries.
+        nvs.write(CRASH_NS, crash_idx, &[0; 4]).unwrap();
+        nvs.write(AUDIT_NS, audit_idx, &[0; 4]).unwrap();
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[1; 100]).unwrap();
+
+        nvs.write(CRASH_NS, "e2", &[2; 100]).unwrap();
+
+        assert!(nvs.exists(CRASH_NS, crash_idx));
+        assert!(nvs.exists(AUDIT_NS, audit_idx));
+        assert!(!nvs.exists(CRASH_NS, "e0"));
+        assert!(nvs.exists(CRASH_NS, "e2"));
+    }
+
+    #[test]
+    fn overwrite_in_place_does_not_evict() {
+        let mut nvs = NvsAdapter::with_capacity(200);
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[1; 100]).unwrap();
+
+        nvs.write(CRASH_NS, "e1", &[2; 100]).unwrap();
+        assert!(nvs.exists(CRASH_NS, "e0"));
+    }
+
+    #[test]
+    fn credential_write_on_full_store_never_evicts() {
+        let mut nvs = NvsAdapter::with_capacity(200);
+        nvs.write(CRASH_NS, "e0", &[0; 100]).unwrap();
+        nvs.write(CRASH_NS, "e1", &[1; 100]).unwrap();
+
+        assert!(matches!(
+            nvs.store_credential("psk", &[0xAA; 32]),
+            Err(StorageError::Full)
+        ));
+        assert!(!nvs.credential_exists("psk"));
+        assert!(nvs.exists(CRASH_NS, "e0"));
+        assert!(nvs.exists(CRASH_NS, "e1"));
+    }
+
+    #[test]
+    fn crash_write_never_evicts_credentials() {
+        // 48-byte secret + 32-byte integrity tag.
+        let mut nvs = NvsAdapter::with_capacity(100);
+        nvs.store_credential("psk", &[0xAA; 48]).unwrap();
+
+        assert!(matches!(
+            nvs.write(CRASH_NS, "e0", &[0; 40]),
+            Err(StorageError::Full)
+        ));
+        assert!(nvs.credential_exists("psk"));
+    }
+
+    #[test]
+    fn config_save_on_full_store_reports_storage_full() {
+        let mut nvs = NvsAdapter::with_capacity(16);
+        nvs.write(CRASH_NS, "e0", &[0; 16]).unwrap();
+
+        assert!(matches!(
+            nvs.save(&SystemConfig::default()),
+            Err(ConfigError::StorageFull)
+        ));
+        assert!(nvs.exists(CRASH_NS, "e0"));
+    }
 }
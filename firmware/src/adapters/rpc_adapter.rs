@@ -10,6 +10,7 @@ use log::info;
 use crate::app::events::AppEvent;
 use crate::app::ports::EventSink;
 use crate::rpc::auth::{ClientId, MAX_CLIENTS};
+use crate::rpc::channels::ResponsePriority;
 use crate::rpc::codec::encode_frame;
 use crate::rpc::fb;
 
@@ -70,7 +71,7 @@ impl RpcEventSink {
             }
             let mut data = heapless::Vec::new();
             if data.extend_from_slice(&buf[..len]).is_ok() {
-                crate::rpc::io_task::send_response(i as ClientId, data);
+                crate::rpc::io_task::send_response(i as ClientId, data, ResponsePriority::Low);
             }
         }
     }
@@ -87,6 +88,7 @@ impl EventSink for RpcEventSink {
                         from_state: fb::state_to_fb(*from),
                         to_state: fb::state_to_fb(*to),
                         timestamp_ms: 0,
+                        ..Default::default()
                     },
                 );
                 let msg = fb::Message::create(
@@ -101,7 +103,7 @@ impl EventSink for RpcEventSink {
                 self.broadcast_frame(&fbb);
             }
 
-            AppEvent::FaultDetected(flags) => {
+            AppEvent::FaultDetected { flags, is_test } => {
                 let mut fbb = FlatBufferBuilder::with_capacity(64);
                 let fe = fb::FaultEvent::create(
                     &mut fbb,
@@ -109,6 +111,7 @@ impl EventSink for RpcEventSink {
                         fault_flags: *flags,
                         is_clear: false,
                         timestamp_ms: 0,
+                        is_test: *is_test,
                     },
                 );
                 let msg = fb::Message::create(
@@ -131,6 +134,7 @@ impl EventSink for RpcEventSink {
                         fault_flags: 0,
                         is_clear: true,
                         timestamp_ms: 0,
+                        is_test: false,
                     },
                 );
                 let msg = fb::Message::create(
@@ -6,6 +6,23 @@
 //!   ESP-IDF high-resolution timer (microsecond precision, monotonic).
 //! - **`not(target_os = "espidf")`** — uses `std::time::Instant` for
 //!   host-side testing and simulation.
+//!
+//! Wall-clock time comes from NTP or, on isolated networks, from the
+//! `SetTime` RPC via [`Esp32TimeAdapter::set_unix_time`].  Like the RTC it
+//! models, the simulated wall clock is process-wide: every adapter
+//! instance sees a time set through any of them.
+
+/// Timestamps before 2020-01-01 mean the wall clock was never set.
+pub const EPOCH_2020: u64 = 1_577_836_800;
+
+/// Simulated RTC: the Unix time that was set and the instant it was set at.
+#[cfg(not(target_os = "espidf"))]
+static SIM_WALL_CLOCK: std::sync::Mutex<Option<(u64, std::time::Instant)>> =
+    std::sync::Mutex::new(None);
+
+/// Serialises tests that set the process-wide simulated wall clock.
+#[cfg(test)]
+pub(crate) static SIM_WALL_CLOCK_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
 /// Time adapter for the ESP32-S3 platform.
 pub struct Esp32TimeAdapter {
@@ -51,23 +68,71 @@ impl Esp32TimeAdapter {
         self.start.elapsed().as_micros() as u64
     }
 
-    /// Current hour-of-day (0–23) from system clock. `None` if wall clock not synced (e.g. pre-NTP).
+    /// Current Unix time (UTC seconds).  `None` if the wall clock has not
+    /// been set by NTP or [`set_unix_time`](Self::set_unix_time).
     #[cfg(target_os = "espidf")]
-    pub fn current_hour(&self) -> Option<u8> {
-        use core::ptr;
+    pub fn unix_time(&self) -> Option<u64> {
         let mut tv = esp_idf_svc::sys::timeval {
             tv_sec: 0,
             tv_usec: 0,
         };
-        if unsafe { esp_idf_svc::sys::gettimeofday(&mut tv, ptr::null_mut()) } != 0 {
+        if unsafe { esp_idf_svc::sys::gettimeofday(&mut tv, core::ptr::null_mut()) } != 0 {
             return None;
         }
-        // Reject obviously unsynced time (e.g. before 2020-01-01)
-        const EPOCH_2020: i64 = 1_577_836_800;
-        if tv.tv_sec < EPOCH_2020 {
-            return None;
+        u64::try_from(tv.tv_sec).ok().filter(|&s| s >= EPOCH_2020)
+    }
+
+    /// Current Unix time (UTC seconds).  `None` if the wall clock has not
+    /// been set by [`set_unix_time`](Self::set_unix_time).
+    #[cfg(not(target_os = "espidf"))]
+    pub fn unix_time(&self) -> Option<u64> {
+        let clock = SIM_WALL_CLOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        clock.map(|(secs, at)| secs + at.elapsed().as_secs())
+    }
+
+    /// Whether the wall clock holds a plausible time, i.e. quiet hours and
+    /// other time-of-day features can be honoured.
+    pub fn is_time_set(&self) -> bool {
+        self.unix_time().is_some()
+    }
+
+    /// Set the wall clock (RTC) to `unix_secs`.  Fails for timestamps
+    /// before [`EPOCH_2020`], which would read back as "not set".
+    #[cfg(target_os = "espidf")]
+    pub fn set_unix_time(&mut self, unix_secs: u64) -> Result<(), TimeError> {
+        if unix_secs < EPOCH_2020 {
+            return Err(TimeError::Implausible);
+        }
+        let tv = esp_idf_svc::sys::timeval {
+            tv_sec: unix_secs as esp_idf_svc::sys::time_t,
+            tv_usec: 0,
+        };
+        if unsafe { esp_idf_svc::sys::settimeofday(&tv, core::ptr::null()) } != 0 {
+            return Err(TimeError::Rtc);
         }
-        let secs = tv.tv_sec as esp_idf_svc::sys::time_t;
+        Ok(())
+    }
+
+    /// Set the simulated wall clock to `unix_secs`.  Fails for timestamps
+    /// before [`EPOCH_2020`].
+    #[cfg(not(target_os = "espidf"))]
+    pub fn set_unix_time(&mut self, unix_secs: u64) -> Result<(), TimeError> {
+        if unix_secs < EPOCH_2020 {
+            return Err(TimeError::Implausible);
+        }
+        *SIM_WALL_CLOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) =
+            Some((unix_secs, std::time::Instant::now()));
+        Ok(())
+    }
+
+    /// Current hour-of-day (0–23) from system clock. `None` if wall clock not synced (e.g. pre-NTP).
+    #[cfg(target_os = "espidf")]
+    pub fn current_hour(&self) -> Option<u8> {
+        let secs = self.unix_time()? as esp_idf_svc::sys::time_t;
         let mut tm: esp_idf_svc::sys::tm = unsafe { core::mem::zeroed() };
         if unsafe { esp_idf_svc::sys::localtime_r(&secs, &mut tm) }.is_null() {
             return None;
@@ -79,9 +144,60 @@ impl Esp32TimeAdapter {
         Some(hour as u8)
     }
 
-    /// Current hour-of-day. On non-ESP targets (simulation) always `None`.
+    /// Current hour-of-day (UTC) from the simulated wall clock.  `None`
+    /// until [`set_unix_time`](Self::set_unix_time) is called.
     #[cfg(not(target_os = "espidf"))]
     pub fn current_hour(&self) -> Option<u8> {
-        None
+        self.unix_time().map(|t| ((t % 86_400) / 3_600) as u8)
+    }
+}
+
+/// Errors from [`Esp32TimeAdapter::set_unix_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeError {
+    /// Timestamp predates [`EPOCH_2020`].
+    Implausible,
+    /// `settimeofday` failed.
+    Rtc,
+}
+
+impl core::fmt::Display for TimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Implausible => write!(f, "time before 2020-01-01"),
+            Self::Rtc => write!(f, "RTC write failed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-03-14 15:09:26 UTC.
+    const PI_DAY_2026: u64 = 1_773_500_966;
+
+    #[test]
+    fn set_time_drives_current_hour() {
+        let _guard = SIM_WALL_CLOCK_TEST_LOCK.lock().unwrap();
+        let mut clock = Esp32TimeAdapter::new();
+        clock.set_unix_time(PI_DAY_2026).unwrap();
+
+        assert!(clock.is_time_set());
+        assert_eq!(clock.current_hour(), Some(15));
+        // Process-wide, like the RTC.
+        assert_eq!(Esp32TimeAdapter::new().current_hour(), Some(15));
+        assert!(clock.unix_time().unwrap() >= PI_DAY_2026);
+    }
+
+    #[test]
+    fn pre_2020_time_is_rejected() {
+        let _guard = SIM_WALL_CLOCK_TEST_LOCK.lock().unwrap();
+        let mut clock = Esp32TimeAdapter::new();
+        assert_eq!(
+            clock.set_unix_time(EPOCH_2020 - 1),
+            Err(TimeError::Implausible)
+        );
+        assert_eq!(clock.set_unix_time(0), Err(TimeError::Implausible));
     }
 }
@@ -111,6 +111,8 @@ pub(super) struct EspTlsServer {
     pub(super) conf: Box<mbedtls_ssl_config>,
     entropy: Box<mbedtls_entropy_context>,
     drbg: Box<mbedtls_ctr_drbg_context>,
+    /// Certificate material referenced by `conf` once X.509 is loaded.
+    pub(super) x509: Option<X509Context>,
 }
 
 unsafe impl Send for EspTlsServer {}
@@ -266,6 +268,7 @@ pub(super) fn esp_new(port: u16, psk: &[u8]) -> Result<EspTlsServer, TlsTranspor
         conf,
         entropy,
         drbg,
+        x509: None,
     })
 }
 
@@ -356,11 +359,18 @@ pub(super) fn esp_accept(server: &EspTlsServer) -> Option<EspTlsClient> {
         return None;
     }
 
+    let client = EspTlsClient { fd: client_fd, ssl };
+    if let Some(x509) = &server.x509
+        && !x509.admits_session(&client.ssl)
+    {
+        return None; // dropping `client` closes the socket
+    }
+
     info!(
         "TLS(espidf): TLS 1.3 PSK handshake complete (fd={})",
         client_fd
     );
-    Some(EspTlsClient { fd: client_fd, ssl })
+    Some(client)
 }
 
 /// Non-blocking read from an active TLS session.
@@ -413,11 +423,23 @@ pub(super) fn esp_write(
 
 /// Additional mbedTLS symbols needed for X.509 certificate auth.
 use esp_idf_svc::sys::{
-    mbedtls_pk_context, mbedtls_pk_free, mbedtls_pk_init, mbedtls_pk_parse_key,
-    mbedtls_ssl_conf_ca_chain, mbedtls_ssl_conf_own_cert, mbedtls_x509_crt, mbedtls_x509_crt_free,
-    mbedtls_x509_crt_init, mbedtls_x509_crt_parse,
+    MBEDTLS_ERR_X509_FATAL_ERROR, MBEDTLS_SSL_VERIFY_OPTIONAL, MBEDTLS_X509_BADCERT_NOT_TRUSTED,
+    mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_DHE_PSK,
+    mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_ECDHE_PSK,
+    mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_NONE,
+    mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_PSK,
+    mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_RSA_PSK, mbedtls_pk_context, mbedtls_pk_free,
+    mbedtls_pk_init, mbedtls_pk_parse_key, mbedtls_ssl_ciphersuite_from_id,
+    mbedtls_ssl_conf_ca_chain, mbedtls_ssl_conf_own_cert, mbedtls_ssl_conf_verify,
+    mbedtls_ssl_get_ciphersuite_id_from_ssl, mbedtls_ssl_get_peer_cert,
+    mbedtls_ssl_get_verify_result, mbedtls_x509_crt, mbedtls_x509_crt_free, mbedtls_x509_crt_init,
+    mbedtls_x509_crt_parse,
 };
 
+use core::cell::UnsafeCell;
+
+use crate::adapters::cert_store::ClientCertPins;
+
 /// Loaded X.509 certificate material for the TLS server.
 ///
 /// Kept alive as long as the TLS server is running; mbedTLS references
@@ -426,6 +448,115 @@ pub(super) struct X509Context {
     pub(super) server_cert: Box<mbedtls_x509_crt>,
     pub(super) ca_cert: Box<mbedtls_x509_crt>,
     pub(super) server_key: Box<mbedtls_pk_context>,
+    /// Pin list read by [`verify_client_pin`] during handshakes.
+    client_pins: Box<UnsafeCell<ClientCertPins>>,
+}
+
+impl X509Context {
+    /// Replace the pin list the verify callback checks.  Handshakes run
+    /// on the same thread (`esp_accept`), so none is reading it now.
+    pub(super) fn set_client_pins(&mut self, pins: ClientCertPins) {
+        // SAFETY: the only other access is `verify_client_pin`, which runs
+        // inside `mbedtls_ssl_handshake` on this thread, never concurrently.
+        unsafe { *self.client_pins.get() = pins };
+    }
+
+    /// Post-handshake check for sessions that authenticated the client
+    /// by certificate rather than PSK.  `VERIFY_OPTIONAL` lets such a
+    /// handshake finish with no certificate, or one that does not chain
+    /// to the CA, so the verify result is checked here; while pins are
+    /// configured the peer certificate must also be pinned.
+    fn admits_session(&self, ssl: &mbedtls_ssl_context) -> bool {
+        if !is_certificate_session(ssl) {
+            return true;
+        }
+        // SAFETY: `ssl` finished its handshake; the pin list is only
+        // written by `set_client_pins` on this thread.
+        let (verify, peer, pins) = unsafe {
+            (
+                mbedtls_ssl_get_verify_result(ssl),
+                mbedtls_ssl_get_peer_cert(ssl),
+                &*self.client_pins.get(),
+            )
+        };
+        if verify != 0 {
+            warn!(
+                "TLS(espidf): client certificate missing or untrusted (flags={:#x}), rejecting",
+                verify
+            );
+            return false;
+        }
+        if !pins.is_empty() {
+            // SAFETY: a non-null peer certificate lives as long as `ssl`.
+            let der = (!peer.is_null()).then(|| unsafe {
+                let raw = &(*peer).raw;
+                core::slice::from_raw_parts(raw.p, raw.len)
+            });
+            if !der.is_some_and(|der| pins.permits(der)) {
+                warn!("TLS(espidf): client certificate is not pinned, rejecting");
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Whether the negotiated suite authenticates the client by certificate.
+/// TLS 1.3 suites report no key exchange; the server only offers the
+/// pure-PSK mode there, as it does the PSK suites on TLS 1.2.
+fn is_certificate_session(ssl: &mbedtls_ssl_context) -> bool {
+    // SAFETY: `ssl` is a live context; the suite table is static.
+    let key_exchange = unsafe {
+        let info = mbedtls_ssl_ciphersuite_from_id(mbedtls_ssl_get_ciphersuite_id_from_ssl(ssl));
+        if info.is_null() {
+            return false;
+        }
+        (*info).private_key_exchange
+    };
+    ![
+        mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_NONE,
+        mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_PSK,
+        mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_DHE_PSK,
+        mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_RSA_PSK,
+        mbedtls_key_exchange_type_t_MBEDTLS_KEY_EXCHANGE_ECDHE_PSK,
+    ]
+    .contains(&u32::from(key_exchange))
+}
+
+/// mbedTLS verify callback enforcing the client certificate pin list.
+///
+/// Called for each certificate in the client's chain; only the client's
+/// own certificate (depth 0) is checked.  An unpinned certificate fails
+/// with a fatal error rather than a verify flag, since `VERIFY_OPTIONAL`
+/// would otherwise let the handshake through.
+///
+/// # Safety
+///
+/// `ctx` must be the `UnsafeCell<ClientCertPins>` of a live
+/// `X509Context`; `crt` and `flags` come from mbedTLS.
+unsafe extern "C" fn verify_client_pin(
+    ctx: *mut core::ffi::c_void,
+    crt: *mut mbedtls_x509_crt,
+    depth: core::ffi::c_int,
+    flags: *mut u32,
+) -> core::ffi::c_int {
+    if depth != 0 {
+        return 0;
+    }
+    // SAFETY: see the function contract; `raw` is the DER the
+    // certificate was parsed from.
+    let (pins, der) = unsafe {
+        let pins = &*(ctx as *const ClientCertPins);
+        let raw = &(*crt).raw;
+        (pins, core::slice::from_raw_parts(raw.p, raw.len))
+    };
+    if pins.permits(der) {
+        return 0;
+    }
+    warn!("TLS(espidf): client certificate is not pinned, rejecting");
+    // SAFETY: mbedTLS passes a valid flags pointer.
+    unsafe { *flags |= MBEDTLS_X509_BADCERT_NOT_TRUSTED };
+    MBEDTLS_ERR_X509_FATAL_ERROR
 }
 
 impl Drop for X509Context {
@@ -444,10 +575,18 @@ impl Drop for X509Context {
 ///
 /// `server_cert_pem`, `server_key_pem`, and `ca_cert_pem` must be
 /// PEM-encoded and NUL-terminated (mbedTLS requirement for _parse functions).
+/// Client certificates are requested but optional at the TLS layer (PSK
+/// clients still connect); a certificate-suite session must present one
+/// that chains to the CA and, if `client_pins` is non-empty, is pinned
+/// (checked after the handshake in `esp_accept`).
 ///
 /// Returns an `X509Context` that must be kept alive for the lifetime
 /// of the TLS config.
 ///
+/// Each parse can take hundreds of milliseconds (the key parse most of
+/// all), so the task yields between steps to let the idle task feed the
+/// watchdog.
+///
 /// # Safety
 ///
 /// `conf` must be a valid, initialised `mbedtls_ssl_config`.
@@ -456,10 +595,12 @@ pub(super) unsafe fn esp_configure_x509(
     server_cert_pem: &[u8],
     server_key_pem: &[u8],
     ca_cert_pem: &[u8],
+    client_pins: ClientCertPins,
 ) -> Result<X509Context, super::TlsTransportError> {
     let mut server_cert = Box::new(mbedtls_x509_crt::default());
     let mut ca_cert = Box::new(mbedtls_x509_crt::default());
     let mut server_key = Box::new(mbedtls_pk_context::default());
+    let client_pins = Box::new(UnsafeCell::new(client_pins));
 
     // SAFETY: All pointers are from Box::as_mut(), valid and exclusively owned.
     unsafe {
@@ -476,12 +617,14 @@ pub(super) unsafe fn esp_configure_x509(
             warn!("TLS(espidf): x509_crt_parse(server) failed (rc={})", rc);
             return Err(super::TlsTransportError::Tls);
         }
+        yield_parse_step();
 
         let rc = mbedtls_x509_crt_parse(ca_cert.as_mut(), ca_cert_pem.as_ptr(), ca_cert_pem.len());
         if rc != 0 {
             warn!("TLS(espidf): x509_crt_parse(ca) failed (rc={})", rc);
             return Err(super::TlsTransportError::Tls);
         }
+        yield_parse_step();
 
         let rc = mbedtls_pk_parse_key(
             server_key.as_mut(),
@@ -496,6 +639,7 @@ pub(super) unsafe fn esp_configure_x509(
             warn!("TLS(espidf): pk_parse_key failed (rc={})", rc);
             return Err(super::TlsTransportError::Tls);
         }
+        yield_parse_step();
 
         // Register server certificate + key with the TLS config.
         let rc = mbedtls_ssl_conf_own_cert(conf, server_cert.as_mut(), server_key.as_mut());
@@ -506,6 +650,15 @@ pub(super) unsafe fn esp_configure_x509(
 
         // Register the CA chain for client certificate verification.
         mbedtls_ssl_conf_ca_chain(conf, ca_cert.as_mut(), core::ptr::null_mut());
+
+        // Verify client certificates when presented, against the pins;
+        // `X509Context::admits_session` rejects what OPTIONAL lets through.
+        mbedtls_ssl_conf_authmode(conf, MBEDTLS_SSL_VERIFY_OPTIONAL as _);
+        mbedtls_ssl_conf_verify(
+            conf,
+            Some(verify_client_pin),
+            client_pins.get() as *mut core::ffi::c_void,
+        );
     }
 
     info!("TLS(espidf): X.509 certificate auth configured");
@@ -513,5 +666,13 @@ pub(super) unsafe fn esp_configure_x509(
         server_cert,
         ca_cert,
         server_key,
+        client_pins,
     })
 }
+
+/// Block for one FreeRTOS tick between X.509 parse steps so the idle
+/// task on this core gets to run and feed the task watchdog.
+fn yield_parse_step() {
+    // SAFETY: called from task context (the I/O thread), never an ISR.
+    unsafe { vTaskDelay(1) };
+}
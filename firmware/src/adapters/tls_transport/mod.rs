@@ -18,15 +18,25 @@
 //!    socket is TLS-wrapped and assigned a client ID.
 //! 3. Reads/writes are non-blocking and addressed by client ID.
 //! 4. `disconnect(client_id)` tears down a specific connection.
+//! 5. `reap_idle(now)` disconnects clients with no read/write activity
+//!    for longer than the idle timeout, so a peer that vanished without
+//!    a clean close, or whose session failed, does not hold its slot
+//!    forever.
 
 use core::fmt;
-use log::info;
+use core::time::Duration;
+use log::{info, warn};
 
+use crate::adapters::cert_store::CertBundle;
+#[cfg(not(target_os = "espidf"))]
+use crate::adapters::cert_store::ClientCertPins;
 use crate::rpc::auth::{ClientId, MAX_CLIENTS};
+use crate::rpc::io_task::{TCP_SLOT_START, UART_SLOT};
 use crate::rpc::transport::Transport;
 
 #[cfg(not(target_os = "espidf"))]
 use std::io::{Read, Write};
+use std::time::Instant;
 
 // ───────────────────────────────────────────────────────────────
 // Constants
@@ -36,6 +46,9 @@ const MAX_PSK_LEN: usize = 64;
 
 pub const DEFAULT_PORT: u16 = 4242;
 
+/// Idle time after which a TCP client is reaped.
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
 // ───────────────────────────────────────────────────────────────
 // Error type
 // ───────────────────────────────────────────────────────────────
@@ -85,6 +98,8 @@ mod esp_impl;
 /// Internal per-client connection state.
 struct ClientSlot {
     state: TlsConnectionState,
+    /// Time of the last accept, read or write with data.
+    last_activity: Option<Instant>,
 
     #[cfg(target_os = "espidf")]
     tls_client: Option<esp_impl::EspTlsClient>,
@@ -97,6 +112,7 @@ impl ClientSlot {
     fn new() -> Self {
         Self {
             state: TlsConnectionState::Listening,
+            last_activity: None,
             #[cfg(target_os = "espidf")]
             tls_client: None,
             #[cfg(not(target_os = "espidf"))]
@@ -108,6 +124,19 @@ impl ClientSlot {
         self.state == TlsConnectionState::Listening
     }
 
+    fn touch(&mut self) {
+        self.last_activity = Some(Instant::now());
+    }
+
+    /// An errored slot counts too: it can no longer read or write, so
+    /// it goes quiet and is freed once the timeout runs out.
+    fn is_idle(&self, now: Instant, timeout: Duration) -> bool {
+        !self.is_free()
+            && self
+                .last_activity
+                .is_some_and(|t| now.saturating_duration_since(t) > timeout)
+    }
+
     fn disconnect(&mut self) {
         #[cfg(target_os = "espidf")]
         {
@@ -117,6 +146,7 @@ impl ClientSlot {
         {
             self.stream.take();
         }
+        self.last_activity = None;
         self.state = TlsConnectionState::Listening;
     }
 }
@@ -132,12 +162,17 @@ pub struct TlsTransport {
     psk: heapless::Vec<u8, MAX_PSK_LEN>,
     port: u16,
     clients: [ClientSlot; MAX_CLIENTS],
+    /// `None` disables idle reaping.
+    idle_timeout: Option<Duration>,
 
     #[cfg(target_os = "espidf")]
     server: Option<esp_impl::EspTlsServer>,
 
     #[cfg(not(target_os = "espidf"))]
     listener: std::net::TcpListener,
+    /// Pins from the last `configure_x509`, for the simulated check.
+    #[cfg(not(target_os = "espidf"))]
+    client_pins: ClientCertPins,
 }
 
 impl TlsTransport {
@@ -159,6 +194,7 @@ impl TlsTransport {
             psk: psk_buf,
             port,
             clients: core::array::from_fn(|_| ClientSlot::new()),
+            idle_timeout: Some(DEFAULT_IDLE_TIMEOUT),
             server: Some(server),
         })
     }
@@ -185,29 +221,110 @@ impl TlsTransport {
             psk: psk_buf,
             port,
             clients: core::array::from_fn(|_| ClientSlot::new()),
+            idle_timeout: Some(DEFAULT_IDLE_TIMEOUT),
             listener,
+            client_pins: ClientCertPins::default(),
         })
     }
 
     // ── Public API ────────────────────────────────────────────
 
     /// Try to accept a new client. Returns the assigned `ClientId` on success.
-    /// Slot 0 is reserved for BLE; TCP clients are assigned to slots 1..MAX_CLIENTS.
+    /// Slot 0 is reserved for BLE and the last slot for the UART; TCP
+    /// clients are assigned to the slots in between.
     pub fn try_accept(&mut self) -> Option<ClientId> {
         let slot_idx = self
             .clients
             .iter()
             .enumerate()
-            .skip(1)
+            .take(UART_SLOT as usize)
+            .skip(TCP_SLOT_START)
             .find(|(_, s)| s.is_free())
             .map(|(i, _)| i)?;
         if self.platform_accept(slot_idx) {
+            self.clients[slot_idx].touch();
             Some(slot_idx as ClientId)
         } else {
             None
         }
     }
 
+    /// Set how long a client may go without reads or writes before
+    /// [`reap_idle`](Self::reap_idle) disconnects it (`None` = never).
+    pub fn set_idle_timeout(&mut self, timeout: Option<Duration>) {
+        self.idle_timeout = timeout;
+    }
+
+    /// Load X.509 certificate auth into the running server.  mbedTLS has
+    /// no way to drop a registered cert, so once a bundle is loaded a
+    /// replacement only takes effect after a restart; its client
+    /// certificate pins apply at once.
+    #[cfg(target_os = "espidf")]
+    pub fn configure_x509(&mut self, bundle: &CertBundle) -> Result<(), TlsTransportError> {
+        let server = self.server.as_mut().ok_or(TlsTransportError::NotConnected)?;
+        if let Some(x509) = server.x509.as_mut() {
+            x509.set_client_pins(bundle.client_pins.clone());
+            info!(
+                "TLS(espidf): {} client cert pin(s) applied; new certificates apply after restart",
+                bundle.client_pins.len()
+            );
+            return Ok(());
+        }
+        // SAFETY: `server.conf` was initialised in `esp_new`; the returned
+        // context is kept in the server alongside it.
+        let x509 = unsafe {
+            esp_impl::esp_configure_x509(
+                server.conf.as_mut(),
+                &bundle.server_cert,
+                &bundle.server_key,
+                &bundle.ca_cert,
+                bundle.client_pins.clone(),
+            )?
+        };
+        server.x509 = Some(x509);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    pub fn configure_x509(&mut self, bundle: &CertBundle) -> Result<(), TlsTransportError> {
+        info!(
+            "TLS(sim): configure_x509 (cert={}B, {} client pin(s), plaintext in simulation)",
+            bundle.server_cert.len(),
+            bundle.client_pins.len()
+        );
+        self.client_pins = bundle.client_pins.clone();
+        Ok(())
+    }
+
+    /// Whether a client presenting the DER certificate `der` would pass
+    /// the pin check.  The simulation has no TLS handshake to run it in.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn permits_client_cert(&self, der: &[u8]) -> bool {
+        self.client_pins.permits(der)
+    }
+
+    /// Disconnect every client idle for longer than the idle timeout
+    /// as of `now`.  Returns the freed client IDs so the caller can
+    /// reset their per-client state.
+    pub fn reap_idle(&mut self, now: Instant) -> heapless::Vec<ClientId, MAX_CLIENTS> {
+        let mut reaped = heapless::Vec::new();
+        let Some(timeout) = self.idle_timeout else {
+            return reaped;
+        };
+        for (i, slot) in self.clients.iter_mut().enumerate() {
+            if slot.is_idle(now, timeout) {
+                info!(
+                    "TLS: client {} idle for over {:?}, disconnecting",
+                    i, timeout
+                );
+                slot.disconnect();
+                // Capacity is MAX_CLIENTS, one entry per slot.
+                let _ = reaped.push(i as ClientId);
+            }
+        }
+        reaped
+    }
+
     /// Disconnect a specific client.
     pub fn disconnect(&mut self, client_id: ClientId) {
         if let Some(slot) = self.clients.get_mut(client_id as usize) {
@@ -231,7 +348,11 @@ impl TlsTransport {
         if slot.state != TlsConnectionState::Connected {
             return Err(TlsTransportError::NotConnected);
         }
-        self.platform_read(client_id, buf)
+        let n = self.platform_read(client_id, buf)?;
+        if n > 0 {
+            self.clients[client_id as usize].touch();
+        }
+        Ok(n)
     }
 
     /// Write to a specific client.
@@ -247,7 +368,11 @@ impl TlsTransport {
         if slot.state != TlsConnectionState::Connected {
             return Err(TlsTransportError::NotConnected);
         }
-        self.platform_write(client_id, data)
+        let n = self.platform_write(client_id, data)?;
+        if n > 0 {
+            self.clients[client_id as usize].touch();
+        }
+        Ok(n)
     }
 
     /// Flush a specific client's output buffer.
@@ -552,13 +677,104 @@ mod tests {
         assert_eq!(&reply, b"world");
     }
 
+    #[test]
+    fn silent_client_is_reaped_after_idle_timeout() {
+        let mut t = make_transport();
+        t.set_idle_timeout(Some(Duration::from_millis(50)));
+        let addr = t.local_addr();
+
+        let mut active = std::net::TcpStream::connect(addr).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let active_id = t.try_accept().unwrap();
+        let _silent = std::net::TcpStream::connect(addr).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let silent_id = t.try_accept().unwrap();
+        let t0 = Instant::now();
+
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        use std::io::Write as _;
+        active.write_all(b"ping").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let mut buf = [0u8; 8];
+        assert_eq!(t.read_client(active_id, &mut buf).unwrap(), 4);
+
+        // Only the silent client has gone longer than the timeout.
+        let reaped = t.reap_idle(t0 + Duration::from_millis(60));
+        assert_eq!(&reaped[..], &[silent_id]);
+        assert!(t.is_connected(active_id));
+        assert!(!t.is_connected(silent_id));
+
+        // The freed slot takes the next connection.
+        let _next = std::net::TcpStream::connect(addr).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(t.try_accept(), Some(silent_id));
+    }
+
+    #[test]
+    fn errored_client_is_reaped_after_idle_timeout() {
+        let mut t = make_transport();
+        t.set_idle_timeout(Some(Duration::from_millis(50)));
+        let addr = t.local_addr();
+
+        let _c = std::net::TcpStream::connect(addr).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let cid = t.try_accept().unwrap();
+        t.clients[cid as usize].state = TlsConnectionState::Error;
+        assert!(!t.is_connected(cid));
+
+        let reaped = t.reap_idle(Instant::now() + Duration::from_millis(60));
+        assert_eq!(&reaped[..], &[cid]);
+        assert!(t.clients[cid as usize].is_free());
+    }
+
+    #[test]
+    fn idle_reaping_can_be_disabled() {
+        let mut t = make_transport();
+        t.set_idle_timeout(None);
+        let addr = t.local_addr();
+
+        let _c = std::net::TcpStream::connect(addr).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let cid = t.try_accept().unwrap();
+
+        assert!(
+            t.reap_idle(Instant::now() + Duration::from_secs(3600))
+                .is_empty()
+        );
+        assert!(t.is_connected(cid));
+    }
+
+    #[test]
+    fn configured_pins_gate_client_certs() {
+        use crate::adapters::cert_store::fingerprint;
+
+        let mut t = make_transport();
+        let mut bundle = CertBundle {
+            server_cert: heapless::Vec::new(),
+            server_key: heapless::Vec::new(),
+            ca_cert: heapless::Vec::new(),
+            client_pins: ClientCertPins::default(),
+        };
+        assert!(t.permits_client_cert(b"any client"));
+
+        let pinned = fingerprint(b"pinned client");
+        bundle.client_pins.add(pinned).unwrap();
+        t.configure_x509(&bundle).unwrap();
+        assert!(t.permits_client_cert(b"pinned client"));
+        assert!(!t.permits_client_cert(b"any client"));
+
+        bundle.client_pins = ClientCertPins::default();
+        t.configure_x509(&bundle).unwrap();
+        assert!(t.permits_client_cert(b"any client"));
+    }
+
     #[test]
     fn max_clients_rejection() {
         let mut t = make_transport();
         let addr = t.local_addr();
 
-        // Slot 0 is reserved for BLE — only (MAX_CLIENTS - 1) TCP slots available
-        let tcp_slots = MAX_CLIENTS - 1;
+        // Slot 0 is reserved for BLE and the last slot for the UART
+        let tcp_slots = MAX_CLIENTS - 2;
         let mut clients = Vec::new();
         for _ in 0..tcp_slots {
             let c = std::net::TcpStream::connect(addr).unwrap();
@@ -0,0 +1,410 @@
+//! UART RPC transport — plaintext framed RPC over a serial port.
+//!
+//! Intended for bench debugging without WiFi or BLE.  The link is
+//! physically local, so frames travel in the normal length-prefixed
+//! codec format ([`crate::rpc::codec`]) without TLS.  Clients still
+//! authenticate with the PSK challenge like on any other slot.
+//!
+//! A serial line has no connect/disconnect signal, so the transport
+//! follows the single-slot accept pattern: the slot is "accepted" when
+//! the first byte arrives after construction or a
+//! [`disconnect`](UartTransport::disconnect).
+//!
+//! On ESP-IDF this drives a UART peripheral through the IDF driver.  If
+//! the same UART also carries the log console, route the console to
+//! USB-Serial-JTAG (`CONFIG_ESP_CONSOLE_USB_SERIAL_JTAG`) or log lines
+//! will corrupt the framing.  On the host the transport wraps an
+//! in-memory pipe pair ([`UartTransport::pipe`]) for tests.
+
+use log::info;
+
+use crate::rpc::auth::ClientId;
+use crate::rpc::transport::Transport;
+
+#[cfg(not(target_os = "espidf"))]
+use std::collections::VecDeque;
+#[cfg(not(target_os = "espidf"))]
+use std::sync::{Arc, Mutex};
+
+#[cfg(target_os = "espidf")]
+use esp_idf_svc::sys::{
+    ESP_OK, uart_config_t, uart_driver_install, uart_flush_input, uart_get_buffered_data_len,
+    uart_hw_flowcontrol_t_UART_HW_FLOWCTRL_DISABLE, uart_is_driver_installed, uart_param_config,
+    uart_parity_t_UART_PARITY_DISABLE, uart_port_t, uart_read_bytes,
+    uart_stop_bits_t_UART_STOP_BITS_1, uart_wait_tx_done, uart_word_length_t_UART_DATA_8_BITS,
+    uart_write_bytes,
+};
+
+// ── Constants ────────────────────────────────────────────────
+
+/// UART0 — wired to the USB-serial bridge on most dev boards.
+pub const DEFAULT_UART_PORT: i32 = 0;
+
+pub const DEFAULT_BAUD: u32 = 115_200;
+
+#[cfg(target_os = "espidf")]
+const DRIVER_BUF_SIZE: i32 = 2048;
+
+/// Upper bound on how long `flush` waits for the TX FIFO to drain.
+#[cfg(target_os = "espidf")]
+const FLUSH_TIMEOUT_TICKS: u32 = 100;
+
+// ── Error type ───────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UartTransportError {
+    NotConnected,
+    DriverInit,
+    Io,
+}
+
+impl core::fmt::Display for UartTransportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotConnected => write!(f, "UART RPC: no client attached"),
+            Self::DriverInit => write!(f, "UART RPC: driver initialisation failed"),
+            Self::Io => write!(f, "UART RPC: read/write failed"),
+        }
+    }
+}
+
+// ── Host pipe ────────────────────────────────────────────────
+
+#[cfg(not(target_os = "espidf"))]
+type Pipe = Arc<Mutex<VecDeque<u8>>>;
+
+/// Host-side end of an in-memory UART pipe pair.  What the peer
+/// writes, the transport reads, and vice versa.
+#[cfg(not(target_os = "espidf"))]
+pub struct UartPeer {
+    to_device: Pipe,
+    from_device: Pipe,
+}
+
+#[cfg(not(target_os = "espidf"))]
+impl std::io::Write for UartPeer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut q = self
+            .to_device
+            .lock()
+            .map_err(|_| std::io::Error::other("pipe poisoned"))?;
+        q.extend(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "espidf"))]
+impl std::io::Read for UartPeer {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut q = self
+            .from_device
+            .lock()
+            .map_err(|_| std::io::Error::other("pipe poisoned"))?;
+        let n = buf.len().min(q.len());
+        for (dst, src) in buf.iter_mut().zip(q.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+}
+
+// ── UART Transport ───────────────────────────────────────────
+
+/// Single-slot serial transport.
+pub struct UartTransport {
+    client_id: ClientId,
+    connected: bool,
+
+    #[cfg(target_os = "espidf")]
+    port: uart_port_t,
+
+    #[cfg(not(target_os = "espidf"))]
+    rx: Pipe,
+    #[cfg(not(target_os = "espidf"))]
+    tx: Pipe,
+}
+
+impl UartTransport {
+    /// Install the IDF UART driver on `port` and serve it as `client_id`.
+    ///
+    /// Reuses the driver if it is already installed (e.g. by the console).
+    #[cfg(target_os = "espidf")]
+    pub fn new(
+        port: uart_port_t,
+        baud: u32,
+        client_id: ClientId,
+    ) -> Result<Self, UartTransportError> {
+        // SAFETY: called once from the main task before the I/O thread
+        // takes ownership; the IDF UART driver serialises access itself.
+        unsafe {
+            if !uart_is_driver_installed(port) {
+                let cfg = uart_config_t {
+                    baud_rate: baud as i32,
+                    data_bits: uart_word_length_t_UART_DATA_8_BITS,
+                    parity: uart_parity_t_UART_PARITY_DISABLE,
+                    stop_bits: uart_stop_bits_t_UART_STOP_BITS_1,
+                    flow_ctrl: uart_hw_flowcontrol_t_UART_HW_FLOWCTRL_DISABLE,
+                    ..Default::default()
+                };
+                if uart_param_config(port, &cfg) != ESP_OK {
+                    return Err(UartTransportError::DriverInit);
+                }
+                let ret = uart_driver_install(
+                    port,
+                    DRIVER_BUF_SIZE,
+                    DRIVER_BUF_SIZE,
+                    0,
+                    core::ptr::null_mut(),
+                    0,
+                );
+                if ret != ESP_OK {
+                    return Err(UartTransportError::DriverInit);
+                }
+            }
+        }
+        info!("UART RPC: listening on UART{} @ {} baud", port, baud);
+        Ok(Self {
+            client_id,
+            connected: false,
+            port,
+        })
+    }
+
+    /// Create a transport wired to an in-memory pipe, plus the host end.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn pipe(client_id: ClientId) -> (Self, UartPeer) {
+        let rx: Pipe = Arc::default();
+        let tx: Pipe = Arc::default();
+        let peer = UartPeer {
+            to_device: rx.clone(),
+            from_device: tx.clone(),
+        };
+        let transport = Self {
+            client_id,
+            connected: false,
+            rx,
+            tx,
+        };
+        (transport, peer)
+    }
+
+    /// Accept the slot once bytes start arriving.  Returns the client id
+    /// on the transition from detached to attached.
+    pub fn try_accept(&mut self) -> Option<ClientId> {
+        if self.connected || !self.platform_available() {
+            return None;
+        }
+        self.connected = true;
+        info!("UART RPC: client {} attached", self.client_id);
+        Some(self.client_id)
+    }
+
+    /// Detach the client and discard any unread input.
+    pub fn disconnect(&mut self) {
+        if self.connected {
+            info!("UART RPC: client {} detached", self.client_id);
+        }
+        self.connected = false;
+        self.platform_flush_input();
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    // ── Platform helpers ──────────────────────────────────────
+
+    #[cfg(target_os = "espidf")]
+    fn platform_available(&self) -> bool {
+        let mut len = 0usize;
+        // SAFETY: driver installed in `new`; `len` is a valid out-pointer.
+        let ret = unsafe { uart_get_buffered_data_len(self.port, &mut len) };
+        ret == ESP_OK && len > 0
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_available(&self) -> bool {
+        self.rx.lock().is_ok_and(|q| !q.is_empty())
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_flush_input(&mut self) {
+        // SAFETY: driver installed in `new`.
+        unsafe { uart_flush_input(self.port) };
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_flush_input(&mut self) {
+        if let Ok(mut q) = self.rx.lock() {
+            q.clear();
+        }
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_read(&mut self, buf: &mut [u8]) -> Result<usize, UartTransportError> {
+        // SAFETY: `buf` is valid for `buf.len()` bytes; zero timeout
+        // makes the call non-blocking.
+        let n = unsafe { uart_read_bytes(self.port, buf.as_mut_ptr().cast(), buf.len() as u32, 0) };
+        usize::try_from(n).map_err(|_| UartTransportError::Io)
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_read(&mut self, buf: &mut [u8]) -> Result<usize, UartTransportError> {
+        let mut q = self.rx.lock().map_err(|_| UartTransportError::Io)?;
+        let n = buf.len().min(q.len());
+        for (dst, src) in buf.iter_mut().zip(q.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_write(&mut self, data: &[u8]) -> Result<usize, UartTransportError> {
+        // SAFETY: `data` is valid for `data.len()` bytes; the driver
+        // copies it into its TX ring buffer before returning.
+        let n = unsafe { uart_write_bytes(self.port, data.as_ptr().cast(), data.len()) };
+        usize::try_from(n).map_err(|_| UartTransportError::Io)
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_write(&mut self, data: &[u8]) -> Result<usize, UartTransportError> {
+        let mut q = self.tx.lock().map_err(|_| UartTransportError::Io)?;
+        q.extend(data);
+        Ok(data.len())
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn platform_flush(&mut self) -> Result<(), UartTransportError> {
+        // SAFETY: driver installed in `new`.
+        let ret = unsafe { uart_wait_tx_done(self.port, FLUSH_TIMEOUT_TICKS) };
+        if ret == ESP_OK {
+            Ok(())
+        } else {
+            Err(UartTransportError::Io)
+        }
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    #[allow(clippy::unnecessary_wraps)] // mirrors the fallible espidf variant
+    fn platform_flush(&mut self) -> Result<(), UartTransportError> {
+        Ok(())
+    }
+}
+
+impl Transport for UartTransport {
+    type Error = UartTransportError;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, UartTransportError> {
+        if !self.connected {
+            return Err(UartTransportError::NotConnected);
+        }
+        self.platform_read(buf)
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<usize, UartTransportError> {
+        if !self.connected {
+            return Err(UartTransportError::NotConnected);
+        }
+        self.platform_write(data)
+    }
+
+    fn flush(&mut self) -> Result<(), UartTransportError> {
+        if !self.connected {
+            return Err(UartTransportError::NotConnected);
+        }
+        self.platform_flush()
+    }
+
+    fn available(&self) -> bool {
+        self.connected && self.platform_available()
+    }
+}
+
+// ── Tests ────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::codec::{FrameDecoder, encode_frame};
+    use std::io::{Read as _, Write as _};
+
+    #[test]
+    fn accepts_on_first_byte() {
+        let (mut uart, mut peer) = UartTransport::pipe(3);
+        assert!(uart.try_accept().is_none());
+        assert!(!uart.is_connected());
+
+        peer.write_all(&[0x00]).unwrap();
+        assert_eq!(uart.try_accept(), Some(3));
+        assert!(uart.is_connected());
+        assert!(uart.try_accept().is_none(), "accepted twice");
+    }
+
+    #[test]
+    fn io_requires_attached_client() {
+        let (mut uart, _peer) = UartTransport::pipe(3);
+        let mut buf = [0u8; 8];
+        assert_eq!(uart.read(&mut buf), Err(UartTransportError::NotConnected));
+        assert_eq!(uart.write(b"x"), Err(UartTransportError::NotConnected));
+    }
+
+    #[test]
+    fn framing_roundtrip_over_pipe() {
+        let (mut uart, mut peer) = UartTransport::pipe(3);
+
+        // Host → device: a framed request, dribbled in two writes.
+        let request = b"get-status";
+        let mut wire = [0u8; 64];
+        let n = encode_frame(request, &mut wire).unwrap();
+        peer.write_all(&wire[..3]).unwrap();
+        assert_eq!(uart.try_accept(), Some(3));
+        peer.write_all(&wire[3..n]).unwrap();
+
+        let mut decoder = FrameDecoder::new();
+        let mut buf = [0u8; 64];
+        let mut frame = None;
+        while uart.available() {
+            let read = uart.read(&mut buf).unwrap();
+            if let Some(f) = decoder.feed(&buf[..read]) {
+                frame = Some(f.to_vec());
+            }
+        }
+        assert_eq!(frame.as_deref(), Some(&request[..]));
+
+        // Device → host: a framed response.
+        let response = b"status-ok";
+        let n = encode_frame(response, &mut wire).unwrap();
+        assert_eq!(uart.write(&wire[..n]).unwrap(), n);
+        uart.flush().unwrap();
+
+        let mut rx = [0u8; 64];
+        let read = peer.read(&mut rx).unwrap();
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decoder.feed(&rx[..read]), Some(&response[..]));
+    }
+
+    #[test]
+    fn disconnect_discards_pending_input() {
+        let (mut uart, mut peer) = UartTransport::pipe(3);
+        peer.write_all(b"partial").unwrap();
+        uart.try_accept().unwrap();
+
+        uart.disconnect();
+        assert!(!uart.is_connected());
+        assert!(uart.try_accept().is_none());
+
+        peer.write_all(b"fresh").unwrap();
+        assert_eq!(uart.try_accept(), Some(3));
+        let mut buf = [0u8; 16];
+        let n = uart.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"fresh");
+    }
+}
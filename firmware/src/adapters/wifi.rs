@@ -13,13 +13,35 @@
 //!
 //! On disconnect the adapter waits an exponential backoff (2 s → 4 s →
 //! 8 s … capped at 60 s) before retrying.
+//!
+//! ## Roaming
+//!
+//! While connected, each poll samples RSSI.  Once it has stayed below
+//! the roam threshold (`wifi_roam_rssi_dbm`) for `wifi_roam_samples`
+//! consecutive polls a roam is scheduled; the next poll scans for the same SSID and, if a
+//! BSSID at least [`ROAM_HYSTERESIS_DB`] stronger is found, re-associates
+//! to it in place.  The adapter never passes through `Disconnected`, so
+//! RPC sessions riding on the same IP survive a successful roam.  A
+//! failed roam falls into the normal reconnect backoff.
+//!
+//! ## Link events
+//!
+//! `WIFI_EVENT_STA_DISCONNECTED` and `IP_EVENT_STA_GOT_IP` are latched
+//! by [`record_link_event`] from the ESP-IDF event task, which then
+//! wakes the main loop with [`Event::WifiLinkChanged`].  The main loop
+//! calls [`WifiAdapter::apply_link_events`] so a dropped link enters
+//! the reconnect backoff immediately instead of on the next poll.
+//! [`ConnectivityPort::poll`] still checks the AP association as a
+//! backstop.
 
 use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
 use log::{error, info, warn};
 
 use super::utils::is_printable_ascii;
 use crate::app::events::AppEvent;
 use crate::app::ports::EventSink;
+use crate::events::{Event, push_event};
 
 // ───────────────────────────────────────────────────────────────
 // Port trait
@@ -62,6 +84,52 @@ pub trait ConnectivityPort {
     fn poll(&mut self);
     fn set_credentials(&mut self, ssid: &str, password: &str) -> Result<(), ConnectivityError>;
     fn rssi(&self) -> Option<i8>;
+    /// Scan for nearby access points (blocking, works while disconnected).
+    fn scan(&mut self) -> heapless::Vec<ScanEntry, MAX_SCAN_RESULTS>;
+}
+
+/// Upper bound on access points returned by [`ConnectivityPort::scan`];
+/// keeps the RPC response inside a single 512-byte frame.
+pub const MAX_SCAN_RESULTS: usize = 6;
+
+/// AP security mode, mirroring ESP-IDF `wifi_auth_mode_t` numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    Open,
+    Wep,
+    WpaPsk,
+    Wpa2Psk,
+    WpaWpa2Psk,
+    Wpa2Enterprise,
+    Wpa3Psk,
+    Wpa2Wpa3Psk,
+    Other,
+}
+
+impl AuthMode {
+    /// Map a raw `wifi_auth_mode_t` value.
+    pub fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => Self::Open,
+            1 => Self::Wep,
+            2 => Self::WpaPsk,
+            3 => Self::Wpa2Psk,
+            4 => Self::WpaWpa2Psk,
+            5 => Self::Wpa2Enterprise,
+            6 => Self::Wpa3Psk,
+            7 => Self::Wpa2Wpa3Psk,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// One access point found by a scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanEntry {
+    pub ssid: heapless::String<32>,
+    pub bssid: [u8; 6],
+    pub rssi: i8,
+    pub auth_mode: AuthMode,
 }
 
 // ───────────────────────────────────────────────────────────────
@@ -79,6 +147,72 @@ pub enum WifiState {
 
 const MAX_BACKOFF_SECS: u32 = 60;
 
+/// RSSI (dBm) below which the current AP is considered weak.
+pub const DEFAULT_ROAM_RSSI_DBM: i8 = -75;
+
+/// Consecutive weak-RSSI polls before a roam is scheduled, so a
+/// transient dip does not cost a scan.
+pub const DEFAULT_ROAM_SAMPLES: u8 = 8;
+
+/// A roam candidate must beat the current RSSI by this margin (dB).
+pub const ROAM_HYSTERESIS_DB: i8 = 8;
+
+// ───────────────────────────────────────────────────────────────
+// Driver link events
+// ───────────────────────────────────────────────────────────────
+
+/// Station link transitions reported by the ESP-IDF event loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkEvent {
+    /// `IP_EVENT_STA_GOT_IP`: associated and addressed.
+    GotIp,
+    /// `WIFI_EVENT_STA_DISCONNECTED`: association lost or refused.
+    Disconnected,
+}
+
+const LINK_GOT_IP: u8 = 0b01;
+const LINK_DROPPED: u8 = 0b10;
+
+/// Link events not yet applied by the main loop.  A disconnect clears
+/// an earlier got-IP, so with both bits set the drop came first and the
+/// link is back up; the drop is still applied so roam and RSSI state
+/// reset.
+static PENDING_LINK: AtomicU8 = AtomicU8::new(0);
+
+fn latch_link_event(event: LinkEvent) {
+    match event {
+        LinkEvent::GotIp => PENDING_LINK.fetch_or(LINK_GOT_IP, Ordering::AcqRel),
+        LinkEvent::Disconnected => PENDING_LINK.swap(LINK_DROPPED, Ordering::AcqRel),
+    };
+}
+
+/// Latch a driver link event and wake the main loop.  Safe to call from
+/// the ESP-IDF event task.
+pub fn record_link_event(event: LinkEvent) {
+    latch_link_event(event);
+    push_event(Event::WifiLinkChanged);
+}
+
+#[cfg(target_os = "espidf")]
+unsafe extern "C" fn link_event_handler(
+    _arg: *mut core::ffi::c_void,
+    base: esp_idf_svc::sys::esp_event_base_t,
+    id: i32,
+    _data: *mut core::ffi::c_void,
+) {
+    use esp_idf_svc::sys::*;
+    let event = unsafe {
+        if base == WIFI_EVENT && id == wifi_event_t_WIFI_EVENT_STA_DISCONNECTED as i32 {
+            LinkEvent::Disconnected
+        } else if base == IP_EVENT && id == ip_event_t_IP_EVENT_STA_GOT_IP as i32 {
+            LinkEvent::GotIp
+        } else {
+            return;
+        }
+    };
+    record_link_event(event);
+}
+
 // ───────────────────────────────────────────────────────────────
 // Validation
 // ───────────────────────────────────────────────────────────────
@@ -138,6 +272,27 @@ pub fn wifi_stack_init() -> Result<(), ConnectivityError> {
             return Err(ConnectivityError::ConnectionFailed);
         }
 
+        let ret = esp_event_handler_register(
+            WIFI_EVENT,
+            wifi_event_t_WIFI_EVENT_STA_DISCONNECTED as i32,
+            Some(link_event_handler),
+            core::ptr::null_mut(),
+        );
+        if ret != ESP_OK as i32 {
+            error!("WiFi: disconnect handler registration failed ({})", ret);
+            return Err(ConnectivityError::ConnectionFailed);
+        }
+        let ret = esp_event_handler_register(
+            IP_EVENT,
+            ip_event_t_IP_EVENT_STA_GOT_IP as i32,
+            Some(link_event_handler),
+            core::ptr::null_mut(),
+        );
+        if ret != ESP_OK as i32 {
+            error!("WiFi: got-IP handler registration failed ({})", ret);
+            return Err(ConnectivityError::ConnectionFailed);
+        }
+
         let ret = esp_wifi_set_mode(wifi_mode_t_WIFI_MODE_STA);
         if ret != ESP_OK as i32 {
             error!("WiFi: esp_wifi_set_mode failed ({})", ret);
@@ -177,6 +332,11 @@ pub struct WifiAdapter {
     password: heapless::String<64>,
     backoff_secs: u32,
     last_rssi: Option<i8>,
+    roam_rssi_dbm: i8,
+    roam_samples: u32,
+    /// Consecutive polls with RSSI below `roam_rssi_dbm`.
+    weak_rssi_polls: u32,
+    roam_pending: bool,
 }
 
 impl Default for WifiAdapter {
@@ -197,6 +357,10 @@ impl WifiAdapter {
             password: heapless::String::new(),
             backoff_secs: 2,
             last_rssi: None,
+            roam_rssi_dbm: DEFAULT_ROAM_RSSI_DBM,
+            roam_samples: u32::from(DEFAULT_ROAM_SAMPLES),
+            weak_rssi_polls: 0,
+            roam_pending: false,
         }
     }
 
@@ -204,6 +368,124 @@ impl WifiAdapter {
         self.state
     }
 
+    /// Override the roam trigger: RSSI threshold (dBm) and how many
+    /// consecutive polls must fall below it.  Re-applying the current
+    /// trigger keeps the weak-poll count.
+    pub fn set_roam_threshold(&mut self, rssi_dbm: i8, samples: u8) {
+        let samples = u32::from(samples.max(1));
+        if rssi_dbm == self.roam_rssi_dbm && samples == self.roam_samples {
+            return;
+        }
+        self.roam_rssi_dbm = rssi_dbm;
+        self.roam_samples = samples;
+        self.weak_rssi_polls = 0;
+    }
+
+    /// Whether a roam scan will run on the next poll.
+    pub fn is_roam_pending(&self) -> bool {
+        self.roam_pending
+    }
+
+    /// Apply link events latched by [`record_link_event`].  Returns the
+    /// new state when it changed.
+    pub fn apply_link_events(&mut self) -> Option<WifiState> {
+        let before = self.state;
+        let pending = PENDING_LINK.swap(0, Ordering::AcqRel);
+        if pending & LINK_DROPPED != 0 {
+            self.on_link_event(LinkEvent::Disconnected);
+        }
+        if pending & LINK_GOT_IP != 0 {
+            self.on_link_event(LinkEvent::GotIp);
+        }
+        (self.state != before).then_some(self.state)
+    }
+
+    // ── Link state ────────────────────────────────────────────
+
+    /// Got-IP completes a pending connect or reconnect; a disconnect
+    /// drops a live or in-flight link into the reconnect backoff.  After
+    /// an intentional [`disconnect`](ConnectivityPort::disconnect) both
+    /// are ignored.
+    fn on_link_event(&mut self, event: LinkEvent) {
+        match (event, self.state) {
+            (LinkEvent::GotIp, WifiState::Connecting | WifiState::Reconnecting { .. }) => {
+                self.mark_connected();
+                info!("WiFi: got IP (RSSI={:?})", self.last_rssi);
+            }
+            (LinkEvent::Disconnected, WifiState::Connected | WifiState::Connecting) => {
+                warn!("WiFi: link dropped, entering reconnect");
+                self.enter_reconnect();
+            }
+            _ => {}
+        }
+    }
+
+    fn mark_connected(&mut self) {
+        self.state = WifiState::Connected;
+        self.backoff_secs = 2;
+        self.last_rssi = self.platform_rssi();
+    }
+
+    fn enter_reconnect(&mut self) {
+        self.state = WifiState::Reconnecting { attempt: 0 };
+        self.last_rssi = None;
+        self.weak_rssi_polls = 0;
+        self.roam_pending = false;
+        #[cfg(not(target_os = "espidf"))]
+        {
+            self.sim_connected_ticks = 0;
+        }
+    }
+
+    // ── Roaming ───────────────────────────────────────────────
+
+    /// Feed one RSSI sample into the roam trigger.
+    fn track_rssi(&mut self, rssi: Option<i8>) {
+        match rssi {
+            Some(r) if r < self.roam_rssi_dbm => {
+                self.weak_rssi_polls = self.weak_rssi_polls.saturating_add(1);
+                if self.weak_rssi_polls >= self.roam_samples && !self.roam_pending {
+                    info!(
+                        "WiFi: RSSI {} dBm below {} for {} polls, scheduling roam",
+                        r, self.roam_rssi_dbm, self.weak_rssi_polls
+                    );
+                    self.roam_pending = true;
+                }
+            }
+            _ => self.weak_rssi_polls = 0,
+        }
+    }
+
+    /// Scan for a stronger BSSID of the current SSID and re-associate.
+    fn roam(&mut self) {
+        self.roam_pending = false;
+        self.weak_rssi_polls = 0;
+        let current = self.last_rssi.unwrap_or(i8::MIN);
+
+        let best = self
+            .platform_scan()
+            .into_iter()
+            .filter(|ap| ap.ssid == self.ssid)
+            .filter(|ap| ap.rssi >= current.saturating_add(ROAM_HYSTERESIS_DB))
+            .max_by_key(|ap| ap.rssi);
+        let Some(target) = best else {
+            info!("WiFi: no stronger AP for '{}', staying", self.ssid);
+            return;
+        };
+
+        info!(
+            "WiFi: roaming to {:02x?} ({} dBm, was {} dBm)",
+            target.bssid, target.rssi, current
+        );
+        if self.platform_roam(target.bssid).is_ok() {
+            self.last_rssi = self.platform_rssi();
+        } else {
+            warn!("WiFi: roam failed, entering reconnect");
+            self.state = WifiState::Reconnecting { attempt: 0 };
+            self.last_rssi = None;
+        }
+    }
+
     // ── Platform-specific ─────────────────────────────────────
 
     #[cfg(target_os = "espidf")]
@@ -279,6 +561,44 @@ impl WifiAdapter {
         info!("WiFi(sim): disconnected");
     }
 
+    /// Re-associate to `bssid` of the configured SSID.  The driver
+    /// switches APs without a full station restart.
+    #[cfg(target_os = "espidf")]
+    fn platform_roam(&mut self, bssid: [u8; 6]) -> Result<(), ConnectivityError> {
+        use esp_idf_svc::sys::*;
+        unsafe {
+            let mut wifi_cfg: wifi_config_t = core::mem::zeroed();
+            let ret = esp_wifi_get_config(wifi_interface_t_WIFI_IF_STA, &mut wifi_cfg);
+            if ret != ESP_OK as i32 {
+                error!("WiFi: esp_wifi_get_config failed ({})", ret);
+                return Err(ConnectivityError::ConnectionFailed);
+            }
+            wifi_cfg.sta.bssid_set = true;
+            wifi_cfg.sta.bssid = bssid;
+
+            let ret = esp_wifi_set_config(wifi_interface_t_WIFI_IF_STA, &mut wifi_cfg);
+            if ret != ESP_OK as i32 {
+                error!("WiFi: esp_wifi_set_config failed ({})", ret);
+                return Err(ConnectivityError::ConnectionFailed);
+            }
+
+            let ret = esp_wifi_connect();
+            if ret != ESP_OK as i32 {
+                error!("WiFi: roam esp_wifi_connect failed ({})", ret);
+                return Err(ConnectivityError::ConnectionFailed);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    #[allow(clippy::unnecessary_wraps)] // mirrors the fallible espidf variant
+    fn platform_roam(&mut self, bssid: [u8; 6]) -> Result<(), ConnectivityError> {
+        info!("WiFi(sim): re-associated to {:02x?}", bssid);
+        self.sim_connected_ticks = 0;
+        Ok(())
+    }
+
     #[cfg(target_os = "espidf")]
     fn platform_is_connected(&self) -> bool {
         use esp_idf_svc::sys::*;
@@ -322,15 +642,82 @@ impl WifiAdapter {
         }
     }
 
+    #[cfg(target_os = "espidf")]
+    fn platform_scan(&mut self) -> heapless::Vec<ScanEntry, MAX_SCAN_RESULTS> {
+        use esp_idf_svc::sys::*;
+        let mut out = heapless::Vec::new();
+        unsafe {
+            // Blocking all-channel scan (~2 s); STA mode need not be associated.
+            let ret = esp_wifi_scan_start(core::ptr::null(), true);
+            if ret != ESP_OK as i32 {
+                warn!("WiFi: esp_wifi_scan_start failed ({})", ret);
+                return out;
+            }
+
+            let mut records: [wifi_ap_record_t; MAX_SCAN_RESULTS] = core::mem::zeroed();
+            let mut count = MAX_SCAN_RESULTS as u16;
+            let ret = esp_wifi_scan_get_ap_records(&mut count, records.as_mut_ptr());
+            if ret != ESP_OK as i32 {
+                warn!("WiFi: esp_wifi_scan_get_ap_records failed ({})", ret);
+                return out;
+            }
+
+            for rec in &records[..count as usize] {
+                let len = rec
+                    .ssid
+                    .iter()
+                    .position(|&b| b == 0)
+                    .unwrap_or(rec.ssid.len());
+                let Ok(name) = core::str::from_utf8(&rec.ssid[..len]) else {
+                    continue;
+                };
+                let mut ssid = heapless::String::new();
+                let _ = ssid.push_str(name);
+                let _ = out.push(ScanEntry {
+                    ssid,
+                    bssid: rec.bssid,
+                    rssi: rec.rssi,
+                    auth_mode: AuthMode::from_raw(rec.authmode),
+                });
+            }
+        }
+        info!("WiFi(espidf): scan found {} APs", out.len());
+        out
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    fn platform_scan(&mut self) -> heapless::Vec<ScanEntry, MAX_SCAN_RESULTS> {
+        const SIM_APS: [(&str, u8, i8, AuthMode); 4] = [
+            ("PetFilter-Home", 0x01, -42, AuthMode::Wpa2Psk),
+            ("Neighbour-5G", 0x02, -67, AuthMode::Wpa2Wpa3Psk),
+            ("CoffeeShop", 0x03, -74, AuthMode::Open),
+            ("IoT-Legacy", 0x04, -81, AuthMode::WpaWpa2Psk),
+        ];
+        let mut out = heapless::Vec::new();
+        for (name, id, rssi, auth_mode) in SIM_APS {
+            let mut ssid = heapless::String::new();
+            let _ = ssid.push_str(name);
+            let _ = out.push(ScanEntry {
+                ssid,
+                bssid: [0x02, 0x00, 0x00, 0x00, 0x00, id],
+                rssi,
+                auth_mode,
+            });
+        }
+        info!("WiFi(sim): scan returned {} fake APs", out.len());
+        out
+    }
+
     #[cfg(not(target_os = "espidf"))]
     fn platform_rssi(&self) -> Option<i8> {
         if self.state != WifiState::Connected {
             return None;
         }
-        // Simulate RSSI between -45 (strong) and -75 (weak) using counter
-        // as a simple oscillation, reflecting realistic environment variation.
-        let oscillation = ((self.sim_connect_counter % 12) as i8) - 6; // -6..+5
-        Some(-60_i8.saturating_add(oscillation))
+        // Triangle wave between -50 (strong) and -80 (weak) over 60 polls,
+        // so each cycle spends ~9 consecutive polls below the roam threshold.
+        let phase = (self.sim_connected_ticks % 60) as i8;
+        let depth = if phase < 30 { phase } else { 60 - phase };
+        Some(-50 - depth)
     }
 }
 
@@ -352,9 +739,7 @@ impl ConnectivityPort for WifiAdapter {
 
         match self.platform_connect() {
             Ok(()) => {
-                self.state = WifiState::Connected;
-                self.backoff_secs = 2;
-                self.last_rssi = self.platform_rssi();
+                self.mark_connected();
                 info!("WiFi: connected (RSSI={:?})", self.last_rssi);
                 Ok(())
             }
@@ -370,6 +755,8 @@ impl ConnectivityPort for WifiAdapter {
         self.platform_disconnect();
         self.state = WifiState::Disconnected;
         self.last_rssi = None;
+        self.weak_rssi_polls = 0;
+        self.roam_pending = false;
         info!("WiFi: disconnected");
     }
 
@@ -385,9 +772,7 @@ impl ConnectivityPort for WifiAdapter {
                     attempt, self.backoff_secs
                 );
                 if self.platform_connect().is_ok() {
-                    self.state = WifiState::Connected;
-                    self.backoff_secs = 2;
-                    self.last_rssi = self.platform_rssi();
+                    self.mark_connected();
                     info!("WiFi: reconnected (RSSI={:?})", self.last_rssi);
                 } else {
                     self.backoff_secs = (self.backoff_secs * 2).min(MAX_BACKOFF_SECS);
@@ -401,16 +786,14 @@ impl ConnectivityPort for WifiAdapter {
                 let still_up = self.sim_advance_tick();
                 #[cfg(target_os = "espidf")]
                 let still_up = self.platform_is_connected();
-                if still_up {
+                if still_up && self.roam_pending {
+                    self.roam();
+                } else if still_up {
                     self.last_rssi = self.platform_rssi();
+                    self.track_rssi(self.last_rssi);
                 } else {
                     warn!("WiFi: connection lost, entering reconnect");
-                    self.state = WifiState::Reconnecting { attempt: 0 };
-                    self.last_rssi = None;
-                    #[cfg(not(target_os = "espidf"))]
-                    {
-                        self.sim_connected_ticks = 0;
-                    }
+                    self.enter_reconnect();
                 }
             }
             _ => {}
@@ -435,6 +818,10 @@ impl ConnectivityPort for WifiAdapter {
     fn rssi(&self) -> Option<i8> {
         self.last_rssi
     }
+
+    fn scan(&mut self) -> heapless::Vec<ScanEntry, MAX_SCAN_RESULTS> {
+        self.platform_scan()
+    }
 }
 
 // ───────────────────────────────────────────────────────────────
@@ -456,8 +843,12 @@ impl EventSink for WifiAdapter {
             AppEvent::StateChanged { from, to } => {
                 info!("WiFi/event: state {:?} -> {:?}", from, to);
             }
-            AppEvent::FaultDetected(flags) => {
-                warn!("WiFi/event: fault detected flags=0b{:08b}", flags);
+            AppEvent::FaultDetected { flags, is_test } => {
+                warn!(
+                    "WiFi/event: fault detected flags=0b{:08b}{}",
+                    flags,
+                    if *is_test { " (test)" } else { "" }
+                );
             }
             AppEvent::FaultCleared => {
                 info!("WiFi/event: faults cleared");
@@ -465,6 +856,9 @@ impl EventSink for WifiAdapter {
             AppEvent::Started(state) => {
                 info!("WiFi/event: started in {:?}", state);
             }
+            AppEvent::SelfTestFailed(report) => {
+                warn!("WiFi/event: sensor self-test failed {:?}", report);
+            }
         }
     }
 }
@@ -532,4 +926,155 @@ mod tests {
         a.connect().unwrap();
         assert_eq!(a.connect(), Err(ConnectivityError::AlreadyConnected));
     }
+
+    #[test]
+    fn sim_scan_returns_fixed_list() {
+        let mut a = WifiAdapter::new();
+        let aps = a.scan();
+        assert_eq!(aps.len(), 4);
+        assert_eq!(aps[0].ssid.as_str(), "PetFilter-Home");
+        assert_eq!(aps[0].rssi, -42);
+        assert_eq!(aps[0].auth_mode, AuthMode::Wpa2Psk);
+        assert_eq!(aps[2].auth_mode, AuthMode::Open);
+        assert!(!a.is_connected(), "scan must not require a connection");
+    }
+
+    fn connected(ssid: &str) -> WifiAdapter {
+        let mut a = WifiAdapter::new();
+        a.set_credentials(ssid, "password1").unwrap();
+        a.connect().unwrap();
+        a
+    }
+
+    #[test]
+    fn sustained_low_rssi_schedules_roam() {
+        let mut a = connected("PetFilter-Home");
+        for _ in 0..DEFAULT_ROAM_SAMPLES - 1 {
+            a.track_rssi(Some(-80));
+        }
+        assert!(!a.is_roam_pending());
+        a.track_rssi(Some(-80));
+        assert!(a.is_roam_pending());
+
+        // The next poll scans and moves to the -42 dBm sim AP in place.
+        a.last_rssi = Some(-80);
+        a.roam();
+        assert!(!a.is_roam_pending());
+        assert_eq!(a.state(), WifiState::Connected);
+    }
+
+    #[test]
+    fn transient_rssi_dip_does_not_roam() {
+        let mut a = connected("PetFilter-Home");
+        for _ in 0..3 {
+            for _ in 0..DEFAULT_ROAM_SAMPLES - 1 {
+                a.track_rssi(Some(-80));
+            }
+            a.track_rssi(Some(-60));
+        }
+        a.track_rssi(None);
+        assert!(!a.is_roam_pending());
+    }
+
+    #[test]
+    fn reapplying_the_roam_threshold_keeps_the_weak_count() {
+        let mut a = connected("PetFilter-Home");
+        for _ in 0..DEFAULT_ROAM_SAMPLES {
+            a.set_roam_threshold(DEFAULT_ROAM_RSSI_DBM, DEFAULT_ROAM_SAMPLES);
+            a.track_rssi(Some(-80));
+        }
+        assert!(a.is_roam_pending());
+
+        // A new trigger starts the count afresh.
+        let mut b = connected("PetFilter-Home");
+        for _ in 0..DEFAULT_ROAM_SAMPLES - 1 {
+            b.track_rssi(Some(-80));
+        }
+        b.set_roam_threshold(-70, DEFAULT_ROAM_SAMPLES);
+        b.track_rssi(Some(-80));
+        assert!(!b.is_roam_pending());
+    }
+
+    #[test]
+    fn sim_rssi_oscillation_triggers_roam() {
+        let mut a = connected("PetFilter-Home");
+        let mut scheduled = false;
+        for _ in 0..60 {
+            a.poll();
+            scheduled |= a.is_roam_pending();
+        }
+        assert!(scheduled, "sim RSSI trough must trigger a roam");
+        assert_eq!(a.state(), WifiState::Connected);
+
+        // A stricter threshold the trough never sustains does not roam.
+        let mut b = connected("PetFilter-Home");
+        b.set_roam_threshold(-78, 5);
+        for _ in 0..60 {
+            b.poll();
+            assert!(!b.is_roam_pending());
+        }
+    }
+
+    #[test]
+    fn link_events_drive_reconnect_and_recovery() {
+        let mut a = connected("TestNet");
+        a.roam_pending = true;
+
+        a.on_link_event(LinkEvent::Disconnected);
+        assert_eq!(a.state(), WifiState::Reconnecting { attempt: 0 });
+        assert!(a.rssi().is_none());
+        assert!(!a.is_roam_pending());
+
+        a.on_link_event(LinkEvent::Disconnected);
+        assert_eq!(a.state(), WifiState::Reconnecting { attempt: 0 });
+
+        a.on_link_event(LinkEvent::GotIp);
+        assert_eq!(a.state(), WifiState::Connected);
+        assert!(a.rssi().is_some());
+    }
+
+    #[test]
+    fn link_events_ignored_after_intentional_disconnect() {
+        let mut a = connected("TestNet");
+        a.disconnect();
+        a.on_link_event(LinkEvent::Disconnected);
+        a.on_link_event(LinkEvent::GotIp);
+        assert_eq!(a.state(), WifiState::Disconnected);
+    }
+
+    #[test]
+    fn latched_link_events_keep_their_order() {
+        let mut a = connected("TestNet");
+        assert_eq!(a.apply_link_events(), None);
+
+        // A flap that recovers before the main loop drains still resets
+        // roam state on its way back to Connected.
+        a.roam_pending = true;
+        latch_link_event(LinkEvent::Disconnected);
+        latch_link_event(LinkEvent::GotIp);
+        assert_eq!(a.apply_link_events(), None);
+        assert_eq!(a.state(), WifiState::Connected);
+        assert!(!a.is_roam_pending());
+
+        latch_link_event(LinkEvent::GotIp);
+        latch_link_event(LinkEvent::Disconnected);
+        assert_eq!(
+            a.apply_link_events(),
+            Some(WifiState::Reconnecting { attempt: 0 })
+        );
+
+        latch_link_event(LinkEvent::GotIp);
+        assert_eq!(a.apply_link_events(), Some(WifiState::Connected));
+        assert_eq!(a.apply_link_events(), None);
+    }
+
+    #[test]
+    fn roam_without_stronger_ap_stays_connected() {
+        let mut a = connected("TestNet");
+        a.last_rssi = Some(-80);
+        a.roam_pending = true;
+        a.roam();
+        assert!(!a.is_roam_pending());
+        assert_eq!(a.state(), WifiState::Connected);
+    }
 }
@@ -7,17 +7,70 @@
 use crate::config::SystemConfig;
 use crate::fsm::StateId;
 
+/// Origin of a scrub start/stop request.
+///
+/// Used by the [`AppService`](super::service::AppService) to arbitrate
+/// between conflicting requests and reported back in status/telemetry so
+/// clients can see who owns the current scrub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandSource {
+    /// Physical button on the device.
+    Button,
+    /// Authenticated RPC client (BLE or TCP).
+    Rpc,
+    /// Scheduler or other automatic trigger.
+    Schedule,
+}
+
+impl CommandSource {
+    /// Manual sources (a person acting right now) outrank automatic ones.
+    pub fn is_manual(self) -> bool {
+        !matches!(self, Self::Schedule)
+    }
+}
+
 /// Commands that external adapters can send into the application core.
 #[derive(Debug, Clone)]
 pub enum AppCommand {
     /// Trigger an immediate scrub cycle (e.g. scheduled or manual).
-    StartScrub,
+    StartScrub(CommandSource),
+
+    /// End the current scrub and return to Idle.  A manual stop opens a
+    /// short lockout during which starts from other sources are rejected.
+    StopScrub(CommandSource),
 
     /// Force the FSM into a specific state (debug / testing only).
     ForceState(StateId),
 
+    /// Pump the reservoir empty for cleaning.  Bypasses NH3 logic and
+    /// returns to Idle once both tanks read empty or the drain cap fires.
+    DrainWater,
+
+    /// Report `ppm` in place of the NH3 sensor for `duration_secs` so the
+    /// scrub cycle can be validated on site.  Rejected while faulted.
+    InjectNh3 { ppm: f32, duration_secs: u16 },
+
+    /// Raise the single `SafetyFault` bit `fault_bit` for `duration_secs`
+    /// without a real hazard, so the Error pathway and LED can be checked
+    /// on site.  Rejected while a real fault is present.
+    TestFault { fault_bit: u8, duration_secs: u16 },
+
+    /// Run a relay-feedback experiment on the pump during a scrub and
+    /// derive suggested flow PID gains from the flow response.  Aborts
+    /// on any fault, when the scrub ends, or after
+    /// `AUTOTUNE_TIMEOUT_SECS`.
+    StartAutoTune,
+
+    /// Apply the pending auto-tune suggestion to the flow PID and save
+    /// it with the config.  Rejected when no completed run is waiting.
+    ConfirmAutoTune,
+
+    /// Abort a running auto-tune or discard its pending suggestion.
+    CancelAutoTune,
+
     /// Hot-reload configuration (e.g. from BLE provisioning or NVS).
-    UpdateConfig(SystemConfig),
+    /// Boxed so the other commands stay small.
+    UpdateConfig(Box<SystemConfig>),
 
     /// Explicitly persist the current config to NVS immediately.
     SaveConfig,
@@ -5,7 +5,14 @@
 //! side decide what to do with them — log to serial, publish over MQTT,
 //! update a BLE characteristic, etc.
 
+use crate::config::PowerProfile;
 use crate::fsm::StateId;
+use crate::fsm::context::Tank;
+
+use crate::sensors::SelfTestReport;
+
+use super::commands::CommandSource;
+use super::service::ZoneId;
 
 /// Structured events emitted by the application core.
 #[derive(Debug, Clone)]
@@ -16,19 +23,25 @@ pub enum AppEvent {
     /// The FSM transitioned between states.
     StateChanged { from: StateId, to: StateId },
 
-    /// One or more safety faults were raised.
-    FaultDetected(u8),
+    /// One or more safety faults were raised; `is_test` when they come
+    /// from a test fault rather than a real condition.
+    FaultDetected { flags: u8, is_test: bool },
 
     /// All safety faults have been cleared.
     FaultCleared,
 
     /// The application service has started (carries initial state).
     Started(StateId),
+
+    /// The boot-time sensor self-test found at least one faulty sensor.
+    SelfTestFailed(SelfTestReport),
 }
 
 /// A point-in-time telemetry snapshot suitable for logging or transmission.
 #[derive(Debug, Clone)]
 pub struct TelemetryData {
+    /// Filter loop the snapshot describes.
+    pub zone: ZoneId,
     pub state: StateId,
     pub nh3_ppm: f32,
     pub nh3_avg_ppm: f32,
@@ -36,9 +49,53 @@ pub struct TelemetryData {
     pub temperature_c: f32,
     pub tank_a_ok: bool,
     pub tank_b_ok: bool,
+    /// Tank the source valve is drawing from.
+    pub source_tank: Tank,
     pub pump_duty: u8,
     pub uvc_duty: u8,
     pub fault_flags: u8,
     /// WiFi signal strength in dBm; None when not connected (e.g. BLE-only).
     pub wifi_rssi: Option<i8>,
+    /// Who started the current scrub; None when idle or sensor-triggered.
+    pub scrub_source: Option<CommandSource>,
+    /// UVC is on but still warming up; scrubbing is not yet effective.
+    pub uvc_warming: bool,
+    /// NH3 sensor is still in its post-boot soak; NH3 triggers are ignored.
+    pub sensor_warming: bool,
+    /// NH3 readings are synthetic (field-validation injection).
+    pub nh3_injected: bool,
+    /// `fault_flags` carries a synthetic test fault, not a real hazard.
+    pub fault_test: bool,
+    /// Pump is running the low-duty priming phase after a long idle.
+    pub priming: bool,
+    /// Non-fatal advisory bitmask (see `Advisory`).
+    pub advisory_flags: u8,
+    /// Water flushed in the current (or last) purge, in mL.
+    pub purge_volume_ml: f32,
+    /// Water pumped out in the current (or last) drain, in mL.
+    pub drain_volume_ml: f32,
+    /// The last drain finished with both tanks empty.
+    pub drain_complete: bool,
+    /// Faults have cleared; Error is waiting out the recovery cooldown.
+    pub recovering: bool,
+    /// Power profile selected in the config.
+    pub power_profile: PowerProfile,
+    /// Smoothed NH3 is inside the alarm band (see `nh3_alarm_high_ppm`).
+    pub nh3_alarm: bool,
+}
+
+/// Compact telemetry point kept in the on-device history ring so a
+/// reconnecting client can backfill its charts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetrySample {
+    /// Service uptime when the sample was taken.
+    pub uptime_secs: u32,
+    pub state: StateId,
+    pub nh3_ppm: f32,
+    pub nh3_avg_ppm: f32,
+    pub flow_ml_per_min: f32,
+    pub temperature_c: f32,
+    pub pump_duty: u8,
+    pub uvc_duty: u8,
+    pub fault_flags: u8,
 }
@@ -9,3 +9,4 @@ pub mod commands;
 pub mod events;
 pub mod ports;
 pub mod service;
+pub mod zones;
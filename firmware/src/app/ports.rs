@@ -15,7 +15,8 @@
 //! - All port errors are typed — callers must handle every variant explicitly.
 
 use crate::config::SystemConfig;
-use crate::fsm::context::SensorSnapshot;
+use crate::fsm::context::{SensorSnapshot, Tank};
+use crate::sensors::SelfTestReport;
 
 // ───────────────────────────────────────────────────────────────
 // Sensor port (driven adapter: hardware → domain)
@@ -26,8 +27,32 @@ pub trait SensorPort {
     /// Read every sensor and return a unified snapshot.
     fn read_all(&mut self, elapsed_secs: f32) -> SensorSnapshot;
 
-    /// Fast ammonia-only read for high-frequency sampling paths.
-    fn read_ammonia_fast(&mut self) -> f32;
+    /// Fast-tick ammonia oversample, folded into the next snapshot's
+    /// NH3 mean and peak.  `None` when this tick is not a sampling point.
+    fn read_ammonia_fast(&mut self) -> Option<f32>;
+
+    /// Take `samples` NH3 readings per control interval, spread over
+    /// `fast_ticks_per_interval` fast ticks.  Adapters that do not
+    /// oversample ignore this.
+    fn set_nh3_oversample(&mut self, _samples: u8, _fast_ticks_per_interval: u32) {}
+
+    /// Take `samples` ADC conversions per NH3 sample and average them
+    /// with the outliers dropped.  Adapters without an ADC ignore this.
+    fn set_nh3_adc_samples(&mut self, _samples: u8) {}
+
+    /// Drop flow pulses closer than `min_pulse_us` to the previous one
+    /// (0 = off).  Adapters without a pulse counter ignore this.
+    fn set_flow_glitch_filter_us(&mut self, _min_pulse_us: u16) {}
+
+    /// Read the UVC interlock as closed on LOW (`true`) or on HIGH.
+    /// Adapters without an interlock input ignore this.
+    fn set_interlock_active_low(&mut self, _active_low: bool) {}
+
+    /// One-shot plausibility check of every sensor, run once at boot.
+    /// Adapters without real hardware report every sensor as passing.
+    fn self_test(&mut self) -> SelfTestReport {
+        SelfTestReport::default()
+    }
 }
 
 // ───────────────────────────────────────────────────────────────
@@ -42,6 +67,10 @@ pub trait ActuatorPort {
     /// Immediately stop the pump.
     fn stop_pump(&mut self);
 
+    /// Point the source valve at `tank`.  Adapters without a valve
+    /// ignore this.
+    fn select_tank(&mut self, _tank: Tank) {}
+
     /// Enable UVC LEDs at the given duty (0–100).
     fn enable_uvc(&mut self, duty: u8);
 
@@ -171,6 +200,9 @@ pub enum ConfigError {
     StorageFull,
     /// Generic I/O error from the storage backend.
     IoError,
+    /// Config storage is in safe mode after repeated corrupt loads; saves
+    /// are refused rather than silently dropped.
+    SafeMode,
 }
 
 /// Errors from [`StoragePort`] operations.
@@ -184,6 +216,8 @@ pub enum StorageError {
     IoError,
     /// Encryption or decryption failed (wrong key, corrupted blob).
     EncryptionError,
+    /// Stored blob failed its integrity check (tampered or corrupted).
+    IntegrityFailure,
 }
 
 impl core::fmt::Display for ConfigError {
@@ -194,6 +228,7 @@ impl core::fmt::Display for ConfigError {
             Self::ValidationFailed(msg) => write!(f, "validation failed: {}", msg),
             Self::StorageFull => write!(f, "storage full"),
             Self::IoError => write!(f, "I/O error"),
+            Self::SafeMode => write!(f, "config storage in safe mode"),
         }
     }
 }
@@ -205,6 +240,7 @@ impl core::fmt::Display for StorageError {
             Self::Full => write!(f, "storage full"),
             Self::IoError => write!(f, "I/O error"),
             Self::EncryptionError => write!(f, "encryption error"),
+            Self::IntegrityFailure => write!(f, "integrity check failed"),
         }
     }
 }
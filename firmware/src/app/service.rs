@@ -1,6 +1,6 @@
 //! Application service — the hexagonal core.
 //!
-//! [`AppService`] owns the FSM, safety supervisor, and shared context.
+//! [`AppService`] owns the FSM, safety supervisor, scheduler, and shared context.
 //! It exposes a clean, hardware-agnostic API.  All I/O flows through
 //! port traits injected at call sites, making the entire service
 //! testable with mock adapters.
@@ -11,36 +11,144 @@
 //! ActuatorPort ◀──│  FSM · Safety · PID     │
 //!                 └────────────────────────┘
 //! ```
+//!
+//! One service drives one filter loop (reservoir, NH3 sensor, pump).
+//! Installations with several loops run one instance per [`ZoneId`], each
+//! with its own config and hardware, from the same event loop; a
+//! [`ZoneSet`](super::zones::ZoneSet) ties them to one safety trip.
+//! Telemetry is tagged with the zone it came from.
 
 use log::{info, warn};
 
 use crate::config::SystemConfig;
+use crate::control::alarm::HysteresisAlarm;
+use crate::control::autotune::{RelayTuneConfig, RelayTuner, TuneStatus, TuneStep};
+use crate::control::derate::derate_duty;
+use crate::control::ema::Ema;
 use crate::control::pid::PidController;
-use crate::fsm::context::FsmContext;
+use crate::diagnostics::FlowTotalizer;
+use crate::error::{Advisory, SafetyFault};
+use crate::fsm::context::{FsmContext, Nh3Injection};
 use crate::fsm::states::build_state_table;
 use crate::fsm::{Fsm, StateId};
 use crate::safety::SafetySupervisor;
+use crate::scheduler::Scheduler;
+use crate::sensors::SelfTestReport;
 
-use super::commands::AppCommand;
-use super::events::{AppEvent, TelemetryData};
+use super::commands::{AppCommand, CommandSource};
+use super::events::{AppEvent, TelemetryData, TelemetrySample};
 use super::ports::{ActuatorPort, EventSink, SensorPort};
 
+/// After a brown-out warning, auto-save stays suspended until the supply
+/// has held up this long — a flash write that loses power mid-page is
+/// worse than a lost setting.
+const BROWNOUT_SAVE_HOLD_SECS: f32 = 30.0;
+
+/// Hard limit on an auto-tune run.  Flow follows pump duty within
+/// seconds, so a few relay periods fit comfortably.
+pub const AUTOTUNE_TIMEOUT_SECS: f32 = 300.0;
+
+/// Auto-tune relay swing: the pump alternates between the bottom and
+/// top of the PID's duty range (10 % and 100 %).
+const AUTOTUNE_BIAS_DUTY: f32 = 55.0;
+const AUTOTUNE_STEP_DUTY: f32 = 45.0;
+
+/// Dead band around the auto-tune setpoint, wide enough to ride over
+/// flow pulse-count quantisation.
+const AUTOTUNE_HYSTERESIS_ML_PER_MIN: f32 = 20.0;
+
+/// Relay periods averaged into an auto-tune result.
+const AUTOTUNE_CYCLES: u8 = 3;
+
+/// Samples kept in the telemetry history ring (~1.5 KiB of RAM).
+pub const TELEMETRY_HISTORY_LEN: usize = 64;
+
+/// Spacing of history samples; with [`TELEMETRY_HISTORY_LEN`] the ring
+/// covers a little over five minutes.
+pub const TELEMETRY_HISTORY_INTERVAL_MS: u64 = 5_000;
+
+/// Longest window an [`AppCommand::InjectNh3`] may override the sensor.
+pub const MAX_NH3_INJECT_SECS: u16 = 600;
+
+/// Longest an [`AppCommand::TestFault`] may hold its synthetic fault.
+pub const MAX_TEST_FAULT_SECS: u16 = 300;
+
+/// Identifies one independent filter loop.
+pub type ZoneId = u8;
+
+/// Zone of a single-loop installation.
+pub const DEFAULT_ZONE: ZoneId = 0;
+
 // ───────────────────────────────────────────────────────────────
 // AppService
 // ───────────────────────────────────────────────────────────────
 
 /// The application service orchestrates all domain logic.
 pub struct AppService {
+    zone: ZoneId,
     fsm: Fsm,
     ctx: FsmContext,
     safety: SafetySupervisor,
+    /// Timer-based scrub schedules (ticked by the main loop).
+    scheduler: Scheduler,
     /// PID controller for closed-loop pump flow regulation.
     pid: PidController,
-    /// Seconds per control tick (derived from config).
-    tick_secs: f32,
+    /// Smooths the measured flow fed to the PID (`flow_filter_tau_ms`).
+    flow_filter: Ema,
+    /// `ctx.commands.pump_duty` is the PID's output, already held under
+    /// the thermal derate by its output limit.
+    pid_driving: bool,
+    /// Relay experiment in progress; owns pump duty while `Some`.
+    tuner: Option<RelayTuner>,
+    /// Outcome of the latest auto-tune run, held until confirmed or
+    /// cancelled.
+    tune_status: TuneStatus,
     tick_count: u64,
+    /// Real safety trips into Error since startup; test faults are not
+    /// counted.
+    fault_count: u32,
     config_dirty: bool,
-    dirty_since_tick: u64,
+    /// `uptime_ms` when the config first went dirty.
+    dirty_since_ms: u64,
+    /// Who started the scrub currently in progress (`None` when idle or
+    /// when the FSM activated on its own from sensor readings).
+    scrub_source: Option<CommandSource>,
+    /// Source and `uptime_ms` of the last manual stop (drives the stop
+    /// lockout).
+    last_stop: Option<(CommandSource, u64)>,
+    /// `uptime_ms` of the most recent brown-out warning.
+    last_brownout_ms: Option<u64>,
+    /// Measured time since the first tick, summed from tick `dt`s.
+    uptime_ms: u64,
+    /// Recent telemetry, oldest first.
+    history: heapless::Deque<TelemetrySample, TELEMETRY_HISTORY_LEN>,
+    /// Uptime at which the next history sample is due.
+    next_history_ms: u64,
+    /// High-ammonia alarm on its own band, separate from the FSM's
+    /// activate/deactivate thresholds.
+    nh3_alarm: HysteresisAlarm,
+    /// The alarm raised or cleared since the main loop last asked.
+    nh3_alarm_changed: bool,
+    /// Config applied since the main loop last asked, by any source.
+    config_changed: bool,
+    /// FSM state the main loop last reported to clients.
+    reported_state: StateId,
+    /// Synthetic fault raised by [`AppCommand::TestFault`].
+    test_fault: Option<TestFault>,
+    /// Faults this zone's own supervisor raised on the latest tick.
+    local_faults: u8,
+    /// Faults held by the other zones of a multi-zone unit.
+    shared_faults: u8,
+    /// Lifetime and since-service water totals, fed from the flow sensor.
+    flow_totals: FlowTotalizer,
+}
+
+/// A safety fault bit raised on request rather than by a sensor.
+#[derive(Debug, Clone, Copy)]
+struct TestFault {
+    mask: u8,
+    /// Time left before the fault drops (seconds).
+    remaining_secs: f32,
 }
 
 impl AppService {
@@ -48,30 +156,61 @@ impl AppService {
     ///
     /// Does **not** start the FSM — call [`start`] or [`start_from`] next.
     pub fn new(config: SystemConfig) -> Self {
-        let tick_secs = config.control_loop_interval_ms as f32 / 1000.0;
+        Self::for_zone(DEFAULT_ZONE, config)
+    }
+
+    /// Construct the service for filter loop `zone`.
+    pub fn for_zone(zone: ZoneId, config: SystemConfig) -> Self {
         let pump_flow = config.pump_flow_ml_per_min as f32;
         let safety = SafetySupervisor::new(&config);
-        let ctx = FsmContext::new(config);
-        let state_table = build_state_table();
-        let fsm = Fsm::new(state_table, StateId::Idle);
-
+        let mut scheduler = Scheduler::new();
+        if let Some(quiet) = config.quiet_hours {
+            scheduler.set_quiet_hours(quiet);
+        }
+        scheduler.set_paused_labels(&config.paused_schedules);
+        scheduler.set_periodic(config.periodic_schedule);
         let mut pid = PidController::new(
-            2.0, // Kp — proportional gain
-            0.5, // Ki — integral gain (slow wind-up for steady-state)
-            0.1, // Kd — derivative gain (dampen oscillation)
+            config.pump_pid_kp,
+            config.pump_pid_ki,
+            config.pump_pid_kd,
             pump_flow,
         );
         pid.set_limits(0.0, 100.0);
+        let flow_filter = Ema::new(f32::from(config.flow_filter_tau_ms) / 1000.0);
+
+        let ctx = FsmContext::new(config);
+        let state_table = build_state_table();
+        let fsm = Fsm::new(state_table, StateId::Idle);
 
         Self {
+            zone,
             fsm,
             ctx,
             safety,
+            scheduler,
             pid,
-            tick_secs,
+            flow_filter,
+            pid_driving: false,
+            tuner: None,
+            tune_status: TuneStatus::Idle,
             tick_count: 0,
+            fault_count: 0,
             config_dirty: false,
-            dirty_since_tick: 0,
+            dirty_since_ms: 0,
+            scrub_source: None,
+            last_stop: None,
+            last_brownout_ms: None,
+            uptime_ms: 0,
+            history: heapless::Deque::new(),
+            next_history_ms: 0,
+            nh3_alarm: HysteresisAlarm::new(),
+            nh3_alarm_changed: false,
+            config_changed: false,
+            reported_state: StateId::Idle,
+            test_fault: None,
+            local_faults: 0,
+            shared_faults: 0,
+            flow_totals: FlowTotalizer::for_zone(zone),
         }
     }
 
@@ -79,19 +218,49 @@ impl AppService {
 
     /// Start the FSM in its default initial state (Idle).
     pub fn start(&mut self, sink: &mut impl EventSink) {
+        // Cold boot: the NH3 sensor needs its soak before Idle trusts it.
+        self.ctx.sensor_warming = self.ctx.config.nh3_warmup_secs > 0;
         self.fsm.start(&mut self.ctx);
+        self.reported_state = self.fsm.current_state();
         sink.emit(&AppEvent::Started(self.fsm.current_state()));
         info!("AppService started in {:?}", self.fsm.current_state());
     }
 
     /// Start the FSM and immediately jump to `state` (e.g. after ULP wake).
+    /// Skips the NH3 warm-up soak: the sensor stayed powered while asleep.
     pub fn start_from(&mut self, state: StateId, sink: &mut impl EventSink) {
         self.fsm.start(&mut self.ctx);
         self.fsm.force_transition(state, &mut self.ctx);
+        self.reported_state = state;
         sink.emit(&AppEvent::Started(state));
         info!("AppService started from {:?}", state);
     }
 
+    /// Run the one-shot sensor self-test and record the result.
+    ///
+    /// Failures are emitted as [`AppEvent::SelfTestFailed`].  A critical
+    /// failure inhibits entry to Active when `self_test_blocks_active` is
+    /// set.  Call once after peripheral init, before the first tick.
+    pub fn run_self_test(
+        &mut self,
+        hw: &mut impl SensorPort,
+        sink: &mut impl EventSink,
+    ) -> SelfTestReport {
+        let report = hw.self_test();
+        if report.passed() {
+            info!("Sensor self-test passed");
+        } else {
+            warn!("Sensor self-test failed: {:?}", report);
+            sink.emit(&AppEvent::SelfTestFailed(report));
+        }
+        self.ctx.active_inhibited =
+            report.critical_failure() && self.ctx.config.self_test_blocks_active;
+        if self.ctx.active_inhibited {
+            warn!("Critical sensor failed self-test — scrubbing disabled");
+        }
+        report
+    }
+
     // ── Per-tick orchestration ────────────────────────────────
 
     /// Run one full control cycle: read sensors → safety → FSM → actuators.
@@ -99,35 +268,123 @@ impl AppService {
     /// The `hw` parameter satisfies **both** [`SensorPort`] and
     /// [`ActuatorPort`] — this avoids a double mutable borrow while
     /// keeping the port boundary explicit.
-    pub fn tick(&mut self, hw: &mut (impl SensorPort + ActuatorPort), sink: &mut impl EventSink) {
+    ///
+    /// `dt_secs` is the measured time since the previous tick.  Sensors
+    /// and `uptime_ms` see it as-is (pulse counts span the real gap); the
+    /// flow filter, PID and FSM timers see it as
+    /// [`SystemConfig::control_dt_secs`] adjusts it.
+    pub fn tick(
+        &mut self,
+        hw: &mut (impl SensorPort + ActuatorPort),
+        sink: &mut impl EventSink,
+        dt_secs: f32,
+    ) {
         self.tick_count += 1;
+        self.uptime_ms += (dt_secs.max(0.0) * 1000.0) as u64;
         let prev_state = self.fsm.current_state();
+        let dt = self.ctx.config.control_dt_secs(dt_secs);
+        self.ctx.tick_dt_secs = dt;
 
-        // 1. Read sensors via SensorPort
-        let snapshot = hw.read_all(self.tick_secs);
-        self.ctx.sensors = snapshot;
+        // 1. Read sensors via SensorPort, closing the NH3 oversampling
+        //    window; the (possibly updated) count applies to the next one.
+        //    Interlock polarity goes first so this reading already uses it.
+        hw.set_interlock_active_low(self.ctx.config.interlock_active_low);
+        let snapshot = hw.read_all(dt_secs.max(0.0));
+        let cfg = &self.ctx.config;
+        hw.set_nh3_oversample(
+            cfg.nh3_oversample_count,
+            cfg.power_timing().control_loop_interval_ms / cfg.sensor_read_interval_ms.max(1),
+        );
+        hw.set_nh3_adc_samples(cfg.nh3_adc_samples);
+        hw.set_flow_glitch_filter_us(cfg.flow_glitch_filter_us);
+        let injecting = self.ctx.nh3_injection.is_some();
+        self.ctx.update_sensors(snapshot);
+        if injecting && self.ctx.nh3_injection.is_none() {
+            info!("NH3 injection window over, back to sensor readings");
+        }
+        self.flow_totals
+            .accumulate(self.ctx.sensors.flow_ml_per_min, dt);
+        self.update_nh3_alarm();
 
         // 2. Safety evaluation
-        self.safety
-            .set_pump_commanded(self.ctx.commands.pump_duty > 0);
-        let faults = self.safety.evaluate(&snapshot);
+        // Priming does its own (advisory) flow check; the supervisor's
+        // grace period starts once priming hands over to the ramp.
+        // A soft-stop tail is winding down, not pumping for flow.
+        let pump_commanded =
+            self.ctx.commands.pump_duty > 0 && !self.ctx.priming && !self.ctx.soft_stop.is_active();
+        self.safety.set_pump_commanded(pump_commanded);
+        self.safety.set_tick_dt(dt);
+        let mut faults = self.safety.evaluate(&snapshot, &self.ctx.config);
+        if self.fsm.current_state() == StateId::Draining {
+            // Emptying the tanks is the point of a drain, not a fault.
+            faults &= !SafetyFault::WaterLevelLow.mask();
+        }
+        // A test fault stands in for the real evaluation while it lasts
+        // and gives way to any real fault at once.
+        if let Some(test) = self.test_fault.as_mut() {
+            if faults != 0 {
+                warn!("Test fault cancelled by real fault 0b{:08b}", faults);
+                self.test_fault = None;
+            } else if test.remaining_secs <= 0.0 {
+                info!("Test fault window over");
+                self.test_fault = None;
+            } else {
+                faults = test.mask;
+                test.remaining_secs -= dt;
+            }
+        }
+        // The other zones' faults trip this one as well: they share the
+        // enclosure, supply and UVC interlock.
+        self.local_faults = faults;
+        faults |= self.shared_faults;
         self.ctx.fault_flags = faults;
+        if faults != 0 && self.ctx.nh3_injection.take().is_some() {
+            warn!("NH3 injection cancelled by safety fault");
+        }
+        let stale = Advisory::SensorStale.mask();
+        self.ctx.advisory_flags =
+            (self.ctx.advisory_flags & !stale) | (self.safety.advisories() & stale);
 
         if faults != 0 && self.fsm.current_state() != StateId::Error {
-            warn!("Safety fault! flags=0b{:08b}", faults);
+            if self.test_fault.is_some() {
+                warn!("Safety fault (TEST)! flags=0b{:08b}", faults);
+            } else {
+                warn!("Safety fault! flags=0b{:08b}", faults);
+                self.fault_count = self.fault_count.saturating_add(1);
+            }
             self.fsm.force_transition(StateId::Error, &mut self.ctx);
-            sink.emit(&AppEvent::FaultDetected(faults));
+            sink.emit(&AppEvent::FaultDetected {
+                flags: faults,
+                is_test: self.test_fault.is_some(),
+            });
         }
 
         // 3. FSM tick (pure state logic)
         self.fsm.tick(&mut self.ctx);
 
         // 4. PID flow regulation — modulate pump duty based on actual vs target flow
-        if self.ctx.commands.pump_duty > 0 && snapshot.flow_detected {
-            let pid_duty = self.pid.compute(snapshot.flow_ml_per_min, self.tick_secs);
-            self.ctx.commands.pump_duty = (pid_duty as u8).clamp(10, 100);
-        } else if self.ctx.commands.pump_duty > 0 {
+        //    (priming and soft-stop ramps own pump duty until they complete,
+        //    a running auto-tune for as long as it lasts)
+        //    The thermal derate caps the PID's output rather than trimming
+        //    it afterwards, so the integral never winds up against a duty
+        //    the pump is not actually given.
+        let pump_sequenced =
+            self.ctx.priming || self.ctx.pump_ramping || self.ctx.soft_stop.is_active();
+        self.pid.set_limits(0.0, f32::from(self.derated(100)));
+        self.pid_driving = false;
+        if self.step_autotune(dt) {
             self.pid.reset();
+            self.flow_filter.reset();
+        } else if self.ctx.commands.pump_duty > 0 && snapshot.flow_detected && !pump_sequenced {
+            let flow = self.flow_filter.update(snapshot.flow_ml_per_min, dt);
+            let pid_duty = self.pid.compute(flow, dt);
+            self.ctx.commands.pump_duty = (pid_duty as u8).clamp(10, 100);
+            self.pid_driving = true;
+        } else {
+            if self.ctx.commands.pump_duty > 0 {
+                self.pid.reset();
+            }
+            self.flow_filter.reset();
         }
 
         // 5. Apply actuator commands via ActuatorPort
@@ -135,51 +392,144 @@ impl AppService {
 
         // 6. Emit state change if the FSM moved
         let new_state = self.fsm.current_state();
+        if matches!(new_state, StateId::Idle | StateId::Error) {
+            self.scrub_source = None;
+        }
         if new_state != prev_state {
             sink.emit(&AppEvent::StateChanged {
                 from: prev_state,
                 to: new_state,
             });
         }
+
+        // 7. Record a history sample when one is due
+        if self.uptime_ms >= self.next_history_ms {
+            self.record_history_sample();
+            self.next_history_ms = self.uptime_ms + TELEMETRY_HISTORY_INTERVAL_MS;
+        }
+    }
+
+    fn record_history_sample(&mut self) {
+        if self.history.is_full() {
+            self.history.pop_front();
+        }
+        let _ = self.history.push_back(TelemetrySample {
+            uptime_secs: (self.uptime_ms / 1000) as u32,
+            state: self.fsm.current_state(),
+            nh3_ppm: self.ctx.sensors.nh3_ppm,
+            nh3_avg_ppm: self.ctx.sensors.nh3_avg_ppm,
+            flow_ml_per_min: self.ctx.sensors.flow_ml_per_min,
+            temperature_c: self.ctx.sensors.temperature_c,
+            pump_duty: self.applied_pump_duty(),
+            uvc_duty: self.ctx.commands.uvc_duty,
+            fault_flags: self.ctx.fault_flags,
+        });
+    }
+
+    /// React to a brown-out warning: drop to Idle with every output off
+    /// and suspend auto-save for [`BROWNOUT_SAVE_HOLD_SECS`].
+    pub fn on_brownout(&mut self, hw: &mut impl ActuatorPort, sink: &mut impl EventSink) {
+        warn!("Brown-out warning — shutting outputs down");
+        self.last_brownout_ms = Some(self.uptime_ms);
+        let prev = self.fsm.current_state();
+        if prev != StateId::Idle {
+            self.fsm.force_transition(StateId::Idle, &mut self.ctx);
+            self.scrub_source = None;
+            sink.emit(&AppEvent::StateChanged {
+                from: prev,
+                to: StateId::Idle,
+            });
+        }
+        hw.all_off();
+    }
+
+    /// Whether a recent brown-out warning is holding off NVS writes.
+    pub fn in_brownout_hold(&self) -> bool {
+        self.last_brownout_ms.is_some_and(|at| {
+            let secs = self.uptime_ms.saturating_sub(at) as f32 / 1000.0;
+            secs < BROWNOUT_SAVE_HOLD_SECS
+        })
     }
 
     // ── Command handling ──────────────────────────────────────
 
     /// Process an external command (from scheduler, BLE, serial, etc.).
+    ///
+    /// Scrub start/stop requests are arbitrated by [`CommandSource`]:
+    ///
+    /// 1. A manual stop always wins and opens a lockout of
+    ///    `stop_lockout_secs` during which starts from any *other* source
+    ///    are rejected (the same source may change its mind).
+    /// 2. A manual start takes over a scrub started automatically, so a
+    ///    later automatic stop cannot end it.
+    /// 3. An automatic stop never ends a manually started scrub.
+    ///
+    /// Returns `false` when the command was rejected by arbitration.
     pub fn handle_command(
         &mut self,
         cmd: AppCommand,
         hw: &mut impl ActuatorPort,
         sink: &mut impl EventSink,
-    ) {
+    ) -> bool {
         match cmd {
-            AppCommand::StartScrub => {
-                if self.fsm.current_state() == StateId::Idle {
-                    let prev = self.fsm.current_state();
-                    self.fsm.force_transition(StateId::Active, &mut self.ctx);
-                    self.apply_actuators(hw);
-                    sink.emit(&AppEvent::StateChanged {
-                        from: prev,
-                        to: StateId::Active,
-                    });
+            AppCommand::StartScrub(source) => return self.start_scrub(source, hw, sink),
+            AppCommand::StopScrub(source) => return self.stop_scrub(source, hw, sink),
+            AppCommand::DrainWater => return self.start_drain(hw, sink),
+            AppCommand::InjectNh3 { ppm, duration_secs } => {
+                return self.inject_nh3(ppm, duration_secs);
+            }
+            AppCommand::TestFault {
+                fault_bit,
+                duration_secs,
+            } => return self.start_test_fault(fault_bit, duration_secs),
+            AppCommand::StartAutoTune => return self.start_autotune(),
+            AppCommand::ConfirmAutoTune => return self.confirm_autotune(),
+            AppCommand::CancelAutoTune => {
+                if self.tuner.take().is_some() {
+                    info!("Auto-tune cancelled");
+                    self.tune_status = TuneStatus::Aborted;
+                } else {
+                    self.tune_status = TuneStatus::Idle;
                 }
             }
             AppCommand::ForceState(target) => {
                 let prev = self.fsm.current_state();
                 self.fsm.force_transition(target, &mut self.ctx);
+                if matches!(target, StateId::Idle | StateId::Error) {
+                    self.scrub_source = None;
+                }
                 self.apply_actuators(hw);
                 sink.emit(&AppEvent::StateChanged {
                     from: prev,
                     to: target,
                 });
             }
+            AppCommand::UpdateConfig(_) | AppCommand::SaveConfig if self.in_config_safe_mode() => {
+                warn!("Config change rejected: config storage in safe mode");
+                return false;
+            }
             AppCommand::UpdateConfig(new_config) => {
                 self.mark_config_dirty();
-                self.ctx.config = new_config;
+                match new_config.quiet_hours {
+                    Some(quiet) => self.scheduler.set_quiet_hours(quiet),
+                    None => self.scheduler.clear_quiet_hours(),
+                }
+                self.scheduler
+                    .set_paused_labels(&new_config.paused_schedules);
+                self.scheduler.set_periodic(new_config.periodic_schedule);
+                self.pid.set_gains(
+                    new_config.pump_pid_kp,
+                    new_config.pump_pid_ki,
+                    new_config.pump_pid_kd,
+                );
+                self.flow_filter
+                    .set_tau_secs(f32::from(new_config.flow_filter_tau_ms) / 1000.0);
+                self.ctx.set_config(*new_config);
+                self.config_changed = true;
                 info!("Configuration updated at runtime");
             }
             AppCommand::SaveConfig => {
-                self.dirty_since_tick = 0;
+                self.dirty_since_ms = 0;
                 self.mark_config_dirty();
                 info!("Explicit config save requested (will flush on next auto-save check)");
             }
@@ -187,6 +537,312 @@ impl AppService {
                 // Handled by the RPC dispatch layer, not the FSM/service.
             }
         }
+        true
+    }
+
+    fn start_scrub(
+        &mut self,
+        source: CommandSource,
+        hw: &mut impl ActuatorPort,
+        sink: &mut impl EventSink,
+    ) -> bool {
+        if let Some(stopper) = self.stop_lockout_holder()
+            && stopper != source
+        {
+            warn!(
+                "StartScrub({:?}) rejected: stop lockout held by {:?}",
+                source, stopper
+            );
+            return false;
+        }
+
+        if self.ctx.config.maintenance_mode {
+            warn!("StartScrub({:?}) rejected: maintenance mode", source);
+            return false;
+        }
+
+        let prev = self.fsm.current_state();
+        match prev {
+            StateId::Idle | StateId::Sensing if self.ctx.active_inhibited => {
+                warn!("StartScrub({:?}) rejected: sensor self-test failed", source);
+                false
+            }
+            StateId::Idle | StateId::Sensing if self.ctx.activation_blocked() => {
+                warn!(
+                    "StartScrub({:?}) rejected: held off after repeated Active timeouts",
+                    source
+                );
+                false
+            }
+            StateId::Idle | StateId::Sensing => {
+                // Manual starts skip the NH3 confirmation window; automatic
+                // ones only fire from Idle so they never cut it short.
+                if prev == StateId::Sensing && !source.is_manual() {
+                    return true;
+                }
+                self.fsm.force_transition(StateId::Active, &mut self.ctx);
+                self.scrub_source = Some(source);
+                self.last_stop = None;
+                self.apply_actuators(hw);
+                sink.emit(&AppEvent::StateChanged {
+                    from: prev,
+                    to: StateId::Active,
+                });
+                true
+            }
+            StateId::Active | StateId::Purging => {
+                if source.is_manual() && !self.scrub_source.is_some_and(CommandSource::is_manual) {
+                    info!("StartScrub({:?}) takes over running scrub", source);
+                    self.scrub_source = Some(source);
+                }
+                true
+            }
+            StateId::Error => {
+                warn!("StartScrub({:?}) rejected: device in Error", source);
+                false
+            }
+            StateId::Draining => {
+                warn!("StartScrub({:?}) rejected: drain in progress", source);
+                false
+            }
+        }
+    }
+
+    /// Start overriding the NH3 reading for `duration_secs`.  Safety still
+    /// evaluates the real snapshot, so an injection cannot mask a fault;
+    /// one already raised refuses it outright.
+    fn inject_nh3(&mut self, ppm: f32, duration_secs: u16) -> bool {
+        if self.ctx.has_faults() {
+            warn!("InjectNh3 rejected: faults=0b{:08b}", self.ctx.fault_flags);
+            return false;
+        }
+        if !(ppm.is_finite() && ppm >= 0.0)
+            || duration_secs == 0
+            || duration_secs > MAX_NH3_INJECT_SECS
+        {
+            warn!("InjectNh3 rejected: {} ppm for {}s", ppm, duration_secs);
+            return false;
+        }
+        info!("NH3 injection: {:.1} ppm for {}s", ppm, duration_secs);
+        self.ctx.nh3_injection = Some(Nh3Injection {
+            ppm,
+            remaining_secs: f32::from(duration_secs),
+        });
+        true
+    }
+
+    /// Raise `fault_bit` as a synthetic fault for `duration_secs`.  It
+    /// takes the normal fault path (Error, outputs off, red LED) and
+    /// drops when the window ends or a real fault appears; leaving Error
+    /// then waits out the usual recovery cooldown.
+    fn start_test_fault(&mut self, fault_bit: u8, duration_secs: u16) -> bool {
+        let state = self.fsm.current_state();
+        if self.ctx.has_faults() || state == StateId::Error {
+            warn!(
+                "TestFault rejected: state={:?} faults=0b{:08b}",
+                state, self.ctx.fault_flags
+            );
+            return false;
+        }
+        let Some(fault) = SafetyFault::from_mask(fault_bit) else {
+            warn!("TestFault rejected: unknown fault bit 0b{:08b}", fault_bit);
+            return false;
+        };
+        if duration_secs == 0 || duration_secs > MAX_TEST_FAULT_SECS {
+            warn!("TestFault rejected: {}s window", duration_secs);
+            return false;
+        }
+        warn!("TEST fault: {} for {}s", fault, duration_secs);
+        self.test_fault = Some(TestFault {
+            mask: fault.mask(),
+            remaining_secs: f32::from(duration_secs),
+        });
+        true
+    }
+
+    /// Start a relay auto-tune of the flow loop around the configured
+    /// flow target.  Needs a running scrub past its pump priming and
+    /// ramp, with no faults.
+    fn start_autotune(&mut self) -> bool {
+        if self.tuner.is_some() {
+            return true;
+        }
+        let state = self.fsm.current_state();
+        let pump_sequenced =
+            self.ctx.priming || self.ctx.pump_ramping || self.ctx.soft_stop.is_active();
+        if self.ctx.has_faults() || state != StateId::Active || pump_sequenced {
+            warn!(
+                "StartAutoTune rejected: state={:?} faults=0b{:08b} sequencing={}",
+                state, self.ctx.fault_flags, pump_sequenced
+            );
+            return false;
+        }
+        let setpoint = f32::from(self.ctx.config.pump_flow_ml_per_min);
+        info!("Auto-tune started around {:.0} mL/min", setpoint);
+        self.tuner = Some(RelayTuner::new(RelayTuneConfig {
+            setpoint,
+            bias: AUTOTUNE_BIAS_DUTY,
+            step: AUTOTUNE_STEP_DUTY,
+            hysteresis: AUTOTUNE_HYSTERESIS_ML_PER_MIN,
+            timeout_secs: AUTOTUNE_TIMEOUT_SECS,
+            cycles: AUTOTUNE_CYCLES,
+            direct_acting: true,
+        }));
+        self.tune_status = TuneStatus::Running;
+        true
+    }
+
+    /// Load the pending auto-tune suggestion into the flow PID and the
+    /// config, so it survives a restart.
+    fn confirm_autotune(&mut self) -> bool {
+        let TuneStatus::Complete(result) = self.tune_status else {
+            warn!("ConfirmAutoTune rejected: no suggestion pending");
+            return false;
+        };
+        if self.in_config_safe_mode() {
+            warn!("ConfirmAutoTune rejected: config storage in safe mode");
+            return false;
+        }
+        let g = result.gains;
+        info!(
+            "Auto-tune gains applied: Kp={:.3} Ki={:.3} Kd={:.3}",
+            g.kp, g.ki, g.kd
+        );
+        self.pid.set_gains(g.kp, g.ki, g.kd);
+        self.pid.reset();
+        self.ctx.config.pump_pid_kp = g.kp;
+        self.ctx.config.pump_pid_ki = g.ki;
+        self.ctx.config.pump_pid_kd = g.kd;
+        self.mark_config_dirty();
+        self.config_changed = true;
+        self.tune_status = TuneStatus::Idle;
+        true
+    }
+
+    /// Advance a running auto-tune by one tick.  Any fault, or the scrub
+    /// leaving Active, aborts it.  Returns `true` while the relay owns
+    /// pump duty.
+    fn step_autotune(&mut self, dt: f32) -> bool {
+        let Some(tuner) = self.tuner.as_mut() else {
+            return false;
+        };
+        let outcome = if self.ctx.has_faults() {
+            warn!("Auto-tune aborted: faults=0b{:08b}", self.ctx.fault_flags);
+            TuneStatus::Aborted
+        } else if self.fsm.current_state() != StateId::Active {
+            warn!("Auto-tune aborted: scrub ended");
+            TuneStatus::Aborted
+        } else {
+            match tuner.update(self.ctx.sensors.flow_ml_per_min, dt) {
+                TuneStep::Drive(duty) => {
+                    self.ctx.commands.pump_duty = duty as u8;
+                    return true;
+                }
+                TuneStep::Complete(result) => {
+                    info!(
+                        "Auto-tune complete: Ku={:.3} Tu={:.1}s",
+                        result.ultimate_gain, result.ultimate_period_secs
+                    );
+                    TuneStatus::Complete(result)
+                }
+                TuneStep::TimedOut => {
+                    warn!("Auto-tune timed out after {}s", AUTOTUNE_TIMEOUT_SECS);
+                    TuneStatus::TimedOut
+                }
+            }
+        };
+        self.tuner = None;
+        self.tune_status = outcome;
+        false
+    }
+
+    /// Enter Draining from a resting state.  A running scrub must be
+    /// stopped first; a `StopScrub` also cancels the drain.
+    fn start_drain(&mut self, hw: &mut impl ActuatorPort, sink: &mut impl EventSink) -> bool {
+        let prev = self.fsm.current_state();
+        match prev {
+            StateId::Idle | StateId::Sensing => {
+                if !self.ctx.sensors.uvc_interlock_closed {
+                    warn!("DrainWater rejected: interlock open");
+                    return false;
+                }
+                self.fsm.force_transition(StateId::Draining, &mut self.ctx);
+                self.scrub_source = None;
+                self.apply_actuators(hw);
+                sink.emit(&AppEvent::StateChanged {
+                    from: prev,
+                    to: StateId::Draining,
+                });
+                true
+            }
+            StateId::Draining => true,
+            StateId::Active | StateId::Purging | StateId::Error => {
+                warn!("DrainWater rejected: device in {:?}", prev);
+                false
+            }
+        }
+    }
+
+    fn stop_scrub(
+        &mut self,
+        source: CommandSource,
+        hw: &mut impl ActuatorPort,
+        sink: &mut impl EventSink,
+    ) -> bool {
+        if !source.is_manual() && self.scrub_source.is_some_and(CommandSource::is_manual) {
+            warn!(
+                "StopScrub({:?}) rejected: scrub owned by {:?}",
+                source, self.scrub_source
+            );
+            return false;
+        }
+
+        if source.is_manual() {
+            self.last_stop = Some((source, self.uptime_ms));
+        }
+        self.scrub_source = None;
+
+        let prev = self.fsm.current_state();
+        if prev != StateId::Idle {
+            self.fsm.force_transition(StateId::Idle, &mut self.ctx);
+            self.apply_actuators(hw);
+            sink.emit(&AppEvent::StateChanged {
+                from: prev,
+                to: StateId::Idle,
+            });
+        }
+        true
+    }
+
+    /// Track the smoothed NH3 against the alarm band.  A sensor still
+    /// in its soak cannot raise the alarm.
+    fn update_nh3_alarm(&mut self) {
+        let cfg = &self.ctx.config;
+        let edge = if self.ctx.sensor_warming {
+            self.nh3_alarm.clear()
+        } else {
+            self.nh3_alarm.update(
+                self.ctx.sensors.nh3_avg_ppm,
+                cfg.nh3_alarm_high_ppm,
+                cfg.nh3_alarm_clear_ppm,
+            )
+        };
+        match edge {
+            Some(true) => warn!(
+                "NH3 alarm raised at {:.1} ppm",
+                self.ctx.sensors.nh3_avg_ppm
+            ),
+            Some(false) => info!("NH3 alarm cleared"),
+            None => return,
+        }
+        self.nh3_alarm_changed = true;
+    }
+
+    /// Source of the manual stop whose lockout is still running, if any.
+    fn stop_lockout_holder(&self) -> Option<CommandSource> {
+        let (source, at_ms) = self.last_stop?;
+        let elapsed_secs = self.uptime_ms.saturating_sub(at_ms) as f32 / 1000.0;
+        (elapsed_secs < f32::from(self.ctx.config.stop_lockout_secs)).then_some(source)
     }
 
     // ── Queries ───────────────────────────────────────────────
@@ -195,6 +851,7 @@ impl AppService {
     /// `wifi_rssi`: WiFi signal strength in dBm when connected; None when not available (e.g. BLE-only).
     pub fn build_telemetry(&self, wifi_rssi: Option<i8>) -> TelemetryData {
         TelemetryData {
+            zone: self.zone,
             state: self.fsm.current_state(),
             nh3_ppm: self.ctx.sensors.nh3_ppm,
             nh3_avg_ppm: self.ctx.sensors.nh3_avg_ppm,
@@ -202,53 +859,229 @@ impl AppService {
             temperature_c: self.ctx.sensors.temperature_c,
             tank_a_ok: self.ctx.sensors.tank_a_ok,
             tank_b_ok: self.ctx.sensors.tank_b_ok,
-            pump_duty: self.ctx.commands.pump_duty,
+            source_tank: self.ctx.commands.source_tank,
+            pump_duty: self.applied_pump_duty(),
             uvc_duty: self.ctx.commands.uvc_duty,
             fault_flags: self.ctx.fault_flags,
             wifi_rssi,
+            scrub_source: self.scrub_source,
+            uvc_warming: self.ctx.uvc_warming,
+            sensor_warming: self.ctx.sensor_warming,
+            nh3_injected: self.ctx.nh3_injection.is_some(),
+            fault_test: self.test_fault.is_some(),
+            priming: self.ctx.priming,
+            advisory_flags: self.ctx.advisory_flags,
+            purge_volume_ml: self.ctx.purge_volume_ml,
+            drain_volume_ml: self.ctx.drain_volume_ml,
+            drain_complete: self.ctx.drain_complete,
+            recovering: self.ctx.recovering,
+            power_profile: self.ctx.config.power_profile,
+            nh3_alarm: self.nh3_alarm.is_active(),
         }
     }
 
+    /// Recent telemetry samples, oldest first.
+    pub fn telemetry_history(&self) -> impl DoubleEndedIterator<Item = &TelemetrySample> {
+        self.history.iter()
+    }
+
+    /// Measured time since the first tick, on the clock used to stamp
+    /// [`telemetry_history`](Self::telemetry_history).
+    pub fn uptime_ms(&self) -> u64 {
+        self.uptime_ms
+    }
+
+    /// Filter loop this service drives.
+    pub fn zone(&self) -> ZoneId {
+        self.zone
+    }
+
     /// Current FSM state.
     pub fn state(&self) -> StateId {
         self.fsm.current_state()
     }
 
+    /// Display name of the current FSM state (e.g. for BLE status).
+    pub fn state_name(&self) -> &'static str {
+        self.fsm.current_state_name()
+    }
+
     /// Total control ticks executed since startup.
     pub fn tick_count(&self) -> u64 {
         self.tick_count
     }
 
+    /// Safety trips into Error since startup, test faults excluded.
+    pub fn fault_count(&self) -> u32 {
+        self.fault_count
+    }
+
     /// Current active fault bitmask (0 = no faults).
     pub fn fault_flags(&self) -> u8 {
         self.ctx.fault_flags
     }
 
+    /// Faults this zone's own supervisor raised on the latest tick (a
+    /// test fault included), leaving out those shared from other zones.
+    pub fn local_fault_flags(&self) -> u8 {
+        self.local_faults
+    }
+
+    /// Faults the other zones of the unit currently hold.  From the next
+    /// tick on this zone treats them as its own: it goes to Error and
+    /// stays there until they clear.
+    pub fn set_shared_faults(&mut self, flags: u8) {
+        self.shared_faults = flags;
+    }
+
+    /// High-ammonia alarm currently raised.
+    pub fn nh3_alarm_active(&self) -> bool {
+        self.nh3_alarm.is_active()
+    }
+
+    /// Take the alarm edge left by the last tick.  The main loop then
+    /// broadcasts the new state to subscribed clients.
+    pub fn take_nh3_alarm_changed(&mut self) -> bool {
+        core::mem::take(&mut self.nh3_alarm_changed)
+    }
+
+    /// Take the config change left by any command since the last call
+    /// (RPC, button or a confirmed auto-tune).  The main loop then
+    /// broadcasts the new config to subscribed clients.
+    pub fn take_config_changed(&mut self) -> bool {
+        core::mem::take(&mut self.config_changed)
+    }
+
+    /// The state the FSM has reached since the last call, as
+    /// `(from, to)`, or `None` if it is where it was.  States passed
+    /// through in between are not reported.
+    pub fn take_state_change(&mut self) -> Option<(StateId, StateId)> {
+        let now = self.fsm.current_state();
+        let from = core::mem::replace(&mut self.reported_state, now);
+        (from != now).then_some((from, now))
+    }
+
+    /// Latch an advisory detected outside the control loop (e.g. a
+    /// status LED channel that failed at boot).
+    pub fn raise_advisory(&mut self, advisory: Advisory) {
+        self.ctx.advisory_flags |= advisory.mask();
+    }
+
+    /// Whether config storage is in safe mode ([`Advisory::ConfigSafeMode`]),
+    /// so config changes could not be persisted and are refused.
+    pub fn in_config_safe_mode(&self) -> bool {
+        self.ctx.advisory_flags & Advisory::ConfigSafeMode.mask() != 0
+    }
+
+    /// Drop an advisory raised with [`raise_advisory`](Self::raise_advisory).
+    pub fn clear_advisory(&mut self, advisory: Advisory) {
+        self.ctx.advisory_flags &= !advisory.mask();
+    }
+
+    /// In Error with faults clear, waiting out the recovery cooldown.
+    pub fn is_recovering(&self) -> bool {
+        self.ctx.recovering
+    }
+
+    /// Whether maintenance mode is holding automatic control off.
+    pub fn in_maintenance(&self) -> bool {
+        self.ctx.config.maintenance_mode
+    }
+
+    /// Who started the scrub currently in progress, if anyone.
+    pub fn scrub_source(&self) -> Option<CommandSource> {
+        self.scrub_source
+    }
+
     /// Clone of the live configuration (for RPC read-back or delta updates).
     pub fn current_config(&self) -> SystemConfig {
         self.ctx.config.clone()
     }
 
+    /// Progress or outcome of the latest auto-tune run.
+    pub fn autotune_status(&self) -> TuneStatus {
+        self.tune_status
+    }
+
+    /// Seconds into the running auto-tune (0 when none is running).
+    pub fn autotune_elapsed_secs(&self) -> f32 {
+        self.tuner.as_ref().map_or(0.0, RelayTuner::elapsed_secs)
+    }
+
+    /// Read-only access to the schedule table (for RPC read-back).
+    pub fn scheduler(&self) -> &Scheduler {
+        &self.scheduler
+    }
+
+    /// Mutable access to the schedule table (main loop ticks it; RPC and
+    /// button handlers add or remove entries).
+    pub fn scheduler_mut(&mut self) -> &mut Scheduler {
+        &mut self.scheduler
+    }
+
+    /// Water processed, for diagnostics.
+    pub fn flow_totals(&self) -> &FlowTotalizer {
+        &self.flow_totals
+    }
+
+    /// Mutable access to the water totals (main loop restores and
+    /// persists them; RPC resets the since-service subtotal).
+    pub fn flow_totals_mut(&mut self) -> &mut FlowTotalizer {
+        &mut self.flow_totals
+    }
+
     // ── Internal ──────────────────────────────────────────────
 
+    /// `duty` under the soft thermal limit, which applies while scrubbing.
+    fn derated(&self, duty: u8) -> u8 {
+        if self.fsm.current_state() != StateId::Active {
+            return duty;
+        }
+        let cfg = &self.ctx.config;
+        derate_duty(
+            duty,
+            self.ctx.sensors.temperature_c,
+            cfg.pump_derate_start_c,
+            cfg.max_temperature_c,
+            cfg.pump_derate_min_duty_percent,
+        )
+    }
+
+    /// Pump duty actually driven: the commanded duty, derated unless the
+    /// PID already held it under the limit.
+    fn applied_pump_duty(&self) -> u8 {
+        let duty = self.ctx.commands.pump_duty;
+        if self.pid_driving {
+            duty
+        } else {
+            self.derated(duty)
+        }
+    }
+
     /// Translate FSM actuator commands into port calls.
     fn apply_actuators(&self, hw: &mut impl ActuatorPort) {
         let cmds = &self.ctx.commands;
         let snap = &self.ctx.sensors;
 
         // ── Pump ──────────────────────────────────────────────
-        if cmds.pump_duty > 0 && !self.safety.has_faults() {
-            hw.set_pump(cmds.pump_duty, cmds.pump_forward);
+        hw.select_tank(cmds.source_tank);
+        if cmds.pump_duty > 0 && !self.ctx.has_faults() {
+            hw.set_pump(self.applied_pump_duty(), cmds.pump_forward);
         } else {
             hw.stop_pump();
         }
 
-        // ── UVC (double-gated: interlock + safety) ───────────
-        if cmds.uvc_duty > 0 && snap.uvc_interlock_closed && !self.safety.has_faults() {
+        // ── UVC (gated: interlock + safety + raw temperature) ─
+        // The OverTemperature fault is debounced; the lamp is not.  Any
+        // hot sample cuts it until the next cool one.
+        let over_temp = snap.temperature_c > self.ctx.config.max_temperature_c;
+        if cmds.uvc_duty > 0 && snap.uvc_interlock_closed && !over_temp && !self.ctx.has_faults() {
             hw.enable_uvc(cmds.uvc_duty);
         } else if hw.is_uvc_on() {
-            if self.safety.has_faults() {
+            if self.ctx.has_faults() {
                 hw.fault_shutdown_uvc("safety fault");
+            } else if over_temp {
+                hw.fault_shutdown_uvc("over temperature");
             } else if !snap.uvc_interlock_closed {
                 hw.fault_shutdown_uvc("interlock open");
             } else {
@@ -267,18 +1100,18 @@ impl AppService {
     pub fn mark_config_dirty(&mut self) {
         if !self.config_dirty {
             self.config_dirty = true;
-            self.dirty_since_tick = self.tick_count;
+            self.dirty_since_ms = self.uptime_ms;
         }
     }
 
-    /// Check if auto-save should trigger (5 seconds after last change).
-    /// Returns `true` if the config was saved.
+    /// Check if auto-save should trigger (5 seconds after last change,
+    /// and not during a brown-out hold).  Returns `true` if the config
+    /// was saved.
     pub fn auto_save_if_needed(&mut self, storage: &impl super::ports::ConfigPort) -> bool {
-        if !self.config_dirty {
+        if !self.config_dirty || self.in_brownout_hold() {
             return false;
         }
-        let ticks_since_dirty = self.tick_count.saturating_sub(self.dirty_since_tick);
-        let secs_since_dirty = ticks_since_dirty as f32 * self.tick_secs;
+        let secs_since_dirty = self.uptime_ms.saturating_sub(self.dirty_since_ms) as f32 / 1000.0;
         if secs_since_dirty < 5.0 {
             return false;
         }
@@ -331,5 +1164,13 @@ mod tests {
         let t_some = app.build_telemetry(Some(-42));
         assert_eq!(t_some.wifi_rssi, Some(-42));
     }
+
+    #[test]
+    fn build_telemetry_reports_uvc_warming() {
+        let mut app = AppService::new(SystemConfig::default());
+        assert!(!app.build_telemetry(None).uvc_warming);
+        app.ctx.uvc_warming = true;
+        assert!(app.build_telemetry(None).uvc_warming);
+    }
 }
 
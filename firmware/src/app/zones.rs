@@ -0,0 +1,152 @@
+//! Multi-zone installations — one [`AppService`] per filter loop.
+//!
+//! Larger units run two reservoirs, each with its own NH3 sensor, pump,
+//! config and state machine.  The loops scrub independently but share
+//! one safety trip: they sit in the same enclosure on the same supply,
+//! so a fault raised by any zone's supervisor stops every zone, and the
+//! unit resumes only once all of them read clear.
+//!
+//! ```text
+//!            ┌──────────── ZoneSet ────────────┐
+//!  hw A ◀──▶ │ AppService (zone 0, primary)    │
+//!  hw B ◀──▶ │ AppService (zone 1)             │
+//!            │     └── faults shared each tick │
+//!            └─────────────────────────────────┘
+//! ```
+//!
+//! The primary zone also carries the unit-wide settings (network,
+//! schedules, LED); RPCs without a `zone` field act on it.
+
+use core::fmt::Write;
+
+use heapless::Vec;
+
+use super::ports::{ActuatorPort, EventSink, SensorPort};
+use super::service::{AppService, DEFAULT_ZONE, ZoneId};
+
+/// Most filter loops one controller drives.
+pub const MAX_ZONES: usize = 2;
+
+/// NVS key under which `zone` keeps what is stored under `base` for a
+/// single-loop unit.  Zone 0 keeps `base` itself, so a unit that gains a
+/// second loop finds its existing data where it left it.
+pub fn zone_key(base: &str, zone: ZoneId) -> heapless::String<15> {
+    let mut key = heapless::String::new();
+    if zone == DEFAULT_ZONE {
+        let _ = key.push_str(base);
+    } else {
+        let _ = write!(key, "{}_z{}", base, zone);
+    }
+    key
+}
+
+/// One filter loop: its service and the hardware it drives.
+pub struct Zone<H> {
+    pub app: AppService,
+    pub hw: H,
+}
+
+/// Every zone of the unit, primary first.
+pub struct ZoneSet<H> {
+    zones: Vec<Zone<H>, MAX_ZONES>,
+}
+
+impl<H> ZoneSet<H> {
+    /// A unit whose primary zone is `app` on `hw`.
+    pub fn new(app: AppService, hw: H) -> Self {
+        let mut zones = Vec::new();
+        let _ = zones.push(Zone { app, hw });
+        Self { zones }
+    }
+
+    /// Add another filter loop.  Handed back, boxed, when the set is
+    /// full or already has a zone with the same id.
+    pub fn add(&mut self, app: AppService, hw: H) -> Result<(), Box<Zone<H>>> {
+        if self.get(app.zone()).is_some() {
+            return Err(Box::new(Zone { app, hw }));
+        }
+        self.zones.push(Zone { app, hw }).map_err(Box::new)
+    }
+
+    /// The zone that carries the unit-wide settings.
+    pub fn primary(&self) -> &Zone<H> {
+        &self.zones[0]
+    }
+
+    pub fn primary_mut(&mut self) -> &mut Zone<H> {
+        &mut self.zones[0]
+    }
+
+    pub fn get(&self, zone: ZoneId) -> Option<&Zone<H>> {
+        self.zones.iter().find(|z| z.app.zone() == zone)
+    }
+
+    pub fn get_mut(&mut self, zone: ZoneId) -> Option<&mut Zone<H>> {
+        self.zones.iter_mut().find(|z| z.app.zone() == zone)
+    }
+
+    /// The zone a request names, or the primary zone when the unit has
+    /// no such zone (so the request is answered, and rejected, there).
+    pub fn route_mut(&mut self, zone: ZoneId) -> &mut Zone<H> {
+        let idx = self
+            .zones
+            .iter()
+            .position(|z| z.app.zone() == zone)
+            .unwrap_or(0);
+        &mut self.zones[idx]
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Zone<H>> {
+        self.zones.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, Zone<H>> {
+        self.zones.iter_mut()
+    }
+
+    /// Share each zone's own faults with every other zone; they take
+    /// effect on each zone's next tick.
+    pub fn share_faults(&mut self) {
+        let raised: Vec<u8, MAX_ZONES> = self
+            .zones
+            .iter()
+            .map(|z| z.app.local_fault_flags())
+            .collect();
+        for (i, zone) in self.zones.iter_mut().enumerate() {
+            let others = raised
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(0, |acc, (_, &flags)| acc | flags);
+            zone.app.set_shared_faults(others);
+        }
+    }
+}
+
+impl<H: SensorPort + ActuatorPort> ZoneSet<H> {
+    /// Run one control cycle on every zone, then share their faults.
+    pub fn tick(&mut self, sink: &mut impl EventSink, dt_secs: f32) {
+        for zone in &mut self.zones {
+            zone.app.tick(&mut zone.hw, sink, dt_secs);
+        }
+        self.share_faults();
+    }
+}
+
+impl<'a, H> IntoIterator for &'a ZoneSet<H> {
+    type Item = &'a Zone<H>;
+    type IntoIter = core::slice::Iter<'a, Zone<H>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, H> IntoIterator for &'a mut ZoneSet<H> {
+    type Item = &'a mut Zone<H>;
+    type IntoIter = core::slice::IterMut<'a, Zone<H>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -2,9 +2,85 @@
 //!
 //! All tunable parameters for the PetFilter system.
 //! Values can be overridden via NVS (non-volatile storage) or BLE provisioning.
+//!
+//! ## Stored layout
+//!
+//! The NVS blob is a little-endian `u16` schema version followed by the
+//! postcard encoding of [`SystemConfig`].  Version 1 is the original
+//! unprefixed layout; [`decode_stored`] recognises it by the absence of a
+//! known version prefix and [`migrate`] upgrades it field-by-field.
+//!
+//! When adding a field: bump [`CONFIG_SCHEMA_VERSION`], freeze the
+//! previous layout as a `SystemConfigVn` struct, and add an arm to
+//! [`migrate`].
 
 use serde::{Deserialize, Serialize};
 
+use crate::adapters::tls_transport::DEFAULT_IDLE_TIMEOUT;
+use crate::adapters::wifi::{DEFAULT_ROAM_RSSI_DBM, DEFAULT_ROAM_SAMPLES};
+use crate::drivers::led_patterns::{
+    DEFAULT_LED_OVERLAY_PRIORITY, LED_OVERLAY_COUNT, LedOverlay, LedOverride,
+};
+use crate::fsm::StateId;
+use crate::rpc::auth::DEFAULT_AUTH_LOCKOUT_THRESHOLD;
+use crate::scheduler::{PausedLabels, PeriodicSchedule, QuietHours};
+
+/// Schema version written by [`encode_stored`].
+pub const CONFIG_SCHEMA_VERSION: u16 = 34;
+
+/// Size of the version prefix on a stored config blob.
+const VERSION_PREFIX_LEN: usize = 2;
+
+/// How the Purging state decides the flush is complete.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PurgeMode {
+    /// Run for `purge_duration_secs`.
+    #[default]
+    Duration,
+    /// Run until `purge_target_volume_ml` has been pumped, capped at
+    /// `purge_max_duration_secs`.
+    Volume,
+}
+
+/// Which tank the source valve draws from during a scrub cycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TankSource {
+    /// Always draw from Tank A.
+    #[default]
+    TankA,
+    /// Always draw from Tank B.
+    TankB,
+    /// Swap tanks at the start of every scrub cycle to even out wear.
+    Alternate,
+}
+
+/// Trade-off between responsiveness and power draw.  Each profile
+/// resolves to a [`PowerTiming`] bundle via [`SystemConfig::power_timing`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerProfile {
+    /// Battery: slow control loop, sleeps after 1 min idle, deep
+    /// sleep after 10 min.
+    Eco,
+    /// Honours `control_loop_interval_ms`; light sleep after 5 min
+    /// idle, deep sleep after 30 min.
+    #[default]
+    Balanced,
+    /// Mains: 500 ms control loop, light sleep after 30 min idle,
+    /// deep sleep after 2 h.
+    Performance,
+}
+
+/// Power-related timing resolved from a [`PowerProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerTiming {
+    /// Idle time before light sleep (seconds)
+    pub idle_to_light_secs: u32,
+    /// Idle time before deep sleep (seconds)
+    pub idle_to_deep_secs: u32,
+    /// Control loop interval (milliseconds)
+    pub control_loop_interval_ms: u32,
+}
+
 /// Core system configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemConfig {
@@ -13,6 +89,28 @@ pub struct SystemConfig {
     pub pump_flow_ml_per_min: u16,
     /// Pump PWM duty cycle (0-100%)
     pub pump_duty_percent: u8,
+    /// Pump idle time after which entering Active primes the line
+    /// first; the first run after boot always primes (seconds, 0 =
+    /// never prime)
+    pub prime_after_idle_secs: u32,
+    /// Length of the low-duty priming phase (seconds)
+    pub prime_duration_secs: u16,
+    /// Pump duty during priming (1-100%)
+    pub prime_duty_percent: u8,
+    /// Flow pulses closer together than this are dropped as EMI or
+    /// contact bounce (microseconds, 0 = off)
+    pub flow_glitch_filter_us: u16,
+    /// Control ticks over which pump and UVC ramp down when Active or
+    /// Purging exits (0 = stop at once).  Faults always stop at once.
+    pub soft_stop_ticks: u8,
+    /// Tank the pump draws from each scrub cycle; a tank reading low
+    /// is skipped in favour of the other
+    pub tank_source: TankSource,
+    /// Flow PID gains (pump duty % per mL/min of error); replaced by a
+    /// confirmed auto-tune
+    pub pump_pid_kp: f32,
+    pub pump_pid_ki: f32,
+    pub pump_pid_kd: f32,
 
     // --- NH3 Thresholds ---
     /// NH3 concentration (ppm) to trigger activation
@@ -21,18 +119,103 @@ pub struct SystemConfig {
     pub nh3_deactivate_threshold_ppm: f32,
     /// Sensing confirmation period (seconds)
     pub nh3_confirm_duration_secs: u16,
+    /// Instantaneous NH3 (ppm) above which Idle skips confirmation and
+    /// activates immediately
+    pub nh3_urgent_threshold_ppm: f32,
+    /// NH3 samples taken per control interval; the control loop sees
+    /// their mean and peak (1 = one sample per control tick)
+    pub nh3_oversample_count: u8,
+    /// ADC conversions per NH3 sample; the highest and lowest are
+    /// discarded and the rest averaged (1 = single conversion)
+    pub nh3_adc_samples: u8,
+    /// NH3 rising faster than this (ppm/s) starts Sensing even below the
+    /// activation threshold, e.g. a fresh deposit (0 = off)
+    pub nh3_rate_threshold_ppm_per_sec: f32,
+    /// Sensor soak after a cold boot during which Idle ignores NH3
+    /// triggers; skipped on ULP wake (seconds, 0 = no soak)
+    pub nh3_warmup_secs: u16,
+    /// NH3 (ppm) at which the high-ammonia alarm raises, independent of
+    /// the activation thresholds (0 = alarm off)
+    pub nh3_alarm_high_ppm: f32,
+    /// NH3 (ppm) the reading must fall below before a raised alarm
+    /// clears; below `nh3_alarm_high_ppm` to give a hysteresis band
+    pub nh3_alarm_clear_ppm: f32,
 
     // --- UVC ---
     /// UVC LED PWM duty cycle (0-100%)
     pub uvc_duty_percent: u8,
+    /// Time after energising the UVC before its output counts as
+    /// germicidal (seconds, 0 = effective immediately)
+    pub uvc_warmup_secs: u16,
+
+    // --- Scheduling ---
+    /// Overnight/daytime window in which periodic schedules are
+    /// suppressed and the status LED may dim (None = disabled)
+    pub quiet_hours: Option<QuietHours>,
+    /// Labels of schedules paused with `SetScheduleEnabledRequest`
+    pub paused_schedules: PausedLabels,
+    /// Recurring scrub set with `SetScheduleRequest`, kept here so it
+    /// survives a restart or deep sleep (None = no periodic schedule)
+    pub periodic_schedule: Option<PeriodicSchedule>,
+
+    // --- Status LED ---
+    /// Global status LED brightness (0-255)
+    pub led_brightness: u8,
+    /// Brightness cap during scheduler quiet hours (255 = no auto-dim)
+    pub led_quiet_brightness: u8,
+    /// Per-state colour/pattern overrides, indexed by `StateId`
+    /// (None = built-in default)
+    pub led_overrides: [Option<LedOverride>; StateId::COUNT],
+    /// Connectivity overlays, highest priority first; each listed once
+    pub led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
 
     // --- Safety ---
     /// Maximum allowed temperature (Celsius) before thermal shutdown
     pub max_temperature_c: f32,
+    /// Consecutive samples above `max_temperature_c` before the
+    /// over-temperature fault sets, and at or below it before the fault
+    /// clears (1 = act on a single sample)
+    pub over_temp_debounce_samples: u8,
+    /// Temperature at which Active starts derating pump duty, scaling
+    /// it down linearly towards `pump_derate_min_duty_percent` at
+    /// `max_temperature_c` (Celsius, 0 = no derating)
+    pub pump_derate_start_c: f32,
+    /// Pump duty allowed at `max_temperature_c` while derating (%)
+    pub pump_derate_min_duty_percent: u8,
     /// Purge duration after scrubbing (seconds)
     pub purge_duration_secs: u16,
+    /// Whether purge ends on elapsed time or on flushed volume
+    pub purge_mode: PurgeMode,
+    /// Flush volume that completes a purge in `PurgeMode::Volume` (mL)
+    pub purge_target_volume_ml: u16,
+    /// Safety cap on a volume purge, e.g. with no flow (seconds)
+    pub purge_max_duration_secs: u16,
+    /// Longest continuous scrub before Active is forced to purge
+    /// (seconds, 0 = unlimited)
+    pub max_active_duration_secs: u32,
+    /// Shortest scrub: once Active is entered it holds for this long
+    /// before falling NH3 can end it; faults still stop it at once
+    /// (seconds, 0 = no minimum)
+    pub min_active_secs: u16,
+    /// Per-state watchdog, indexed by `StateId`: a state held longer
+    /// than its entry takes its escape transition (see
+    /// [`StateId::timeout_escape`]).  Seconds, 0 = no limit; Idle and
+    /// Error have no escape and must stay 0.
+    pub state_max_secs: [u32; StateId::COUNT],
     /// Minimum water level for operation (0-100%)
     pub min_water_level_percent: u8,
+    /// Faults must stay clear this long before Error returns to Idle;
+    /// a re-fault restarts the wait (seconds, 0 = recover immediately)
+    pub fault_recovery_cooldown_secs: u16,
+    /// How long the UVC interlock may read open before it faults; UVC
+    /// is cut at once regardless (seconds, 0 = fault immediately)
+    pub interlock_grace_secs: u16,
+    /// The UVC interlock input reads LOW with the lid closed (magnet
+    /// present).  `false` for lid sensors wired to read HIGH when closed.
+    pub interlock_active_low: bool,
+    /// A critical sensor (NH3, temperature) not updated for this long
+    /// raises the `SensorStale` advisory (seconds, 0 = never)
+    pub sensor_stale_after_secs: u16,
 
     // --- Timing ---
     /// Sensor read interval (milliseconds)
@@ -41,6 +224,59 @@ pub struct SystemConfig {
     pub control_loop_interval_ms: u32,
     /// Telemetry report interval (seconds)
     pub telemetry_interval_secs: u32,
+    /// Refresh the BLE Device Status characteristic this often while a
+    /// central is connected, subscribed or not (seconds, 0 = off)
+    pub ble_status_interval_secs: u16,
+    /// Longest measured gap between control ticks that timers and the
+    /// control math accept; a longer stall counts as this long, and it
+    /// never clamps below the nominal interval (ms, 0 = ignore the
+    /// measured gap and use the nominal interval)
+    pub max_control_dt_ms: u32,
+    /// Time constant of the low-pass on measured flow ahead of the pump
+    /// PID, scaled by the measured tick gap (ms, 0 = unfiltered)
+    pub flow_filter_tau_ms: u16,
+
+    // --- Power ---
+    /// Sleep timeouts and control-loop pace (see [`PowerProfile`])
+    pub power_profile: PowerProfile,
+
+    // --- Command arbitration ---
+    /// After a manual stop, reject scrub starts from other sources for
+    /// this many seconds (0 = no lockout)
+    pub stop_lockout_secs: u16,
+
+    // --- Startup self-test ---
+    /// Refuse to enter Active when the boot self-test finds a critical
+    /// sensor (temperature or interlock) faulty
+    pub self_test_blocks_active: bool,
+
+    // --- Maintenance ---
+    /// Hold the FSM in Idle, ignoring NH3 triggers and schedules, while
+    /// the unit is cleaned or moved; safety faults still apply
+    pub maintenance_mode: bool,
+
+    // --- RPC security ---
+    /// How long the previous PSK still authenticates after a rotation
+    /// (seconds, 0 = revoke it at once)
+    pub psk_grace_secs: u32,
+    /// Consecutive failed auth verifies that lock a client slot out; each
+    /// further failure doubles the lockout (0 = never lock out)
+    pub auth_lockout_threshold: u8,
+    /// Answer `PingRequest` only on authenticated sessions; when false,
+    /// clients may also ping during the handshake
+    pub ping_requires_auth: bool,
+
+    // --- WiFi ---
+    /// RSSI below which the current AP counts as weak (dBm)
+    pub wifi_roam_rssi_dbm: i8,
+    /// Consecutive weak polls before roaming to a stronger AP of the
+    /// same SSID
+    pub wifi_roam_samples: u8,
+
+    // --- RPC transport ---
+    /// How long a TCP client may send and receive nothing before it is
+    /// disconnected (seconds, 0 = never)
+    pub tcp_idle_timeout_secs: u16,
 }
 
 impl Default for SystemConfig {
@@ -49,36 +285,4913 @@ impl Default for SystemConfig {
             // Pump
             pump_flow_ml_per_min: 1000, // 1 L/min
             pump_duty_percent: 70,
+            prime_after_idle_secs: 6 * 3600,
+            prime_duration_secs: 5,
+            prime_duty_percent: 30,
+            flow_glitch_filter_us: 1000,
+            soft_stop_ticks: 3,
+            tank_source: TankSource::TankA,
+            pump_pid_kp: 2.0,
+            pump_pid_ki: 0.5,
+            pump_pid_kd: 0.1,
 
             // NH3 thresholds
             nh3_activate_threshold_ppm: 10.0,
             nh3_deactivate_threshold_ppm: 5.0,
             nh3_confirm_duration_secs: 30,
+            nh3_urgent_threshold_ppm: 50.0,
+            nh3_oversample_count: 4,
+            nh3_adc_samples: 5,
+            nh3_rate_threshold_ppm_per_sec: 0.0,
+            nh3_warmup_secs: 0,
+            nh3_alarm_high_ppm: 0.0,
+            nh3_alarm_clear_ppm: 0.0,
 
             // UVC
             uvc_duty_percent: 100,
+            uvc_warmup_secs: 10,
+
+            // Scheduling
+            quiet_hours: None,
+            paused_schedules: PausedLabels::new(),
+            periodic_schedule: None,
+
+            // Status LED
+            led_brightness: 255,
+            led_quiet_brightness: 255,
+            led_overrides: [None; StateId::COUNT],
+            led_overlay_priority: DEFAULT_LED_OVERLAY_PRIORITY,
 
             // Safety
             max_temperature_c: 80.0,
+            over_temp_debounce_samples: 3,
+            pump_derate_start_c: 0.0,
+            pump_derate_min_duty_percent: 30,
             purge_duration_secs: 120,
+            purge_mode: PurgeMode::Duration,
+            purge_target_volume_ml: 1000,
+            purge_max_duration_secs: 300,
+            max_active_duration_secs: 3600,
+            min_active_secs: 0,
+            state_max_secs: [0; StateId::COUNT],
             min_water_level_percent: 20,
+            fault_recovery_cooldown_secs: 10,
+            interlock_grace_secs: 2,
+            interlock_active_low: true,
+            sensor_stale_after_secs: 10,
 
             // Timing
             sensor_read_interval_ms: 100,   // 10 Hz
             control_loop_interval_ms: 1000, // 1 Hz
             telemetry_interval_secs: 60,    // 1/min
+            ble_status_interval_secs: 5,
+            max_control_dt_ms: 4000,
+            flow_filter_tau_ms: 0,
+
+            // Power
+            power_profile: PowerProfile::Balanced,
+
+            // Command arbitration
+            stop_lockout_secs: 5,
+
+            // Startup self-test
+            self_test_blocks_active: true,
+
+            // Maintenance
+            maintenance_mode: false,
+
+            // RPC security
+            psk_grace_secs: 24 * 60 * 60,
+            auth_lockout_threshold: DEFAULT_AUTH_LOCKOUT_THRESHOLD,
+            ping_requires_auth: true,
+
+            // WiFi
+            wifi_roam_rssi_dbm: DEFAULT_ROAM_RSSI_DBM,
+            wifi_roam_samples: DEFAULT_ROAM_SAMPLES,
+
+            // RPC transport
+            tcp_idle_timeout_secs: DEFAULT_IDLE_TIMEOUT.as_secs() as u16,
+        }
+    }
+}
+
+impl SystemConfig {
+    /// Sleep timeouts and control-loop interval for the selected
+    /// [`PowerProfile`].
+    pub fn power_timing(&self) -> PowerTiming {
+        match self.power_profile {
+            PowerProfile::Eco => PowerTiming {
+                idle_to_light_secs: 60,
+                idle_to_deep_secs: 600,
+                control_loop_interval_ms: 2000,
+            },
+            PowerProfile::Balanced => PowerTiming {
+                idle_to_light_secs: 300,
+                idle_to_deep_secs: 1800,
+                control_loop_interval_ms: self.control_loop_interval_ms,
+            },
+            PowerProfile::Performance => PowerTiming {
+                idle_to_light_secs: 1800,
+                idle_to_deep_secs: 7200,
+                control_loop_interval_ms: 500,
+            },
+        }
+    }
+
+    /// The tick length timers and control math should use, given the
+    /// `measured_secs` since the previous control tick: the measurement
+    /// clamped to `max_control_dt_ms` (never below the nominal interval),
+    /// or the nominal interval when compensation is off.
+    pub fn control_dt_secs(&self, measured_secs: f32) -> f32 {
+        let nominal = self.power_timing().control_loop_interval_ms as f32 / 1000.0;
+        if self.max_control_dt_ms == 0 {
+            return nominal;
+        }
+        let max = (self.max_control_dt_ms as f32 / 1000.0).max(nominal);
+        measured_secs.clamp(0.0, max)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Versioned storage
+// ---------------------------------------------------------------------------
+
+/// Schema v1: the unversioned layout shipped before the version prefix.
+#[derive(Deserialize)]
+struct SystemConfigV1 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    uvc_duty_percent: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    min_water_level_percent: u8,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+}
+
+impl From<SystemConfigV1> for SystemConfig {
+    fn from(v1: SystemConfigV1) -> Self {
+        Self {
+            pump_flow_ml_per_min: v1.pump_flow_ml_per_min,
+            pump_duty_percent: v1.pump_duty_percent,
+            nh3_activate_threshold_ppm: v1.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v1.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v1.nh3_confirm_duration_secs,
+            uvc_duty_percent: v1.uvc_duty_percent,
+            max_temperature_c: v1.max_temperature_c,
+            purge_duration_secs: v1.purge_duration_secs,
+            min_water_level_percent: v1.min_water_level_percent,
+            sensor_read_interval_ms: v1.sensor_read_interval_ms,
+            control_loop_interval_ms: v1.control_loop_interval_ms,
+            telemetry_interval_secs: v1.telemetry_interval_secs,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v2: first versioned layout, before `max_active_duration_secs`.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV2 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    min_water_level_percent: u8,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV2> for SystemConfig {
+    fn from(v2: SystemConfigV2) -> Self {
+        Self {
+            pump_flow_ml_per_min: v2.pump_flow_ml_per_min,
+            pump_duty_percent: v2.pump_duty_percent,
+            prime_after_idle_secs: v2.prime_after_idle_secs,
+            prime_duration_secs: v2.prime_duration_secs,
+            prime_duty_percent: v2.prime_duty_percent,
+            nh3_activate_threshold_ppm: v2.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v2.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v2.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v2.nh3_urgent_threshold_ppm,
+            uvc_duty_percent: v2.uvc_duty_percent,
+            uvc_warmup_secs: v2.uvc_warmup_secs,
+            quiet_hours: v2.quiet_hours,
+            led_brightness: v2.led_brightness,
+            led_quiet_brightness: v2.led_quiet_brightness,
+            max_temperature_c: v2.max_temperature_c,
+            purge_duration_secs: v2.purge_duration_secs,
+            min_water_level_percent: v2.min_water_level_percent,
+            sensor_read_interval_ms: v2.sensor_read_interval_ms,
+            control_loop_interval_ms: v2.control_loop_interval_ms,
+            telemetry_interval_secs: v2.telemetry_interval_secs,
+            stop_lockout_secs: v2.stop_lockout_secs,
+            self_test_blocks_active: v2.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v3: before the volume-targeted purge settings.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV3 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV3> for SystemConfig {
+    fn from(v3: SystemConfigV3) -> Self {
+        Self {
+            pump_flow_ml_per_min: v3.pump_flow_ml_per_min,
+            pump_duty_percent: v3.pump_duty_percent,
+            prime_after_idle_secs: v3.prime_after_idle_secs,
+            prime_duration_secs: v3.prime_duration_secs,
+            prime_duty_percent: v3.prime_duty_percent,
+            nh3_activate_threshold_ppm: v3.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v3.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v3.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v3.nh3_urgent_threshold_ppm,
+            uvc_duty_percent: v3.uvc_duty_percent,
+            uvc_warmup_secs: v3.uvc_warmup_secs,
+            quiet_hours: v3.quiet_hours,
+            led_brightness: v3.led_brightness,
+            led_quiet_brightness: v3.led_quiet_brightness,
+            max_temperature_c: v3.max_temperature_c,
+            purge_duration_secs: v3.purge_duration_secs,
+            max_active_duration_secs: v3.max_active_duration_secs,
+            min_water_level_percent: v3.min_water_level_percent,
+            sensor_read_interval_ms: v3.sensor_read_interval_ms,
+            control_loop_interval_ms: v3.control_loop_interval_ms,
+            telemetry_interval_secs: v3.telemetry_interval_secs,
+            stop_lockout_secs: v3.stop_lockout_secs,
+            self_test_blocks_active: v3.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v4: before NH3 oversampling.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV4 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV4> for SystemConfig {
+    fn from(v4: SystemConfigV4) -> Self {
+        Self {
+            pump_flow_ml_per_min: v4.pump_flow_ml_per_min,
+            pump_duty_percent: v4.pump_duty_percent,
+            prime_after_idle_secs: v4.prime_after_idle_secs,
+            prime_duration_secs: v4.prime_duration_secs,
+            prime_duty_percent: v4.prime_duty_percent,
+            nh3_activate_threshold_ppm: v4.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v4.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v4.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v4.nh3_urgent_threshold_ppm,
+            uvc_duty_percent: v4.uvc_duty_percent,
+            uvc_warmup_secs: v4.uvc_warmup_secs,
+            quiet_hours: v4.quiet_hours,
+            led_brightness: v4.led_brightness,
+            led_quiet_brightness: v4.led_quiet_brightness,
+            max_temperature_c: v4.max_temperature_c,
+            purge_duration_secs: v4.purge_duration_secs,
+            purge_mode: v4.purge_mode,
+            purge_target_volume_ml: v4.purge_target_volume_ml,
+            purge_max_duration_secs: v4.purge_max_duration_secs,
+            max_active_duration_secs: v4.max_active_duration_secs,
+            min_water_level_percent: v4.min_water_level_percent,
+            sensor_read_interval_ms: v4.sensor_read_interval_ms,
+            control_loop_interval_ms: v4.control_loop_interval_ms,
+            telemetry_interval_secs: v4.telemetry_interval_secs,
+            stop_lockout_secs: v4.stop_lockout_secs,
+            self_test_blocks_active: v4.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v5: before the post-fault recovery cooldown.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV5 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV5> for SystemConfig {
+    fn from(v5: SystemConfigV5) -> Self {
+        Self {
+            pump_flow_ml_per_min: v5.pump_flow_ml_per_min,
+            pump_duty_percent: v5.pump_duty_percent,
+            prime_after_idle_secs: v5.prime_after_idle_secs,
+            prime_duration_secs: v5.prime_duration_secs,
+            prime_duty_percent: v5.prime_duty_percent,
+            nh3_activate_threshold_ppm: v5.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v5.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v5.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v5.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v5.nh3_oversample_count,
+            uvc_duty_percent: v5.uvc_duty_percent,
+            uvc_warmup_secs: v5.uvc_warmup_secs,
+            quiet_hours: v5.quiet_hours,
+            led_brightness: v5.led_brightness,
+            led_quiet_brightness: v5.led_quiet_brightness,
+            max_temperature_c: v5.max_temperature_c,
+            purge_duration_secs: v5.purge_duration_secs,
+            purge_mode: v5.purge_mode,
+            purge_target_volume_ml: v5.purge_target_volume_ml,
+            purge_max_duration_secs: v5.purge_max_duration_secs,
+            max_active_duration_secs: v5.max_active_duration_secs,
+            min_water_level_percent: v5.min_water_level_percent,
+            sensor_read_interval_ms: v5.sensor_read_interval_ms,
+            control_loop_interval_ms: v5.control_loop_interval_ms,
+            telemetry_interval_secs: v5.telemetry_interval_secs,
+            stop_lockout_secs: v5.stop_lockout_secs,
+            self_test_blocks_active: v5.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v6: before power profiles.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV6 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV6> for SystemConfig {
+    fn from(v6: SystemConfigV6) -> Self {
+        Self {
+            pump_flow_ml_per_min: v6.pump_flow_ml_per_min,
+            pump_duty_percent: v6.pump_duty_percent,
+            prime_after_idle_secs: v6.prime_after_idle_secs,
+            prime_duration_secs: v6.prime_duration_secs,
+            prime_duty_percent: v6.prime_duty_percent,
+            nh3_activate_threshold_ppm: v6.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v6.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v6.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v6.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v6.nh3_oversample_count,
+            uvc_duty_percent: v6.uvc_duty_percent,
+            uvc_warmup_secs: v6.uvc_warmup_secs,
+            quiet_hours: v6.quiet_hours,
+            led_brightness: v6.led_brightness,
+            led_quiet_brightness: v6.led_quiet_brightness,
+            max_temperature_c: v6.max_temperature_c,
+            purge_duration_secs: v6.purge_duration_secs,
+            purge_mode: v6.purge_mode,
+            purge_target_volume_ml: v6.purge_target_volume_ml,
+            purge_max_duration_secs: v6.purge_max_duration_secs,
+            max_active_duration_secs: v6.max_active_duration_secs,
+            min_water_level_percent: v6.min_water_level_percent,
+            fault_recovery_cooldown_secs: v6.fault_recovery_cooldown_secs,
+            sensor_read_interval_ms: v6.sensor_read_interval_ms,
+            control_loop_interval_ms: v6.control_loop_interval_ms,
+            telemetry_interval_secs: v6.telemetry_interval_secs,
+            stop_lockout_secs: v6.stop_lockout_secs,
+            self_test_blocks_active: v6.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v7: before per-state LED overrides.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV7 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV7> for SystemConfig {
+    fn from(v7: SystemConfigV7) -> Self {
+        Self {
+            pump_flow_ml_per_min: v7.pump_flow_ml_per_min,
+            pump_duty_percent: v7.pump_duty_percent,
+            prime_after_idle_secs: v7.prime_after_idle_secs,
+            prime_duration_secs: v7.prime_duration_secs,
+            prime_duty_percent: v7.prime_duty_percent,
+            nh3_activate_threshold_ppm: v7.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v7.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v7.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v7.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v7.nh3_oversample_count,
+            uvc_duty_percent: v7.uvc_duty_percent,
+            uvc_warmup_secs: v7.uvc_warmup_secs,
+            quiet_hours: v7.quiet_hours,
+            led_brightness: v7.led_brightness,
+            led_quiet_brightness: v7.led_quiet_brightness,
+            max_temperature_c: v7.max_temperature_c,
+            purge_duration_secs: v7.purge_duration_secs,
+            purge_mode: v7.purge_mode,
+            purge_target_volume_ml: v7.purge_target_volume_ml,
+            purge_max_duration_secs: v7.purge_max_duration_secs,
+            max_active_duration_secs: v7.max_active_duration_secs,
+            min_water_level_percent: v7.min_water_level_percent,
+            fault_recovery_cooldown_secs: v7.fault_recovery_cooldown_secs,
+            sensor_read_interval_ms: v7.sensor_read_interval_ms,
+            control_loop_interval_ms: v7.control_loop_interval_ms,
+            telemetry_interval_secs: v7.telemetry_interval_secs,
+            power_profile: v7.power_profile,
+            stop_lockout_secs: v7.stop_lockout_secs,
+            self_test_blocks_active: v7.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v8: before the interlock grace period.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV8 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV8> for SystemConfig {
+    fn from(v8: SystemConfigV8) -> Self {
+        Self {
+            pump_flow_ml_per_min: v8.pump_flow_ml_per_min,
+            pump_duty_percent: v8.pump_duty_percent,
+            prime_after_idle_secs: v8.prime_after_idle_secs,
+            prime_duration_secs: v8.prime_duration_secs,
+            prime_duty_percent: v8.prime_duty_percent,
+            nh3_activate_threshold_ppm: v8.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v8.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v8.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v8.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v8.nh3_oversample_count,
+            uvc_duty_percent: v8.uvc_duty_percent,
+            uvc_warmup_secs: v8.uvc_warmup_secs,
+            quiet_hours: v8.quiet_hours,
+            led_brightness: v8.led_brightness,
+            led_quiet_brightness: v8.led_quiet_brightness,
+            led_overrides: v8.led_overrides,
+            max_temperature_c: v8.max_temperature_c,
+            purge_duration_secs: v8.purge_duration_secs,
+            purge_mode: v8.purge_mode,
+            purge_target_volume_ml: v8.purge_target_volume_ml,
+            purge_max_duration_secs: v8.purge_max_duration_secs,
+            max_active_duration_secs: v8.max_active_duration_secs,
+            min_water_level_percent: v8.min_water_level_percent,
+            fault_recovery_cooldown_secs: v8.fault_recovery_cooldown_secs,
+            sensor_read_interval_ms: v8.sensor_read_interval_ms,
+            control_loop_interval_ms: v8.control_loop_interval_ms,
+            telemetry_interval_secs: v8.telemetry_interval_secs,
+            power_profile: v8.power_profile,
+            stop_lockout_secs: v8.stop_lockout_secs,
+            self_test_blocks_active: v8.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v9: before the flow glitch filter.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV9 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV9> for SystemConfig {
+    fn from(v9: SystemConfigV9) -> Self {
+        Self {
+            pump_flow_ml_per_min: v9.pump_flow_ml_per_min,
+            pump_duty_percent: v9.pump_duty_percent,
+            prime_after_idle_secs: v9.prime_after_idle_secs,
+            prime_duration_secs: v9.prime_duration_secs,
+            prime_duty_percent: v9.prime_duty_percent,
+            nh3_activate_threshold_ppm: v9.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v9.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v9.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v9.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v9.nh3_oversample_count,
+            uvc_duty_percent: v9.uvc_duty_percent,
+            uvc_warmup_secs: v9.uvc_warmup_secs,
+            quiet_hours: v9.quiet_hours,
+            led_brightness: v9.led_brightness,
+            led_quiet_brightness: v9.led_quiet_brightness,
+            led_overrides: v9.led_overrides,
+            max_temperature_c: v9.max_temperature_c,
+            purge_duration_secs: v9.purge_duration_secs,
+            purge_mode: v9.purge_mode,
+            purge_target_volume_ml: v9.purge_target_volume_ml,
+            purge_max_duration_secs: v9.purge_max_duration_secs,
+            max_active_duration_secs: v9.max_active_duration_secs,
+            min_water_level_percent: v9.min_water_level_percent,
+            fault_recovery_cooldown_secs: v9.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v9.interlock_grace_secs,
+            sensor_read_interval_ms: v9.sensor_read_interval_ms,
+            control_loop_interval_ms: v9.control_loop_interval_ms,
+            telemetry_interval_secs: v9.telemetry_interval_secs,
+            power_profile: v9.power_profile,
+            stop_lockout_secs: v9.stop_lockout_secs,
+            self_test_blocks_active: v9.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v10: before sensor staleness detection.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV10 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV10> for SystemConfig {
+    fn from(v10: SystemConfigV10) -> Self {
+        Self {
+            pump_flow_ml_per_min: v10.pump_flow_ml_per_min,
+            pump_duty_percent: v10.pump_duty_percent,
+            prime_after_idle_secs: v10.prime_after_idle_secs,
+            prime_duration_secs: v10.prime_duration_secs,
+            prime_duty_percent: v10.prime_duty_percent,
+            flow_glitch_filter_us: v10.flow_glitch_filter_us,
+            nh3_activate_threshold_ppm: v10.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v10.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v10.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v10.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v10.nh3_oversample_count,
+            uvc_duty_percent: v10.uvc_duty_percent,
+            uvc_warmup_secs: v10.uvc_warmup_secs,
+            quiet_hours: v10.quiet_hours,
+            led_brightness: v10.led_brightness,
+            led_quiet_brightness: v10.led_quiet_brightness,
+            led_overrides: v10.led_overrides,
+            max_temperature_c: v10.max_temperature_c,
+            purge_duration_secs: v10.purge_duration_secs,
+            purge_mode: v10.purge_mode,
+            purge_target_volume_ml: v10.purge_target_volume_ml,
+            purge_max_duration_secs: v10.purge_max_duration_secs,
+            max_active_duration_secs: v10.max_active_duration_secs,
+            min_water_level_percent: v10.min_water_level_percent,
+            fault_recovery_cooldown_secs: v10.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v10.interlock_grace_secs,
+            sensor_read_interval_ms: v10.sensor_read_interval_ms,
+            control_loop_interval_ms: v10.control_loop_interval_ms,
+            telemetry_interval_secs: v10.telemetry_interval_secs,
+            power_profile: v10.power_profile,
+            stop_lockout_secs: v10.stop_lockout_secs,
+            self_test_blocks_active: v10.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v11: before the soft-stop ramp.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV11 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV11> for SystemConfig {
+    fn from(v11: SystemConfigV11) -> Self {
+        Self {
+            pump_flow_ml_per_min: v11.pump_flow_ml_per_min,
+            pump_duty_percent: v11.pump_duty_percent,
+            prime_after_idle_secs: v11.prime_after_idle_secs,
+            prime_duration_secs: v11.prime_duration_secs,
+            prime_duty_percent: v11.prime_duty_percent,
+            flow_glitch_filter_us: v11.flow_glitch_filter_us,
+            nh3_activate_threshold_ppm: v11.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v11.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v11.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v11.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v11.nh3_oversample_count,
+            uvc_duty_percent: v11.uvc_duty_percent,
+            uvc_warmup_secs: v11.uvc_warmup_secs,
+            quiet_hours: v11.quiet_hours,
+            led_brightness: v11.led_brightness,
+            led_quiet_brightness: v11.led_quiet_brightness,
+            led_overrides: v11.led_overrides,
+            max_temperature_c: v11.max_temperature_c,
+            purge_duration_secs: v11.purge_duration_secs,
+            purge_mode: v11.purge_mode,
+            purge_target_volume_ml: v11.purge_target_volume_ml,
+            purge_max_duration_secs: v11.purge_max_duration_secs,
+            max_active_duration_secs: v11.max_active_duration_secs,
+            min_water_level_percent: v11.min_water_level_percent,
+            fault_recovery_cooldown_secs: v11.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v11.interlock_grace_secs,
+            sensor_stale_after_secs: v11.sensor_stale_after_secs,
+            sensor_read_interval_ms: v11.sensor_read_interval_ms,
+            control_loop_interval_ms: v11.control_loop_interval_ms,
+            telemetry_interval_secs: v11.telemetry_interval_secs,
+            power_profile: v11.power_profile,
+            stop_lockout_secs: v11.stop_lockout_secs,
+            self_test_blocks_active: v11.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v12: before the over-temperature debounce.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV12 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV12> for SystemConfig {
+    fn from(v12: SystemConfigV12) -> Self {
+        Self {
+            pump_flow_ml_per_min: v12.pump_flow_ml_per_min,
+            pump_duty_percent: v12.pump_duty_percent,
+            prime_after_idle_secs: v12.prime_after_idle_secs,
+            prime_duration_secs: v12.prime_duration_secs,
+            prime_duty_percent: v12.prime_duty_percent,
+            flow_glitch_filter_us: v12.flow_glitch_filter_us,
+            soft_stop_ticks: v12.soft_stop_ticks,
+            nh3_activate_threshold_ppm: v12.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v12.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v12.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v12.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v12.nh3_oversample_count,
+            uvc_duty_percent: v12.uvc_duty_percent,
+            uvc_warmup_secs: v12.uvc_warmup_secs,
+            quiet_hours: v12.quiet_hours,
+            led_brightness: v12.led_brightness,
+            led_quiet_brightness: v12.led_quiet_brightness,
+            led_overrides: v12.led_overrides,
+            max_temperature_c: v12.max_temperature_c,
+            purge_duration_secs: v12.purge_duration_secs,
+            purge_mode: v12.purge_mode,
+            purge_target_volume_ml: v12.purge_target_volume_ml,
+            purge_max_duration_secs: v12.purge_max_duration_secs,
+            max_active_duration_secs: v12.max_active_duration_secs,
+            min_water_level_percent: v12.min_water_level_percent,
+            fault_recovery_cooldown_secs: v12.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v12.interlock_grace_secs,
+            sensor_stale_after_secs: v12.sensor_stale_after_secs,
+            sensor_read_interval_ms: v12.sensor_read_interval_ms,
+            control_loop_interval_ms: v12.control_loop_interval_ms,
+            telemetry_interval_secs: v12.telemetry_interval_secs,
+            power_profile: v12.power_profile,
+            stop_lockout_secs: v12.stop_lockout_secs,
+            self_test_blocks_active: v12.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v13: before the NH3 rate-of-rise trigger.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV13 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV13> for SystemConfig {
+    fn from(v13: SystemConfigV13) -> Self {
+        Self {
+            pump_flow_ml_per_min: v13.pump_flow_ml_per_min,
+            pump_duty_percent: v13.pump_duty_percent,
+            prime_after_idle_secs: v13.prime_after_idle_secs,
+            prime_duration_secs: v13.prime_duration_secs,
+            prime_duty_percent: v13.prime_duty_percent,
+            flow_glitch_filter_us: v13.flow_glitch_filter_us,
+            soft_stop_ticks: v13.soft_stop_ticks,
+            nh3_activate_threshold_ppm: v13.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v13.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v13.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v13.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v13.nh3_oversample_count,
+            uvc_duty_percent: v13.uvc_duty_percent,
+            uvc_warmup_secs: v13.uvc_warmup_secs,
+            quiet_hours: v13.quiet_hours,
+            led_brightness: v13.led_brightness,
+            led_quiet_brightness: v13.led_quiet_brightness,
+            led_overrides: v13.led_overrides,
+            max_temperature_c: v13.max_temperature_c,
+            over_temp_debounce_samples: v13.over_temp_debounce_samples,
+            purge_duration_secs: v13.purge_duration_secs,
+            purge_mode: v13.purge_mode,
+            purge_target_volume_ml: v13.purge_target_volume_ml,
+            purge_max_duration_secs: v13.purge_max_duration_secs,
+            max_active_duration_secs: v13.max_active_duration_secs,
+            min_water_level_percent: v13.min_water_level_percent,
+            fault_recovery_cooldown_secs: v13.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v13.interlock_grace_secs,
+            sensor_stale_after_secs: v13.sensor_stale_after_secs,
+            sensor_read_interval_ms: v13.sensor_read_interval_ms,
+            control_loop_interval_ms: v13.control_loop_interval_ms,
+            telemetry_interval_secs: v13.telemetry_interval_secs,
+            power_profile: v13.power_profile,
+            stop_lockout_secs: v13.stop_lockout_secs,
+            self_test_blocks_active: v13.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v14: before maintenance mode.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV14 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+}
+
+impl From<SystemConfigV14> for SystemConfig {
+    fn from(v14: SystemConfigV14) -> Self {
+        Self {
+            pump_flow_ml_per_min: v14.pump_flow_ml_per_min,
+            pump_duty_percent: v14.pump_duty_percent,
+            prime_after_idle_secs: v14.prime_after_idle_secs,
+            prime_duration_secs: v14.prime_duration_secs,
+            prime_duty_percent: v14.prime_duty_percent,
+            flow_glitch_filter_us: v14.flow_glitch_filter_us,
+            soft_stop_ticks: v14.soft_stop_ticks,
+            nh3_activate_threshold_ppm: v14.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v14.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v14.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v14.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v14.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v14.nh3_rate_threshold_ppm_per_sec,
+            uvc_duty_percent: v14.uvc_duty_percent,
+            uvc_warmup_secs: v14.uvc_warmup_secs,
+            quiet_hours: v14.quiet_hours,
+            led_brightness: v14.led_brightness,
+            led_quiet_brightness: v14.led_quiet_brightness,
+            led_overrides: v14.led_overrides,
+            max_temperature_c: v14.max_temperature_c,
+            over_temp_debounce_samples: v14.over_temp_debounce_samples,
+            purge_duration_secs: v14.purge_duration_secs,
+            purge_mode: v14.purge_mode,
+            purge_target_volume_ml: v14.purge_target_volume_ml,
+            purge_max_duration_secs: v14.purge_max_duration_secs,
+            max_active_duration_secs: v14.max_active_duration_secs,
+            min_water_level_percent: v14.min_water_level_percent,
+            fault_recovery_cooldown_secs: v14.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v14.interlock_grace_secs,
+            sensor_stale_after_secs: v14.sensor_stale_after_secs,
+            sensor_read_interval_ms: v14.sensor_read_interval_ms,
+            control_loop_interval_ms: v14.control_loop_interval_ms,
+            telemetry_interval_secs: v14.telemetry_interval_secs,
+            power_profile: v14.power_profile,
+            stop_lockout_secs: v14.stop_lockout_secs,
+            self_test_blocks_active: v14.self_test_blocks_active,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v15: before the NH3 sensor warm-up soak.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV15 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV15> for SystemConfig {
+    fn from(v15: SystemConfigV15) -> Self {
+        Self {
+            pump_flow_ml_per_min: v15.pump_flow_ml_per_min,
+            pump_duty_percent: v15.pump_duty_percent,
+            prime_after_idle_secs: v15.prime_after_idle_secs,
+            prime_duration_secs: v15.prime_duration_secs,
+            prime_duty_percent: v15.prime_duty_percent,
+            flow_glitch_filter_us: v15.flow_glitch_filter_us,
+            soft_stop_ticks: v15.soft_stop_ticks,
+            nh3_activate_threshold_ppm: v15.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v15.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v15.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v15.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v15.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v15.nh3_rate_threshold_ppm_per_sec,
+            uvc_duty_percent: v15.uvc_duty_percent,
+            uvc_warmup_secs: v15.uvc_warmup_secs,
+            quiet_hours: v15.quiet_hours,
+            led_brightness: v15.led_brightness,
+            led_quiet_brightness: v15.led_quiet_brightness,
+            led_overrides: v15.led_overrides,
+            max_temperature_c: v15.max_temperature_c,
+            over_temp_debounce_samples: v15.over_temp_debounce_samples,
+            purge_duration_secs: v15.purge_duration_secs,
+            purge_mode: v15.purge_mode,
+            purge_target_volume_ml: v15.purge_target_volume_ml,
+            purge_max_duration_secs: v15.purge_max_duration_secs,
+            max_active_duration_secs: v15.max_active_duration_secs,
+            min_water_level_percent: v15.min_water_level_percent,
+            fault_recovery_cooldown_secs: v15.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v15.interlock_grace_secs,
+            sensor_stale_after_secs: v15.sensor_stale_after_secs,
+            sensor_read_interval_ms: v15.sensor_read_interval_ms,
+            control_loop_interval_ms: v15.control_loop_interval_ms,
+            telemetry_interval_secs: v15.telemetry_interval_secs,
+            power_profile: v15.power_profile,
+            stop_lockout_secs: v15.stop_lockout_secs,
+            self_test_blocks_active: v15.self_test_blocks_active,
+            maintenance_mode: v15.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v16: before selectable source tank.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV16 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV16> for SystemConfig {
+    fn from(v16: SystemConfigV16) -> Self {
+        Self {
+            pump_flow_ml_per_min: v16.pump_flow_ml_per_min,
+            pump_duty_percent: v16.pump_duty_percent,
+            prime_after_idle_secs: v16.prime_after_idle_secs,
+            prime_duration_secs: v16.prime_duration_secs,
+            prime_duty_percent: v16.prime_duty_percent,
+            flow_glitch_filter_us: v16.flow_glitch_filter_us,
+            soft_stop_ticks: v16.soft_stop_ticks,
+            nh3_activate_threshold_ppm: v16.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v16.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v16.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v16.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v16.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v16.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v16.nh3_warmup_secs,
+            uvc_duty_percent: v16.uvc_duty_percent,
+            uvc_warmup_secs: v16.uvc_warmup_secs,
+            quiet_hours: v16.quiet_hours,
+            led_brightness: v16.led_brightness,
+            led_quiet_brightness: v16.led_quiet_brightness,
+            led_overrides: v16.led_overrides,
+            max_temperature_c: v16.max_temperature_c,
+            over_temp_debounce_samples: v16.over_temp_debounce_samples,
+            purge_duration_secs: v16.purge_duration_secs,
+            purge_mode: v16.purge_mode,
+            purge_target_volume_ml: v16.purge_target_volume_ml,
+            purge_max_duration_secs: v16.purge_max_duration_secs,
+            max_active_duration_secs: v16.max_active_duration_secs,
+            min_water_level_percent: v16.min_water_level_percent,
+            fault_recovery_cooldown_secs: v16.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v16.interlock_grace_secs,
+            sensor_stale_after_secs: v16.sensor_stale_after_secs,
+            sensor_read_interval_ms: v16.sensor_read_interval_ms,
+            control_loop_interval_ms: v16.control_loop_interval_ms,
+            telemetry_interval_secs: v16.telemetry_interval_secs,
+            power_profile: v16.power_profile,
+            stop_lockout_secs: v16.stop_lockout_secs,
+            self_test_blocks_active: v16.self_test_blocks_active,
+            maintenance_mode: v16.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v17: before the scrub minimum runtime.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV17 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV17> for SystemConfig {
+    fn from(v17: SystemConfigV17) -> Self {
+        Self {
+            pump_flow_ml_per_min: v17.pump_flow_ml_per_min,
+            pump_duty_percent: v17.pump_duty_percent,
+            prime_after_idle_secs: v17.prime_after_idle_secs,
+            prime_duration_secs: v17.prime_duration_secs,
+            prime_duty_percent: v17.prime_duty_percent,
+            flow_glitch_filter_us: v17.flow_glitch_filter_us,
+            soft_stop_ticks: v17.soft_stop_ticks,
+            tank_source: v17.tank_source,
+            nh3_activate_threshold_ppm: v17.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v17.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v17.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v17.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v17.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v17.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v17.nh3_warmup_secs,
+            uvc_duty_percent: v17.uvc_duty_percent,
+            uvc_warmup_secs: v17.uvc_warmup_secs,
+            quiet_hours: v17.quiet_hours,
+            led_brightness: v17.led_brightness,
+            led_quiet_brightness: v17.led_quiet_brightness,
+            led_overrides: v17.led_overrides,
+            max_temperature_c: v17.max_temperature_c,
+            over_temp_debounce_samples: v17.over_temp_debounce_samples,
+            purge_duration_secs: v17.purge_duration_secs,
+            purge_mode: v17.purge_mode,
+            purge_target_volume_ml: v17.purge_target_volume_ml,
+            purge_max_duration_secs: v17.purge_max_duration_secs,
+            max_active_duration_secs: v17.max_active_duration_secs,
+            min_water_level_percent: v17.min_water_level_percent,
+            fault_recovery_cooldown_secs: v17.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v17.interlock_grace_secs,
+            sensor_stale_after_secs: v17.sensor_stale_after_secs,
+            sensor_read_interval_ms: v17.sensor_read_interval_ms,
+            control_loop_interval_ms: v17.control_loop_interval_ms,
+            telemetry_interval_secs: v17.telemetry_interval_secs,
+            power_profile: v17.power_profile,
+            stop_lockout_secs: v17.stop_lockout_secs,
+            self_test_blocks_active: v17.self_test_blocks_active,
+            maintenance_mode: v17.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+/// Schema v18: before configurable LED overlay priority.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV18 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV18> for SystemConfig {
+    fn from(v18: SystemConfigV18) -> Self {
+        Self {
+            pump_flow_ml_per_min: v18.pump_flow_ml_per_min,
+            pump_duty_percent: v18.pump_duty_percent,
+            prime_after_idle_secs: v18.prime_after_idle_secs,
+            prime_duration_secs: v18.prime_duration_secs,
+            prime_duty_percent: v18.prime_duty_percent,
+            flow_glitch_filter_us: v18.flow_glitch_filter_us,
+            soft_stop_ticks: v18.soft_stop_ticks,
+            tank_source: v18.tank_source,
+            nh3_activate_threshold_ppm: v18.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v18.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v18.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v18.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v18.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v18.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v18.nh3_warmup_secs,
+            uvc_duty_percent: v18.uvc_duty_percent,
+            uvc_warmup_secs: v18.uvc_warmup_secs,
+            quiet_hours: v18.quiet_hours,
+            led_brightness: v18.led_brightness,
+            led_quiet_brightness: v18.led_quiet_brightness,
+            led_overrides: v18.led_overrides,
+            max_temperature_c: v18.max_temperature_c,
+            over_temp_debounce_samples: v18.over_temp_debounce_samples,
+            purge_duration_secs: v18.purge_duration_secs,
+            purge_mode: v18.purge_mode,
+            purge_target_volume_ml: v18.purge_target_volume_ml,
+            purge_max_duration_secs: v18.purge_max_duration_secs,
+            max_active_duration_secs: v18.max_active_duration_secs,
+            min_active_secs: v18.min_active_secs,
+            min_water_level_percent: v18.min_water_level_percent,
+            fault_recovery_cooldown_secs: v18.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v18.interlock_grace_secs,
+            sensor_stale_after_secs: v18.sensor_stale_after_secs,
+            sensor_read_interval_ms: v18.sensor_read_interval_ms,
+            control_loop_interval_ms: v18.control_loop_interval_ms,
+            telemetry_interval_secs: v18.telemetry_interval_secs,
+            power_profile: v18.power_profile,
+            stop_lockout_secs: v18.stop_lockout_secs,
+            self_test_blocks_active: v18.self_test_blocks_active,
+            maintenance_mode: v18.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV19 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV19> for SystemConfig {
+    fn from(v19: SystemConfigV19) -> Self {
+        Self {
+            pump_flow_ml_per_min: v19.pump_flow_ml_per_min,
+            pump_duty_percent: v19.pump_duty_percent,
+            prime_after_idle_secs: v19.prime_after_idle_secs,
+            prime_duration_secs: v19.prime_duration_secs,
+            prime_duty_percent: v19.prime_duty_percent,
+            flow_glitch_filter_us: v19.flow_glitch_filter_us,
+            soft_stop_ticks: v19.soft_stop_ticks,
+            tank_source: v19.tank_source,
+            nh3_activate_threshold_ppm: v19.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v19.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v19.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v19.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v19.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v19.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v19.nh3_warmup_secs,
+            uvc_duty_percent: v19.uvc_duty_percent,
+            uvc_warmup_secs: v19.uvc_warmup_secs,
+            quiet_hours: v19.quiet_hours,
+            led_brightness: v19.led_brightness,
+            led_quiet_brightness: v19.led_quiet_brightness,
+            led_overrides: v19.led_overrides,
+            led_overlay_priority: v19.led_overlay_priority,
+            max_temperature_c: v19.max_temperature_c,
+            over_temp_debounce_samples: v19.over_temp_debounce_samples,
+            purge_duration_secs: v19.purge_duration_secs,
+            purge_mode: v19.purge_mode,
+            purge_target_volume_ml: v19.purge_target_volume_ml,
+            purge_max_duration_secs: v19.purge_max_duration_secs,
+            max_active_duration_secs: v19.max_active_duration_secs,
+            min_active_secs: v19.min_active_secs,
+            min_water_level_percent: v19.min_water_level_percent,
+            fault_recovery_cooldown_secs: v19.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v19.interlock_grace_secs,
+            sensor_stale_after_secs: v19.sensor_stale_after_secs,
+            sensor_read_interval_ms: v19.sensor_read_interval_ms,
+            control_loop_interval_ms: v19.control_loop_interval_ms,
+            telemetry_interval_secs: v19.telemetry_interval_secs,
+            power_profile: v19.power_profile,
+            stop_lockout_secs: v19.stop_lockout_secs,
+            self_test_blocks_active: v19.self_test_blocks_active,
+            maintenance_mode: v19.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV20 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV20> for SystemConfig {
+    fn from(v20: SystemConfigV20) -> Self {
+        Self {
+            pump_flow_ml_per_min: v20.pump_flow_ml_per_min,
+            pump_duty_percent: v20.pump_duty_percent,
+            prime_after_idle_secs: v20.prime_after_idle_secs,
+            prime_duration_secs: v20.prime_duration_secs,
+            prime_duty_percent: v20.prime_duty_percent,
+            flow_glitch_filter_us: v20.flow_glitch_filter_us,
+            soft_stop_ticks: v20.soft_stop_ticks,
+            tank_source: v20.tank_source,
+            nh3_activate_threshold_ppm: v20.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v20.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v20.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v20.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v20.nh3_oversample_count,
+            nh3_rate_threshold_ppm_per_sec: v20.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v20.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v20.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v20.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v20.uvc_duty_percent,
+            uvc_warmup_secs: v20.uvc_warmup_secs,
+            quiet_hours: v20.quiet_hours,
+            led_brightness: v20.led_brightness,
+            led_quiet_brightness: v20.led_quiet_brightness,
+            led_overrides: v20.led_overrides,
+            led_overlay_priority: v20.led_overlay_priority,
+            max_temperature_c: v20.max_temperature_c,
+            over_temp_debounce_samples: v20.over_temp_debounce_samples,
+            purge_duration_secs: v20.purge_duration_secs,
+            purge_mode: v20.purge_mode,
+            purge_target_volume_ml: v20.purge_target_volume_ml,
+            purge_max_duration_secs: v20.purge_max_duration_secs,
+            max_active_duration_secs: v20.max_active_duration_secs,
+            min_active_secs: v20.min_active_secs,
+            min_water_level_percent: v20.min_water_level_percent,
+            fault_recovery_cooldown_secs: v20.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v20.interlock_grace_secs,
+            sensor_stale_after_secs: v20.sensor_stale_after_secs,
+            sensor_read_interval_ms: v20.sensor_read_interval_ms,
+            control_loop_interval_ms: v20.control_loop_interval_ms,
+            telemetry_interval_secs: v20.telemetry_interval_secs,
+            power_profile: v20.power_profile,
+            stop_lockout_secs: v20.stop_lockout_secs,
+            self_test_blocks_active: v20.self_test_blocks_active,
+            maintenance_mode: v20.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV21 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV21> for SystemConfig {
+    fn from(v21: SystemConfigV21) -> Self {
+        Self {
+            pump_flow_ml_per_min: v21.pump_flow_ml_per_min,
+            pump_duty_percent: v21.pump_duty_percent,
+            prime_after_idle_secs: v21.prime_after_idle_secs,
+            prime_duration_secs: v21.prime_duration_secs,
+            prime_duty_percent: v21.prime_duty_percent,
+            flow_glitch_filter_us: v21.flow_glitch_filter_us,
+            soft_stop_ticks: v21.soft_stop_ticks,
+            tank_source: v21.tank_source,
+            nh3_activate_threshold_ppm: v21.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v21.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v21.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v21.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v21.nh3_oversample_count,
+            nh3_adc_samples: v21.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v21.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v21.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v21.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v21.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v21.uvc_duty_percent,
+            uvc_warmup_secs: v21.uvc_warmup_secs,
+            quiet_hours: v21.quiet_hours,
+            led_brightness: v21.led_brightness,
+            led_quiet_brightness: v21.led_quiet_brightness,
+            led_overrides: v21.led_overrides,
+            led_overlay_priority: v21.led_overlay_priority,
+            max_temperature_c: v21.max_temperature_c,
+            over_temp_debounce_samples: v21.over_temp_debounce_samples,
+            purge_duration_secs: v21.purge_duration_secs,
+            purge_mode: v21.purge_mode,
+            purge_target_volume_ml: v21.purge_target_volume_ml,
+            purge_max_duration_secs: v21.purge_max_duration_secs,
+            max_active_duration_secs: v21.max_active_duration_secs,
+            min_active_secs: v21.min_active_secs,
+            min_water_level_percent: v21.min_water_level_percent,
+            fault_recovery_cooldown_secs: v21.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v21.interlock_grace_secs,
+            sensor_stale_after_secs: v21.sensor_stale_after_secs,
+            sensor_read_interval_ms: v21.sensor_read_interval_ms,
+            control_loop_interval_ms: v21.control_loop_interval_ms,
+            telemetry_interval_secs: v21.telemetry_interval_secs,
+            power_profile: v21.power_profile,
+            stop_lockout_secs: v21.stop_lockout_secs,
+            self_test_blocks_active: v21.self_test_blocks_active,
+            maintenance_mode: v21.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV22 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV22> for SystemConfig {
+    fn from(v22: SystemConfigV22) -> Self {
+        Self {
+            pump_flow_ml_per_min: v22.pump_flow_ml_per_min,
+            pump_duty_percent: v22.pump_duty_percent,
+            prime_after_idle_secs: v22.prime_after_idle_secs,
+            prime_duration_secs: v22.prime_duration_secs,
+            prime_duty_percent: v22.prime_duty_percent,
+            flow_glitch_filter_us: v22.flow_glitch_filter_us,
+            soft_stop_ticks: v22.soft_stop_ticks,
+            tank_source: v22.tank_source,
+            nh3_activate_threshold_ppm: v22.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v22.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v22.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v22.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v22.nh3_oversample_count,
+            nh3_adc_samples: v22.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v22.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v22.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v22.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v22.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v22.uvc_duty_percent,
+            uvc_warmup_secs: v22.uvc_warmup_secs,
+            quiet_hours: v22.quiet_hours,
+            led_brightness: v22.led_brightness,
+            led_quiet_brightness: v22.led_quiet_brightness,
+            led_overrides: v22.led_overrides,
+            led_overlay_priority: v22.led_overlay_priority,
+            max_temperature_c: v22.max_temperature_c,
+            over_temp_debounce_samples: v22.over_temp_debounce_samples,
+            pump_derate_start_c: v22.pump_derate_start_c,
+            pump_derate_min_duty_percent: v22.pump_derate_min_duty_percent,
+            purge_duration_secs: v22.purge_duration_secs,
+            purge_mode: v22.purge_mode,
+            purge_target_volume_ml: v22.purge_target_volume_ml,
+            purge_max_duration_secs: v22.purge_max_duration_secs,
+            max_active_duration_secs: v22.max_active_duration_secs,
+            min_active_secs: v22.min_active_secs,
+            min_water_level_percent: v22.min_water_level_percent,
+            fault_recovery_cooldown_secs: v22.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v22.interlock_grace_secs,
+            sensor_stale_after_secs: v22.sensor_stale_after_secs,
+            sensor_read_interval_ms: v22.sensor_read_interval_ms,
+            control_loop_interval_ms: v22.control_loop_interval_ms,
+            telemetry_interval_secs: v22.telemetry_interval_secs,
+            power_profile: v22.power_profile,
+            stop_lockout_secs: v22.stop_lockout_secs,
+            self_test_blocks_active: v22.self_test_blocks_active,
+            maintenance_mode: v22.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV23 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV23> for SystemConfig {
+    fn from(v23: SystemConfigV23) -> Self {
+        Self {
+            pump_flow_ml_per_min: v23.pump_flow_ml_per_min,
+            pump_duty_percent: v23.pump_duty_percent,
+            prime_after_idle_secs: v23.prime_after_idle_secs,
+            prime_duration_secs: v23.prime_duration_secs,
+            prime_duty_percent: v23.prime_duty_percent,
+            flow_glitch_filter_us: v23.flow_glitch_filter_us,
+            soft_stop_ticks: v23.soft_stop_ticks,
+            tank_source: v23.tank_source,
+            nh3_activate_threshold_ppm: v23.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v23.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v23.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v23.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v23.nh3_oversample_count,
+            nh3_adc_samples: v23.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v23.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v23.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v23.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v23.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v23.uvc_duty_percent,
+            uvc_warmup_secs: v23.uvc_warmup_secs,
+            quiet_hours: v23.quiet_hours,
+            led_brightness: v23.led_brightness,
+            led_quiet_brightness: v23.led_quiet_brightness,
+            led_overrides: v23.led_overrides,
+            led_overlay_priority: v23.led_overlay_priority,
+            max_temperature_c: v23.max_temperature_c,
+            over_temp_debounce_samples: v23.over_temp_debounce_samples,
+            pump_derate_start_c: v23.pump_derate_start_c,
+            pump_derate_min_duty_percent: v23.pump_derate_min_duty_percent,
+            purge_duration_secs: v23.purge_duration_secs,
+            purge_mode: v23.purge_mode,
+            purge_target_volume_ml: v23.purge_target_volume_ml,
+            purge_max_duration_secs: v23.purge_max_duration_secs,
+            max_active_duration_secs: v23.max_active_duration_secs,
+            min_active_secs: v23.min_active_secs,
+            min_water_level_percent: v23.min_water_level_percent,
+            fault_recovery_cooldown_secs: v23.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v23.interlock_grace_secs,
+            interlock_active_low: v23.interlock_active_low,
+            sensor_stale_after_secs: v23.sensor_stale_after_secs,
+            sensor_read_interval_ms: v23.sensor_read_interval_ms,
+            control_loop_interval_ms: v23.control_loop_interval_ms,
+            telemetry_interval_secs: v23.telemetry_interval_secs,
+            power_profile: v23.power_profile,
+            stop_lockout_secs: v23.stop_lockout_secs,
+            self_test_blocks_active: v23.self_test_blocks_active,
+            maintenance_mode: v23.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV24 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV24> for SystemConfig {
+    fn from(v24: SystemConfigV24) -> Self {
+        Self {
+            pump_flow_ml_per_min: v24.pump_flow_ml_per_min,
+            pump_duty_percent: v24.pump_duty_percent,
+            prime_after_idle_secs: v24.prime_after_idle_secs,
+            prime_duration_secs: v24.prime_duration_secs,
+            prime_duty_percent: v24.prime_duty_percent,
+            flow_glitch_filter_us: v24.flow_glitch_filter_us,
+            soft_stop_ticks: v24.soft_stop_ticks,
+            tank_source: v24.tank_source,
+            nh3_activate_threshold_ppm: v24.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v24.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v24.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v24.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v24.nh3_oversample_count,
+            nh3_adc_samples: v24.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v24.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v24.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v24.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v24.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v24.uvc_duty_percent,
+            uvc_warmup_secs: v24.uvc_warmup_secs,
+            quiet_hours: v24.quiet_hours,
+            led_brightness: v24.led_brightness,
+            led_quiet_brightness: v24.led_quiet_brightness,
+            led_overrides: v24.led_overrides,
+            led_overlay_priority: v24.led_overlay_priority,
+            max_temperature_c: v24.max_temperature_c,
+            over_temp_debounce_samples: v24.over_temp_debounce_samples,
+            pump_derate_start_c: v24.pump_derate_start_c,
+            pump_derate_min_duty_percent: v24.pump_derate_min_duty_percent,
+            purge_duration_secs: v24.purge_duration_secs,
+            purge_mode: v24.purge_mode,
+            purge_target_volume_ml: v24.purge_target_volume_ml,
+            purge_max_duration_secs: v24.purge_max_duration_secs,
+            max_active_duration_secs: v24.max_active_duration_secs,
+            min_active_secs: v24.min_active_secs,
+            state_max_secs: v24.state_max_secs,
+            min_water_level_percent: v24.min_water_level_percent,
+            fault_recovery_cooldown_secs: v24.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v24.interlock_grace_secs,
+            interlock_active_low: v24.interlock_active_low,
+            sensor_stale_after_secs: v24.sensor_stale_after_secs,
+            sensor_read_interval_ms: v24.sensor_read_interval_ms,
+            control_loop_interval_ms: v24.control_loop_interval_ms,
+            telemetry_interval_secs: v24.telemetry_interval_secs,
+            power_profile: v24.power_profile,
+            stop_lockout_secs: v24.stop_lockout_secs,
+            self_test_blocks_active: v24.self_test_blocks_active,
+            maintenance_mode: v24.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV25 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV25> for SystemConfig {
+    fn from(v25: SystemConfigV25) -> Self {
+        Self {
+            pump_flow_ml_per_min: v25.pump_flow_ml_per_min,
+            pump_duty_percent: v25.pump_duty_percent,
+            prime_after_idle_secs: v25.prime_after_idle_secs,
+            prime_duration_secs: v25.prime_duration_secs,
+            prime_duty_percent: v25.prime_duty_percent,
+            flow_glitch_filter_us: v25.flow_glitch_filter_us,
+            soft_stop_ticks: v25.soft_stop_ticks,
+            tank_source: v25.tank_source,
+            nh3_activate_threshold_ppm: v25.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v25.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v25.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v25.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v25.nh3_oversample_count,
+            nh3_adc_samples: v25.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v25.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v25.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v25.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v25.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v25.uvc_duty_percent,
+            uvc_warmup_secs: v25.uvc_warmup_secs,
+            quiet_hours: v25.quiet_hours,
+            led_brightness: v25.led_brightness,
+            led_quiet_brightness: v25.led_quiet_brightness,
+            led_overrides: v25.led_overrides,
+            led_overlay_priority: v25.led_overlay_priority,
+            max_temperature_c: v25.max_temperature_c,
+            over_temp_debounce_samples: v25.over_temp_debounce_samples,
+            pump_derate_start_c: v25.pump_derate_start_c,
+            pump_derate_min_duty_percent: v25.pump_derate_min_duty_percent,
+            purge_duration_secs: v25.purge_duration_secs,
+            purge_mode: v25.purge_mode,
+            purge_target_volume_ml: v25.purge_target_volume_ml,
+            purge_max_duration_secs: v25.purge_max_duration_secs,
+            max_active_duration_secs: v25.max_active_duration_secs,
+            min_active_secs: v25.min_active_secs,
+            state_max_secs: v25.state_max_secs,
+            min_water_level_percent: v25.min_water_level_percent,
+            fault_recovery_cooldown_secs: v25.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v25.interlock_grace_secs,
+            interlock_active_low: v25.interlock_active_low,
+            sensor_stale_after_secs: v25.sensor_stale_after_secs,
+            sensor_read_interval_ms: v25.sensor_read_interval_ms,
+            control_loop_interval_ms: v25.control_loop_interval_ms,
+            telemetry_interval_secs: v25.telemetry_interval_secs,
+            ble_status_interval_secs: v25.ble_status_interval_secs,
+            power_profile: v25.power_profile,
+            stop_lockout_secs: v25.stop_lockout_secs,
+            self_test_blocks_active: v25.self_test_blocks_active,
+            maintenance_mode: v25.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV26 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV26> for SystemConfig {
+    fn from(v26: SystemConfigV26) -> Self {
+        Self {
+            pump_flow_ml_per_min: v26.pump_flow_ml_per_min,
+            pump_duty_percent: v26.pump_duty_percent,
+            prime_after_idle_secs: v26.prime_after_idle_secs,
+            prime_duration_secs: v26.prime_duration_secs,
+            prime_duty_percent: v26.prime_duty_percent,
+            flow_glitch_filter_us: v26.flow_glitch_filter_us,
+            soft_stop_ticks: v26.soft_stop_ticks,
+            tank_source: v26.tank_source,
+            pump_pid_kp: v26.pump_pid_kp,
+            pump_pid_ki: v26.pump_pid_ki,
+            pump_pid_kd: v26.pump_pid_kd,
+            nh3_activate_threshold_ppm: v26.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v26.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v26.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v26.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v26.nh3_oversample_count,
+            nh3_adc_samples: v26.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v26.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v26.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v26.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v26.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v26.uvc_duty_percent,
+            uvc_warmup_secs: v26.uvc_warmup_secs,
+            quiet_hours: v26.quiet_hours,
+            led_brightness: v26.led_brightness,
+            led_quiet_brightness: v26.led_quiet_brightness,
+            led_overrides: v26.led_overrides,
+            led_overlay_priority: v26.led_overlay_priority,
+            max_temperature_c: v26.max_temperature_c,
+            over_temp_debounce_samples: v26.over_temp_debounce_samples,
+            pump_derate_start_c: v26.pump_derate_start_c,
+            pump_derate_min_duty_percent: v26.pump_derate_min_duty_percent,
+            purge_duration_secs: v26.purge_duration_secs,
+            purge_mode: v26.purge_mode,
+            purge_target_volume_ml: v26.purge_target_volume_ml,
+            purge_max_duration_secs: v26.purge_max_duration_secs,
+            max_active_duration_secs: v26.max_active_duration_secs,
+            min_active_secs: v26.min_active_secs,
+            state_max_secs: v26.state_max_secs,
+            min_water_level_percent: v26.min_water_level_percent,
+            fault_recovery_cooldown_secs: v26.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v26.interlock_grace_secs,
+            interlock_active_low: v26.interlock_active_low,
+            sensor_stale_after_secs: v26.sensor_stale_after_secs,
+            sensor_read_interval_ms: v26.sensor_read_interval_ms,
+            control_loop_interval_ms: v26.control_loop_interval_ms,
+            telemetry_interval_secs: v26.telemetry_interval_secs,
+            ble_status_interval_secs: v26.ble_status_interval_secs,
+            power_profile: v26.power_profile,
+            stop_lockout_secs: v26.stop_lockout_secs,
+            self_test_blocks_active: v26.self_test_blocks_active,
+            maintenance_mode: v26.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV27 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV27> for SystemConfig {
+    fn from(v27: SystemConfigV27) -> Self {
+        Self {
+            pump_flow_ml_per_min: v27.pump_flow_ml_per_min,
+            pump_duty_percent: v27.pump_duty_percent,
+            prime_after_idle_secs: v27.prime_after_idle_secs,
+            prime_duration_secs: v27.prime_duration_secs,
+            prime_duty_percent: v27.prime_duty_percent,
+            flow_glitch_filter_us: v27.flow_glitch_filter_us,
+            soft_stop_ticks: v27.soft_stop_ticks,
+            tank_source: v27.tank_source,
+            pump_pid_kp: v27.pump_pid_kp,
+            pump_pid_ki: v27.pump_pid_ki,
+            pump_pid_kd: v27.pump_pid_kd,
+            nh3_activate_threshold_ppm: v27.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v27.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v27.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v27.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v27.nh3_oversample_count,
+            nh3_adc_samples: v27.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v27.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v27.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v27.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v27.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v27.uvc_duty_percent,
+            uvc_warmup_secs: v27.uvc_warmup_secs,
+            quiet_hours: v27.quiet_hours,
+            paused_schedules: v27.paused_schedules,
+            led_brightness: v27.led_brightness,
+            led_quiet_brightness: v27.led_quiet_brightness,
+            led_overrides: v27.led_overrides,
+            led_overlay_priority: v27.led_overlay_priority,
+            max_temperature_c: v27.max_temperature_c,
+            over_temp_debounce_samples: v27.over_temp_debounce_samples,
+            pump_derate_start_c: v27.pump_derate_start_c,
+            pump_derate_min_duty_percent: v27.pump_derate_min_duty_percent,
+            purge_duration_secs: v27.purge_duration_secs,
+            purge_mode: v27.purge_mode,
+            purge_target_volume_ml: v27.purge_target_volume_ml,
+            purge_max_duration_secs: v27.purge_max_duration_secs,
+            max_active_duration_secs: v27.max_active_duration_secs,
+            min_active_secs: v27.min_active_secs,
+            state_max_secs: v27.state_max_secs,
+            min_water_level_percent: v27.min_water_level_percent,
+            fault_recovery_cooldown_secs: v27.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v27.interlock_grace_secs,
+            interlock_active_low: v27.interlock_active_low,
+            sensor_stale_after_secs: v27.sensor_stale_after_secs,
+            sensor_read_interval_ms: v27.sensor_read_interval_ms,
+            control_loop_interval_ms: v27.control_loop_interval_ms,
+            telemetry_interval_secs: v27.telemetry_interval_secs,
+            ble_status_interval_secs: v27.ble_status_interval_secs,
+            power_profile: v27.power_profile,
+            stop_lockout_secs: v27.stop_lockout_secs,
+            self_test_blocks_active: v27.self_test_blocks_active,
+            maintenance_mode: v27.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV28 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+}
+
+impl From<SystemConfigV28> for SystemConfig {
+    fn from(v28: SystemConfigV28) -> Self {
+        Self {
+            pump_flow_ml_per_min: v28.pump_flow_ml_per_min,
+            pump_duty_percent: v28.pump_duty_percent,
+            prime_after_idle_secs: v28.prime_after_idle_secs,
+            prime_duration_secs: v28.prime_duration_secs,
+            prime_duty_percent: v28.prime_duty_percent,
+            flow_glitch_filter_us: v28.flow_glitch_filter_us,
+            soft_stop_ticks: v28.soft_stop_ticks,
+            tank_source: v28.tank_source,
+            pump_pid_kp: v28.pump_pid_kp,
+            pump_pid_ki: v28.pump_pid_ki,
+            pump_pid_kd: v28.pump_pid_kd,
+            nh3_activate_threshold_ppm: v28.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v28.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v28.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v28.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v28.nh3_oversample_count,
+            nh3_adc_samples: v28.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v28.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v28.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v28.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v28.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v28.uvc_duty_percent,
+            uvc_warmup_secs: v28.uvc_warmup_secs,
+            quiet_hours: v28.quiet_hours,
+            paused_schedules: v28.paused_schedules,
+            periodic_schedule: v28.periodic_schedule,
+            led_brightness: v28.led_brightness,
+            led_quiet_brightness: v28.led_quiet_brightness,
+            led_overrides: v28.led_overrides,
+            led_overlay_priority: v28.led_overlay_priority,
+            max_temperature_c: v28.max_temperature_c,
+            over_temp_debounce_samples: v28.over_temp_debounce_samples,
+            pump_derate_start_c: v28.pump_derate_start_c,
+            pump_derate_min_duty_percent: v28.pump_derate_min_duty_percent,
+            purge_duration_secs: v28.purge_duration_secs,
+            purge_mode: v28.purge_mode,
+            purge_target_volume_ml: v28.purge_target_volume_ml,
+            purge_max_duration_secs: v28.purge_max_duration_secs,
+            max_active_duration_secs: v28.max_active_duration_secs,
+            min_active_secs: v28.min_active_secs,
+            state_max_secs: v28.state_max_secs,
+            min_water_level_percent: v28.min_water_level_percent,
+            fault_recovery_cooldown_secs: v28.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v28.interlock_grace_secs,
+            interlock_active_low: v28.interlock_active_low,
+            sensor_stale_after_secs: v28.sensor_stale_after_secs,
+            sensor_read_interval_ms: v28.sensor_read_interval_ms,
+            control_loop_interval_ms: v28.control_loop_interval_ms,
+            telemetry_interval_secs: v28.telemetry_interval_secs,
+            ble_status_interval_secs: v28.ble_status_interval_secs,
+            power_profile: v28.power_profile,
+            stop_lockout_secs: v28.stop_lockout_secs,
+            self_test_blocks_active: v28.self_test_blocks_active,
+            maintenance_mode: v28.maintenance_mode,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV29 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+    psk_grace_secs: u32,
+}
+
+impl From<SystemConfigV29> for SystemConfig {
+    fn from(v29: SystemConfigV29) -> Self {
+        Self {
+            pump_flow_ml_per_min: v29.pump_flow_ml_per_min,
+            pump_duty_percent: v29.pump_duty_percent,
+            prime_after_idle_secs: v29.prime_after_idle_secs,
+            prime_duration_secs: v29.prime_duration_secs,
+            prime_duty_percent: v29.prime_duty_percent,
+            flow_glitch_filter_us: v29.flow_glitch_filter_us,
+            soft_stop_ticks: v29.soft_stop_ticks,
+            tank_source: v29.tank_source,
+            pump_pid_kp: v29.pump_pid_kp,
+            pump_pid_ki: v29.pump_pid_ki,
+            pump_pid_kd: v29.pump_pid_kd,
+            nh3_activate_threshold_ppm: v29.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v29.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v29.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v29.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v29.nh3_oversample_count,
+            nh3_adc_samples: v29.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v29.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v29.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v29.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v29.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v29.uvc_duty_percent,
+            uvc_warmup_secs: v29.uvc_warmup_secs,
+            quiet_hours: v29.quiet_hours,
+            paused_schedules: v29.paused_schedules,
+            periodic_schedule: v29.periodic_schedule,
+            led_brightness: v29.led_brightness,
+            led_quiet_brightness: v29.led_quiet_brightness,
+            led_overrides: v29.led_overrides,
+            led_overlay_priority: v29.led_overlay_priority,
+            max_temperature_c: v29.max_temperature_c,
+            over_temp_debounce_samples: v29.over_temp_debounce_samples,
+            pump_derate_start_c: v29.pump_derate_start_c,
+            pump_derate_min_duty_percent: v29.pump_derate_min_duty_percent,
+            purge_duration_secs: v29.purge_duration_secs,
+            purge_mode: v29.purge_mode,
+            purge_target_volume_ml: v29.purge_target_volume_ml,
+            purge_max_duration_secs: v29.purge_max_duration_secs,
+            max_active_duration_secs: v29.max_active_duration_secs,
+            min_active_secs: v29.min_active_secs,
+            state_max_secs: v29.state_max_secs,
+            min_water_level_percent: v29.min_water_level_percent,
+            fault_recovery_cooldown_secs: v29.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v29.interlock_grace_secs,
+            interlock_active_low: v29.interlock_active_low,
+            sensor_stale_after_secs: v29.sensor_stale_after_secs,
+            sensor_read_interval_ms: v29.sensor_read_interval_ms,
+            control_loop_interval_ms: v29.control_loop_interval_ms,
+            telemetry_interval_secs: v29.telemetry_interval_secs,
+            ble_status_interval_secs: v29.ble_status_interval_secs,
+            power_profile: v29.power_profile,
+            stop_lockout_secs: v29.stop_lockout_secs,
+            self_test_blocks_active: v29.self_test_blocks_active,
+            maintenance_mode: v29.maintenance_mode,
+            psk_grace_secs: v29.psk_grace_secs,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV30 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    max_control_dt_ms: u32,
+    flow_filter_tau_ms: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+    psk_grace_secs: u32,
+}
+
+impl From<SystemConfigV30> for SystemConfig {
+    fn from(v30: SystemConfigV30) -> Self {
+        Self {
+            pump_flow_ml_per_min: v30.pump_flow_ml_per_min,
+            pump_duty_percent: v30.pump_duty_percent,
+            prime_after_idle_secs: v30.prime_after_idle_secs,
+            prime_duration_secs: v30.prime_duration_secs,
+            prime_duty_percent: v30.prime_duty_percent,
+            flow_glitch_filter_us: v30.flow_glitch_filter_us,
+            soft_stop_ticks: v30.soft_stop_ticks,
+            tank_source: v30.tank_source,
+            pump_pid_kp: v30.pump_pid_kp,
+            pump_pid_ki: v30.pump_pid_ki,
+            pump_pid_kd: v30.pump_pid_kd,
+            nh3_activate_threshold_ppm: v30.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v30.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v30.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v30.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v30.nh3_oversample_count,
+            nh3_adc_samples: v30.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v30.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v30.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v30.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v30.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v30.uvc_duty_percent,
+            uvc_warmup_secs: v30.uvc_warmup_secs,
+            quiet_hours: v30.quiet_hours,
+            paused_schedules: v30.paused_schedules,
+            periodic_schedule: v30.periodic_schedule,
+            led_brightness: v30.led_brightness,
+            led_quiet_brightness: v30.led_quiet_brightness,
+            led_overrides: v30.led_overrides,
+            led_overlay_priority: v30.led_overlay_priority,
+            max_temperature_c: v30.max_temperature_c,
+            over_temp_debounce_samples: v30.over_temp_debounce_samples,
+            pump_derate_start_c: v30.pump_derate_start_c,
+            pump_derate_min_duty_percent: v30.pump_derate_min_duty_percent,
+            purge_duration_secs: v30.purge_duration_secs,
+            purge_mode: v30.purge_mode,
+            purge_target_volume_ml: v30.purge_target_volume_ml,
+            purge_max_duration_secs: v30.purge_max_duration_secs,
+            max_active_duration_secs: v30.max_active_duration_secs,
+            min_active_secs: v30.min_active_secs,
+            state_max_secs: v30.state_max_secs,
+            min_water_level_percent: v30.min_water_level_percent,
+            fault_recovery_cooldown_secs: v30.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v30.interlock_grace_secs,
+            interlock_active_low: v30.interlock_active_low,
+            sensor_stale_after_secs: v30.sensor_stale_after_secs,
+            sensor_read_interval_ms: v30.sensor_read_interval_ms,
+            control_loop_interval_ms: v30.control_loop_interval_ms,
+            telemetry_interval_secs: v30.telemetry_interval_secs,
+            ble_status_interval_secs: v30.ble_status_interval_secs,
+            max_control_dt_ms: v30.max_control_dt_ms,
+            flow_filter_tau_ms: v30.flow_filter_tau_ms,
+            power_profile: v30.power_profile,
+            stop_lockout_secs: v30.stop_lockout_secs,
+            self_test_blocks_active: v30.self_test_blocks_active,
+            maintenance_mode: v30.maintenance_mode,
+            psk_grace_secs: v30.psk_grace_secs,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV31 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    max_control_dt_ms: u32,
+    flow_filter_tau_ms: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+    psk_grace_secs: u32,
+    auth_lockout_threshold: u8,
+}
+
+impl From<SystemConfigV31> for SystemConfig {
+    fn from(v31: SystemConfigV31) -> Self {
+        Self {
+            pump_flow_ml_per_min: v31.pump_flow_ml_per_min,
+            pump_duty_percent: v31.pump_duty_percent,
+            prime_after_idle_secs: v31.prime_after_idle_secs,
+            prime_duration_secs: v31.prime_duration_secs,
+            prime_duty_percent: v31.prime_duty_percent,
+            flow_glitch_filter_us: v31.flow_glitch_filter_us,
+            soft_stop_ticks: v31.soft_stop_ticks,
+            tank_source: v31.tank_source,
+            pump_pid_kp: v31.pump_pid_kp,
+            pump_pid_ki: v31.pump_pid_ki,
+            pump_pid_kd: v31.pump_pid_kd,
+            nh3_activate_threshold_ppm: v31.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v31.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v31.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v31.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v31.nh3_oversample_count,
+            nh3_adc_samples: v31.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v31.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v31.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v31.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v31.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v31.uvc_duty_percent,
+            uvc_warmup_secs: v31.uvc_warmup_secs,
+            quiet_hours: v31.quiet_hours,
+            paused_schedules: v31.paused_schedules,
+            periodic_schedule: v31.periodic_schedule,
+            led_brightness: v31.led_brightness,
+            led_quiet_brightness: v31.led_quiet_brightness,
+            led_overrides: v31.led_overrides,
+            led_overlay_priority: v31.led_overlay_priority,
+            max_temperature_c: v31.max_temperature_c,
+            over_temp_debounce_samples: v31.over_temp_debounce_samples,
+            pump_derate_start_c: v31.pump_derate_start_c,
+            pump_derate_min_duty_percent: v31.pump_derate_min_duty_percent,
+            purge_duration_secs: v31.purge_duration_secs,
+            purge_mode: v31.purge_mode,
+            purge_target_volume_ml: v31.purge_target_volume_ml,
+            purge_max_duration_secs: v31.purge_max_duration_secs,
+            max_active_duration_secs: v31.max_active_duration_secs,
+            min_active_secs: v31.min_active_secs,
+            state_max_secs: v31.state_max_secs,
+            min_water_level_percent: v31.min_water_level_percent,
+            fault_recovery_cooldown_secs: v31.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v31.interlock_grace_secs,
+            interlock_active_low: v31.interlock_active_low,
+            sensor_stale_after_secs: v31.sensor_stale_after_secs,
+            sensor_read_interval_ms: v31.sensor_read_interval_ms,
+            control_loop_interval_ms: v31.control_loop_interval_ms,
+            telemetry_interval_secs: v31.telemetry_interval_secs,
+            ble_status_interval_secs: v31.ble_status_interval_secs,
+            max_control_dt_ms: v31.max_control_dt_ms,
+            flow_filter_tau_ms: v31.flow_filter_tau_ms,
+            power_profile: v31.power_profile,
+            stop_lockout_secs: v31.stop_lockout_secs,
+            self_test_blocks_active: v31.self_test_blocks_active,
+            maintenance_mode: v31.maintenance_mode,
+            psk_grace_secs: v31.psk_grace_secs,
+            auth_lockout_threshold: v31.auth_lockout_threshold,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV32 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    max_control_dt_ms: u32,
+    flow_filter_tau_ms: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+    psk_grace_secs: u32,
+    auth_lockout_threshold: u8,
+    wifi_roam_rssi_dbm: i8,
+    wifi_roam_samples: u8,
+}
+
+impl From<SystemConfigV32> for SystemConfig {
+    fn from(v32: SystemConfigV32) -> Self {
+        Self {
+            pump_flow_ml_per_min: v32.pump_flow_ml_per_min,
+            pump_duty_percent: v32.pump_duty_percent,
+            prime_after_idle_secs: v32.prime_after_idle_secs,
+            prime_duration_secs: v32.prime_duration_secs,
+            prime_duty_percent: v32.prime_duty_percent,
+            flow_glitch_filter_us: v32.flow_glitch_filter_us,
+            soft_stop_ticks: v32.soft_stop_ticks,
+            tank_source: v32.tank_source,
+            pump_pid_kp: v32.pump_pid_kp,
+            pump_pid_ki: v32.pump_pid_ki,
+            pump_pid_kd: v32.pump_pid_kd,
+            nh3_activate_threshold_ppm: v32.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v32.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v32.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v32.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v32.nh3_oversample_count,
+            nh3_adc_samples: v32.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v32.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v32.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v32.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v32.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v32.uvc_duty_percent,
+            uvc_warmup_secs: v32.uvc_warmup_secs,
+            quiet_hours: v32.quiet_hours,
+            paused_schedules: v32.paused_schedules,
+            periodic_schedule: v32.periodic_schedule,
+            led_brightness: v32.led_brightness,
+            led_quiet_brightness: v32.led_quiet_brightness,
+            led_overrides: v32.led_overrides,
+            led_overlay_priority: v32.led_overlay_priority,
+            max_temperature_c: v32.max_temperature_c,
+            over_temp_debounce_samples: v32.over_temp_debounce_samples,
+            pump_derate_start_c: v32.pump_derate_start_c,
+            pump_derate_min_duty_percent: v32.pump_derate_min_duty_percent,
+            purge_duration_secs: v32.purge_duration_secs,
+            purge_mode: v32.purge_mode,
+            purge_target_volume_ml: v32.purge_target_volume_ml,
+            purge_max_duration_secs: v32.purge_max_duration_secs,
+            max_active_duration_secs: v32.max_active_duration_secs,
+            min_active_secs: v32.min_active_secs,
+            state_max_secs: v32.state_max_secs,
+            min_water_level_percent: v32.min_water_level_percent,
+            fault_recovery_cooldown_secs: v32.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v32.interlock_grace_secs,
+            interlock_active_low: v32.interlock_active_low,
+            sensor_stale_after_secs: v32.sensor_stale_after_secs,
+            sensor_read_interval_ms: v32.sensor_read_interval_ms,
+            control_loop_interval_ms: v32.control_loop_interval_ms,
+            telemetry_interval_secs: v32.telemetry_interval_secs,
+            ble_status_interval_secs: v32.ble_status_interval_secs,
+            max_control_dt_ms: v32.max_control_dt_ms,
+            flow_filter_tau_ms: v32.flow_filter_tau_ms,
+            power_profile: v32.power_profile,
+            stop_lockout_secs: v32.stop_lockout_secs,
+            self_test_blocks_active: v32.self_test_blocks_active,
+            maintenance_mode: v32.maintenance_mode,
+            psk_grace_secs: v32.psk_grace_secs,
+            auth_lockout_threshold: v32.auth_lockout_threshold,
+            wifi_roam_rssi_dbm: v32.wifi_roam_rssi_dbm,
+            wifi_roam_samples: v32.wifi_roam_samples,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct SystemConfigV33 {
+    pump_flow_ml_per_min: u16,
+    pump_duty_percent: u8,
+    prime_after_idle_secs: u32,
+    prime_duration_secs: u16,
+    prime_duty_percent: u8,
+    flow_glitch_filter_us: u16,
+    soft_stop_ticks: u8,
+    tank_source: TankSource,
+    pump_pid_kp: f32,
+    pump_pid_ki: f32,
+    pump_pid_kd: f32,
+    nh3_activate_threshold_ppm: f32,
+    nh3_deactivate_threshold_ppm: f32,
+    nh3_confirm_duration_secs: u16,
+    nh3_urgent_threshold_ppm: f32,
+    nh3_oversample_count: u8,
+    nh3_adc_samples: u8,
+    nh3_rate_threshold_ppm_per_sec: f32,
+    nh3_warmup_secs: u16,
+    nh3_alarm_high_ppm: f32,
+    nh3_alarm_clear_ppm: f32,
+    uvc_duty_percent: u8,
+    uvc_warmup_secs: u16,
+    quiet_hours: Option<QuietHours>,
+    paused_schedules: PausedLabels,
+    periodic_schedule: Option<PeriodicSchedule>,
+    led_brightness: u8,
+    led_quiet_brightness: u8,
+    led_overrides: [Option<LedOverride>; StateId::COUNT],
+    led_overlay_priority: [LedOverlay; LED_OVERLAY_COUNT],
+    max_temperature_c: f32,
+    over_temp_debounce_samples: u8,
+    pump_derate_start_c: f32,
+    pump_derate_min_duty_percent: u8,
+    purge_duration_secs: u16,
+    purge_mode: PurgeMode,
+    purge_target_volume_ml: u16,
+    purge_max_duration_secs: u16,
+    max_active_duration_secs: u32,
+    min_active_secs: u16,
+    state_max_secs: [u32; StateId::COUNT],
+    min_water_level_percent: u8,
+    fault_recovery_cooldown_secs: u16,
+    interlock_grace_secs: u16,
+    interlock_active_low: bool,
+    sensor_stale_after_secs: u16,
+    sensor_read_interval_ms: u32,
+    control_loop_interval_ms: u32,
+    telemetry_interval_secs: u32,
+    ble_status_interval_secs: u16,
+    max_control_dt_ms: u32,
+    flow_filter_tau_ms: u16,
+    power_profile: PowerProfile,
+    stop_lockout_secs: u16,
+    self_test_blocks_active: bool,
+    maintenance_mode: bool,
+    psk_grace_secs: u32,
+    auth_lockout_threshold: u8,
+    wifi_roam_rssi_dbm: i8,
+    wifi_roam_samples: u8,
+    tcp_idle_timeout_secs: u16,
+}
+
+impl From<SystemConfigV33> for SystemConfig {
+    fn from(v33: SystemConfigV33) -> Self {
+        Self {
+            pump_flow_ml_per_min: v33.pump_flow_ml_per_min,
+            pump_duty_percent: v33.pump_duty_percent,
+            prime_after_idle_secs: v33.prime_after_idle_secs,
+            prime_duration_secs: v33.prime_duration_secs,
+            prime_duty_percent: v33.prime_duty_percent,
+            flow_glitch_filter_us: v33.flow_glitch_filter_us,
+            soft_stop_ticks: v33.soft_stop_ticks,
+            tank_source: v33.tank_source,
+            pump_pid_kp: v33.pump_pid_kp,
+            pump_pid_ki: v33.pump_pid_ki,
+            pump_pid_kd: v33.pump_pid_kd,
+            nh3_activate_threshold_ppm: v33.nh3_activate_threshold_ppm,
+            nh3_deactivate_threshold_ppm: v33.nh3_deactivate_threshold_ppm,
+            nh3_confirm_duration_secs: v33.nh3_confirm_duration_secs,
+            nh3_urgent_threshold_ppm: v33.nh3_urgent_threshold_ppm,
+            nh3_oversample_count: v33.nh3_oversample_count,
+            nh3_adc_samples: v33.nh3_adc_samples,
+            nh3_rate_threshold_ppm_per_sec: v33.nh3_rate_threshold_ppm_per_sec,
+            nh3_warmup_secs: v33.nh3_warmup_secs,
+            nh3_alarm_high_ppm: v33.nh3_alarm_high_ppm,
+            nh3_alarm_clear_ppm: v33.nh3_alarm_clear_ppm,
+            uvc_duty_percent: v33.uvc_duty_percent,
+            uvc_warmup_secs: v33.uvc_warmup_secs,
+            quiet_hours: v33.quiet_hours,
+            paused_schedules: v33.paused_schedules,
+            periodic_schedule: v33.periodic_schedule,
+            led_brightness: v33.led_brightness,
+            led_quiet_brightness: v33.led_quiet_brightness,
+            led_overrides: v33.led_overrides,
+            led_overlay_priority: v33.led_overlay_priority,
+            max_temperature_c: v33.max_temperature_c,
+            over_temp_debounce_samples: v33.over_temp_debounce_samples,
+            pump_derate_start_c: v33.pump_derate_start_c,
+            pump_derate_min_duty_percent: v33.pump_derate_min_duty_percent,
+            purge_duration_secs: v33.purge_duration_secs,
+            purge_mode: v33.purge_mode,
+            purge_target_volume_ml: v33.purge_target_volume_ml,
+            purge_max_duration_secs: v33.purge_max_duration_secs,
+            max_active_duration_secs: v33.max_active_duration_secs,
+            min_active_secs: v33.min_active_secs,
+            state_max_secs: v33.state_max_secs,
+            min_water_level_percent: v33.min_water_level_percent,
+            fault_recovery_cooldown_secs: v33.fault_recovery_cooldown_secs,
+            interlock_grace_secs: v33.interlock_grace_secs,
+            interlock_active_low: v33.interlock_active_low,
+            sensor_stale_after_secs: v33.sensor_stale_after_secs,
+            sensor_read_interval_ms: v33.sensor_read_interval_ms,
+            control_loop_interval_ms: v33.control_loop_interval_ms,
+            telemetry_interval_secs: v33.telemetry_interval_secs,
+            ble_status_interval_secs: v33.ble_status_interval_secs,
+            max_control_dt_ms: v33.max_control_dt_ms,
+            flow_filter_tau_ms: v33.flow_filter_tau_ms,
+            power_profile: v33.power_profile,
+            stop_lockout_secs: v33.stop_lockout_secs,
+            self_test_blocks_active: v33.self_test_blocks_active,
+            maintenance_mode: v33.maintenance_mode,
+            psk_grace_secs: v33.psk_grace_secs,
+            auth_lockout_threshold: v33.auth_lockout_threshold,
+            wifi_roam_rssi_dbm: v33.wifi_roam_rssi_dbm,
+            wifi_roam_samples: v33.wifi_roam_samples,
+            tcp_idle_timeout_secs: v33.tcp_idle_timeout_secs,
+            ..Self::default()
         }
     }
 }
 
+/// Serialize `cfg` in the current stored layout (version prefix + postcard).
+pub fn encode_stored(cfg: &SystemConfig) -> Result<Vec<u8>, postcard::Error> {
+    let mut bytes = CONFIG_SCHEMA_VERSION.to_le_bytes().to_vec();
+    bytes.extend(postcard::to_allocvec(cfg)?);
+    Ok(bytes)
+}
+
+/// Decode a stored config blob of any known version.
+///
+/// Returns the config together with the schema version it was stored
+/// in, so the caller can re-save blobs older than
+/// [`CONFIG_SCHEMA_VERSION`].  `None` if the blob is undecodable.
+pub fn decode_stored(blob: &[u8]) -> Option<(SystemConfig, u16)> {
+    if let Some((prefix, body)) = blob.split_first_chunk::<VERSION_PREFIX_LEN>() {
+        let version = u16::from_le_bytes(*prefix);
+        if (2..=CONFIG_SCHEMA_VERSION).contains(&version)
+            && let Some(cfg) = migrate(version, body)
+        {
+            return Some((cfg, version));
+        }
+    }
+    // No recognised prefix: the unversioned v1 layout.
+    migrate(1, blob).map(|cfg| (cfg, 1))
+}
+
+/// Upgrade a config body stored as `old_version` to the current layout.
+///
+/// `bytes` is the postcard body without the version prefix.  Fields
+/// that did not exist in `old_version` take their [`Default`] values.
+/// `None` for unknown versions or bodies that fail to decode.
+pub fn migrate(old_version: u16, bytes: &[u8]) -> Option<SystemConfig> {
+    match old_version {
+        1 => postcard::from_bytes::<SystemConfigV1>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        2 => postcard::from_bytes::<SystemConfigV2>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        3 => postcard::from_bytes::<SystemConfigV3>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        4 => postcard::from_bytes::<SystemConfigV4>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        5 => postcard::from_bytes::<SystemConfigV5>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        6 => postcard::from_bytes::<SystemConfigV6>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        7 => postcard::from_bytes::<SystemConfigV7>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        8 => postcard::from_bytes::<SystemConfigV8>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        9 => postcard::from_bytes::<SystemConfigV9>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        10 => postcard::from_bytes::<SystemConfigV10>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        11 => postcard::from_bytes::<SystemConfigV11>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        12 => postcard::from_bytes::<SystemConfigV12>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        13 => postcard::from_bytes::<SystemConfigV13>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        14 => postcard::from_bytes::<SystemConfigV14>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        15 => postcard::from_bytes::<SystemConfigV15>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        16 => postcard::from_bytes::<SystemConfigV16>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        17 => postcard::from_bytes::<SystemConfigV17>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        18 => postcard::from_bytes::<SystemConfigV18>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        19 => postcard::from_bytes::<SystemConfigV19>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        20 => postcard::from_bytes::<SystemConfigV20>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        21 => postcard::from_bytes::<SystemConfigV21>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        22 => postcard::from_bytes::<SystemConfigV22>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        23 => postcard::from_bytes::<SystemConfigV23>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        24 => postcard::from_bytes::<SystemConfigV24>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        25 => postcard::from_bytes::<SystemConfigV25>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        26 => postcard::from_bytes::<SystemConfigV26>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        27 => postcard::from_bytes::<SystemConfigV27>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        28 => postcard::from_bytes::<SystemConfigV28>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        29 => postcard::from_bytes::<SystemConfigV29>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        30 => postcard::from_bytes::<SystemConfigV30>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        31 => postcard::from_bytes::<SystemConfigV31>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        32 => postcard::from_bytes::<SystemConfigV32>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        33 => postcard::from_bytes::<SystemConfigV33>(bytes)
+            .ok()
+            .map(SystemConfig::from),
+        CONFIG_SCHEMA_VERSION => postcard::from_bytes(bytes).ok(),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Mirror of the v1 layout, for hand-crafting legacy blobs.
+    #[derive(Serialize)]
+    struct V1Blob {
+        pump_flow_ml_per_min: u16,
+        pump_duty_percent: u8,
+        nh3_activate_threshold_ppm: f32,
+        nh3_deactivate_threshold_ppm: f32,
+        nh3_confirm_duration_secs: u16,
+        uvc_duty_percent: u8,
+        max_temperature_c: f32,
+        purge_duration_secs: u16,
+        min_water_level_percent: u8,
+        sensor_read_interval_ms: u32,
+        control_loop_interval_ms: u32,
+        telemetry_interval_secs: u32,
+    }
+
+    fn v1_blob() -> Vec<u8> {
+        postcard::to_allocvec(&V1Blob {
+            pump_flow_ml_per_min: 800,
+            pump_duty_percent: 55,
+            nh3_activate_threshold_ppm: 12.5,
+            nh3_deactivate_threshold_ppm: 4.0,
+            nh3_confirm_duration_secs: 45,
+            uvc_duty_percent: 90,
+            max_temperature_c: 75.0,
+            purge_duration_secs: 200,
+            min_water_level_percent: 30,
+            sensor_read_interval_ms: 200,
+            control_loop_interval_ms: 500,
+            telemetry_interval_secs: 30,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn v1_blob_migrates_with_defaults_for_new_fields() {
+        let (cfg, version) = decode_stored(&v1_blob()).expect("v1 blob rejected");
+        assert_eq!(version, 1);
+
+        // Carried over from the v1 blob.
+        assert_eq!(cfg.pump_flow_ml_per_min, 800);
+        assert_eq!(cfg.pump_duty_percent, 55);
+        assert!((cfg.nh3_activate_threshold_ppm - 12.5).abs() < f32::EPSILON);
+        assert_eq!(cfg.nh3_confirm_duration_secs, 45);
+        assert_eq!(cfg.uvc_duty_percent, 90);
+        assert_eq!(cfg.purge_duration_secs, 200);
+        assert_eq!(cfg.control_loop_interval_ms, 500);
+        assert_eq!(cfg.telemetry_interval_secs, 30);
+
+        // Introduced after v1: defaults.
+        let d = SystemConfig::default();
+        assert!((cfg.nh3_urgent_threshold_ppm - d.nh3_urgent_threshold_ppm).abs() < f32::EPSILON);
+        assert_eq!(cfg.uvc_warmup_secs, d.uvc_warmup_secs);
+        assert_eq!(cfg.quiet_hours, None);
+        assert_eq!(cfg.led_brightness, d.led_brightness);
+        assert_eq!(cfg.prime_after_idle_secs, d.prime_after_idle_secs);
+        assert_eq!(cfg.stop_lockout_secs, d.stop_lockout_secs);
+        assert_eq!(cfg.self_test_blocks_active, d.self_test_blocks_active);
+    }
+
+    #[test]
+    fn v2_blob_migrates_with_default_active_limit() {
+        let c = SystemConfig {
+            pump_duty_percent: 61,
+            max_active_duration_secs: 1,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "max_active_duration_secs",
+            "purge_mode",
+            "purge_target_volume_ml",
+            "purge_max_duration_secs",
+            "nh3_oversample_count",
+            "fault_recovery_cooldown_secs",
+            "power_profile",
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v2: SystemConfigV2 = serde_json::from_value(json).unwrap();
+        let mut blob = 2u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v2).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v2 blob rejected");
+        assert_eq!(version, 2);
+        assert_eq!(cfg.pump_duty_percent, 61);
+        assert_eq!(
+            cfg.max_active_duration_secs,
+            SystemConfig::default().max_active_duration_secs
+        );
+    }
+
+    #[test]
+    fn v3_blob_migrates_with_duration_purge() {
+        let c = SystemConfig {
+            purge_duration_secs: 90,
+            max_active_duration_secs: 1800,
+            purge_mode: PurgeMode::Volume,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "purge_mode",
+            "purge_target_volume_ml",
+            "purge_max_duration_secs",
+            "nh3_oversample_count",
+            "fault_recovery_cooldown_secs",
+            "power_profile",
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v3: SystemConfigV3 = serde_json::from_value(json).unwrap();
+        let mut blob = 3u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v3).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v3 blob rejected");
+        assert_eq!(version, 3);
+        assert_eq!(cfg.purge_duration_secs, 90);
+        assert_eq!(cfg.max_active_duration_secs, 1800);
+        assert_eq!(cfg.purge_mode, PurgeMode::Duration);
+        assert_eq!(
+            cfg.purge_target_volume_ml,
+            SystemConfig::default().purge_target_volume_ml
+        );
+    }
+
+    #[test]
+    fn v4_blob_migrates_with_default_oversampling() {
+        let c = SystemConfig {
+            purge_mode: PurgeMode::Volume,
+            nh3_urgent_threshold_ppm: 80.0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "nh3_oversample_count",
+            "fault_recovery_cooldown_secs",
+            "power_profile",
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v4: SystemConfigV4 = serde_json::from_value(json).unwrap();
+        let mut blob = 4u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v4).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v4 blob rejected");
+        assert_eq!(version, 4);
+        assert_eq!(cfg.purge_mode, PurgeMode::Volume);
+        assert_eq!(cfg.nh3_urgent_threshold_ppm.to_bits(), 80.0f32.to_bits());
+        assert_eq!(
+            cfg.nh3_oversample_count,
+            SystemConfig::default().nh3_oversample_count
+        );
+    }
+
+    #[test]
+    fn v5_blob_migrates_with_default_recovery_cooldown() {
+        let c = SystemConfig {
+            nh3_oversample_count: 8,
+            min_water_level_percent: 30,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "fault_recovery_cooldown_secs",
+            "power_profile",
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v5: SystemConfigV5 = serde_json::from_value(json).unwrap();
+        let mut blob = 5u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v5).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v5 blob rejected");
+        assert_eq!(version, 5);
+        assert_eq!(cfg.nh3_oversample_count, 8);
+        assert_eq!(cfg.min_water_level_percent, 30);
+        assert_eq!(
+            cfg.fault_recovery_cooldown_secs,
+            SystemConfig::default().fault_recovery_cooldown_secs
+        );
+    }
+
+    #[test]
+    fn v6_blob_migrates_with_balanced_profile() {
+        let c = SystemConfig {
+            fault_recovery_cooldown_secs: 30,
+            power_profile: PowerProfile::Eco,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "power_profile",
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v6: SystemConfigV6 = serde_json::from_value(json).unwrap();
+        let mut blob = 6u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v6).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v6 blob rejected");
+        assert_eq!(version, 6);
+        assert_eq!(cfg.fault_recovery_cooldown_secs, 30);
+        assert_eq!(cfg.power_profile, PowerProfile::Balanced);
+    }
+
+    #[test]
+    fn v7_blob_migrates_without_led_overrides() {
+        let c = SystemConfig {
+            power_profile: PowerProfile::Performance,
+            led_brightness: 90,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "led_overrides",
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v7: SystemConfigV7 = serde_json::from_value(json).unwrap();
+        let mut blob = 7u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v7).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v7 blob rejected");
+        assert_eq!(version, 7);
+        assert_eq!(cfg.power_profile, PowerProfile::Performance);
+        assert_eq!(cfg.led_brightness, 90);
+        assert!(cfg.led_overrides.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn v8_blob_migrates_with_default_interlock_grace() {
+        let mut c = SystemConfig {
+            interlock_grace_secs: 9,
+            ..Default::default()
+        };
+        c.led_overrides[StateId::Idle as usize] = Some(LedOverride {
+            colour: (4, 5, 6),
+            pattern: crate::drivers::led_patterns::PatternId::Solid,
+        });
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "interlock_grace_secs",
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v8: SystemConfigV8 = serde_json::from_value(json).unwrap();
+        let mut blob = 8u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v8).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v8 blob rejected");
+        assert_eq!(version, 8);
+        assert_eq!(cfg.led_overrides, c.led_overrides);
+        assert_eq!(
+            cfg.interlock_grace_secs,
+            SystemConfig::default().interlock_grace_secs
+        );
+    }
+
+    #[test]
+    fn v9_blob_migrates_with_default_flow_glitch_filter() {
+        let c = SystemConfig {
+            interlock_grace_secs: 9,
+            flow_glitch_filter_us: 0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "flow_glitch_filter_us",
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v9: SystemConfigV9 = serde_json::from_value(json).unwrap();
+        let mut blob = 9u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v9).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v9 blob rejected");
+        assert_eq!(version, 9);
+        assert_eq!(cfg.interlock_grace_secs, 9);
+        assert_eq!(
+            cfg.flow_glitch_filter_us,
+            SystemConfig::default().flow_glitch_filter_us
+        );
+    }
+
+    #[test]
+    fn v10_blob_migrates_with_default_staleness_threshold() {
+        let c = SystemConfig {
+            flow_glitch_filter_us: 2500,
+            sensor_stale_after_secs: 0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "sensor_stale_after_secs",
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v10: SystemConfigV10 = serde_json::from_value(json).unwrap();
+        let mut blob = 10u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v10).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v10 blob rejected");
+        assert_eq!(version, 10);
+        assert_eq!(cfg.flow_glitch_filter_us, 2500);
+        assert_eq!(
+            cfg.sensor_stale_after_secs,
+            SystemConfig::default().sensor_stale_after_secs
+        );
+    }
+
+    #[test]
+    fn v11_blob_migrates_with_default_soft_stop() {
+        let c = SystemConfig {
+            sensor_stale_after_secs: 30,
+            soft_stop_ticks: 0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "soft_stop_ticks",
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v11: SystemConfigV11 = serde_json::from_value(json).unwrap();
+        let mut blob = 11u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v11).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v11 blob rejected");
+        assert_eq!(version, 11);
+        assert_eq!(cfg.sensor_stale_after_secs, 30);
+        assert_eq!(cfg.soft_stop_ticks, SystemConfig::default().soft_stop_ticks);
+    }
+
+    #[test]
+    fn v12_blob_migrates_with_default_over_temp_debounce() {
+        let c = SystemConfig {
+            soft_stop_ticks: 5,
+            over_temp_debounce_samples: 1,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "over_temp_debounce_samples",
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v12: SystemConfigV12 = serde_json::from_value(json).unwrap();
+        let mut blob = 12u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v12).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v12 blob rejected");
+        assert_eq!(version, 12);
+        assert_eq!(cfg.soft_stop_ticks, 5);
+        assert_eq!(
+            cfg.over_temp_debounce_samples,
+            SystemConfig::default().over_temp_debounce_samples
+        );
+    }
+
+    #[test]
+    fn v13_blob_migrates_with_rate_trigger_off() {
+        let c = SystemConfig {
+            over_temp_debounce_samples: 5,
+            nh3_rate_threshold_ppm_per_sec: 2.5,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "nh3_rate_threshold_ppm_per_sec",
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v13: SystemConfigV13 = serde_json::from_value(json).unwrap();
+        let mut blob = 13u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v13).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v13 blob rejected");
+        assert_eq!(version, 13);
+        assert_eq!(cfg.over_temp_debounce_samples, 5);
+        assert!(cfg.nh3_rate_threshold_ppm_per_sec.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn v14_blob_migrates_out_of_maintenance_mode() {
+        let c = SystemConfig {
+            nh3_rate_threshold_ppm_per_sec: 2.5,
+            maintenance_mode: true,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "maintenance_mode",
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v14: SystemConfigV14 = serde_json::from_value(json).unwrap();
+        let mut blob = 14u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v14).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v14 blob rejected");
+        assert_eq!(version, 14);
+        assert!((cfg.nh3_rate_threshold_ppm_per_sec - 2.5).abs() < f32::EPSILON);
+        assert!(!cfg.maintenance_mode);
+    }
+
+    #[test]
+    fn v15_blob_migrates_without_warmup_soak() {
+        let c = SystemConfig {
+            maintenance_mode: true,
+            nh3_warmup_secs: 90,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "nh3_warmup_secs",
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v15: SystemConfigV15 = serde_json::from_value(json).unwrap();
+        let mut blob = 15u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v15).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v15 blob rejected");
+        assert_eq!(version, 15);
+        assert!(cfg.maintenance_mode);
+        assert_eq!(cfg.nh3_warmup_secs, 0);
+    }
+
+    #[test]
+    fn v16_blob_migrates_to_tank_a_source() {
+        let c = SystemConfig {
+            nh3_warmup_secs: 90,
+            tank_source: TankSource::Alternate,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "tank_source",
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v16: SystemConfigV16 = serde_json::from_value(json).unwrap();
+        let mut blob = 16u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v16).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v16 blob rejected");
+        assert_eq!(version, 16);
+        assert_eq!(cfg.nh3_warmup_secs, 90);
+        assert_eq!(cfg.tank_source, TankSource::TankA);
+    }
+
+    #[test]
+    fn v17_blob_migrates_without_minimum_runtime() {
+        let c = SystemConfig {
+            tank_source: TankSource::Alternate,
+            min_active_secs: 120,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "min_active_secs",
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v17: SystemConfigV17 = serde_json::from_value(json).unwrap();
+        let mut blob = 17u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v17).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v17 blob rejected");
+        assert_eq!(version, 17);
+        assert_eq!(cfg.tank_source, TankSource::Alternate);
+        assert_eq!(cfg.min_active_secs, 0);
+    }
+
+    #[test]
+    fn v18_blob_migrates_to_default_overlay_priority() {
+        let c = SystemConfig {
+            min_active_secs: 120,
+            led_overlay_priority: [
+                LedOverlay::LowWater,
+                LedOverlay::Ota,
+                LedOverlay::Wifi,
+                LedOverlay::Ble,
+            ],
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "led_overlay_priority",
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v18: SystemConfigV18 = serde_json::from_value(json).unwrap();
+        let mut blob = 18u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v18).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v18 blob rejected");
+        assert_eq!(version, 18);
+        assert_eq!(cfg.min_active_secs, 120);
+        assert_eq!(cfg.led_overlay_priority, DEFAULT_LED_OVERLAY_PRIORITY);
+    }
+
+    #[test]
+    fn v19_blob_migrates_with_alarm_off() {
+        let c = SystemConfig {
+            led_overlay_priority: [
+                LedOverlay::Ble,
+                LedOverlay::Wifi,
+                LedOverlay::LowWater,
+                LedOverlay::Ota,
+            ],
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        for key in [
+            "nh3_alarm_high_ppm",
+            "nh3_alarm_clear_ppm",
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            obj.remove(key);
+        }
+        let v19: SystemConfigV19 = serde_json::from_value(json).unwrap();
+        let mut blob = 19u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v19).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v19 blob rejected");
+        assert_eq!(version, 19);
+        assert_eq!(cfg.led_overlay_priority, c.led_overlay_priority);
+        assert!(cfg.nh3_alarm_high_ppm.abs() < f32::EPSILON);
+        assert!(cfg.nh3_alarm_clear_ppm.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn v20_blob_migrates_with_default_adc_samples() {
+        let c = SystemConfig {
+            nh3_alarm_high_ppm: 30.0,
+            nh3_alarm_clear_ppm: 25.0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "nh3_adc_samples",
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v20: SystemConfigV20 = serde_json::from_value(json).unwrap();
+        let mut blob = 20u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v20).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v20 blob rejected");
+        assert_eq!(version, 20);
+        assert!((cfg.nh3_alarm_high_ppm - 30.0).abs() < f32::EPSILON);
+        assert_eq!(cfg.nh3_adc_samples, SystemConfig::default().nh3_adc_samples);
+    }
+
+    #[test]
+    fn v21_blob_migrates_with_derating_off() {
+        let c = SystemConfig {
+            nh3_adc_samples: 7,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "pump_derate_start_c",
+            "pump_derate_min_duty_percent",
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v21: SystemConfigV21 = serde_json::from_value(json).unwrap();
+        let mut blob = 21u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v21).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v21 blob rejected");
+        assert_eq!(version, 21);
+        assert_eq!(cfg.nh3_adc_samples, 7);
+        assert!(cfg.pump_derate_start_c.abs() < f32::EPSILON);
+        assert_eq!(
+            cfg.pump_derate_min_duty_percent,
+            SystemConfig::default().pump_derate_min_duty_percent
+        );
+    }
+
+    #[test]
+    fn v22_blob_migrates_with_interlock_active_low() {
+        let c = SystemConfig {
+            pump_derate_start_c: 60.0,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "interlock_active_low",
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v22: SystemConfigV22 = serde_json::from_value(json).unwrap();
+        let mut blob = 22u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v22).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v22 blob rejected");
+        assert_eq!(version, 22);
+        assert!((cfg.pump_derate_start_c - 60.0).abs() < f32::EPSILON);
+        assert!(cfg.interlock_active_low);
+    }
+
+    #[test]
+    fn v23_blob_migrates_without_state_watchdogs() {
+        let c = SystemConfig {
+            interlock_active_low: false,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "state_max_secs",
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v23: SystemConfigV23 = serde_json::from_value(json).unwrap();
+        let mut blob = 23u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v23).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v23 blob rejected");
+        assert_eq!(version, 23);
+        assert!(!cfg.interlock_active_low);
+        assert_eq!(cfg.state_max_secs, [0; StateId::COUNT]);
+    }
+
+    #[test]
+    fn v24_blob_migrates_with_default_ble_status_interval() {
+        let mut c = SystemConfig::default();
+        c.state_max_secs[StateId::Active as usize] = 7200;
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "ble_status_interval_secs",
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v24: SystemConfigV24 = serde_json::from_value(json).unwrap();
+        let mut blob = 24u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v24).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v24 blob rejected");
+        assert_eq!(version, 24);
+        assert_eq!(cfg.state_max_secs[StateId::Active as usize], 7200);
+        assert_eq!(cfg.ble_status_interval_secs, 5);
+    }
+
+    #[test]
+    fn v25_blob_migrates_with_default_pump_gains() {
+        let c = SystemConfig {
+            ble_status_interval_secs: 30,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "pump_pid_kp",
+            "pump_pid_ki",
+            "pump_pid_kd",
+            "paused_schedules",
+            "periodic_schedule",
+            "psk_grace_secs",
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v25: SystemConfigV25 = serde_json::from_value(json).unwrap();
+        let mut blob = 25u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v25).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v25 blob rejected");
+        assert_eq!(version, 25);
+        assert_eq!(cfg.ble_status_interval_secs, 30);
+        let d = SystemConfig::default();
+        assert_eq!(
+            (cfg.pump_pid_kp, cfg.pump_pid_ki, cfg.pump_pid_kd),
+            (d.pump_pid_kp, d.pump_pid_ki, d.pump_pid_kd)
+        );
+    }
+
+    #[test]
+    fn v26_blob_migrates_with_no_paused_schedules() {
+        let c = SystemConfig {
+            pump_pid_kp: 3.5,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in ["paused_schedules", "periodic_schedule"] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v26: SystemConfigV26 = serde_json::from_value(json).unwrap();
+        let mut blob = 26u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v26).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v26 blob rejected");
+        assert_eq!(version, 26);
+        assert_eq!(cfg.pump_pid_kp.to_bits(), 3.5f32.to_bits());
+        assert!(cfg.paused_schedules.is_empty());
+    }
+
+    #[test]
+    fn v27_blob_migrates_with_no_periodic_schedule() {
+        let mut c = SystemConfig::default();
+        let label = heapless::String::try_from("hourly").unwrap();
+        c.paused_schedules.push(label).unwrap();
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in ["periodic_schedule", "psk_grace_secs"] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v27: SystemConfigV27 = serde_json::from_value(json).unwrap();
+        let mut blob = 27u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v27).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v27 blob rejected");
+        assert_eq!(version, 27);
+        assert!(cfg.paused_schedules.iter().any(|l| l == "hourly"));
+        assert_eq!(cfg.periodic_schedule, None);
+    }
+
+    #[test]
+    fn v28_blob_migrates_with_default_psk_grace() {
+        let c = SystemConfig {
+            periodic_schedule: Some(PeriodicSchedule {
+                interval_secs: 3600,
+                duration_secs: 600,
+            }),
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in ["psk_grace_secs", "max_control_dt_ms", "flow_filter_tau_ms"] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v28: SystemConfigV28 = serde_json::from_value(json).unwrap();
+        let mut blob = 28u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v28).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v28 blob rejected");
+        assert_eq!(version, 28);
+        assert_eq!(cfg.periodic_schedule, c.periodic_schedule);
+        assert_eq!(cfg.psk_grace_secs, SystemConfig::default().psk_grace_secs);
+    }
+
+    #[test]
+    fn v29_blob_migrates_with_default_jitter_compensation() {
+        let c = SystemConfig {
+            psk_grace_secs: 600,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "max_control_dt_ms",
+            "flow_filter_tau_ms",
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v29: SystemConfigV29 = serde_json::from_value(json).unwrap();
+        let mut blob = 29u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v29).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v29 blob rejected");
+        assert_eq!(version, 29);
+        assert_eq!(cfg.psk_grace_secs, 600);
+        let d = SystemConfig::default();
+        assert_eq!(cfg.max_control_dt_ms, d.max_control_dt_ms);
+        assert_eq!(cfg.flow_filter_tau_ms, d.flow_filter_tau_ms);
+    }
+
+    #[test]
+    fn v30_blob_migrates_with_default_auth_lockout() {
+        let c = SystemConfig {
+            flow_filter_tau_ms: 2000,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "auth_lockout_threshold",
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v30: SystemConfigV30 = serde_json::from_value(json).unwrap();
+        let mut blob = 30u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v30).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v30 blob rejected");
+        assert_eq!(version, 30);
+        assert_eq!(cfg.flow_filter_tau_ms, 2000);
+        assert_eq!(
+            cfg.auth_lockout_threshold,
+            SystemConfig::default().auth_lockout_threshold
+        );
+    }
+
+    #[test]
+    fn v31_blob_migrates_with_default_roam_trigger() {
+        let c = SystemConfig {
+            auth_lockout_threshold: 3,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in [
+            "wifi_roam_rssi_dbm",
+            "wifi_roam_samples",
+            "tcp_idle_timeout_secs",
+            "ping_requires_auth",
+        ] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v31: SystemConfigV31 = serde_json::from_value(json).unwrap();
+        let mut blob = 31u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v31).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v31 blob rejected");
+        assert_eq!(version, 31);
+        assert_eq!(cfg.auth_lockout_threshold, 3);
+        let d = SystemConfig::default();
+        assert_eq!(cfg.wifi_roam_rssi_dbm, d.wifi_roam_rssi_dbm);
+        assert_eq!(cfg.wifi_roam_samples, d.wifi_roam_samples);
+    }
+
+    #[test]
+    fn v32_blob_migrates_with_default_tcp_idle_timeout() {
+        let c = SystemConfig {
+            wifi_roam_samples: 5,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        for key in ["tcp_idle_timeout_secs", "ping_requires_auth"] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let v32: SystemConfigV32 = serde_json::from_value(json).unwrap();
+        let mut blob = 32u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v32).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v32 blob rejected");
+        assert_eq!(version, 32);
+        assert_eq!(cfg.wifi_roam_samples, 5);
+        assert_eq!(
+            cfg.tcp_idle_timeout_secs,
+            SystemConfig::default().tcp_idle_timeout_secs
+        );
+    }
+
+    #[test]
+    fn v33_blob_migrates_with_ping_behind_auth() {
+        let c = SystemConfig {
+            tcp_idle_timeout_secs: 60,
+            ..Default::default()
+        };
+        let mut json = serde_json::to_value(&c).unwrap();
+        json.as_object_mut().unwrap().remove("ping_requires_auth");
+        let v33: SystemConfigV33 = serde_json::from_value(json).unwrap();
+        let mut blob = 33u16.to_le_bytes().to_vec();
+        blob.extend(postcard::to_allocvec(&v33).unwrap());
+
+        let (cfg, version) = decode_stored(&blob).expect("v33 blob rejected");
+        assert_eq!(version, 33);
+        assert_eq!(cfg.tcp_idle_timeout_secs, 60);
+        assert!(cfg.ping_requires_auth);
+    }
+
+    #[test]
+    fn control_dt_follows_measurement_within_the_clamp() {
+        let dt =
+            |c: &SystemConfig, measured: f32| (c.control_dt_secs(measured) * 1000.0).round() as u32;
+        let c = SystemConfig::default();
+        assert_eq!(dt(&c, 0.4), 400);
+        assert_eq!(dt(&c, 2.5), 2500);
+        assert_eq!(dt(&c, 30.0), 4000);
+        assert_eq!(dt(&c, -1.0), 0);
+
+        let nominal = SystemConfig {
+            max_control_dt_ms: 0,
+            ..Default::default()
+        };
+        assert_eq!(dt(&nominal, 0.4), 1000);
+        assert_eq!(dt(&nominal, 30.0), 1000);
+
+        let tight = SystemConfig {
+            max_control_dt_ms: 200,
+            ..Default::default()
+        };
+        assert_eq!(dt(&tight, 30.0), 1000, "never below nominal");
+    }
+
+    #[test]
+    fn balanced_profile_honours_configured_control_interval() {
+        let mut c = SystemConfig {
+            control_loop_interval_ms: 750,
+            ..Default::default()
+        };
+        assert_eq!(c.power_timing().control_loop_interval_ms, 750);
+
+        c.power_profile = PowerProfile::Eco;
+        assert_eq!(c.power_timing().control_loop_interval_ms, 2000);
+        c.power_profile = PowerProfile::Performance;
+        assert_eq!(c.power_timing().control_loop_interval_ms, 500);
+    }
+
+    #[test]
+    fn current_version_roundtrips_through_stored_layout() {
+        use crate::drivers::led_patterns::PatternId;
+
+        let mut c = SystemConfig {
+            pump_duty_percent: 42,
+            quiet_hours: Some(QuietHours {
+                start_hour: 21,
+                end_hour: 7,
+            }),
+            ..Default::default()
+        };
+        c.paused_schedules
+            .push("manual-boost".try_into().unwrap())
+            .unwrap();
+        c.led_overrides[StateId::Purging as usize] = Some(LedOverride {
+            colour: (1, 2, 3),
+            pattern: PatternId::DoubleBlink,
+        });
+        let blob = encode_stored(&c).unwrap();
+        assert_eq!(&blob[..2], &CONFIG_SCHEMA_VERSION.to_le_bytes());
+
+        let (c2, version) = decode_stored(&blob).unwrap();
+        assert_eq!(version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(c2.pump_duty_percent, 42);
+        assert_eq!(c2.quiet_hours, c.quiet_hours);
+        assert_eq!(c2.paused_schedules, c.paused_schedules);
+        assert_eq!(c2.led_overrides, c.led_overrides);
+    }
+
+    #[test]
+    fn unknown_version_and_garbage_are_rejected() {
+        assert!(migrate(CONFIG_SCHEMA_VERSION + 1, &[]).is_none());
+        assert!(decode_stored(&[]).is_none());
+        assert!(decode_stored(&[0xFF; 3]).is_none());
+    }
+
     #[test]
     fn default_config_is_sane() {
         let c = SystemConfig::default();
         assert!(c.nh3_activate_threshold_ppm > c.nh3_deactivate_threshold_ppm);
+        assert!(c.nh3_urgent_threshold_ppm > c.nh3_activate_threshold_ppm);
         assert!(c.pump_duty_percent > 0 && c.pump_duty_percent <= 100);
         assert!(c.uvc_duty_percent > 0 && c.uvc_duty_percent <= 100);
         assert!(c.max_temperature_c > 0.0);
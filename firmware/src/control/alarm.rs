@@ -0,0 +1,107 @@
+//! Level alarm with a hysteresis band
+//!
+//! The alarm raises when the reading reaches the high level and stays
+//! raised until it falls below a separate, lower clear level, so a
+//! reading hovering at the threshold does not flap the flag.
+
+/// Two-level alarm; reports only the edges.
+#[derive(Debug, Default)]
+pub struct HysteresisAlarm {
+    active: bool,
+}
+
+impl HysteresisAlarm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold in `value` and return the new state if it changed.
+    ///
+    /// Raises at `value >= high`, clears at `value < clear`.  A `high`
+    /// of zero (or below) disables the alarm and clears it if raised.
+    pub fn update(&mut self, value: f32, high: f32, clear: f32) -> Option<bool> {
+        let next = if high <= 0.0 {
+            false
+        } else if self.active {
+            value >= clear
+        } else {
+            value >= high
+        };
+        self.set(next)
+    }
+
+    /// Force the alarm down, e.g. while the sensor is not trustworthy.
+    /// Returns `Some(false)` if it was raised.
+    pub fn clear(&mut self) -> Option<bool> {
+        self.set(false)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn set(&mut self, next: bool) -> Option<bool> {
+        if next == self.active {
+            return None;
+        }
+        self.active = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HIGH: f32 = 25.0;
+    const CLEAR: f32 = 20.0;
+
+    #[test]
+    fn raises_at_high_level() {
+        let mut alarm = HysteresisAlarm::new();
+        assert_eq!(alarm.update(24.9, HIGH, CLEAR), None);
+        assert!(!alarm.is_active());
+        assert_eq!(alarm.update(25.0, HIGH, CLEAR), Some(true));
+        assert!(alarm.is_active());
+        assert_eq!(alarm.update(30.0, HIGH, CLEAR), None);
+    }
+
+    #[test]
+    fn holds_inside_band_and_clears_below_clear_level() {
+        let mut alarm = HysteresisAlarm::new();
+        alarm.update(26.0, HIGH, CLEAR);
+        for ppm in [24.0, 22.0, 20.0] {
+            assert_eq!(alarm.update(ppm, HIGH, CLEAR), None, "ppm={ppm}");
+            assert!(alarm.is_active(), "cleared early at {ppm}");
+        }
+        assert_eq!(alarm.update(19.9, HIGH, CLEAR), Some(false));
+        assert!(!alarm.is_active());
+    }
+
+    #[test]
+    fn does_not_re_raise_inside_band_after_clearing() {
+        let mut alarm = HysteresisAlarm::new();
+        alarm.update(26.0, HIGH, CLEAR);
+        alarm.update(19.0, HIGH, CLEAR);
+        assert_eq!(alarm.update(24.0, HIGH, CLEAR), None);
+        assert!(!alarm.is_active());
+        assert_eq!(alarm.update(25.0, HIGH, CLEAR), Some(true));
+    }
+
+    #[test]
+    fn zero_high_level_disables_and_clears() {
+        let mut alarm = HysteresisAlarm::new();
+        assert_eq!(alarm.update(500.0, 0.0, 0.0), None);
+        alarm.update(26.0, HIGH, CLEAR);
+        assert_eq!(alarm.update(26.0, 0.0, 0.0), Some(false));
+    }
+
+    #[test]
+    fn forced_clear_reports_edge_once() {
+        let mut alarm = HysteresisAlarm::new();
+        assert_eq!(alarm.clear(), None);
+        alarm.update(26.0, HIGH, CLEAR);
+        assert_eq!(alarm.clear(), Some(false));
+        assert_eq!(alarm.clear(), None);
+    }
+}
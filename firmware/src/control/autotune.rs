@@ -0,0 +1,289 @@
+//! Relay-feedback PID auto-tune
+//!
+//! Åström–Hägglund relay experiment: the output is switched between
+//! `bias ± step` each time the measurement crosses the setpoint, which
+//! drives the loop into a limit cycle.  The amplitude `a` and period
+//! `Tu` of that oscillation give the ultimate gain
+//! `Ku = 4·step / (π·a)`, from which the classic Ziegler–Nichols rules
+//! suggest PID gains.
+//!
+//! The tuner only measures and computes; whether the suggestion is
+//! applied is the caller's decision.
+
+use core::f32::consts::PI;
+
+/// PID gains in the controller's units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidGains {
+    pub kp: f32,
+    pub ki: f32,
+    pub kd: f32,
+}
+
+/// Outcome of a completed relay experiment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TuneResult {
+    /// Ultimate (critical) gain `Ku`.
+    pub ultimate_gain: f32,
+    /// Ultimate oscillation period `Tu`, in seconds.
+    pub ultimate_period_secs: f32,
+    /// Ziegler–Nichols gains derived from `Ku` and `Tu`.
+    pub gains: PidGains,
+}
+
+/// Where an auto-tune run stands, as reported to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TuneStatus {
+    /// No run in progress and no suggestion pending.
+    #[default]
+    Idle,
+    /// Relay experiment in progress.
+    Running,
+    /// Finished; the suggestion waits for confirmation.
+    Complete(TuneResult),
+    /// Hit the hard timeout before the oscillation settled.
+    TimedOut,
+    /// Cancelled, or stopped by a fault or the scrub ending.
+    Aborted,
+}
+
+/// Relay experiment parameters.
+#[derive(Debug, Clone, Copy)]
+pub struct RelayTuneConfig {
+    /// Level the relay switches around.
+    pub setpoint: f32,
+    /// Output midpoint.
+    pub bias: f32,
+    /// Relay amplitude: the output swings between `bias ± step`.
+    pub step: f32,
+    /// Dead band either side of the setpoint so sensor noise cannot
+    /// chatter the relay.
+    pub hysteresis: f32,
+    /// Hard limit on the whole experiment.
+    pub timeout_secs: f32,
+    /// Oscillation periods averaged for the result.  The first full
+    /// period is a start-up transient and is not counted.
+    pub cycles: u8,
+    /// More output raises the measurement (pump duty and flow) rather
+    /// than lowering it (pumping and NH3).
+    pub direct_acting: bool,
+}
+
+/// What the caller should do after feeding one sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TuneStep {
+    /// Keep going and drive the output to this value.
+    Drive(f32),
+    /// Enough periods measured.
+    Complete(TuneResult),
+    /// `timeout_secs` elapsed first.
+    TimedOut,
+}
+
+/// Ultimate gain from the describing-function approximation of an
+/// ideal relay: `Ku = 4·step / (π·amplitude)`, `amplitude` being half
+/// the peak-to-peak swing of the measurement.
+pub fn ultimate_gain(step: f32, amplitude: f32) -> f32 {
+    4.0 * step / (PI * amplitude)
+}
+
+/// Classic Ziegler–Nichols PID rules: `Kp = 0.6·Ku`, `Ti = Tu/2`,
+/// `Td = Tu/8`, expressed as parallel-form `Ki = Kp/Ti`, `Kd = Kp·Td`.
+pub fn ziegler_nichols(ultimate_gain: f32, ultimate_period_secs: f32) -> PidGains {
+    let kp = 0.6 * ultimate_gain;
+    PidGains {
+        kp,
+        ki: kp / (ultimate_period_secs / 2.0),
+        kd: kp * (ultimate_period_secs / 8.0),
+    }
+}
+
+/// Relay auto-tuner.  The relay logic is written for a reverse-acting
+/// loop; a direct-acting one is mirrored about the setpoint first.
+pub struct RelayTuner {
+    cfg: RelayTuneConfig,
+    elapsed_secs: f32,
+    /// Relay currently at `bias + step`.
+    high: bool,
+    /// Time of the last low → high switch, which closes a period.
+    last_rise_secs: Option<f32>,
+    /// The start-up period has been seen and discarded.
+    settled: bool,
+    cycle_max: f32,
+    cycle_min: f32,
+    period_sum: f32,
+    amplitude_sum: f32,
+    measured: u8,
+}
+
+impl RelayTuner {
+    /// Start with the relay high: the measurement sits at the setpoint,
+    /// so the first crossing is on the way down.
+    pub fn new(cfg: RelayTuneConfig) -> Self {
+        Self {
+            cfg,
+            elapsed_secs: 0.0,
+            high: true,
+            last_rise_secs: None,
+            settled: false,
+            cycle_max: cfg.setpoint,
+            cycle_min: cfg.setpoint,
+            period_sum: 0.0,
+            amplitude_sum: 0.0,
+            measured: 0,
+        }
+    }
+
+    /// Fold in `measurement`, taken `dt_secs` after the previous one.
+    pub fn update(&mut self, measurement: f32, dt_secs: f32) -> TuneStep {
+        self.elapsed_secs += dt_secs.max(0.0);
+        if self.elapsed_secs >= self.cfg.timeout_secs {
+            return TuneStep::TimedOut;
+        }
+        let measurement = if self.cfg.direct_acting {
+            2.0 * self.cfg.setpoint - measurement
+        } else {
+            measurement
+        };
+        self.cycle_max = self.cycle_max.max(measurement);
+        self.cycle_min = self.cycle_min.min(measurement);
+
+        let sp = self.cfg.setpoint;
+        if self.high && measurement < sp - self.cfg.hysteresis {
+            self.high = false;
+        } else if !self.high && measurement > sp + self.cfg.hysteresis {
+            self.high = true;
+            if let Some(prev) = self.last_rise_secs {
+                if self.settled {
+                    self.period_sum += self.elapsed_secs - prev;
+                    self.amplitude_sum += (self.cycle_max - self.cycle_min) / 2.0;
+                    self.measured += 1;
+                }
+                self.settled = true;
+            }
+            self.last_rise_secs = Some(self.elapsed_secs);
+            self.cycle_max = measurement;
+            self.cycle_min = measurement;
+            if self.measured >= self.cfg.cycles.max(1) {
+                return TuneStep::Complete(self.result());
+            }
+        }
+        TuneStep::Drive(self.output())
+    }
+
+    /// Seconds since the run started.
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed_secs
+    }
+
+    fn output(&self) -> f32 {
+        if self.high {
+            self.cfg.bias + self.cfg.step
+        } else {
+            self.cfg.bias - self.cfg.step
+        }
+    }
+
+    fn result(&self) -> TuneResult {
+        let n = f32::from(self.measured);
+        let ku = ultimate_gain(self.cfg.step, self.amplitude_sum / n);
+        let tu = self.period_sum / n;
+        TuneResult {
+            ultimate_gain: ku,
+            ultimate_period_secs: tu,
+            gains: ziegler_nichols(ku, tu),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4 * b.abs().max(1.0)
+    }
+
+    fn config() -> RelayTuneConfig {
+        RelayTuneConfig {
+            setpoint: 10.0,
+            bias: 55.0,
+            step: 45.0,
+            hysteresis: 0.1,
+            timeout_secs: 600.0,
+            cycles: 3,
+            direct_acting: false,
+        }
+    }
+
+    #[test]
+    fn ultimate_gain_from_relay_and_amplitude() {
+        // Ku = 4·d / (π·a): d = 45 % duty, a = 2 ppm.
+        assert!(close(ultimate_gain(45.0, 2.0), 90.0 / PI));
+        // Halving the swing doubles the gain.
+        assert!(close(ultimate_gain(45.0, 1.0), 2.0 * ultimate_gain(45.0, 2.0)));
+    }
+
+    #[test]
+    fn ziegler_nichols_pid_rules() {
+        let g = ziegler_nichols(10.0, 40.0);
+        assert!(close(g.kp, 6.0));
+        assert!(close(g.ki, 6.0 / 20.0)); // Ti = Tu/2
+        assert!(close(g.kd, 6.0 * 5.0)); // Td = Tu/8
+    }
+
+    #[test]
+    fn known_oscillation_yields_expected_gains() {
+        // Square-ish wave, ±2 ppm around the setpoint with a 20 s period,
+        // sampled every second.
+        let mut tuner = RelayTuner::new(config());
+        let mut t = 0u32;
+        let result = loop {
+            let phase = t % 20;
+            let pv = if phase < 10 { 8.0 } else { 12.0 };
+            t += 1;
+            match tuner.update(pv, 1.0) {
+                TuneStep::Drive(_) => {}
+                TuneStep::Complete(r) => break r,
+                TuneStep::TimedOut => panic!("timed out"),
+            }
+        };
+        assert!(close(result.ultimate_period_secs, 20.0));
+        let ku = ultimate_gain(45.0, 2.0);
+        assert!(close(result.ultimate_gain, ku));
+        assert_eq!(result.gains, ziegler_nichols(ku, 20.0));
+    }
+
+    #[test]
+    fn relay_is_reverse_acting() {
+        let mut tuner = RelayTuner::new(config());
+        assert_eq!(tuner.update(10.0, 1.0), TuneStep::Drive(100.0));
+        assert_eq!(tuner.update(9.0, 1.0), TuneStep::Drive(10.0));
+        // Inside the dead band: no switch.
+        assert_eq!(tuner.update(10.05, 1.0), TuneStep::Drive(10.0));
+        assert_eq!(tuner.update(11.0, 1.0), TuneStep::Drive(100.0));
+    }
+
+    #[test]
+    fn direct_acting_relay_mirrors_the_switching() {
+        let mut tuner = RelayTuner::new(RelayTuneConfig {
+            direct_acting: true,
+            ..config()
+        });
+        assert_eq!(tuner.update(10.0, 1.0), TuneStep::Drive(100.0));
+        assert_eq!(tuner.update(11.0, 1.0), TuneStep::Drive(10.0));
+        assert_eq!(tuner.update(9.95, 1.0), TuneStep::Drive(10.0));
+        assert_eq!(tuner.update(9.0, 1.0), TuneStep::Drive(100.0));
+    }
+
+    #[test]
+    fn flat_measurement_times_out() {
+        let mut tuner = RelayTuner::new(config());
+        let mut steps = 0;
+        while let TuneStep::Drive(_) = tuner.update(10.0, 1.0) {
+            steps += 1;
+            assert!(steps < 1000, "no timeout");
+        }
+        assert!(tuner.elapsed_secs() >= 600.0);
+    }
+}
@@ -0,0 +1,64 @@
+//! Thermal derating of an actuator duty
+//!
+//! A soft limit under the hard over-temperature fault: from `start_c`
+//! the commanded duty is scaled down linearly, reaching `min_duty` at
+//! `limit_c`, so the pump adds less heat as the water warms instead of
+//! running flat out into the fault.
+
+/// Derate `duty` for `temperature_c`.
+///
+/// Unchanged at or below `start_c`; interpolated towards `min_duty`
+/// between `start_c` and `limit_c`; `min_duty` at or above `limit_c`.
+/// Never raises a duty already below `min_duty`.  A `start_c` of zero
+/// (or a band that is not below `limit_c`) disables derating.
+pub fn derate_duty(duty: u8, temperature_c: f32, start_c: f32, limit_c: f32, min_duty: u8) -> u8 {
+    if start_c <= 0.0 || start_c >= limit_c || duty <= min_duty {
+        return duty;
+    }
+    if temperature_c.is_nan() || temperature_c <= start_c {
+        return duty;
+    }
+    let t = ((temperature_c - start_c) / (limit_c - start_c)).min(1.0);
+    let span = f32::from(duty - min_duty);
+    duty - (span * t) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: f32 = 60.0;
+    const LIMIT: f32 = 80.0;
+    const MIN: u8 = 30;
+
+    #[test]
+    fn unaffected_below_start() {
+        for temp in [20.0, 59.9, 60.0] {
+            assert_eq!(derate_duty(90, temp, START, LIMIT, MIN), 90, "temp={temp}");
+        }
+    }
+
+    #[test]
+    fn scales_linearly_inside_band() {
+        assert_eq!(derate_duty(90, 65.0, START, LIMIT, MIN), 75);
+        assert_eq!(derate_duty(90, 70.0, START, LIMIT, MIN), 60);
+        assert_eq!(derate_duty(90, 75.0, START, LIMIT, MIN), 45);
+    }
+
+    #[test]
+    fn floored_at_min_duty_at_and_past_limit() {
+        assert_eq!(derate_duty(90, 79.9, START, LIMIT, MIN), 31);
+        assert_eq!(derate_duty(90, 80.0, START, LIMIT, MIN), MIN);
+        assert_eq!(derate_duty(90, 95.0, START, LIMIT, MIN), MIN);
+    }
+
+    #[test]
+    fn never_raises_a_low_duty() {
+        assert_eq!(derate_duty(20, 75.0, START, LIMIT, MIN), 20);
+    }
+
+    #[test]
+    fn zero_start_disables() {
+        assert_eq!(derate_duty(90, 95.0, 0.0, LIMIT, MIN), 90);
+    }
+}
@@ -0,0 +1,119 @@
+//! Time-constant exponential moving average
+//!
+//! The smoothing factor is derived from the elapsed time of each sample
+//! (`alpha = 1 - e^(-dt/tau)`) instead of being fixed per call, so the
+//! filter settles in the same wall-clock time however irregularly it is
+//! fed.
+
+/// First-order low-pass filter with time constant `tau_secs`.
+pub struct Ema {
+    tau_secs: f32,
+    value: Option<f32>,
+}
+
+impl Ema {
+    /// `tau_secs <= 0` disables smoothing (output follows input).
+    pub fn new(tau_secs: f32) -> Self {
+        Self {
+            tau_secs,
+            value: None,
+        }
+    }
+
+    /// Fold in `sample`, taken `dt_secs` after the previous one, and
+    /// return the filtered value.  The first sample seeds the filter.
+    pub fn update(&mut self, sample: f32, dt_secs: f32) -> f32 {
+        let next = match self.value {
+            Some(prev) if self.tau_secs > 0.0 => {
+                let alpha = 1.0 - (-dt_secs.max(0.0) / self.tau_secs).exp();
+                prev + alpha * (sample - prev)
+            }
+            _ => sample,
+        };
+        self.value = Some(next);
+        next
+    }
+
+    /// Change the time constant; the filtered value carries over.
+    pub fn set_tau_secs(&mut self, tau_secs: f32) {
+        self.tau_secs = tau_secs;
+    }
+
+    /// Last filtered value (`None` before the first sample).
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Forget history; the next sample re-seeds the filter.
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sample_seeds_output() {
+        let mut ema = Ema::new(2.0);
+        assert_eq!(ema.value(), None);
+        assert!((ema.update(40.0, 1.0) - 40.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn one_time_constant_reaches_63_percent() {
+        let mut ema = Ema::new(2.0);
+        ema.update(0.0, 0.0);
+        let out = ema.update(100.0, 2.0);
+        assert!((out - 63.212).abs() < 0.01, "{out}");
+    }
+
+    #[test]
+    fn variable_dt_matches_uniform_dt_over_same_span() {
+        let mut uniform = Ema::new(3.0);
+        let mut jittery = Ema::new(3.0);
+        uniform.update(0.0, 0.0);
+        jittery.update(0.0, 0.0);
+
+        for _ in 0..4 {
+            uniform.update(100.0, 1.0);
+        }
+        for dt in [0.2, 1.7, 0.4, 0.9, 0.8] {
+            jittery.update(100.0, dt);
+        }
+        let (u, j) = (uniform.value().unwrap(), jittery.value().unwrap());
+        assert!((u - j).abs() < 0.01, "uniform {u} jittery {j}");
+    }
+
+    #[test]
+    fn longer_gap_moves_further() {
+        let mut short = Ema::new(1.0);
+        let mut long = Ema::new(1.0);
+        short.update(0.0, 0.0);
+        long.update(0.0, 0.0);
+        assert!(long.update(10.0, 1.5) > short.update(10.0, 0.5));
+    }
+
+    #[test]
+    fn zero_dt_holds_value() {
+        let mut ema = Ema::new(1.0);
+        ema.update(5.0, 0.0);
+        assert!((ema.update(50.0, 0.0) - 5.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn zero_tau_passes_through() {
+        let mut ema = Ema::new(0.0);
+        ema.update(5.0, 1.0);
+        assert!((ema.update(50.0, 0.1) - 50.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn reset_reseeds() {
+        let mut ema = Ema::new(5.0);
+        ema.update(10.0, 1.0);
+        ema.reset();
+        assert!((ema.update(90.0, 1.0) - 90.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,185 @@
+//! Q16.16 signed fixed-point arithmetic.
+//!
+//! 16 integer bits, 16 fractional bits in an `i32`: range ±32768 with a
+//! resolution of 1/65536 (≈1.5e-5).  Every operation saturates instead of
+//! wrapping, so an out-of-range PID term pins at the rail rather than
+//! flipping sign.  Only integer instructions are used, which keeps the
+//! FPU powered down on the control path.
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Signed Q16.16 fixed-point number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[must_use]
+pub struct Q16(i32);
+
+impl Q16 {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+    pub const MAX: Self = Self(i32::MAX);
+    pub const MIN: Self = Self(i32::MIN);
+    /// Smallest representable step.
+    pub const EPSILON: Self = Self(1);
+
+    /// Wrap a raw Q16.16 bit pattern.
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// The raw Q16.16 bit pattern.
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Convert an integer, saturating outside ±32768.
+    pub const fn from_int(v: i32) -> Self {
+        Self(saturate(((v as i64) << Self::FRAC_BITS) as i128))
+    }
+
+    /// Convert from `f32`, rounding to nearest and saturating.  NaN maps
+    /// to zero.  Intended for configuration values, not the hot path.
+    pub fn from_f32(v: f32) -> Self {
+        // `as` from float saturates and maps NaN to 0.
+        Self((v * (1u32 << Self::FRAC_BITS) as f32).round() as i32)
+    }
+
+    /// Convert to `f32` (exact for |v| < 256, otherwise rounded).
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / (1u32 << Self::FRAC_BITS) as f32
+    }
+
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        if self.0 < min.0 {
+            min
+        } else if self.0 > max.0 {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+const fn saturate(v: i128) -> i32 {
+    if v > i32::MAX as i128 {
+        i32::MAX
+    } else if v < i32::MIN as i128 {
+        i32::MIN
+    } else {
+        v as i32
+    }
+}
+
+impl Add for Q16 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Q16 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Q16 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Neg for Q16 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl Mul for Q16 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let wide = self.0 as i64 * rhs.0 as i64;
+        Self(saturate((wide >> Self::FRAC_BITS) as i128))
+    }
+}
+
+impl Div for Q16 {
+    type Output = Self;
+    /// Division by zero saturates toward the sign of the dividend
+    /// (and yields zero for 0/0) rather than panicking.
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return match self.0 {
+                0 => Self::ZERO,
+                n if n > 0 => Self::MAX,
+                _ => Self::MIN,
+            };
+        }
+        let wide = ((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64;
+        Self(saturate(wide as i128))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(v: f32) -> Q16 {
+        Q16::from_f32(v)
+    }
+
+    #[test]
+    fn f32_round_trip_within_one_lsb() {
+        for v in [
+            0.0, 1.0, -1.0, 0.5, 3.25, -12.125, 123.456, -0.0001, 30_000.0,
+        ] {
+            let back = q(v).to_f32();
+            assert!((back - v).abs() <= 1.0 / 65_536.0, "{v} -> {back}");
+        }
+    }
+
+    #[test]
+    fn from_f32_saturates_and_maps_nan_to_zero() {
+        assert_eq!(q(1e9), Q16::MAX);
+        assert_eq!(q(-1e9), Q16::MIN);
+        assert_eq!(q(f32::NAN), Q16::ZERO);
+        assert_eq!(Q16::from_int(40_000), Q16::MAX);
+        assert_eq!(Q16::from_int(-3), q(-3.0));
+    }
+
+    #[test]
+    fn arithmetic_matches_float() {
+        assert_eq!(q(1.5) + q(2.25), q(3.75));
+        assert_eq!(q(1.5) - q(2.25), q(-0.75));
+        assert_eq!(q(1.5) * q(-2.0), q(-3.0));
+        assert_eq!(q(7.5) / q(2.5), q(3.0));
+        assert_eq!(-q(4.0), q(-4.0));
+    }
+
+    #[test]
+    fn operations_saturate_instead_of_wrapping() {
+        assert_eq!(Q16::MAX + Q16::ONE, Q16::MAX);
+        assert_eq!(Q16::MIN - Q16::ONE, Q16::MIN);
+        assert_eq!(q(30_000.0) * q(30_000.0), Q16::MAX);
+        assert_eq!(q(-30_000.0) * q(30_000.0), Q16::MIN);
+        assert_eq!(q(30_000.0) / q(0.001), Q16::MAX);
+        assert_eq!(-Q16::MIN, Q16::MAX);
+    }
+
+    #[test]
+    fn divide_by_zero_saturates() {
+        assert_eq!(q(1.0) / Q16::ZERO, Q16::MAX);
+        assert_eq!(q(-1.0) / Q16::ZERO, Q16::MIN);
+        assert_eq!(Q16::ZERO / Q16::ZERO, Q16::ZERO);
+    }
+
+    #[test]
+    fn clamp_orders_correctly() {
+        assert_eq!(q(150.0).clamp(q(0.0), q(100.0)), q(100.0));
+        assert_eq!(q(-5.0).clamp(q(0.0), q(100.0)), q(0.0));
+        assert_eq!(q(42.0).clamp(q(0.0), q(100.0)), q(42.0));
+    }
+}
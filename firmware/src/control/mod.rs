@@ -1,3 +1,9 @@
 //! Control algorithms.
 
+pub mod alarm;
+pub mod autotune;
+pub mod derate;
+pub mod ema;
+pub mod fixed;
+pub mod numeric;
 pub mod pid;
@@ -0,0 +1,196 @@
+//! Numeric backend for the control core.
+//!
+//! The PID loop, the NH3 running average and the FSM threshold checks are
+//! written against [`ControlNum`], implemented for both `f32` and
+//! [`Q16`].  [`Num`] picks one at compile time: `f32` by default, Q16.16
+//! with the `fixed-point` cargo feature.  Sensor drivers and the FSM keep
+//! exchanging `f32` at their boundaries either way.
+//!
+//! ## Fixed-point tolerance
+//!
+//! Against the `f32` build, the Q16.16 backend stays within
+//! [`PID_TOLERANCE`] duty-% on the pump PID and [`MEAN_TOLERANCE_PPM`] on
+//! the NH3 average for inputs in the sensors' operating range.  Threshold
+//! comparisons agree except when the reading and the threshold are within
+//! one Q16 step (≈1.5e-5) of each other.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::fixed::Q16;
+
+/// Maximum PID output deviation (duty %) of Q16 from `f32`.
+pub const PID_TOLERANCE: f32 = 0.05;
+
+/// Maximum NH3 running-average deviation (ppm) of Q16 from `f32`.
+pub const MEAN_TOLERANCE_PPM: f32 = 0.001;
+
+/// Arithmetic the control core needs from its number type.
+pub trait ControlNum:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+
+    fn from_f32(v: f32) -> Self;
+    fn to_f32(self) -> f32;
+    /// Convert a sample count or ADC delta.
+    fn from_count(n: u32) -> Self;
+    #[must_use]
+    fn clamp_to(self, min: Self, max: Self) -> Self;
+}
+
+impl ControlNum for f32 {
+    const ZERO: Self = 0.0;
+
+    fn from_f32(v: f32) -> Self {
+        v
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn from_count(n: u32) -> Self {
+        n as f32
+    }
+
+    fn clamp_to(self, min: Self, max: Self) -> Self {
+        self.clamp(min, max)
+    }
+}
+
+impl ControlNum for Q16 {
+    const ZERO: Self = Q16::ZERO;
+
+    fn from_f32(v: f32) -> Self {
+        Q16::from_f32(v)
+    }
+
+    fn to_f32(self) -> f32 {
+        Q16::to_f32(self)
+    }
+
+    fn from_count(n: u32) -> Self {
+        Q16::from_int(i32::try_from(n).unwrap_or(i32::MAX))
+    }
+
+    fn clamp_to(self, min: Self, max: Self) -> Self {
+        self.clamp(min, max)
+    }
+}
+
+/// Number type selected for the control core.
+#[cfg(not(feature = "fixed-point"))]
+pub type Num = f32;
+
+/// Number type selected for the control core.
+#[cfg(feature = "fixed-point")]
+pub type Num = Q16;
+
+/// Mean of `samples`, or zero when empty.
+pub fn mean<N: ControlNum>(samples: &[N]) -> N {
+    if samples.is_empty() {
+        return N::ZERO;
+    }
+    let sum = samples.iter().fold(N::ZERO, |acc, &s| acc + s);
+    sum / N::from_count(samples.len() as u32)
+}
+
+/// `value > threshold`, evaluated in [`Num`].  Thresholds come from
+/// the config and are converted once when it changes, not per call.
+pub fn above(value: f32, threshold: Num) -> bool {
+    Num::from_f32(value) > threshold
+}
+
+/// `value >= threshold`, evaluated in [`Num`].
+pub fn at_or_above(value: f32, threshold: Num) -> bool {
+    Num::from_f32(value) >= threshold
+}
+
+/// `value < threshold`, evaluated in [`Num`].
+pub fn below(value: f32, threshold: Num) -> bool {
+    Num::from_f32(value) < threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::pid::Pid;
+
+    /// A pump spinning up from dry, overshooting, and settling — the same
+    /// gains and limits `AppService` uses.
+    const FLOW_TRACE: [f32; 16] = [
+        0.0, 40.0, 120.0, 260.0, 410.0, 530.0, 560.0, 545.0, 515.0, 498.0, 503.0, 501.0, 470.0,
+        455.0, 490.0, 500.0,
+    ];
+
+    #[test]
+    fn pid_fixed_tracks_float_within_tolerance() {
+        for dt in [0.25, 0.5, 1.0] {
+            let mut float = Pid::<f32>::new(2.0, 0.5, 0.1, 500.0);
+            let mut fixed = Pid::<Q16>::new(2.0, 0.5, 0.1, 500.0);
+            float.set_limits(0.0, 100.0);
+            fixed.set_limits(0.0, 100.0);
+            for flow in FLOW_TRACE {
+                let a = float.compute(flow, dt);
+                let b = fixed.compute(flow, dt);
+                assert!(
+                    (a - b).abs() <= PID_TOLERANCE,
+                    "dt={dt} flow={flow}: f32={a} q16={b}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pid_fixed_tracks_float_in_unsaturated_region() {
+        // Small gains keep the output off the rails so the P, I and D
+        // terms are all compared, not just the clamp.
+        let mut float = Pid::<f32>::new(0.05, 0.01, 0.02, 500.0);
+        let mut fixed = Pid::<Q16>::new(0.05, 0.01, 0.02, 500.0);
+        float.set_limits(-100.0, 100.0);
+        fixed.set_limits(-100.0, 100.0);
+        for flow in FLOW_TRACE {
+            let a = float.compute(flow, 1.0);
+            let b = fixed.compute(flow, 1.0);
+            assert!(a.abs() < 100.0, "trace should not saturate: {a}");
+            assert!((a - b).abs() <= PID_TOLERANCE, "flow={flow}: {a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn mean_fixed_tracks_float_within_tolerance() {
+        let ppm: [f32; 30] = core::array::from_fn(|i| 2.0 + (i as f32 * 0.37) % 23.0);
+        let fixed: [Q16; 30] = ppm.map(Q16::from_f32);
+        for n in [1, 7, 30] {
+            let a = mean(&ppm[..n]);
+            let b = mean(&fixed[..n]).to_f32();
+            assert!((a - b).abs() <= MEAN_TOLERANCE_PPM, "n={n}: {a} vs {b}");
+        }
+        assert_eq!(mean::<Q16>(&[]), Q16::ZERO);
+    }
+
+    #[test]
+    fn threshold_comparisons_agree_outside_one_step() {
+        let thresholds = [5.0f32, 15.0, 25.0, 40.0];
+        let offsets = [-1.0f32, -0.01, -0.001, 0.001, 0.01, 1.0];
+        for t in thresholds {
+            for d in offsets {
+                let v = t + d;
+                let (fv, ft) = (Q16::from_f32(v), Q16::from_f32(t));
+                assert_eq!(fv > ft, v > t, "{v} > {t}");
+                assert_eq!(fv >= ft, v >= t, "{v} >= {t}");
+                assert_eq!(fv < ft, v < t, "{v} < {t}");
+            }
+        }
+        let t = Num::from_f32(15.0);
+        assert!(at_or_above(15.0, t));
+        assert!(!above(15.0, t));
+        assert!(below(14.0, t));
+    }
+}
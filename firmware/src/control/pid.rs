@@ -3,79 +3,94 @@
 //! Simple proportional-integral-derivative controller
 //! for maintaining target water flow rate through the venturi.
 
-/// PID controller for venturi pump flow-rate regulation.
-pub struct PidController {
-    kp: f32,
-    ki: f32,
-    kd: f32,
-    setpoint: f32,
-    integral: f32,
-    prev_error: f32,
-    output_min: f32,
-    output_max: f32,
+use super::numeric::{ControlNum, Num};
+
+/// PID controller for venturi pump flow-rate regulation, generic over the
+/// [`ControlNum`] backend.  The public API takes and returns `f32`; state
+/// and arithmetic stay in `N`.
+pub struct Pid<N: ControlNum> {
+    kp: N,
+    ki: N,
+    kd: N,
+    setpoint: N,
+    integral: N,
+    prev_error: N,
+    output_min: N,
+    output_max: N,
 }
 
-impl PidController {
+/// The pump PID on the compile-time selected backend.
+pub type PidController = Pid<Num>;
+
+impl<N: ControlNum> Pid<N> {
     pub fn new(kp: f32, ki: f32, kd: f32, setpoint: f32) -> Self {
         Self {
-            kp,
-            ki,
-            kd,
-            setpoint,
-            integral: 0.0,
-            prev_error: 0.0,
-            output_min: 0.0,
-            output_max: 100.0,
+            kp: N::from_f32(kp),
+            ki: N::from_f32(ki),
+            kd: N::from_f32(kd),
+            setpoint: N::from_f32(setpoint),
+            integral: N::ZERO,
+            prev_error: N::ZERO,
+            output_min: N::ZERO,
+            output_max: N::from_f32(100.0),
         }
     }
 
     /// Set output limits
     pub fn set_limits(&mut self, min: f32, max: f32) {
-        self.output_min = min;
-        self.output_max = max;
+        self.output_min = N::from_f32(min);
+        self.output_max = N::from_f32(max);
+    }
+
+    /// Replace the gains (e.g. with confirmed auto-tune suggestions).
+    pub fn set_gains(&mut self, kp: f32, ki: f32, kd: f32) {
+        self.kp = N::from_f32(kp);
+        self.ki = N::from_f32(ki);
+        self.kd = N::from_f32(kd);
     }
 
     /// Update setpoint
     pub fn set_target(&mut self, setpoint: f32) {
-        self.setpoint = setpoint;
+        self.setpoint = N::from_f32(setpoint);
     }
 
     /// Compute PID output given current measurement
     pub fn compute(&mut self, measurement: f32, dt: f32) -> f32 {
-        let error = self.setpoint - measurement;
+        let dt = N::from_f32(dt);
+        let error = self.setpoint - N::from_f32(measurement);
 
         // Proportional
         let p = self.kp * error;
 
         // Integral (with anti-windup)
-        self.integral += error * dt;
+        self.integral = self.integral + error * dt;
         let i = self.ki * self.integral;
 
         // Derivative
-        let derivative = if dt > 0.0 {
+        let derivative = if dt > N::ZERO {
             (error - self.prev_error) / dt
         } else {
-            0.0
+            N::ZERO
         };
         let d = self.kd * derivative;
 
         self.prev_error = error;
 
         // Clamp output
-        let output = (p + i + d).clamp(self.output_min, self.output_max);
+        let output = (p + i + d).clamp_to(self.output_min, self.output_max);
 
         // Anti-windup: if output is saturated, stop integrating
         if output >= self.output_max || output <= self.output_min {
-            self.integral -= error * dt;
+            self.integral = self.integral - error * dt;
         }
 
-        output
+        output.to_f32()
     }
 
     /// Reset controller state
     pub fn reset(&mut self) {
-        self.integral = 0.0;
-        self.prev_error = 0.0;
+        self.integral = N::ZERO;
+        self.prev_error = N::ZERO;
     }
 }
 
@@ -144,8 +159,8 @@ mod tests {
         pid.compute(50.0, 1.0);
         pid.reset();
         let fresh = PidController::new(1.0, 1.0, 1.0, 100.0);
-        assert!((pid.integral - fresh.integral).abs() < f32::EPSILON);
-        assert!((pid.prev_error - fresh.prev_error).abs() < f32::EPSILON);
+        assert!((pid.integral.to_f32() - fresh.integral.to_f32()).abs() < f32::EPSILON);
+        assert!((pid.prev_error.to_f32() - fresh.prev_error.to_f32()).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -1,25 +1,82 @@
 //! Crash logging and runtime diagnostics.
 //!
 //! Stores up to 4 crash entries in an NVS ring buffer under the "crash"
-//! namespace. Each entry captures timestamp, reason, program counter, and
-//! a truncated backtrace. A custom panic handler writes the entry before
-//! the TWDT or panic handler triggers a reset.
+//! namespace. Each entry captures timestamp, reason, panic site
+//! (`file:line`), program counter, and a truncated backtrace. A custom
+//! panic handler writes the entry before the TWDT or panic handler
+//! triggers a reset.
+//!
+//! Backtraces are raw return addresses for off-device symbolization
+//! (`xtensa-esp32s3-elf-addr2line -e firmware.elf <addr>...`).  They are
+//! walked from inside the panic hook, so the innermost frames belong to
+//! the panic runtime; the site itself comes from `PanicHookInfo::location`
+//! and the deeper frames give its callers.  They are capped at
+//! [`MAX_BACKTRACE_FRAMES`] so a worst-case entry still fits one NVS slot.
+//!
+//! A second, larger ring under the "audit" namespace records every
+//! authenticated RPC command that changes the device (who, what, when,
+//! and whether it succeeded) for security review.  It wraps after
+//! [`AUDIT_RING_SLOTS`] entries.
 //!
 //! Runtime metrics (heap, RSSI, NVS usage, ULP wakes) are collected
 //! on-demand for the diagnostics RPC response.
+//!
+//! [`BandwidthCounters`] accumulate, for every response frame the RPC
+//! engine encodes, the payload size before and after compression, so
+//! operators can judge the compression threshold from the field.
+//!
+//! [`DropRateMonitor`] rates inbound command frames dropped on a full
+//! queue and decides when the device counts as overloaded.
+//!
+//! [`FlowTotalizer`] integrates measured flow into a lifetime total and
+//! a resettable since-service subtotal, persisted under "flow" at most
+//! every [`FLOW_SAVE_EVERY_ML`] so flash wear stays low.
 
 use serde::{Deserialize, Serialize};
 
+use crate::app::service::ZoneId;
+use crate::app::zones::zone_key;
+
 const CRASH_RING_SLOTS: usize = 4;
-const CRASH_KEY_PREFIX: &str = "crash";
-const CRASH_INDEX_KEY: &str = "crash_idx";
+pub(crate) const CRASH_KEY_PREFIX: &str = "crash";
+pub(crate) const CRASH_INDEX_KEY: &str = "crash_idx";
+/// Read buffer per ring slot; every encoded [`CrashEntry`] must fit.
+const CRASH_SLOT_BYTES: usize = 256;
+
+/// Frames kept per crash entry: enough to get past the panic runtime
+/// to the panicking function's callers.
+pub const MAX_BACKTRACE_FRAMES: usize = 16;
+
+/// Longest stored panic site; longer paths keep their tail.
+const CRASH_LOCATION_LEN: usize = 48;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Bounded list of return addresses, innermost first.
+pub type Backtrace = heapless::Vec<u32, MAX_BACKTRACE_FRAMES>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrashEntry {
     pub uptime_secs: u64,
     pub reason: heapless::String<64>,
     pub pc: u32,
-    pub backtrace: heapless::Vec<u32, 8>,
+    pub backtrace: Backtrace,
+    /// Panic site as `file:line` (empty if unknown).  Last, so entries
+    /// written before it existed fail to decode and take the legacy path.
+    pub location: heapless::String<CRASH_LOCATION_LEN>,
+}
+
+/// Entry layout before the panic site was recorded.
+#[derive(Deserialize)]
+struct CrashEntryV1 {
+    uptime_secs: u64,
+    reason: heapless::String<64>,
+    pc: u32,
+    backtrace: heapless::Vec<u32, 8>,
+}
+
+impl From<CrashEntryV1> for CrashEntry {
+    fn from(v1: CrashEntryV1) -> Self {
+        CrashEntry::new(v1.uptime_secs, &v1.reason, v1.pc).with_backtrace(&v1.backtrace)
+    }
 }
 
 impl CrashEntry {
@@ -31,8 +88,35 @@ impl CrashEntry {
             reason: r,
             pc,
             backtrace: heapless::Vec::new(),
+            location: heapless::String::new(),
         }
     }
+
+    /// Record the panic site, dropping leading path components that do
+    /// not fit.
+    #[must_use]
+    pub fn with_location(mut self, file: &str, line: u32) -> Self {
+        let mut suffix = heapless::String::<12>::new();
+        let _ = core::fmt::Write::write_fmt(&mut suffix, format_args!(":{}", line));
+        let room = CRASH_LOCATION_LEN - suffix.len();
+        let mut start = file.len().saturating_sub(room);
+        while !file.is_char_boundary(start) {
+            start += 1;
+        }
+        self.location.clear();
+        let _ = self.location.push_str(&file[start..]);
+        let _ = self.location.push_str(&suffix);
+        self
+    }
+
+    /// Attach `frames`, keeping at most [`MAX_BACKTRACE_FRAMES`].
+    #[must_use]
+    pub fn with_backtrace(mut self, frames: &[u32]) -> Self {
+        let n = frames.len().min(MAX_BACKTRACE_FRAMES);
+        self.backtrace.clear();
+        let _ = self.backtrace.extend_from_slice(&frames[..n]);
+        self
+    }
 }
 
 /// NVS-backed ring buffer for crash entries.
@@ -78,9 +162,15 @@ impl CrashLog {
         let mut entries = heapless::Vec::new();
         for i in 0..CRASH_RING_SLOTS {
             let slot_key = Self::slot_key(i);
-            let mut buf = [0u8; 256];
+            let mut buf = [0u8; CRASH_SLOT_BYTES];
             if let Ok(len) = nvs.read(CRASH_KEY_PREFIX, &slot_key, &mut buf) {
-                if let Ok(entry) = postcard::from_bytes::<CrashEntry>(&buf[..len]) {
+                let bytes = &buf[..len];
+                let entry = postcard::from_bytes::<CrashEntry>(bytes).ok().or_else(|| {
+                    postcard::from_bytes::<CrashEntryV1>(bytes)
+                        .ok()
+                        .map(CrashEntry::from)
+                });
+                if let Some(entry) = entry {
                     let _ = entries.push(entry);
                 }
             }
@@ -111,6 +201,252 @@ impl CrashLog {
     }
 }
 
+/// Entries kept by the command audit ring.
+pub const AUDIT_RING_SLOTS: usize = 32;
+pub(crate) const AUDIT_KEY_PREFIX: &str = "audit";
+pub(crate) const AUDIT_INDEX_KEY: &str = "audit_idx";
+const AUDIT_SLOT_BYTES: usize = 32;
+
+/// One authenticated command, as seen by the RPC engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub uptime_secs: u32,
+    pub client_id: u8,
+    /// Raw FlatBuffers `Payload` discriminant of the request.
+    pub payload_type: u8,
+    pub success: bool,
+}
+
+/// NVS-backed ring buffer of recent authenticated commands.
+#[derive(Default)]
+pub struct AuditLog {
+    write_index: usize,
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the write index from NVS, or default to 0.
+    pub fn init(&mut self, nvs: &dyn crate::app::ports::StoragePort) {
+        let mut buf = [0u8; 4];
+        if let Ok(4) = nvs.read(AUDIT_KEY_PREFIX, AUDIT_INDEX_KEY, &mut buf) {
+            self.write_index = u32::from_le_bytes(buf) as usize % AUDIT_RING_SLOTS;
+        }
+    }
+
+    /// Record `entry` in the next slot, overwriting the oldest once full.
+    pub fn record(&mut self, nvs: &mut dyn crate::app::ports::StoragePort, entry: &AuditEntry) {
+        let slot_key = Self::slot_key(self.write_index);
+        if let Ok(bytes) = postcard::to_allocvec(entry) {
+            let _ = nvs.write(AUDIT_KEY_PREFIX, &slot_key, &bytes);
+        }
+
+        self.write_index = (self.write_index + 1) % AUDIT_RING_SLOTS;
+        let idx_bytes = (self.write_index as u32).to_le_bytes();
+        let _ = nvs.write(AUDIT_KEY_PREFIX, AUDIT_INDEX_KEY, &idx_bytes);
+    }
+
+    /// Read the stored entries, oldest first.
+    pub fn read_all(
+        &self,
+        nvs: &dyn crate::app::ports::StoragePort,
+    ) -> heapless::Vec<AuditEntry, AUDIT_RING_SLOTS> {
+        let mut entries = heapless::Vec::new();
+        for i in 0..AUDIT_RING_SLOTS {
+            let slot_key = Self::slot_key((self.write_index + i) % AUDIT_RING_SLOTS);
+            let mut buf = [0u8; AUDIT_SLOT_BYTES];
+            if let Ok(len) = nvs.read(AUDIT_KEY_PREFIX, &slot_key, &mut buf)
+                && let Ok(entry) = postcard::from_bytes::<AuditEntry>(&buf[..len])
+            {
+                let _ = entries.push(entry);
+            }
+        }
+        entries
+    }
+
+    fn slot_key(index: usize) -> heapless::String<16> {
+        let mut s = heapless::String::new();
+        let _ = core::fmt::Write::write_fmt(&mut s, format_args!("a{}", index));
+        s
+    }
+}
+
+/// Saturating totals of outbound RPC frames and their payload bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BandwidthCounters {
+    /// Frames encoded.
+    pub frames: u32,
+    /// FlatBuffer payload bytes before compression.
+    pub raw_bytes: u64,
+    /// Payload bytes actually framed (after compression, if applied).
+    pub sent_bytes: u64,
+}
+
+impl BandwidthCounters {
+    /// Account for one frame whose `raw`-byte payload went out as `sent`.
+    pub fn record(&mut self, raw: usize, sent: usize) {
+        self.frames = self.frames.saturating_add(1);
+        self.raw_bytes = self.raw_bytes.saturating_add(raw as u64);
+        self.sent_bytes = self.sent_bytes.saturating_add(sent as u64);
+    }
+
+    /// Sent bytes per raw byte (1.0 = no saving; 1.0 before any traffic).
+    pub fn compression_ratio(&self) -> f32 {
+        if self.raw_bytes == 0 {
+            1.0
+        } else {
+            (self.sent_bytes as f64 / self.raw_bytes as f64) as f32
+        }
+    }
+}
+
+/// Window over which inbound frame drops are rated.
+pub const OVERLOAD_WINDOW_MS: u64 = 10_000;
+
+/// Drops within one window that mark the device overloaded.
+pub const OVERLOAD_DROPS_PER_WINDOW: u32 = 4;
+
+/// Rates the cumulative command-drop count per fixed window.  Trips
+/// once a window sees [`OVERLOAD_DROPS_PER_WINDOW`] drops and clears
+/// only after a window with none, so a trickle near the threshold does
+/// not flap the advisory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DropRateMonitor {
+    window_start_ms: u64,
+    drops_at_window_start: u32,
+    overloaded: bool,
+}
+
+impl DropRateMonitor {
+    /// Feed the cumulative drop count at `now_ms`; returns whether the
+    /// device is currently overloaded.
+    pub fn sample(&mut self, now_ms: u64, total_drops: u32) -> bool {
+        if now_ms.saturating_sub(self.window_start_ms) < OVERLOAD_WINDOW_MS {
+            return self.overloaded;
+        }
+        let drops = total_drops.saturating_sub(self.drops_at_window_start);
+        if drops >= OVERLOAD_DROPS_PER_WINDOW {
+            self.overloaded = true;
+        } else if drops == 0 {
+            self.overloaded = false;
+        }
+        self.window_start_ms = now_ms;
+        self.drops_at_window_start = total_drops;
+        self.overloaded
+    }
+}
+
+const FLOW_NAMESPACE: &str = "flow";
+const FLOW_TOTALS_KEY: &str = "totals";
+
+/// Accumulated volume that triggers a write to NVS.  Also the most a
+/// power cut can lose from the persisted totals.
+pub const FLOW_SAVE_EVERY_ML: f64 = 5_000.0;
+
+/// Water processed, over the device's life and since the last service.
+///
+/// Totals are kept in memory as `f64` millilitres and written to NVS as
+/// whole millilitres once [`FLOW_SAVE_EVERY_ML`] has built up since the
+/// last write (or on [`flush`](Self::flush) before a planned shutdown).
+/// Each zone of a multi-zone unit keeps its own totals.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlowTotalizer {
+    zone: ZoneId,
+    lifetime_ml: f64,
+    since_service_ml: f64,
+    unsaved_ml: f64,
+}
+
+impl FlowTotalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Totals of filter loop `zone`.
+    pub fn for_zone(zone: ZoneId) -> Self {
+        Self {
+            zone,
+            ..Self::default()
+        }
+    }
+
+    /// Load the persisted totals, or start from zero.
+    pub fn init(&mut self, nvs: &dyn crate::app::ports::StoragePort) {
+        let mut buf = [0u8; 16];
+        if let Ok(16) = nvs.read(FLOW_NAMESPACE, &self.key(), &mut buf) {
+            let (lifetime, since_service) = buf.split_at(8);
+            self.lifetime_ml = u64::from_le_bytes(lifetime.try_into().unwrap()) as f64;
+            self.since_service_ml = u64::from_le_bytes(since_service.try_into().unwrap()) as f64;
+        }
+        self.unsaved_ml = 0.0;
+    }
+
+    /// Add `dt_secs` of flow at `flow_ml_per_min`.  Non-finite and
+    /// negative readings add nothing.
+    pub fn accumulate(&mut self, flow_ml_per_min: f32, dt_secs: f32) {
+        let ml = f64::from(flow_ml_per_min) / 60.0 * f64::from(dt_secs);
+        if !ml.is_finite() || ml <= 0.0 {
+            return;
+        }
+        self.lifetime_ml += ml;
+        self.since_service_ml += ml;
+        self.unsaved_ml += ml;
+    }
+
+    /// Write the totals if [`FLOW_SAVE_EVERY_ML`] has built up.
+    pub fn save_if_due(&mut self, nvs: &mut dyn crate::app::ports::StoragePort) {
+        if self.unsaved_ml >= FLOW_SAVE_EVERY_ML {
+            self.flush(nvs);
+        }
+    }
+
+    /// Write the totals if anything is unsaved.  A failed write keeps
+    /// the volume pending for the next attempt.
+    pub fn flush(&mut self, nvs: &mut dyn crate::app::ports::StoragePort) {
+        if self.unsaved_ml == 0.0 {
+            return;
+        }
+        match nvs.write(FLOW_NAMESPACE, &self.key(), &self.to_bytes()) {
+            Ok(()) => self.unsaved_ml = 0.0,
+            Err(e) => log::warn!("Flow totals not saved: {:?}", e),
+        }
+    }
+
+    /// Zero the since-service subtotal and persist at once.
+    pub fn reset_since_service(
+        &mut self,
+        nvs: &mut dyn crate::app::ports::StoragePort,
+    ) -> Result<(), crate::app::ports::StorageError> {
+        self.since_service_ml = 0.0;
+        nvs.write(FLOW_NAMESPACE, &self.key(), &self.to_bytes())?;
+        self.unsaved_ml = 0.0;
+        Ok(())
+    }
+
+    /// Whole millilitres processed over the device's life.
+    pub fn lifetime_ml(&self) -> u64 {
+        self.lifetime_ml as u64
+    }
+
+    /// Whole millilitres processed since the last service reset.
+    pub fn since_service_ml(&self) -> u64 {
+        self.since_service_ml as u64
+    }
+
+    fn key(&self) -> heapless::String<15> {
+        zone_key(FLOW_TOTALS_KEY, self.zone)
+    }
+
+    fn to_bytes(self) -> [u8; 16] {
+        let mut out = [0u8; 16];
+        out[..8].copy_from_slice(&self.lifetime_ml().to_le_bytes());
+        out[8..].copy_from_slice(&self.since_service_ml().to_le_bytes());
+        out
+    }
+}
+
 /// Runtime diagnostics snapshot collected on-demand.
 #[derive(Debug, Clone)]
 pub struct RuntimeMetrics {
@@ -120,6 +456,9 @@ pub struct RuntimeMetrics {
     pub crash_count: u32,
     pub heap_free: u32,
     pub heap_min_free: u32,
+    /// Largest contiguous free block — the real limit on the next
+    /// allocation once the heap fragments.
+    pub heap_largest_block: u32,
     pub wifi_rssi: i8,
     pub nvs_free_entries: u32,
     pub ulp_wake_count: u32,
@@ -137,6 +476,8 @@ impl RuntimeMetrics {
         use esp_idf_svc::sys::*;
         let heap_free = unsafe { esp_get_free_heap_size() };
         let heap_min = unsafe { esp_get_minimum_free_heap_size() };
+        let heap_largest_block =
+            unsafe { heap_caps_get_largest_free_block(MALLOC_CAP_8BIT) } as u32;
 
         let wifi_rssi = Self::read_wifi_rssi();
         let nvs_free_entries = Self::read_nvs_free_entries();
@@ -148,6 +489,7 @@ impl RuntimeMetrics {
             crash_count,
             heap_free,
             heap_min_free: heap_min,
+            heap_largest_block,
             wifi_rssi,
             nvs_free_entries,
             ulp_wake_count: ulp_wakes,
@@ -190,6 +532,8 @@ impl RuntimeMetrics {
         let decay = (uptime_secs / 60) as u32 * 512; // lose ~512B/min
         let heap_free = base_free.saturating_sub(decay);
         let heap_min_free = (heap_free as f32 * 0.85) as u32;
+        // Largest block trails free space as the heap fragments.
+        let heap_largest_block = (heap_free as f32 * 0.6) as u32;
 
         Self {
             uptime_secs,
@@ -198,6 +542,7 @@ impl RuntimeMetrics {
             crash_count,
             heap_free,
             heap_min_free,
+            heap_largest_block,
             wifi_rssi: -60,
             nvs_free_entries: 120,
             ulp_wake_count: ulp_wakes,
@@ -205,6 +550,60 @@ impl RuntimeMetrics {
     }
 }
 
+// ───────────────────────────────────────────────────────────────
+// Backtrace capture
+// ───────────────────────────────────────────────────────────────
+
+/// Program counter of the caller and up to [`MAX_BACKTRACE_FRAMES`]
+/// return addresses above it, walked with the IDF Xtensa unwinder.
+#[cfg(target_os = "espidf")]
+pub fn capture_backtrace() -> (u32, Backtrace) {
+    use esp_idf_svc::sys::{
+        esp_backtrace_frame_t, esp_backtrace_get_next_frame, esp_backtrace_get_start,
+    };
+
+    // SAFETY: the unwinder only reads the current task's stack and
+    // register windows; it allocates nothing and is what the IDF panic
+    // handler itself uses.
+    let mut frame: esp_backtrace_frame_t = unsafe { core::mem::zeroed() };
+    unsafe { esp_backtrace_get_start(&mut frame.pc, &mut frame.sp, &mut frame.next_pc) };
+    let pc = stack_pc(frame.pc);
+
+    let mut frames = Backtrace::new();
+    while frames.len() < MAX_BACKTRACE_FRAMES && frame.next_pc != 0 {
+        if !unsafe { esp_backtrace_get_next_frame(&mut frame) } {
+            break;
+        }
+        let _ = frames.push(stack_pc(frame.pc));
+    }
+    (pc, frames)
+}
+
+/// Windowed-ABI return address → call-site address
+/// (`esp_cpu_process_stack_pc`, which is inline and not in the bindings).
+#[cfg(target_os = "espidf")]
+fn stack_pc(pc: u32) -> u32 {
+    let pc = if pc & 0x8000_0000 != 0 {
+        (pc & 0x3fff_ffff) | 0x4000_0000
+    } else {
+        pc
+    };
+    pc.wrapping_sub(3)
+}
+
+/// Base of the synthetic frames reported on the host (start of the
+/// ESP32-S3 IROM mapping, so they look like real flash addresses).
+#[cfg(not(target_os = "espidf"))]
+pub const SIM_FRAME_BASE: u32 = 0x4200_0000;
+
+/// Host stand-in: there is no Xtensa stack to walk, so report a fixed,
+/// recognisable frame list that exercises the persistence and RPC paths.
+#[cfg(not(target_os = "espidf"))]
+pub fn capture_backtrace() -> (u32, Backtrace) {
+    let frames = (1..=4u32).map(|i| SIM_FRAME_BASE + i * 0x10).collect();
+    (SIM_FRAME_BASE, frames)
+}
+
 // ───────────────────────────────────────────────────────────────
 // Custom panic handler — writes a CrashEntry to NVS before reset
 // ───────────────────────────────────────────────────────────────
@@ -212,8 +611,10 @@ impl RuntimeMetrics {
 /// Install a panic hook that persists crash info to NVS.
 ///
 /// Must be called once during init, after NVS is ready.
-/// On panic, captures the reason string and writes a CrashEntry
-/// to the NVS ring buffer before the default panic handler aborts.
+/// On panic, captures the reason string, the panic site from
+/// `info.location()`, program counter and a bounded backtrace, and writes
+/// a CrashEntry to the NVS ring buffer before the default panic handler
+/// aborts.
 pub fn install_panic_handler() {
     std::panic::set_hook(Box::new(|info| {
         let reason = if let Some(msg) = info.payload().downcast_ref::<&str>() {
@@ -224,14 +625,19 @@ pub fn install_panic_handler() {
             "unknown panic"
         };
 
-        log::error!("PANIC: {}", reason);
+        let (file, line) = info.location().map_or(("", 0), |l| (l.file(), l.line()));
+        log::error!("PANIC at {}:{}: {}", file, line, reason);
+        let (pc, frames) = capture_backtrace();
 
         #[cfg(target_os = "espidf")]
         {
             // SAFETY: esp_timer_get_time is safe to call from panic context
             // (it is a simple RTC counter read with no dynamic allocation).
             let uptime = (unsafe { esp_idf_svc::sys::esp_timer_get_time() }) as u64 / 1_000_000;
-            let entry = CrashEntry::new(uptime, reason, 0);
+            let mut entry = CrashEntry::new(uptime, reason, pc).with_backtrace(&frames);
+            if !file.is_empty() {
+                entry = entry.with_location(file, line);
+            }
 
             // Attempt to write the crash entry; if NVS is unavailable at this
             // point (e.g. the panic occurred before init), we log and skip.
@@ -253,7 +659,14 @@ pub fn install_panic_handler() {
 
         #[cfg(not(target_os = "espidf"))]
         {
-            log::error!("Crash entry (simulation): {}", reason);
+            log::error!(
+                "Crash entry (simulation): {} at {}:{} pc={:#010x} frames={:x?}",
+                reason,
+                file,
+                line,
+                pc,
+                frames.as_slice()
+            );
         }
     }));
 }
@@ -361,6 +774,74 @@ mod tests {
         assert!(entry.reason.len() <= 63);
     }
 
+    #[test]
+    fn with_backtrace_keeps_at_most_max_frames() {
+        let frames: Vec<u32> = (0..20).collect();
+        let entry = CrashEntry::new(0, "x", 0).with_backtrace(&frames);
+        assert_eq!(entry.backtrace.len(), MAX_BACKTRACE_FRAMES);
+        assert_eq!(entry.backtrace[0], 0);
+    }
+
+    #[test]
+    fn with_location_keeps_the_tail_of_long_paths() {
+        let entry = CrashEntry::new(0, "x", 0).with_location("src/app/service.rs", 42);
+        assert_eq!(entry.location, "src/app/service.rs:42");
+
+        let long = format!("{}/src/rpc/engine.rs", "registry/".repeat(10));
+        let entry = CrashEntry::new(0, "x", 0).with_location(&long, 1234);
+        assert_eq!(entry.location.len(), CRASH_LOCATION_LEN);
+        assert!(entry.location.ends_with("/src/rpc/engine.rs:1234"));
+    }
+
+    #[test]
+    fn entry_without_location_still_reads_back() {
+        #[derive(Serialize)]
+        struct V1 {
+            uptime_secs: u64,
+            reason: heapless::String<64>,
+            pc: u32,
+            backtrace: heapless::Vec<u32, 8>,
+        }
+        let mut nvs = MockStorage::new();
+        let old = V1 {
+            uptime_secs: 5,
+            reason: "old".try_into().unwrap(),
+            pc: 0x4200_0100,
+            backtrace: [1, 2, 3].into_iter().collect(),
+        };
+        let bytes = postcard::to_allocvec(&old).unwrap();
+        nvs.write(CRASH_KEY_PREFIX, "e0", &bytes).unwrap();
+
+        let entries = CrashLog::new().read_all(&nvs);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].reason, "old");
+        assert_eq!(entries[0].pc, 0x4200_0100);
+        assert_eq!(entries[0].backtrace.as_slice(), &[1, 2, 3]);
+        assert!(entries[0].location.is_empty());
+    }
+
+    #[test]
+    fn captured_backtrace_survives_ring_roundtrip() {
+        let mut nvs = MockStorage::new();
+        let mut log = CrashLog::new();
+        let (pc, frames) = capture_backtrace();
+        assert!(!frames.is_empty());
+
+        let entry = CrashEntry::new(7, "boom", pc).with_backtrace(&frames);
+        log.write_entry(&mut nvs, &entry);
+        let entries = log.read_all(&nvs);
+        assert_eq!(entries[0], entry);
+    }
+
+    #[test]
+    fn worst_case_entry_fits_one_slot() {
+        let entry = CrashEntry::new(u64::MAX, &"r".repeat(63), u32::MAX)
+            .with_backtrace(&[u32::MAX; MAX_BACKTRACE_FRAMES])
+            .with_location(&"f".repeat(80), u32::MAX);
+        let bytes = postcard::to_allocvec(&entry).unwrap();
+        assert!(bytes.len() <= CRASH_SLOT_BYTES, "{} bytes", bytes.len());
+    }
+
     #[test]
     fn count_matches_entries() {
         let mut nvs = MockStorage::new();
@@ -372,4 +853,203 @@ mod tests {
         log.write_entry(&mut nvs, &CrashEntry::new(2, "b", 0));
         assert_eq!(log.count(&nvs), 2);
     }
+
+    #[test]
+    fn drop_rate_trips_on_burst_and_clears_after_quiet_window() {
+        let mut m = DropRateMonitor::default();
+        let w = OVERLOAD_WINDOW_MS;
+        let n = OVERLOAD_DROPS_PER_WINDOW;
+
+        assert!(!m.sample(w, n - 1));
+        assert!(!m.sample(w + 1, 3 * n), "mid-window samples wait for the window");
+        assert!(m.sample(2 * w, 3 * n));
+
+        // A trickle below the threshold holds the advisory...
+        assert!(m.sample(3 * w, 3 * n + 1));
+        // ...until a window passes with no drops at all.
+        assert!(!m.sample(4 * w, 3 * n + 1));
+    }
+
+    #[test]
+    fn flow_integrates_over_time_and_ignores_bad_readings() {
+        let mut totals = FlowTotalizer::new();
+        for _ in 0..60 {
+            totals.accumulate(1200.0, 1.0);
+        }
+        assert_eq!(totals.lifetime_ml(), 1200);
+        totals.accumulate(600.0, 0.5);
+        assert_eq!(totals.lifetime_ml(), 1205);
+
+        for bad in [f32::NAN, f32::INFINITY, -300.0, 0.0] {
+            totals.accumulate(bad, 1.0);
+        }
+        assert_eq!(totals.lifetime_ml(), 1205);
+        assert_eq!(totals.since_service_ml(), 1205);
+    }
+
+    #[test]
+    fn flow_totals_persist_once_a_batch_builds_up() {
+        let mut nvs = MockStorage::new();
+        let mut totals = FlowTotalizer::new();
+        // 1.2 l/min in 1 s ticks: a batch is 250 ticks.
+        for _ in 0..249 {
+            totals.accumulate(1200.0, 1.0);
+            totals.save_if_due(&mut nvs);
+        }
+        assert!(!nvs.exists(FLOW_NAMESPACE, FLOW_TOTALS_KEY));
+        totals.accumulate(1200.0, 1.0);
+        totals.save_if_due(&mut nvs);
+
+        let mut restored = FlowTotalizer::new();
+        restored.init(&nvs);
+        assert_eq!(restored.lifetime_ml(), FLOW_SAVE_EVERY_ML as u64);
+    }
+
+    #[test]
+    fn power_loss_drops_at_most_one_batch() {
+        let mut nvs = MockStorage::new();
+        let mut totals = FlowTotalizer::new();
+        for _ in 0..1000 {
+            totals.accumulate(1000.0, 1.0);
+            totals.save_if_due(&mut nvs);
+        }
+        let mut after_reboot = FlowTotalizer::new();
+        after_reboot.init(&nvs);
+        let lost = totals.lifetime_ml() - after_reboot.lifetime_ml();
+        assert!((lost as f64) < FLOW_SAVE_EVERY_ML, "lost {lost} ml");
+
+        totals.flush(&mut nvs);
+        after_reboot.init(&nvs);
+        assert_eq!(after_reboot.lifetime_ml(), totals.lifetime_ml());
+    }
+
+    #[test]
+    fn service_reset_zeroes_subtotal_only_and_persists() {
+        let mut nvs = MockStorage::new();
+        let mut totals = FlowTotalizer::new();
+        totals.accumulate(1200.0, 75.0);
+        totals.reset_since_service(&mut nvs).unwrap();
+        assert_eq!(totals.since_service_ml(), 0);
+        assert_eq!(totals.lifetime_ml(), 1500);
+
+        totals.accumulate(1200.0, 5.0);
+        assert_eq!(totals.since_service_ml(), 100);
+
+        let mut restored = FlowTotalizer::new();
+        restored.init(&nvs);
+        assert_eq!(restored.lifetime_ml(), 1500);
+        assert_eq!(restored.since_service_ml(), 0);
+    }
+
+    #[test]
+    fn each_zone_persists_its_own_totals() {
+        let mut nvs = MockStorage::new();
+        let mut zone_a = FlowTotalizer::new();
+        let mut zone_b = FlowTotalizer::for_zone(1);
+        zone_a.accumulate(1200.0, 75.0);
+        zone_b.accumulate(1200.0, 5.0);
+        zone_a.flush(&mut nvs);
+        zone_b.flush(&mut nvs);
+        assert!(nvs.exists(FLOW_NAMESPACE, FLOW_TOTALS_KEY));
+
+        let mut restored = FlowTotalizer::for_zone(1);
+        restored.init(&nvs);
+        assert_eq!(restored.lifetime_ml(), 100);
+        restored = FlowTotalizer::new();
+        restored.init(&nvs);
+        assert_eq!(restored.lifetime_ml(), 1500);
+    }
+
+    fn audit(uptime_secs: u32, payload_type: u8) -> AuditEntry {
+        AuditEntry {
+            uptime_secs,
+            client_id: 1,
+            payload_type,
+            success: true,
+        }
+    }
+
+    #[test]
+    fn audit_log_reads_back_in_order() {
+        let mut nvs = MockStorage::new();
+        let mut log = AuditLog::new();
+        for i in 0..3 {
+            log.record(&mut nvs, &audit(i, i as u8));
+        }
+        let entries = log.read_all(&nvs);
+        assert_eq!(entries.as_slice(), &[audit(0, 0), audit(1, 1), audit(2, 2)]);
+    }
+
+    #[test]
+    fn audit_log_wraps_keeping_newest() {
+        let mut nvs = MockStorage::new();
+        let mut log = AuditLog::new();
+        let total = AUDIT_RING_SLOTS as u32 + 5;
+        for i in 0..total {
+            log.record(&mut nvs, &audit(i, 0));
+        }
+        let entries = log.read_all(&nvs);
+        assert_eq!(entries.len(), AUDIT_RING_SLOTS);
+        assert_eq!(entries[0].uptime_secs, 5);
+        assert_eq!(entries[AUDIT_RING_SLOTS - 1].uptime_secs, total - 1);
+    }
+
+    #[test]
+    fn audit_log_resumes_after_reboot() {
+        let mut nvs = MockStorage::new();
+        let mut log = AuditLog::new();
+        log.record(&mut nvs, &audit(1, 0));
+        log.record(&mut nvs, &audit(2, 0));
+
+        let mut rebooted = AuditLog::new();
+        rebooted.init(&nvs);
+        rebooted.record(&mut nvs, &audit(3, 0));
+        let entries = rebooted.read_all(&nvs);
+        assert_eq!(entries.as_slice(), &[audit(1, 0), audit(2, 0), audit(3, 0)]);
+    }
+
+    #[test]
+    fn worst_case_audit_entry_fits_one_slot() {
+        let entry = AuditEntry {
+            uptime_secs: u32::MAX,
+            client_id: u8::MAX,
+            payload_type: u8::MAX,
+            success: true,
+        };
+        let bytes = postcard::to_allocvec(&entry).unwrap();
+        assert!(bytes.len() <= AUDIT_SLOT_BYTES, "{} bytes", bytes.len());
+    }
+
+    #[test]
+    fn sim_metrics_largest_block_below_free_heap() {
+        let m = RuntimeMetrics::collect(3600, 0, 0, 0, 0);
+        assert!(m.heap_largest_block > 0);
+        assert!(m.heap_largest_block <= m.heap_free);
+    }
+
+    #[test]
+    fn bandwidth_counters_accumulate_and_report_ratio() {
+        let mut bw = BandwidthCounters::default();
+        assert!((bw.compression_ratio() - 1.0).abs() < f32::EPSILON);
+
+        bw.record(200, 50);
+        bw.record(100, 100);
+        assert_eq!(bw.frames, 2);
+        assert_eq!(bw.raw_bytes, 300);
+        assert_eq!(bw.sent_bytes, 150);
+        assert!((bw.compression_ratio() - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bandwidth_counters_saturate() {
+        let mut bw = BandwidthCounters {
+            frames: u32::MAX,
+            raw_bytes: u64::MAX - 1,
+            sent_bytes: u64::MAX,
+        };
+        bw.record(10, 10);
+        assert_eq!(bw.frames, u32::MAX);
+        assert_eq!(bw.raw_bytes, u64::MAX);
+        assert_eq!(bw.sent_bytes, u64::MAX);
+    }
 }
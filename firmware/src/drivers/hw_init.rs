@@ -4,6 +4,8 @@
 //! using raw ESP-IDF sys calls. Called once from `main()` before the
 //! event loop starts.
 
+use core::sync::atomic::{AtomicU8, Ordering};
+
 #[cfg(target_os = "espidf")]
 use esp_idf_svc::sys::*;
 #[cfg(target_os = "espidf")]
@@ -16,7 +18,7 @@ use crate::pins;
 pub enum HwInitError {
     AdcInitFailed(i32),
     GpioConfigFailed(i32),
-    LedcInitFailed,
+    LedcInitFailed(i32),
     IsrInstallFailed(i32),
 }
 
@@ -25,7 +27,7 @@ impl core::fmt::Display for HwInitError {
         match self {
             Self::AdcInitFailed(rc) => write!(f, "ADC1 init failed (rc={})", rc),
             Self::GpioConfigFailed(rc) => write!(f, "GPIO config failed (rc={})", rc),
-            Self::LedcInitFailed => write!(f, "LEDC timer/channel config failed"),
+            Self::LedcInitFailed(rc) => write!(f, "LEDC timer/channel config failed (rc={})", rc),
             Self::IsrInstallFailed(rc) => write!(f, "GPIO ISR service install failed (rc={})", rc),
         }
     }
@@ -41,7 +43,7 @@ pub fn init_peripherals() -> Result<(), HwInitError> {
         init_adc()?;
         init_gpio_inputs()?;
         init_gpio_outputs()?;
-        init_ledc();
+        init_ledc()?;
     }
     info!("hw_init: all peripherals configured");
     Ok(())
@@ -98,26 +100,38 @@ unsafe fn init_adc() -> Result<(), HwInitError> {
         return Err(HwInitError::AdcInitFailed(ret));
     }
 
+    #[cfg(feature = "dual-zone")]
+    {
+        let ret = unsafe {
+            adc_oneshot_config_channel(adc1_handle(), adc_channel_t_ADC_CHANNEL_3, &chan_cfg)
+        };
+        if ret != ESP_OK as i32 {
+            return Err(HwInitError::AdcInitFailed(ret));
+        }
+        info!("hw_init: ADC1 CH3 configured (zone 1 NH3)");
+    }
+
     info!("hw_init: ADC1 configured (CH4=NH3, CH8=temp)");
     Ok(())
 }
 
+/// One-shot ADC1 conversion; `None` if the driver reports an error.
 #[cfg(target_os = "espidf")]
-pub fn adc1_read(channel: u32) -> u16 {
+pub fn adc1_try_read(channel: u32) -> Option<u16> {
     let mut raw: i32 = 0;
     // SAFETY: ADC1_HANDLE is written once during init_adc() before this
     // function is called; single-threaded main-loop access guaranteed.
     // SAFETY: adc1_handle() contract — single-threaded main-loop access only.
     let ret = unsafe { adc_oneshot_read(adc1_handle(), channel, &mut raw) };
     if ret != ESP_OK as i32 {
-        return 0;
+        return None;
     }
-    raw.max(0) as u16
+    Some(raw.max(0) as u16)
 }
 
 #[cfg(not(target_os = "espidf"))]
-pub fn adc1_read(_channel: u32) -> u16 {
-    0
+pub fn adc1_try_read(_channel: u32) -> Option<u16> {
+    Some(0)
 }
 
 // ── GPIO Inputs ───────────────────────────────────────────────
@@ -129,6 +143,12 @@ unsafe fn init_gpio_inputs() -> Result<(), HwInitError> {
         pins::WATER_LEVEL_B_GPIO,
         pins::FLOW_PULSE_GPIO,
         pins::UVC_INTERLOCK_GPIO,
+        #[cfg(feature = "dual-zone")]
+        pins::zone_b::WATER_LEVEL_A_GPIO,
+        #[cfg(feature = "dual-zone")]
+        pins::zone_b::WATER_LEVEL_B_GPIO,
+        #[cfg(feature = "dual-zone")]
+        pins::zone_b::FLOW_PULSE_GPIO,
     ];
 
     for &pin in &input_pins {
@@ -177,9 +197,27 @@ pub fn gpio_read(_pin: i32) -> bool {
 
 #[cfg(target_os = "espidf")]
 unsafe fn init_gpio_outputs() -> Result<(), HwInitError> {
-    let output_pins = [pins::PUMP_DIR_GPIO, pins::UVC_ENABLE_GPIO];
+    // Each output starts released, whatever its polarity.
+    let output_pins = [
+        (pins::PUMP_DIR_GPIO, pins::PUMP_DIR_ACTIVE),
+        (pins::TANK_SELECT_GPIO, pins::TANK_SELECT_ACTIVE),
+        (pins::UVC_ENABLE_GPIO, pins::UVC_ENABLE_ACTIVE),
+        #[cfg(feature = "dual-zone")]
+        (pins::zone_b::PUMP_DIR_GPIO, pins::PUMP_DIR_ACTIVE),
+        #[cfg(feature = "dual-zone")]
+        (pins::zone_b::TANK_SELECT_GPIO, pins::TANK_SELECT_ACTIVE),
+        #[cfg(feature = "dual-zone")]
+        (pins::zone_b::UVC_ENABLE_GPIO, pins::UVC_ENABLE_ACTIVE),
+    ];
 
-    for &pin in &output_pins {
+    for &(pin, active) in &output_pins {
+        // Latch the released level before the driver is enabled, so an
+        // active-low output is never driven low in between.
+        // SAFETY: gpio_set_level only writes the pin's output register;
+        // init runs once, before any other task drives these pins.
+        unsafe {
+            gpio_set_level(pin, if active.level(false) { 1 } else { 0 });
+        }
         let cfg = gpio_config_t {
             pin_bit_mask: 1u64 << pin,
             mode: gpio_mode_t_GPIO_MODE_OUTPUT,
@@ -191,7 +229,6 @@ unsafe fn init_gpio_outputs() -> Result<(), HwInitError> {
         if ret != ESP_OK as i32 {
             return Err(HwInitError::GpioConfigFailed(ret));
         }
-        unsafe { gpio_set_level(pin, 0) };
     }
 
     info!("hw_init: GPIO outputs configured");
@@ -208,12 +245,46 @@ pub fn gpio_write(pin: i32, high: bool) {
 }
 
 #[cfg(not(target_os = "espidf"))]
-pub fn gpio_write(_pin: i32, _high: bool) {}
+std::thread_local! {
+    /// Simulation: last level written to each output pin, per thread so
+    /// parallel tests do not see each other's writes.
+    static SIM_GPIO_LEVELS: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+}
+
+#[cfg(not(target_os = "espidf"))]
+pub fn gpio_write(pin: i32, high: bool) {
+    SIM_GPIO_LEVELS.with(|levels| {
+        let bit = 1u64 << pin;
+        let cur = levels.get();
+        levels.set(if high { cur | bit } else { cur & !bit });
+    });
+}
+
+/// Simulation: level this thread last wrote to `pin` (LOW if never).
+#[cfg(not(target_os = "espidf"))]
+pub fn gpio_output_level(pin: i32) -> bool {
+    SIM_GPIO_LEVELS.with(|levels| levels.get() & (1u64 << pin) != 0)
+}
 
 // ── LEDC PWM ─────────────────────────────────────────────────
 
+/// Bitmask of RGB status LED channels that failed to configure
+/// (bit 0 = R, bit 1 = G, bit 2 = B; see `led_patterns::LED_FAILED_*`).
+/// Written once by `init_ledc()`, read by the main loop afterwards.
+static LED_FAILED_CHANNELS: AtomicU8 = AtomicU8::new(0);
+
+/// RGB channels that failed to configure at boot (0 = all healthy).
+pub fn led_failed_channels() -> u8 {
+    LED_FAILED_CHANNELS.load(Ordering::Relaxed)
+}
+
+/// Configure the LEDC timers and channels.
+///
+/// A timer, pump or UVC failure is fatal — those channels drive
+/// actuators.  An RGB channel failure only degrades status indication,
+/// so it is recorded in [`led_failed_channels`] and init continues.
 #[cfg(target_os = "espidf")]
-unsafe fn init_ledc() {
+unsafe fn init_ledc() -> Result<(), HwInitError> {
     // Timer 0: Pump motor (25 kHz, 8-bit)
     // SAFETY: Called from single main-task context via init_peripherals().
     let timer0 = ledc_timer_config_t {
@@ -224,8 +295,9 @@ unsafe fn init_ledc() {
         clk_cfg: soc_periph_ledc_clk_src_legacy_t_LEDC_AUTO_CLK,
         ..Default::default()
     };
-    unsafe {
-        ledc_timer_config(&timer0);
+    let ret = unsafe { ledc_timer_config(&timer0) };
+    if ret != ESP_OK as i32 {
+        return Err(HwInitError::LedcInitFailed(ret));
     }
 
     // Timer 1: UVC + LED (1 kHz, 8-bit)
@@ -237,12 +309,13 @@ unsafe fn init_ledc() {
         clk_cfg: soc_periph_ledc_clk_src_legacy_t_LEDC_AUTO_CLK,
         ..Default::default()
     };
-    unsafe {
-        ledc_timer_config(&timer1);
+    let ret = unsafe { ledc_timer_config(&timer1) };
+    if ret != ESP_OK as i32 {
+        return Err(HwInitError::LedcInitFailed(ret));
     }
 
     // Channel 0: Pump PWM
-    unsafe {
+    let ret = unsafe {
         ledc_channel_config(&ledc_channel_config_t {
             speed_mode: ledc_mode_t_LEDC_LOW_SPEED_MODE,
             channel: ledc_channel_t_LEDC_CHANNEL_0,
@@ -251,26 +324,57 @@ unsafe fn init_ledc() {
             duty: 0,
             hpoint: 0,
             ..Default::default()
-        });
+        })
+    };
+    if ret != ESP_OK as i32 {
+        return Err(HwInitError::LedcInitFailed(ret));
     }
 
-    // Channel 1: UVC PWM
-    unsafe {
-        ledc_channel_config(&ledc_channel_config_t {
-            speed_mode: ledc_mode_t_LEDC_LOW_SPEED_MODE,
-            channel: ledc_channel_t_LEDC_CHANNEL_1,
-            timer_sel: ledc_timer_t_LEDC_TIMER_1,
-            gpio_num: pins::UVC_PWM_GPIO,
-            duty: 0,
-            hpoint: 0,
-            ..Default::default()
-        });
+    // Channel 1: UVC PWM.  The dual-zone carrier ties the dimming input
+    // HIGH and uses its GPIO for zone 1's NH3 sensor.
+    #[cfg(not(feature = "dual-zone"))]
+    {
+        let ret = unsafe {
+            ledc_channel_config(&ledc_channel_config_t {
+                speed_mode: ledc_mode_t_LEDC_LOW_SPEED_MODE,
+                channel: ledc_channel_t_LEDC_CHANNEL_1,
+                timer_sel: ledc_timer_t_LEDC_TIMER_1,
+                gpio_num: pins::UVC_PWM_GPIO,
+                duty: 0,
+                hpoint: 0,
+                ..Default::default()
+            })
+        };
+        if ret != ESP_OK as i32 {
+            return Err(HwInitError::LedcInitFailed(ret));
+        }
+    }
+
+    // Channel 5: zone 1 pump PWM
+    #[cfg(feature = "dual-zone")]
+    {
+        let ret = unsafe {
+            ledc_channel_config(&ledc_channel_config_t {
+                speed_mode: ledc_mode_t_LEDC_LOW_SPEED_MODE,
+                channel: ledc_channel_t_LEDC_CHANNEL_5,
+                timer_sel: ledc_timer_t_LEDC_TIMER_0,
+                gpio_num: pins::zone_b::PUMP_PWM_GPIO,
+                duty: 0,
+                hpoint: 0,
+                ..Default::default()
+            })
+        };
+        if ret != ESP_OK as i32 {
+            return Err(HwInitError::LedcInitFailed(ret));
+        }
+        info!("hw_init: LEDC CH5 configured (zone 1 pump)");
     }
 
-    // Channels 2-4: RGB LED
+    // Channels 2-4: RGB LED (non-fatal)
     let led_gpios = [pins::LED_R_GPIO, pins::LED_G_GPIO, pins::LED_B_GPIO];
+    let mut failed = 0u8;
     for (i, &gpio) in led_gpios.iter().enumerate() {
-        unsafe {
+        let ret = unsafe {
             ledc_channel_config(&ledc_channel_config_t {
                 speed_mode: ledc_mode_t_LEDC_LOW_SPEED_MODE,
                 channel: (ledc_channel_t_LEDC_CHANNEL_2 + i as u32),
@@ -279,11 +383,17 @@ unsafe fn init_ledc() {
                 duty: 0,
                 hpoint: 0,
                 ..Default::default()
-            });
+            })
+        };
+        if ret != ESP_OK as i32 {
+            log::warn!("hw_init: LED channel CH{} config failed (rc={})", 2 + i, ret);
+            failed |= 1 << i;
         }
     }
+    LED_FAILED_CHANNELS.store(failed, Ordering::Relaxed);
 
     info!("hw_init: LEDC configured (pump=CH0, uvc=CH1, led=CH2-4)");
+    Ok(())
 }
 
 pub const LEDC_CH_PUMP: u32 = 0;
@@ -291,6 +401,8 @@ pub const LEDC_CH_UVC: u32 = 1;
 pub const LEDC_CH_LED_R: u32 = 2;
 pub const LEDC_CH_LED_G: u32 = 3;
 pub const LEDC_CH_LED_B: u32 = 4;
+/// Pump of the second filter loop (`dual-zone` feature).
+pub const LEDC_CH_PUMP_B: u32 = 5;
 
 #[cfg(target_os = "espidf")]
 pub fn ledc_set(channel: u32, duty: u8) {
@@ -307,6 +419,8 @@ pub fn ledc_set(_channel: u32, _duty: u8) {}
 
 pub const ADC1_CH_NH3: u32 = 4;
 pub const ADC1_CH_TEMP: u32 = 8;
+/// NH3 sensor of the second filter loop (`dual-zone` feature).
+pub const ADC1_CH_NH3_B: u32 = 3;
 
 // ── GPIO ISR Service ──────────────────────────────────────────
 
@@ -314,12 +428,25 @@ pub const ADC1_CH_TEMP: u32 = 8;
 use crate::drivers::button::button_isr_handler;
 #[cfg(target_os = "espidf")]
 use crate::events::{Event, push_event};
+#[cfg(all(target_os = "espidf", feature = "dual-zone"))]
+use crate::sensors::flow::flow_b_isr_handler;
 #[cfg(target_os = "espidf")]
 use crate::sensors::flow::flow_isr_handler;
 
 #[cfg(target_os = "espidf")]
 unsafe extern "C" fn flow_gpio_isr(_arg: *mut core::ffi::c_void) {
-    if flow_isr_handler() {
+    // SAFETY: esp_timer_get_time is a RTC counter read; safe in ISR context.
+    let now_us = unsafe { esp_idf_svc::sys::esp_timer_get_time() } as u32;
+    if flow_isr_handler(now_us) {
+        push_event(Event::SensorReadTick);
+    }
+}
+
+#[cfg(all(target_os = "espidf", feature = "dual-zone"))]
+unsafe extern "C" fn flow_b_gpio_isr(_arg: *mut core::ffi::c_void) {
+    // SAFETY: esp_timer_get_time is a RTC counter read; safe in ISR context.
+    let now_us = unsafe { esp_idf_svc::sys::esp_timer_get_time() } as u32;
+    if flow_b_isr_handler(now_us) {
         push_event(Event::SensorReadTick);
     }
 }
@@ -333,10 +460,11 @@ unsafe extern "C" fn button_gpio_isr(_arg: *mut core::ffi::c_void) {
 
 #[cfg(target_os = "espidf")]
 unsafe extern "C" fn interlock_gpio_isr(_arg: *mut core::ffi::c_void) {
-    // LOW = closed (magnet present = safe), HIGH = open.
+    // Closed = magnet present = safe; which level that is depends on
+    // the configured polarity (LOW by default).
     // SAFETY: gpio_get_level is a register read; safe in ISR context.
-    let closed = unsafe { gpio_get_level(pins::UVC_INTERLOCK_GPIO) } == 0;
-    crate::sensors::set_interlock_from_isr(closed);
+    let level_high = unsafe { gpio_get_level(pins::UVC_INTERLOCK_GPIO) } != 0;
+    crate::sensors::set_interlock_from_isr(crate::sensors::interlock_closed_at(level_high));
     push_event(Event::InterlockChanged);
 }
 
@@ -352,8 +480,10 @@ unsafe extern "C" fn water_level_b_isr(_arg: *mut core::ffi::c_void) {
 
 /// Install per-pin GPIO ISR service and register interrupt handlers.
 /// Call after init_peripherals() and before the event loop.
+/// `interlock_active_low` is the configured interlock polarity.
 #[cfg(target_os = "espidf")]
-pub fn init_isr_service() -> Result<(), HwInitError> {
+pub fn init_isr_service(interlock_active_low: bool) -> Result<(), HwInitError> {
+    crate::sensors::set_interlock_active_low(interlock_active_low);
     // SAFETY: gpio_install_isr_service is idempotent; ESP_ERR_INVALID_STATE
     // means it was already installed (acceptable). ISR handlers registered
     // below are static functions that only push to the lock-free event queue.
@@ -363,7 +493,21 @@ pub fn init_isr_service() -> Result<(), HwInitError> {
             return Err(HwInitError::IsrInstallFailed(ret));
         }
 
-        // Flow sensor: rising edge
+        // Flow sensor: rising edge.  The S3 pin glitch filter swallows
+        // sub-2-APB-cycle spikes in hardware; its width is fixed, so the
+        // configurable `flow_glitch_filter_us` is enforced in the ISR.
+        {
+            let filter_cfg = gpio_pin_glitch_filter_config_t {
+                clk_src: soc_periph_glitch_filter_clk_src_t_GLITCH_FILTER_CLK_SRC_DEFAULT,
+                gpio_num: pins::FLOW_PULSE_GPIO,
+            };
+            let mut filter: gpio_glitch_filter_handle_t = core::ptr::null_mut();
+            if gpio_new_pin_glitch_filter(&filter_cfg, &mut filter) == ESP_OK {
+                gpio_glitch_filter_enable(filter);
+            } else {
+                log::warn!("hw_init: flow pin glitch filter unavailable");
+            }
+        }
         gpio_set_intr_type(pins::FLOW_PULSE_GPIO, gpio_int_type_t_GPIO_INTR_POSEDGE);
         gpio_isr_handler_add(
             pins::FLOW_PULSE_GPIO,
@@ -384,8 +528,8 @@ pub fn init_isr_service() -> Result<(), HwInitError> {
         // Seed the interlock atomic with the current GPIO level so the
         // safety supervisor has a valid reading before the first edge fires.
         {
-            let closed = gpio_get_level(pins::UVC_INTERLOCK_GPIO) == 0;
-            crate::sensors::set_interlock_from_isr(closed);
+            let level_high = gpio_get_level(pins::UVC_INTERLOCK_GPIO) != 0;
+            crate::sensors::set_interlock_from_isr(crate::sensors::interlock_closed_at(level_high));
         }
 
         // Water level A: falling edge (tank going empty)
@@ -406,6 +550,31 @@ pub fn init_isr_service() -> Result<(), HwInitError> {
         );
         gpio_intr_enable(pins::WATER_LEVEL_B_GPIO);
 
+        // Zone 1: its own flow sensor and tank levels
+        #[cfg(feature = "dual-zone")]
+        {
+            gpio_set_intr_type(
+                pins::zone_b::FLOW_PULSE_GPIO,
+                gpio_int_type_t_GPIO_INTR_POSEDGE,
+            );
+            gpio_isr_handler_add(
+                pins::zone_b::FLOW_PULSE_GPIO,
+                Some(flow_b_gpio_isr),
+                core::ptr::null_mut(),
+            );
+            gpio_intr_enable(pins::zone_b::FLOW_PULSE_GPIO);
+
+            // Either float handler will do: both only queue WaterLevelChanged.
+            for pin in [
+                pins::zone_b::WATER_LEVEL_A_GPIO,
+                pins::zone_b::WATER_LEVEL_B_GPIO,
+            ] {
+                gpio_set_intr_type(pin, gpio_int_type_t_GPIO_INTR_NEGEDGE);
+                gpio_isr_handler_add(pin, Some(water_level_a_isr), core::ptr::null_mut());
+                gpio_intr_enable(pin);
+            }
+        }
+
         // Button: falling edge (active-low with pull-up already configured)
         gpio_set_intr_type(pins::BUTTON_GPIO, gpio_int_type_t_GPIO_INTR_NEGEDGE);
         gpio_isr_handler_add(
@@ -421,7 +590,10 @@ pub fn init_isr_service() -> Result<(), HwInitError> {
 }
 
 #[cfg(not(target_os = "espidf"))]
-pub fn init_isr_service() -> Result<(), HwInitError> {
+pub fn init_isr_service(interlock_active_low: bool) -> Result<(), HwInitError> {
     log::info!("hw_init(sim): ISR service skipped");
+    crate::sensors::set_interlock_active_low(interlock_active_low);
+    let level_high = gpio_read(crate::pins::UVC_INTERLOCK_GPIO);
+    crate::sensors::set_interlock_from_isr(crate::sensors::interlock_closed_at(level_high));
     Ok(())
 }
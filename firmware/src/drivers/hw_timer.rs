@@ -1,15 +1,23 @@
 //! Hardware timer module using ESP-IDF's esp_timer API.
 //!
 //! Creates periodic timers that push events into the lock-free SPSC queue.
-//! On simulation targets, uses thread::sleep to approximate timing.
+//! The control tick runs at the configured `control_loop_interval_ms`
+//! from its own timer, so control cadence does not depend on when the
+//! main loop happens to wake; GPIO edges still wake the loop (and may
+//! inject an extra `ControlTick`) for fast reaction.
+//!
+//! On simulation targets a host thread paces `ControlTick` against
+//! absolute deadlines ([`Cadence`]), so sleep overshoot does not
+//! accumulate into drift.
 //!
 //! Timer callbacks execute in the ESP timer task context (not ISR), so
 //! they can safely call push_event() which uses AtomicU8.
 
 #[cfg(target_os = "espidf")]
 use esp_idf_svc::sys::*;
-#[cfg(target_os = "espidf")]
 use crate::events::{push_event, Event};
+#[cfg(not(target_os = "espidf"))]
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 #[cfg(target_os = "espidf")]
 use log::info;
@@ -42,12 +50,52 @@ unsafe extern "C" fn control_tick_cb(_arg: *mut core::ffi::c_void) {
     push_event(Event::ControlTick);
 }
 
+/// Absolute-deadline schedule for a periodic tick.
+///
+/// Deadlines are `start + k·period`, never "last wake + period", so a
+/// late wake shortens the next wait instead of pushing every later tick
+/// back.  A wake more than a period late skips the missed deadlines
+/// rather than bursting to catch up.
+#[derive(Debug, Clone, Copy)]
+pub struct Cadence {
+    period_us: u64,
+    next_us: u64,
+}
+
+impl Cadence {
+    /// First deadline one `period_ms` after `start_us`.
+    pub fn new(period_ms: u32, start_us: u64) -> Self {
+        let step_us = u64::from(period_ms.max(1)) * 1000;
+        Self {
+            period_us: step_us,
+            next_us: start_us + step_us,
+        }
+    }
+
+    /// When the next tick is due (µs, same clock as `start_us`).
+    pub fn next_deadline_us(&self) -> u64 {
+        self.next_us
+    }
+
+    /// Move to the deadline after the tick just delivered at `now_us`.
+    /// Returns how many deadlines had already passed and were skipped.
+    pub fn advance(&mut self, now_us: u64) -> u32 {
+        self.next_us += self.period_us;
+        if now_us <= self.next_us {
+            return 0;
+        }
+        let skipped = (now_us - self.next_us).div_ceil(self.period_us);
+        self.next_us += skipped * self.period_us;
+        skipped as u32
+    }
+}
+
 /// Start the hardware tick timers.
 ///
 /// - 10 Hz sensor read timer (100ms period)
-/// - 1 Hz control tick timer (1000ms period)
+/// - control tick timer every `control_interval_ms`
 #[cfg(target_os = "espidf")]
-pub fn start_timers() {
+pub fn start_timers(control_interval_ms: u32) {
     // SAFETY: SENSOR_TIMER and CONTROL_TIMER are written here once at boot
     // from the single main-task context before any timer callbacks fire.
     // The callbacks themselves only call push_event(), which is ISR-safe.
@@ -74,7 +122,7 @@ pub fn start_timers() {
             return;
         }
 
-        // Control timer: 1 Hz
+        // Control timer: the configured control-loop interval
         let control_args = esp_timer_create_args_t {
             callback: Some(control_tick_cb),
             arg: core::ptr::null_mut(),
@@ -90,19 +138,124 @@ pub fn start_timers() {
             );
             return;
         }
-        let ret = esp_timer_start_periodic(CONTROL_TIMER, 1_000_000); // 1s
+        let period_us = u64::from(control_interval_ms.max(1)) * 1000;
+        let ret = esp_timer_start_periodic(CONTROL_TIMER, period_us);
         if ret != ESP_OK {
             log::error!("hw_timer: control timer start failed (rc={})", ret);
             return;
         }
 
-        info!("hw_timer: sensor@10Hz + control@1Hz started");
+        info!(
+            "hw_timer: sensor@10Hz + control@{}ms started",
+            control_interval_ms
+        );
     }
 }
 
+/// Change the control tick period, e.g. after a power-profile switch.
+/// The next tick comes one new period from now.
+#[cfg(target_os = "espidf")]
+pub fn set_control_interval(control_interval_ms: u32) {
+    // SAFETY: control_timer() contract — main task only.
+    unsafe {
+        let ct = control_timer();
+        if ct.is_null() {
+            return;
+        }
+        esp_timer_stop(ct);
+        let period_us = u64::from(control_interval_ms.max(1)) * 1000;
+        let ret = esp_timer_start_periodic(ct, period_us);
+        if ret != ESP_OK {
+            log::error!("hw_timer: control timer restart failed (rc={})", ret);
+            return;
+        }
+    }
+    info!("hw_timer: control@{}ms", control_interval_ms);
+}
+
+/// Set while the simulated control ticker should keep running.
 #[cfg(not(target_os = "espidf"))]
-pub fn start_timers() {
-    log::info!("hw_timer(sim): timers not started (events driven by sleep loop)");
+static SIM_CONTROL_RUNNING: AtomicBool = AtomicBool::new(false);
+/// Period (ms) of the simulated control ticker.
+#[cfg(not(target_os = "espidf"))]
+static SIM_CONTROL_PERIOD_MS: AtomicU32 = AtomicU32::new(1000);
+
+#[cfg(not(target_os = "espidf"))]
+pub fn start_timers(control_interval_ms: u32) {
+    SIM_CONTROL_PERIOD_MS.store(control_interval_ms, Ordering::Release);
+    if SIM_CONTROL_RUNNING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    spawn_periodic(&SIM_CONTROL_PERIOD_MS, &SIM_CONTROL_RUNNING, || {
+        push_event(Event::ControlTick);
+    });
+    log::info!(
+        "hw_timer(sim): control@{}ms started (no sensor timer)",
+        control_interval_ms
+    );
+}
+
+/// Change the control tick period, e.g. after a power-profile switch.
+/// The next tick comes one new period after the current one.
+#[cfg(not(target_os = "espidf"))]
+pub fn set_control_interval(control_interval_ms: u32) {
+    SIM_CONTROL_PERIOD_MS.store(control_interval_ms, Ordering::Release);
+    log::info!("hw_timer(sim): control@{}ms", control_interval_ms);
+}
+
+/// Call `on_tick` every `period_ms` on a host thread, paced by a
+/// [`Cadence`], until `running` is cleared.  The period is re-read
+/// after each tick.
+#[cfg(not(target_os = "espidf"))]
+fn spawn_periodic<F>(
+    period_ms: &'static AtomicU32,
+    running: &'static AtomicBool,
+    mut on_tick: F,
+) -> std::thread::JoinHandle<()>
+where
+    F: FnMut() + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let origin = std::time::Instant::now();
+        run_periodic(
+            || period_ms.load(Ordering::Acquire),
+            || origin.elapsed().as_micros() as u64,
+            |us| std::thread::sleep(std::time::Duration::from_micros(us)),
+            || running.load(Ordering::Acquire),
+            &mut on_tick,
+        );
+    })
+}
+
+/// Pacing loop behind [`spawn_periodic`], on an injected clock so it can
+/// be exercised without real sleeps.  `now_us` starts the cadence at its
+/// first reading; `sleep_us` may overshoot.  A changed `period_ms`
+/// restarts the cadence from the tick just delivered.
+#[cfg(not(target_os = "espidf"))]
+fn run_periodic(
+    period_ms: impl Fn() -> u32,
+    mut now_us: impl FnMut() -> u64,
+    mut sleep_us: impl FnMut(u64),
+    keep_running: impl Fn() -> bool,
+    mut on_tick: impl FnMut(),
+) {
+    let mut period = period_ms();
+    let mut cadence = Cadence::new(period, now_us());
+    loop {
+        let wait_us = cadence.next_deadline_us().saturating_sub(now_us());
+        sleep_us(wait_us);
+        if !keep_running() {
+            break;
+        }
+        on_tick();
+        let latest = period_ms();
+        if latest == period {
+            cadence.advance(now_us());
+        } else {
+            period = latest;
+            cadence = Cadence::new(period, now_us());
+        }
+    }
 }
 
 /// Stop all hardware tick timers.
@@ -124,4 +277,91 @@ pub fn stop_timers() {
 }
 
 #[cfg(not(target_os = "espidf"))]
-pub fn stop_timers() {}
+pub fn stop_timers() {
+    SIM_CONTROL_RUNNING.store(false, Ordering::Release);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn late_wakes_do_not_drift_the_cadence() {
+        let mut c = Cadence::new(1000, 0);
+        for k in 1..=10u64 {
+            assert_eq!(c.next_deadline_us(), k * 1_000_000);
+            // Every tick is handled 30 ms late.
+            assert_eq!(c.advance(k * 1_000_000 + 30_000), 0);
+        }
+        assert_eq!(c.next_deadline_us(), 11_000_000);
+    }
+
+    #[test]
+    fn overrun_skips_missed_deadlines() {
+        let mut c = Cadence::new(1000, 0);
+        // The 1 s tick is delivered at 3.5 s: the 2 s and 3 s deadlines
+        // are gone, the next one is still on the 1 s grid.
+        assert_eq!(c.advance(3_500_000), 2);
+        assert_eq!(c.next_deadline_us(), 4_000_000);
+        // Exactly on a deadline is not a miss.
+        assert_eq!(c.advance(5_000_000), 0);
+        assert_eq!(c.next_deadline_us(), 5_000_000);
+    }
+
+    #[test]
+    fn host_ticker_holds_the_configured_interval() {
+        use std::cell::{Cell, RefCell};
+        const PERIOD_US: u64 = 20_000;
+        const TICKS: usize = 25;
+
+        // Fake clock: every sleep oversleeps by a varying 0–7 ms, the way
+        // a loaded host scheduler does.
+        let clock = Cell::new(0u64);
+        let sleeps = Cell::new(0u64);
+        let arrivals = RefCell::new(Vec::new());
+        run_periodic(
+            || (PERIOD_US / 1000) as u32,
+            || clock.get(),
+            |us| {
+                sleeps.set(sleeps.get() + 1);
+                clock.set(clock.get() + us + (sleeps.get() * 3_000) % 8_000);
+            },
+            || arrivals.borrow().len() < TICKS,
+            || arrivals.borrow_mut().push(clock.get()),
+        );
+
+        // Each tick lands on its own deadline, never early, and late by
+        // less than a period; lateness does not build up across ticks.
+        let arrivals = arrivals.into_inner();
+        assert_eq!(arrivals.len(), TICKS);
+        for (k, &at) in arrivals.iter().enumerate() {
+            let deadline = PERIOD_US * (k as u64 + 1);
+            assert!(at >= deadline, "tick {k} early: {at} µs");
+            assert!(
+                at - deadline < PERIOD_US,
+                "tick {k} late: {at} vs {deadline} µs"
+            );
+        }
+    }
+
+    #[test]
+    fn host_ticker_follows_a_period_change() {
+        use std::cell::{Cell, RefCell};
+
+        // 1 s for three ticks, then 250 ms.
+        let period_ms = |ticks: usize| if ticks < 3 { 1000 } else { 250 };
+        let clock = Cell::new(0u64);
+        let arrivals = RefCell::new(Vec::new());
+        run_periodic(
+            || period_ms(arrivals.borrow().len()),
+            || clock.get(),
+            |us| clock.set(clock.get() + us),
+            || arrivals.borrow().len() < 6,
+            || arrivals.borrow_mut().push(clock.get()),
+        );
+
+        let expected_ms = [1000, 2000, 3000, 3250, 3500, 3750];
+        let arrivals = arrivals.into_inner();
+        assert_eq!(arrivals, expected_ms.map(|ms: u64| ms * 1000));
+    }
+}
@@ -7,9 +7,17 @@
 //! ## Priority hierarchy (highest first)
 //!
 //! 1. **Error** — rapid red flash (8 Hz)
-//! 2. **FSM state** — solid brand colours (idle=teal, sensing=blue, etc.)
+//! 2. **FSM state** — solid brand colours (idle=teal, sensing=blue, etc.);
+//!    maintenance mode replaces Idle with a white breathing pattern
 //! 3. **Connectivity** — patterns overlay when no higher priority is active
 //!
+//! ## Connectivity overlay
+//!
+//! Several conditions compete for the connectivity layer: an OTA in
+//! progress, low water, WiFi connected and BLE provisioning.  The first
+//! one that holds in `SystemConfig::led_overlay_priority` wins (see
+//! [`select_overlay`]); by default that is the order just listed.
+//!
 //! ## Pattern types
 //!
 //! | Pattern      | Description                      | Rate   |
@@ -20,12 +28,41 @@
 //! | Breathing    | Smooth ramp up/down              | 0.5 Hz |
 //! | DoubleBlink  | Two quick flashes, then pause    | 1 Hz   |
 //! | RapidFlash   | Very fast on/off                 | 8 Hz   |
+//!
+//! ## Brightness
+//!
+//! The selected pattern's output is scaled by a global brightness
+//! (0–255), further capped by the quiet-hours level while night
+//! dimming is in effect.  Scaling only changes magnitude — pattern
+//! timing and on/off shape are untouched.
+//!
+//! ## Per-state overrides
+//!
+//! Each FSM state has a built-in colour and pattern (see
+//! [`default_state_pattern`]).  Users may replace any of them with a
+//! [`LedOverride`]; the table is persisted in `SystemConfig` and pushed
+//! into the engine via [`LedPatternEngine::set_overrides`].  An override
+//! on [`StateId::Error`] changes the error-layer flash.
+//!
+//! ## Degraded output
+//!
+//! If any RGB channel failed to configure at boot, colour can no longer
+//! be trusted.  The engine then ignores the selected pattern and shows
+//! a single-channel blink code on whatever channels survive: N flashes
+//! (1 s on, 1 s off) followed by a 3 s gap, where N identifies the FSM
+//! state (see [`blink_code`]).  The slow timing keeps the code readable
+//! at the 1 Hz control-loop tick.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SafetyFault;
+use crate::fsm::StateId;
 
 /// Colour as (R, G, B) tuple, each 0–255.
 pub type Rgb = (u8, u8, u8);
 
 /// Pattern identifier.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PatternId {
     Solid,
     SlowPulse,
@@ -36,6 +73,117 @@ pub enum PatternId {
     Off,
 }
 
+/// User-chosen colour and pattern for one FSM state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedOverride {
+    pub colour: Rgb,
+    pub pattern: PatternId,
+}
+
+/// Built-in colour and pattern shown for `state`.
+pub fn default_state_pattern(state: StateId) -> (Rgb, PatternId) {
+    match state {
+        StateId::Idle => (COLOUR_IDLE, PatternId::Solid),
+        StateId::Sensing => (COLOUR_SENSING, PatternId::SlowPulse),
+        StateId::Active => (COLOUR_ACTIVE, PatternId::Solid),
+        StateId::Purging => (COLOUR_PURGING, PatternId::Breathing),
+        StateId::Error => (COLOUR_ERROR, PatternId::RapidFlash),
+        StateId::Draining => (COLOUR_LOW_WATER, PatternId::Breathing),
+    }
+}
+
+// ── Connectivity overlay ──────────────────────────────────────
+
+/// A condition that can claim the connectivity overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LedOverlay {
+    /// Firmware update in progress (orange fast blink).
+    Ota,
+    /// `WaterLevelLow` fault raised (yellow slow pulse).
+    LowWater,
+    /// Station link up (solid green).
+    Wifi,
+    /// BLE provisioning advertising or connected (purple double blink).
+    Ble,
+}
+
+/// Number of [`LedOverlay`] variants; a priority order lists each once.
+pub const LED_OVERLAY_COUNT: usize = 4;
+
+/// Built-in overlay order, highest priority first.
+pub const DEFAULT_LED_OVERLAY_PRIORITY: [LedOverlay; LED_OVERLAY_COUNT] = [
+    LedOverlay::Ota,
+    LedOverlay::LowWater,
+    LedOverlay::Wifi,
+    LedOverlay::Ble,
+];
+
+/// Colour and pattern shown for `overlay`.
+pub fn overlay_pattern(overlay: LedOverlay) -> (Rgb, PatternId) {
+    match overlay {
+        LedOverlay::Ota => (COLOUR_OTA, PatternId::FastBlink),
+        LedOverlay::LowWater => (COLOUR_LOW_WATER, PatternId::SlowPulse),
+        LedOverlay::Wifi => (COLOUR_WIFI_CONNECTED, PatternId::Solid),
+        LedOverlay::Ble => (COLOUR_PROVISIONING, PatternId::DoubleBlink),
+    }
+}
+
+/// Pick the connectivity overlay: the first entry of `priority` whose
+/// condition holds, or `None` to clear the layer.
+pub fn select_overlay(
+    fault_flags: u8,
+    ota_active: bool,
+    wifi_connected: bool,
+    ble_active: bool,
+    priority: &[LedOverlay; LED_OVERLAY_COUNT],
+) -> Option<(Rgb, PatternId)> {
+    let holds = |overlay: LedOverlay| match overlay {
+        LedOverlay::Ota => ota_active,
+        LedOverlay::LowWater => fault_flags & SafetyFault::WaterLevelLow.mask() != 0,
+        LedOverlay::Wifi => wifi_connected,
+        LedOverlay::Ble => ble_active,
+    };
+    priority
+        .iter()
+        .copied()
+        .find(|&o| holds(o))
+        .map(overlay_pattern)
+}
+
+// ── Single-channel fallback ───────────────────────────────────
+
+/// Failed-channel bits reported by `hw_init::led_failed_channels()`.
+pub const LED_FAILED_R: u8 = 0b001;
+pub const LED_FAILED_G: u8 = 0b010;
+pub const LED_FAILED_B: u8 = 0b100;
+pub const LED_FAILED_ALL: u8 = LED_FAILED_R | LED_FAILED_G | LED_FAILED_B;
+
+const BLINK_CODE_STEP_MS: u32 = 1000;
+const BLINK_CODE_GAP_MS: u32 = 3000;
+
+/// Flash count identifying `state` in the fallback blink code
+/// (Idle = 1, Sensing = 2, … Draining = 6).
+pub fn blink_code(state: StateId) -> u8 {
+    state as u8 + 1
+}
+
+/// Whether a blink code of `count` flashes is lit at `phase_ms`.
+pub fn blink_code_on(count: u8, phase_ms: u32) -> bool {
+    let flashes_ms = count as u32 * BLINK_CODE_STEP_MS * 2;
+    let pos = phase_ms % (flashes_ms + BLINK_CODE_GAP_MS);
+    pos < flashes_ms && (pos / BLINK_CODE_STEP_MS) % 2 == 0
+}
+
+/// Degraded LED output: every channel not in `failed_channels` lit at
+/// full scale while the code is on, all dark otherwise.
+pub fn fallback_output(failed_channels: u8, count: u8, phase_ms: u32) -> Rgb {
+    if !blink_code_on(count, phase_ms) {
+        return (0, 0, 0);
+    }
+    let lit = |bit: u8| if failed_channels & bit == 0 { 255 } else { 0 };
+    (lit(LED_FAILED_R), lit(LED_FAILED_G), lit(LED_FAILED_B))
+}
+
 /// A pattern request with colour and type.
 #[derive(Debug, Clone, Copy)]
 pub struct PatternRequest {
@@ -45,18 +193,95 @@ pub struct PatternRequest {
 }
 
 /// LED pattern engine. Stack-allocated, no heap.
-#[derive(Default)]
 pub struct LedPatternEngine {
     phase_ms: u32,
     active: Option<PatternRequest>,
     fsm_request: Option<PatternRequest>,
     connectivity_request: Option<PatternRequest>,
     error_request: Option<PatternRequest>,
+    overrides: [Option<LedOverride>; StateId::COUNT],
+    brightness: u8,
+    quiet_brightness: u8,
+    quiet: bool,
+    failed_channels: u8,
+    fallback_state: StateId,
+}
+
+impl Default for LedPatternEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl LedPatternEngine {
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            phase_ms: 0,
+            active: None,
+            fsm_request: None,
+            connectivity_request: None,
+            error_request: None,
+            overrides: [None; StateId::COUNT],
+            brightness: 255,
+            quiet_brightness: 255,
+            quiet: false,
+            failed_channels: 0,
+            fallback_state: StateId::Idle,
+        }
+    }
+
+    /// Set the global output brightness (255 = full).
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness;
+    }
+
+    /// Set the brightness cap applied while quiet hours are active.
+    pub fn set_quiet_brightness(&mut self, brightness: u8) {
+        self.quiet_brightness = brightness;
+    }
+
+    /// Enter or leave quiet-hours dimming.
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// Brightness currently applied to the pattern output.
+    pub fn effective_brightness(&self) -> u8 {
+        if self.quiet {
+            self.brightness.min(self.quiet_brightness)
+        } else {
+            self.brightness
+        }
+    }
+
+    /// Record RGB channels that failed to configure (`LED_FAILED_*`).
+    /// Any non-zero mask switches output to the blink code.
+    pub fn set_failed_channels(&mut self, failed_channels: u8) {
+        self.failed_channels = failed_channels;
+    }
+
+    /// Whether output has fallen back to the single-channel blink code.
+    pub fn is_degraded(&self) -> bool {
+        self.failed_channels != 0
+    }
+
+    /// Set the FSM state reported by the fallback blink code.
+    pub fn set_fallback_state(&mut self, state: StateId) {
+        self.fallback_state = state;
+    }
+
+    /// Replace the per-state override table (indexed by `StateId`).
+    pub fn set_overrides(&mut self, overrides: &[Option<LedOverride>; StateId::COUNT]) {
+        self.overrides = *overrides;
+    }
+
+    /// Colour and pattern for `state`: the user override if one is
+    /// set, otherwise the built-in default.
+    pub fn state_pattern(&self, state: StateId) -> (Rgb, PatternId) {
+        match self.overrides[state as usize] {
+            Some(o) => (o.colour, o.pattern),
+            None => default_state_pattern(state),
+        }
     }
 
     /// Set the FSM-layer pattern (priority 2).
@@ -85,9 +310,10 @@ impl LedPatternEngine {
     /// Set or clear the error pattern (priority 1 — highest).
     pub fn set_error_pattern(&mut self, active: bool) {
         if active {
+            let (colour, pattern) = self.state_pattern(StateId::Error);
             self.error_request = Some(PatternRequest {
-                colour: (255, 0, 0),
-                pattern: PatternId::RapidFlash,
+                colour,
+                pattern,
                 priority: 1,
             });
         } else {
@@ -122,10 +348,21 @@ impl LedPatternEngine {
         }
         self.active = selected;
 
-        match &self.active {
+        if self.is_degraded() {
+            let state = if self.error_request.is_some() {
+                StateId::Error
+            } else {
+                self.fallback_state
+            };
+            let (r, g, b) = fallback_output(self.failed_channels, blink_code(state), self.phase_ms);
+            return Self::scale(r, g, b, self.effective_brightness());
+        }
+
+        let (r, g, b) = match &self.active {
             Some(req) => self.generate(req.colour, req.pattern),
             None => (0, 0, 0),
-        }
+        };
+        Self::scale(r, g, b, self.effective_brightness())
     }
 
     fn select_active(&self) -> Option<PatternRequest> {
@@ -198,11 +435,66 @@ pub const COLOUR_WIFI_CONNECTING: Rgb = (0, 100, 255); // Blue
 pub const COLOUR_WIFI_CONNECTED: Rgb = (0, 255, 50); // Green
 pub const COLOUR_LOW_WATER: Rgb = (255, 200, 0); // Yellow
 pub const COLOUR_OTA: Rgb = (255, 140, 0); // Orange
+pub const COLOUR_RECOVERING: Rgb = (255, 120, 0); // Amber
+pub const COLOUR_MAINTENANCE: Rgb = (200, 200, 200); // White
+pub const COLOUR_SAFE_MODE: Rgb = (255, 0, 120); // Magenta
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const LOW_WATER: u8 = SafetyFault::WaterLevelLow.mask();
+
+    #[test]
+    fn default_overlay_order_is_ota_low_water_wifi_ble() {
+        let p = &DEFAULT_LED_OVERLAY_PRIORITY;
+        assert_eq!(
+            select_overlay(LOW_WATER, true, true, true, p),
+            Some((COLOUR_OTA, PatternId::FastBlink))
+        );
+        assert_eq!(
+            select_overlay(LOW_WATER, false, true, true, p),
+            Some((COLOUR_LOW_WATER, PatternId::SlowPulse))
+        );
+        assert_eq!(
+            select_overlay(0, false, true, true, p),
+            Some((COLOUR_WIFI_CONNECTED, PatternId::Solid))
+        );
+        assert_eq!(
+            select_overlay(0, false, false, true, p),
+            Some((COLOUR_PROVISIONING, PatternId::DoubleBlink))
+        );
+        assert_eq!(select_overlay(0, false, false, false, p), None);
+    }
+
+    #[test]
+    fn custom_overlay_order_puts_low_water_above_ota() {
+        let p = [
+            LedOverlay::LowWater,
+            LedOverlay::Ota,
+            LedOverlay::Wifi,
+            LedOverlay::Ble,
+        ];
+        assert_eq!(
+            select_overlay(LOW_WATER, true, true, true, &p),
+            Some((COLOUR_LOW_WATER, PatternId::SlowPulse))
+        );
+        // Without the fault, OTA is next in line.
+        assert_eq!(
+            select_overlay(0, true, true, true, &p),
+            Some((COLOUR_OTA, PatternId::FastBlink))
+        );
+    }
+
+    #[test]
+    fn other_faults_do_not_claim_the_low_water_overlay() {
+        let flags = SafetyFault::OverTemperature.mask();
+        assert_eq!(
+            select_overlay(flags, false, false, false, &DEFAULT_LED_OVERLAY_PRIORITY),
+            None
+        );
+    }
+
     #[test]
     fn solid_pattern_returns_exact_colour() {
         let mut engine = LedPatternEngine::new();
@@ -262,6 +554,184 @@ mod tests {
         assert_eq!(off, (0, 0, 0));
     }
 
+    #[test]
+    fn half_brightness_halves_every_channel() {
+        let colour = (255, 180, 101);
+        for pattern in [PatternId::Solid, PatternId::SlowPulse, PatternId::FastBlink] {
+            let mut full = LedPatternEngine::new();
+            let mut half = LedPatternEngine::new();
+            half.set_brightness(128);
+            full.set_fsm_pattern(colour, pattern);
+            half.set_fsm_pattern(colour, pattern);
+
+            for step in [0, 100, 150, 250] {
+                let (fr, fg, fb) = full.tick(step);
+                let (hr, hg, hb) = half.tick(step);
+                for (f, h) in [(fr, hr), (fg, hg), (fb, hb)] {
+                    assert!(
+                        (f as i16 / 2 - h as i16).abs() <= 1,
+                        "{:?}: full {} half {}",
+                        pattern,
+                        f,
+                        h
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_brightness_is_dark() {
+        let mut engine = LedPatternEngine::new();
+        engine.set_brightness(0);
+        engine.set_fsm_pattern(COLOUR_IDLE, PatternId::Solid);
+        assert_eq!(engine.tick(1000), (0, 0, 0));
+    }
+
+    #[test]
+    fn quiet_hours_auto_dim_at_configured_hour() {
+        use crate::scheduler::{QuietHours, Scheduler};
+
+        let mut sched = Scheduler::new();
+        sched.set_quiet_hours(QuietHours {
+            start_hour: 22,
+            end_hour: 7,
+        });
+        let mut engine = LedPatternEngine::new();
+        engine.set_quiet_brightness(64);
+        engine.set_fsm_pattern((255, 255, 255), PatternId::Solid);
+
+        engine.set_quiet(sched.is_quiet_hour(Some(21)));
+        assert_eq!(engine.tick(1000), (255, 255, 255));
+
+        engine.set_quiet(sched.is_quiet_hour(Some(22)));
+        assert_eq!(engine.tick(1000), (64, 64, 64));
+
+        engine.set_quiet(sched.is_quiet_hour(Some(7)));
+        assert_eq!(engine.tick(1000), (255, 255, 255));
+    }
+
+    #[test]
+    fn quiet_dim_never_brightens() {
+        let mut engine = LedPatternEngine::new();
+        engine.set_brightness(40);
+        engine.set_quiet_brightness(100);
+        engine.set_quiet(true);
+        assert_eq!(engine.effective_brightness(), 40);
+    }
+
+    #[test]
+    fn override_changes_targeted_state_only() {
+        let mut engine = LedPatternEngine::new();
+        let mut overrides = [None; StateId::COUNT];
+        overrides[StateId::Active as usize] = Some(LedOverride {
+            colour: (200, 0, 200),
+            pattern: PatternId::Solid,
+        });
+        engine.set_overrides(&overrides);
+
+        let (colour, pattern) = engine.state_pattern(StateId::Active);
+        engine.set_fsm_pattern(colour, pattern);
+        assert_eq!(engine.tick(1000), (200, 0, 200));
+
+        let (colour, pattern) = engine.state_pattern(StateId::Idle);
+        engine.set_fsm_pattern(colour, pattern);
+        assert_eq!(engine.tick(1000), COLOUR_IDLE);
+    }
+
+    #[test]
+    fn error_override_recolours_error_flash() {
+        let mut engine = LedPatternEngine::new();
+        let mut overrides = [None; StateId::COUNT];
+        overrides[StateId::Error as usize] = Some(LedOverride {
+            colour: (255, 0, 255),
+            pattern: PatternId::Solid,
+        });
+        engine.set_overrides(&overrides);
+        engine.set_error_pattern(true);
+        assert_eq!(engine.tick(130), (255, 0, 255));
+    }
+
+    #[test]
+    fn reset_overrides_restores_defaults() {
+        let mut engine = LedPatternEngine::new();
+        let mut overrides = [None; StateId::COUNT];
+        overrides[StateId::Idle as usize] = Some(LedOverride {
+            colour: (10, 20, 30),
+            pattern: PatternId::Solid,
+        });
+        engine.set_overrides(&overrides);
+        engine.set_overrides(&[None; StateId::COUNT]);
+
+        for i in 0..StateId::COUNT {
+            let state = StateId::from_index(i);
+            assert_eq!(engine.state_pattern(state), default_state_pattern(state));
+        }
+        let (colour, pattern) = engine.state_pattern(StateId::Idle);
+        engine.set_fsm_pattern(colour, pattern);
+        assert_eq!(engine.tick(1000), COLOUR_IDLE);
+    }
+
+    #[test]
+    fn blink_code_flashes_count_times_then_pauses() {
+        // Three flashes: on at 0/2/4 s, off between, dark for the 3 s gap.
+        let lit: Vec<bool> = (0..9).map(|s| blink_code_on(3, s * 1000)).collect();
+        assert_eq!(
+            lit,
+            [true, false, true, false, true, false, false, false, false]
+        );
+        assert!(blink_code_on(3, 9000), "code repeats after the gap");
+    }
+
+    #[test]
+    fn fallback_lights_only_surviving_channels() {
+        assert_eq!(fallback_output(LED_FAILED_R, 1, 0), (0, 255, 255));
+        assert_eq!(fallback_output(LED_FAILED_R | LED_FAILED_B, 1, 0), (0, 255, 0));
+        assert_eq!(fallback_output(LED_FAILED_G, 1, 1000), (0, 0, 0));
+        assert_eq!(fallback_output(LED_FAILED_ALL, 1, 0), (0, 0, 0));
+    }
+
+    #[test]
+    fn degraded_engine_shows_state_blink_code() {
+        let mut engine = LedPatternEngine::new();
+        engine.set_failed_channels(LED_FAILED_G);
+        engine.set_fsm_pattern(COLOUR_SENSING, PatternId::SlowPulse);
+        engine.set_fallback_state(StateId::Sensing);
+
+        // Sensing = 2 flashes: on, off, on, then the gap.
+        let frames: Vec<Rgb> = [0, 1000, 1000, 1000, 1000]
+            .iter()
+            .map(|&dt| engine.tick(dt))
+            .collect();
+        assert_eq!(
+            frames,
+            [(255, 0, 255), (0, 0, 0), (255, 0, 255), (0, 0, 0), (0, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn degraded_error_layer_uses_error_code() {
+        let mut engine = LedPatternEngine::new();
+        engine.set_failed_channels(LED_FAILED_R);
+        engine.set_fallback_state(StateId::Idle);
+        engine.set_fsm_pattern(COLOUR_IDLE, PatternId::Solid);
+        engine.set_error_pattern(true);
+
+        let lit = (0..14).filter(|_| engine.tick(1000) != (0, 0, 0)).count();
+        // First tick resets phase to 0, so 14 ticks span one 13 s Error
+        // cycle (5 flashes + gap) plus the start of the next.
+        assert_eq!(lit, blink_code(StateId::Error) as usize + 1);
+    }
+
+    #[test]
+    fn healthy_channels_keep_colour_output() {
+        let mut engine = LedPatternEngine::new();
+        engine.set_failed_channels(0);
+        engine.set_fsm_pattern(COLOUR_IDLE, PatternId::Solid);
+        assert!(!engine.is_degraded());
+        assert_eq!(engine.tick(1000), COLOUR_IDLE);
+    }
+
     #[test]
     fn brightness_ramp() {
         assert_eq!(LedPatternEngine::sine_brightness(0, 1000), 0);
@@ -1,7 +1,8 @@
 //! Peristaltic pump motor driver (DRV8871 H-bridge).
 //!
 //! Variable-speed forward/reverse control via LEDC PWM (ch0) and a
-//! digital direction pin.
+//! digital direction pin.  A second digital pin drives the source
+//! valve that picks which tank the pump draws from.
 //!
 //! ## Safety contract
 //!
@@ -12,9 +13,13 @@
 //!
 //! On ESP-IDF: drives real PWM and GPIO via hw_init helpers.
 //! On host/test: tracks state in-memory only.
+//!
+//! The direction and source-valve pins honour their `pins::*_ACTIVE`
+//! polarity, so an active-low board is driven with inverted levels.
 
 use crate::drivers::hw_init;
-use crate::pins;
+use crate::fsm::context::Tank;
+use crate::pins::{self, ActiveLevel};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -29,15 +34,55 @@ pub enum PumpState {
     Running { duty: u8, dir: Direction },
 }
 
-#[derive(Default)]
 pub struct PumpDriver {
     state: PumpState,
     hw_duty: u8,
+    source: Tank,
+    /// Polarity of the direction pin; asserted = forward.
+    dir_active: ActiveLevel,
+    /// Polarity of the source valve; asserted = Tank B.
+    source_active: ActiveLevel,
+    pwm_channel: u32,
+    dir_gpio: i32,
+    select_gpio: i32,
+}
+
+impl Default for PumpDriver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl PumpDriver {
     pub fn new() -> Self {
-        Self::default()
+        Self::with_polarity(pins::PUMP_DIR_ACTIVE, pins::TANK_SELECT_ACTIVE)
+    }
+
+    /// Driver for a board whose direction and source-valve outputs
+    /// assert at the given levels.
+    pub fn with_polarity(dir_active: ActiveLevel, source_active: ActiveLevel) -> Self {
+        Self {
+            state: PumpState::Stopped,
+            hw_duty: 0,
+            source: Tank::default(),
+            dir_active,
+            source_active,
+            pwm_channel: hw_init::LEDC_CH_PUMP,
+            dir_gpio: pins::PUMP_DIR_GPIO,
+            select_gpio: pins::TANK_SELECT_GPIO,
+        }
+    }
+
+    /// Drive a pump wired to another LEDC channel and direction and
+    /// source-valve pins (a second filter loop).
+    #[must_use]
+    pub fn on_pins(self, pwm_channel: u32, dir_gpio: i32, select_gpio: i32) -> Self {
+        Self {
+            pwm_channel,
+            dir_gpio,
+            select_gpio,
+            ..self
+        }
     }
 
     pub fn set(&mut self, duty: u8, direction: Direction) {
@@ -64,14 +109,23 @@ impl PumpDriver {
         self.state = PumpState::Stopped;
     }
 
+    /// Point the source valve at `tank`.  The GPIO is only written
+    /// when the selection changes.
+    pub fn set_source(&mut self, tank: Tank) {
+        if tank != self.source {
+            hw_init::gpio_write(self.select_gpio, self.source_active.level(tank == Tank::B));
+            self.source = tank;
+        }
+    }
+
     fn set_direction_hw(&self, dir: Direction) {
-        let high = matches!(dir, Direction::Forward);
-        hw_init::gpio_write(pins::PUMP_DIR_GPIO, high);
+        let forward = matches!(dir, Direction::Forward);
+        hw_init::gpio_write(self.dir_gpio, self.dir_active.level(forward));
     }
 
     fn set_duty_hw(&self, duty: u8) {
         let duty_8bit = ((duty as u16) * 255 / 100) as u8;
-        hw_init::ledc_set(hw_init::LEDC_CH_PUMP, duty_8bit);
+        hw_init::ledc_set(self.pwm_channel, duty_8bit);
     }
 
     pub fn state(&self) -> PumpState {
@@ -86,3 +140,50 @@ impl PumpDriver {
         self.hw_duty
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(pin: i32) -> bool {
+        hw_init::gpio_output_level(pin)
+    }
+
+    #[test]
+    fn active_low_direction_and_valve_invert_levels() {
+        let mut pump = PumpDriver::with_polarity(ActiveLevel::Low, ActiveLevel::Low);
+        pump.set(60, Direction::Forward);
+        assert!(!level(pins::PUMP_DIR_GPIO), "forward is LOW on an active-low board");
+        pump.set(60, Direction::Reverse);
+        assert!(level(pins::PUMP_DIR_GPIO));
+
+        pump.set_source(Tank::B);
+        assert!(!level(pins::TANK_SELECT_GPIO));
+        pump.set_source(Tank::A);
+        assert!(level(pins::TANK_SELECT_GPIO));
+    }
+
+    #[test]
+    fn default_polarity_keeps_active_high_levels() {
+        let mut pump = PumpDriver::new();
+        pump.set(60, Direction::Forward);
+        assert!(level(pins::PUMP_DIR_GPIO));
+        pump.set_source(Tank::B);
+        assert!(level(pins::TANK_SELECT_GPIO));
+    }
+
+    #[test]
+    fn second_zone_pump_drives_its_own_pins() {
+        let mut pump = PumpDriver::new().on_pins(
+            hw_init::LEDC_CH_PUMP_B,
+            pins::zone_b::PUMP_DIR_GPIO,
+            pins::zone_b::TANK_SELECT_GPIO,
+        );
+        pump.set(60, Direction::Forward);
+        pump.set_source(Tank::B);
+        assert!(level(pins::zone_b::PUMP_DIR_GPIO));
+        assert!(level(pins::zone_b::TANK_SELECT_GPIO));
+        assert!(!level(pins::PUMP_DIR_GPIO));
+        assert!(!level(pins::TANK_SELECT_GPIO));
+    }
+}
@@ -7,23 +7,38 @@
 //!
 //! On ESP-IDF: drives three LEDC PWM channels via hw_init.
 //! On host/test: tracks state in-memory only.
+//!
+//! Channels that failed to configure at boot are never written; the
+//! pattern engine drives the survivors with a blink code instead.
 
 use crate::drivers::hw_init;
+use crate::drivers::led_patterns::{LED_FAILED_B, LED_FAILED_G, LED_FAILED_R};
 
 #[derive(Default)]
 pub struct StatusLed {
     current: (u8, u8, u8),
+    failed_channels: u8,
 }
 
 impl StatusLed {
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            current: (0, 0, 0),
+            failed_channels: hw_init::led_failed_channels(),
+        }
     }
 
     pub fn set_colour(&mut self, r: u8, g: u8, b: u8) {
-        hw_init::ledc_set(hw_init::LEDC_CH_LED_R, r);
-        hw_init::ledc_set(hw_init::LEDC_CH_LED_G, g);
-        hw_init::ledc_set(hw_init::LEDC_CH_LED_B, b);
+        let channels = [
+            (hw_init::LEDC_CH_LED_R, LED_FAILED_R, r),
+            (hw_init::LEDC_CH_LED_G, LED_FAILED_G, g),
+            (hw_init::LEDC_CH_LED_B, LED_FAILED_B, b),
+        ];
+        for (channel, bit, duty) in channels {
+            if self.failed_channels & bit == 0 {
+                hw_init::ledc_set(channel, duty);
+            }
+        }
         self.current = (r, g, b);
     }
 
@@ -10,11 +10,14 @@
 //!
 //! On ESP-IDF: drives real GPIO enable pin and LEDC PWM via hw_init.
 //! On host/test: tracks state in-memory only.
+//!
+//! The enable pin honours [`pins::UVC_ENABLE_ACTIVE`], so active-low
+//! relay boards are driven LOW to switch the UVC on.
 
 use log::warn;
 
 use crate::drivers::hw_init;
-use crate::pins;
+use crate::pins::{self, ActiveLevel};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum UvcState {
@@ -24,15 +27,46 @@ pub enum UvcState {
     Faulted(&'static str),
 }
 
-#[derive(Default)]
 pub struct UvcDriver {
     state: UvcState,
     hw_duty: u8,
+    enable_active: ActiveLevel,
+    enable_gpio: i32,
+    /// LEDC channel for dimming; `None` when the input is tied HIGH.
+    pwm_channel: Option<u32>,
+}
+
+impl Default for UvcDriver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl UvcDriver {
     pub fn new() -> Self {
-        Self::default()
+        Self::with_polarity(pins::UVC_ENABLE_ACTIVE)
+    }
+
+    /// Driver for a board whose enable relay asserts at `enable_active`.
+    pub fn with_polarity(enable_active: ActiveLevel) -> Self {
+        Self {
+            state: UvcState::Off,
+            hw_duty: 0,
+            enable_active,
+            enable_gpio: pins::UVC_ENABLE_GPIO,
+            pwm_channel: Some(hw_init::LEDC_CH_UVC),
+        }
+    }
+
+    /// Drive a UVC driver on another enable pin, dimmed through
+    /// `pwm_channel` or at full brightness when `None`.
+    #[must_use]
+    pub fn on_pins(self, enable_gpio: i32, pwm_channel: Option<u32>) -> Self {
+        Self {
+            enable_gpio,
+            pwm_channel,
+            ..self
+        }
     }
 
     pub fn enable(&mut self, duty: u8) {
@@ -64,12 +98,14 @@ impl UvcDriver {
     }
 
     fn set_enable_hw(&self, on: bool) {
-        hw_init::gpio_write(pins::UVC_ENABLE_GPIO, on);
+        hw_init::gpio_write(self.enable_gpio, self.enable_active.level(on));
     }
 
     fn set_duty_hw(&self, duty: u8) {
-        let duty_8bit = ((duty as u16) * 255 / 100) as u8;
-        hw_init::ledc_set(hw_init::LEDC_CH_UVC, duty_8bit);
+        if let Some(channel) = self.pwm_channel {
+            let duty_8bit = ((duty as u16) * 255 / 100) as u8;
+            hw_init::ledc_set(channel, duty_8bit);
+        }
     }
 
     pub fn state(&self) -> UvcState {
@@ -84,3 +120,31 @@ impl UvcDriver {
         self.hw_duty
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enable_pin() -> bool {
+        hw_init::gpio_output_level(pins::UVC_ENABLE_GPIO)
+    }
+
+    #[test]
+    fn active_high_enable_follows_duty() {
+        let mut uvc = UvcDriver::with_polarity(ActiveLevel::High);
+        uvc.enable(80);
+        assert!(enable_pin());
+        uvc.disable();
+        assert!(!enable_pin());
+    }
+
+    #[test]
+    fn active_low_enable_inverts_level() {
+        let mut uvc = UvcDriver::with_polarity(ActiveLevel::Low);
+        uvc.enable(80);
+        assert!(uvc.is_on());
+        assert!(!enable_pin(), "active-low relay must be driven LOW to turn on");
+        uvc.fault_shutdown("test");
+        assert!(enable_pin());
+    }
+}
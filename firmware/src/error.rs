@@ -120,7 +120,7 @@ impl From<ActuatorError> for Error {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum SafetyFault {
-    /// Tank A (supply) water level below minimum.
+    /// Both tanks' water level below minimum.
     WaterLevelLow = 0b0000_0001,
     /// Pump commanded on but flow sensor reads zero.
     NoFlowDetected = 0b0000_0010,
@@ -135,6 +135,17 @@ impl SafetyFault {
     pub const fn mask(self) -> u8 {
         self as u8
     }
+
+    /// The fault whose bit is exactly `mask`, if any.
+    pub const fn from_mask(mask: u8) -> Option<Self> {
+        match mask {
+            0b0000_0001 => Some(Self::WaterLevelLow),
+            0b0000_0010 => Some(Self::NoFlowDetected),
+            0b0000_0100 => Some(Self::OverTemperature),
+            0b0000_1000 => Some(Self::UvcInterlockOpen),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for SafetyFault {
@@ -148,6 +159,55 @@ impl fmt::Display for SafetyFault {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Advisories
+// ---------------------------------------------------------------------------
+
+/// Non-fatal conditions worth surfacing to the user.  Unlike
+/// [`SafetyFault`]s they never force the `Error` state; they are kept in
+/// a separate bitfield (`FsmContext.advisory_flags`) and reported in
+/// telemetry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Advisory {
+    /// Priming finished without the flow sensor seeing any flow.
+    NoFlowWhilePumping = 0b0000_0001,
+    /// A scrub hit `max_active_duration_secs` and was cut short.
+    ActiveTimeout = 0b0000_0010,
+    /// A critical sensor has not produced a reading for
+    /// `sensor_stale_after_secs`; its value in the snapshot is old.
+    SensorStale = 0b0000_0100,
+    /// An RGB status LED channel failed to configure at boot; status is
+    /// shown as a single-channel blink code instead of colour.
+    StatusLedFailed = 0b0000_1000,
+    /// Inbound RPC frames are being dropped on a full command queue
+    /// (see `diagnostics::DropRateMonitor`).
+    Overloaded = 0b0001_0000,
+    /// The config failed to load on too many consecutive boots; running
+    /// on defaults with config persistence off (see `adapters::nvs`).
+    ConfigSafeMode = 0b0010_0000,
+}
+
+impl Advisory {
+    /// Return the bitmask for this advisory.
+    pub const fn mask(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for Advisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoFlowWhilePumping => write!(f, "no flow while pumping"),
+            Self::ActiveTimeout => write!(f, "scrub ran abnormally long"),
+            Self::SensorStale => write!(f, "sensor data stale"),
+            Self::StatusLedFailed => write!(f, "status LED degraded"),
+            Self::Overloaded => write!(f, "command queue overloaded"),
+            Self::ConfigSafeMode => write!(f, "config safe mode"),
+        }
+    }
+}
+
 impl From<SafetyFault> for Error {
     fn from(e: SafetyFault) -> Self {
         Self::Safety(e)
@@ -25,6 +25,10 @@ use core::sync::atomic::{AtomicU8, Ordering};
 /// Power of 2 for efficient ring buffer modulo.
 const EVENT_QUEUE_CAP: usize = 32;
 
+/// Events the main loop handles per `drain_events` call before falling
+/// through to feed the watchdog and refresh the LEDs.
+pub const DRAIN_BUDGET: usize = 8;
+
 /// System event types, ordered by rough priority.
 /// Lower discriminant = higher priority when multiple events
 /// are pending simultaneously.
@@ -38,6 +42,9 @@ pub enum Event {
     InterlockChanged = 1,
     /// Water level changed on Tank A or B.
     WaterLevelChanged = 2,
+    /// Supply voltage dipped below the brown-out detector threshold.
+    /// Outputs must go off before the rail collapses.
+    BrownoutWarning = 3,
 
     // ── Sensor data ───────────────────────────────────────
     /// Periodic sensor read timer fired.
@@ -85,9 +92,15 @@ pub enum Event {
     /// BLE PSK characteristic written.
     BlePskWrite = 39,
 
+    // ── WiFi ──────────────────────────────────────────────
+    /// Station got an IP or lost its AP; see `wifi::record_link_event`.
+    WifiLinkChanged = 42,
+
     // ── Housekeeping ──────────────────────────────────────
     /// Watchdog heartbeat.
     WatchdogTick = 50,
+    /// Provisioned certificates are ready to load into the TLS server.
+    TlsReconfigure = 51,
 }
 
 // ── Lock-free SPSC ring buffer ────────────────────────────────
@@ -142,6 +155,8 @@ pub fn wait_for_event(timeout_ms: u32) {
 /// The caller must be the sole producer (ISR / timer-task context).
 /// `slot` must be `< EVENT_QUEUE_CAP`.  SPSC discipline guarantees that
 /// the consumer is not reading this slot simultaneously.
+#[inline(always)]
+#[allow(clippy::inline_always)] // reached from IRAM ISRs, which must not call into flash
 unsafe fn event_buffer_write(slot: usize, val: u8) {
     unsafe {
         EVENT_BUFFER[slot] = val;
@@ -161,7 +176,10 @@ unsafe fn event_buffer_read(slot: usize) -> u8 {
 /// Push an event into the queue and wake the main task.
 ///
 /// Safe to call from ISR context, timer callbacks, and any thread.
-/// Returns `false` if the queue is full (event dropped).
+/// Returns `false` if the queue is full (event dropped).  Always inlined
+/// so IRAM-resident ISRs do not call into flash.
+#[inline(always)]
+#[allow(clippy::inline_always)] // see the doc comment
 pub fn push_event(event: Event) -> bool {
     let head = EVENT_HEAD.load(Ordering::Relaxed);
     let tail = EVENT_TAIL.load(Ordering::Acquire);
@@ -202,12 +220,19 @@ pub fn pop_event() -> Option<Event> {
     event_from_u8(raw)
 }
 
-/// Drain all pending events into a callback.
+/// Drain up to `max_events` pending events into a callback.
 /// Processes events in FIFO order.
-pub fn drain_events(mut handler: impl FnMut(Event)) {
-    while let Some(event) = pop_event() {
-        handler(event);
+///
+/// Returns `true` if events are still queued afterwards, so the caller
+/// can come straight back instead of blocking in `wait_for_event()`.
+pub fn drain_events(max_events: usize, mut handler: impl FnMut(Event)) -> bool {
+    for _ in 0..max_events {
+        match pop_event() {
+            Some(event) => handler(event),
+            None => return false,
+        }
     }
+    !queue_is_empty()
 }
 
 /// Check if the event queue is empty.
@@ -231,6 +256,7 @@ fn event_from_u8(raw: u8) -> Option<Event> {
         0 => Some(Event::SafetyFault),
         1 => Some(Event::InterlockChanged),
         2 => Some(Event::WaterLevelChanged),
+        3 => Some(Event::BrownoutWarning),
         10 => Some(Event::SensorReadTick),
         11 => Some(Event::Nh3ThresholdCrossed),
         20 => Some(Event::ControlTick),
@@ -248,7 +274,9 @@ fn event_from_u8(raw: u8) -> Option<Event> {
         39 => Some(Event::BlePskWrite),
         40 => Some(Event::IdleTimeout),
         41 => Some(Event::UlpWake),
+        42 => Some(Event::WifiLinkChanged),
         50 => Some(Event::WatchdogTick),
+        51 => Some(Event::TlsReconfigure),
         _ => None,
     }
 }
@@ -257,15 +285,21 @@ fn event_from_u8(raw: u8) -> Option<Event> {
 mod tests {
     use super::*;
     use core::sync::atomic::Ordering;
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    /// The queue is a process-wide static; serialize the tests using it.
+    static QUEUE_LOCK: Mutex<()> = Mutex::new(());
 
-    fn reset_queue() {
+    fn reset_queue() -> MutexGuard<'static, ()> {
+        let guard = QUEUE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
         EVENT_HEAD.store(0, Ordering::Relaxed);
         EVENT_TAIL.store(0, Ordering::Relaxed);
+        guard
     }
 
     #[test]
     fn queue_starts_empty() {
-        reset_queue();
+        let _q = reset_queue();
         assert!(queue_is_empty());
         assert_eq!(queue_len(), 0);
         assert!(pop_event().is_none());
@@ -273,7 +307,7 @@ mod tests {
 
     #[test]
     fn push_and_pop_single() {
-        reset_queue();
+        let _q = reset_queue();
         assert!(push_event(Event::ControlTick));
         assert!(!queue_is_empty());
         assert_eq!(queue_len(), 1);
@@ -285,7 +319,7 @@ mod tests {
 
     #[test]
     fn fifo_ordering() {
-        reset_queue();
+        let _q = reset_queue();
         push_event(Event::SafetyFault);
         push_event(Event::ControlTick);
         push_event(Event::TelemetryTick);
@@ -298,21 +332,52 @@ mod tests {
 
     #[test]
     fn drain_events_collects_all() {
-        reset_queue();
+        let _q = reset_queue();
         push_event(Event::SensorReadTick);
         push_event(Event::ControlTick);
 
         let mut collected = Vec::new();
-        drain_events(|e| collected.push(e));
+        assert!(!drain_events(DRAIN_BUDGET, |e| collected.push(e)));
         assert_eq!(collected.len(), 2);
         assert_eq!(collected[0], Event::SensorReadTick);
         assert_eq!(collected[1], Event::ControlTick);
         assert!(queue_is_empty());
     }
 
+    #[test]
+    fn drain_events_stops_at_budget() {
+        let _q = reset_queue();
+        for _ in 0..(2 * DRAIN_BUDGET + 3) {
+            assert!(push_event(Event::CommandReceived));
+        }
+
+        let mut count = 0;
+        assert!(drain_events(DRAIN_BUDGET, |_| count += 1));
+        assert_eq!(count, DRAIN_BUDGET);
+        assert_eq!(queue_len(), DRAIN_BUDGET + 3);
+
+        count = 0;
+        assert!(drain_events(DRAIN_BUDGET, |_| count += 1));
+        assert_eq!(count, DRAIN_BUDGET);
+
+        count = 0;
+        assert!(!drain_events(DRAIN_BUDGET, |_| count += 1));
+        assert_eq!(count, 3);
+        assert!(queue_is_empty());
+    }
+
+    #[test]
+    fn drain_events_exact_budget_reports_empty() {
+        let _q = reset_queue();
+        for _ in 0..DRAIN_BUDGET {
+            push_event(Event::ControlTick);
+        }
+        assert!(!drain_events(DRAIN_BUDGET, |_| {}));
+    }
+
     #[test]
     fn overflow_returns_false() {
-        reset_queue();
+        let _q = reset_queue();
         for _ in 0..(EVENT_QUEUE_CAP - 1) {
             assert!(push_event(Event::ControlTick));
         }
@@ -324,10 +389,13 @@ mod tests {
         let events = [
             Event::SafetyFault,
             Event::InterlockChanged,
+            Event::BrownoutWarning,
             Event::ControlTick,
             Event::TelemetryTick,
             Event::ButtonShortPress,
             Event::UlpWake,
+            Event::WifiLinkChanged,
+            Event::TlsReconfigure,
         ];
         for e in events {
             let raw = e as u8;
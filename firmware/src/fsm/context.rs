@@ -5,7 +5,13 @@
 //! outputs, timing information, configuration, and accumulated safety
 //! faults.  Think of it as the "blackboard" in a blackboard architecture.
 
-use crate::config::SystemConfig;
+use crate::config::{SystemConfig, TankSource};
+use crate::control::numeric::{ControlNum, Num};
+
+/// Shortest span the NH3 rate of change is measured over (seconds).
+/// GPIO-triggered ticks can land milliseconds apart, and a reading
+/// difference over such a gap is sensor noise, not a trend.
+pub const MIN_NH3_RATE_WINDOW_SECS: f32 = 1.0;
 
 // ---------------------------------------------------------------------------
 // Sensor snapshot (read-only to state handlers; written by sensor hub)
@@ -14,8 +20,11 @@ use crate::config::SystemConfig;
 /// A point-in-time snapshot of every sensor in the system.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SensorSnapshot {
-    /// Instantaneous NH3 concentration (ppm).
+    /// Instantaneous NH3 concentration (ppm): the mean of the samples
+    /// taken over the last control interval.
     pub nh3_ppm: f32,
+    /// Highest NH3 sample (ppm) over the last control interval.
+    pub nh3_peak_ppm: f32,
     /// Running-average NH3 concentration (ppm) over the confirmation window.
     pub nh3_avg_ppm: f32,
     /// Raw ADC value from the NH3 sensor (0 – 4095).
@@ -38,6 +47,80 @@ pub struct SensorSnapshot {
 
     /// UVC chamber interlock: true = lid closed (safe to operate).
     pub uvc_interlock_closed: bool,
+
+    /// Sensor-hub uptime (ms) when this snapshot was taken.
+    pub timestamp_ms: u64,
+    /// Hub uptime (ms) of the last successful NH3 conversion.
+    pub nh3_updated_ms: u64,
+    /// Hub uptime (ms) of the last successful temperature conversion.
+    pub temperature_updated_ms: u64,
+}
+
+impl SensorSnapshot {
+    /// Peak NH3 for spike detection.  Never below `nh3_ppm`, so sources
+    /// that do not oversample still trip on the plain reading.
+    pub fn nh3_peak(&self) -> f32 {
+        self.nh3_peak_ppm.max(self.nh3_ppm)
+    }
+
+    /// How long (ms) before this snapshot a channel last updated at
+    /// `updated_ms`.
+    pub fn age_ms(&self, updated_ms: u64) -> u64 {
+        self.timestamp_ms.saturating_sub(updated_ms)
+    }
+}
+
+/// Synthetic NH3 reading that stands in for the sensor for a bounded
+/// window, so a technician can walk the scrub cycle without ammonia.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Nh3Injection {
+    /// Reading reported in place of the sensor (ppm).
+    pub ppm: f32,
+    /// Time left in the window (seconds).
+    pub remaining_secs: f32,
+}
+
+/// One of the two water tanks the source valve can draw from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Tank {
+    #[default]
+    A,
+    B,
+}
+
+impl Tank {
+    /// The opposite tank.
+    #[must_use]
+    pub fn other(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+
+    /// Whether this tank reads as having water in `snapshot`.
+    pub fn is_ok(self, snapshot: &SensorSnapshot) -> bool {
+        match self {
+            Self::A => snapshot.tank_a_ok,
+            Self::B => snapshot.tank_b_ok,
+        }
+    }
+}
+
+/// Pick the source tank for a new scrub cycle.  `Alternate` swaps from
+/// `last` (starting on Tank A); a tank reading low is passed over for
+/// the other, unless both are low.
+pub fn pick_source_tank(mode: TankSource, last: Option<Tank>, snapshot: &SensorSnapshot) -> Tank {
+    let preferred = match mode {
+        TankSource::TankA => Tank::A,
+        TankSource::TankB => Tank::B,
+        TankSource::Alternate => last.map_or(Tank::A, Tank::other),
+    };
+    if !preferred.is_ok(snapshot) && preferred.other().is_ok(snapshot) {
+        preferred.other()
+    } else {
+        preferred
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -52,6 +135,8 @@ pub struct ActuatorCommands {
     pub pump_duty: u8,
     /// Desired pump direction: `true` = forward, `false` = reverse.
     pub pump_forward: bool,
+    /// Tank the source valve draws from.
+    pub source_tank: Tank,
     /// Desired UVC LED duty cycle (0 = off, 1–100 = on).
     pub uvc_duty: u8,
     /// Status LED colour (R, G, B) — each 0–255.
@@ -63,6 +148,7 @@ impl Default for ActuatorCommands {
         Self {
             pump_duty: 0,
             pump_forward: true,
+            source_tank: Tank::A,
             uvc_duty: 0,
             led_rgb: (0, 0, 0), // off
         }
@@ -76,6 +162,80 @@ impl ActuatorCommands {
     }
 }
 
+/// Linear ramp of pump and UVC duty down to what the next state
+/// commands, so leaving a running state does not slam the pump (water
+/// hammer) or the UVC driver off in one step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SoftStop {
+    pump_from: u8,
+    uvc_from: u8,
+    total_steps: u8,
+    remaining: u8,
+    /// Duties the new state commanded, captured on the first step.
+    target: Option<(u8, u8)>,
+}
+
+impl SoftStop {
+    /// Ramp down from the duties in `from` over `steps` ticks (0 = no ramp).
+    pub fn new(from: &ActuatorCommands, steps: u8) -> Self {
+        Self {
+            pump_from: from.pump_duty,
+            uvc_from: from.uvc_duty,
+            total_steps: steps,
+            remaining: steps,
+            target: None,
+        }
+    }
+
+    /// True while the ramp is still above its target.
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Advance one step, holding each duty at the ramp level while that
+    /// is above what the current state commands.
+    pub fn step(&mut self, commands: &mut ActuatorCommands) {
+        if self.remaining == 0 {
+            return;
+        }
+        let (pump_to, uvc_to) = *self
+            .target
+            .get_or_insert((commands.pump_duty, commands.uvc_duty));
+        self.remaining -= 1;
+        let level = |from: u8| {
+            (u16::from(from) * u16::from(self.remaining) / u16::from(self.total_steps)) as u8
+        };
+        commands.pump_duty = pump_to.max(level(self.pump_from));
+        commands.uvc_duty = uvc_to.max(level(self.uvc_from));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// NH3 thresholds
+// ---------------------------------------------------------------------------
+
+/// The config's NH3 thresholds in the control core's [`Num`], converted
+/// once per config change rather than on every comparison.
+#[derive(Debug, Clone, Copy)]
+pub struct Nh3Thresholds {
+    pub activate: Num,
+    pub deactivate: Num,
+    pub urgent: Num,
+    /// Rate-of-rise trigger (ppm/s); zero disables it.
+    pub rate: Num,
+}
+
+impl Nh3Thresholds {
+    pub fn from_config(config: &SystemConfig) -> Self {
+        Self {
+            activate: Num::from_f32(config.nh3_activate_threshold_ppm),
+            deactivate: Num::from_f32(config.nh3_deactivate_threshold_ppm),
+            urgent: Num::from_f32(config.nh3_urgent_threshold_ppm),
+            rate: Num::from_f32(config.nh3_rate_threshold_ppm_per_sec),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // FsmContext
 // ---------------------------------------------------------------------------
@@ -87,44 +247,233 @@ pub struct FsmContext {
     pub ticks_in_state: u64,
     /// Monotonic total tick count.
     pub total_ticks: u64,
-    /// Duration of one tick in seconds (inverse of control loop frequency).
+    /// Nominal duration of one tick in seconds (inverse of control loop
+    /// frequency); the first `tick_dt_secs` until one is measured.
     pub tick_period_secs: f32,
+    /// Measured time since the previous tick (seconds).  Per-tick
+    /// integrations and every timer use this rather than the nominal
+    /// period.
+    pub tick_dt_secs: f32,
+    /// Seconds since the FSM started ticking, summed from `tick_dt_secs`.
+    pub elapsed_secs: f64,
+    /// `elapsed_secs` when the current state was entered.
+    pub state_entry_secs: f64,
 
     // -- Sensor data --
     /// Latest sensor readings.  Updated before each FSM tick.
     pub sensors: SensorSnapshot,
+    /// Instantaneous NH3 at the start of the current rate window, if any.
+    pub prev_nh3_ppm: Option<f32>,
+    /// Measured time since `prev_nh3_ppm` was taken (seconds).
+    pub nh3_rate_window_secs: f32,
+    /// NH3 rate of change over the last completed rate window of at
+    /// least [`MIN_NH3_RATE_WINDOW_SECS`] (ppm/s).
+    pub nh3_rate_ppm_per_sec: f32,
+    /// True when Sensing was entered on NH3 rate of rise rather than level;
+    /// the reading gets the full confirmation window to reach threshold.
+    pub sensing_on_rate: bool,
+    /// Uptime (seconds) before which Idle will not start Sensing again,
+    /// set when the state watchdog takes Sensing out; an urgent spike
+    /// still activates.
+    pub sensing_holdoff_until_secs: f32,
+    /// True during the post-boot NH3 sensor soak; Idle ignores NH3
+    /// triggers until `nh3_warmup_secs` of uptime have passed.
+    pub sensor_warming: bool,
+    /// Active NH3 injection; overrides the sensor's NH3 fields until
+    /// the window runs out.
+    pub nh3_injection: Option<Nh3Injection>,
 
     // -- Actuator outputs --
     /// Commands to be applied to actuators after the FSM tick.
     pub commands: ActuatorCommands,
 
     // -- Configuration --
-    /// System configuration (tunable parameters).
+    /// System configuration (tunable parameters).  Replace it with
+    /// [`set_config`](Self::set_config) so the thresholds follow.
     pub config: SystemConfig,
+    /// `config`'s NH3 thresholds, pre-converted for the state handlers.
+    pub nh3_thresholds: Nh3Thresholds,
 
     // -- Safety --
     /// Accumulated safety fault bitmask (see `SafetyFault::mask()`).
     /// Set by the safety supervisor, read by state handlers.
     pub fault_flags: u8,
+    /// Set when the boot self-test found a critical sensor faulty and
+    /// config forbids scrubbing without it; blocks entry to Active.
+    pub active_inhibited: bool,
+    /// Uptime (seconds) before which Active will not be entered, set
+    /// when the Active timeout recurred.
+    pub active_holdoff_until_secs: f32,
+    /// Fault-free time spent in Error (seconds); recovery to Idle waits
+    /// for `fault_recovery_cooldown_secs` of it.
+    pub fault_clear_secs: f32,
+    /// True while in Error with all faults clear, waiting out the
+    /// recovery cooldown.
+    pub recovering: bool,
+
+    // -- Scrub duration --
+    /// Ticks spent in Active since the system last rested in Idle.
+    /// Survives Purging → Active bounces so a stuck-high NH3 reading
+    /// cannot reset the clock.
+    pub active_ticks: u64,
+    /// Seconds spent in Active since the system last rested in Idle,
+    /// integrated from `tick_dt_secs` alongside `active_ticks`.
+    pub active_total_secs: f32,
+    /// `elapsed_secs` of the last Active timeout, if any.
+    pub last_active_timeout_secs: Option<f64>,
+    /// Seconds since Active was last entered, integrated from
+    /// `tick_dt_secs`.  Unlike `active_total_secs` this restarts on every
+    /// entry, so each Purging → Active bounce serves `min_active_secs`.
+    pub active_run_secs: f32,
+
+    // -- UVC --
+    /// True while the UVC is energised but has not yet run for
+    /// `uvc_warmup_secs`; disinfection is not yet effective.
+    pub uvc_warming: bool,
+
+    // -- Pump priming --
+    /// `elapsed_secs` when the pump was last commanded on; `None` until
+    /// it first runs after boot.
+    pub last_pump_secs: Option<f64>,
+    /// True during the low-duty priming phase at the start of Active.
+    pub priming: bool,
+    /// True while pump duty ramps from the priming duty to full duty.
+    pub pump_ramping: bool,
+    /// Ramp-down started when Active or Purging exits; Error cancels it.
+    pub soft_stop: SoftStop,
+    /// Tank picked for the current or most recent scrub cycle (None
+    /// before the first).
+    pub source_tank: Option<Tank>,
+    /// Non-fatal advisory bitmask (see `Advisory::mask()`).
+    pub advisory_flags: u8,
+
+    // -- Purge --
+    /// Water pumped since Purging was entered (mL), integrated from
+    /// `flow_ml_per_min`.  Holds its final value until the next purge.
+    pub purge_volume_ml: f32,
+
+    // -- Drain --
+    /// Water pumped out since Draining was entered (mL).  Holds its final
+    /// value until the next drain.
+    pub drain_volume_ml: f32,
+    /// True once the last drain finished with both tanks reading empty;
+    /// false while draining or when it stopped on the cap or a fault.
+    pub drain_complete: bool,
 }
 
 impl FsmContext {
     /// Create a new context with the given configuration.
     pub fn new(config: SystemConfig) -> Self {
+        let tick_period_secs = config.power_timing().control_loop_interval_ms as f32 / 1000.0;
         Self {
             ticks_in_state: 0,
             total_ticks: 0,
-            tick_period_secs: config.control_loop_interval_ms as f32 / 1000.0,
+            tick_period_secs,
+            tick_dt_secs: tick_period_secs,
+            elapsed_secs: 0.0,
+            state_entry_secs: 0.0,
             sensors: SensorSnapshot::default(),
+            prev_nh3_ppm: None,
+            nh3_rate_window_secs: 0.0,
+            nh3_rate_ppm_per_sec: 0.0,
+            sensing_on_rate: false,
+            sensing_holdoff_until_secs: 0.0,
+            sensor_warming: false,
+            nh3_injection: None,
             commands: ActuatorCommands::all_off(),
+            nh3_thresholds: Nh3Thresholds::from_config(&config),
             config,
             fault_flags: 0,
+            active_inhibited: false,
+            active_holdoff_until_secs: 0.0,
+            fault_clear_secs: 0.0,
+            recovering: false,
+            active_ticks: 0,
+            active_total_secs: 0.0,
+            last_active_timeout_secs: None,
+            active_run_secs: 0.0,
+            uvc_warming: false,
+            last_pump_secs: None,
+            priming: false,
+            pump_ramping: false,
+            soft_stop: SoftStop::default(),
+            source_tank: None,
+            advisory_flags: 0,
+            purge_volume_ml: 0.0,
+            drain_volume_ml: 0.0,
+            drain_complete: false,
         }
     }
 
+    /// Replace the configuration and re-derive the NH3 thresholds.
+    pub fn set_config(&mut self, config: SystemConfig) {
+        self.nh3_thresholds = Nh3Thresholds::from_config(&config);
+        self.config = config;
+    }
+
+    /// Install a fresh sensor snapshot and update the NH3 rate of change.
+    /// The rate is taken over the measured time since the window began,
+    /// once that reaches [`MIN_NH3_RATE_WINDOW_SECS`]; until then the
+    /// last rate stands.
+    /// While an [`Nh3Injection`] is active its reading replaces the
+    /// snapshot's NH3 fields and its window counts down.
+    pub fn update_sensors(&mut self, mut snapshot: SensorSnapshot) {
+        if let Some(injection) = self.nh3_injection.as_mut() {
+            snapshot.nh3_ppm = injection.ppm;
+            snapshot.nh3_peak_ppm = injection.ppm;
+            snapshot.nh3_avg_ppm = injection.ppm;
+            injection.remaining_secs -= self.tick_dt_secs;
+            if injection.remaining_secs <= 0.0 {
+                self.nh3_injection = None;
+            }
+        }
+        let nh3 = snapshot.nh3_ppm;
+        if let Some(prev) = self.prev_nh3_ppm {
+            self.nh3_rate_window_secs += self.tick_dt_secs.max(0.0);
+            let window = self.nh3_rate_window_secs;
+            if window >= MIN_NH3_RATE_WINDOW_SECS {
+                self.nh3_rate_ppm_per_sec = (nh3 - prev) / window;
+                self.prev_nh3_ppm = Some(nh3);
+                self.nh3_rate_window_secs = 0.0;
+            }
+        } else {
+            self.prev_nh3_ppm = Some(nh3);
+            self.nh3_rate_window_secs = 0.0;
+        }
+        self.sensors = snapshot;
+    }
+
+    /// Start ramping pump and UVC down from their current duties over
+    /// `soft_stop_ticks`.  Called from the exit handlers of running states.
+    pub fn begin_soft_stop(&mut self) {
+        self.soft_stop = SoftStop::new(&self.commands, self.config.soft_stop_ticks);
+    }
+
+    /// Seconds since the FSM started ticking.
+    pub fn uptime_secs(&self) -> f32 {
+        self.elapsed_secs as f32
+    }
+
+    /// Whether entry to Active is blocked, by the boot self-test or by
+    /// the hold-off after a recurring Active timeout.
+    pub fn activation_blocked(&self) -> bool {
+        self.active_inhibited || self.uptime_secs() < self.active_holdoff_until_secs
+    }
+
+    /// Seconds since the pump was last commanded on; `None` if it has
+    /// not run since boot.
+    pub fn secs_since_pump(&self) -> Option<f32> {
+        self.last_pump_secs.map(|t| (self.elapsed_secs - t) as f32)
+    }
+
+    /// Accumulated Active time (seconds) since the last Idle.
+    pub fn active_secs(&self) -> f32 {
+        self.active_total_secs
+    }
+
     /// Seconds elapsed since the current state was entered.
     pub fn secs_in_state(&self) -> f32 {
-        self.ticks_in_state as f32 * self.tick_period_secs
+        (self.elapsed_secs - self.state_entry_secs) as f32
     }
 
     /// Returns `true` if **any** safety fault is active.
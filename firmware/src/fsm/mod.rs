@@ -13,6 +13,7 @@
 //! │  │ Active  │ fn(ctx)   │ fn(ctx)  │ fn(ctx)->Option<> │   │
 //! │  │ Purging │ fn(ctx)   │ fn(ctx)  │ fn(ctx)->Option<> │   │
 //! │  │ Error   │ fn(ctx)   │ fn(ctx)  │ fn(ctx)->Option<> │   │
+//! │  │ Draining│ fn(ctx)   │ fn(ctx)  │ fn(ctx)->Option<> │   │
 //! │  └────────┴───────────┴──────────┴───────────────────┘   │
 //! └──────────────────────────────────────────────────────────┘
 //! ```
@@ -22,12 +23,18 @@
 //! current state, then `on_enter` for the next, and updates the
 //! current pointer.  All functions receive `&mut FsmContext` which
 //! holds sensor readings, actuator handles, config, and timing.
+//!
+//! A state that stays put longer than its `state_max_secs` entry in
+//! the config is taken out by the engine's watchdog along its
+//! [`StateId::timeout_escape`] transition, whatever `on_update` says.
+//! After Sensing is taken out, Idle waits another `state_max_secs`
+//! before it may start Sensing again.
 
 pub mod context;
 pub mod states;
 
 use context::FsmContext;
-use log::info;
+use log::{info, warn};
 
 // ---------------------------------------------------------------------------
 // State identity
@@ -43,11 +50,13 @@ pub enum StateId {
     Active = 2,
     Purging = 3,
     Error = 4,
+    /// Maintenance drain of the reservoir; only entered by command.
+    Draining = 5,
 }
 
 impl StateId {
     /// Total number of states — used to size the table array.
-    pub const COUNT: usize = 5;
+    pub const COUNT: usize = 6;
 
     /// Convert a `u8` index back to `StateId`.  Panics on out-of-range in
     /// debug builds; returns `Error` in release (safe fallback).
@@ -58,12 +67,25 @@ impl StateId {
             2 => Self::Active,
             3 => Self::Purging,
             4 => Self::Error,
+            5 => Self::Draining,
             _ => {
                 debug_assert!(false, "invalid state index: {idx}");
                 Self::Error
             }
         }
     }
+
+    /// Where the state watchdog sends this state when it overstays its
+    /// configured maximum.  `None` for Idle, the rest state, and Error,
+    /// which may only be left once the faults clear.
+    pub fn timeout_escape(self) -> Option<StateId> {
+        match self {
+            Self::Sensing | Self::Purging | Self::Draining => Some(Self::Idle),
+            // Purge what was pumped rather than stop dead.
+            Self::Active => Some(Self::Purging),
+            Self::Idle | Self::Error => None,
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -133,20 +155,26 @@ impl Fsm {
 
     /// Advance the FSM by one tick.
     ///
-    /// 1. Call `on_update` for the current state.
-    /// 2. If it returns `Some(next)`, execute the transition:
+    /// 1. Call `on_update` for the current state; if it stays put past
+    ///    its `state_max_secs`, take its escape transition instead.
+    /// 2. If there is a next state, execute the transition:
     ///    `on_exit(current)` → update pointer → `on_enter(next)`.
     /// 3. Increment tick counter.
     pub fn tick(&mut self, ctx: &mut FsmContext) {
-        self.tick_count += 1;
-        ctx.ticks_in_state = self.tick_count - self.state_entry_tick;
+        self.tick_count = self.tick_count.wrapping_add(1);
+        ctx.ticks_in_state = self.ticks_in_current_state();
         ctx.total_ticks = self.tick_count;
+        ctx.elapsed_secs += f64::from(ctx.tick_dt_secs);
 
-        let next = (self.table[self.current].on_update)(ctx);
+        let next = (self.table[self.current].on_update)(ctx).or_else(|| self.state_timeout(ctx));
 
         if let Some(next_id) = next {
             self.transition(next_id, ctx);
+        } else {
+            ctx.soft_stop.step(&mut ctx.commands);
         }
+
+        self.note_pump_activity(ctx);
     }
 
     /// Force an immediate transition (used by the safety supervisor to
@@ -162,9 +190,17 @@ impl Fsm {
         StateId::from_index(self.current)
     }
 
+    /// Display name of the current state, from its descriptor.
+    pub fn current_state_name(&self) -> &'static str {
+        self.table[self.current].name
+    }
+
     /// How many ticks the FSM has been in the current state.
+    ///
+    /// Modular difference, so it stays correct if `tick_count` wraps
+    /// after the state was entered.
     pub fn ticks_in_current_state(&self) -> u64 {
-        self.tick_count - self.state_entry_tick
+        self.tick_count.wrapping_sub(self.state_entry_tick)
     }
 
     // -----------------------------------------------------------------------
@@ -179,6 +215,9 @@ impl Fsm {
             self.table[self.current].name, self.table[next_idx].name
         );
 
+        // The outgoing state may have been pumping right up to now.
+        self.note_pump_activity(ctx);
+
         // Exit current state
         if let Some(exit) = self.table[self.current].on_exit {
             exit(ctx);
@@ -188,11 +227,41 @@ impl Fsm {
         self.current = next_idx;
         self.state_entry_tick = self.tick_count;
         ctx.ticks_in_state = 0;
+        ctx.state_entry_secs = ctx.elapsed_secs;
 
         // Enter new state
         if let Some(enter) = self.table[self.current].on_enter {
             enter(ctx);
         }
+
+        // First soft-stop step against what the new state commanded.
+        ctx.soft_stop.step(&mut ctx.commands);
+    }
+
+    /// The escape transition if the current state has outlived its
+    /// configured maximum.
+    fn state_timeout(&self, ctx: &mut FsmContext) -> Option<StateId> {
+        let max_secs = ctx.config.state_max_secs[self.current];
+        if max_secs == 0 || ctx.secs_in_state() < max_secs as f32 {
+            return None;
+        }
+        let escape = self.current_state().timeout_escape()?;
+        warn!(
+            "FSM watchdog: {} exceeded {}s -> {}",
+            self.table[self.current].name, max_secs, self.table[escape as usize].name
+        );
+        if self.current_state() == StateId::Sensing {
+            ctx.sensing_holdoff_until_secs = ctx.uptime_secs() + max_secs as f32;
+        }
+        Some(escape)
+    }
+
+    /// Record the pump as active now if it is commanded on, for the
+    /// Active state's long-idle priming check.
+    fn note_pump_activity(&self, ctx: &mut FsmContext) {
+        if ctx.commands.pump_duty > 0 {
+            ctx.last_pump_secs = Some(ctx.elapsed_secs);
+        }
     }
 }
 
@@ -210,6 +279,26 @@ mod tests {
         Fsm::new(states::build_state_table(), StateId::Idle)
     }
 
+    #[test]
+    fn ticks_in_state_survive_counter_wrap() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+
+        fsm.tick_count = u64::MAX - 2;
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        assert_eq!(fsm.ticks_in_current_state(), 0);
+
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        for expected in 1..=5 {
+            fsm.tick(&mut ctx);
+            assert_eq!(fsm.ticks_in_current_state(), expected);
+            assert_eq!(ctx.ticks_in_state, expected);
+        }
+        assert!(fsm.tick_count < 3, "counter did not wrap");
+        assert_eq!(fsm.current_state(), StateId::Purging);
+    }
+
     #[test]
     fn starts_in_idle() {
         let fsm = make_fsm();
@@ -235,6 +324,20 @@ mod tests {
         assert_eq!(fsm.ticks_in_current_state(), 2);
     }
 
+    #[test]
+    fn state_timers_sum_measured_tick_time() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        for dt in [0.25, 1.75, 0.5] {
+            ctx.tick_dt_secs = dt;
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(ctx.ticks_in_state, 3);
+        assert!((ctx.secs_in_state() - 2.5).abs() < 1e-6);
+        assert!((ctx.uptime_secs() - 2.5).abs() < 1e-6);
+    }
+
     #[test]
     fn idle_to_sensing_on_nh3_spike() {
         let mut fsm = make_fsm();
@@ -314,109 +417,1030 @@ mod tests {
     }
 
     #[test]
-    fn active_to_purging_on_nh3_drop() {
+    fn urgent_spike_reaches_active_in_one_tick() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
         fsm.start(&mut ctx);
-        fsm.force_transition(StateId::Active, &mut ctx);
-        assert_eq!(fsm.current_state(), StateId::Active);
 
-        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        let urgent = ctx.config.nh3_urgent_threshold_ppm;
+        ctx.sensors.nh3_ppm = urgent + 1.0;
+        ctx.sensors.nh3_avg_ppm = urgent + 1.0;
         fsm.tick(&mut ctx);
-        assert_eq!(fsm.current_state(), StateId::Purging);
+        assert_eq!(fsm.current_state(), StateId::Active);
     }
 
     #[test]
-    fn purging_to_idle_after_duration() {
+    fn oversampled_peak_between_ticks_triggers_urgent_path() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
         fsm.start(&mut ctx);
-        fsm.force_transition(StateId::Purging, &mut ctx);
 
-        ctx.sensors.nh3_avg_ppm = 0.0;
+        // A brief spike pulls the interval mean only slightly above
+        // baseline, but the oversampled peak crosses the urgent line.
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm - 1.0;
+        ctx.sensors.nh3_peak_ppm = ctx.config.nh3_urgent_threshold_ppm + 5.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    /// Feed an NH3 reading (instantaneous and average) through the
+    /// snapshot path so the rate of change is tracked.
+    fn feed_nh3(fsm: &mut Fsm, ctx: &mut FsmContext, ppm: f32) {
+        let mut snapshot = ctx.sensors;
+        snapshot.nh3_ppm = ppm;
+        snapshot.nh3_avg_ppm = ppm;
+        ctx.update_sensors(snapshot);
+        fsm.tick(ctx);
+    }
+
+    /// Context with the rate-of-rise trigger armed at 2 ppm/s.
+    fn rate_ctx() -> FsmContext {
+        FsmContext::new(SystemConfig {
+            nh3_rate_threshold_ppm_per_sec: 2.0,
+            ..SystemConfig::default()
+        })
+    }
+
+    #[test]
+    fn fast_nh3_rise_below_threshold_triggers_sensing() {
+        let mut fsm = make_fsm();
+        let mut ctx = rate_ctx();
+        fsm.start(&mut ctx);
+
+        let level = ctx.config.nh3_activate_threshold_ppm - 2.0;
+        feed_nh3(&mut fsm, &mut ctx, 0.0);
+        feed_nh3(&mut fsm, &mut ctx, level);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+        assert!(ctx.sensing_on_rate);
+
+        // Still below threshold — the confirmation window holds Sensing.
+        feed_nh3(&mut fsm, &mut ctx, level);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+    }
+
+    #[test]
+    fn nh3_rate_ignores_jitter_between_closely_spaced_ticks() {
+        let mut fsm = make_fsm();
+        let mut ctx = rate_ctx();
+        fsm.start(&mut ctx);
+        ctx.tick_dt_secs = 0.01;
+
+        // 0.5 ppm of noise 10 ms apart would read as 50 ppm/s.
+        feed_nh3(&mut fsm, &mut ctx, 0.0);
+        feed_nh3(&mut fsm, &mut ctx, 0.5);
+        assert!(ctx.nh3_rate_ppm_per_sec.abs() < f32::EPSILON);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+
+        // Measured over the full window it is the slow drift it is.
+        for _ in 0..100 {
+            feed_nh3(&mut fsm, &mut ctx, 0.5);
+        }
+        assert!(ctx.nh3_rate_ppm_per_sec < 1.0);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    #[test]
+    fn slow_nh3_rise_of_same_magnitude_stays_idle() {
+        let mut fsm = make_fsm();
+        let mut ctx = rate_ctx();
+        fsm.start(&mut ctx);
+
+        let level = ctx.config.nh3_activate_threshold_ppm - 2.0;
+        for step in 0..=8 {
+            feed_nh3(&mut fsm, &mut ctx, level * step as f32 / 8.0);
+            assert_eq!(fsm.current_state(), StateId::Idle, "step {step}");
+        }
+    }
+
+    #[test]
+    fn rate_triggered_sensing_returns_idle_if_level_never_arrives() {
+        let mut fsm = make_fsm();
+        let mut ctx = rate_ctx();
+        fsm.start(&mut ctx);
+
+        let level = ctx.config.nh3_activate_threshold_ppm - 2.0;
+        feed_nh3(&mut fsm, &mut ctx, 0.0);
+        feed_nh3(&mut fsm, &mut ctx, level);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+
         let ticks_needed =
-            (ctx.config.purge_duration_secs as f32 / ctx.tick_period_secs) as u64 + 1;
+            (ctx.config.nh3_confirm_duration_secs as f32 / ctx.tick_period_secs) as u64 + 1;
         for _ in 0..ticks_needed {
-            fsm.tick(&mut ctx);
+            feed_nh3(&mut fsm, &mut ctx, level);
         }
         assert_eq!(fsm.current_state(), StateId::Idle);
     }
 
     #[test]
-    fn purging_returns_to_active_on_re_elevation() {
+    fn rate_trigger_off_by_default() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
         fsm.start(&mut ctx);
-        fsm.force_transition(StateId::Purging, &mut ctx);
 
-        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        let level = ctx.config.nh3_activate_threshold_ppm - 0.5;
+        feed_nh3(&mut fsm, &mut ctx, 0.0);
+        feed_nh3(&mut fsm, &mut ctx, level);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    #[test]
+    fn maintenance_mode_ignores_nh3_spikes() {
+        let mut fsm = make_fsm();
+        let mut ctx = rate_ctx();
+        ctx.config.maintenance_mode = true;
+        fsm.start(&mut ctx);
+
+        let spike = ctx.config.nh3_urgent_threshold_ppm + 10.0;
+        feed_nh3(&mut fsm, &mut ctx, 0.0);
+        feed_nh3(&mut fsm, &mut ctx, spike);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    #[test]
+    fn maintenance_mode_still_honours_faults() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        ctx.config.maintenance_mode = true;
+        fsm.start(&mut ctx);
+
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
         fsm.tick(&mut ctx);
-        assert_eq!(fsm.current_state(), StateId::Active);
+        assert_eq!(fsm.current_state(), StateId::Error);
     }
 
     #[test]
-    fn fault_sends_any_state_to_error() {
-        for start_state in [
-            StateId::Idle,
-            StateId::Sensing,
-            StateId::Active,
-            StateId::Purging,
-        ] {
-            let mut fsm = make_fsm();
-            let mut ctx = make_ctx();
-            fsm.start(&mut ctx);
-            if start_state != StateId::Idle {
-                fsm.force_transition(start_state, &mut ctx);
-            }
+    fn maintenance_mode_winds_a_running_scrub_down_to_idle() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.nh3_avg_ppm = ctx.sensors.nh3_ppm;
+        fsm.force_transition(StateId::Active, &mut ctx);
 
-            ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        ctx.config.maintenance_mode = true;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+
+        ctx.config.maintenance_mode = false;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+    }
+
+    #[test]
+    fn nh3_warmup_suppresses_triggers_until_soak_ends() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        ctx.config.nh3_warmup_secs = 10;
+        ctx.sensor_warming = true;
+        fsm.start(&mut ctx);
+
+        ctx.sensors.nh3_ppm = ctx.config.nh3_urgent_threshold_ppm + 10.0;
+        ctx.sensors.nh3_avg_ppm = ctx.sensors.nh3_ppm;
+        let soak_ticks = (10.0 / ctx.tick_period_secs) as u64;
+        for tick in 1..soak_ticks {
             fsm.tick(&mut ctx);
-            assert_eq!(
-                fsm.current_state(),
-                StateId::Error,
-                "Expected Error from {:?}",
-                start_state
-            );
+            assert_eq!(fsm.current_state(), StateId::Idle, "triggered at tick {tick}");
+            assert!(ctx.sensor_warming);
         }
+
+        fsm.tick(&mut ctx);
+        assert!(!ctx.sensor_warming);
+        assert_eq!(fsm.current_state(), StateId::Active);
     }
 
     #[test]
-    fn error_kills_all_actuators() {
+    fn nh3_warmup_still_honours_faults() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
+        ctx.config.nh3_warmup_secs = 60;
+        ctx.sensor_warming = true;
         fsm.start(&mut ctx);
-        ctx.commands.pump_duty = 100;
-        ctx.commands.uvc_duty = 100;
-        fsm.force_transition(StateId::Error, &mut ctx);
-        assert_eq!(ctx.commands.pump_duty, 0);
-        assert_eq!(ctx.commands.uvc_duty, 0);
+
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Error);
     }
 
     #[test]
-    fn error_to_idle_when_faults_clear() {
+    fn sub_urgent_rise_goes_through_sensing() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
         fsm.start(&mut ctx);
-        fsm.force_transition(StateId::Error, &mut ctx);
-        assert_eq!(fsm.current_state(), StateId::Error);
 
-        ctx.fault_flags = 0;
+        let urgent = ctx.config.nh3_urgent_threshold_ppm;
+        ctx.sensors.nh3_ppm = urgent - 1.0;
+        ctx.sensors.nh3_avg_ppm = urgent - 1.0;
         fsm.tick(&mut ctx);
-        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
     }
 
     #[test]
-    fn error_stays_while_faults_active() {
+    fn urgent_spike_during_sensing_activates() {
         let mut fsm = make_fsm();
         let mut ctx = make_ctx();
         fsm.start(&mut ctx);
-        fsm.force_transition(StateId::Error, &mut ctx);
 
-        ctx.fault_flags = crate::error::SafetyFault::WaterLevelLow.mask();
-        for _ in 0..10 {
+        let activate = ctx.config.nh3_activate_threshold_ppm;
+        ctx.sensors.nh3_ppm = activate + 1.0;
+        ctx.sensors.nh3_avg_ppm = activate + 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+
+        ctx.sensors.nh3_ppm = ctx.config.nh3_urgent_threshold_ppm + 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    #[test]
+    fn urgent_spike_respects_self_test_inhibit() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        ctx.active_inhibited = true;
+        fsm.start(&mut ctx);
+
+        ctx.sensors.nh3_ppm = ctx.config.nh3_urgent_threshold_ppm + 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    /// Enter Active on a line that is already primed.
+    fn make_active(ctx: &mut FsmContext) -> Fsm {
+        let mut fsm = make_fsm();
+        fsm.start(ctx);
+        ctx.last_pump_secs = Some(ctx.elapsed_secs);
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        fsm.force_transition(StateId::Active, ctx);
+        fsm
+    }
+
+    #[test]
+    fn uvc_warming_clears_after_configured_ticks() {
+        let mut ctx = FsmContext::new(SystemConfig {
+            uvc_warmup_secs: 3,
+            control_loop_interval_ms: 500,
+            ..Default::default()
+        });
+        let mut fsm = make_active(&mut ctx);
+
+        // Pump and UVC run from the first tick; only the flag waits.
+        assert!(ctx.uvc_warming);
+        assert!(ctx.commands.pump_duty > 0);
+        assert!(ctx.commands.uvc_duty > 0);
+
+        for tick in 1..6 {
             fsm.tick(&mut ctx);
+            assert!(ctx.uvc_warming, "cleared early at tick {}", tick);
         }
-        assert_eq!(fsm.current_state(), StateId::Error);
+        fsm.tick(&mut ctx);
+        assert!(!ctx.uvc_warming);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    #[test]
+    fn zero_uvc_warmup_is_effective_immediately() {
+        let mut ctx = FsmContext::new(SystemConfig {
+            uvc_warmup_secs: 0,
+            ..Default::default()
+        });
+        make_active(&mut ctx);
+        assert!(!ctx.uvc_warming);
+    }
+
+    #[test]
+    fn leaving_active_clears_uvc_warming() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_active(&mut ctx);
+        assert!(ctx.uvc_warming);
+
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Purging);
+        assert!(!ctx.uvc_warming);
+    }
+
+    /// Priming config, with the pump last run at boot.
+    fn priming_ctx() -> FsmContext {
+        let mut ctx = FsmContext::new(SystemConfig {
+            prime_after_idle_secs: 10,
+            prime_duration_secs: 2,
+            prime_duty_percent: 30,
+            pump_duty_percent: 70,
+            ..Default::default()
+        });
+        ctx.last_pump_secs = Some(0.0);
+        ctx
+    }
+
+    /// Start in Idle, sit there for `idle_ticks`, then enter Active.
+    fn activate_after_idle(ctx: &mut FsmContext, idle_ticks: u32) -> Fsm {
+        let mut fsm = make_fsm();
+        fsm.start(ctx);
+        for _ in 0..idle_ticks {
+            fsm.tick(ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.flow_detected = true;
+        fsm.force_transition(StateId::Active, ctx);
+        fsm
+    }
+
+    #[test]
+    fn no_priming_before_idle_threshold() {
+        let mut ctx = priming_ctx();
+        activate_after_idle(&mut ctx, 5);
+        assert!(!ctx.priming);
+        assert_eq!(ctx.commands.pump_duty, 70);
+    }
+
+    #[test]
+    fn priming_after_long_idle_ramps_to_full_duty() {
+        let mut ctx = priming_ctx();
+        let mut fsm = activate_after_idle(&mut ctx, 10);
+        assert!(ctx.priming);
+        assert_eq!(ctx.commands.pump_duty, 30);
+
+        fsm.tick(&mut ctx);
+        assert!(ctx.priming, "priming ended early");
+        assert_eq!(ctx.commands.pump_duty, 30);
+
+        let mut last = ctx.commands.pump_duty;
+        for _ in 0..4 {
+            fsm.tick(&mut ctx);
+            assert!(!ctx.priming);
+            assert!(ctx.commands.pump_duty >= last, "ramp went backwards");
+            last = ctx.commands.pump_duty;
+        }
+        assert!(!ctx.pump_ramping);
+        assert_eq!(ctx.commands.pump_duty, 70);
+        assert_eq!(ctx.advisory_flags, 0);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    #[test]
+    fn priming_without_flow_raises_advisory() {
+        let mut ctx = priming_ctx();
+        let mut fsm = activate_after_idle(&mut ctx, 10);
+        ctx.sensors.flow_detected = false;
+
+        fsm.tick(&mut ctx);
+        fsm.tick(&mut ctx);
+        assert!(!ctx.priming);
+        assert_eq!(
+            ctx.advisory_flags,
+            crate::error::Advisory::NoFlowWhilePumping.mask()
+        );
+        assert_eq!(fsm.current_state(), StateId::Active);
+
+        ctx.sensors.flow_detected = true;
+        fsm.tick(&mut ctx);
+        assert_eq!(ctx.advisory_flags, 0);
+    }
+
+    #[test]
+    fn first_activation_after_boot_primes() {
+        let mut ctx = priming_ctx();
+        ctx.last_pump_secs = None;
+        activate_after_idle(&mut ctx, 0);
+        assert!(ctx.priming);
+        assert_eq!(ctx.commands.pump_duty, 30);
+    }
+
+    #[test]
+    fn recent_pump_activity_skips_priming() {
+        let mut ctx = priming_ctx();
+        let mut fsm = activate_after_idle(&mut ctx, 10);
+        assert!(ctx.priming);
+
+        // Scrub, purge briefly, then re-activate: the line is still wet.
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        fsm.tick(&mut ctx);
+        fsm.force_transition(StateId::Active, &mut ctx);
+        assert!(!ctx.priming);
+        assert_eq!(ctx.commands.pump_duty, 70);
+    }
+
+    fn timeout_ctx() -> FsmContext {
+        FsmContext::new(SystemConfig {
+            max_active_duration_secs: 300,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn active_times_out_to_purging_with_nh3_stuck_high() {
+        let mut ctx = timeout_ctx();
+        let mut fsm = make_active(&mut ctx);
+
+        for _ in 0..299 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Active);
+        assert_eq!(ctx.advisory_flags, 0);
+
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Purging);
+        assert_eq!(
+            ctx.advisory_flags,
+            crate::error::Advisory::ActiveTimeout.mask()
+        );
+    }
+
+    #[test]
+    fn repeated_active_timeout_goes_to_error_and_inhibits() {
+        let mut ctx = timeout_ctx();
+        let mut fsm = make_active(&mut ctx);
+        for _ in 0..300 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Purging);
+
+        // NH3 is still high: purge bounces straight back to Active, whose
+        // accumulated time is already over the limit.
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Error);
+        assert!(ctx.activation_blocked());
+        assert_eq!(ctx.commands.pump_duty, 0);
+
+        for _ in 0..ctx.config.fault_recovery_cooldown_secs {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Idle);
+
+        // Held off with NH3 still high, then free to try again rather
+        // than disabled until reboot.
+        assert!(ctx.activation_blocked());
+        while ctx.activation_blocked() {
+            fsm.tick(&mut ctx);
+            // The tick that ends the hold-off may already leave Idle.
+            if ctx.activation_blocked() {
+                assert_eq!(fsm.current_state(), StateId::Idle);
+            }
+        }
+        assert!(ctx.uptime_secs() >= states::ACTIVE_TIMEOUT_HOLDOFF_SECS);
+        fsm.tick(&mut ctx);
+        assert_ne!(fsm.current_state(), StateId::Idle);
+    }
+
+    #[test]
+    fn active_to_purging_on_nh3_drop() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Active, &mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Purging);
+    }
+
+    fn min_active_ctx() -> (Fsm, FsmContext) {
+        let mut fsm = make_fsm();
+        let mut ctx = FsmContext::new(SystemConfig {
+            min_active_secs: 30,
+            ..Default::default()
+        });
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Active, &mut ctx);
+        (fsm, ctx)
+    }
+
+    #[test]
+    fn early_nh3_drop_holds_active_until_minimum_runtime() {
+        let (mut fsm, mut ctx) = min_active_ctx();
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        for _ in 0..29 {
+            fsm.tick(&mut ctx);
+            assert_eq!(fsm.current_state(), StateId::Active);
+        }
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Purging);
+    }
+
+    #[test]
+    fn minimum_runtime_restarts_on_re_entry_from_purging() {
+        let (mut fsm, mut ctx) = min_active_ctx();
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        for _ in 0..30 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Purging);
+
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_deactivate_threshold_ppm - 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+        assert!((ctx.active_run_secs - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fault_ends_active_inside_minimum_runtime() {
+        let (mut fsm, mut ctx) = min_active_ctx();
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Error);
+    }
+
+    #[test]
+    fn purging_to_idle_after_duration() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        let ticks_needed =
+            (ctx.config.purge_duration_secs as f32 / ctx.tick_period_secs) as u64 + 1;
+        for _ in 0..ticks_needed {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    fn volume_purge_ctx() -> FsmContext {
+        FsmContext::new(SystemConfig {
+            purge_mode: crate::config::PurgeMode::Volume,
+            purge_target_volume_ml: 100,
+            purge_max_duration_secs: 30,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn volume_purge_exits_at_target_volume() {
+        let mut fsm = make_fsm();
+        let mut ctx = volume_purge_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        // 600 mL/min at 1 Hz = 10 mL per tick → 100 mL after 10 ticks.
+        ctx.sensors.flow_ml_per_min = 600.0;
+
+        for _ in 0..9 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Purging);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert!((ctx.purge_volume_ml - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn volume_purge_respects_duration_cap_without_flow() {
+        let mut fsm = make_fsm();
+        let mut ctx = volume_purge_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        ctx.sensors.flow_ml_per_min = 0.0;
+
+        let cap_ticks = (ctx.config.purge_max_duration_secs as f32 / ctx.tick_period_secs) as u64;
+        for _ in 0..cap_ticks - 1 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Purging);
+        fsm.tick(&mut ctx);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert_eq!(ctx.purge_volume_ml.to_bits(), 0.0f32.to_bits());
+    }
+
+    #[test]
+    fn volume_purge_ignores_fixed_duration() {
+        let mut fsm = make_fsm();
+        let mut ctx = FsmContext::new(SystemConfig {
+            purge_mode: crate::config::PurgeMode::Volume,
+            purge_duration_secs: 10,
+            purge_target_volume_ml: 1000,
+            purge_max_duration_secs: 600,
+            ..Default::default()
+        });
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        ctx.sensors.flow_ml_per_min = 60.0; // 1 mL per tick
+
+        for _ in 0..20 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Purging);
+    }
+
+    #[test]
+    fn source_tank_pick_honours_mode_and_skips_low_tank() {
+        use super::context::{SensorSnapshot, Tank, pick_source_tank};
+        use crate::config::TankSource;
+
+        let both = SensorSnapshot {
+            tank_a_ok: true,
+            tank_b_ok: true,
+            ..Default::default()
+        };
+        assert_eq!(pick_source_tank(TankSource::TankA, None, &both), Tank::A);
+        assert_eq!(pick_source_tank(TankSource::TankB, None, &both), Tank::B);
+        assert_eq!(pick_source_tank(TankSource::Alternate, None, &both), Tank::A);
+        assert_eq!(
+            pick_source_tank(TankSource::Alternate, Some(Tank::A), &both),
+            Tank::B
+        );
+
+        let a_low = SensorSnapshot {
+            tank_a_ok: false,
+            ..both
+        };
+        assert_eq!(pick_source_tank(TankSource::TankA, None, &a_low), Tank::B);
+        let neither = SensorSnapshot {
+            tank_b_ok: false,
+            ..a_low
+        };
+        assert_eq!(pick_source_tank(TankSource::TankB, None, &neither), Tank::B);
+    }
+
+    fn draining_ctx() -> FsmContext {
+        let mut ctx = make_ctx();
+        ctx.sensors.tank_a_ok = true;
+        ctx.sensors.tank_b_ok = true;
+        ctx.sensors.flow_ml_per_min = 600.0;
+        ctx
+    }
+
+    #[test]
+    fn drain_runs_pump_in_reverse_until_both_tanks_empty() {
+        let mut fsm = make_fsm();
+        let mut ctx = draining_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Draining, &mut ctx);
+        assert!(ctx.commands.pump_duty > 0);
+        assert!(!ctx.commands.pump_forward);
+        assert_eq!(ctx.commands.uvc_duty, 0);
+
+        fsm.tick(&mut ctx);
+        ctx.sensors.tank_a_ok = false;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Draining);
+        assert!(!ctx.drain_complete);
+
+        ctx.sensors.tank_b_ok = false;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert_eq!(ctx.commands.pump_duty, 0);
+        assert!(ctx.commands.pump_forward);
+        assert!(ctx.drain_complete);
+        assert!((ctx.drain_volume_ml - 30.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn drain_stops_at_duration_cap() {
+        let mut fsm = make_fsm();
+        let mut ctx = draining_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Draining, &mut ctx);
+
+        let cap_ticks = (states::DRAIN_MAX_DURATION_SECS / ctx.tick_period_secs) as u64;
+        for _ in 0..cap_ticks - 1 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Draining);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert!(!ctx.drain_complete);
+    }
+
+    #[test]
+    fn purging_returns_to_active_on_re_elevation() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    #[test]
+    fn fault_sends_any_state_to_error() {
+        for start_state in [
+            StateId::Idle,
+            StateId::Sensing,
+            StateId::Active,
+            StateId::Purging,
+        ] {
+            let mut fsm = make_fsm();
+            let mut ctx = make_ctx();
+            fsm.start(&mut ctx);
+            if start_state != StateId::Idle {
+                fsm.force_transition(start_state, &mut ctx);
+            }
+
+            ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+            fsm.tick(&mut ctx);
+            assert_eq!(
+                fsm.current_state(),
+                StateId::Error,
+                "Expected Error from {:?}",
+                start_state
+            );
+        }
+    }
+
+    #[test]
+    fn leaving_active_ramps_duties_down() {
+        let mut ctx = make_ctx(); // soft_stop_ticks = 3
+        let mut fsm = make_active(&mut ctx);
+        fsm.tick(&mut ctx);
+        let (pump, uvc) = (ctx.commands.pump_duty, ctx.commands.uvc_duty);
+        assert!(pump > 0 && uvc > 0);
+
+        fsm.force_transition(StateId::Idle, &mut ctx);
+        let mut seq = vec![(ctx.commands.pump_duty, ctx.commands.uvc_duty)];
+        for _ in 0..3 {
+            fsm.tick(&mut ctx);
+            seq.push((ctx.commands.pump_duty, ctx.commands.uvc_duty));
+        }
+        assert!(seq[0].0 < pump && seq[0].0 > 0, "{seq:?}");
+        assert!(seq[0].1 < uvc && seq[0].1 > 0, "{seq:?}");
+        assert!(seq.windows(2).all(|w| w[1].0 <= w[0].0 && w[1].1 <= w[0].1));
+        assert_eq!(seq[2], (0, 0), "{seq:?}");
+        assert!(!ctx.soft_stop.is_active());
+    }
+
+    #[test]
+    fn purge_handover_ramps_uvc_off_and_pump_to_purge_duty() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_active(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+        let purge_duty = (ctx.config.pump_duty_percent / 2).max(20);
+        assert!(ctx.commands.pump_duty > purge_duty);
+        assert!(ctx.commands.uvc_duty > 0);
+
+        ctx.sensors.nh3_avg_ppm = 0.0;
+        fsm.tick(&mut ctx);
+        fsm.tick(&mut ctx);
+        assert_eq!(ctx.commands.pump_duty, purge_duty);
+        assert_eq!(ctx.commands.uvc_duty, 0);
+    }
+
+    #[test]
+    fn zero_soft_stop_ticks_stops_at_once() {
+        let mut ctx = FsmContext::new(SystemConfig {
+            soft_stop_ticks: 0,
+            ..Default::default()
+        });
+        let mut fsm = make_active(&mut ctx);
+        fsm.force_transition(StateId::Idle, &mut ctx);
+        assert_eq!((ctx.commands.pump_duty, ctx.commands.uvc_duty), (0, 0));
+    }
+
+    #[test]
+    fn error_zeroes_instantly_even_mid_ramp() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_active(&mut ctx);
+        fsm.force_transition(StateId::Idle, &mut ctx);
+        assert!(ctx.commands.pump_duty > 0, "ramp should be running");
+
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        fsm.force_transition(StateId::Error, &mut ctx);
+        assert_eq!((ctx.commands.pump_duty, ctx.commands.uvc_duty), (0, 0));
+        assert!(!ctx.soft_stop.is_active());
+        fsm.tick(&mut ctx);
+        assert_eq!((ctx.commands.pump_duty, ctx.commands.uvc_duty), (0, 0));
+    }
+
+    #[test]
+    fn error_kills_all_actuators() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        ctx.commands.pump_duty = 100;
+        ctx.commands.uvc_duty = 100;
+        fsm.force_transition(StateId::Error, &mut ctx);
+        assert_eq!(ctx.commands.pump_duty, 0);
+        assert_eq!(ctx.commands.uvc_duty, 0);
+    }
+
+    #[test]
+    fn error_to_idle_when_faults_clear() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        ctx.config.fault_recovery_cooldown_secs = 0;
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Error, &mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Error);
+
+        ctx.fault_flags = 0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    /// Error entered with a fault latched, as the safety supervisor does.
+    fn make_faulted(ctx: &mut FsmContext) -> Fsm {
+        let mut fsm = make_fsm();
+        ctx.config.fault_recovery_cooldown_secs = 5;
+        fsm.start(ctx);
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        fsm.force_transition(StateId::Error, ctx);
+        ctx.fault_flags = 0;
+        fsm
+    }
+
+    #[test]
+    fn single_clear_tick_does_not_recover() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_faulted(&mut ctx);
+
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Error);
+        assert!(ctx.recovering);
+        assert_eq!(ctx.commands.pump_duty, 0);
+    }
+
+    #[test]
+    fn sustained_clear_recovers_after_cooldown() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_faulted(&mut ctx);
+
+        for tick in 1..5 {
+            fsm.tick(&mut ctx);
+            assert_eq!(
+                fsm.current_state(),
+                StateId::Error,
+                "recovered early at tick {tick}"
+            );
+        }
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+        assert!(!ctx.recovering);
+    }
+
+    #[test]
+    fn refault_during_cooldown_restarts_it() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_faulted(&mut ctx);
+
+        for _ in 0..4 {
+            fsm.tick(&mut ctx);
+        }
+        ctx.fault_flags = crate::error::SafetyFault::OverTemperature.mask();
+        fsm.tick(&mut ctx);
+        assert!(!ctx.recovering);
+        ctx.fault_flags = 0;
+
+        // The four clear ticks before the flap no longer count.
+        for _ in 0..4 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Error);
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Idle);
+    }
+
+    #[test]
+    fn error_stays_while_faults_active() {
+        let mut fsm = make_fsm();
+        let mut ctx = make_ctx();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Error, &mut ctx);
+
+        ctx.fault_flags = crate::error::SafetyFault::WaterLevelLow.mask();
+        for _ in 0..10 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Error);
+    }
+
+    fn watchdog_ctx(state: StateId, max_secs: u32) -> FsmContext {
+        let mut ctx = make_ctx();
+        ctx.config.state_max_secs[state as usize] = max_secs;
+        ctx
+    }
+
+    /// Tick `fsm` out of `from` on its watchdog alone: still there one
+    /// tick short of `max_secs`, in `to` on the tick that reaches it.
+    fn assert_escapes(fsm: &mut Fsm, ctx: &mut FsmContext, from: StateId, to: StateId) {
+        let max_secs = ctx.config.state_max_secs[from as usize];
+        for _ in 1..max_secs {
+            fsm.tick(ctx);
+        }
+        assert_eq!(fsm.current_state(), from);
+        fsm.tick(ctx);
+        assert_eq!(fsm.current_state(), to);
+    }
+
+    #[test]
+    fn sensing_watchdog_escapes_to_idle() {
+        let mut ctx = watchdog_ctx(StateId::Sensing, 60);
+        // A confirmation window the readings never get through.
+        ctx.config.nh3_confirm_duration_secs = 600;
+        let mut fsm = make_fsm();
+        fsm.start(&mut ctx);
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        fsm.force_transition(StateId::Sensing, &mut ctx);
+
+        assert_escapes(&mut fsm, &mut ctx, StateId::Sensing, StateId::Idle);
+
+        // NH3 is still high, but Idle holds off for another watchdog
+        // period instead of cycling straight back into Sensing.
+        for _ in 1..60 {
+            fsm.tick(&mut ctx);
+            assert_eq!(fsm.current_state(), StateId::Idle);
+        }
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Sensing);
+    }
+
+    #[test]
+    fn sensing_holdoff_still_lets_an_urgent_spike_activate() {
+        let mut ctx = watchdog_ctx(StateId::Sensing, 60);
+        ctx.config.nh3_confirm_duration_secs = 600;
+        let mut fsm = make_fsm();
+        fsm.start(&mut ctx);
+        ctx.sensors.nh3_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        ctx.sensors.nh3_avg_ppm = ctx.config.nh3_activate_threshold_ppm + 5.0;
+        fsm.force_transition(StateId::Sensing, &mut ctx);
+        assert_escapes(&mut fsm, &mut ctx, StateId::Sensing, StateId::Idle);
+
+        ctx.sensors.nh3_ppm = ctx.config.nh3_urgent_threshold_ppm + 1.0;
+        fsm.tick(&mut ctx);
+        assert_eq!(fsm.current_state(), StateId::Active);
+    }
+
+    #[test]
+    fn active_watchdog_escapes_to_purging() {
+        // NH3 stuck high, well inside the Active runtime limit.
+        let mut ctx = watchdog_ctx(StateId::Active, 120);
+        let mut fsm = make_active(&mut ctx);
+
+        assert_escapes(&mut fsm, &mut ctx, StateId::Active, StateId::Purging);
+        assert_eq!(ctx.advisory_flags, 0);
+    }
+
+    #[test]
+    fn purging_watchdog_escapes_to_idle() {
+        // A volume purge with no flow, well inside its own cap.
+        let mut ctx = watchdog_ctx(StateId::Purging, 100);
+        ctx.config.purge_mode = crate::config::PurgeMode::Volume;
+        ctx.config.purge_max_duration_secs = 1800;
+        let mut fsm = make_fsm();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Purging, &mut ctx);
+
+        assert_escapes(&mut fsm, &mut ctx, StateId::Purging, StateId::Idle);
+        assert!(ctx.purge_volume_ml.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn draining_watchdog_escapes_to_idle() {
+        let mut ctx = draining_ctx();
+        ctx.config.state_max_secs[StateId::Draining as usize] = 120;
+        let mut fsm = make_fsm();
+        fsm.start(&mut ctx);
+        fsm.force_transition(StateId::Draining, &mut ctx);
+
+        assert_escapes(&mut fsm, &mut ctx, StateId::Draining, StateId::Idle);
+        assert!(!ctx.drain_complete);
+        assert_eq!(ctx.commands.pump_duty, 0);
+    }
+
+    #[test]
+    fn watchdog_never_moves_idle_or_error() {
+        let mut ctx = watchdog_ctx(StateId::Idle, 5);
+        ctx.config.state_max_secs[StateId::Error as usize] = 5;
+        let mut fsm = make_fsm();
+        fsm.start(&mut ctx);
+        for _ in 0..10 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Idle);
+
+        fsm.force_transition(StateId::Error, &mut ctx);
+        ctx.fault_flags = crate::error::SafetyFault::WaterLevelLow.mask();
+        for _ in 0..10 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Error);
+    }
+
+    #[test]
+    fn zero_limit_disables_watchdog() {
+        let mut ctx = make_ctx();
+        let mut fsm = make_active(&mut ctx);
+        for _ in 0..1800 {
+            fsm.tick(&mut ctx);
+        }
+        assert_eq!(fsm.current_state(), StateId::Active);
     }
 
     #[test]
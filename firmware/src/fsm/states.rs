@@ -13,15 +13,27 @@
 //!    │                            │
 //!    │                   [NH3 < deactivate]
 //!    │                            ▼
-//!    └──────[purge done]────── PURGING
+//!    └──────[purge done]────── PURGING   (time or flushed volume)
 //!
+//!  ACTIVE ──[max duration]──▶ PURGING  (ERROR if it recurs quickly)
 //!  Any state ──[safety fault]──▶ ERROR ──[faults cleared]──▶ IDLE
+//!
+//!  IDLE ──[DrainWater]──▶ DRAINING ──[tanks empty | cap]──▶ IDLE
+//!  SENSING | ACTIVE | PURGING ──[maintenance mode]──▶ IDLE (held there)
 //! ```
 
-use super::context::FsmContext;
+use super::context::{FsmContext, SoftStop, pick_source_tank};
 use super::{StateDescriptor, StateId};
+use crate::config::PurgeMode;
+use crate::control::numeric::{ControlNum, Num, above, at_or_above, below};
+use crate::drivers::led_patterns::COLOUR_RECOVERING;
+use crate::error::Advisory;
 use log::{info, warn};
 
+/// Longest a maintenance drain may run before it is stopped, even if the
+/// level sensors never report both tanks empty.
+pub const DRAIN_MAX_DURATION_SECS: f32 = 600.0;
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Table builder
 // ═══════════════════════════════════════════════════════════════════════════
@@ -69,6 +81,14 @@ pub fn build_state_table() -> [StateDescriptor; StateId::COUNT] {
             on_exit: Some(error_exit),
             on_update: error_update,
         },
+        // Index 5 — Draining
+        StateDescriptor {
+            id: StateId::Draining,
+            name: "Draining",
+            on_enter: Some(draining_enter),
+            on_exit: Some(draining_exit),
+            on_update: draining_update,
+        },
     ]
 }
 
@@ -81,6 +101,8 @@ fn idle_enter(ctx: &mut FsmContext) {
     ctx.commands.pump_duty = 0;
     ctx.commands.uvc_duty = 0;
     ctx.commands.led_rgb = (0, 180, 148); // teal #00B894
+    ctx.active_ticks = 0;
+    ctx.active_total_secs = 0.0;
     info!("IDLE: system quiescent, monitoring NH3");
 }
 
@@ -90,14 +112,65 @@ fn idle_update(ctx: &mut FsmContext) -> Option<StateId> {
         return Some(StateId::Error);
     }
 
-    // Trigger: instantaneous NH3 above activation threshold
-    if ctx.sensors.nh3_ppm > ctx.config.nh3_activate_threshold_ppm {
+    // Maintenance mode: stay put whatever NH3 does.
+    if ctx.config.maintenance_mode {
+        return None;
+    }
+
+    // Post-boot sensor soak: readings are not trustworthy yet.
+    if ctx.sensor_warming {
+        if ctx.uptime_secs() < f32::from(ctx.config.nh3_warmup_secs) {
+            return None;
+        }
+        ctx.sensor_warming = false;
+        info!("IDLE: NH3 sensor warm-up complete, triggers enabled");
+    }
+
+    // Trigger: instantaneous NH3 above activation threshold (unless
+    // activation is blocked).  A spike past the urgent threshold — even
+    // one seen only by an oversample between control ticks — skips
+    // confirmation entirely.
+    if ctx.activation_blocked() {
+        return None;
+    }
+    if above(ctx.sensors.nh3_peak(), ctx.nh3_thresholds.urgent) {
+        info!(
+            "IDLE: urgent NH3 spike {:.1} ppm > {:.1} → activating immediately",
+            ctx.sensors.nh3_peak(),
+            ctx.config.nh3_urgent_threshold_ppm
+        );
+        return Some(StateId::Active);
+    }
+    // The watchdog just took Sensing out; going straight back would
+    // only loop while NH3 stays high.
+    if ctx.uptime_secs() < ctx.sensing_holdoff_until_secs {
+        return None;
+    }
+    if above(ctx.sensors.nh3_ppm, ctx.nh3_thresholds.activate) {
+        ctx.sensing_on_rate = false;
+        return Some(StateId::Sensing);
+    }
+    // Trigger: NH3 climbing fast (e.g. a fresh deposit) while still below
+    // the activation level — start confirming early.
+    let rate_threshold = ctx.nh3_thresholds.rate;
+    if rate_threshold > Num::ZERO && above(ctx.nh3_rate_ppm_per_sec, rate_threshold) {
+        info!(
+            "IDLE: NH3 rising {:.2} ppm/s > {:.2} → sensing",
+            ctx.nh3_rate_ppm_per_sec, ctx.config.nh3_rate_threshold_ppm_per_sec
+        );
+        ctx.sensing_on_rate = true;
         return Some(StateId::Sensing);
     }
 
     None
 }
 
+/// Maintenance mode was switched on mid-cycle: wind down to Idle.
+fn maintenance_stop(state: &str) -> StateId {
+    info!("{state}: maintenance mode → Idle");
+    StateId::Idle
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  SENSING state — confirming the NH3 reading before full activation
 // ═══════════════════════════════════════════════════════════════════════════
@@ -114,9 +187,16 @@ fn sensing_update(ctx: &mut FsmContext) -> Option<StateId> {
     if ctx.has_faults() {
         return Some(StateId::Error);
     }
+    if ctx.config.maintenance_mode {
+        return Some(maintenance_stop("SENSING"));
+    }
 
-    // If NH3 drops back below activation threshold → false alarm, return to Idle
-    if ctx.sensors.nh3_avg_ppm < ctx.config.nh3_activate_threshold_ppm {
+    // If NH3 drops back below activation threshold → false alarm, return to
+    // Idle.  A rate-triggered entry starts below threshold, so it gets the
+    // full confirmation window before that check applies.
+    let confirm_secs = ctx.config.nh3_confirm_duration_secs as f32;
+    let rate_grace = ctx.sensing_on_rate && ctx.secs_in_state() < confirm_secs;
+    if !rate_grace && below(ctx.sensors.nh3_avg_ppm, ctx.nh3_thresholds.activate) {
         info!(
             "SENSING: NH3 avg dropped to {:.1} ppm, returning to Idle",
             ctx.sensors.nh3_avg_ppm
@@ -124,10 +204,23 @@ fn sensing_update(ctx: &mut FsmContext) -> Option<StateId> {
         return Some(StateId::Idle);
     }
 
+    // Self-test failure or Active-timeout hold-off — do not activate.
+    if ctx.activation_blocked() {
+        return Some(StateId::Idle);
+    }
+
+    // Spike past the urgent threshold mid-confirmation → stop waiting
+    if above(ctx.sensors.nh3_peak(), ctx.nh3_thresholds.urgent) {
+        info!(
+            "SENSING: urgent NH3 spike {:.1} ppm → activating immediately",
+            ctx.sensors.nh3_peak()
+        );
+        return Some(StateId::Active);
+    }
+
     // If we've been in Sensing long enough and the average is still high → confirm
-    let confirm_secs = ctx.config.nh3_confirm_duration_secs as f32;
     if ctx.secs_in_state() >= confirm_secs
-        && ctx.sensors.nh3_avg_ppm >= ctx.config.nh3_activate_threshold_ppm
+        && at_or_above(ctx.sensors.nh3_avg_ppm, ctx.nh3_thresholds.activate)
     {
         info!(
             "SENSING: confirmed NH3 avg {:.1} ppm after {:.0}s → activating",
@@ -144,20 +237,61 @@ fn sensing_update(ctx: &mut FsmContext) -> Option<StateId> {
 //  ACTIVE state — pump running, UVC on, actively scrubbing
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Time to ramp from the priming duty to full duty once priming ends.
+const PRIME_RAMP_SECS: f32 = 3.0;
+
+/// A second Active timeout within this window of the first means the
+/// scrub cannot finish on its own (e.g. a stuck NH3 sensor).
+const ACTIVE_TIMEOUT_RECUR_SECS: f32 = 900.0;
+
+/// How long activation stays blocked after a recurring Active timeout.
+pub const ACTIVE_TIMEOUT_HOLDOFF_SECS: f32 = 3600.0;
+
 fn active_enter(ctx: &mut FsmContext) {
-    ctx.commands.pump_duty = ctx.config.pump_duty_percent;
+    // Active sets its own duties; a ramp from Purging is moot.
+    ctx.soft_stop = SoftStop::default();
+    let idle_limit = ctx.config.prime_after_idle_secs;
+    ctx.priming = idle_limit > 0
+        && ctx
+            .secs_since_pump()
+            .is_none_or(|idle| idle >= idle_limit as f32);
+    ctx.pump_ramping = false;
+    ctx.active_run_secs = 0.0;
+    if ctx.active_ticks == 0 {
+        select_source_tank(ctx);
+    }
+    if ctx.priming {
+        ctx.commands.pump_duty = ctx.config.prime_duty_percent;
+        match ctx.secs_since_pump() {
+            Some(idle) => info!(
+                "ACTIVE: pump idle {:.0}s, priming at {}% for {}s",
+                idle, ctx.config.prime_duty_percent, ctx.config.prime_duration_secs
+            ),
+            None => info!(
+                "ACTIVE: first run since boot, priming at {}% for {}s",
+                ctx.config.prime_duty_percent, ctx.config.prime_duration_secs
+            ),
+        }
+    } else {
+        ctx.commands.pump_duty = ctx.config.pump_duty_percent;
+    }
     ctx.commands.pump_forward = true;
     ctx.commands.uvc_duty = ctx.config.uvc_duty_percent;
     ctx.commands.led_rgb = (0, 50, 255); // deep blue — "working"
+    ctx.uvc_warming = ctx.config.uvc_warmup_secs > 0;
     info!(
-        "ACTIVE: pump at {}%, UVC at {}%",
-        ctx.config.pump_duty_percent, ctx.config.uvc_duty_percent
+        "ACTIVE: pump at {}%, UVC at {}% (warm-up {} s)",
+        ctx.config.pump_duty_percent, ctx.config.uvc_duty_percent, ctx.config.uvc_warmup_secs
     );
 }
 
 fn active_exit(ctx: &mut FsmContext) {
-    // UVC off immediately on exit (pump continues in purge)
+    // UVC ramps off over `soft_stop_ticks` (pump continues in purge).
+    ctx.begin_soft_stop();
     ctx.commands.uvc_duty = 0;
+    ctx.uvc_warming = false;
+    ctx.priming = false;
+    ctx.pump_ramping = false;
     info!("ACTIVE: UVC disabled on state exit");
 }
 
@@ -165,19 +299,144 @@ fn active_update(ctx: &mut FsmContext) -> Option<StateId> {
     if ctx.has_faults() {
         return Some(StateId::Error);
     }
+    if ctx.config.maintenance_mode {
+        return Some(maintenance_stop("ACTIVE"));
+    }
 
-    // NH3 dropped below deactivation threshold → begin purge
-    if ctx.sensors.nh3_avg_ppm < ctx.config.nh3_deactivate_threshold_ppm {
+    if ctx.uvc_warming && ctx.secs_in_state() >= ctx.config.uvc_warmup_secs as f32 {
+        ctx.uvc_warming = false;
+        info!(
+            "ACTIVE: UVC warm-up complete after {:.1}s",
+            ctx.secs_in_state()
+        );
+    }
+
+    steer_source_tank(ctx, "ACTIVE");
+    prime_update(ctx);
+
+    ctx.active_ticks = ctx.active_ticks.wrapping_add(1);
+    ctx.active_total_secs += ctx.tick_dt_secs;
+    ctx.active_run_secs += ctx.tick_dt_secs;
+    if let Some(next) = active_timeout(ctx) {
+        return Some(next);
+    }
+
+    // NH3 dropped below deactivation threshold → begin purge, but not
+    // before the minimum runtime so a reading hovering at the threshold
+    // cannot short-cycle the pump
+    if below(ctx.sensors.nh3_avg_ppm, ctx.nh3_thresholds.deactivate)
+        && ctx.active_run_secs >= f32::from(ctx.config.min_active_secs)
+    {
         info!(
             "ACTIVE: NH3 avg {:.1} ppm < {:.1} threshold → purging",
             ctx.sensors.nh3_avg_ppm, ctx.config.nh3_deactivate_threshold_ppm
         );
+        ctx.advisory_flags &= !Advisory::ActiveTimeout.mask();
         return Some(StateId::Purging);
     }
 
     None
 }
 
+/// Pick the source tank at the start of a scrub cycle.  A Purging →
+/// Active bounce stays on the tank already in use.
+fn select_source_tank(ctx: &mut FsmContext) {
+    let tank = pick_source_tank(ctx.config.tank_source, ctx.source_tank, &ctx.sensors);
+    ctx.source_tank = Some(tank);
+    ctx.commands.source_tank = tank;
+    info!("ACTIVE: drawing from tank {:?}", tank);
+}
+
+/// Switch to the other tank mid-cycle if the one in use reads low.
+fn steer_source_tank(ctx: &mut FsmContext, state: &str) {
+    let tank = ctx.commands.source_tank;
+    if tank.is_ok(&ctx.sensors) || !tank.other().is_ok(&ctx.sensors) {
+        return;
+    }
+    warn!(
+        "{}: tank {:?} low, switching to tank {:?}",
+        state,
+        tank,
+        tank.other()
+    );
+    ctx.source_tank = Some(tank.other());
+    ctx.commands.source_tank = tank.other();
+}
+
+/// Cut the scrub short once it has run `max_active_duration_secs`.
+/// A repeat within [`ACTIVE_TIMEOUT_RECUR_SECS`] goes to Error and
+/// blocks activation for [`ACTIVE_TIMEOUT_HOLDOFF_SECS`] instead of
+/// purging again.
+fn active_timeout(ctx: &mut FsmContext) -> Option<StateId> {
+    let limit = ctx.config.max_active_duration_secs;
+    if limit == 0 || ctx.active_secs() < limit as f32 {
+        return None;
+    }
+
+    ctx.advisory_flags |= Advisory::ActiveTimeout.mask();
+    let recurred = ctx
+        .last_active_timeout_secs
+        .is_some_and(|t| ((ctx.elapsed_secs - t) as f32) < ACTIVE_TIMEOUT_RECUR_SECS);
+    ctx.last_active_timeout_secs = Some(ctx.elapsed_secs);
+
+    if recurred {
+        warn!(
+            "ACTIVE: {} again within {:.0}s → error, activation held off for {:.0}s",
+            Advisory::ActiveTimeout,
+            ACTIVE_TIMEOUT_RECUR_SECS,
+            ACTIVE_TIMEOUT_HOLDOFF_SECS
+        );
+        ctx.active_holdoff_until_secs = ctx.uptime_secs() + ACTIVE_TIMEOUT_HOLDOFF_SECS;
+        Some(StateId::Error)
+    } else {
+        warn!(
+            "ACTIVE: {} ({:.0}s ≥ {}s) → purging",
+            Advisory::ActiveTimeout,
+            ctx.active_secs(),
+            limit
+        );
+        Some(StateId::Purging)
+    }
+}
+
+/// Advance the priming sequence: low duty for `prime_duration_secs`,
+/// then a linear ramp to full duty over [`PRIME_RAMP_SECS`].
+fn prime_update(ctx: &mut FsmContext) {
+    let prime_secs = ctx.config.prime_duration_secs as f32;
+    let elapsed = ctx.secs_in_state();
+
+    if ctx.priming && elapsed >= prime_secs {
+        ctx.priming = false;
+        ctx.pump_ramping = true;
+        if ctx.sensors.flow_detected {
+            info!("ACTIVE: priming complete, ramping pump");
+        } else {
+            ctx.advisory_flags |= Advisory::NoFlowWhilePumping.mask();
+            warn!(
+                "ACTIVE: {} after {:.1}s priming",
+                Advisory::NoFlowWhilePumping,
+                elapsed
+            );
+        }
+    }
+
+    if ctx.pump_ramping {
+        let low = ctx.config.prime_duty_percent as f32;
+        let full = ctx.config.pump_duty_percent as f32;
+        let t = (elapsed - prime_secs) / PRIME_RAMP_SECS;
+        if t >= 1.0 {
+            ctx.pump_ramping = false;
+            ctx.commands.pump_duty = ctx.config.pump_duty_percent;
+        } else {
+            ctx.commands.pump_duty = (low + (full - low) * t) as u8;
+        }
+    }
+
+    if !ctx.priming && ctx.sensors.flow_detected {
+        ctx.advisory_flags &= !Advisory::NoFlowWhilePumping.mask();
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  PURGING state — flushing the system before returning to idle
 // ═══════════════════════════════════════════════════════════════════════════
@@ -189,13 +448,23 @@ fn purging_enter(ctx: &mut FsmContext) {
     ctx.commands.pump_forward = true;
     ctx.commands.uvc_duty = 0;
     ctx.commands.led_rgb = (0, 200, 200); // cyan — "winding down"
-    info!(
-        "PURGING: pump at {}% for {}s",
-        ctx.commands.pump_duty, ctx.config.purge_duration_secs
-    );
+    ctx.purge_volume_ml = 0.0;
+    match ctx.config.purge_mode {
+        PurgeMode::Duration => info!(
+            "PURGING: pump at {}% for {}s",
+            ctx.commands.pump_duty, ctx.config.purge_duration_secs
+        ),
+        PurgeMode::Volume => info!(
+            "PURGING: pump at {}% until {} mL (cap {}s)",
+            ctx.commands.pump_duty,
+            ctx.config.purge_target_volume_ml,
+            ctx.config.purge_max_duration_secs
+        ),
+    }
 }
 
 fn purging_exit(ctx: &mut FsmContext) {
+    ctx.begin_soft_stop();
     ctx.commands.pump_duty = 0;
     info!("PURGING: pump stopped, system returning to idle");
 }
@@ -204,9 +473,16 @@ fn purging_update(ctx: &mut FsmContext) -> Option<StateId> {
     if ctx.has_faults() {
         return Some(StateId::Error);
     }
+    if ctx.config.maintenance_mode {
+        return Some(maintenance_stop("PURGING"));
+    }
+
+    steer_source_tank(ctx, "PURGING");
 
     // If NH3 spikes back up during purge → skip back to Active
-    if ctx.sensors.nh3_avg_ppm >= ctx.config.nh3_activate_threshold_ppm {
+    if at_or_above(ctx.sensors.nh3_avg_ppm, ctx.nh3_thresholds.activate)
+        && !ctx.activation_blocked()
+    {
         info!(
             "PURGING: NH3 re-elevated to {:.1} ppm, returning to Active",
             ctx.sensors.nh3_avg_ppm
@@ -214,9 +490,82 @@ fn purging_update(ctx: &mut FsmContext) -> Option<StateId> {
         return Some(StateId::Active);
     }
 
-    // Purge complete after configured duration
-    let purge_secs = ctx.config.purge_duration_secs as f32;
-    if ctx.secs_in_state() >= purge_secs {
+    ctx.purge_volume_ml += ctx.sensors.flow_ml_per_min / 60.0 * ctx.tick_dt_secs;
+
+    match ctx.config.purge_mode {
+        // Purge complete after configured duration
+        PurgeMode::Duration => {
+            if ctx.secs_in_state() >= ctx.config.purge_duration_secs as f32 {
+                return Some(StateId::Idle);
+            }
+        }
+        // Purge complete once the target volume is through, or at the
+        // cap if the pump cannot deliver it (air lock, empty tank)
+        PurgeMode::Volume => {
+            if ctx.purge_volume_ml >= ctx.config.purge_target_volume_ml as f32 {
+                info!("PURGING: flushed {:.0} mL", ctx.purge_volume_ml);
+                return Some(StateId::Idle);
+            }
+            if ctx.secs_in_state() >= ctx.config.purge_max_duration_secs as f32 {
+                warn!(
+                    "PURGING: only {:.0} of {} mL after {}s cap",
+                    ctx.purge_volume_ml,
+                    ctx.config.purge_target_volume_ml,
+                    ctx.config.purge_max_duration_secs
+                );
+                return Some(StateId::Idle);
+            }
+        }
+    }
+
+    None
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  DRAINING state — maintenance: empty the reservoir for cleaning
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn draining_enter(ctx: &mut FsmContext) {
+    // Pump in reverse to move water out of the tanks; no NH3 logic, no UVC.
+    ctx.soft_stop = SoftStop::default();
+    ctx.commands.pump_duty = ctx.config.pump_duty_percent;
+    ctx.commands.pump_forward = false;
+    ctx.commands.uvc_duty = 0;
+    ctx.commands.led_rgb = (255, 200, 0); // yellow — maintenance
+    ctx.drain_volume_ml = 0.0;
+    ctx.drain_complete = false;
+    info!(
+        "DRAINING: pump reversed at {}% (cap {}s)",
+        ctx.commands.pump_duty, DRAIN_MAX_DURATION_SECS
+    );
+}
+
+fn draining_exit(ctx: &mut FsmContext) {
+    ctx.commands.pump_duty = 0;
+    ctx.commands.pump_forward = true;
+    info!("DRAINING: pump stopped after {:.0} mL", ctx.drain_volume_ml);
+}
+
+fn draining_update(ctx: &mut FsmContext) -> Option<StateId> {
+    // Low water is expected here and masked by the service; anything
+    // else (interlock, temperature, dry pump) aborts the drain.
+    if ctx.has_faults() {
+        return Some(StateId::Error);
+    }
+
+    ctx.drain_volume_ml += ctx.sensors.flow_ml_per_min / 60.0 * ctx.tick_dt_secs;
+
+    if !ctx.sensors.tank_a_ok && !ctx.sensors.tank_b_ok {
+        info!("DRAINING: both tanks empty");
+        ctx.drain_complete = true;
+        return Some(StateId::Idle);
+    }
+
+    if ctx.secs_in_state() >= DRAIN_MAX_DURATION_SECS {
+        warn!(
+            "DRAINING: tanks not empty after {}s cap, stopping",
+            DRAIN_MAX_DURATION_SECS
+        );
         return Some(StateId::Idle);
     }
 
@@ -228,9 +577,12 @@ fn purging_update(ctx: &mut FsmContext) -> Option<StateId> {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn error_enter(ctx: &mut FsmContext) {
-    // Kill everything immediately.
+    // Kill everything immediately — no soft stop on a fault.
+    ctx.soft_stop = SoftStop::default();
     ctx.commands = super::context::ActuatorCommands::all_off();
     ctx.commands.led_rgb = (255, 0, 0); // red — fault
+    ctx.fault_clear_secs = 0.0;
+    ctx.recovering = false;
     warn!(
         "ERROR: all actuators disabled, fault_flags=0b{:08b}",
         ctx.fault_flags
@@ -239,6 +591,8 @@ fn error_enter(ctx: &mut FsmContext) {
 
 fn error_exit(ctx: &mut FsmContext) {
     info!("ERROR: faults cleared, resuming normal operation");
+    ctx.fault_clear_secs = 0.0;
+    ctx.recovering = false;
     ctx.commands.led_rgb = (0, 180, 148); // back to teal
 }
 
@@ -250,10 +604,37 @@ fn error_update(ctx: &mut FsmContext) -> Option<StateId> {
         ctx.commands.led_rgb = (40, 0, 0);
     }
 
-    // Stay in Error until ALL faults are cleared
-    if !ctx.has_faults() {
+    // Stay in Error until ALL faults have been clear for the cooldown;
+    // a re-fault restarts the wait so a flapping sensor cannot cycle us.
+    if ctx.has_faults() {
+        if ctx.recovering {
+            warn!(
+                "ERROR: fault returned during recovery cooldown, fault_flags=0b{:08b}",
+                ctx.fault_flags
+            );
+        }
+        ctx.fault_clear_secs = 0.0;
+        ctx.recovering = false;
+        return None;
+    }
+
+    ctx.fault_clear_secs += ctx.tick_dt_secs;
+    if ctx.fault_clear_secs >= f32::from(ctx.config.fault_recovery_cooldown_secs) {
         return Some(StateId::Idle);
     }
+    if !ctx.recovering {
+        info!(
+            "ERROR: faults clear, recovering after {}s cooldown",
+            ctx.config.fault_recovery_cooldown_secs
+        );
+        ctx.recovering = true;
+    }
+    // Amber blink while recovering.
+    ctx.commands.led_rgb = if ctx.ticks_in_state % 2 == 0 {
+        COLOUR_RECOVERING
+    } else {
+        (40, 20, 0)
+    };
 
     None
 }
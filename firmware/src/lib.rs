@@ -9,6 +9,7 @@
 pub mod app;
 pub mod config;
 pub mod diagnostics;
+pub mod error;
 pub mod events;
 pub mod fsm;
 pub mod power;
@@ -16,7 +17,6 @@ pub mod rpc;
 pub mod safety;
 pub mod scheduler;
 
-mod error;
 mod pins;
 mod esp_link_shims;
 
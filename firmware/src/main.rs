@@ -54,28 +54,30 @@ use adapters::mdns::MdnsAdapter;
 use adapters::nvs::NvsAdapter;
 use adapters::rpc_adapter::RpcEventSink;
 use adapters::time::Esp32TimeAdapter;
-use adapters::wifi::{ConnectivityPort, WifiAdapter};
-use app::commands::AppCommand;
+use adapters::wifi::{ConnectivityPort, WifiAdapter, WifiState};
+use app::commands::{AppCommand, CommandSource};
 use app::events::AppEvent;
 use app::ports::{
     ActuatorPort, ConfigPort, EventSink, ScheduleFiredKind, SchedulerDelegate, SensorPort,
     StoragePort,
 };
 use app::service::AppService;
+use app::zones::ZoneSet;
 use config::SystemConfig;
 use drivers::button::{ButtonDriver, ButtonEvent};
 use drivers::led_patterns::{
-    COLOUR_ACTIVE, COLOUR_IDLE, COLOUR_LOW_WATER, COLOUR_OTA, COLOUR_PROVISIONING,
-    COLOUR_PURGING, COLOUR_SENSING, COLOUR_WIFI_CONNECTED, LedPatternEngine, PatternId,
+    COLOUR_IDLE, COLOUR_MAINTENANCE, COLOUR_RECOVERING, COLOUR_SAFE_MODE, LED_FAILED_ALL,
+    LedPatternEngine, PatternId, blink_code, select_overlay,
 };
 use drivers::pump::PumpDriver;
 use drivers::status_led::StatusLed;
 use drivers::uvc::UvcDriver;
 use events::{Event, push_event};
 use fsm::StateId;
-use power::{PowerManager, PowerMode, WakeReason};
+use power::{DeepSleepWake, PowerManager, PowerMode, WakeReason};
 use rpc::auth::MAX_CLIENTS;
-use scheduler::{Schedule, ScheduleKind, Scheduler};
+use rpc::channels::ResponsePriority;
+use scheduler::{Schedule, ScheduleKind};
 
 // ── Scheduler delegate ────────────────────────────────────────
 //
@@ -123,10 +125,6 @@ fn main() -> Result<()> {
         #[allow(clippy::empty_loop)]
         loop {}
     }
-    drivers::hw_timer::start_timers();
-    if let Err(e) = drivers::hw_init::init_isr_service() {
-        log::error!("ISR service init failed: {} — continuing without ISRs", e);
-    }
     let watchdog = drivers::watchdog::Watchdog::new();
 
     // ── 2. Load config from NVS (or defaults) ─────────────────
@@ -142,7 +140,9 @@ fn main() -> Result<()> {
             NvsAdapter::default()
         }
     };
-    let config = match nvs.load() {
+    let loaded = nvs.load();
+    let config_safe_mode = nvs.record_config_load(loaded.is_ok());
+    let config = match loaded {
         Ok(cfg) => {
             info!("Config loaded from NVS");
             cfg
@@ -152,10 +152,24 @@ fn main() -> Result<()> {
             SystemConfig::default()
         }
     };
+    // ISRs go in once the config is known: the interlock handler and its
+    // boot-time seed depend on the configured polarity.
+    if let Err(e) = drivers::hw_init::init_isr_service(config.interlock_active_low) {
+        log::error!("ISR service init failed: {} — continuing without ISRs", e);
+    }
 
     // ── 3. Power manager + wake reason ────────────────────────
     let mut power_mgr = PowerManager::new(&config);
+    if let Err(e) = power::install_brownout_handler() {
+        warn!("{} — brown-out will reset without a safe shutdown", e);
+    }
     let wake_reason = power_mgr.determine_wake_reason();
+    let reset_cause = power_mgr.determine_reset_cause();
+    if reset_cause.is_abnormal() {
+        warn!("Boot: reset cause {:?}", reset_cause);
+    } else {
+        info!("Boot: reset cause {:?}", reset_cause);
+    }
     let time_adapter = Esp32TimeAdapter::new();
 
     match wake_reason {
@@ -192,16 +206,16 @@ fn main() -> Result<()> {
         pins::UVC_INTERLOCK_GPIO,
     );
 
-    let mut hw = HardwareAdapter::new(
-        sensor_hub,
-        PumpDriver::new(),
-        UvcDriver::new(),
-        StatusLed::new(),
-    );
+    // The dual-zone carrier takes zone 0's UVC dimming pin for zone 1's
+    // NH3 sensor; the lamp is then switched on its enable line alone.
+    #[cfg(feature = "dual-zone")]
+    let uvc = UvcDriver::new().on_pins(pins::UVC_ENABLE_GPIO, None);
+    #[cfg(not(feature = "dual-zone"))]
+    let uvc = UvcDriver::new();
+    let hw = HardwareAdapter::new(sensor_hub, PumpDriver::new(), uvc, StatusLed::new());
 
     let mut log_sink = LogEventSink::new();
     let mut rpc_sink = RpcEventSink::new();
-    let mut sched = Scheduler::new();
     let mut sched_delegate = EventQueueDelegate;
     #[cfg(target_os = "espidf")]
     let mut _sntp: Option<esp_idf_svc::sntp::EspSntp<'static>> = None;
@@ -217,7 +231,9 @@ fn main() -> Result<()> {
 
     // ── LED pattern engine ────────────────────────────────
     let mut led_engine = LedPatternEngine::new();
-    led_engine.set_fsm_pattern(COLOUR_IDLE, PatternId::Solid);
+    led_engine.set_overrides(&config.led_overrides);
+    let (idle_colour, idle_pattern) = led_engine.state_pattern(StateId::Idle);
+    led_engine.set_fsm_pattern(idle_colour, idle_pattern);
 
     // ── WiFi station adapter ──────────────────────────────────
     if let Err(e) = adapters::wifi::wifi_stack_init() {
@@ -226,6 +242,13 @@ fn main() -> Result<()> {
     let mut wifi = WifiAdapter::new();
 
     // ── Boot-time WiFi auto-reconnect ─────────────────────────
+    let migrated = nvs.migrate_untagged_credentials();
+    if migrated > 0 {
+        info!(
+            "Boot: re-stored {} untagged credential(s) with integrity tags",
+            migrated
+        );
+    }
     let mut wifi_connected_on_boot = false;
     {
         let mut ssid_buf = [0u8; 32];
@@ -268,17 +291,62 @@ fn main() -> Result<()> {
 
     // ── 6. Construct app service ──────────────────────────────
     let mut app = AppService::new(config.clone());
+    app.flow_totals_mut().init(&nvs);
+
+    // RGB channel failures are non-fatal: fall back to a blink code.
+    let led_failed = drivers::hw_init::led_failed_channels();
+    if led_failed != 0 {
+        warn!(
+            "Status LED degraded (failed channels 0b{:03b}) — showing blink code",
+            led_failed
+        );
+        led_engine.set_failed_channels(led_failed);
+        app.raise_advisory(error::Advisory::StatusLedFailed);
+    }
+    // Never cleared at runtime: leaving safe mode takes a boot that loads
+    // the config, or a factory reset discarding it.
+    if config_safe_mode {
+        app.raise_advisory(error::Advisory::ConfigSafeMode);
+    }
+    let mut blink_code_logged: Option<StateId> = None;
 
-    if wake_reason == WakeReason::UlpWake {
-        app.start_from(StateId::Sensing, &mut log_sink);
-    } else {
-        app.start(&mut log_sink);
+    let mut zones = ZoneSet::new(app, hw);
+    #[cfg(feature = "dual-zone")]
+    {
+        let added = zones.add(zone_b_service(&nvs), zone_b_hardware(&config));
+        if added.is_err() {
+            warn!("Zone 1 could not be added — running zone 0 only");
+        }
+    }
+
+    // ── 6a. Sensor self-test (pump and UVC still off) ─────────
+    for zone in &mut zones {
+        zone.app.run_self_test(&mut zone.hw, &mut log_sink);
+        if wake_reason == WakeReason::UlpWake {
+            zone.app.start_from(StateId::Sensing, &mut log_sink);
+        } else {
+            zone.app.start(&mut log_sink);
+        }
+    }
+    // Schedule countdowns restart from zero after deep sleep; a timer
+    // wake means the one the timer was armed for is due, so run it now.
+    if wake_reason == WakeReason::Timer {
+        push_event(Event::ScheduledScrub);
     }
 
     // ── 6b. RPC engine + I/O task ──────────────────────────────
     let rpc_psk = b"default-psk-change-me";
     let mut rpc_engine = rpc::engine::RpcEngine::new(rpc_psk);
     rpc_engine.init_crash_log(&nvs);
+    rpc_engine.init_audit_log(&nvs);
+    rpc_engine.restore_ota(&nvs);
+    rpc_engine.init_install_id(&mut nvs);
+    rpc_engine.set_boot_reason(reset_cause, wake_reason);
+    if wake_reason == WakeReason::UlpWake {
+        rpc_engine.record_ulp_state(power_mgr.read_ulp_state());
+    }
+    // BLE has been advertising since step 5; let held writes through now.
+    rpc::io_task::mark_rpc_ready();
 
     // TLS transport — multi-client server on port 4242.
     // Ownership moves to the I/O task thread; main loop communicates
@@ -299,7 +367,25 @@ fn main() -> Result<()> {
             None
         }
     };
-    let _io_handle = tls_transport.map(|t| rpc::io_task::spawn(t));
+
+    // Bench UART — plaintext RPC on the USB-serial bridge (opt-in).
+    #[cfg(feature = "uart-rpc")]
+    let uart_transport = match adapters::uart_transport::UartTransport::new(
+        adapters::uart_transport::DEFAULT_UART_PORT,
+        adapters::uart_transport::DEFAULT_BAUD,
+        rpc::io_task::UART_SLOT,
+    ) {
+        Ok(u) => Some(u),
+        Err(e) => {
+            warn!("UART RPC init failed: {} — bench serial disabled", e);
+            None
+        }
+    };
+    #[cfg(not(feature = "uart-rpc"))]
+    let uart_transport = None;
+
+    let _io_handle = (tls_transport.is_some() || uart_transport.is_some())
+        .then(|| rpc::io_task::spawn(tls_transport, uart_transport));
 
     // Register this task for FreeRTOS notification-based wake.
     // On ESP-IDF, main() runs on Core 1 (APP_CPU) per sdkconfig.
@@ -308,31 +394,35 @@ fn main() -> Result<()> {
     info!("System ready. Entering event loop.");
 
     // ── 7. Event loop ─────────────────────────────────────────
-    let tick_secs = config.control_loop_interval_ms as f32 / 1000.0;
-    let mut telemetry_counter: u64 = 0;
+    // The power profile sets the loop pace; a profile change re-paces
+    // the control timer below without a restart.
+    let mut control_interval_ms = config.power_timing().control_loop_interval_ms;
+    let mut tick_secs = control_interval_ms as f32 / 1000.0;
+    // The control tick comes from its own periodic timer at the
+    // configured interval, independent of when the loop wakes.
+    drivers::hw_timer::start_timers(control_interval_ms);
+    // Seconds of measured control time since the last TelemetryTick.
+    let mut telemetry_elapsed_secs: f32 = 0.0;
+    // GPIO edges inject extra ticks between timer ticks, so the control
+    // math is fed the measured gap rather than the nominal interval.
+    let mut last_control_us: Option<u64> = None;
+
+    let mut events_pending = false;
+    let mut drop_monitor = diagnostics::DropRateMonitor::default();
+    let mut restart_held = false;
 
     loop {
         // Block until a push_event() notification arrives or timeout expires.
+        // Skipped when the previous pass left events behind its drain budget.
         // On ESP-IDF: ulTaskNotifyTake suspends the task, allowing the
         // FreeRTOS idle task to run PM hooks (automatic light sleep).
         // Wakes instantly when any ISR/timer/software calls push_event().
-        // On simulation: sleeps for the control interval then injects ControlTick.
-        #[cfg(target_os = "espidf")]
-        events::wait_for_event(100);
-
-        #[cfg(not(target_os = "espidf"))]
-        {
-            events::wait_for_event(config.control_loop_interval_ms as u32);
-            push_event(Event::ControlTick);
+        // ControlTick arrives from hw_timer on both target and simulation.
+        if !events_pending {
+            events::wait_for_event(100);
         }
+        rpc_engine.set_connected_clients(rpc::io_task::connected_clients());
 
-        telemetry_counter += 1;
-        if telemetry_counter >= config.telemetry_interval_secs as u64 {
-            push_event(Event::TelemetryTick);
-            telemetry_counter = 0;
-        }
-
-        // Tick the scheduler (delegate-driven, decoupled from events).
         #[cfg(target_os = "espidf")]
         if wifi.is_connected() && _sntp.is_none() {
             if let Ok(s) = esp_idf_svc::sntp::EspSntp::new_default() {
@@ -341,46 +431,109 @@ fn main() -> Result<()> {
             }
         }
         let current_hour = time_adapter.current_hour();
-        sched.tick(current_hour, tick_secs, &mut sched_delegate);
 
-        // Process all pending events.
+        // Process pending events, at most DRAIN_BUDGET per pass so a burst
+        // cannot starve the watchdog feed and LED refresh below.
         let mut activity = false;
 
-        events::drain_events(|event| {
+        events_pending = events::drain_events(events::DRAIN_BUDGET, |event| {
             match event {
                 Event::ControlTick => {
-                    app.tick(&mut hw, &mut log_sink);
+                    let now_us = time_adapter.uptime_us();
+                    let dt_secs = last_control_us.map_or(tick_secs, |prev| {
+                        now_us.saturating_sub(prev) as f32 / 1_000_000.0
+                    });
+                    last_control_us = Some(now_us);
+                    zones.tick(&mut log_sink, dt_secs);
+                    // Scheduler and telemetry pacing run on the same
+                    // measured time as the control loop, not loop wakes.
+                    let scheduler = zones.primary_mut().app.scheduler_mut();
+                    scheduler.tick(current_hour, dt_secs, &mut sched_delegate);
+                    telemetry_elapsed_secs += dt_secs;
+                    let telemetry_interval = config.telemetry_interval_secs as f32;
+                    if telemetry_elapsed_secs >= telemetry_interval {
+                        push_event(Event::TelemetryTick);
+                        telemetry_elapsed_secs -= telemetry_interval;
+                    }
+                    for zone in &mut zones {
+                        if zone.app.take_nh3_alarm_changed() {
+                            rpc_engine.broadcast_nh3_alarm(&zone.app);
+                        }
+                    }
+                    // Keep Device Status fresh for centrals that read or
+                    // notify on it without an RPC telemetry subscription.
+                    let app = &zones.primary().app;
+                    if ble.status_refresh_due(
+                        now_us / 1000,
+                        app.current_config().ble_status_interval_secs,
+                    ) {
+                        let t = app.build_telemetry(None);
+                        ble.update_status(
+                            app.state_name(),
+                            t.nh3_ppm,
+                            app.fault_flags(),
+                            (now_us / 1_000_000) as u32,
+                        );
+                    }
                     if rpc_engine.ota_mut().has_pending() {
                         if let Err(e) = rpc_engine.ota_mut().flush_pending() {
                             warn!("OTA flush failed: {}", e);
+                            rpc_engine.abort_ota(&mut nvs);
                         }
                     }
-                    if app.state() != StateId::Idle {
+                    if zones.iter().any(|z| z.app.state() != StateId::Idle) {
                         activity = true;
                     }
                 }
 
                 Event::SensorReadTick => {
-                    let _ = hw.read_ammonia_fast();
+                    for zone in &mut zones {
+                        let _ = zone.hw.read_ammonia_fast();
+                    }
                     sensors::flow::flow_clear_event_latch();
                     activity = true;
                 }
 
                 Event::TelemetryTick => {
                     let wifi_rssi = wifi.rssi();
-                    let t = app.build_telemetry(wifi_rssi);
-                    log_sink.emit(&AppEvent::Telemetry(t.clone()));
-                    rpc_sink.emit(&AppEvent::Telemetry(t));
+                    for zone in &zones {
+                        let t = zone.app.build_telemetry(wifi_rssi);
+                        log_sink.emit(&AppEvent::Telemetry(t.clone()));
+                        rpc_sink.emit(&AppEvent::Telemetry(t));
+                    }
                     let tick_ms = config.telemetry_interval_secs as u32 * 1000;
                     for cid in 0..MAX_CLIENTS as u8 {
+                        // Each client streams the zone it subscribed to.
+                        let Some(zone) = zones.get(rpc_engine.telemetry_zone(cid)) else {
+                            continue;
+                        };
                         if rpc_engine.should_stream_telemetry(cid, tick_ms) {
-                            if let Some(frame) = rpc_engine.build_telemetry_frame(cid, &app, wifi_rssi) {
-                                rpc::io_task::send_response(frame.client_id, frame.data);
+                            if let Some(frame) = rpc_engine.build_telemetry_frame(cid, &zone.app, wifi_rssi) {
+                                let delivered = rpc::io_task::send_response(
+                                    frame.client_id,
+                                    frame.data,
+                                    ResponsePriority::Low,
+                                );
+                                rpc_engine.report_telemetry_send(cid, delivered);
                             }
                         }
                     }
                 }
 
+                Event::BrownoutWarning => {
+                    // Supply is sagging: no half-written OTA image (one
+                    // erase drops its resume record), no other flash
+                    // writes, outputs off before the rail goes.
+                    if rpc_engine.abort_ota(&mut nvs) {
+                        warn!("OTA aborted on brown-out");
+                    }
+                    for zone in &mut zones {
+                        zone.app.on_brownout(&mut zone.hw, &mut log_sink);
+                    }
+                    watchdog.feed();
+                    power::rearm_brownout();
+                }
+
                 Event::SafetyFault => {
                     // Safety supervisor runs inside app.tick() — faults
                     // are evaluated on the next ControlTick automatically.
@@ -395,25 +548,83 @@ fn main() -> Result<()> {
                 Event::UlpWake => {
                     info!("ULP wake in event loop");
                     power_mgr.stop_ulp_monitor();
+                    rpc_engine.record_ulp_state(power_mgr.read_ulp_state());
                     activity = true;
                 }
 
                 Event::ScheduledScrub => {
-                    app.handle_command(AppCommand::StartScrub, &mut hw, &mut log_sink);
+                    // Schedules are unit-wide: every loop scrubs.
+                    for zone in &mut zones {
+                        zone.app.handle_command(
+                            AppCommand::StartScrub(CommandSource::Schedule),
+                            &mut zone.hw,
+                            &mut log_sink,
+                        );
+                    }
                     activity = true;
                 }
 
                 Event::CommandReceived => {
                     while let Some(cmd) = rpc::io_task::try_recv_command() {
+                        // A request for a zone the unit lacks lands on the
+                        // primary zone, which rejects it.
+                        let zone =
+                            zones.route_mut(rpc::engine::RpcEngine::request_zone(&cmd.frame));
                         if let Some(resp) = rpc_engine.dispatch(
                             cmd.client_id,
                             &cmd.frame,
-                            &mut app,
-                            &mut hw,
+                            &mut zone.app,
+                            &mut zone.hw,
                             &mut log_sink,
                             &mut nvs,
                         ) {
-                            rpc::io_task::send_response(resp.client_id, resp.data);
+                            rpc::io_task::send_response(
+                                resp.client_id,
+                                resp.data,
+                                ResponsePriority::High,
+                            );
+                        }
+                        // Scans block for ~2 s; run here where the radio is owned.
+                        if let Some((cid, reply_to)) = rpc_engine.take_pending_wifi_scan() {
+                            let aps = wifi.scan();
+                            if let Some(resp) =
+                                rpc_engine.build_wifi_scan_response(cid, reply_to, &aps)
+                            {
+                                rpc::io_task::send_response(
+                                    resp.client_id,
+                                    resp.data,
+                                    ResponsePriority::High,
+                                );
+                            }
+                        }
+                        if let Some(delay_ms) = rpc_engine.take_pending_restart() {
+                            power_mgr.schedule_restart(time_adapter.uptime_us() / 1000, delay_ms);
+                        }
+                        // Cert parsing is slow; ack now, reload on the next tick.
+                        if rpc_engine.take_pending_tls_reconfigure() {
+                            push_event(Event::TlsReconfigure);
+                        }
+                        if rpc_engine.take_pending_factory_reset() {
+                            warn!("RPC: FACTORY RESET");
+                            nvs.factory_reset();
+                            info!("Factory reset: credentials erased, restart scheduled");
+                            power_mgr.schedule_restart(
+                                time_adapter.uptime_us() / 1000,
+                                rpc::engine::FACTORY_RESET_RESTART_DELAY_MS,
+                            );
+                        }
+                    }
+                    // Frames the I/O task dropped on a full queue: tell
+                    // the sender to retry rather than let it time out.
+                    while let Some(dropped) = rpc::io_task::try_recv_dropped() {
+                        if let Some(resp) =
+                            rpc_engine.build_overload_nack(dropped.client_id, dropped.msg_id)
+                        {
+                            rpc::io_task::send_response(
+                                resp.client_id,
+                                resp.data,
+                                ResponsePriority::High,
+                            );
                         }
                     }
                     while let Some(disc) = rpc::io_task::try_recv_disconnect() {
@@ -424,17 +635,34 @@ fn main() -> Result<()> {
                     activity = true;
                 }
 
+                Event::TlsReconfigure => {
+                    match rpc_engine.load_cert_bundle() {
+                        Some(bundle) => rpc::io_task::request_tls_reload(bundle),
+                        None => warn!("TLS: provisioned certificates could not be read back"),
+                    }
+                }
+
                 Event::ButtonShortPress => {
                     info!("Button: short press → toggle scrub");
-                    app.handle_command(AppCommand::StartScrub, &mut hw, &mut log_sink);
+                    // One button for the unit: stop every loop if any is
+                    // scrubbing, otherwise start them all.
+                    let scrubbing = zones
+                        .iter()
+                        .any(|z| matches!(z.app.state(), StateId::Active | StateId::Purging));
+                    for zone in &mut zones {
+                        let cmd = if scrubbing {
+                            AppCommand::StopScrub(CommandSource::Button)
+                        } else {
+                            AppCommand::StartScrub(CommandSource::Button)
+                        };
+                        zone.app.handle_command(cmd, &mut zone.hw, &mut log_sink);
+                    }
                     activity = true;
                 }
 
                 Event::ButtonLongPress => {
                     warn!("Button: long press → FACTORY RESET");
-                    let _ = nvs.erase_credentials();
-                    let _ = nvs.delete("auth", "wifi_ssid");
-                    let _ = nvs.delete("auth", "wifi_pass");
+                    nvs.factory_reset();
                     info!("Factory reset: credentials erased, restarting...");
                     #[cfg(target_os = "espidf")]
                     unsafe {
@@ -445,7 +673,7 @@ fn main() -> Result<()> {
 
                 Event::ButtonDoublePress => {
                     info!("Button: double press → manual boost (5 min)");
-                    if sched.add(Schedule {
+                    if zones.primary_mut().app.scheduler_mut().add(Schedule {
                         label: "manual-boost",
                         kind: ScheduleKind::Boost { duration_secs: 300 },
                         enabled: true,
@@ -457,7 +685,7 @@ fn main() -> Result<()> {
 
                 Event::BleConnected => {
                     ble.on_central_connected();
-                    rpc::io_task::ble_set_connected(128);
+                    rpc::io_task::ble_set_connected(adapters::ble_transport::negotiated_mtu());
                     activity = true;
                 }
 
@@ -491,10 +719,34 @@ fn main() -> Result<()> {
                     activity = true;
                 }
 
+                Event::WifiLinkChanged => match wifi.apply_link_events() {
+                    Some(WifiState::Connected) => {
+                        info!("WiFi: link up, announcing mDNS");
+                        mdns.start();
+                        activity = true;
+                    }
+                    Some(WifiState::Reconnecting { .. }) => {
+                        mdns.stop();
+                        activity = true;
+                    }
+                    _ => {}
+                },
+
                 _ => {}
             }
         });
 
+        // Tell subscribers about state and config changes, whatever made
+        // them: a request, the button, a schedule or the FSM itself.
+        for zone in &mut zones {
+            if let Some((from, to)) = zone.app.take_state_change() {
+                rpc_engine.broadcast_state_change(zone.app.zone(), from, to);
+            }
+            if zone.app.take_config_changed() {
+                rpc_engine.broadcast_config_changed(&zone.app);
+            }
+        }
+
         // Drain BLE RPC responses from io_task and send over GATT notify.
         while let Some(resp) = rpc::io_task::try_recv_ble_response() {
             ble.send_rpc_response(&resp);
@@ -516,36 +768,59 @@ fn main() -> Result<()> {
             }
         }
 
-        // Connectivity overlay (BLE / WiFi / OTA / low-water) — priority order.
+        // Status LED, power profile and unit-wide settings follow the
+        // primary zone; zone faults are shared, so its state covers all.
+        let app = &zones.primary().app;
+
+        // Connectivity overlay (BLE / WiFi / OTA / low-water) — configured priority order.
         {
             use crate::adapters::ble::ProvisioningPort;
-            use crate::error::SafetyFault;
             use crate::rpc::ota::OtaState;
-            if rpc_engine.ota_mut().state() != OtaState::Idle {
-                led_engine.set_connectivity_pattern(COLOUR_OTA, PatternId::FastBlink);
-            } else if app.fault_flags() & SafetyFault::WaterLevelLow.mask() != 0 {
-                led_engine.set_connectivity_pattern(COLOUR_LOW_WATER, PatternId::SlowPulse);
-            } else if wifi.is_connected() {
-                led_engine.set_connectivity_pattern(COLOUR_WIFI_CONNECTED, PatternId::Solid);
-            } else if ble.is_active() {
-                led_engine.set_connectivity_pattern(COLOUR_PROVISIONING, PatternId::DoubleBlink);
-            } else {
-                led_engine.clear_connectivity();
+            match select_overlay(
+                app.fault_flags(),
+                rpc_engine.ota_mut().state() != OtaState::Idle,
+                wifi.is_connected(),
+                ble.is_active(),
+                &app.current_config().led_overlay_priority,
+            ) {
+                Some((colour, pattern)) => led_engine.set_connectivity_pattern(colour, pattern),
+                None => led_engine.clear_connectivity(),
             }
         }
 
         // LED pattern engine — update based on current FSM state.
+        let live_config = app.current_config();
+        let timing = live_config.power_timing();
+        if timing.control_loop_interval_ms != control_interval_ms {
+            control_interval_ms = timing.control_loop_interval_ms;
+            tick_secs = control_interval_ms as f32 / 1000.0;
+            drivers::hw_timer::set_control_interval(control_interval_ms);
+        }
+        led_engine.set_overrides(&live_config.led_overrides);
         let led_pattern = match app.state() {
-            StateId::Idle => (COLOUR_IDLE, PatternId::Solid),
-            StateId::Sensing => (COLOUR_SENSING, PatternId::SlowPulse),
-            StateId::Active => (COLOUR_ACTIVE, PatternId::Solid),
-            StateId::Purging => (COLOUR_PURGING, PatternId::Breathing),
+            StateId::Error if app.is_recovering() => (COLOUR_RECOVERING, PatternId::SlowPulse),
             StateId::Error => (COLOUR_IDLE, PatternId::Off), // error_pattern handles this
+            StateId::Idle if config_safe_mode => (COLOUR_SAFE_MODE, PatternId::DoubleBlink),
+            StateId::Idle if app.in_maintenance() => (COLOUR_MAINTENANCE, PatternId::Breathing),
+            state => led_engine.state_pattern(state),
         };
         led_engine.set_fsm_pattern(led_pattern.0, led_pattern.1);
-        led_engine.set_error_pattern(app.state() == StateId::Error);
-        let (lr, lg, lb) = led_engine.tick(config.control_loop_interval_ms);
-        hw.set_led(lr, lg, lb);
+        led_engine.set_error_pattern(app.state() == StateId::Error && !app.is_recovering());
+        led_engine.set_fallback_state(app.state());
+        if led_failed == LED_FAILED_ALL && blink_code_logged != Some(app.state()) {
+            // No channel left to blink — the log is the only indicator.
+            warn!(
+                "Status LED unavailable: state {} (blink code {})",
+                app.state_name(),
+                blink_code(app.state())
+            );
+            blink_code_logged = Some(app.state());
+        }
+        led_engine.set_brightness(live_config.led_brightness);
+        led_engine.set_quiet_brightness(live_config.led_quiet_brightness);
+        led_engine.set_quiet(app.scheduler().is_quiet_hour(current_hour));
+        let (lr, lg, lb) = led_engine.tick(control_interval_ms);
+        zones.primary_mut().hw.set_led(lr, lg, lb);
 
         // BLE→WiFi provisioning flow:
         // Check if BLE has received credentials, attempt WiFi connection.
@@ -579,27 +854,87 @@ fn main() -> Result<()> {
 
             if let Some(psk) = ble.take_pending_psk() {
                 info!("Provisioning: PSK received, storing in encrypted NVS");
-                if let Err(e) = nvs.store_credential("psk", &psk) {
-                    warn!("Failed to store PSK: {:?}", e);
+                match nvs.store_credential("psk", &psk) {
+                    Ok(()) => rpc_engine.rotate_psk(&psk, time_adapter.uptime_us() / 1000),
+                    Err(e) => warn!("Failed to store PSK: {:?}", e),
                 }
             }
         }
 
-        // WiFi reconnection poll (exponential backoff).
+        // Sustained inbound frame loss raises the overload advisory.
+        let app = &mut zones.primary_mut().app;
+        if drop_monitor.sample(time_adapter.uptime_us() / 1000, rpc::channels::command_drops()) {
+            app.raise_advisory(error::Advisory::Overloaded);
+        } else {
+            app.clear_advisory(error::Advisory::Overloaded);
+        }
+
+        // Failed-auth lockout and the pre-auth ping gate from the live
+        // config (SetAuthLockoutRequest, SetPingAuthRequest).
+        rpc_engine.set_auth_lockout_threshold(live_config.auth_lockout_threshold);
+        rpc_engine.set_ping_requires_auth(live_config.ping_requires_auth);
+
+        // Stop accepting the pre-rotation PSK once its grace period ends.
+        rpc_engine.set_psk_grace_ms(u64::from(live_config.psk_grace_secs) * 1000);
+        rpc_engine.expire_previous_psk(time_adapter.uptime_us() / 1000);
+
+        // Abandon an OTA transfer whose client has gone quiet.
+        if rpc_engine.expire_stale_ota(time_adapter.uptime_us() / 1000, &mut nvs) {
+            warn!("OTA transfer expired");
+        }
+
+        // Reap silent TCP clients after the live config's idle timeout.
+        rpc::io_task::set_tcp_idle_timeout(live_config.tcp_idle_timeout_secs);
+
+        // WiFi reconnection poll (exponential backoff), roaming on the
+        // live config's trigger (SetWifiRoamRequest).
+        wifi.set_roam_threshold(
+            live_config.wifi_roam_rssi_dbm,
+            live_config.wifi_roam_samples,
+        );
         wifi.poll();
 
         // Config auto-save (5s debounce after last change).
-        app.auto_save_if_needed(&nvs);
+        for zone in &mut zones {
+            let store = nvs.zone_config(zone.app.zone());
+            zone.app.auto_save_if_needed(&store);
+            zone.app.flow_totals_mut().save_if_due(&mut nvs);
+        }
 
         // Feed watchdog on every iteration.
         watchdog.feed();
 
+        // RPC-requested restart, once the ack has had time to drain.  An
+        // OTA transfer begun during the delay holds it: finalizing reboots
+        // anyway, and after an abort the restart goes ahead.
+        let restart_due = power_mgr.is_restart_due(time_adapter.uptime_us() / 1000);
+        let ota_active = rpc_engine.ota_mut().is_transfer_active();
+        if restart_due && ota_active && !restart_held {
+            warn!("Restart held until the OTA transfer ends");
+        }
+        restart_held = restart_due && ota_active;
+        if restart_due && !ota_active {
+            info!("Restarting — graceful shutdown");
+            mdns.stop();
+            wifi.disconnect();
+            ble.stop();
+            shut_down_zones(&mut zones, &mut nvs);
+            watchdog.feed();
+            power_mgr.restart();
+        }
+
         // Power management.
-        if let Some(mode) = power_mgr.tick(activity) {
+        power_mgr.set_timing(timing);
+        let restart_pending = power_mgr.is_restart_pending();
+        let busy = activity || events_pending || restart_pending;
+        let all_idle = zones.iter().all(|z| z.app.state() == StateId::Idle);
+        if let Some(mode) = power_mgr.tick(busy, time_adapter.uptime_us() / 1000) {
             match mode {
-                PowerMode::LightSleep if app.state() == StateId::Idle => {
+                PowerMode::LightSleep if all_idle => {
                     mdns.stop();
-                    hw.all_off();
+                    for zone in &mut zones {
+                        zone.hw.all_off();
+                    }
                     watchdog.feed();
                     let _wake = power_mgr.enter_light_sleep(60);
                     // Re-announce on wake
@@ -607,18 +942,73 @@ fn main() -> Result<()> {
                         mdns.start();
                     }
                 }
-                PowerMode::DeepSleep if app.state() == StateId::Idle => {
+                PowerMode::DeepSleep if all_idle => {
                     info!("Entering deep sleep — graceful shutdown");
                     mdns.stop();
                     wifi.disconnect();
                     ble.stop();
-                    app.force_save_if_dirty(&nvs);
-                    hw.all_off();
+                    shut_down_zones(&mut zones, &mut nvs);
                     watchdog.feed();
-                    power_mgr.enter_deep_sleep(500);
+                    power_mgr.enter_deep_sleep(&DeepSleepWake {
+                        nh3_threshold_adc: 500,
+                        timer_secs: zones.primary().app.scheduler().secs_until_next_fire(),
+                        button: true,
+                    });
                 }
                 _ => {}
             }
         }
     }
 }
+
+/// Persist every zone's unsaved config and flow totals, then switch its
+/// outputs off.
+fn shut_down_zones(zones: &mut ZoneSet<HardwareAdapter>, nvs: &mut NvsAdapter) {
+    for zone in zones {
+        let store = nvs.zone_config(zone.app.zone());
+        zone.app.force_save_if_dirty(&store);
+        zone.app.flow_totals_mut().flush(nvs);
+        zone.hw.all_off();
+    }
+}
+
+/// Zone 1's service, on its own stored config.
+#[cfg(feature = "dual-zone")]
+fn zone_b_service(nvs: &NvsAdapter) -> AppService {
+    let config = nvs.zone_config(1).load().unwrap_or_else(|e| {
+        warn!("Zone 1: config load failed ({}), using defaults", e);
+        SystemConfig::default()
+    });
+    let mut app = AppService::for_zone(1, config);
+    app.flow_totals_mut().init(nvs);
+    app
+}
+
+/// Zone 1's sensors and outputs on the dual-zone carrier.  The
+/// thermistor and the UVC interlock are shared with zone 0.
+#[cfg(feature = "dual-zone")]
+fn zone_b_hardware(config: &SystemConfig) -> HardwareAdapter {
+    use drivers::hw_init::{ADC1_CH_NH3_B, LEDC_CH_PUMP_B};
+    use pins::zone_b;
+
+    let sensor_hub = sensors::SensorHub::new(
+        sensors::ammonia::AmmoniaSensor::new(zone_b::NH3_ADC_GPIO).on_adc_channel(ADC1_CH_NH3_B),
+        sensors::flow::FlowSensor::new(zone_b::FLOW_PULSE_GPIO)
+            .on_counter(&sensors::flow::FLOW_B_PULSES),
+        sensors::water_level::WaterLevelSensor::new(
+            zone_b::WATER_LEVEL_A_GPIO,
+            zone_b::WATER_LEVEL_B_GPIO,
+        ),
+        sensors::temperature::TemperatureSensor::new(pins::TEMP_ADC_GPIO, config.max_temperature_c),
+        pins::UVC_INTERLOCK_GPIO,
+    );
+    HardwareAdapter::secondary(
+        sensor_hub,
+        PumpDriver::new().on_pins(
+            LEDC_CH_PUMP_B,
+            zone_b::PUMP_DIR_GPIO,
+            zone_b::TANK_SELECT_GPIO,
+        ),
+        UvcDriver::new().on_pins(zone_b::UVC_ENABLE_GPIO, None),
+    )
+}
@@ -6,22 +6,52 @@
 //!
 //! Pin assignments match the schematic in `docs/engineering/system-architecture.md`.
 
+// ---------------------------------------------------------------------------
+// Output polarity
+// ---------------------------------------------------------------------------
+
+/// Electrical level that asserts a digital output.  Relay boards differ:
+/// some energise on HIGH, others on LOW.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActiveLevel {
+    High,
+    Low,
+}
+
+impl ActiveLevel {
+    /// GPIO level that drives the output `asserted` (or released).
+    pub const fn level(self, asserted: bool) -> bool {
+        match self {
+            Self::High => asserted,
+            Self::Low => !asserted,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pump motor driver (DRV8871 H-bridge)
 // ---------------------------------------------------------------------------
 
 /// LEDC PWM channel for pump speed control.
 pub const PUMP_PWM_GPIO: i32 = 1;
-/// Digital output: HIGH = forward, LOW = reverse.
+/// Digital output: asserted = forward, released = reverse.
 pub const PUMP_DIR_GPIO: i32 = 2;
+/// Polarity of [`PUMP_DIR_GPIO`]; HIGH = forward on the reference board.
+pub const PUMP_DIR_ACTIVE: ActiveLevel = ActiveLevel::High;
+/// Digital output: pump source valve, released = Tank A, asserted = Tank B.
+pub const TANK_SELECT_GPIO: i32 = 21;
+/// Polarity of [`TANK_SELECT_GPIO`]; HIGH = Tank B on the reference board.
+pub const TANK_SELECT_ACTIVE: ActiveLevel = ActiveLevel::High;
 
 // ---------------------------------------------------------------------------
 // UVC LED driver (PT4115 constant-current buck)
 // ---------------------------------------------------------------------------
 
-/// Digital output: enables the UVC LED driver (active HIGH).
+/// Digital output: enables the UVC LED driver when asserted.
 /// Routed through a relay in series for hardware-level interlock.
 pub const UVC_ENABLE_GPIO: i32 = 3;
+/// Polarity of [`UVC_ENABLE_GPIO`]; set `Low` for active-low relay boards.
+pub const UVC_ENABLE_ACTIVE: ActiveLevel = ActiveLevel::High;
 /// LEDC PWM channel for UVC dimming (optional — can be tied HIGH).
 pub const UVC_PWM_GPIO: i32 = 4;
 /// Digital input: reed-switch interlock on UVC chamber lid.
@@ -95,3 +125,34 @@ pub const LED_PWM_FREQ_HZ: u32 = 1_000;
 
 /// Momentary push-button for manual control.
 pub const BUTTON_GPIO: i32 = 16;
+
+// ---------------------------------------------------------------------------
+// Second filter loop (dual-zone carrier, `dual-zone` feature)
+// ---------------------------------------------------------------------------
+
+/// Sensors and actuators of the second reservoir.  The UVC lid interlock,
+/// heatsink thermistor, status LED and button are shared with zone 0, and
+/// the output polarities match the zone-0 constants above.
+///
+/// ADC1 has no free channel on the main board, so the carrier ties zone
+/// 0's UVC dimming input HIGH (full brightness) and gives its GPIO to
+/// this zone's NH3 sensor.  Zone 1's UVC has no dimming either.
+pub mod zone_b {
+    /// MQ-137 NH3 sensor — ADC1 channel 3 (GPIO 4, zone 0's UVC PWM pin).
+    pub const NH3_ADC_GPIO: i32 = 4;
+    /// YF-S201 flow sensor — pulse output, interrupt-driven.
+    pub const FLOW_PULSE_GPIO: i32 = 38;
+    /// Water-level sensor — Tank A (supply). HIGH = water present.
+    pub const WATER_LEVEL_A_GPIO: i32 = 39;
+    /// Water-level sensor — Tank B (collection). HIGH = water present.
+    pub const WATER_LEVEL_B_GPIO: i32 = 40;
+    /// LEDC PWM output for pump speed.
+    pub const PUMP_PWM_GPIO: i32 = 41;
+    /// Digital output: asserted = forward, released = reverse.
+    pub const PUMP_DIR_GPIO: i32 = 42;
+    /// Digital output: pump source valve, asserted = Tank B.
+    pub const TANK_SELECT_GPIO: i32 = 47;
+    /// Digital output: UVC driver enable (relay in series with the
+    /// shared interlock).
+    pub const UVC_ENABLE_GPIO: i32 = 48;
+}
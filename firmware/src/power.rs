@@ -39,7 +39,7 @@
 //!   `std::thread::sleep`, and deep sleep panics (since the CPU would reset
 //!   on real hardware and `main()` re-enters).
 
-use crate::config::SystemConfig;
+use crate::config::{PowerTiming, SystemConfig};
 use log::info;
 
 // ── Error type ────────────────────────────────────────────────
@@ -51,6 +51,8 @@ pub enum PowerError {
     UlpLoadFailed(i32),
     /// `ulp_riscv_run()` returned a non-OK error code.
     UlpStartFailed(i32),
+    /// `rtc_isr_register()` rejected the brown-out interrupt handler.
+    BrownoutIsrFailed(i32),
 }
 
 impl core::fmt::Display for PowerError {
@@ -58,6 +60,9 @@ impl core::fmt::Display for PowerError {
         match self {
             Self::UlpLoadFailed(rc) => write!(f, "ULP binary load failed (rc={})", rc),
             Self::UlpStartFailed(rc) => write!(f, "ULP run failed (rc={})", rc),
+            Self::BrownoutIsrFailed(rc) => {
+                write!(f, "brown-out ISR registration failed (rc={})", rc)
+            }
         }
     }
 }
@@ -79,7 +84,7 @@ pub struct UlpSharedState {
     pub nh3_above_count: u32,
     /// Required consecutive readings before wake.
     pub nh3_confirm_count: u32,
-    /// ULP cycle counter.
+    /// ULP sampling cycles since the monitor was last started.
     pub cycle_count: u32,
     /// Stop flag (main CPU → ULP).
     pub stop_flag: u32,
@@ -162,6 +167,15 @@ unsafe fn ulp_write_confirm_count(val: u32) {
     }
 }
 
+/// Zero the ULP cycle counter so it counts one sleep period.
+/// SAFETY: Same as `ulp_write_threshold()`.
+#[cfg(target_os = "espidf")]
+unsafe fn ulp_write_cycle_count(val: u32) {
+    unsafe {
+        ulp_ulp_cycle_count = val;
+    }
+}
+
 /// Set the stop flag to signal the ULP to halt.
 /// SAFETY: Written before reading shared fields; read by ULP in its loop.
 #[cfg(target_os = "espidf")]
@@ -171,6 +185,120 @@ unsafe fn ulp_write_stop_flag(val: u32) {
     }
 }
 
+// ── Brown-out detection ───────────────────────────────────────
+//
+// The stock ESP-IDF brown-out ISR restarts the chip from the interrupt,
+// which leaves the pump and UVC driven right up to the moment the rail
+// collapses.  `CONFIG_ESP_BROWNOUT_DET` stays enabled so boot is covered,
+// and the stock ISR is replaced here once the event loop exists: the
+// detector keeps its hardware reset, and during the reset delay our ISR
+// pushes `Event::BrownoutWarning` so the main loop can shut outputs down.
+// Flash stays powered until the reset — the recovery path runs from it.
+
+/// Detector threshold level (0–7); level 7 trips at ≈2.80 V on the S3.
+#[cfg(target_os = "espidf")]
+const BROWNOUT_LEVEL: i32 = 7;
+
+/// `RTC_CNTL_BROWN_OUT_INT_ENA_M` on the ESP32-S3.
+#[cfg(target_os = "espidf")]
+const RTC_CNTL_BROWN_OUT_INT_ENA_M: u32 = 1 << 9;
+
+/// `RTC_INTR_FLAG_IRAM`: the handler is safe to run with the flash
+/// cache disabled (an NVS write may be in progress when the rail sags).
+#[cfg(target_os = "espidf")]
+const RTC_INTR_FLAG_IRAM: u32 = 1 << 0;
+
+// SAFETY: Plain C entry points from private ESP-IDF headers
+// (`esp_private/rtc_ctrl.h`, `esp_private/brownout.h`) that esp-idf-sys
+// does not bind.
+#[cfg(target_os = "espidf")]
+unsafe extern "C" {
+    fn esp_brownout_disable();
+    fn rtc_isr_register(
+        handler: Option<unsafe extern "C" fn(*mut core::ffi::c_void)>,
+        arg: *mut core::ffi::c_void,
+        intr_mask: u32,
+        flags: u32,
+    ) -> i32;
+}
+
+/// Brown-out ISR: masks itself until re-armed so a sagging rail cannot
+/// flood the event queue, then wakes the main loop.  Lives in IRAM; the
+/// `brownout_hal` calls are IRAM-resident (the stock ISR relies on the
+/// same) and [`push_event`](crate::events::push_event) is inlined here.
+#[cfg(target_os = "espidf")]
+#[unsafe(link_section = ".iram1.brownout_isr")]
+unsafe extern "C" fn brownout_isr(_arg: *mut core::ffi::c_void) {
+    // SAFETY: register writes on the RTC controller; safe in ISR context.
+    unsafe {
+        esp_idf_sys::brownout_hal_intr_enable(false);
+        esp_idf_sys::brownout_hal_intr_clear();
+    }
+    crate::events::push_event(crate::events::Event::BrownoutWarning);
+}
+
+/// Replace the stock brown-out ISR with [`brownout_isr`], keeping the
+/// hardware reset armed.  Call once during init, before the event loop.
+#[cfg(target_os = "espidf")]
+pub fn install_brownout_handler() -> Result<(), PowerError> {
+    let cfg = esp_idf_sys::brownout_hal_config_t {
+        threshold: BROWNOUT_LEVEL,
+        enabled: true,
+        reset_enabled: true,
+        flash_power_down: false,
+        rf_power_down: true,
+    };
+    // SAFETY: `cfg` outlives the call; the ISR only touches RTC registers
+    // and the lock-free event queue.
+    unsafe {
+        // Drops the stock restart-from-ISR handler.
+        esp_brownout_disable();
+        esp_idf_sys::brownout_hal_config(&cfg);
+        let rc = rtc_isr_register(
+            Some(brownout_isr),
+            core::ptr::null_mut(),
+            RTC_CNTL_BROWN_OUT_INT_ENA_M,
+            RTC_INTR_FLAG_IRAM,
+        );
+        if rc != esp_idf_sys::ESP_OK {
+            return Err(PowerError::BrownoutIsrFailed(rc));
+        }
+        esp_idf_sys::brownout_hal_intr_clear();
+        esp_idf_sys::brownout_hal_intr_enable(true);
+    }
+    info!("Brown-out detector armed (level {})", BROWNOUT_LEVEL);
+    Ok(())
+}
+
+/// Simulation: there is no detector; use [`inject_brownout`] instead.
+#[cfg(not(target_os = "espidf"))]
+#[allow(clippy::unnecessary_wraps)] // mirrors the fallible espidf variant
+pub fn install_brownout_handler() -> Result<(), PowerError> {
+    info!("Brown-out detector (simulated) — use inject_brownout()");
+    Ok(())
+}
+
+/// Re-enable the brown-out interrupt after the main loop has handled a
+/// warning.  A supply that is still low re-triggers immediately.
+#[cfg(target_os = "espidf")]
+pub fn rearm_brownout() {
+    // SAFETY: RTC register writes; no shared state.
+    unsafe {
+        esp_idf_sys::brownout_hal_intr_clear();
+        esp_idf_sys::brownout_hal_intr_enable(true);
+    }
+}
+
+#[cfg(not(target_os = "espidf"))]
+pub fn rearm_brownout() {}
+
+/// Simulation: raise a brown-out warning as the ISR would.
+/// Returns `false` if the event queue was full.
+#[cfg(not(target_os = "espidf"))]
+pub fn inject_brownout() -> bool {
+    crate::events::push_event(crate::events::Event::BrownoutWarning)
+}
+
 // ── Power modes ───────────────────────────────────────────────
 
 /// System power mode.
@@ -203,31 +331,143 @@ pub enum WakeReason {
     Unknown,
 }
 
+impl WakeReason {
+    /// Map a raw `esp_sleep_source_t` value.  The button's EXT0 wake and
+    /// the other GPIO sources all report [`WakeReason::Gpio`]; sources
+    /// this firmware never arms map to [`WakeReason::Unknown`].
+    pub fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => Self::PowerOn,
+            2 | 3 | 7 => Self::Gpio,
+            4 => Self::Timer,
+            5 => Self::Touchpad,
+            6 => Self::UlpWake,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Wake sources armed before deep sleep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeepSleepWake {
+    /// NH3 ADC level at which the ULP monitor wakes the CPU.
+    pub nh3_threshold_adc: u32,
+    /// RTC timer wake, normally the time until the next schedule is due.
+    /// `None` leaves the timer disarmed.
+    pub timer_secs: Option<u32>,
+    /// Wake on a press of the user button (EXT0, active-low).
+    pub button: bool,
+}
+
+/// Cause of the most recent chip reset, mirroring ESP-IDF
+/// `esp_reset_reason_t`.  Complements [`WakeReason`]: a wake from deep
+/// sleep is one kind of reset; this tells the others apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetCause {
+    /// Cause could not be determined.
+    Unknown,
+    /// Power-on or first boot.
+    PowerOn,
+    /// External pin reset.
+    External,
+    /// `esp_restart()` — OTA completion, remote restart, factory reset.
+    Software,
+    /// Exception or panic.
+    Panic,
+    /// Interrupt watchdog.
+    InterruptWatchdog,
+    /// Task watchdog.
+    TaskWatchdog,
+    /// Other watchdog (RTC or main timer group).
+    Watchdog,
+    /// Wake from deep sleep; see [`WakeReason`] for the source.
+    DeepSleep,
+    /// Supply voltage dropped below the brownout threshold.
+    Brownout,
+    /// Reset over SDIO.
+    Sdio,
+    /// Reset by the USB peripheral.
+    Usb,
+    /// Reset by JTAG.
+    Jtag,
+    /// eFuse error.
+    Efuse,
+    /// Power glitch detected.
+    PowerGlitch,
+    /// CPU lock-up (double exception).
+    CpuLockup,
+}
+
+impl ResetCause {
+    /// Map a raw `esp_reset_reason_t` value.  Codes added by later IDF
+    /// releases map to [`ResetCause::Unknown`].
+    pub fn from_raw(raw: u32) -> Self {
+        match raw {
+            1 => Self::PowerOn,
+            2 => Self::External,
+            3 => Self::Software,
+            4 => Self::Panic,
+            5 => Self::InterruptWatchdog,
+            6 => Self::TaskWatchdog,
+            7 => Self::Watchdog,
+            8 => Self::DeepSleep,
+            9 => Self::Brownout,
+            10 => Self::Sdio,
+            11 => Self::Usb,
+            12 => Self::Jtag,
+            13 => Self::Efuse,
+            14 => Self::PowerGlitch,
+            15 => Self::CpuLockup,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// True for resets that indicate a fault worth investigating.
+    pub fn is_abnormal(self) -> bool {
+        matches!(
+            self,
+            Self::Panic
+                | Self::InterruptWatchdog
+                | Self::TaskWatchdog
+                | Self::Watchdog
+                | Self::Brownout
+                | Self::PowerGlitch
+                | Self::CpuLockup
+        )
+    }
+}
+
 // ── Power manager ─────────────────────────────────────────────
 
 /// Manages power state transitions and ULP lifecycle.
 pub struct PowerManager {
     mode: PowerMode,
     ulp_state: UlpSharedState,
-    /// Seconds of idle before transitioning to light sleep.
-    idle_to_light_secs: u32,
-    /// Seconds of idle before transitioning to deep sleep.
-    idle_to_deep_secs: u32,
-    /// Ticks since last meaningful activity (NH3 event, user interaction).
-    idle_ticks: u64,
+    /// Sleep timeouts from the config's power profile.
+    timing: PowerTiming,
+    /// Uptime (ms) of the last meaningful activity (NH3 event, user
+    /// interaction); idle time is measured from here.
+    last_activity_ms: u64,
     /// Whether ULP program has been loaded into RTC memory.
     ulp_loaded: bool,
+    /// Uptime (ms) at which a requested software restart becomes due.
+    restart_at_ms: Option<u64>,
+    /// Simulation stand-in for `esp_restart()`; observable by tests.
+    #[cfg(not(target_os = "espidf"))]
+    restarted: bool,
 }
 
 impl PowerManager {
-    pub fn new(_config: &SystemConfig) -> Self {
+    pub fn new(config: &SystemConfig) -> Self {
         Self {
             mode: PowerMode::Active,
             ulp_state: UlpSharedState::default(),
-            idle_to_light_secs: 300, // 5 minutes
-            idle_to_deep_secs: 1800, // 30 minutes
-            idle_ticks: 0,
+            timing: config.power_timing(),
+            last_activity_ms: 0,
             ulp_loaded: false,
+            restart_at_ms: None,
+            #[cfg(not(target_os = "espidf"))]
+            restarted: false,
         }
     }
 
@@ -237,16 +477,7 @@ impl PowerManager {
     /// sleep wakeup cause register.
     #[cfg(target_os = "espidf")]
     pub fn determine_wake_reason(&self) -> WakeReason {
-        use esp_idf_sys::*;
-
-        match unsafe { esp_sleep_get_wakeup_cause() } {
-            esp_sleep_source_t_ESP_SLEEP_WAKEUP_ULP => WakeReason::UlpWake,
-            esp_sleep_source_t_ESP_SLEEP_WAKEUP_TIMER => WakeReason::Timer,
-            esp_sleep_source_t_ESP_SLEEP_WAKEUP_GPIO => WakeReason::Gpio,
-            esp_sleep_source_t_ESP_SLEEP_WAKEUP_TOUCHPAD => WakeReason::Touchpad,
-            esp_sleep_source_t_ESP_SLEEP_WAKEUP_UNDEFINED => WakeReason::PowerOn,
-            _ => WakeReason::Unknown,
-        }
+        WakeReason::from_raw(unsafe { esp_idf_sys::esp_sleep_get_wakeup_cause() })
     }
 
     /// Determine the wake reason on startup.
@@ -257,6 +488,20 @@ impl PowerManager {
         WakeReason::PowerOn
     }
 
+    /// Read why the chip last reset via `esp_reset_reason()`.
+    #[cfg(target_os = "espidf")]
+    pub fn determine_reset_cause(&self) -> ResetCause {
+        ResetCause::from_raw(unsafe { esp_idf_sys::esp_reset_reason() })
+    }
+
+    /// Read why the chip last reset.
+    ///
+    /// Simulation always returns [`ResetCause::PowerOn`].
+    #[cfg(not(target_os = "espidf"))]
+    pub fn determine_reset_cause(&self) -> ResetCause {
+        ResetCause::PowerOn
+    }
+
     // ── ULP program loading ───────────────────────────────────
 
     /// Mark the ULP monitor as available.
@@ -297,11 +542,13 @@ impl PowerManager {
     pub fn start_ulp_monitor(&mut self, threshold_adc: u32, confirm_count: u32) {
         self.ulp_state.nh3_threshold_adc = threshold_adc;
         self.ulp_state.nh3_confirm_count = confirm_count;
+        self.ulp_state.cycle_count = 0;
         self.ulp_state.stop_flag = 0;
 
         unsafe {
             ulp_write_threshold(threshold_adc);
             ulp_write_confirm_count(confirm_count);
+            ulp_write_cycle_count(0);
             ulp_write_stop_flag(0);
             esp_idf_sys::ulp_riscv_run();
         }
@@ -319,6 +566,7 @@ impl PowerManager {
     pub fn start_ulp_monitor(&mut self, threshold_adc: u32, confirm_count: u32) {
         self.ulp_state.nh3_threshold_adc = threshold_adc;
         self.ulp_state.nh3_confirm_count = confirm_count;
+        self.ulp_state.cycle_count = 0;
         self.ulp_state.stop_flag = 0;
 
         info!(
@@ -367,9 +615,13 @@ impl PowerManager {
 
     /// Read ULP diagnostic data after wake.
     ///
-    /// Simulation returns the in-struct state unchanged.
+    /// Simulation stubs the quickest possible wake: the confirm count of
+    /// samples, the last of them sitting at the threshold.
     #[cfg(not(target_os = "espidf"))]
     pub fn read_ulp_state(&mut self) -> &UlpSharedState {
+        self.ulp_state.nh3_last_reading = self.ulp_state.nh3_threshold_adc;
+        self.ulp_state.nh3_above_count = self.ulp_state.nh3_confirm_count;
+        self.ulp_state.cycle_count = self.ulp_state.nh3_confirm_count;
         &self.ulp_state
     }
 
@@ -419,84 +671,233 @@ impl PowerManager {
 
     // ── Deep sleep ────────────────────────────────────────────
 
-    /// Enter deep sleep with ULP + timer wakeup sources. Starts the
-    /// ULP NH3 monitor before sleeping. This function does **not**
-    /// return — the CPU resets on wake.
+    /// Enter deep sleep with the ULP NH3 monitor plus the timer and
+    /// button sources in `wake`. Starts the ULP monitor before sleeping.
+    /// This function does **not** return — the CPU resets on wake.
     #[cfg(target_os = "espidf")]
     #[allow(unreachable_code)]
-    pub fn enter_deep_sleep(&mut self, nh3_threshold_adc: u32) -> ! {
-        info!("Entering deep sleep with ULP NH3 monitor");
+    pub fn enter_deep_sleep(&mut self, wake: &DeepSleepWake) -> ! {
+        info!("Entering deep sleep: {:?}", wake);
         self.mode = PowerMode::DeepSleep;
 
-        self.start_ulp_monitor(nh3_threshold_adc, 3);
+        self.start_ulp_monitor(wake.nh3_threshold_adc, 3);
 
         unsafe {
-            esp_idf_sys::esp_sleep_enable_ulp_wakeup();
-            esp_idf_sys::esp_sleep_enable_timer_wakeup(self.idle_to_deep_secs as u64 * 1_000_000);
+            check_wake_rc(esp_idf_sys::esp_sleep_enable_ulp_wakeup(), "ULP");
+            if let Some(secs) = wake.timer_secs {
+                let rc = esp_idf_sys::esp_sleep_enable_timer_wakeup(u64::from(secs) * 1_000_000);
+                check_wake_rc(rc, "timer");
+            }
+            if wake.button {
+                // The digital pull-up is off in deep sleep; only the RTC
+                // pad's own pull keeps the active-low button from floating.
+                let gpio = crate::pins::BUTTON_GPIO;
+                check_wake_rc(esp_idf_sys::rtc_gpio_pullup_en(gpio), "button pull-up");
+                check_wake_rc(esp_idf_sys::rtc_gpio_pulldown_dis(gpio), "button pull-down");
+                check_wake_rc(esp_idf_sys::esp_sleep_enable_ext0_wakeup(gpio, 0), "button");
+            }
             esp_idf_sys::esp_deep_sleep_start();
         }
 
     }
 
-    /// Enter deep sleep mode with ULP monitoring.
+    /// Enter deep sleep mode with ULP monitoring and the extra wake
+    /// sources in `wake`.
     /// This function does **not** return — the CPU resets on wake.
     ///
     /// Simulation panics because on real hardware the CPU would reset
     /// and `main()` re-enters with a new wake reason.
     #[cfg(not(target_os = "espidf"))]
-    pub fn enter_deep_sleep(&mut self, nh3_threshold_adc: u32) -> ! {
-        info!("Entering deep sleep (simulated) with ULP NH3 monitor");
+    pub fn enter_deep_sleep(&mut self, wake: &DeepSleepWake) -> ! {
+        info!("Entering deep sleep (simulated): {:?}", wake);
         self.mode = PowerMode::DeepSleep;
 
-        self.start_ulp_monitor(nh3_threshold_adc, 3);
+        self.start_ulp_monitor(wake.nh3_threshold_adc, 3);
 
         panic!("deep_sleep: simulation — restart to continue");
     }
 
     // ── Idle tick / power transition suggestions ──────────────
 
-    /// Called each tick to track idle time and suggest power transitions.
-    pub fn tick(&mut self, activity: bool) -> Option<PowerMode> {
+    /// Called each main-loop pass to track idle time and suggest power
+    /// transitions.  Passes come at no fixed rate, so idle time is the
+    /// uptime `now_ms` since the last activity, not a count of calls.
+    pub fn tick(&mut self, activity: bool, now_ms: u64) -> Option<PowerMode> {
         if activity {
-            self.idle_ticks = 0;
+            self.last_activity_ms = now_ms;
             if self.mode != PowerMode::Active {
                 self.mode = PowerMode::Active;
             }
             return None;
         }
 
-        self.idle_ticks += 1;
-        let idle_secs = self.idle_ticks; // At 1Hz tick rate, ticks ≈ seconds.
+        let idle_ms = now_ms.saturating_sub(self.last_activity_ms);
 
-        if idle_secs >= self.idle_to_deep_secs as u64 && self.mode != PowerMode::DeepSleep {
+        if idle_ms >= u64::from(self.timing.idle_to_deep_secs) * 1000
+            && self.mode != PowerMode::DeepSleep
+        {
             return Some(PowerMode::DeepSleep);
         }
 
-        if idle_secs >= self.idle_to_light_secs as u64 && self.mode == PowerMode::Active {
+        if idle_ms >= u64::from(self.timing.idle_to_light_secs) * 1000
+            && self.mode == PowerMode::Active
+        {
             return Some(PowerMode::LightSleep);
         }
 
         None
     }
 
+    /// Adopt the timing of a newly selected profile.  Idle time already
+    /// accrued counts against the new timeouts.
+    pub fn set_timing(&mut self, timing: PowerTiming) {
+        self.timing = timing;
+    }
+
+    /// Profile timing currently in effect.
+    pub fn timing(&self) -> PowerTiming {
+        self.timing
+    }
+
     pub fn mode(&self) -> PowerMode {
         self.mode
     }
 
+    // ── Software restart ──────────────────────────────────────
+
+    /// Arm a restart `delay_ms` after `now_ms`.  A later request
+    /// replaces an earlier one.
+    pub fn schedule_restart(&mut self, now_ms: u64, delay_ms: u32) {
+        info!("Restart scheduled in {} ms", delay_ms);
+        self.restart_at_ms = Some(now_ms.saturating_add(u64::from(delay_ms)));
+    }
+
+    /// Whether a restart has been scheduled.  The main loop treats this
+    /// as activity so the device does not fall asleep before it fires.
+    pub fn is_restart_pending(&self) -> bool {
+        self.restart_at_ms.is_some()
+    }
+
+    /// Whether the scheduled restart deadline has passed.
+    pub fn is_restart_due(&self, now_ms: u64) -> bool {
+        self.restart_at_ms.is_some_and(|at| now_ms >= at)
+    }
+
+    /// Reboot the chip.  The caller is responsible for the graceful
+    /// shutdown sequence (config save, outputs off) beforehand.
+    #[cfg(target_os = "espidf")]
+    pub fn restart(&mut self) -> ! {
+        info!("Restarting");
+        unsafe { esp_idf_sys::esp_restart() }
+    }
+
+    /// Simulation: record the restart instead of resetting the host.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn restart(&mut self) {
+        info!("Restarting (simulated)");
+        self.restart_at_ms = None;
+        self.restarted = true;
+    }
+
+    /// Simulation only: whether [`restart`](Self::restart) has run.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn has_restarted(&self) -> bool {
+        self.restarted
+    }
+
     pub fn is_ulp_loaded(&self) -> bool {
         self.ulp_loaded
     }
 }
 
+/// Log a wake source that failed to arm.  Sleep still proceeds: the
+/// remaining sources (at minimum the ULP) can still wake the device.
+#[cfg(target_os = "espidf")]
+fn check_wake_rc(rc: esp_idf_sys::esp_err_t, source: &str) {
+    if rc != esp_idf_sys::ESP_OK {
+        log::warn!("Deep sleep: failed to arm {} wake (err {})", source, rc);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::SystemConfig;
+    use crate::config::{PowerProfile, SystemConfig};
 
     fn make_pm() -> PowerManager {
         PowerManager::new(&SystemConfig::default())
     }
 
+    #[test]
+    fn reset_cause_maps_every_esp_reset_code() {
+        let expected = [
+            (0, ResetCause::Unknown),
+            (1, ResetCause::PowerOn),
+            (2, ResetCause::External),
+            (3, ResetCause::Software),
+            (4, ResetCause::Panic),
+            (5, ResetCause::InterruptWatchdog),
+            (6, ResetCause::TaskWatchdog),
+            (7, ResetCause::Watchdog),
+            (8, ResetCause::DeepSleep),
+            (9, ResetCause::Brownout),
+            (10, ResetCause::Sdio),
+            (11, ResetCause::Usb),
+            (12, ResetCause::Jtag),
+            (13, ResetCause::Efuse),
+            (14, ResetCause::PowerGlitch),
+            (15, ResetCause::CpuLockup),
+            (16, ResetCause::Unknown),
+            (u32::MAX, ResetCause::Unknown),
+        ];
+        for (raw, cause) in expected {
+            assert_eq!(ResetCause::from_raw(raw), cause, "raw code {}", raw);
+        }
+    }
+
+    #[test]
+    fn wake_reason_maps_every_esp_sleep_source() {
+        let expected = [
+            (0, WakeReason::PowerOn),
+            (1, WakeReason::Unknown),
+            (2, WakeReason::Gpio),
+            (3, WakeReason::Gpio),
+            (4, WakeReason::Timer),
+            (5, WakeReason::Touchpad),
+            (6, WakeReason::UlpWake),
+            (7, WakeReason::Gpio),
+            (8, WakeReason::Unknown),
+            (9, WakeReason::Unknown),
+            (10, WakeReason::Unknown),
+            (11, WakeReason::Unknown),
+            (12, WakeReason::Unknown),
+            (u32::MAX, WakeReason::Unknown),
+        ];
+        for (raw, reason) in expected {
+            assert_eq!(WakeReason::from_raw(raw), reason, "raw source {}", raw);
+        }
+    }
+
+    #[test]
+    fn sim_wake_reason_is_power_on() {
+        assert_eq!(make_pm().determine_wake_reason(), WakeReason::PowerOn);
+    }
+
+    #[test]
+    fn abnormal_reset_causes() {
+        assert!(ResetCause::Brownout.is_abnormal());
+        assert!(ResetCause::TaskWatchdog.is_abnormal());
+        assert!(ResetCause::Panic.is_abnormal());
+        assert!(!ResetCause::PowerOn.is_abnormal());
+        assert!(!ResetCause::Software.is_abnormal());
+        assert!(!ResetCause::DeepSleep.is_abnormal());
+    }
+
+    #[test]
+    fn sim_reset_cause_is_power_on() {
+        assert_eq!(make_pm().determine_reset_cause(), ResetCause::PowerOn);
+    }
+
     #[test]
     fn starts_in_active_mode() {
         let pm = make_pm();
@@ -512,20 +913,19 @@ mod tests {
     #[test]
     fn activity_resets_idle() {
         let mut pm = make_pm();
-        for _ in 0..100 {
-            pm.tick(false);
-        }
-        assert!(pm.idle_ticks > 0);
+        let light_ms = u64::from(pm.timing.idle_to_light_secs) * 1000;
+        assert_eq!(pm.tick(false, light_ms - 1), None);
 
-        pm.tick(true);
-        assert_eq!(pm.idle_ticks, 0);
+        pm.tick(true, light_ms - 1);
+        assert_eq!(pm.last_activity_ms, light_ms - 1);
+        assert_eq!(pm.tick(false, light_ms), None);
     }
 
     #[test]
     fn no_transition_with_activity() {
         let mut pm = make_pm();
-        for _ in 0..10000 {
-            let result = pm.tick(true);
+        for s in 0..10000 {
+            let result = pm.tick(true, s * 1000);
             assert!(result.is_none());
         }
         assert_eq!(pm.mode(), PowerMode::Active);
@@ -534,12 +934,14 @@ mod tests {
     #[test]
     fn light_sleep_after_idle_threshold() {
         let mut pm = make_pm();
-        let threshold = pm.idle_to_light_secs as u64;
+        let threshold = pm.timing.idle_to_light_secs as u64;
 
-        for i in 0..=threshold {
-            let result = pm.tick(false);
-            if i == threshold {
+        for s in 0..=threshold {
+            let result = pm.tick(false, s * 1000);
+            if s == threshold {
                 assert_eq!(result, Some(PowerMode::LightSleep));
+            } else {
+                assert_eq!(result, None);
             }
         }
     }
@@ -547,15 +949,68 @@ mod tests {
     #[test]
     fn deep_sleep_after_extended_idle() {
         let mut pm = make_pm();
-        let threshold = pm.idle_to_deep_secs as u64;
+        let threshold = pm.timing.idle_to_deep_secs as u64;
 
-        for _ in 0..threshold {
-            pm.tick(false);
-        }
-        let result = pm.tick(false);
+        let result = pm.tick(false, threshold * 1000);
         assert_eq!(result, Some(PowerMode::DeepSleep));
     }
 
+    #[test]
+    fn idle_time_is_measured_not_counted() {
+        let mut pm = make_pm();
+        let light_ms = u64::from(pm.timing.idle_to_light_secs) * 1000;
+
+        // A busy loop calls tick many times per second; that alone must
+        // not bring sleep closer.
+        for _ in 0..100_000 {
+            assert_eq!(pm.tick(false, 1000), None);
+        }
+        assert_eq!(pm.tick(false, light_ms), Some(PowerMode::LightSleep));
+    }
+
+    /// Idle seconds until `tick` first suggests `mode`.
+    fn secs_until(pm: &mut PowerManager, mode: PowerMode) -> u64 {
+        (1..=100_000)
+            .find(|s| pm.tick(false, s * 1000) == Some(mode))
+            .expect("never suggested")
+    }
+
+    #[test]
+    fn each_profile_yields_documented_sleep_timing() {
+        // (profile, light sleep after, deep sleep after) in seconds.
+        let documented = [
+            (PowerProfile::Eco, 60, 600),
+            (PowerProfile::Balanced, 300, 1800),
+            (PowerProfile::Performance, 1800, 7200),
+        ];
+        for (profile, light_secs, deep_secs) in documented {
+            let cfg = SystemConfig {
+                power_profile: profile,
+                ..Default::default()
+            };
+
+            let mut pm = PowerManager::new(&cfg);
+            let light = secs_until(&mut pm, PowerMode::LightSleep);
+            assert_eq!(light, light_secs, "{profile:?} light");
+
+            let mut pm = PowerManager::new(&cfg);
+            let deep = secs_until(&mut pm, PowerMode::DeepSleep);
+            assert_eq!(deep, deep_secs, "{profile:?} deep");
+        }
+    }
+
+    #[test]
+    fn profile_switch_applies_new_timeouts() {
+        let mut pm = make_pm();
+        let eco = SystemConfig {
+            power_profile: PowerProfile::Eco,
+            ..Default::default()
+        };
+        pm.set_timing(eco.power_timing());
+
+        assert_eq!(secs_until(&mut pm, PowerMode::LightSleep), 60);
+    }
+
     #[test]
     fn ulp_shared_state_exists() {
         let state = UlpSharedState::default();
@@ -578,6 +1033,23 @@ mod tests {
         pm.start_ulp_monitor(2000, 5);
         let state = pm.read_ulp_state();
         assert_eq!(state.nh3_threshold_adc, 2000);
+        assert_eq!(state.nh3_last_reading, 2000);
+        assert_eq!(state.cycle_count, 5);
         pm.stop_ulp_monitor();
     }
+
+    #[test]
+    fn delayed_restart_fires_after_deadline() {
+        let mut pm = make_pm();
+        assert!(!pm.is_restart_pending());
+        pm.schedule_restart(10_000, 2_000);
+        assert!(pm.is_restart_pending());
+        assert!(!pm.is_restart_due(11_999));
+        assert!(pm.is_restart_due(12_000));
+        assert!(!pm.has_restarted());
+
+        pm.restart();
+        assert!(pm.has_restarted());
+        assert!(!pm.is_restart_pending());
+    }
 }
@@ -9,27 +9,87 @@
 //!
 //! Crypto is handled by the `hmac-sha256` crate — pure Rust, no_std,
 //! constant-time verification, identical on ESP-IDF and host targets.
+//!
+//! Nonces are [`NONCE_RANDOM_BYTES`] of CSPRNG output followed by a
+//! per-session challenge counter, so a session never issues the same
+//! nonce twice even if the generator misbehaves.  On the device the
+//! generator is mbedtls `ctr_drbg`, seeded from the hardware entropy
+//! source.  The host build uses `rand`'s `StdRng` seeded from the OS;
+//! it exists for tests and simulation and is **not** a production
+//! security boundary.
+//!
+//! An authenticated client can also carry its session to a new
+//! connection: the [`SessionTable`] issues a random, single-use
+//! migration token that expires after [`MIGRATION_TOKEN_TTL`], and the
+//! slot that presents it is authenticated without a handshake.
 
 use burster::Limiter;
 use core::time::Duration;
-use log::warn;
+use log::{info, warn};
 
 // ── Constants ────────────────────────────────────────────────
 
-/// Maximum number of concurrent RPC client sessions.
-pub const MAX_CLIENTS: usize = 3;
+/// Maximum number of concurrent RPC client sessions: BLE, two TCP
+/// clients and the bench UART (see `io_task` for the slot layout).
+pub const MAX_CLIENTS: usize = 4;
 
 /// Client identifier (index into the session table).
 pub type ClientId = u8;
 
+/// Leading nonce bytes drawn from the CSPRNG; the remaining 8 carry the
+/// session's challenge counter (little-endian).
+pub const NONCE_RANDOM_BYTES: usize = 24;
+
+/// Number of message ids below the highest seen that the replay window
+/// remembers.  Older ids are rejected outright.
+pub const REPLAY_WINDOW: u32 = 64;
+
+/// Consecutive failed verifies that lock a client out of authentication
+/// (0 disables the lockout).
+pub const DEFAULT_AUTH_LOCKOUT_THRESHOLD: u8 = 5;
+
+/// Lockout imposed by the failure that reaches the threshold; each
+/// further failure doubles it up to [`AUTH_LOCKOUT_MAX`].
+pub const AUTH_LOCKOUT_BASE: Duration = Duration::from_secs(2);
+
+/// Ceiling on a single lockout.
+pub const AUTH_LOCKOUT_MAX: Duration = Duration::from_secs(300);
+
+/// Length of a session migration token.
+pub const MIGRATION_TOKEN_LEN: usize = 32;
+
+/// How long a migration token can be redeemed after it is issued.
+pub const MIGRATION_TOKEN_TTL: Duration = Duration::from_secs(30);
+
+/// How an authenticated session validates incoming `msg.id()` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SequenceMode {
+    /// Every id must be strictly greater than the last one accepted.
+    Strict,
+    /// Sliding anti-replay window (RFC 4303 §3.4.3): ids up to
+    /// [`REPLAY_WINDOW`] behind the highest seen are accepted once, so
+    /// retransmits and reordered requests survive a lossy link.
+    #[default]
+    Window,
+}
+
 // ── Session state machine ────────────────────────────────────
 
 /// Authentication state of a single RPC session.
 #[derive(Debug, Clone)]
 pub enum SessionState {
     Unauthenticated,
-    Challenged { nonce: [u8; 32], session_id: u32 },
-    Authenticated { session_id: u32, msg_seq: u32 },
+    Challenged {
+        nonce: [u8; 32],
+        session_id: u32,
+    },
+    Authenticated {
+        session_id: u32,
+        /// Highest message id accepted so far.
+        msg_seq: u32,
+        /// Bit `n` set = id `msg_seq - n` has been accepted.
+        replay_bitmap: u64,
+    },
 }
 
 /// Tracks a single client session through the auth handshake and beyond.
@@ -38,6 +98,15 @@ pub struct Session {
     pub created_at: u64,
     rate_limiter: burster::TokenBucket<fn() -> Duration>,
     next_session_id: u32,
+    /// Challenges issued by this slot; stamped into every nonce.
+    nonce_counter: u64,
+    sequence_mode: SequenceMode,
+    /// Consecutive failed verifies; survives [`reset`](Self::reset) so a
+    /// fresh challenge does not clear it.
+    failed_verifies: u32,
+    lockout_threshold: u8,
+    /// Platform time until which auth attempts are refused.
+    locked_until: Option<Duration>,
 }
 
 impl Session {
@@ -51,7 +120,62 @@ impl Session {
                 platform_now as fn() -> Duration,
             ),
             next_session_id: 1,
+            nonce_counter: 0,
+            sequence_mode: SequenceMode::default(),
+            failed_verifies: 0,
+            lockout_threshold: DEFAULT_AUTH_LOCKOUT_THRESHOLD,
+            locked_until: None,
+        }
+    }
+
+    /// Set how many consecutive failed verifies trigger a lockout
+    /// (0 = never lock out).
+    pub fn set_lockout_threshold(&mut self, threshold: u8) {
+        self.lockout_threshold = threshold;
+    }
+
+    /// Time left on the auth lockout at platform time `now`, if any.
+    pub fn lockout_remaining(&self, now: Duration) -> Option<Duration> {
+        self.locked_until
+            .and_then(|until| until.checked_sub(now))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Count a failed verify at `now`.  Once the threshold is reached
+    /// every failure locks the client out for twice as long as the last;
+    /// returns the new lockout, if one was imposed.
+    pub fn record_auth_failure(&mut self, now: Duration) -> Option<Duration> {
+        self.failed_verifies = self.failed_verifies.saturating_add(1);
+        let threshold = u32::from(self.lockout_threshold);
+        if threshold == 0 || self.failed_verifies < threshold {
+            return None;
         }
+        let doublings = (self.failed_verifies - threshold).min(16);
+        let lockout = AUTH_LOCKOUT_BASE
+            .saturating_mul(1 << doublings)
+            .min(AUTH_LOCKOUT_MAX);
+        self.locked_until = Some(now + lockout);
+        Some(lockout)
+    }
+
+    /// Clear the failure count and any lockout (successful verify).
+    pub fn clear_auth_failures(&mut self) {
+        self.failed_verifies = 0;
+        self.locked_until = None;
+    }
+
+    /// Consecutive failed verifies since the last success.
+    pub fn failed_verifies(&self) -> u32 {
+        self.failed_verifies
+    }
+
+    /// Select how `check_sequence` treats out-of-order ids.
+    pub fn set_sequence_mode(&mut self, mode: SequenceMode) {
+        self.sequence_mode = mode;
+    }
+
+    pub fn sequence_mode(&self) -> SequenceMode {
+        self.sequence_mode
     }
 
     /// Begin the challenge-response handshake.
@@ -59,7 +183,10 @@ impl Session {
     /// Returns `(session_id, nonce)` for inclusion in `AuthChallengeResponse`.
     pub fn begin_challenge(&mut self) -> (u32, [u8; 32]) {
         let session_id = self.alloc_session_id();
-        let nonce = fill_random_nonce();
+        let mut nonce = [0u8; 32];
+        fill_random(&mut nonce[..NONCE_RANDOM_BYTES]);
+        nonce[NONCE_RANDOM_BYTES..].copy_from_slice(&self.nonce_counter.to_le_bytes());
+        self.nonce_counter = self.nonce_counter.wrapping_add(1);
 
         self.state = SessionState::Challenged { nonce, session_id };
         (session_id, nonce)
@@ -68,9 +195,22 @@ impl Session {
     /// Verify the client's HMAC response against the stored nonce and PSK.
     ///
     /// Uses `hmac_sha256::HMAC` with constant-time verification.
-    /// Transitions to `Authenticated` on success; resets to
-    /// `Unauthenticated` on failure.
+    /// Transitions to `Authenticated` (clearing any failed-verify count)
+    /// on success; resets to `Unauthenticated` on failure.
     pub fn verify_response(&mut self, session_id: u32, hmac_tag: &[u8], psk: &[u8]) -> bool {
+        self.verify_response_any(session_id, hmac_tag, &[psk])
+    }
+
+    /// Like [`verify_response`](Self::verify_response), but accepts a tag
+    /// computed with any of `psks` (e.g. current and previous key during
+    /// a rotation).  Every key is checked so timing does not reveal which
+    /// one matched.
+    pub fn verify_response_any(
+        &mut self,
+        session_id: u32,
+        hmac_tag: &[u8],
+        psks: &[&[u8]],
+    ) -> bool {
         let (expected_session_id, nonce) =
             if let SessionState::Challenged { nonce, session_id } = &self.state {
                 (*session_id, *nonce)
@@ -91,35 +231,82 @@ impl Session {
             return false;
         }
 
-        let computed = hmac_sha256::HMAC::mac(nonce, psk);
         let tag_array: &[u8; 32] = hmac_tag.try_into().unwrap();
-        if !hmac_sha256::HMAC::verify(nonce, psk, tag_array) {
+        let matched = psks.iter().fold(false, |acc, psk| {
+            acc | hmac_sha256::HMAC::verify(nonce, psk, tag_array)
+        });
+        if !matched {
             warn!("auth: HMAC verification failed");
-            let _ = computed; // prevent optimization of timing side-channel
             self.reset();
             return false;
         }
 
+        self.authenticate(session_id);
+        true
+    }
+
+    /// Authenticate with a fresh session id, as if a handshake had just
+    /// succeeded (session migration).
+    fn authenticate_migrated(&mut self) -> u32 {
+        let session_id = self.alloc_session_id();
+        self.authenticate(session_id);
+        session_id
+    }
+
+    fn authenticate(&mut self, session_id: u32) {
         self.state = SessionState::Authenticated {
             session_id,
             msg_seq: 0,
+            replay_bitmap: 1, // id 0 is never valid
         };
-        true
+        self.clear_auth_failures();
     }
 
-    /// Validate that `msg_id` is strictly greater than the last seen sequence.
+    /// Validate `msg_id` against the session's sequence state and record
+    /// it if accepted.
+    ///
+    /// In [`SequenceMode::Strict`] the id must exceed the last accepted
+    /// id.  In [`SequenceMode::Window`] any id not yet seen and no more
+    /// than [`REPLAY_WINDOW`] - 1 behind the highest is also accepted.
     pub fn check_sequence(&mut self, msg_id: u32) -> bool {
-        match &mut self.state {
-            SessionState::Authenticated { msg_seq, .. } => {
-                if msg_id <= *msg_seq {
-                    warn!("auth: sequence regression (got {msg_id}, last {msg_seq})");
-                    return false;
-                }
-                *msg_seq = msg_id;
-                true
-            }
-            _ => false,
+        let mode = self.sequence_mode;
+        let SessionState::Authenticated {
+            msg_seq,
+            replay_bitmap,
+            ..
+        } = &mut self.state
+        else {
+            return false;
+        };
+
+        if msg_id > *msg_seq {
+            let shift = msg_id - *msg_seq;
+            *replay_bitmap = if shift >= REPLAY_WINDOW {
+                1
+            } else {
+                (*replay_bitmap << shift) | 1
+            };
+            *msg_seq = msg_id;
+            return true;
+        }
+
+        if mode == SequenceMode::Strict {
+            warn!("auth: sequence regression (got {msg_id}, last {msg_seq})");
+            return false;
+        }
+
+        let offset = *msg_seq - msg_id;
+        if offset >= REPLAY_WINDOW {
+            warn!("auth: msg_id {msg_id} outside replay window (highest {msg_seq})");
+            return false;
+        }
+        let bit = 1u64 << offset;
+        if *replay_bitmap & bit != 0 {
+            warn!("auth: replayed msg_id {msg_id} (highest {msg_seq})");
+            return false;
         }
+        *replay_bitmap |= bit;
+        true
     }
 
     /// Consume one rate-limit token; returns `false` when exhausted.
@@ -151,18 +338,31 @@ impl Default for Session {
 
 // ── Session table for multi-client support ───────────────────
 
+/// Outstanding session migration token.
+struct MigrationToken {
+    token: [u8; MIGRATION_TOKEN_LEN],
+    /// Slot whose session the token carries.
+    from: ClientId,
+    /// Platform time after which the token is refused.
+    expires_at: Duration,
+}
+
 /// Fixed-size table of per-client sessions.
 ///
 /// Each slot maps to one connected RPC client. Slots are indexed
 /// by `ClientId` (0..MAX_CLIENTS).
 pub struct SessionTable {
     sessions: [Session; MAX_CLIENTS],
+    /// At most one migration token is outstanding; issuing another
+    /// replaces it.
+    migration: Option<MigrationToken>,
 }
 
 impl SessionTable {
     pub fn new() -> Self {
         Self {
             sessions: core::array::from_fn(|_| Session::new()),
+            migration: None,
         }
     }
 
@@ -176,17 +376,50 @@ impl SessionTable {
         self.sessions.get(client_id as usize)
     }
 
-    /// Reset a specific client's session (e.g. on disconnect).
+    /// Reset a specific client's session (e.g. on disconnect).  Its
+    /// failed-auth count and lockout stay with the slot, so reconnecting
+    /// does not lift them.
     pub fn reset_client(&mut self, client_id: ClientId) {
         if let Some(s) = self.sessions.get_mut(client_id as usize) {
             s.reset();
         }
     }
 
-    /// Reset all sessions.
+    /// Reset all sessions and drop any outstanding migration token.
     pub fn reset_all(&mut self) {
         for s in &mut self.sessions {
             s.reset();
+            s.clear_auth_failures();
+        }
+        self.migration = None;
+    }
+
+    /// Apply a failed-verify lockout threshold to every client slot.
+    pub fn set_lockout_threshold(&mut self, threshold: u8) {
+        for s in &mut self.sessions {
+            s.set_lockout_threshold(threshold);
+        }
+    }
+
+    /// Time left on `client_id`'s auth lockout, if any.
+    pub fn lockout_remaining(&self, client_id: ClientId) -> Option<Duration> {
+        self.sessions
+            .get(client_id as usize)
+            .and_then(|s| s.lockout_remaining(platform_now()))
+    }
+
+    /// Record a failed verify for `client_id`; returns the lockout it
+    /// triggered, if any.
+    pub fn record_auth_failure(&mut self, client_id: ClientId) -> Option<Duration> {
+        self.sessions
+            .get_mut(client_id as usize)
+            .and_then(|s| s.record_auth_failure(platform_now()))
+    }
+
+    /// Apply a sequence-check mode to every client slot.
+    pub fn set_sequence_mode(&mut self, mode: SequenceMode) {
+        for s in &mut self.sessions {
+            s.set_sequence_mode(mode);
         }
     }
 
@@ -196,6 +429,71 @@ impl SessionTable {
             .get(client_id as usize)
             .is_some_and(Session::is_authenticated)
     }
+
+    /// Issue a migration token for `from`'s session, replacing any
+    /// outstanding one.  `None` unless `from` is authenticated.
+    pub fn issue_migration_token(&mut self, from: ClientId) -> Option<[u8; MIGRATION_TOKEN_LEN]> {
+        self.issue_migration_token_at(from, platform_now())
+    }
+
+    /// Authenticate `to` with the outstanding migration token.  Any
+    /// attempt uses the token up, so a wrong guess cannot be retried and
+    /// a right one cannot be replayed.
+    pub fn redeem_migration_token(&mut self, to: ClientId, token: &[u8]) -> bool {
+        self.redeem_migration_token_at(to, token, platform_now())
+    }
+
+    fn issue_migration_token_at(
+        &mut self,
+        from: ClientId,
+        now: Duration,
+    ) -> Option<[u8; MIGRATION_TOKEN_LEN]> {
+        if !self.is_authenticated(from) {
+            return None;
+        }
+        let mut token = [0u8; MIGRATION_TOKEN_LEN];
+        fill_random(&mut token);
+        self.migration = Some(MigrationToken {
+            token,
+            from,
+            expires_at: now + MIGRATION_TOKEN_TTL,
+        });
+        Some(token)
+    }
+
+    fn redeem_migration_token_at(&mut self, to: ClientId, token: &[u8], now: Duration) -> bool {
+        let Some(pending) = self.migration.take() else {
+            warn!("auth: migration attempted with no token outstanding");
+            return false;
+        };
+        if now >= pending.expires_at {
+            warn!("auth: migration token expired");
+            return false;
+        }
+        if to == pending.from || token.len() != MIGRATION_TOKEN_LEN {
+            warn!("auth: migration token rejected for slot {to}");
+            return false;
+        }
+        // Constant-time compare: the outcome must not leak a prefix.
+        let diff = pending
+            .token
+            .iter()
+            .zip(token)
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        if diff != 0 {
+            warn!("auth: migration token mismatch");
+            return false;
+        }
+        let Some(session) = self.sessions.get_mut(to as usize) else {
+            return false;
+        };
+        let session_id = session.authenticate_migrated();
+        info!(
+            "auth: slot {} took over slot {}'s session (session_id={session_id})",
+            to, pending.from
+        );
+        true
+    }
 }
 
 impl Default for SessionTable {
@@ -214,34 +512,80 @@ pub fn compute_hmac(psk: &[u8], nonce: &[u8; 32]) -> [u8; 32] {
 
 // ── Platform-specific nonce generation ───────────────────────
 
-/// Fill a 32-byte nonce with cryptographically random data.
+/// Fill `buf` from mbedtls `ctr_drbg` (AES-256 CTR_DRBG, NIST SP 800-90A).
 ///
-/// ESP-IDF: delegates to the hardware RNG via `esp_fill_random`.
+/// The DRBG is seeded once from `mbedtls_entropy_func`, which draws on the
+/// hardware RNG.  If seeding fails, falls back to `esp_fill_random`.
 #[cfg(target_os = "espidf")]
-fn fill_random_nonce() -> [u8; 32] {
-    let mut buf = [0u8; 32];
-    // SAFETY: esp_fill_random writes to the provided buffer using
-    // the hardware RNG. Buffer is valid and exclusively owned.
-    unsafe {
-        esp_idf_sys::esp_fill_random(buf.as_mut_ptr().cast(), buf.len());
-    }
-    buf
+pub(crate) fn fill_random(buf: &mut [u8]) {
+    use esp_idf_sys::*;
+    use std::sync::{Mutex, PoisonError};
+
+    struct Drbg {
+        ctr: mbedtls_ctr_drbg_context,
+        entropy: mbedtls_entropy_context,
+    }
+    // SAFETY: the contexts are only touched while holding `DRBG`.
+    unsafe impl Send for Drbg {}
+
+    const PERSONALIZATION: &[u8] = b"petfilter-auth-nonce";
+    static DRBG: Mutex<Option<Box<Drbg>>> = Mutex::new(None);
+
+    let mut guard = DRBG.lock().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_none() {
+        // Boxed so the entropy context the DRBG points at never moves.
+        // SAFETY: both contexts are zero-initialised, then set up by their
+        // mbedtls init functions before use.
+        let mut drbg: Box<Drbg> = Box::new(unsafe { core::mem::zeroed() });
+        let ret = unsafe {
+            mbedtls_entropy_init(&mut drbg.entropy);
+            mbedtls_ctr_drbg_init(&mut drbg.ctr);
+            mbedtls_ctr_drbg_seed(
+                &mut drbg.ctr,
+                Some(mbedtls_entropy_func),
+                (&mut drbg.entropy as *mut mbedtls_entropy_context).cast(),
+                PERSONALIZATION.as_ptr(),
+                PERSONALIZATION.len(),
+            )
+        };
+        if ret == 0 {
+            *guard = Some(drbg);
+        } else {
+            warn!("auth: ctr_drbg seed failed ({ret}), using esp_fill_random");
+        }
+    }
+
+    let ret = match guard.as_mut() {
+        // SAFETY: the DRBG was seeded above and is exclusively borrowed.
+        Some(drbg) => unsafe {
+            mbedtls_ctr_drbg_random(
+                (&mut drbg.ctr as *mut mbedtls_ctr_drbg_context).cast(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        },
+        None => -1,
+    };
+    if ret != 0 {
+        // SAFETY: esp_fill_random writes to the provided buffer using
+        // the hardware RNG. Buffer is valid and exclusively owned.
+        unsafe { esp_fill_random(buf.as_mut_ptr().cast(), buf.len()) };
+    }
 }
 
-/// Simulation stub — uses `RandomState` to produce non-cryptographic entropy.
+/// Host/simulation source: `StdRng` (ChaCha12) seeded once from the OS.
+/// Test-only — nothing on the host is a production security boundary.
 #[cfg(not(target_os = "espidf"))]
-fn fill_random_nonce() -> [u8; 32] {
-    use std::collections::hash_map::RandomState;
-    use std::hash::{BuildHasher, Hasher};
-
-    let mut buf = [0u8; 32];
-    for chunk in buf.chunks_mut(8) {
-        let s = RandomState::new();
-        let val = s.build_hasher().finish().to_le_bytes();
-        let len = chunk.len().min(val.len());
-        chunk[..len].copy_from_slice(&val[..len]);
-    }
-    buf
+pub(crate) fn fill_random(buf: &mut [u8]) {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+    use std::sync::{Mutex, OnceLock, PoisonError};
+
+    static RNG: OnceLock<Mutex<StdRng>> = OnceLock::new();
+    RNG.get_or_init(|| Mutex::new(StdRng::from_os_rng()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .fill_bytes(buf);
 }
 
 // ── Platform time for rate limiter ───────────────────────────
@@ -280,6 +624,51 @@ mod tests {
         assert!(sess.is_authenticated());
     }
 
+    #[test]
+    fn nonces_never_repeat_within_a_session() {
+        let mut sess = Session::new();
+        let mut seen = std::collections::HashSet::new();
+        for i in 0..2000u64 {
+            let (_, nonce) = sess.begin_challenge();
+            assert!(seen.insert(nonce), "nonce repeated at draw {i}");
+            assert_eq!(nonce[NONCE_RANDOM_BYTES..], i.to_le_bytes());
+            sess.reset();
+        }
+    }
+
+    #[test]
+    fn nonce_random_bytes_are_well_distributed() {
+        const DRAWS: usize = 2000;
+        let mut sess = Session::new();
+        let mut histogram = [0u32; 256];
+        let mut ones = 0u32;
+        for _ in 0..DRAWS {
+            let (_, nonce) = sess.begin_challenge();
+            for &b in &nonce[..NONCE_RANDOM_BYTES] {
+                histogram[b as usize] += 1;
+                ones += b.count_ones();
+            }
+        }
+
+        // 384 000 bits: a fair source lands within ~0.5% of half ones
+        // (≈ 7σ margin here), and every byte value turns up near
+        // 48 000 / 256 = 187.5 times.
+        let bits = (DRAWS * NONCE_RANDOM_BYTES * 8) as f64;
+        let ratio = f64::from(ones) / bits;
+        assert!((ratio - 0.5).abs() < 0.005, "ones ratio {ratio}");
+        let min = *histogram.iter().min().unwrap();
+        let max = *histogram.iter().max().unwrap();
+        assert!(min > 100 && max < 300, "byte counts {min}..{max}");
+    }
+
+    #[test]
+    fn separate_sessions_draw_independent_nonces() {
+        let (_, a) = Session::new().begin_challenge();
+        let (_, b) = Session::new().begin_challenge();
+        assert_eq!(a[NONCE_RANDOM_BYTES..], b[NONCE_RANDOM_BYTES..]);
+        assert_ne!(a[..NONCE_RANDOM_BYTES], b[..NONCE_RANDOM_BYTES]);
+    }
+
     #[test]
     fn session_rejects_wrong_hmac() {
         let psk = b"correct-key";
@@ -305,12 +694,36 @@ mod tests {
     }
 
     #[test]
-    fn sequence_check_enforces_monotonic_increase() {
+    fn session_accepts_any_listed_psk() {
+        let (current, previous) = (b"new-key".as_slice(), b"old-key".as_slice());
+        for psk in [current, previous] {
+            let mut sess = Session::new();
+            let (sid, nonce) = sess.begin_challenge();
+            let tag = compute_hmac(psk, &nonce);
+            assert!(sess.verify_response_any(sid, &tag, &[current, previous]));
+        }
+
+        let mut sess = Session::new();
+        let (sid, nonce) = sess.begin_challenge();
+        let tag = compute_hmac(b"other-key", &nonce);
+        assert!(!sess.verify_response_any(sid, &tag, &[current, previous]));
+        assert!(!sess.is_authenticated());
+    }
+
+    fn authenticated_session(mode: SequenceMode) -> Session {
         let mut sess = Session::new();
+        sess.set_sequence_mode(mode);
         sess.state = SessionState::Authenticated {
             session_id: 1,
             msg_seq: 0,
+            replay_bitmap: 1,
         };
+        sess
+    }
+
+    #[test]
+    fn sequence_check_enforces_monotonic_increase() {
+        let mut sess = authenticated_session(SequenceMode::Strict);
 
         assert!(sess.check_sequence(1));
         assert!(sess.check_sequence(2));
@@ -320,6 +733,60 @@ mod tests {
         assert!(sess.check_sequence(6));
     }
 
+    #[test]
+    fn replay_window_accepts_in_window_reordering() {
+        let mut sess = authenticated_session(SequenceMode::Window);
+
+        assert!(sess.check_sequence(1));
+        assert!(sess.check_sequence(4));
+        assert!(sess.check_sequence(3)); // overtaken by 4
+        assert!(sess.check_sequence(2));
+        assert!(sess.check_sequence(5));
+    }
+
+    #[test]
+    fn replay_window_rejects_duplicates() {
+        let mut sess = authenticated_session(SequenceMode::Window);
+
+        assert!(sess.check_sequence(10));
+        assert!(sess.check_sequence(7));
+        assert!(!sess.check_sequence(10)); // replay of highest
+        assert!(!sess.check_sequence(7)); // replay of out-of-order id
+        assert!(!sess.check_sequence(0)); // id 0 is never valid
+        assert!(sess.check_sequence(8));
+    }
+
+    #[test]
+    fn replay_window_rejects_ids_older_than_window() {
+        let mut sess = authenticated_session(SequenceMode::Window);
+
+        assert!(sess.check_sequence(100));
+        assert!(!sess.check_sequence(100 - REPLAY_WINDOW));
+        assert!(sess.check_sequence(100 - REPLAY_WINDOW + 1));
+    }
+
+    #[test]
+    fn replay_window_large_jump_forgets_history() {
+        let mut sess = authenticated_session(SequenceMode::Window);
+
+        assert!(sess.check_sequence(5));
+        assert!(sess.check_sequence(5 + 200));
+        assert!(!sess.check_sequence(5 + 200));
+        assert!(sess.check_sequence(5 + 199));
+        assert!(!sess.check_sequence(5)); // now far outside the window
+    }
+
+    #[test]
+    fn session_table_sets_mode_on_all_slots() {
+        let mut table = SessionTable::new();
+        assert_eq!(table.get(0).unwrap().sequence_mode(), SequenceMode::Window);
+
+        table.set_sequence_mode(SequenceMode::Strict);
+        for id in 0..MAX_CLIENTS as u8 {
+            assert_eq!(table.get(id).unwrap().sequence_mode(), SequenceMode::Strict);
+        }
+    }
+
     #[test]
     fn rate_limiter_exhaustion() {
         let mut sess = Session::new();
@@ -335,6 +802,7 @@ mod tests {
         sess.state = SessionState::Authenticated {
             session_id: 42,
             msg_seq: 10,
+            replay_bitmap: 1,
         };
         sess.reset();
         assert!(matches!(sess.state, SessionState::Unauthenticated));
@@ -351,6 +819,7 @@ mod tests {
             s.state = SessionState::Authenticated {
                 session_id: 1,
                 msg_seq: 0,
+                replay_bitmap: 1,
             };
         }
         assert!(table.is_authenticated(0));
@@ -360,6 +829,79 @@ mod tests {
         assert!(!table.is_authenticated(0));
     }
 
+    #[test]
+    fn repeated_failures_lock_out_with_growing_cooldown() {
+        let mut sess = Session::new();
+        let t0 = Duration::from_secs(100);
+        let threshold = u32::from(DEFAULT_AUTH_LOCKOUT_THRESHOLD);
+
+        for _ in 1..threshold {
+            assert_eq!(sess.record_auth_failure(t0), None);
+        }
+        assert_eq!(sess.lockout_remaining(t0), None);
+
+        assert_eq!(sess.record_auth_failure(t0), Some(AUTH_LOCKOUT_BASE));
+        assert_eq!(sess.lockout_remaining(t0), Some(AUTH_LOCKOUT_BASE));
+        let expired = t0 + AUTH_LOCKOUT_BASE;
+        assert_eq!(sess.lockout_remaining(expired), None);
+
+        assert_eq!(
+            sess.record_auth_failure(expired),
+            Some(AUTH_LOCKOUT_BASE * 2)
+        );
+        assert_eq!(
+            sess.record_auth_failure(expired),
+            Some(AUTH_LOCKOUT_BASE * 4)
+        );
+        for _ in 0..40 {
+            sess.record_auth_failure(expired);
+        }
+        assert_eq!(sess.lockout_remaining(expired), Some(AUTH_LOCKOUT_MAX));
+    }
+
+    #[test]
+    fn success_before_threshold_clears_failure_count() {
+        let psk = b"test-psk-12345";
+        let mut sess = Session::new();
+        let now = Duration::ZERO;
+        let threshold = u32::from(DEFAULT_AUTH_LOCKOUT_THRESHOLD);
+
+        for _ in 1..threshold {
+            sess.record_auth_failure(now);
+        }
+        assert_eq!(sess.failed_verifies(), threshold - 1);
+
+        let (sid, nonce) = sess.begin_challenge();
+        assert!(sess.verify_response(sid, &compute_hmac(psk, &nonce), psk));
+        assert_eq!(sess.failed_verifies(), 0);
+
+        // The next failure starts a fresh count rather than locking out.
+        assert_eq!(sess.record_auth_failure(now), None);
+        assert_eq!(sess.lockout_remaining(now), None);
+    }
+
+    #[test]
+    fn lockout_threshold_zero_never_locks() {
+        let mut sess = Session::new();
+        sess.set_lockout_threshold(0);
+        for _ in 0..100 {
+            assert_eq!(sess.record_auth_failure(Duration::ZERO), None);
+        }
+    }
+
+    #[test]
+    fn reset_client_keeps_lockout() {
+        let mut table = SessionTable::new();
+        table.set_lockout_threshold(1);
+        assert!(table.record_auth_failure(0).is_some());
+        assert!(table.lockout_remaining(0).is_some());
+        assert!(table.lockout_remaining(1).is_none());
+
+        table.reset_client(0);
+        assert!(table.lockout_remaining(0).is_some());
+        assert_eq!(table.get(0).unwrap().failed_verifies(), 1);
+    }
+
     #[test]
     fn session_table_out_of_bounds() {
         let table = SessionTable::new();
@@ -375,4 +917,58 @@ mod tests {
         let h2 = compute_hmac(psk, &nonce);
         assert_eq!(h1, h2);
     }
+
+    fn table_with_authenticated(client: ClientId) -> SessionTable {
+        let psk = b"test-psk-12345";
+        let mut table = SessionTable::new();
+        let sess = table.get_mut(client).unwrap();
+        let (sid, nonce) = sess.begin_challenge();
+        assert!(sess.verify_response(sid, &compute_hmac(psk, &nonce), psk));
+        table
+    }
+
+    #[test]
+    fn migration_token_authenticates_new_slot_once() {
+        let mut table = table_with_authenticated(0);
+        let now = Duration::from_secs(100);
+        let token = table.issue_migration_token_at(0, now).unwrap();
+
+        assert!(table.redeem_migration_token_at(1, &token, now + Duration::from_secs(1)));
+        assert!(table.is_authenticated(1));
+        assert!(table.is_authenticated(0));
+
+        assert!(!table.redeem_migration_token_at(2, &token, now + Duration::from_secs(2)));
+        assert!(!table.is_authenticated(2));
+    }
+
+    #[test]
+    fn expired_migration_token_is_refused() {
+        let mut table = table_with_authenticated(0);
+        let now = Duration::from_secs(100);
+        let token = table.issue_migration_token_at(0, now).unwrap();
+        assert!(!table.redeem_migration_token_at(1, &token, now + MIGRATION_TOKEN_TTL));
+        assert!(!table.is_authenticated(1));
+    }
+
+    #[test]
+    fn wrong_migration_token_burns_the_real_one() {
+        let mut table = table_with_authenticated(0);
+        let now = Duration::from_secs(100);
+        let token = table.issue_migration_token_at(0, now).unwrap();
+        let mut guess = token;
+        guess[0] ^= 1;
+        assert!(!table.redeem_migration_token_at(1, &guess, now));
+        assert!(!table.redeem_migration_token_at(1, &token, now));
+        assert!(!table.is_authenticated(1));
+    }
+
+    #[test]
+    fn migration_token_needs_authenticated_source_and_other_slot() {
+        let mut table = table_with_authenticated(0);
+        let now = Duration::from_secs(100);
+        assert!(table.issue_migration_token_at(1, now).is_none());
+
+        let token = table.issue_migration_token_at(0, now).unwrap();
+        assert!(!table.redeem_migration_token_at(0, &token, now));
+    }
 }
@@ -10,7 +10,19 @@
 //! │  (async)     │◀────────────│  (sync)       │
 //! └──────────────┘  ResponseMsg └──────────────┘
 //! ```
+//!
+//! Responses travel on two channels: replies to requests (acks, auth
+//! handshakes) on [`RESP_PRIORITY_CHANNEL`] and streamed frames
+//! (telemetry, broadcast events) on [`RESP_CHANNEL`].  The I/O task
+//! drains the priority channel first via [`receive_response`], so an
+//! ack never waits behind a backlog of telemetry.
+//!
+//! A command frame that finds [`CMD_CHANNEL`] full is dropped, counted
+//! in [`command_drops`] and reported on [`DROPPED_CHANNEL`] so the
+//! control loop can NAK it back to the sender.
 
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::task::Poll;
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 use embassy_sync::channel::Channel;
 use heapless::Vec;
@@ -33,8 +45,17 @@ pub struct ResponseMsg {
     pub data: Vec<u8, 512>,
 }
 
+/// Delivery priority of an outbound response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponsePriority {
+    /// Reply to a client request; sent ahead of any queued `Low` frame.
+    High,
+    /// Streamed frame (telemetry, events) that may wait behind replies.
+    Low,
+}
+
 /// Channel depth for command (inbound) messages.
-const CMD_DEPTH: usize = 8;
+pub const CMD_DEPTH: usize = 8;
 
 /// Channel depth for response (outbound) messages.
 const RESP_DEPTH: usize = 16;
@@ -42,12 +63,127 @@ const RESP_DEPTH: usize = 16;
 /// Inbound command channel: I/O task → control loop.
 pub static CMD_CHANNEL: Channel<CriticalSectionRawMutex, CommandMsg, CMD_DEPTH> = Channel::new();
 
-/// Outbound response channel: control loop → I/O task.
+/// Command frames dropped on a full [`CMD_CHANNEL`] since boot.
+static CMD_DROPS: AtomicU32 = AtomicU32::new(0);
+
+/// Queue `msg` for the control loop.  Returns `false`, counting the
+/// drop, if the channel is full; the caller still holds the decoded
+/// frame to identify the request.
+pub fn try_send_command(msg: CommandMsg) -> bool {
+    let sent = CMD_CHANNEL.try_send(msg).is_ok();
+    if !sent {
+        let _ = CMD_DROPS.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+            Some(n.saturating_add(1))
+        });
+    }
+    sent
+}
+
+/// Saturating count of command frames dropped since boot.
+pub fn command_drops() -> u32 {
+    CMD_DROPS.load(Ordering::Relaxed)
+}
+
+/// Channel depth for high-priority response messages.
+const RESP_PRIORITY_DEPTH: usize = 8;
+
+/// Outbound response channel for [`ResponsePriority::Low`] frames:
+/// control loop → I/O task.
 pub static RESP_CHANNEL: Channel<CriticalSectionRawMutex, ResponseMsg, RESP_DEPTH> = Channel::new();
 
+/// Outbound response channel for [`ResponsePriority::High`] frames:
+/// control loop → I/O task.
+pub static RESP_PRIORITY_CHANNEL: Channel<
+    CriticalSectionRawMutex,
+    ResponseMsg,
+    RESP_PRIORITY_DEPTH,
+> = Channel::new();
+
+/// Queue `msg` on the channel for `priority`.  Returns `false` if that
+/// channel is full.
+pub fn try_send_response(msg: ResponseMsg, priority: ResponsePriority) -> bool {
+    match priority {
+        ResponsePriority::High => RESP_PRIORITY_CHANNEL.try_send(msg).is_ok(),
+        ResponsePriority::Low => RESP_CHANNEL.try_send(msg).is_ok(),
+    }
+}
+
+/// Take the next queued response without waiting, high priority first.
+pub fn try_receive_response() -> Option<ResponseMsg> {
+    RESP_PRIORITY_CHANNEL
+        .try_receive()
+        .or_else(|_| RESP_CHANNEL.try_receive())
+        .ok()
+}
+
+/// Wait for the next response, high priority first.  Registers the
+/// caller's waker on both channels, so a send to either wakes it.
+pub async fn receive_response() -> ResponseMsg {
+    core::future::poll_fn(|cx| match RESP_PRIORITY_CHANNEL.poll_receive(cx) {
+        Poll::Ready(msg) => Poll::Ready(msg),
+        Poll::Pending => RESP_CHANNEL.poll_receive(cx),
+    })
+    .await
+}
+
 /// Notification channel for client disconnect events.
 pub struct DisconnectMsg {
     pub client_id: ClientId,
 }
 
 pub static DISCONNECT_CHANNEL: Channel<CriticalSectionRawMutex, DisconnectMsg, 4> = Channel::new();
+
+/// A command frame dropped before the control loop saw it.
+pub struct DroppedMsg {
+    pub client_id: ClientId,
+    /// `Message.id` of the dropped request, for the NAK's `reply_to`.
+    pub msg_id: u32,
+}
+
+pub static DROPPED_CHANNEL: Channel<CriticalSectionRawMutex, DroppedMsg, 4> = Channel::new();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// The response channels are process-wide statics.
+    static RESP_LOCK: Mutex<()> = Mutex::new(());
+
+    fn msg(client_id: ClientId, byte: u8) -> ResponseMsg {
+        let mut data = Vec::new();
+        data.push(byte).unwrap();
+        ResponseMsg { client_id, data }
+    }
+
+    fn drain() {
+        while try_receive_response().is_some() {}
+    }
+
+    #[test]
+    fn high_priority_response_overtakes_queued_low() {
+        let _guard = RESP_LOCK.lock().unwrap();
+        drain();
+        assert!(try_send_response(msg(1, 0x10), ResponsePriority::Low));
+        assert!(try_send_response(msg(1, 0x11), ResponsePriority::Low));
+        assert!(try_send_response(msg(2, 0x20), ResponsePriority::High));
+
+        let order: std::vec::Vec<u8> = core::iter::from_fn(try_receive_response)
+            .map(|m| m.data[0])
+            .collect();
+        assert_eq!(order, [0x20, 0x10, 0x11]);
+    }
+
+    #[test]
+    fn async_receive_prefers_high_priority() {
+        let _guard = RESP_LOCK.lock().unwrap();
+        drain();
+        assert!(try_send_response(msg(1, 0x10), ResponsePriority::Low));
+        assert!(try_send_response(msg(2, 0x20), ResponsePriority::High));
+
+        let first = futures_lite::future::block_on(receive_response());
+        assert_eq!((first.client_id, first.data[0]), (2, 0x20));
+        let second = futures_lite::future::block_on(receive_response());
+        assert_eq!((second.client_id, second.data[0]), (1, 0x10));
+    }
+}
@@ -5,91 +5,193 @@
 //! multiple frames using the v2 codec's chunking flags.
 //!
 //! ```text
-//! Frame 1: [FLAG_CHUNKED | FLAG_COMPRESSED?]  payload part 1
-//! Frame 2: [FLAG_CHUNKED]                      payload part 2
-//! Frame N: [FLAG_CHUNKED | FLAG_LAST_CHUNK]    payload part N (final)
+//! Frame 1: [FLAG_CHUNKED | FLAG_COMPRESSED?]  [idx 0] payload part 1
+//! Frame 2: [FLAG_CHUNKED]                      [idx 1] payload part 2
+//! Frame N: [FLAG_CHUNKED | FLAG_LAST_CHUNK]    [idx N-1] payload part N (final)
 //! ```
 //!
-//! The receiver reassembles chunks before passing the complete
-//! payload to the RPC dispatcher.
+//! Each chunked frame's payload starts with a little-endian `u16` chunk
+//! index so the receiver can cope with a lossy link:
+//!
+//! - duplicate indices are dropped (feeding a chunk twice is a no-op);
+//! - chunks arriving out of order are buffered by index;
+//! - a transfer that stalls for [`MISSING_CHUNK_TIMEOUT_MS`] waiting on a
+//!   missing chunk is aborted and its buffer freed.
+//!
+//! Each anomaly is counted in [`ChunkStats`].  The receiver reassembles
+//! chunks before passing the complete payload to the RPC dispatcher.
 
 extern crate alloc;
 use alloc::vec::Vec;
 
+use log::warn;
+
 use super::codec::{self, FLAG_CHUNKED, FLAG_LAST_CHUNK, FrameHeader};
 
 /// Maximum chunk payload size (fits within a single frame).
 const CHUNK_SIZE: usize = 2048;
 
+/// Size of the chunk index prefixed to every chunked frame payload.
+pub const CHUNK_INDEX_SIZE: usize = 2;
+
+/// Most chunks one transfer may span; bounds reassembly memory.
+pub const MAX_CHUNKS: usize = 32;
+
+/// A transfer with no new chunk for this long is abandoned.
+pub const MISSING_CHUNK_TIMEOUT_MS: u64 = 5_000;
+
 /// Split a large payload into chunked frames.
 ///
-/// Returns a vector of `(flags, payload_slice)` tuples ready for
-/// encoding with `encode_frame_with_flags`.
-pub fn chunk_payload(data: &[u8]) -> Vec<(u8, &[u8])> {
+/// Returns a vector of `(flags, frame_payload)` tuples ready for
+/// encoding with `encode_frame_with_flags`.  Chunked payloads already
+/// carry their index prefix.
+pub fn chunk_payload(data: &[u8]) -> Vec<(u8, Vec<u8>)> {
     if data.len() <= codec::MAX_FRAME_SIZE {
-        return alloc::vec![(0, data)];
+        return alloc::vec![(0, data.to_vec())];
     }
 
-    let chunks: Vec<&[u8]> = data.chunks(CHUNK_SIZE).collect();
-    let total = chunks.len();
+    let total = data.len().div_ceil(CHUNK_SIZE);
 
-    chunks
-        .into_iter()
+    data.chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             let mut flags = FLAG_CHUNKED;
             if i == total - 1 {
                 flags |= FLAG_LAST_CHUNK;
             }
-            (flags, chunk)
+            let mut payload = Vec::with_capacity(CHUNK_INDEX_SIZE + chunk.len());
+            payload.extend_from_slice(&(i as u16).to_le_bytes());
+            payload.extend_from_slice(chunk);
+            (flags, payload)
         })
         .collect()
 }
 
+/// Link anomalies seen by a [`ChunkReassembler`] since it was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChunkStats {
+    /// Chunks dropped because their index had already been received.
+    pub duplicates: u32,
+    /// Chunks that did not follow the previously received index.
+    pub out_of_order: u32,
+    /// Transfers aborted because a missing chunk never arrived.
+    pub timeouts: u32,
+    /// Chunks rejected for a short header or an index out of range.
+    pub malformed: u32,
+}
+
 /// Reassembly buffer for incoming chunked frames.
 pub struct ChunkReassembler {
-    buffer: Vec<u8>,
+    /// Received chunk bodies, indexed by chunk index.
+    chunks: Vec<Option<Vec<u8>>>,
+    /// Chunk count, known once the last chunk has arrived.
+    total: Option<usize>,
+    received: usize,
+    /// Index expected if the link delivers in order.
+    next_index: usize,
+    /// Uptime (ms) of the last chunk that made progress.
+    last_progress_ms: u64,
     active: bool,
+    stats: ChunkStats,
 }
 
 impl ChunkReassembler {
     pub fn new() -> Self {
         Self {
-            buffer: Vec::new(),
+            chunks: Vec::new(),
+            total: None,
+            received: 0,
+            next_index: 0,
+            last_progress_ms: 0,
             active: false,
+            stats: ChunkStats::default(),
         }
     }
 
     /// Feed a frame's payload and header into the reassembler.
     ///
-    /// Returns `Some(complete_payload)` when the last chunk arrives.
-    /// Returns `None` when more chunks are expected.
-    pub fn feed(&mut self, header: FrameHeader, payload: &[u8]) -> Option<Vec<u8>> {
+    /// Returns `Some(complete_payload)` once every chunk up to the last
+    /// has arrived, in whatever order.  Returns `None` when more chunks
+    /// are expected or the chunk was dropped.
+    pub fn feed(&mut self, header: FrameHeader, payload: &[u8], now_ms: u64) -> Option<Vec<u8>> {
         if !header.is_chunked() {
             // Non-chunked frame — return as-is.
             self.reset();
             return Some(payload.to_vec());
         }
 
+        self.poll_timeout(now_ms);
+
+        let Some((index, body)) = payload.split_first_chunk::<CHUNK_INDEX_SIZE>() else {
+            warn!("chunked: frame shorter than its index");
+            self.stats.malformed = self.stats.malformed.saturating_add(1);
+            return None;
+        };
+        let index = usize::from(u16::from_le_bytes(*index));
+
+        if !self.accepts_index(index, header.is_last_chunk()) {
+            warn!("chunked: index {} out of range, dropped", index);
+            self.stats.malformed = self.stats.malformed.saturating_add(1);
+            return None;
+        }
+
         if !self.active {
-            self.buffer.clear();
+            self.reset();
             self.active = true;
         }
 
-        self.buffer.extend_from_slice(payload);
+        if self.chunks.len() <= index {
+            self.chunks.resize(index + 1, None);
+        }
+        if self.chunks[index].is_some() {
+            self.stats.duplicates = self.stats.duplicates.saturating_add(1);
+            return None;
+        }
+        if index != self.next_index {
+            self.stats.out_of_order = self.stats.out_of_order.saturating_add(1);
+        }
 
+        self.chunks[index] = Some(body.to_vec());
+        self.received += 1;
+        self.next_index = index + 1;
+        self.last_progress_ms = now_ms;
         if header.is_last_chunk() {
-            self.active = false;
-            let complete = core::mem::take(&mut self.buffer);
-            Some(complete)
-        } else {
-            None
+            self.total = Some(index + 1);
         }
+
+        if self.total != Some(self.received) {
+            return None;
+        }
+        let complete = core::mem::take(&mut self.chunks)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .collect();
+        self.reset();
+        Some(complete)
+    }
+
+    /// Abort a transfer whose next chunk is overdue, freeing its buffer.
+    /// Returns `true` if a transfer was aborted.
+    pub fn poll_timeout(&mut self, now_ms: u64) -> bool {
+        if !self.active || now_ms.saturating_sub(self.last_progress_ms) < MISSING_CHUNK_TIMEOUT_MS {
+            return false;
+        }
+        warn!(
+            "chunked: transfer stalled at {} chunks, aborting",
+            self.received
+        );
+        self.stats.timeouts = self.stats.timeouts.saturating_add(1);
+        self.reset();
+        true
     }
 
     /// Reset the reassembler, discarding any partial data.
     pub fn reset(&mut self) {
-        self.buffer.clear();
+        self.chunks = Vec::new();
+        self.total = None;
+        self.received = 0;
+        self.next_index = 0;
         self.active = false;
     }
 
@@ -97,6 +199,27 @@ impl ChunkReassembler {
     pub fn is_active(&self) -> bool {
         self.active
     }
+
+    /// Anomaly counters since creation.
+    pub fn stats(&self) -> ChunkStats {
+        self.stats
+    }
+
+    /// Whether `index` can belong to the transfer in progress: within
+    /// [`MAX_CHUNKS`], before a known last chunk, and — for a last
+    /// chunk — not ahead of chunks already received.
+    fn accepts_index(&self, index: usize, is_last: bool) -> bool {
+        if index >= MAX_CHUNKS {
+            return false;
+        }
+        if !self.active {
+            return true;
+        }
+        match self.total {
+            Some(total) => index < total,
+            None => !is_last || index + 1 >= self.chunks.len(),
+        }
+    }
 }
 
 impl Default for ChunkReassembler {
@@ -111,6 +234,40 @@ impl Default for ChunkReassembler {
 mod tests {
     use super::*;
 
+    const LAST: FrameHeader = FrameHeader {
+        flags: FLAG_CHUNKED | FLAG_LAST_CHUNK,
+    };
+    const MID: FrameHeader = FrameHeader {
+        flags: FLAG_CHUNKED,
+    };
+
+    fn chunk(index: u16, body: &[u8]) -> Vec<u8> {
+        let mut v = index.to_le_bytes().to_vec();
+        v.extend_from_slice(body);
+        v
+    }
+
+    /// A payload large enough to split into five chunks.
+    fn large_payload() -> Vec<u8> {
+        (0..9000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn feed_order(
+        r: &mut ChunkReassembler,
+        chunks: &[(u8, Vec<u8>)],
+        order: &[usize],
+    ) -> Option<Vec<u8>> {
+        let mut out = None;
+        for &i in order {
+            let (flags, payload) = &chunks[i];
+            if let Some(done) = r.feed(FrameHeader { flags: *flags }, payload, 0) {
+                assert!(out.is_none(), "completed twice");
+                out = Some(done);
+            }
+        }
+        out
+    }
+
     #[test]
     fn small_payload_not_chunked() {
         let data = b"small payload";
@@ -128,17 +285,18 @@ mod tests {
         assert!(chunks.len() > 1);
 
         // All chunks except last should have FLAG_CHUNKED
-        for (i, (flags, _)) in chunks.iter().enumerate() {
+        for (i, (flags, payload)) in chunks.iter().enumerate() {
             assert!(*flags & FLAG_CHUNKED != 0);
             if i == chunks.len() - 1 {
                 assert!(*flags & FLAG_LAST_CHUNK != 0);
             } else {
                 assert!(*flags & FLAG_LAST_CHUNK == 0);
             }
+            assert_eq!(payload[..CHUNK_INDEX_SIZE], (i as u16).to_le_bytes());
         }
 
         // Total reassembled size matches
-        let total: usize = chunks.iter().map(|(_, c)| c.len()).sum();
+        let total: usize = chunks.iter().map(|(_, c)| c.len() - CHUNK_INDEX_SIZE).sum();
         assert_eq!(total, data.len());
     }
 
@@ -146,7 +304,7 @@ mod tests {
     fn reassembler_single_frame() {
         let mut r = ChunkReassembler::new();
         let hdr = FrameHeader { flags: 0 };
-        let result = r.feed(hdr, b"complete");
+        let result = r.feed(hdr, b"complete", 0);
         assert_eq!(result.unwrap(), b"complete");
     }
 
@@ -154,35 +312,100 @@ mod tests {
     fn reassembler_multi_chunk() {
         let mut r = ChunkReassembler::new();
 
-        let h1 = FrameHeader {
-            flags: FLAG_CHUNKED,
-        };
-        assert!(r.feed(h1, b"part1").is_none());
+        assert!(r.feed(MID, &chunk(0, b"part1"), 0).is_none());
         assert!(r.is_active());
+        assert!(r.feed(MID, &chunk(1, b"part2"), 0).is_none());
 
-        let h2 = FrameHeader {
-            flags: FLAG_CHUNKED,
-        };
-        assert!(r.feed(h2, b"part2").is_none());
-
-        let h3 = FrameHeader {
-            flags: FLAG_CHUNKED | FLAG_LAST_CHUNK,
-        };
-        let result = r.feed(h3, b"part3").unwrap();
+        let result = r.feed(LAST, &chunk(2, b"part3"), 0).unwrap();
         assert_eq!(result, b"part1part2part3");
         assert!(!r.is_active());
+        assert_eq!(r.stats(), ChunkStats::default());
     }
 
     #[test]
     fn reassembler_reset() {
         let mut r = ChunkReassembler::new();
-        let h = FrameHeader {
-            flags: FLAG_CHUNKED,
-        };
-        r.feed(h, b"partial");
+        r.feed(MID, &chunk(0, b"partial"), 0);
         assert!(r.is_active());
 
         r.reset();
         assert!(!r.is_active());
     }
+
+    #[test]
+    fn shuffled_chunks_reassemble_in_index_order() {
+        let data = large_payload();
+        let chunks = chunk_payload(&data);
+        assert_eq!(chunks.len(), 5);
+
+        let mut r = ChunkReassembler::new();
+        let out = feed_order(&mut r, &chunks, &[3, 0, 4, 2, 1]);
+        assert_eq!(out.unwrap(), data);
+        assert!(r.stats().out_of_order > 0);
+        assert_eq!(r.stats().duplicates, 0);
+        assert!(!r.is_active());
+    }
+
+    #[test]
+    fn duplicate_chunks_are_idempotent() {
+        let data = large_payload();
+        let chunks = chunk_payload(&data);
+
+        let mut r = ChunkReassembler::new();
+        let out = feed_order(&mut r, &chunks, &[0, 0, 1, 2, 1, 3, 2, 4]);
+        assert_eq!(out.unwrap(), data);
+        assert_eq!(r.stats().duplicates, 3);
+    }
+
+    #[test]
+    fn missing_chunk_times_out_and_frees_buffer() {
+        let data = large_payload();
+        let chunks = chunk_payload(&data);
+
+        let mut r = ChunkReassembler::new();
+        assert!(feed_order(&mut r, &chunks, &[0, 1, 3, 4]).is_none());
+        assert!(r.is_active());
+
+        assert!(!r.poll_timeout(MISSING_CHUNK_TIMEOUT_MS - 1));
+        assert!(r.poll_timeout(MISSING_CHUNK_TIMEOUT_MS));
+        assert!(!r.is_active());
+        assert!(r.chunks.is_empty());
+        assert_eq!(r.stats().timeouts, 1);
+
+        // The straggler alone starts (and cannot finish) a new transfer.
+        let (flags, payload) = &chunks[2];
+        let late = MISSING_CHUNK_TIMEOUT_MS + 10;
+        assert!(
+            r.feed(FrameHeader { flags: *flags }, payload, late)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn stalled_transfer_is_dropped_before_next_chunk() {
+        let mut r = ChunkReassembler::new();
+        r.feed(MID, &chunk(0, b"stale"), 0);
+
+        let later = MISSING_CHUNK_TIMEOUT_MS;
+        assert!(r.feed(MID, &chunk(0, b"fresh-"), later).is_none());
+        let out = r.feed(LAST, &chunk(1, b"data"), later).unwrap();
+        assert_eq!(out, b"fresh-data");
+        assert_eq!(r.stats().timeouts, 1);
+        assert_eq!(r.stats().duplicates, 0);
+    }
+
+    #[test]
+    fn malformed_chunks_are_rejected() {
+        let mut r = ChunkReassembler::new();
+        assert!(r.feed(MID, &[0x01], 0).is_none());
+        assert!(r.feed(MID, &chunk(MAX_CHUNKS as u16, b"x"), 0).is_none());
+        assert!(!r.is_active());
+
+        r.feed(MID, &chunk(3, b"d"), 0);
+        // A last chunk behind one already received, then one past the end.
+        assert!(r.feed(LAST, &chunk(1, b"b"), 0).is_none());
+        r.feed(LAST, &chunk(4, b"e"), 0);
+        assert!(r.feed(MID, &chunk(5, b"f"), 0).is_none());
+        assert_eq!(r.stats().malformed, 4);
+    }
 }
@@ -19,7 +19,8 @@
 //! | 0   | Compressed (deflate via miniz)   |
 //! | 1   | Chunked (part of multi-frame)    |
 //! | 2   | Last chunk in sequence           |
-//! | 3-7 | Reserved (must be 0)             |
+//! | 3   | Packed telemetry, not FlatBuffer |
+//! | 4-7 | Reserved (must be 0)             |
 
 /// Maximum frame payload size (protects against memory exhaustion).
 pub const MAX_FRAME_SIZE: usize = 4096;
@@ -41,9 +42,18 @@ pub const FLAG_CHUNKED: u8 = 0x02;
 /// Last chunk in a chunked sequence.
 pub const FLAG_LAST_CHUNK: u8 = 0x04;
 
-/// Mask of all valid flag bits.
+/// Payload is a fixed-layout telemetry record (see
+/// [`telemetry_packed`](super::telemetry_packed)) rather than a `Message`.
+pub const FLAG_PACKED: u8 = 0x08;
+
+/// Mask of the flag bits a client may send.  [`FLAG_PACKED`] only ever
+/// travels from the device, so an inbound frame carrying it is rejected
+/// rather than parsed as a `Message`.
 const VALID_FLAGS_MASK: u8 = FLAG_COMPRESSED | FLAG_CHUNKED | FLAG_LAST_CHUNK;
 
+/// Mask of the flag bits the device may send.
+const DEVICE_FLAGS_MASK: u8 = VALID_FLAGS_MASK | FLAG_PACKED;
+
 /// Parsed frame metadata returned alongside payload bytes.
 #[derive(Debug, Clone, Copy)]
 pub struct FrameHeader {
@@ -62,6 +72,10 @@ impl FrameHeader {
     pub fn is_last_chunk(self) -> bool {
         self.flags & FLAG_LAST_CHUNK != 0
     }
+
+    pub fn is_packed(self) -> bool {
+        self.flags & FLAG_PACKED != 0
+    }
 }
 
 // ── Decoder ──────────────────────────────────────────────────
@@ -84,6 +98,8 @@ pub struct FrameDecoder {
     header_buf: [u8; HEADER_SIZE],
     payload_buf: [u8; MAX_FRAME_SIZE],
     last_header: FrameHeader,
+    /// Flag bits accepted; frames with any other bit set are dropped.
+    valid_flags: u8,
 }
 
 impl Default for FrameDecoder {
@@ -93,12 +109,23 @@ impl Default for FrameDecoder {
 }
 
 impl FrameDecoder {
+    /// Decoder for frames a client sends to the device.
     pub fn new() -> Self {
         Self {
             state: DecoderState::ReadingHeader { collected: 0 },
             header_buf: [0; HEADER_SIZE],
             payload_buf: [0; MAX_FRAME_SIZE],
             last_header: FrameHeader { flags: 0 },
+            valid_flags: VALID_FLAGS_MASK,
+        }
+    }
+
+    /// Decoder for frames the device sends, which may carry
+    /// [`FLAG_PACKED`] (client side and tests).
+    pub fn for_device_frames() -> Self {
+        Self {
+            valid_flags: DEVICE_FLAGS_MASK,
+            ..Self::new()
         }
     }
 
@@ -138,7 +165,7 @@ impl FrameDecoder {
                             continue;
                         }
 
-                        if flags & !VALID_FLAGS_MASK != 0 {
+                        if flags & !self.valid_flags != 0 {
                             self.state = DecoderState::ReadingHeader { collected: 0 };
                             continue;
                         }
@@ -306,6 +333,19 @@ mod tests {
         assert!(dec.feed(&buf[..5 + payload.len()]).is_none());
     }
 
+    #[test]
+    fn packed_frames_are_only_accepted_from_the_device() {
+        let mut buf = [0u8; 16];
+        let n = encode_frame_with_flags(b"rec", FLAG_PACKED, &mut buf).unwrap();
+
+        let mut inbound = FrameDecoder::new();
+        assert!(inbound.feed(&buf[..n]).is_none());
+
+        let mut from_device = FrameDecoder::for_device_frames();
+        assert_eq!(from_device.feed(&buf[..n]).unwrap(), b"rec");
+        assert!(from_device.last_header().is_packed());
+    }
+
     #[test]
     fn encode_too_large_returns_none() {
         let big = [0u8; MAX_FRAME_SIZE + 1];
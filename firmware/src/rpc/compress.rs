@@ -25,7 +25,13 @@ const COMPRESSION_LEVEL: u8 = 6;
 /// Returns `Some(compressed_bytes)` if compression is beneficial
 /// (output < input), or `None` if compression should be skipped.
 pub fn compress(input: &[u8]) -> Option<Vec<u8>> {
-    if input.len() < COMPRESS_THRESHOLD {
+    compress_above(input, COMPRESS_THRESHOLD)
+}
+
+/// Like [`compress`], with a caller-chosen minimum size for streams
+/// of small, repetitive frames such as telemetry.
+pub fn compress_above(input: &[u8], threshold: usize) -> Option<Vec<u8>> {
+    if input.len() < threshold {
         return None;
     }
 
@@ -92,6 +98,15 @@ mod tests {
         let _ = compress(&data);
     }
 
+    #[test]
+    fn custom_threshold_admits_smaller_payloads() {
+        let input = [0u8; 64];
+        assert!(compress(&input).is_none());
+        let compressed = compress_above(&input, 64).expect("should compress");
+        assert_eq!(decompress(&compressed).unwrap(), input);
+        assert!(compress_above(&input, 65).is_none());
+    }
+
     #[test]
     fn decompress_invalid_returns_none() {
         let garbage = b"not valid deflate";
@@ -13,6 +13,15 @@ mod petfilter_generated;
 
 pub use petfilter_generated::petfilter::rpc::*;
 
+use crate::adapters::wifi::AuthMode;
+use crate::power::{ResetCause as PowerResetCause, WakeReason as PowerWakeReason};
+use crate::app::commands::CommandSource as AppCommandSource;
+use crate::config::{
+    PowerProfile as ConfigPowerProfile, PurgeMode as ConfigPurgeMode,
+    TankSource as ConfigTankSource,
+};
+use crate::control::autotune::TuneStatus;
+use crate::drivers::led_patterns::{LedOverlay as ConfigLedOverlay, PatternId};
 use crate::fsm::StateId;
 
 pub fn state_to_fb(state: StateId) -> DeviceState {
@@ -22,6 +31,7 @@ pub fn state_to_fb(state: StateId) -> DeviceState {
         StateId::Active => DeviceState::Active,
         StateId::Purging => DeviceState::Purging,
         StateId::Error => DeviceState::Error,
+        StateId::Draining => DeviceState::Draining,
     }
 }
 
@@ -31,6 +41,150 @@ pub fn fb_to_state(state: DeviceState) -> StateId {
         1 => StateId::Sensing,
         2 => StateId::Active,
         3 => StateId::Purging,
+        5 => StateId::Draining,
         _ => StateId::Error,
     }
 }
+
+pub fn source_to_fb(source: Option<AppCommandSource>) -> CommandSource {
+    match source {
+        None => CommandSource::None,
+        Some(AppCommandSource::Button) => CommandSource::Button,
+        Some(AppCommandSource::Rpc) => CommandSource::Rpc,
+        Some(AppCommandSource::Schedule) => CommandSource::Schedule,
+    }
+}
+
+pub fn tune_status_to_fb(status: TuneStatus) -> AutoTuneState {
+    match status {
+        TuneStatus::Idle => AutoTuneState::Idle,
+        TuneStatus::Running => AutoTuneState::Running,
+        TuneStatus::Complete(_) => AutoTuneState::Complete,
+        TuneStatus::TimedOut => AutoTuneState::TimedOut,
+        TuneStatus::Aborted => AutoTuneState::Aborted,
+    }
+}
+
+pub fn purge_mode_to_fb(mode: ConfigPurgeMode) -> PurgeMode {
+    match mode {
+        ConfigPurgeMode::Duration => PurgeMode::Duration,
+        ConfigPurgeMode::Volume => PurgeMode::Volume,
+    }
+}
+
+pub fn tank_source_to_fb(source: ConfigTankSource) -> TankSource {
+    match source {
+        ConfigTankSource::TankA => TankSource::TankA,
+        ConfigTankSource::TankB => TankSource::TankB,
+        ConfigTankSource::Alternate => TankSource::Alternate,
+    }
+}
+
+/// `None` for values outside the schema enum.
+pub fn fb_to_tank_source(source: TankSource) -> Option<ConfigTankSource> {
+    match source {
+        TankSource::TankA => Some(ConfigTankSource::TankA),
+        TankSource::TankB => Some(ConfigTankSource::TankB),
+        TankSource::Alternate => Some(ConfigTankSource::Alternate),
+        _ => None,
+    }
+}
+
+pub fn power_profile_to_fb(profile: ConfigPowerProfile) -> PowerProfile {
+    match profile {
+        ConfigPowerProfile::Eco => PowerProfile::Eco,
+        ConfigPowerProfile::Balanced => PowerProfile::Balanced,
+        ConfigPowerProfile::Performance => PowerProfile::Performance,
+    }
+}
+
+/// `None` for values outside the schema enum.
+pub fn fb_to_power_profile(profile: PowerProfile) -> Option<ConfigPowerProfile> {
+    match profile {
+        PowerProfile::Eco => Some(ConfigPowerProfile::Eco),
+        PowerProfile::Balanced => Some(ConfigPowerProfile::Balanced),
+        PowerProfile::Performance => Some(ConfigPowerProfile::Performance),
+        _ => None,
+    }
+}
+
+pub fn led_pattern_to_fb(pattern: PatternId) -> LedPattern {
+    match pattern {
+        PatternId::Solid => LedPattern::Solid,
+        PatternId::SlowPulse => LedPattern::SlowPulse,
+        PatternId::FastBlink => LedPattern::FastBlink,
+        PatternId::Breathing => LedPattern::Breathing,
+        PatternId::DoubleBlink => LedPattern::DoubleBlink,
+        PatternId::RapidFlash => LedPattern::RapidFlash,
+        PatternId::Off => LedPattern::Off,
+    }
+}
+
+/// `None` for values outside the schema enum.
+pub fn fb_to_led_pattern(pattern: LedPattern) -> Option<PatternId> {
+    match pattern {
+        LedPattern::Solid => Some(PatternId::Solid),
+        LedPattern::SlowPulse => Some(PatternId::SlowPulse),
+        LedPattern::FastBlink => Some(PatternId::FastBlink),
+        LedPattern::Breathing => Some(PatternId::Breathing),
+        LedPattern::DoubleBlink => Some(PatternId::DoubleBlink),
+        LedPattern::RapidFlash => Some(PatternId::RapidFlash),
+        LedPattern::Off => Some(PatternId::Off),
+        _ => None,
+    }
+}
+
+pub fn led_overlay_to_fb(overlay: ConfigLedOverlay) -> LedOverlay {
+    match overlay {
+        ConfigLedOverlay::Ota => LedOverlay::Ota,
+        ConfigLedOverlay::LowWater => LedOverlay::LowWater,
+        ConfigLedOverlay::Wifi => LedOverlay::Wifi,
+        ConfigLedOverlay::Ble => LedOverlay::Ble,
+    }
+}
+
+pub fn reset_cause_to_fb(cause: PowerResetCause) -> ResetCause {
+    match cause {
+        PowerResetCause::Unknown => ResetCause::Unknown,
+        PowerResetCause::PowerOn => ResetCause::PowerOn,
+        PowerResetCause::External => ResetCause::External,
+        PowerResetCause::Software => ResetCause::Software,
+        PowerResetCause::Panic => ResetCause::Panic,
+        PowerResetCause::InterruptWatchdog => ResetCause::InterruptWatchdog,
+        PowerResetCause::TaskWatchdog => ResetCause::TaskWatchdog,
+        PowerResetCause::Watchdog => ResetCause::Watchdog,
+        PowerResetCause::DeepSleep => ResetCause::DeepSleep,
+        PowerResetCause::Brownout => ResetCause::Brownout,
+        PowerResetCause::Sdio => ResetCause::Sdio,
+        PowerResetCause::Usb => ResetCause::Usb,
+        PowerResetCause::Jtag => ResetCause::Jtag,
+        PowerResetCause::Efuse => ResetCause::Efuse,
+        PowerResetCause::PowerGlitch => ResetCause::PowerGlitch,
+        PowerResetCause::CpuLockup => ResetCause::CpuLockup,
+    }
+}
+
+pub fn wake_reason_to_fb(reason: PowerWakeReason) -> WakeReason {
+    match reason {
+        PowerWakeReason::PowerOn => WakeReason::PowerOn,
+        PowerWakeReason::UlpWake => WakeReason::UlpWake,
+        PowerWakeReason::Timer => WakeReason::Timer,
+        PowerWakeReason::Gpio => WakeReason::Gpio,
+        PowerWakeReason::Touchpad => WakeReason::Touchpad,
+        PowerWakeReason::Unknown => WakeReason::Unknown,
+    }
+}
+
+pub fn auth_mode_to_fb(mode: AuthMode) -> WifiAuthMode {
+    match mode {
+        AuthMode::Open => WifiAuthMode::Open,
+        AuthMode::Wep => WifiAuthMode::Wep,
+        AuthMode::WpaPsk => WifiAuthMode::WpaPsk,
+        AuthMode::Wpa2Psk => WifiAuthMode::Wpa2Psk,
+        AuthMode::WpaWpa2Psk => WifiAuthMode::WpaWpa2Psk,
+        AuthMode::Wpa2Enterprise => WifiAuthMode::Wpa2Enterprise,
+        AuthMode::Wpa3Psk => WifiAuthMode::Wpa3Psk,
+        AuthMode::Wpa2Wpa3Psk => WifiAuthMode::Wpa2Wpa3Psk,
+        AuthMode::Other => WifiAuthMode::Other,
+    }
+}
@@ -29,15 +29,16 @@ pub mod rpc {
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_DEVICE_STATE: i8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_DEVICE_STATE: i8 = 4;
+pub const ENUM_MAX_DEVICE_STATE: i8 = 5;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_DEVICE_STATE: [DeviceState; 5] = [
+pub const ENUM_VALUES_DEVICE_STATE: [DeviceState; 6] = [
   DeviceState::Idle,
   DeviceState::Sensing,
   DeviceState::Active,
   DeviceState::Purging,
   DeviceState::Error,
+  DeviceState::Draining,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -50,15 +51,17 @@ impl DeviceState {
   pub const Active: Self = Self(2);
   pub const Purging: Self = Self(3);
   pub const Error: Self = Self(4);
+  pub const Draining: Self = Self(5);
 
   pub const ENUM_MIN: i8 = 0;
-  pub const ENUM_MAX: i8 = 4;
+  pub const ENUM_MAX: i8 = 5;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::Idle,
     Self::Sensing,
     Self::Active,
     Self::Purging,
     Self::Error,
+    Self::Draining,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -68,6 +71,7 @@ impl DeviceState {
       Self::Active => Some("Active"),
       Self::Purging => Some("Purging"),
       Self::Error => Some("Error"),
+      Self::Draining => Some("Draining"),
       _ => None,
     }
   }
@@ -124,45 +128,45 @@ impl<'a> flatbuffers::Verifiable for DeviceState {
 
 impl flatbuffers::SimpleToVerifyInSlice for DeviceState {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_TLS_MODE: i8 = 0;
+pub const ENUM_MIN_SCHEDULE_KIND: i8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_TLS_MODE: i8 = 2;
+pub const ENUM_MAX_SCHEDULE_KIND: i8 = 2;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_TLS_MODE: [TlsMode; 3] = [
-  TlsMode::PskOnly,
-  TlsMode::CertOnly,
-  TlsMode::PskAndCert,
+pub const ENUM_VALUES_SCHEDULE_KIND: [ScheduleKind; 3] = [
+  ScheduleKind::Periodic,
+  ScheduleKind::Boost,
+  ScheduleKind::OneShot,
 ];
 
-/// TLS authentication mode for the device's RPC listener.
+/// Schedule type, mirroring `scheduler::ScheduleKind`.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct TlsMode(pub i8);
+pub struct ScheduleKind(pub i8);
 #[allow(non_upper_case_globals)]
-impl TlsMode {
-  pub const PskOnly: Self = Self(0);
-  pub const CertOnly: Self = Self(1);
-  pub const PskAndCert: Self = Self(2);
+impl ScheduleKind {
+  pub const Periodic: Self = Self(0);
+  pub const Boost: Self = Self(1);
+  pub const OneShot: Self = Self(2);
 
   pub const ENUM_MIN: i8 = 0;
   pub const ENUM_MAX: i8 = 2;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::PskOnly,
-    Self::CertOnly,
-    Self::PskAndCert,
+    Self::Periodic,
+    Self::Boost,
+    Self::OneShot,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::PskOnly => Some("PskOnly"),
-      Self::CertOnly => Some("CertOnly"),
-      Self::PskAndCert => Some("PskAndCert"),
+      Self::Periodic => Some("Periodic"),
+      Self::Boost => Some("Boost"),
+      Self::OneShot => Some("OneShot"),
       _ => None,
     }
   }
 }
-impl core::fmt::Debug for TlsMode {
+impl core::fmt::Debug for ScheduleKind {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -171,7 +175,7 @@ impl core::fmt::Debug for TlsMode {
     }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for TlsMode {
+impl<'a> flatbuffers::Follow<'a> for ScheduleKind {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -180,15 +184,15 @@ impl<'a> flatbuffers::Follow<'a> for TlsMode {
   }
 }
 
-impl flatbuffers::Push for TlsMode {
-    type Output = TlsMode;
+impl flatbuffers::Push for ScheduleKind {
+    type Output = ScheduleKind;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<i8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for TlsMode {
+impl flatbuffers::EndianScalar for ScheduleKind {
   type Scalar = i8;
   #[inline]
   fn to_little_endian(self) -> i8 {
@@ -202,7 +206,7 @@ impl flatbuffers::EndianScalar for TlsMode {
   }
 }
 
-impl<'a> flatbuffers::Verifiable for TlsMode {
+impl<'a> flatbuffers::Verifiable for ScheduleKind {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -212,162 +216,51 @@ impl<'a> flatbuffers::Verifiable for TlsMode {
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for TlsMode {}
+impl flatbuffers::SimpleToVerifyInSlice for ScheduleKind {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_PAYLOAD: u8 = 0;
+pub const ENUM_MIN_COMMAND_SOURCE: i8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_PAYLOAD: u8 = 31;
+pub const ENUM_MAX_COMMAND_SOURCE: i8 = 3;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_PAYLOAD: [Payload; 32] = [
-  Payload::NONE,
-  Payload::GetStatusRequest,
-  Payload::StartScrubRequest,
-  Payload::StopScrubRequest,
-  Payload::ClearFaultsRequest,
-  Payload::SetConfigRequest,
-  Payload::SetScheduleRequest,
-  Payload::CancelScheduleRequest,
-  Payload::SubscribeTelemetryRequest,
-  Payload::UnsubscribeTelemetryRequest,
-  Payload::GetDeviceInfoRequest,
-  Payload::StatusResponse,
-  Payload::DeviceInfoResponse,
-  Payload::AckResponse,
-  Payload::TelemetryFrame,
-  Payload::StateChangeEvent,
-  Payload::FaultEvent,
-  Payload::AuthChallengeRequest,
-  Payload::AuthChallengeResponse,
-  Payload::AuthVerifyRequest,
-  Payload::AuthVerifyResponse,
-  Payload::ProvisionCertRequest,
-  Payload::GetCertStatusRequest,
-  Payload::CertStatusResponse,
-  Payload::OtaBeginRequest,
-  Payload::OtaChunkRequest,
-  Payload::OtaFinalizeRequest,
-  Payload::OtaResponse,
-  Payload::OtaProgressEvent,
-  Payload::GetDiagnosticsRequest,
-  Payload::ClearDiagnosticsRequest,
-  Payload::DiagnosticsResponse,
+pub const ENUM_VALUES_COMMAND_SOURCE: [CommandSource; 4] = [
+  CommandSource::None,
+  CommandSource::Button,
+  CommandSource::Rpc,
+  CommandSource::Schedule,
 ];
 
+/// Who started the current scrub, mirroring `app::commands::CommandSource`.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct Payload(pub u8);
+pub struct CommandSource(pub i8);
 #[allow(non_upper_case_globals)]
-impl Payload {
-  pub const NONE: Self = Self(0);
-  pub const GetStatusRequest: Self = Self(1);
-  pub const StartScrubRequest: Self = Self(2);
-  pub const StopScrubRequest: Self = Self(3);
-  pub const ClearFaultsRequest: Self = Self(4);
-  pub const SetConfigRequest: Self = Self(5);
-  pub const SetScheduleRequest: Self = Self(6);
-  pub const CancelScheduleRequest: Self = Self(7);
-  pub const SubscribeTelemetryRequest: Self = Self(8);
-  pub const UnsubscribeTelemetryRequest: Self = Self(9);
-  pub const GetDeviceInfoRequest: Self = Self(10);
-  pub const StatusResponse: Self = Self(11);
-  pub const DeviceInfoResponse: Self = Self(12);
-  pub const AckResponse: Self = Self(13);
-  pub const TelemetryFrame: Self = Self(14);
-  pub const StateChangeEvent: Self = Self(15);
-  pub const FaultEvent: Self = Self(16);
-  pub const AuthChallengeRequest: Self = Self(17);
-  pub const AuthChallengeResponse: Self = Self(18);
-  pub const AuthVerifyRequest: Self = Self(19);
-  pub const AuthVerifyResponse: Self = Self(20);
-  pub const ProvisionCertRequest: Self = Self(21);
-  pub const GetCertStatusRequest: Self = Self(22);
-  pub const CertStatusResponse: Self = Self(23);
-  pub const OtaBeginRequest: Self = Self(24);
-  pub const OtaChunkRequest: Self = Self(25);
-  pub const OtaFinalizeRequest: Self = Self(26);
-  pub const OtaResponse: Self = Self(27);
-  pub const OtaProgressEvent: Self = Self(28);
-  pub const GetDiagnosticsRequest: Self = Self(29);
-  pub const ClearDiagnosticsRequest: Self = Self(30);
-  pub const DiagnosticsResponse: Self = Self(31);
+impl CommandSource {
+  pub const None: Self = Self(0);
+  pub const Button: Self = Self(1);
+  pub const Rpc: Self = Self(2);
+  pub const Schedule: Self = Self(3);
 
-  pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 31;
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::NONE,
-    Self::GetStatusRequest,
-    Self::StartScrubRequest,
-    Self::StopScrubRequest,
-    Self::ClearFaultsRequest,
-    Self::SetConfigRequest,
-    Self::SetScheduleRequest,
-    Self::CancelScheduleRequest,
-    Self::SubscribeTelemetryRequest,
-    Self::UnsubscribeTelemetryRequest,
-    Self::GetDeviceInfoRequest,
-    Self::StatusResponse,
-    Self::DeviceInfoResponse,
-    Self::AckResponse,
-    Self::TelemetryFrame,
-    Self::StateChangeEvent,
-    Self::FaultEvent,
-    Self::AuthChallengeRequest,
-    Self::AuthChallengeResponse,
-    Self::AuthVerifyRequest,
-    Self::AuthVerifyResponse,
-    Self::ProvisionCertRequest,
-    Self::GetCertStatusRequest,
-    Self::CertStatusResponse,
-    Self::OtaBeginRequest,
-    Self::OtaChunkRequest,
-    Self::OtaFinalizeRequest,
-    Self::OtaResponse,
-    Self::OtaProgressEvent,
-    Self::GetDiagnosticsRequest,
-    Self::ClearDiagnosticsRequest,
-    Self::DiagnosticsResponse,
+    Self::None,
+    Self::Button,
+    Self::Rpc,
+    Self::Schedule,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::NONE => Some("NONE"),
-      Self::GetStatusRequest => Some("GetStatusRequest"),
-      Self::StartScrubRequest => Some("StartScrubRequest"),
-      Self::StopScrubRequest => Some("StopScrubRequest"),
-      Self::ClearFaultsRequest => Some("ClearFaultsRequest"),
-      Self::SetConfigRequest => Some("SetConfigRequest"),
-      Self::SetScheduleRequest => Some("SetScheduleRequest"),
-      Self::CancelScheduleRequest => Some("CancelScheduleRequest"),
-      Self::SubscribeTelemetryRequest => Some("SubscribeTelemetryRequest"),
-      Self::UnsubscribeTelemetryRequest => Some("UnsubscribeTelemetryRequest"),
-      Self::GetDeviceInfoRequest => Some("GetDeviceInfoRequest"),
-      Self::StatusResponse => Some("StatusResponse"),
-      Self::DeviceInfoResponse => Some("DeviceInfoResponse"),
-      Self::AckResponse => Some("AckResponse"),
-      Self::TelemetryFrame => Some("TelemetryFrame"),
-      Self::StateChangeEvent => Some("StateChangeEvent"),
-      Self::FaultEvent => Some("FaultEvent"),
-      Self::AuthChallengeRequest => Some("AuthChallengeRequest"),
-      Self::AuthChallengeResponse => Some("AuthChallengeResponse"),
-      Self::AuthVerifyRequest => Some("AuthVerifyRequest"),
-      Self::AuthVerifyResponse => Some("AuthVerifyResponse"),
-      Self::ProvisionCertRequest => Some("ProvisionCertRequest"),
-      Self::GetCertStatusRequest => Some("GetCertStatusRequest"),
-      Self::CertStatusResponse => Some("CertStatusResponse"),
-      Self::OtaBeginRequest => Some("OtaBeginRequest"),
-      Self::OtaChunkRequest => Some("OtaChunkRequest"),
-      Self::OtaFinalizeRequest => Some("OtaFinalizeRequest"),
-      Self::OtaResponse => Some("OtaResponse"),
-      Self::OtaProgressEvent => Some("OtaProgressEvent"),
-      Self::GetDiagnosticsRequest => Some("GetDiagnosticsRequest"),
-      Self::ClearDiagnosticsRequest => Some("ClearDiagnosticsRequest"),
-      Self::DiagnosticsResponse => Some("DiagnosticsResponse"),
+      Self::None => Some("None"),
+      Self::Button => Some("Button"),
+      Self::Rpc => Some("Rpc"),
+      Self::Schedule => Some("Schedule"),
       _ => None,
     }
   }
 }
-impl core::fmt::Debug for Payload {
+impl core::fmt::Debug for CommandSource {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -376,4075 +269,14966 @@ impl core::fmt::Debug for Payload {
     }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for Payload {
+impl<'a> flatbuffers::Follow<'a> for CommandSource {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
     Self(b)
   }
 }
 
-impl flatbuffers::Push for Payload {
-    type Output = Payload;
+impl flatbuffers::Push for CommandSource {
+    type Output = CommandSource;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for Payload {
-  type Scalar = u8;
+impl flatbuffers::EndianScalar for CommandSource {
+  type Scalar = i8;
   #[inline]
-  fn to_little_endian(self) -> u8 {
+  fn to_little_endian(self) -> i8 {
     self.0.to_le()
   }
   #[inline]
   #[allow(clippy::wrong_self_convention)]
-  fn from_little_endian(v: u8) -> Self {
-    let b = u8::from_le(v);
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
     Self(b)
   }
 }
 
-impl<'a> flatbuffers::Verifiable for Payload {
+impl<'a> flatbuffers::Verifiable for CommandSource {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    u8::run_verifier(v, pos)
+    i8::run_verifier(v, pos)
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for Payload {}
-pub struct PayloadUnionTableOffset {}
+impl flatbuffers::SimpleToVerifyInSlice for CommandSource {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_PURGE_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_PURGE_MODE: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PURGE_MODE: [PurgeMode; 2] = [
+  PurgeMode::Duration,
+  PurgeMode::Volume,
+];
 
-pub enum GetStatusRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
+/// Purge completion rule, mirroring `config::PurgeMode`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PurgeMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl PurgeMode {
+  pub const Duration: Self = Self(0);
+  pub const Volume: Self = Self(1);
 
-pub struct GetStatusRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Duration,
+    Self::Volume,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Duration => Some("Duration"),
+      Self::Volume => Some("Volume"),
+      _ => None,
+    }
+  }
 }
-
-impl<'a> flatbuffers::Follow<'a> for GetStatusRequest<'a> {
-  type Inner = GetStatusRequest<'a>;
+impl core::fmt::Debug for PurgeMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for PurgeMode {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> GetStatusRequest<'a> {
+impl flatbuffers::Push for PurgeMode {
+    type Output = PurgeMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for PurgeMode {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GetStatusRequest { _tab: table }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args GetStatusRequestArgs
-  ) -> flatbuffers::WIPOffset<GetStatusRequest<'bldr>> {
-    let mut builder = GetStatusRequestBuilder::new(_fbb);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
-
 }
 
-impl flatbuffers::Verifiable for GetStatusRequest<'_> {
+impl<'a> flatbuffers::Verifiable for PurgeMode {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct GetStatusRequestArgs {
-}
-impl<'a> Default for GetStatusRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    GetStatusRequestArgs {
-    }
+    i8::run_verifier(v, pos)
   }
 }
 
-pub struct GetStatusRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetStatusRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetStatusRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    GetStatusRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for PurgeMode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_POWER_PROFILE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_POWER_PROFILE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_POWER_PROFILE: [PowerProfile; 3] = [
+  PowerProfile::Eco,
+  PowerProfile::Balanced,
+  PowerProfile::Performance,
+];
+
+/// Power trade-off, mirroring `config::PowerProfile`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PowerProfile(pub i8);
+#[allow(non_upper_case_globals)]
+impl PowerProfile {
+  pub const Eco: Self = Self(0);
+  pub const Balanced: Self = Self(1);
+  pub const Performance: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Eco,
+    Self::Balanced,
+    Self::Performance,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Eco => Some("Eco"),
+      Self::Balanced => Some("Balanced"),
+      Self::Performance => Some("Performance"),
+      _ => None,
     }
   }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GetStatusRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
 }
-
-impl core::fmt::Debug for GetStatusRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GetStatusRequest");
-      ds.finish()
+impl core::fmt::Debug for PowerProfile {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-pub enum StartScrubRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct StartScrubRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for StartScrubRequest<'a> {
-  type Inner = StartScrubRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for PowerProfile {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> StartScrubRequest<'a> {
+impl flatbuffers::Push for PowerProfile {
+    type Output = PowerProfile;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for PowerProfile {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StartScrubRequest { _tab: table }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args StartScrubRequestArgs
-  ) -> flatbuffers::WIPOffset<StartScrubRequest<'bldr>> {
-    let mut builder = StartScrubRequestBuilder::new(_fbb);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
-
 }
 
-impl flatbuffers::Verifiable for StartScrubRequest<'_> {
+impl<'a> flatbuffers::Verifiable for PowerProfile {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct StartScrubRequestArgs {
-}
-impl<'a> Default for StartScrubRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    StartScrubRequestArgs {
-    }
+    i8::run_verifier(v, pos)
   }
 }
 
-pub struct StartScrubRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StartScrubRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StartScrubRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    StartScrubRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for PowerProfile {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TANK_SOURCE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TANK_SOURCE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TANK_SOURCE: [TankSource; 3] = [
+  TankSource::TankA,
+  TankSource::TankB,
+  TankSource::Alternate,
+];
+
+/// Source tank selection, mirroring `config::TankSource`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TankSource(pub i8);
+#[allow(non_upper_case_globals)]
+impl TankSource {
+  pub const TankA: Self = Self(0);
+  pub const TankB: Self = Self(1);
+  pub const Alternate: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::TankA,
+    Self::TankB,
+    Self::Alternate,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::TankA => Some("TankA"),
+      Self::TankB => Some("TankB"),
+      Self::Alternate => Some("Alternate"),
+      _ => None,
     }
   }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StartScrubRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
 }
-
-impl core::fmt::Debug for StartScrubRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StartScrubRequest");
-      ds.finish()
+impl core::fmt::Debug for TankSource {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-pub enum StopScrubRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct StopScrubRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for StopScrubRequest<'a> {
-  type Inner = StopScrubRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for TankSource {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> StopScrubRequest<'a> {
+impl flatbuffers::Push for TankSource {
+    type Output = TankSource;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for TankSource {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StopScrubRequest { _tab: table }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args StopScrubRequestArgs
-  ) -> flatbuffers::WIPOffset<StopScrubRequest<'bldr>> {
-    let mut builder = StopScrubRequestBuilder::new(_fbb);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
-
 }
 
-impl flatbuffers::Verifiable for StopScrubRequest<'_> {
+impl<'a> flatbuffers::Verifiable for TankSource {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
+    i8::run_verifier(v, pos)
   }
 }
-pub struct StopScrubRequestArgs {
-}
-impl<'a> Default for StopScrubRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    StopScrubRequestArgs {
+
+impl flatbuffers::SimpleToVerifyInSlice for TankSource {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LED_PATTERN: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LED_PATTERN: i8 = 6;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LED_PATTERN: [LedPattern; 7] = [
+  LedPattern::Solid,
+  LedPattern::SlowPulse,
+  LedPattern::FastBlink,
+  LedPattern::Breathing,
+  LedPattern::DoubleBlink,
+  LedPattern::RapidFlash,
+  LedPattern::Off,
+];
+
+/// Status LED animation, mirroring `led_patterns::PatternId`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LedPattern(pub i8);
+#[allow(non_upper_case_globals)]
+impl LedPattern {
+  pub const Solid: Self = Self(0);
+  pub const SlowPulse: Self = Self(1);
+  pub const FastBlink: Self = Self(2);
+  pub const Breathing: Self = Self(3);
+  pub const DoubleBlink: Self = Self(4);
+  pub const RapidFlash: Self = Self(5);
+  pub const Off: Self = Self(6);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 6;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Solid,
+    Self::SlowPulse,
+    Self::FastBlink,
+    Self::Breathing,
+    Self::DoubleBlink,
+    Self::RapidFlash,
+    Self::Off,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Solid => Some("Solid"),
+      Self::SlowPulse => Some("SlowPulse"),
+      Self::FastBlink => Some("FastBlink"),
+      Self::Breathing => Some("Breathing"),
+      Self::DoubleBlink => Some("DoubleBlink"),
+      Self::RapidFlash => Some("RapidFlash"),
+      Self::Off => Some("Off"),
+      _ => None,
     }
   }
 }
-
-pub struct StopScrubRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StopScrubRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StopScrubRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    StopScrubRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
+impl core::fmt::Debug for LedPattern {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StopScrubRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
 }
-
-impl core::fmt::Debug for StopScrubRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StopScrubRequest");
-      ds.finish()
+impl<'a> flatbuffers::Follow<'a> for LedPattern {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
-pub enum ClearFaultsRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
 
-pub struct ClearFaultsRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+impl flatbuffers::Push for LedPattern {
+    type Output = LedPattern;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
 }
 
-impl<'a> flatbuffers::Follow<'a> for ClearFaultsRequest<'a> {
-  type Inner = ClearFaultsRequest<'a>;
+impl flatbuffers::EndianScalar for LedPattern {
+  type Scalar = i8;
   #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl<'a> ClearFaultsRequest<'a> {
-
-  #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ClearFaultsRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args ClearFaultsRequestArgs
-  ) -> flatbuffers::WIPOffset<ClearFaultsRequest<'bldr>> {
-    let mut builder = ClearFaultsRequestBuilder::new(_fbb);
-    builder.finish()
-  }
-
-}
-
-impl flatbuffers::Verifiable for ClearFaultsRequest<'_> {
+impl<'a> flatbuffers::Verifiable for LedPattern {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
+    i8::run_verifier(v, pos)
   }
 }
-pub struct ClearFaultsRequestArgs {
+
+impl flatbuffers::SimpleToVerifyInSlice for LedPattern {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LED_OVERLAY: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LED_OVERLAY: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LED_OVERLAY: [LedOverlay; 4] = [
+  LedOverlay::Ota,
+  LedOverlay::LowWater,
+  LedOverlay::Wifi,
+  LedOverlay::Ble,
+];
+
+/// Connectivity LED overlay, mirroring `led_patterns::LedOverlay`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LedOverlay(pub i8);
+#[allow(non_upper_case_globals)]
+impl LedOverlay {
+  pub const Ota: Self = Self(0);
+  pub const LowWater: Self = Self(1);
+  pub const Wifi: Self = Self(2);
+  pub const Ble: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Ota,
+    Self::LowWater,
+    Self::Wifi,
+    Self::Ble,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Ota => Some("Ota"),
+      Self::LowWater => Some("LowWater"),
+      Self::Wifi => Some("Wifi"),
+      Self::Ble => Some("Ble"),
+      _ => None,
+    }
+  }
 }
-impl<'a> Default for ClearFaultsRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    ClearFaultsRequestArgs {
+impl core::fmt::Debug for LedOverlay {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
 }
+impl<'a> flatbuffers::Follow<'a> for LedOverlay {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
 
-pub struct ClearFaultsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+impl flatbuffers::Push for LedOverlay {
+    type Output = LedOverlay;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClearFaultsRequestBuilder<'a, 'b, A> {
+
+impl flatbuffers::EndianScalar for LedOverlay {
+  type Scalar = i8;
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClearFaultsRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    ClearFaultsRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ClearFaultsRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl core::fmt::Debug for ClearFaultsRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ClearFaultsRequest");
-      ds.finish()
+impl<'a> flatbuffers::Verifiable for LedOverlay {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
   }
 }
-pub enum SetConfigRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
 
-pub struct SetConfigRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
+impl flatbuffers::SimpleToVerifyInSlice for LedOverlay {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_WIFI_AUTH_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_WIFI_AUTH_MODE: i8 = 127;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_WIFI_AUTH_MODE: [WifiAuthMode; 9] = [
+  WifiAuthMode::Open,
+  WifiAuthMode::Wep,
+  WifiAuthMode::WpaPsk,
+  WifiAuthMode::Wpa2Psk,
+  WifiAuthMode::WpaWpa2Psk,
+  WifiAuthMode::Wpa2Enterprise,
+  WifiAuthMode::Wpa3Psk,
+  WifiAuthMode::Wpa2Wpa3Psk,
+  WifiAuthMode::Other,
+];
 
-impl<'a> flatbuffers::Follow<'a> for SetConfigRequest<'a> {
-  type Inner = SetConfigRequest<'a>;
+/// AP security mode, mirroring ESP-IDF `wifi_auth_mode_t` numbering.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct WifiAuthMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl WifiAuthMode {
+  pub const Open: Self = Self(0);
+  pub const Wep: Self = Self(1);
+  pub const WpaPsk: Self = Self(2);
+  pub const Wpa2Psk: Self = Self(3);
+  pub const WpaWpa2Psk: Self = Self(4);
+  pub const Wpa2Enterprise: Self = Self(5);
+  pub const Wpa3Psk: Self = Self(6);
+  pub const Wpa2Wpa3Psk: Self = Self(7);
+  pub const Other: Self = Self(127);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 127;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Open,
+    Self::Wep,
+    Self::WpaPsk,
+    Self::Wpa2Psk,
+    Self::WpaWpa2Psk,
+    Self::Wpa2Enterprise,
+    Self::Wpa3Psk,
+    Self::Wpa2Wpa3Psk,
+    Self::Other,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Open => Some("Open"),
+      Self::Wep => Some("Wep"),
+      Self::WpaPsk => Some("WpaPsk"),
+      Self::Wpa2Psk => Some("Wpa2Psk"),
+      Self::WpaWpa2Psk => Some("WpaWpa2Psk"),
+      Self::Wpa2Enterprise => Some("Wpa2Enterprise"),
+      Self::Wpa3Psk => Some("Wpa3Psk"),
+      Self::Wpa2Wpa3Psk => Some("Wpa2Wpa3Psk"),
+      Self::Other => Some("Other"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for WifiAuthMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for WifiAuthMode {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> SetConfigRequest<'a> {
-  pub const VT_NH3_ACTIVATE_PPM: flatbuffers::VOffsetT = 4;
-  pub const VT_NH3_DEACTIVATE_PPM: flatbuffers::VOffsetT = 6;
-  pub const VT_PUMP_DUTY_PERCENT: flatbuffers::VOffsetT = 8;
-  pub const VT_UVC_DUTY_PERCENT: flatbuffers::VOffsetT = 10;
-  pub const VT_PURGE_DURATION_SECS: flatbuffers::VOffsetT = 12;
+impl flatbuffers::Push for WifiAuthMode {
+    type Output = WifiAuthMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for WifiAuthMode {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SetConfigRequest { _tab: table }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SetConfigRequestArgs
-  ) -> flatbuffers::WIPOffset<SetConfigRequest<'bldr>> {
-    let mut builder = SetConfigRequestBuilder::new(_fbb);
-    builder.add_nh3_deactivate_ppm(args.nh3_deactivate_ppm);
-    builder.add_nh3_activate_ppm(args.nh3_activate_ppm);
-    builder.add_purge_duration_secs(args.purge_duration_secs);
-    builder.add_uvc_duty_percent(args.uvc_duty_percent);
-    builder.add_pump_duty_percent(args.pump_duty_percent);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
+}
 
-
+impl<'a> flatbuffers::Verifiable for WifiAuthMode {
   #[inline]
-  pub fn nh3_activate_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(SetConfigRequest::VT_NH3_ACTIVATE_PPM, Some(0.0)).unwrap()}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
   }
-  #[inline]
-  pub fn nh3_deactivate_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(SetConfigRequest::VT_NH3_DEACTIVATE_PPM, Some(0.0)).unwrap()}
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for WifiAuthMode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_RESET_CAUSE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_RESET_CAUSE: i8 = 15;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_RESET_CAUSE: [ResetCause; 16] = [
+  ResetCause::Unknown,
+  ResetCause::PowerOn,
+  ResetCause::External,
+  ResetCause::Software,
+  ResetCause::Panic,
+  ResetCause::InterruptWatchdog,
+  ResetCause::TaskWatchdog,
+  ResetCause::Watchdog,
+  ResetCause::DeepSleep,
+  ResetCause::Brownout,
+  ResetCause::Sdio,
+  ResetCause::Usb,
+  ResetCause::Jtag,
+  ResetCause::Efuse,
+  ResetCause::PowerGlitch,
+  ResetCause::CpuLockup,
+];
+
+/// Why the chip last reset, mirroring ESP-IDF `esp_reset_reason_t`
+/// numbering (`power::ResetCause`).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ResetCause(pub i8);
+#[allow(non_upper_case_globals)]
+impl ResetCause {
+  pub const Unknown: Self = Self(0);
+  pub const PowerOn: Self = Self(1);
+  pub const External: Self = Self(2);
+  pub const Software: Self = Self(3);
+  pub const Panic: Self = Self(4);
+  pub const InterruptWatchdog: Self = Self(5);
+  pub const TaskWatchdog: Self = Self(6);
+  pub const Watchdog: Self = Self(7);
+  pub const DeepSleep: Self = Self(8);
+  pub const Brownout: Self = Self(9);
+  pub const Sdio: Self = Self(10);
+  pub const Usb: Self = Self(11);
+  pub const Jtag: Self = Self(12);
+  pub const Efuse: Self = Self(13);
+  pub const PowerGlitch: Self = Self(14);
+  pub const CpuLockup: Self = Self(15);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 15;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Unknown,
+    Self::PowerOn,
+    Self::External,
+    Self::Software,
+    Self::Panic,
+    Self::InterruptWatchdog,
+    Self::TaskWatchdog,
+    Self::Watchdog,
+    Self::DeepSleep,
+    Self::Brownout,
+    Self::Sdio,
+    Self::Usb,
+    Self::Jtag,
+    Self::Efuse,
+    Self::PowerGlitch,
+    Self::CpuLockup,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Unknown => Some("Unknown"),
+      Self::PowerOn => Some("PowerOn"),
+      Self::External => Some("External"),
+      Self::Software => Some("Software"),
+      Self::Panic => Some("Panic"),
+      Self::InterruptWatchdog => Some("InterruptWatchdog"),
+      Self::TaskWatchdog => Some("TaskWatchdog"),
+      Self::Watchdog => Some("Watchdog"),
+      Self::DeepSleep => Some("DeepSleep"),
+      Self::Brownout => Some("Brownout"),
+      Self::Sdio => Some("Sdio"),
+      Self::Usb => Some("Usb"),
+      Self::Jtag => Some("Jtag"),
+      Self::Efuse => Some("Efuse"),
+      Self::PowerGlitch => Some("PowerGlitch"),
+      Self::CpuLockup => Some("CpuLockup"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ResetCause {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
+}
+impl<'a> flatbuffers::Follow<'a> for ResetCause {
+  type Inner = Self;
   #[inline]
-  pub fn pump_duty_percent(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(SetConfigRequest::VT_PUMP_DUTY_PERCENT, Some(0)).unwrap()}
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
+}
+
+impl flatbuffers::Push for ResetCause {
+    type Output = ResetCause;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ResetCause {
+  type Scalar = i8;
   #[inline]
-  pub fn uvc_duty_percent(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(SetConfigRequest::VT_UVC_DUTY_PERCENT, Some(0)).unwrap()}
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn purge_duration_secs(&self) -> u16 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(SetConfigRequest::VT_PURGE_DURATION_SECS, Some(0)).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for SetConfigRequest<'_> {
+impl<'a> flatbuffers::Verifiable for ResetCause {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<f32>("nh3_activate_ppm", Self::VT_NH3_ACTIVATE_PPM, false)?
-     .visit_field::<f32>("nh3_deactivate_ppm", Self::VT_NH3_DEACTIVATE_PPM, false)?
-     .visit_field::<u8>("pump_duty_percent", Self::VT_PUMP_DUTY_PERCENT, false)?
-     .visit_field::<u8>("uvc_duty_percent", Self::VT_UVC_DUTY_PERCENT, false)?
-     .visit_field::<u16>("purge_duration_secs", Self::VT_PURGE_DURATION_SECS, false)?
-     .finish();
-    Ok(())
+    i8::run_verifier(v, pos)
   }
 }
-pub struct SetConfigRequestArgs {
-    pub nh3_activate_ppm: f32,
-    pub nh3_deactivate_ppm: f32,
-    pub pump_duty_percent: u8,
-    pub uvc_duty_percent: u8,
-    pub purge_duration_secs: u16,
-}
-impl<'a> Default for SetConfigRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    SetConfigRequestArgs {
-      nh3_activate_ppm: 0.0,
-      nh3_deactivate_ppm: 0.0,
-      pump_duty_percent: 0,
-      uvc_duty_percent: 0,
-      purge_duration_secs: 0,
+
+impl flatbuffers::SimpleToVerifyInSlice for ResetCause {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_WAKE_REASON: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_WAKE_REASON: i8 = 5;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_WAKE_REASON: [WakeReason; 6] = [
+  WakeReason::PowerOn,
+  WakeReason::UlpWake,
+  WakeReason::Timer,
+  WakeReason::Gpio,
+  WakeReason::Touchpad,
+  WakeReason::Unknown,
+];
+
+/// Sleep wake source at boot, mirroring `power::WakeReason`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct WakeReason(pub i8);
+#[allow(non_upper_case_globals)]
+impl WakeReason {
+  pub const PowerOn: Self = Self(0);
+  pub const UlpWake: Self = Self(1);
+  pub const Timer: Self = Self(2);
+  pub const Gpio: Self = Self(3);
+  pub const Touchpad: Self = Self(4);
+  pub const Unknown: Self = Self(5);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 5;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::PowerOn,
+    Self::UlpWake,
+    Self::Timer,
+    Self::Gpio,
+    Self::Touchpad,
+    Self::Unknown,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::PowerOn => Some("PowerOn"),
+      Self::UlpWake => Some("UlpWake"),
+      Self::Timer => Some("Timer"),
+      Self::Gpio => Some("Gpio"),
+      Self::Touchpad => Some("Touchpad"),
+      Self::Unknown => Some("Unknown"),
+      _ => None,
     }
   }
 }
-
-pub struct SetConfigRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetConfigRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_nh3_activate_ppm(&mut self, nh3_activate_ppm: f32) {
-    self.fbb_.push_slot::<f32>(SetConfigRequest::VT_NH3_ACTIVATE_PPM, nh3_activate_ppm, 0.0);
-  }
-  #[inline]
-  pub fn add_nh3_deactivate_ppm(&mut self, nh3_deactivate_ppm: f32) {
-    self.fbb_.push_slot::<f32>(SetConfigRequest::VT_NH3_DEACTIVATE_PPM, nh3_deactivate_ppm, 0.0);
-  }
-  #[inline]
-  pub fn add_pump_duty_percent(&mut self, pump_duty_percent: u8) {
-    self.fbb_.push_slot::<u8>(SetConfigRequest::VT_PUMP_DUTY_PERCENT, pump_duty_percent, 0);
+impl core::fmt::Debug for WakeReason {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
+}
+impl<'a> flatbuffers::Follow<'a> for WakeReason {
+  type Inner = Self;
   #[inline]
-  pub fn add_uvc_duty_percent(&mut self, uvc_duty_percent: u8) {
-    self.fbb_.push_slot::<u8>(SetConfigRequest::VT_UVC_DUTY_PERCENT, uvc_duty_percent, 0);
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
+}
+
+impl flatbuffers::Push for WakeReason {
+    type Output = WakeReason;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for WakeReason {
+  type Scalar = i8;
   #[inline]
-  pub fn add_purge_duration_secs(&mut self, purge_duration_secs: u16) {
-    self.fbb_.push_slot::<u16>(SetConfigRequest::VT_PURGE_DURATION_SECS, purge_duration_secs, 0);
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetConfigRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    SetConfigRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
+}
+
+impl<'a> flatbuffers::Verifiable for WakeReason {
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SetConfigRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
   }
 }
 
-impl core::fmt::Debug for SetConfigRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SetConfigRequest");
-      ds.field("nh3_activate_ppm", &self.nh3_activate_ppm());
-      ds.field("nh3_deactivate_ppm", &self.nh3_deactivate_ppm());
-      ds.field("pump_duty_percent", &self.pump_duty_percent());
-      ds.field("uvc_duty_percent", &self.uvc_duty_percent());
-      ds.field("purge_duration_secs", &self.purge_duration_secs());
-      ds.finish()
+impl flatbuffers::SimpleToVerifyInSlice for WakeReason {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TLS_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TLS_MODE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TLS_MODE: [TlsMode; 3] = [
+  TlsMode::PskOnly,
+  TlsMode::CertOnly,
+  TlsMode::PskAndCert,
+];
+
+/// TLS authentication mode for the device's RPC listener.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TlsMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl TlsMode {
+  pub const PskOnly: Self = Self(0);
+  pub const CertOnly: Self = Self(1);
+  pub const PskAndCert: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::PskOnly,
+    Self::CertOnly,
+    Self::PskAndCert,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::PskOnly => Some("PskOnly"),
+      Self::CertOnly => Some("CertOnly"),
+      Self::PskAndCert => Some("PskAndCert"),
+      _ => None,
+    }
   }
 }
-pub enum SetScheduleRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct SetScheduleRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+impl core::fmt::Debug for TlsMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
 }
-
-impl<'a> flatbuffers::Follow<'a> for SetScheduleRequest<'a> {
-  type Inner = SetScheduleRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for TlsMode {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> SetScheduleRequest<'a> {
-  pub const VT_INTERVAL_SECS: flatbuffers::VOffsetT = 4;
-  pub const VT_DURATION_SECS: flatbuffers::VOffsetT = 6;
-  pub const VT_QUIET_START_HOUR: flatbuffers::VOffsetT = 8;
-  pub const VT_QUIET_END_HOUR: flatbuffers::VOffsetT = 10;
-
-  #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SetScheduleRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SetScheduleRequestArgs
-  ) -> flatbuffers::WIPOffset<SetScheduleRequest<'bldr>> {
-    let mut builder = SetScheduleRequestBuilder::new(_fbb);
-    builder.add_interval_secs(args.interval_secs);
-    builder.add_duration_secs(args.duration_secs);
-    builder.add_quiet_end_hour(args.quiet_end_hour);
-    builder.add_quiet_start_hour(args.quiet_start_hour);
-    builder.finish()
-  }
-
+impl flatbuffers::Push for TlsMode {
+    type Output = TlsMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for TlsMode {
+  type Scalar = i8;
   #[inline]
-  pub fn interval_secs(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(SetScheduleRequest::VT_INTERVAL_SECS, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn duration_secs(&self) -> u16 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(SetScheduleRequest::VT_DURATION_SECS, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn quiet_start_hour(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(SetScheduleRequest::VT_QUIET_START_HOUR, Some(255)).unwrap()}
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn quiet_end_hour(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(SetScheduleRequest::VT_QUIET_END_HOUR, Some(255)).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for SetScheduleRequest<'_> {
+impl<'a> flatbuffers::Verifiable for TlsMode {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<u32>("interval_secs", Self::VT_INTERVAL_SECS, false)?
-     .visit_field::<u16>("duration_secs", Self::VT_DURATION_SECS, false)?
-     .visit_field::<u8>("quiet_start_hour", Self::VT_QUIET_START_HOUR, false)?
-     .visit_field::<u8>("quiet_end_hour", Self::VT_QUIET_END_HOUR, false)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct SetScheduleRequestArgs {
-    pub interval_secs: u32,
-    pub duration_secs: u16,
-    pub quiet_start_hour: u8,
-    pub quiet_end_hour: u8,
-}
-impl<'a> Default for SetScheduleRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    SetScheduleRequestArgs {
-      interval_secs: 0,
-      duration_secs: 0,
-      quiet_start_hour: 255,
-      quiet_end_hour: 255,
-    }
+    i8::run_verifier(v, pos)
   }
 }
 
-pub struct SetScheduleRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetScheduleRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_interval_secs(&mut self, interval_secs: u32) {
-    self.fbb_.push_slot::<u32>(SetScheduleRequest::VT_INTERVAL_SECS, interval_secs, 0);
-  }
-  #[inline]
-  pub fn add_duration_secs(&mut self, duration_secs: u16) {
-    self.fbb_.push_slot::<u16>(SetScheduleRequest::VT_DURATION_SECS, duration_secs, 0);
-  }
-  #[inline]
-  pub fn add_quiet_start_hour(&mut self, quiet_start_hour: u8) {
-    self.fbb_.push_slot::<u8>(SetScheduleRequest::VT_QUIET_START_HOUR, quiet_start_hour, 255);
-  }
-  #[inline]
-  pub fn add_quiet_end_hour(&mut self, quiet_end_hour: u8) {
-    self.fbb_.push_slot::<u8>(SetScheduleRequest::VT_QUIET_END_HOUR, quiet_end_hour, 255);
-  }
-  #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetScheduleRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    SetScheduleRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for TlsMode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ACK_ERROR: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ACK_ERROR: i8 = 15;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ACK_ERROR: [AckError; 16] = [
+  AckError::None,
+  AckError::RateLimited,
+  AckError::AuthRequired,
+  AckError::SequenceRejected,
+  AckError::AuthLockedOut,
+  AckError::UnknownCommand,
+  AckError::InvalidArgument,
+  AckError::Rejected,
+  AckError::Busy,
+  AckError::ConfirmationMismatch,
+  AckError::RollbackRejected,
+  AckError::OtaFailed,
+  AckError::StorageFailed,
+  AckError::HardwareFailed,
+  AckError::Overloaded,
+  AckError::Internal,
+];
+
+/// Machine-readable reason an `AckResponse` reports failure.  `None`
+/// accompanies every successful ack; values are append-only.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AckError(pub i8);
+#[allow(non_upper_case_globals)]
+impl AckError {
+  pub const None: Self = Self(0);
+  pub const RateLimited: Self = Self(1);
+  pub const AuthRequired: Self = Self(2);
+  pub const SequenceRejected: Self = Self(3);
+  pub const AuthLockedOut: Self = Self(4);
+  pub const UnknownCommand: Self = Self(5);
+  pub const InvalidArgument: Self = Self(6);
+  pub const Rejected: Self = Self(7);
+  pub const Busy: Self = Self(8);
+  pub const ConfirmationMismatch: Self = Self(9);
+  pub const RollbackRejected: Self = Self(10);
+  pub const OtaFailed: Self = Self(11);
+  pub const StorageFailed: Self = Self(12);
+  pub const HardwareFailed: Self = Self(13);
+  pub const Overloaded: Self = Self(14);
+  pub const Internal: Self = Self(15);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 15;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::None,
+    Self::RateLimited,
+    Self::AuthRequired,
+    Self::SequenceRejected,
+    Self::AuthLockedOut,
+    Self::UnknownCommand,
+    Self::InvalidArgument,
+    Self::Rejected,
+    Self::Busy,
+    Self::ConfirmationMismatch,
+    Self::RollbackRejected,
+    Self::OtaFailed,
+    Self::StorageFailed,
+    Self::HardwareFailed,
+    Self::Overloaded,
+    Self::Internal,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::None => Some("None"),
+      Self::RateLimited => Some("RateLimited"),
+      Self::AuthRequired => Some("AuthRequired"),
+      Self::SequenceRejected => Some("SequenceRejected"),
+      Self::AuthLockedOut => Some("AuthLockedOut"),
+      Self::UnknownCommand => Some("UnknownCommand"),
+      Self::InvalidArgument => Some("InvalidArgument"),
+      Self::Rejected => Some("Rejected"),
+      Self::Busy => Some("Busy"),
+      Self::ConfirmationMismatch => Some("ConfirmationMismatch"),
+      Self::RollbackRejected => Some("RollbackRejected"),
+      Self::OtaFailed => Some("OtaFailed"),
+      Self::StorageFailed => Some("StorageFailed"),
+      Self::HardwareFailed => Some("HardwareFailed"),
+      Self::Overloaded => Some("Overloaded"),
+      Self::Internal => Some("Internal"),
+      _ => None,
     }
   }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SetScheduleRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
 }
-
-impl core::fmt::Debug for SetScheduleRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SetScheduleRequest");
-      ds.field("interval_secs", &self.interval_secs());
-      ds.field("duration_secs", &self.duration_secs());
-      ds.field("quiet_start_hour", &self.quiet_start_hour());
-      ds.field("quiet_end_hour", &self.quiet_end_hour());
-      ds.finish()
+impl core::fmt::Debug for AckError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-pub enum CancelScheduleRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct CancelScheduleRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for CancelScheduleRequest<'a> {
-  type Inner = CancelScheduleRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for AckError {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> CancelScheduleRequest<'a> {
+impl flatbuffers::Push for AckError {
+    type Output = AckError;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for AckError {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    CancelScheduleRequest { _tab: table }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args CancelScheduleRequestArgs
-  ) -> flatbuffers::WIPOffset<CancelScheduleRequest<'bldr>> {
-    let mut builder = CancelScheduleRequestBuilder::new(_fbb);
-    builder.finish()
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
-
 }
 
-impl flatbuffers::Verifiable for CancelScheduleRequest<'_> {
+impl<'a> flatbuffers::Verifiable for AckError {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
+    i8::run_verifier(v, pos)
   }
 }
-pub struct CancelScheduleRequestArgs {
+
+impl flatbuffers::SimpleToVerifyInSlice for AckError {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_AUTO_TUNE_ACTION: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_AUTO_TUNE_ACTION: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_AUTO_TUNE_ACTION: [AutoTuneAction; 4] = [
+  AutoTuneAction::Status,
+  AutoTuneAction::Start,
+  AutoTuneAction::Confirm,
+  AutoTuneAction::Cancel,
+];
+
+/// Step of the pump PID auto-tune; `Status` is the read-only default.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AutoTuneAction(pub i8);
+#[allow(non_upper_case_globals)]
+impl AutoTuneAction {
+  pub const Status: Self = Self(0);
+  pub const Start: Self = Self(1);
+  pub const Confirm: Self = Self(2);
+  pub const Cancel: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Status,
+    Self::Start,
+    Self::Confirm,
+    Self::Cancel,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Status => Some("Status"),
+      Self::Start => Some("Start"),
+      Self::Confirm => Some("Confirm"),
+      Self::Cancel => Some("Cancel"),
+      _ => None,
+    }
+  }
 }
-impl<'a> Default for CancelScheduleRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    CancelScheduleRequestArgs {
+impl core::fmt::Debug for AutoTuneAction {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
 }
+impl<'a> flatbuffers::Follow<'a> for AutoTuneAction {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
 
-pub struct CancelScheduleRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+impl flatbuffers::Push for AutoTuneAction {
+    type Output = AutoTuneAction;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CancelScheduleRequestBuilder<'a, 'b, A> {
+
+impl flatbuffers::EndianScalar for AutoTuneAction {
+  type Scalar = i8;
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CancelScheduleRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    CancelScheduleRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<CancelScheduleRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl core::fmt::Debug for CancelScheduleRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("CancelScheduleRequest");
-      ds.finish()
+impl<'a> flatbuffers::Verifiable for AutoTuneAction {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
   }
 }
-pub enum SubscribeTelemetryRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
 
-pub struct SubscribeTelemetryRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
+impl flatbuffers::SimpleToVerifyInSlice for AutoTuneAction {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_AUTO_TUNE_STATE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_AUTO_TUNE_STATE: i8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_AUTO_TUNE_STATE: [AutoTuneState; 5] = [
+  AutoTuneState::Idle,
+  AutoTuneState::Running,
+  AutoTuneState::Complete,
+  AutoTuneState::TimedOut,
+  AutoTuneState::Aborted,
+];
 
-impl<'a> flatbuffers::Follow<'a> for SubscribeTelemetryRequest<'a> {
-  type Inner = SubscribeTelemetryRequest<'a>;
+/// Progress of the pump PID auto-tune, mirroring
+/// `control::autotune::TuneStatus`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AutoTuneState(pub i8);
+#[allow(non_upper_case_globals)]
+impl AutoTuneState {
+  pub const Idle: Self = Self(0);
+  pub const Running: Self = Self(1);
+  pub const Complete: Self = Self(2);
+  pub const TimedOut: Self = Self(3);
+  pub const Aborted: Self = Self(4);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Idle,
+    Self::Running,
+    Self::Complete,
+    Self::TimedOut,
+    Self::Aborted,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Idle => Some("Idle"),
+      Self::Running => Some("Running"),
+      Self::Complete => Some("Complete"),
+      Self::TimedOut => Some("TimedOut"),
+      Self::Aborted => Some("Aborted"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for AutoTuneState {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for AutoTuneState {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
   }
 }
 
-impl<'a> SubscribeTelemetryRequest<'a> {
-  pub const VT_INTERVAL_MS: flatbuffers::VOffsetT = 4;
+impl flatbuffers::Push for AutoTuneState {
+    type Output = AutoTuneState;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
 
+impl flatbuffers::EndianScalar for AutoTuneState {
+  type Scalar = i8;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SubscribeTelemetryRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SubscribeTelemetryRequestArgs
-  ) -> flatbuffers::WIPOffset<SubscribeTelemetryRequest<'bldr>> {
-    let mut builder = SubscribeTelemetryRequestBuilder::new(_fbb);
-    builder.add_interval_ms(args.interval_ms);
-    builder.finish()
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
   }
-
-
   #[inline]
-  pub fn interval_ms(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(SubscribeTelemetryRequest::VT_INTERVAL_MS, Some(1000)).unwrap()}
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
   }
 }
 
-impl flatbuffers::Verifiable for SubscribeTelemetryRequest<'_> {
+impl<'a> flatbuffers::Verifiable for AutoTuneState {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<u32>("interval_ms", Self::VT_INTERVAL_MS, false)?
-     .finish();
-    Ok(())
+    i8::run_verifier(v, pos)
   }
 }
-pub struct SubscribeTelemetryRequestArgs {
-    pub interval_ms: u32,
+
+impl flatbuffers::SimpleToVerifyInSlice for AutoTuneState {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_PAYLOAD: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_PAYLOAD: u8 = 82;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PAYLOAD: [Payload; 83] = [
+  Payload::NONE,
+  Payload::GetStatusRequest,
+  Payload::StartScrubRequest,
+  Payload::StopScrubRequest,
+  Payload::ClearFaultsRequest,
+  Payload::SetConfigRequest,
+  Payload::SetScheduleRequest,
+  Payload::CancelScheduleRequest,
+  Payload::SubscribeTelemetryRequest,
+  Payload::UnsubscribeTelemetryRequest,
+  Payload::GetDeviceInfoRequest,
+  Payload::StatusResponse,
+  Payload::DeviceInfoResponse,
+  Payload::AckResponse,
+  Payload::TelemetryFrame,
+  Payload::StateChangeEvent,
+  Payload::FaultEvent,
+  Payload::AuthChallengeRequest,
+  Payload::AuthChallengeResponse,
+  Payload::AuthVerifyRequest,
+  Payload::AuthVerifyResponse,
+  Payload::ProvisionCertRequest,
+  Payload::GetCertStatusRequest,
+  Payload::CertStatusResponse,
+  Payload::OtaBeginRequest,
+  Payload::OtaChunkRequest,
+  Payload::OtaFinalizeRequest,
+  Payload::OtaResponse,
+  Payload::OtaProgressEvent,
+  Payload::GetDiagnosticsRequest,
+  Payload::ClearDiagnosticsRequest,
+  Payload::DiagnosticsResponse,
+  Payload::GetScheduleListRequest,
+  Payload::GetScheduleListResponse,
+  Payload::ScanWifiRequest,
+  Payload::ScanWifiResponse,
+  Payload::SetLedBrightnessRequest,
+  Payload::SetQuietHoursRequest,
+  Payload::ClearQuietHoursRequest,
+  Payload::RestartDeviceRequest,
+  Payload::GetConfigRequest,
+  Payload::GetConfigResponse,
+  Payload::DrainWaterRequest,
+  Payload::SetPowerProfileRequest,
+  Payload::SetLedPatternRequest,
+  Payload::ResetLedPatternsRequest,
+  Payload::PingRequest,
+  Payload::PongResponse,
+  Payload::GetAuditLogRequest,
+  Payload::GetAuditLogResponse,
+  Payload::FactoryResetRequest,
+  Payload::GetTelemetryHistoryRequest,
+  Payload::GetTelemetryHistoryResponse,
+  Payload::GetCapabilitiesRequest,
+  Payload::GetCapabilitiesResponse,
+  Payload::SetTimeRequest,
+  Payload::SetMaintenanceModeRequest,
+  Payload::ConfigChangedEvent,
+  Payload::ResetCountersRequest,
+  Payload::InjectSensorRequest,
+  Payload::AutoTuneRequest,
+  Payload::AutoTuneResponse,
+  Payload::OtaStatusRequest,
+  Payload::OtaStatusResponse,
+  Payload::Nh3AlarmEvent,
+  Payload::SetScheduleEnabledRequest,
+  Payload::SetInstallIdRequest,
+  Payload::TestFaultRequest,
+  Payload::AddClientCertPinRequest,
+  Payload::RemoveClientCertPinRequest,
+  Payload::ResetServiceFlowRequest,
+  Payload::SessionMigrationTokenRequest,
+  Payload::SessionMigrationTokenResponse,
+  Payload::MigrateSessionRequest,
+  Payload::SetStateTimeoutRequest,
+  Payload::OtaAbortRequest,
+  Payload::SetTankSourceRequest,
+  Payload::SetPskGraceRequest,
+  Payload::SetJitterCompensationRequest,
+  Payload::SetAuthLockoutRequest,
+  Payload::SetWifiRoamRequest,
+  Payload::SetTcpIdleTimeoutRequest,
+  Payload::SetPingAuthRequest,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Payload(pub u8);
+#[allow(non_upper_case_globals)]
+impl Payload {
+  pub const NONE: Self = Self(0);
+  pub const GetStatusRequest: Self = Self(1);
+  pub const StartScrubRequest: Self = Self(2);
+  pub const StopScrubRequest: Self = Self(3);
+  pub const ClearFaultsRequest: Self = Self(4);
+  pub const SetConfigRequest: Self = Self(5);
+  pub const SetScheduleRequest: Self = Self(6);
+  pub const CancelScheduleRequest: Self = Self(7);
+  pub const SubscribeTelemetryRequest: Self = Self(8);
+  pub const UnsubscribeTelemetryRequest: Self = Self(9);
+  pub const GetDeviceInfoRequest: Self = Self(10);
+  pub const StatusResponse: Self = Self(11);
+  pub const DeviceInfoResponse: Self = Self(12);
+  pub const AckResponse: Self = Self(13);
+  pub const TelemetryFrame: Self = Self(14);
+  pub const StateChangeEvent: Self = Self(15);
+  pub const FaultEvent: Self = Self(16);
+  pub const AuthChallengeRequest: Self = Self(17);
+  pub const AuthChallengeResponse: Self = Self(18);
+  pub const AuthVerifyRequest: Self = Self(19);
+  pub const AuthVerifyResponse: Self = Self(20);
+  pub const ProvisionCertRequest: Self = Self(21);
+  pub const GetCertStatusRequest: Self = Self(22);
+  pub const CertStatusResponse: Self = Self(23);
+  pub const OtaBeginRequest: Self = Self(24);
+  pub const OtaChunkRequest: Self = Self(25);
+  pub const OtaFinalizeRequest: Self = Self(26);
+  pub const OtaResponse: Self = Self(27);
+  pub const OtaProgressEvent: Self = Self(28);
+  pub const GetDiagnosticsRequest: Self = Self(29);
+  pub const ClearDiagnosticsRequest: Self = Self(30);
+  pub const DiagnosticsResponse: Self = Self(31);
+  pub const GetScheduleListRequest: Self = Self(32);
+  pub const GetScheduleListResponse: Self = Self(33);
+  pub const ScanWifiRequest: Self = Self(34);
+  pub const ScanWifiResponse: Self = Self(35);
+  pub const SetLedBrightnessRequest: Self = Self(36);
+  pub const SetQuietHoursRequest: Self = Self(37);
+  pub const ClearQuietHoursRequest: Self = Self(38);
+  pub const RestartDeviceRequest: Self = Self(39);
+  pub const GetConfigRequest: Self = Self(40);
+  pub const GetConfigResponse: Self = Self(41);
+  pub const DrainWaterRequest: Self = Self(42);
+  pub const SetPowerProfileRequest: Self = Self(43);
+  pub const SetLedPatternRequest: Self = Self(44);
+  pub const ResetLedPatternsRequest: Self = Self(45);
+  pub const PingRequest: Self = Self(46);
+  pub const PongResponse: Self = Self(47);
+  pub const GetAuditLogRequest: Self = Self(48);
+  pub const GetAuditLogResponse: Self = Self(49);
+  pub const FactoryResetRequest: Self = Self(50);
+  pub const GetTelemetryHistoryRequest: Self = Self(51);
+  pub const GetTelemetryHistoryResponse: Self = Self(52);
+  pub const GetCapabilitiesRequest: Self = Self(53);
+  pub const GetCapabilitiesResponse: Self = Self(54);
+  pub const SetTimeRequest: Self = Self(55);
+  pub const SetMaintenanceModeRequest: Self = Self(56);
+  pub const ConfigChangedEvent: Self = Self(57);
+  pub const ResetCountersRequest: Self = Self(58);
+  pub const InjectSensorRequest: Self = Self(59);
+  pub const AutoTuneRequest: Self = Self(60);
+  pub const AutoTuneResponse: Self = Self(61);
+  pub const OtaStatusRequest: Self = Self(62);
+  pub const OtaStatusResponse: Self = Self(63);
+  pub const Nh3AlarmEvent: Self = Self(64);
+  pub const SetScheduleEnabledRequest: Self = Self(65);
+  pub const SetInstallIdRequest: Self = Self(66);
+  pub const TestFaultRequest: Self = Self(67);
+  pub const AddClientCertPinRequest: Self = Self(68);
+  pub const RemoveClientCertPinRequest: Self = Self(69);
+  pub const ResetServiceFlowRequest: Self = Self(70);
+  pub const SessionMigrationTokenRequest: Self = Self(71);
+  pub const SessionMigrationTokenResponse: Self = Self(72);
+  pub const MigrateSessionRequest: Self = Self(73);
+  pub const SetStateTimeoutRequest: Self = Self(74);
+  pub const OtaAbortRequest: Self = Self(75);
+  pub const SetTankSourceRequest: Self = Self(76);
+  pub const SetPskGraceRequest: Self = Self(77);
+  pub const SetJitterCompensationRequest: Self = Self(78);
+  pub const SetAuthLockoutRequest: Self = Self(79);
+  pub const SetWifiRoamRequest: Self = Self(80);
+  pub const SetTcpIdleTimeoutRequest: Self = Self(81);
+  pub const SetPingAuthRequest: Self = Self(82);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 82;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::GetStatusRequest,
+    Self::StartScrubRequest,
+    Self::StopScrubRequest,
+    Self::ClearFaultsRequest,
+    Self::SetConfigRequest,
+    Self::SetScheduleRequest,
+    Self::CancelScheduleRequest,
+    Self::SubscribeTelemetryRequest,
+    Self::UnsubscribeTelemetryRequest,
+    Self::GetDeviceInfoRequest,
+    Self::StatusResponse,
+    Self::DeviceInfoResponse,
+    Self::AckResponse,
+    Self::TelemetryFrame,
+    Self::StateChangeEvent,
+    Self::FaultEvent,
+    Self::AuthChallengeRequest,
+    Self::AuthChallengeResponse,
+    Self::AuthVerifyRequest,
+    Self::AuthVerifyResponse,
+    Self::ProvisionCertRequest,
+    Self::GetCertStatusRequest,
+    Self::CertStatusResponse,
+    Self::OtaBeginRequest,
+    Self::OtaChunkRequest,
+    Self::OtaFinalizeRequest,
+    Self::OtaResponse,
+    Self::OtaProgressEvent,
+    Self::GetDiagnosticsRequest,
+    Self::ClearDiagnosticsRequest,
+    Self::DiagnosticsResponse,
+    Self::GetScheduleListRequest,
+    Self::GetScheduleListResponse,
+    Self::ScanWifiRequest,
+    Self::ScanWifiResponse,
+    Self::SetLedBrightnessRequest,
+    Self::SetQuietHoursRequest,
+    Self::ClearQuietHoursRequest,
+    Self::RestartDeviceRequest,
+    Self::GetConfigRequest,
+    Self::GetConfigResponse,
+    Self::DrainWaterRequest,
+    Self::SetPowerProfileRequest,
+    Self::SetLedPatternRequest,
+    Self::ResetLedPatternsRequest,
+    Self::PingRequest,
+    Self::PongResponse,
+    Self::GetAuditLogRequest,
+    Self::GetAuditLogResponse,
+    Self::FactoryResetRequest,
+    Self::GetTelemetryHistoryRequest,
+    Self::GetTelemetryHistoryResponse,
+    Self::GetCapabilitiesRequest,
+    Self::GetCapabilitiesResponse,
+    Self::SetTimeRequest,
+    Self::SetMaintenanceModeRequest,
+    Self::ConfigChangedEvent,
+    Self::ResetCountersRequest,
+    Self::InjectSensorRequest,
+    Self::AutoTuneRequest,
+    Self::AutoTuneResponse,
+    Self::OtaStatusRequest,
+    Self::OtaStatusResponse,
+    Self::Nh3AlarmEvent,
+    Self::SetScheduleEnabledRequest,
+    Self::SetInstallIdRequest,
+    Self::TestFaultRequest,
+    Self::AddClientCertPinRequest,
+    Self::RemoveClientCertPinRequest,
+    Self::ResetServiceFlowRequest,
+    Self::SessionMigrationTokenRequest,
+    Self::SessionMigrationTokenResponse,
+    Self::MigrateSessionRequest,
+    Self::SetStateTimeoutRequest,
+    Self::OtaAbortRequest,
+    Self::SetTankSourceRequest,
+    Self::SetPskGraceRequest,
+    Self::SetJitterCompensationRequest,
+    Self::SetAuthLockoutRequest,
+    Self::SetWifiRoamRequest,
+    Self::SetTcpIdleTimeoutRequest,
+    Self::SetPingAuthRequest,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::GetStatusRequest => Some("GetStatusRequest"),
+      Self::StartScrubRequest => Some("StartScrubRequest"),
+      Self::StopScrubRequest => Some("StopScrubRequest"),
+      Self::ClearFaultsRequest => Some("ClearFaultsRequest"),
+      Self::SetConfigRequest => Some("SetConfigRequest"),
+      Self::SetScheduleRequest => Some("SetScheduleRequest"),
+      Self::CancelScheduleRequest => Some("CancelScheduleRequest"),
+      Self::SubscribeTelemetryRequest => Some("SubscribeTelemetryRequest"),
+      Self::UnsubscribeTelemetryRequest => Some("UnsubscribeTelemetryRequest"),
+      Self::GetDeviceInfoRequest => Some("GetDeviceInfoRequest"),
+      Self::StatusResponse => Some("StatusResponse"),
+      Self::DeviceInfoResponse => Some("DeviceInfoResponse"),
+      Self::AckResponse => Some("AckResponse"),
+      Self::TelemetryFrame => Some("TelemetryFrame"),
+      Self::StateChangeEvent => Some("StateChangeEvent"),
+      Self::FaultEvent => Some("FaultEvent"),
+      Self::AuthChallengeRequest => Some("AuthChallengeRequest"),
+      Self::AuthChallengeResponse => Some("AuthChallengeResponse"),
+      Self::AuthVerifyRequest => Some("AuthVerifyRequest"),
+      Self::AuthVerifyResponse => Some("AuthVerifyResponse"),
+      Self::ProvisionCertRequest => Some("ProvisionCertRequest"),
+      Self::GetCertStatusRequest => Some("GetCertStatusRequest"),
+      Self::CertStatusResponse => Some("CertStatusResponse"),
+      Self::OtaBeginRequest => Some("OtaBeginRequest"),
+      Self::OtaChunkRequest => Some("OtaChunkRequest"),
+      Self::OtaFinalizeRequest => Some("OtaFinalizeRequest"),
+      Self::OtaResponse => Some("OtaResponse"),
+      Self::OtaProgressEvent => Some("OtaProgressEvent"),
+      Self::GetDiagnosticsRequest => Some("GetDiagnosticsRequest"),
+      Self::ClearDiagnosticsRequest => Some("ClearDiagnosticsRequest"),
+      Self::DiagnosticsResponse => Some("DiagnosticsResponse"),
+      Self::GetScheduleListRequest => Some("GetScheduleListRequest"),
+      Self::GetScheduleListResponse => Some("GetScheduleListResponse"),
+      Self::ScanWifiRequest => Some("ScanWifiRequest"),
+      Self::ScanWifiResponse => Some("ScanWifiResponse"),
+      Self::SetLedBrightnessRequest => Some("SetLedBrightnessRequest"),
+      Self::SetQuietHoursRequest => Some("SetQuietHoursRequest"),
+      Self::ClearQuietHoursRequest => Some("ClearQuietHoursRequest"),
+      Self::RestartDeviceRequest => Some("RestartDeviceRequest"),
+      Self::GetConfigRequest => Some("GetConfigRequest"),
+      Self::GetConfigResponse => Some("GetConfigResponse"),
+      Self::DrainWaterRequest => Some("DrainWaterRequest"),
+      Self::SetPowerProfileRequest => Some("SetPowerProfileRequest"),
+      Self::SetLedPatternRequest => Some("SetLedPatternRequest"),
+      Self::ResetLedPatternsRequest => Some("ResetLedPatternsRequest"),
+      Self::PingRequest => Some("PingRequest"),
+      Self::PongResponse => Some("PongResponse"),
+      Self::GetAuditLogRequest => Some("GetAuditLogRequest"),
+      Self::GetAuditLogResponse => Some("GetAuditLogResponse"),
+      Self::FactoryResetRequest => Some("FactoryResetRequest"),
+      Self::GetTelemetryHistoryRequest => Some("GetTelemetryHistoryRequest"),
+      Self::GetTelemetryHistoryResponse => Some("GetTelemetryHistoryResponse"),
+      Self::GetCapabilitiesRequest => Some("GetCapabilitiesRequest"),
+      Self::GetCapabilitiesResponse => Some("GetCapabilitiesResponse"),
+      Self::SetTimeRequest => Some("SetTimeRequest"),
+      Self::SetMaintenanceModeRequest => Some("SetMaintenanceModeRequest"),
+      Self::ConfigChangedEvent => Some("ConfigChangedEvent"),
+      Self::ResetCountersRequest => Some("ResetCountersRequest"),
+      Self::InjectSensorRequest => Some("InjectSensorRequest"),
+      Self::AutoTuneRequest => Some("AutoTuneRequest"),
+      Self::AutoTuneResponse => Some("AutoTuneResponse"),
+      Self::OtaStatusRequest => Some("OtaStatusRequest"),
+      Self::OtaStatusResponse => Some("OtaStatusResponse"),
+      Self::Nh3AlarmEvent => Some("Nh3AlarmEvent"),
+      Self::SetScheduleEnabledRequest => Some("SetScheduleEnabledRequest"),
+      Self::SetInstallIdRequest => Some("SetInstallIdRequest"),
+      Self::TestFaultRequest => Some("TestFaultRequest"),
+      Self::AddClientCertPinRequest => Some("AddClientCertPinRequest"),
+      Self::RemoveClientCertPinRequest => Some("RemoveClientCertPinRequest"),
+      Self::ResetServiceFlowRequest => Some("ResetServiceFlowRequest"),
+      Self::SessionMigrationTokenRequest => Some("SessionMigrationTokenRequest"),
+      Self::SessionMigrationTokenResponse => Some("SessionMigrationTokenResponse"),
+      Self::MigrateSessionRequest => Some("MigrateSessionRequest"),
+      Self::SetStateTimeoutRequest => Some("SetStateTimeoutRequest"),
+      Self::OtaAbortRequest => Some("OtaAbortRequest"),
+      Self::SetTankSourceRequest => Some("SetTankSourceRequest"),
+      Self::SetPskGraceRequest => Some("SetPskGraceRequest"),
+      Self::SetJitterCompensationRequest => Some("SetJitterCompensationRequest"),
+      Self::SetAuthLockoutRequest => Some("SetAuthLockoutRequest"),
+      Self::SetWifiRoamRequest => Some("SetWifiRoamRequest"),
+      Self::SetTcpIdleTimeoutRequest => Some("SetTcpIdleTimeoutRequest"),
+      Self::SetPingAuthRequest => Some("SetPingAuthRequest"),
+      _ => None,
+    }
+  }
 }
-impl<'a> Default for SubscribeTelemetryRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    SubscribeTelemetryRequestArgs {
-      interval_ms: 1000,
+impl core::fmt::Debug for Payload {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
 }
+impl<'a> flatbuffers::Follow<'a> for Payload {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
 
-pub struct SubscribeTelemetryRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+impl flatbuffers::Push for Payload {
+    type Output = Payload;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SubscribeTelemetryRequestBuilder<'a, 'b, A> {
+
+impl flatbuffers::EndianScalar for Payload {
+  type Scalar = u8;
   #[inline]
-  pub fn add_interval_ms(&mut self, interval_ms: u32) {
-    self.fbb_.push_slot::<u32>(SubscribeTelemetryRequest::VT_INTERVAL_MS, interval_ms, 1000);
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SubscribeTelemetryRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    SubscribeTelemetryRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
   }
+}
+
+impl<'a> flatbuffers::Verifiable for Payload {
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SubscribeTelemetryRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
   }
 }
 
-impl core::fmt::Debug for SubscribeTelemetryRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SubscribeTelemetryRequest");
-      ds.field("interval_ms", &self.interval_ms());
-      ds.finish()
+impl flatbuffers::SimpleToVerifyInSlice for Payload {}
+pub struct PayloadUnionTableOffset {}
+
+/// One history point.  A struct so a full page fits one frame.
+// struct TelemetrySampleFbs, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct TelemetrySampleFbs(pub [u8; 24]);
+impl Default for TelemetrySampleFbs { 
+  fn default() -> Self { 
+    Self([0; 24])
   }
 }
-pub enum UnsubscribeTelemetryRequestOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct UnsubscribeTelemetryRequest<'a> {
-  pub _tab: flatbuffers::Table<'a>,
+impl core::fmt::Debug for TelemetrySampleFbs {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("TelemetrySampleFbs")
+      .field("uptime_secs", &self.uptime_secs())
+      .field("nh3_ppm", &self.nh3_ppm())
+      .field("nh3_avg_ppm", &self.nh3_avg_ppm())
+      .field("flow_ml_per_min", &self.flow_ml_per_min())
+      .field("temperature_c", &self.temperature_c())
+      .field("state", &self.state())
+      .field("pump_duty", &self.pump_duty())
+      .field("uvc_duty", &self.uvc_duty())
+      .field("fault_flags", &self.fault_flags())
+      .finish()
+  }
 }
 
-impl<'a> flatbuffers::Follow<'a> for UnsubscribeTelemetryRequest<'a> {
-  type Inner = UnsubscribeTelemetryRequest<'a>;
+impl flatbuffers::SimpleToVerifyInSlice for TelemetrySampleFbs {}
+impl<'a> flatbuffers::Follow<'a> for TelemetrySampleFbs {
+  type Inner = &'a TelemetrySampleFbs;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+    <&'a TelemetrySampleFbs>::follow(buf, loc)
   }
 }
-
-impl<'a> UnsubscribeTelemetryRequest<'a> {
-
+impl<'a> flatbuffers::Follow<'a> for &'a TelemetrySampleFbs {
+  type Inner = &'a TelemetrySampleFbs;
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    UnsubscribeTelemetryRequest { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args UnsubscribeTelemetryRequestArgs
-  ) -> flatbuffers::WIPOffset<UnsubscribeTelemetryRequest<'bldr>> {
-    let mut builder = UnsubscribeTelemetryRequestBuilder::new(_fbb);
-    builder.finish()
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<TelemetrySampleFbs>(buf, loc)
   }
-
+}
+impl<'b> flatbuffers::Push for TelemetrySampleFbs {
+    type Output = TelemetrySampleFbs;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const TelemetrySampleFbs as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
 }
 
-impl flatbuffers::Verifiable for UnsubscribeTelemetryRequest<'_> {
+impl<'a> flatbuffers::Verifiable for TelemetrySampleFbs {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .finish();
-    Ok(())
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> TelemetrySampleFbs {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    uptime_secs: u32,
+    nh3_ppm: f32,
+    nh3_avg_ppm: f32,
+    flow_ml_per_min: f32,
+    temperature_c: f32,
+    state: DeviceState,
+    pump_duty: u8,
+    uvc_duty: u8,
+    fault_flags: u8,
+  ) -> Self {
+    let mut s = Self([0; 24]);
+    s.set_uptime_secs(uptime_secs);
+    s.set_nh3_ppm(nh3_ppm);
+    s.set_nh3_avg_ppm(nh3_avg_ppm);
+    s.set_flow_ml_per_min(flow_ml_per_min);
+    s.set_temperature_c(temperature_c);
+    s.set_state(state);
+    s.set_pump_duty(pump_duty);
+    s.set_uvc_duty(uvc_duty);
+    s.set_fault_flags(fault_flags);
+    s
+  }
+
+  pub fn uptime_secs(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_uptime_secs(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
   }
-}
-pub struct UnsubscribeTelemetryRequestArgs {
-}
-impl<'a> Default for UnsubscribeTelemetryRequestArgs {
-  #[inline]
-  fn default() -> Self {
-    UnsubscribeTelemetryRequestArgs {
+
+  pub fn nh3_ppm(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_nh3_ppm(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn nh3_avg_ppm(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_nh3_avg_ppm(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn flow_ml_per_min(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_flow_ml_per_min(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn temperature_c(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_temperature_c(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn state(&self) -> DeviceState {
+    let mut mem = core::mem::MaybeUninit::<<DeviceState as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[20..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<DeviceState as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_state(&mut self, x: DeviceState) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[20..].as_mut_ptr(),
+        core::mem::size_of::<<DeviceState as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn pump_duty(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[21..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_pump_duty(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[21..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn uvc_duty(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[22..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_uvc_duty(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[22..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn fault_flags(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[23..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_fault_flags(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[23..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
     }
   }
+
 }
 
-pub struct UnsubscribeTelemetryRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+/// One authenticated command.  A struct rather than a table so a full
+/// 32-entry ring fits in a single response frame.
+// struct AuditEntryFbs, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct AuditEntryFbs(pub [u8; 8]);
+impl Default for AuditEntryFbs { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UnsubscribeTelemetryRequestBuilder<'a, 'b, A> {
+impl core::fmt::Debug for AuditEntryFbs {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("AuditEntryFbs")
+      .field("uptime_secs", &self.uptime_secs())
+      .field("client_id", &self.client_id())
+      .field("payload_type", &self.payload_type())
+      .field("success", &self.success())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AuditEntryFbs {}
+impl<'a> flatbuffers::Follow<'a> for AuditEntryFbs {
+  type Inner = &'a AuditEntryFbs;
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> UnsubscribeTelemetryRequestBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    UnsubscribeTelemetryRequestBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a AuditEntryFbs>::follow(buf, loc)
   }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a AuditEntryFbs {
+  type Inner = &'a AuditEntryFbs;
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<UnsubscribeTelemetryRequest<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<AuditEntryFbs>(buf, loc)
   }
 }
+impl<'b> flatbuffers::Push for AuditEntryFbs {
+    type Output = AuditEntryFbs;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const AuditEntryFbs as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
 
-impl core::fmt::Debug for UnsubscribeTelemetryRequest<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("UnsubscribeTelemetryRequest");
-      ds.finish()
+impl<'a> flatbuffers::Verifiable for AuditEntryFbs {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
   }
 }
-pub enum GetDeviceInfoRequestOffset {}
+
+impl<'a> AuditEntryFbs {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    uptime_secs: u32,
+    client_id: u8,
+    payload_type: u8,
+    success: bool,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_uptime_secs(uptime_secs);
+    s.set_client_id(client_id);
+    s.set_payload_type(payload_type);
+    s.set_success(success);
+    s
+  }
+
+  pub fn uptime_secs(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_uptime_secs(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn client_id(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_client_id(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  /// `Payload` discriminant of the request.
+  pub fn payload_type(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[5..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_payload_type(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[5..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn success(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[6..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_success(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[6..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum GetStatusRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GetDeviceInfoRequest<'a> {
+pub struct GetStatusRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GetDeviceInfoRequest<'a> {
-  type Inner = GetDeviceInfoRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for GetStatusRequest<'a> {
+  type Inner = GetStatusRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> GetDeviceInfoRequest<'a> {
+impl<'a> GetStatusRequest<'a> {
+  pub const VT_ZONE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GetDeviceInfoRequest { _tab: table }
+    GetStatusRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args GetDeviceInfoRequestArgs
-  ) -> flatbuffers::WIPOffset<GetDeviceInfoRequest<'bldr>> {
-    let mut builder = GetDeviceInfoRequestBuilder::new(_fbb);
+    args: &'args GetStatusRequestArgs
+  ) -> flatbuffers::WIPOffset<GetStatusRequest<'bldr>> {
+    let mut builder = GetStatusRequestBuilder::new(_fbb);
+    builder.add_zone(args.zone);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetStatusRequest::VT_ZONE, Some(0)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for GetDeviceInfoRequest<'_> {
+impl flatbuffers::Verifiable for GetStatusRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GetDeviceInfoRequestArgs {
+pub struct GetStatusRequestArgs {
+    pub zone: u8,
 }
-impl<'a> Default for GetDeviceInfoRequestArgs {
+impl<'a> Default for GetStatusRequestArgs {
   #[inline]
   fn default() -> Self {
-    GetDeviceInfoRequestArgs {
+    GetStatusRequestArgs {
+      zone: 0,
     }
   }
 }
 
-pub struct GetDeviceInfoRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GetStatusRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetDeviceInfoRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetStatusRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetDeviceInfoRequestBuilder<'a, 'b, A> {
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(GetStatusRequest::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetStatusRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GetDeviceInfoRequestBuilder {
+    GetStatusRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GetDeviceInfoRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetStatusRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for GetDeviceInfoRequest<'_> {
+impl core::fmt::Debug for GetStatusRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GetDeviceInfoRequest");
+    let mut ds = f.debug_struct("GetStatusRequest");
+      ds.field("zone", &self.zone());
       ds.finish()
   }
 }
-pub enum StatusResponseOffset {}
+pub enum StartScrubRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusResponse<'a> {
+pub struct StartScrubRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusResponse<'a> {
-  type Inner = StatusResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for StartScrubRequest<'a> {
+  type Inner = StartScrubRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusResponse<'a> {
-  pub const VT_STATE: flatbuffers::VOffsetT = 4;
-  pub const VT_NH3_PPM: flatbuffers::VOffsetT = 6;
-  pub const VT_NH3_AVG_PPM: flatbuffers::VOffsetT = 8;
-  pub const VT_FLOW_ML_PER_MIN: flatbuffers::VOffsetT = 10;
-  pub const VT_TEMPERATURE_C: flatbuffers::VOffsetT = 12;
-  pub const VT_TANK_A_OK: flatbuffers::VOffsetT = 14;
-  pub const VT_TANK_B_OK: flatbuffers::VOffsetT = 16;
-  pub const VT_PUMP_DUTY: flatbuffers::VOffsetT = 18;
-  pub const VT_UVC_DUTY: flatbuffers::VOffsetT = 20;
-  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 22;
-  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 24;
+impl<'a> StartScrubRequest<'a> {
+  pub const VT_ZONE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusResponse { _tab: table }
+    StartScrubRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusResponseArgs
-  ) -> flatbuffers::WIPOffset<StatusResponse<'bldr>> {
-    let mut builder = StatusResponseBuilder::new(_fbb);
-    builder.add_uptime_secs(args.uptime_secs);
-    builder.add_temperature_c(args.temperature_c);
-    builder.add_flow_ml_per_min(args.flow_ml_per_min);
-    builder.add_nh3_avg_ppm(args.nh3_avg_ppm);
-    builder.add_nh3_ppm(args.nh3_ppm);
-    builder.add_fault_flags(args.fault_flags);
-    builder.add_uvc_duty(args.uvc_duty);
-    builder.add_pump_duty(args.pump_duty);
-    builder.add_tank_b_ok(args.tank_b_ok);
-    builder.add_tank_a_ok(args.tank_a_ok);
-    builder.add_state(args.state);
+    args: &'args StartScrubRequestArgs
+  ) -> flatbuffers::WIPOffset<StartScrubRequest<'bldr>> {
+    let mut builder = StartScrubRequestBuilder::new(_fbb);
+    builder.add_zone(args.zone);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn state(&self) -> DeviceState {
+  pub fn zone(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<DeviceState>(StatusResponse::VT_STATE, Some(DeviceState::Idle)).unwrap()}
+    unsafe { self._tab.get::<u8>(StartScrubRequest::VT_ZONE, Some(0)).unwrap()}
   }
+}
+
+impl flatbuffers::Verifiable for StartScrubRequest<'_> {
   #[inline]
-  pub fn nh3_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(StatusResponse::VT_NH3_PPM, Some(0.0)).unwrap()}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct StartScrubRequestArgs {
+    pub zone: u8,
+}
+impl<'a> Default for StartScrubRequestArgs {
   #[inline]
-  pub fn nh3_avg_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(StatusResponse::VT_NH3_AVG_PPM, Some(0.0)).unwrap()}
+  fn default() -> Self {
+    StartScrubRequestArgs {
+      zone: 0,
+    }
   }
+}
+
+pub struct StartScrubRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StartScrubRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn flow_ml_per_min(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(StatusResponse::VT_FLOW_ML_PER_MIN, Some(0.0)).unwrap()}
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(StartScrubRequest::VT_ZONE, zone, 0);
   }
   #[inline]
-  pub fn temperature_c(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(StatusResponse::VT_TEMPERATURE_C, Some(0.0)).unwrap()}
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StartScrubRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StartScrubRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
   }
   #[inline]
-  pub fn tank_a_ok(&self) -> bool {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(StatusResponse::VT_TANK_A_OK, Some(false)).unwrap()}
+  pub fn finish(self) -> flatbuffers::WIPOffset<StartScrubRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
-  #[inline]
-  pub fn tank_b_ok(&self) -> bool {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(StatusResponse::VT_TANK_B_OK, Some(false)).unwrap()}
+}
+
+impl core::fmt::Debug for StartScrubRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StartScrubRequest");
+      ds.field("zone", &self.zone());
+      ds.finish()
   }
+}
+pub enum StopScrubRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StopScrubRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StopScrubRequest<'a> {
+  type Inner = StopScrubRequest<'a>;
   #[inline]
-  pub fn pump_duty(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(StatusResponse::VT_PUMP_DUTY, Some(0)).unwrap()}
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
+}
+
+impl<'a> StopScrubRequest<'a> {
+  pub const VT_ZONE: flatbuffers::VOffsetT = 4;
+
   #[inline]
-  pub fn uvc_duty(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(StatusResponse::VT_UVC_DUTY, Some(0)).unwrap()}
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StopScrubRequest { _tab: table }
   }
-  #[inline]
-  pub fn fault_flags(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(StatusResponse::VT_FAULT_FLAGS, Some(0)).unwrap()}
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StopScrubRequestArgs
+  ) -> flatbuffers::WIPOffset<StopScrubRequest<'bldr>> {
+    let mut builder = StopScrubRequestBuilder::new(_fbb);
+    builder.add_zone(args.zone);
+    builder.finish()
   }
+
+
   #[inline]
-  pub fn uptime_secs(&self) -> u64 {
+  pub fn zone(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(StatusResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(StopScrubRequest::VT_ZONE, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for StatusResponse<'_> {
+impl flatbuffers::Verifiable for StopScrubRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
-     .visit_field::<f32>("nh3_ppm", Self::VT_NH3_PPM, false)?
-     .visit_field::<f32>("nh3_avg_ppm", Self::VT_NH3_AVG_PPM, false)?
-     .visit_field::<f32>("flow_ml_per_min", Self::VT_FLOW_ML_PER_MIN, false)?
-     .visit_field::<f32>("temperature_c", Self::VT_TEMPERATURE_C, false)?
-     .visit_field::<bool>("tank_a_ok", Self::VT_TANK_A_OK, false)?
-     .visit_field::<bool>("tank_b_ok", Self::VT_TANK_B_OK, false)?
-     .visit_field::<u8>("pump_duty", Self::VT_PUMP_DUTY, false)?
-     .visit_field::<u8>("uvc_duty", Self::VT_UVC_DUTY, false)?
-     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
-     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusResponseArgs {
-    pub state: DeviceState,
-    pub nh3_ppm: f32,
-    pub nh3_avg_ppm: f32,
-    pub flow_ml_per_min: f32,
-    pub temperature_c: f32,
-    pub tank_a_ok: bool,
-    pub tank_b_ok: bool,
-    pub pump_duty: u8,
-    pub uvc_duty: u8,
-    pub fault_flags: u8,
-    pub uptime_secs: u64,
+pub struct StopScrubRequestArgs {
+    pub zone: u8,
 }
-impl<'a> Default for StatusResponseArgs {
+impl<'a> Default for StopScrubRequestArgs {
   #[inline]
   fn default() -> Self {
-    StatusResponseArgs {
-      state: DeviceState::Idle,
-      nh3_ppm: 0.0,
-      nh3_avg_ppm: 0.0,
-      flow_ml_per_min: 0.0,
-      temperature_c: 0.0,
-      tank_a_ok: false,
-      tank_b_ok: false,
-      pump_duty: 0,
-      uvc_duty: 0,
-      fault_flags: 0,
-      uptime_secs: 0,
+    StopScrubRequestArgs {
+      zone: 0,
     }
   }
 }
 
-pub struct StatusResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct StopScrubRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_state(&mut self, state: DeviceState) {
-    self.fbb_.push_slot::<DeviceState>(StatusResponse::VT_STATE, state, DeviceState::Idle);
-  }
-  #[inline]
-  pub fn add_nh3_ppm(&mut self, nh3_ppm: f32) {
-    self.fbb_.push_slot::<f32>(StatusResponse::VT_NH3_PPM, nh3_ppm, 0.0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StopScrubRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_nh3_avg_ppm(&mut self, nh3_avg_ppm: f32) {
-    self.fbb_.push_slot::<f32>(StatusResponse::VT_NH3_AVG_PPM, nh3_avg_ppm, 0.0);
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(StopScrubRequest::VT_ZONE, zone, 0);
   }
   #[inline]
-  pub fn add_flow_ml_per_min(&mut self, flow_ml_per_min: f32) {
-    self.fbb_.push_slot::<f32>(StatusResponse::VT_FLOW_ML_PER_MIN, flow_ml_per_min, 0.0);
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StopScrubRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StopScrubRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
   }
   #[inline]
-  pub fn add_temperature_c(&mut self, temperature_c: f32) {
-    self.fbb_.push_slot::<f32>(StatusResponse::VT_TEMPERATURE_C, temperature_c, 0.0);
+  pub fn finish(self) -> flatbuffers::WIPOffset<StopScrubRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
-  #[inline]
-  pub fn add_tank_a_ok(&mut self, tank_a_ok: bool) {
-    self.fbb_.push_slot::<bool>(StatusResponse::VT_TANK_A_OK, tank_a_ok, false);
+}
+
+impl core::fmt::Debug for StopScrubRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StopScrubRequest");
+      ds.field("zone", &self.zone());
+      ds.finish()
   }
+}
+pub enum ClearFaultsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ClearFaultsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ClearFaultsRequest<'a> {
+  type Inner = ClearFaultsRequest<'a>;
   #[inline]
-  pub fn add_tank_b_ok(&mut self, tank_b_ok: bool) {
-    self.fbb_.push_slot::<bool>(StatusResponse::VT_TANK_B_OK, tank_b_ok, false);
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
+}
+
+impl<'a> ClearFaultsRequest<'a> {
+
   #[inline]
-  pub fn add_pump_duty(&mut self, pump_duty: u8) {
-    self.fbb_.push_slot::<u8>(StatusResponse::VT_PUMP_DUTY, pump_duty, 0);
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ClearFaultsRequest { _tab: table }
   }
-  #[inline]
-  pub fn add_uvc_duty(&mut self, uvc_duty: u8) {
-    self.fbb_.push_slot::<u8>(StatusResponse::VT_UVC_DUTY, uvc_duty, 0);
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ClearFaultsRequestArgs
+  ) -> flatbuffers::WIPOffset<ClearFaultsRequest<'bldr>> {
+    let mut builder = ClearFaultsRequestBuilder::new(_fbb);
+    builder.finish()
   }
+
+}
+
+impl flatbuffers::Verifiable for ClearFaultsRequest<'_> {
   #[inline]
-  pub fn add_fault_flags(&mut self, fault_flags: u8) {
-    self.fbb_.push_slot::<u8>(StatusResponse::VT_FAULT_FLAGS, fault_flags, 0);
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
   }
+}
+pub struct ClearFaultsRequestArgs {
+}
+impl<'a> Default for ClearFaultsRequestArgs {
   #[inline]
-  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
-    self.fbb_.push_slot::<u64>(StatusResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  fn default() -> Self {
+    ClearFaultsRequestArgs {
+    }
   }
+}
+
+pub struct ClearFaultsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClearFaultsRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClearFaultsRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusResponseBuilder {
+    ClearFaultsRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClearFaultsRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusResponse<'_> {
+impl core::fmt::Debug for ClearFaultsRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusResponse");
-      ds.field("state", &self.state());
-      ds.field("nh3_ppm", &self.nh3_ppm());
-      ds.field("nh3_avg_ppm", &self.nh3_avg_ppm());
-      ds.field("flow_ml_per_min", &self.flow_ml_per_min());
-      ds.field("temperature_c", &self.temperature_c());
-      ds.field("tank_a_ok", &self.tank_a_ok());
-      ds.field("tank_b_ok", &self.tank_b_ok());
-      ds.field("pump_duty", &self.pump_duty());
-      ds.field("uvc_duty", &self.uvc_duty());
-      ds.field("fault_flags", &self.fault_flags());
-      ds.field("uptime_secs", &self.uptime_secs());
+    let mut ds = f.debug_struct("ClearFaultsRequest");
       ds.finish()
   }
 }
-pub enum DeviceInfoResponseOffset {}
+pub enum DrainWaterRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DeviceInfoResponse<'a> {
+/// Pump the reservoir empty for cleaning (maintenance).  Runs until both
+/// tanks read empty or the device-side duration cap, whichever is first.
+pub struct DrainWaterRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for DeviceInfoResponse<'a> {
-  type Inner = DeviceInfoResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for DrainWaterRequest<'a> {
+  type Inner = DrainWaterRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> DeviceInfoResponse<'a> {
-  pub const VT_FIRMWARE_VERSION: flatbuffers::VOffsetT = 4;
-  pub const VT_HARDWARE_REVISION: flatbuffers::VOffsetT = 6;
-  pub const VT_SERIAL_NUMBER: flatbuffers::VOffsetT = 8;
-  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 10;
-  pub const VT_CAPABILITIES: flatbuffers::VOffsetT = 12;
-  pub const VT_MAX_CLIENTS: flatbuffers::VOffsetT = 14;
+impl<'a> DrainWaterRequest<'a> {
+  pub const VT_ZONE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    DeviceInfoResponse { _tab: table }
+    DrainWaterRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DeviceInfoResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<DeviceInfoResponse<'bldr>> {
-    let mut builder = DeviceInfoResponseBuilder::new(_fbb);
-    builder.add_uptime_secs(args.uptime_secs);
-    builder.add_capabilities(args.capabilities);
-    if let Some(x) = args.serial_number { builder.add_serial_number(x); }
-    if let Some(x) = args.hardware_revision { builder.add_hardware_revision(x); }
-    if let Some(x) = args.firmware_version { builder.add_firmware_version(x); }
-    builder.add_max_clients(args.max_clients);
+    args: &'args DrainWaterRequestArgs
+  ) -> flatbuffers::WIPOffset<DrainWaterRequest<'bldr>> {
+    let mut builder = DrainWaterRequestBuilder::new(_fbb);
+    builder.add_zone(args.zone);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn firmware_version(&self) -> Option<&'a str> {
+  pub fn zone(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_FIRMWARE_VERSION, None)}
+    unsafe { self._tab.get::<u8>(DrainWaterRequest::VT_ZONE, Some(0)).unwrap()}
   }
+}
+
+impl flatbuffers::Verifiable for DrainWaterRequest<'_> {
   #[inline]
-  pub fn hardware_revision(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_HARDWARE_REVISION, None)}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct DrainWaterRequestArgs {
+    pub zone: u8,
+}
+impl<'a> Default for DrainWaterRequestArgs {
   #[inline]
-  pub fn serial_number(&self) -> Option<&'a str> {
+  fn default() -> Self {
+    DrainWaterRequestArgs {
+      zone: 0,
+    }
+  }
+}
+
+pub struct DrainWaterRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DrainWaterRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(DrainWaterRequest::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DrainWaterRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DrainWaterRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DrainWaterRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DrainWaterRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DrainWaterRequest");
+      ds.field("zone", &self.zone());
+      ds.finish()
+  }
+}
+pub enum SetConfigRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetConfigRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetConfigRequest<'a> {
+  type Inner = SetConfigRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetConfigRequest<'a> {
+  pub const VT_NH3_ACTIVATE_PPM: flatbuffers::VOffsetT = 4;
+  pub const VT_NH3_DEACTIVATE_PPM: flatbuffers::VOffsetT = 6;
+  pub const VT_PUMP_DUTY_PERCENT: flatbuffers::VOffsetT = 8;
+  pub const VT_UVC_DUTY_PERCENT: flatbuffers::VOffsetT = 10;
+  pub const VT_PURGE_DURATION_SECS: flatbuffers::VOffsetT = 12;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetConfigRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetConfigRequestArgs
+  ) -> flatbuffers::WIPOffset<SetConfigRequest<'bldr>> {
+    let mut builder = SetConfigRequestBuilder::new(_fbb);
+    builder.add_nh3_deactivate_ppm(args.nh3_deactivate_ppm);
+    builder.add_nh3_activate_ppm(args.nh3_activate_ppm);
+    builder.add_purge_duration_secs(args.purge_duration_secs);
+    builder.add_zone(args.zone);
+    builder.add_uvc_duty_percent(args.uvc_duty_percent);
+    builder.add_pump_duty_percent(args.pump_duty_percent);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nh3_activate_ppm(&self) -> f32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_SERIAL_NUMBER, None)}
+    unsafe { self._tab.get::<f32>(SetConfigRequest::VT_NH3_ACTIVATE_PPM, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn uptime_secs(&self) -> u64 {
+  pub fn nh3_deactivate_ppm(&self) -> f32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(DeviceInfoResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<f32>(SetConfigRequest::VT_NH3_DEACTIVATE_PPM, Some(0.0)).unwrap()}
   }
-  /// Bitmask: bit0=compression, bit1=chunked, bit2=cert_auth, bit3=multi_client
   #[inline]
-  pub fn capabilities(&self) -> u32 {
+  pub fn pump_duty_percent(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DeviceInfoResponse::VT_CAPABILITIES, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetConfigRequest::VT_PUMP_DUTY_PERCENT, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn max_clients(&self) -> u8 {
+  pub fn uvc_duty_percent(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(DeviceInfoResponse::VT_MAX_CLIENTS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetConfigRequest::VT_UVC_DUTY_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn purge_duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(SetConfigRequest::VT_PURGE_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SetConfigRequest::VT_ZONE, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for DeviceInfoResponse<'_> {
+impl flatbuffers::Verifiable for SetConfigRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("firmware_version", Self::VT_FIRMWARE_VERSION, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("hardware_revision", Self::VT_HARDWARE_REVISION, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("serial_number", Self::VT_SERIAL_NUMBER, false)?
-     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
-     .visit_field::<u32>("capabilities", Self::VT_CAPABILITIES, false)?
-     .visit_field::<u8>("max_clients", Self::VT_MAX_CLIENTS, false)?
+     .visit_field::<f32>("nh3_activate_ppm", Self::VT_NH3_ACTIVATE_PPM, false)?
+     .visit_field::<f32>("nh3_deactivate_ppm", Self::VT_NH3_DEACTIVATE_PPM, false)?
+     .visit_field::<u8>("pump_duty_percent", Self::VT_PUMP_DUTY_PERCENT, false)?
+     .visit_field::<u8>("uvc_duty_percent", Self::VT_UVC_DUTY_PERCENT, false)?
+     .visit_field::<u16>("purge_duration_secs", Self::VT_PURGE_DURATION_SECS, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct DeviceInfoResponseArgs<'a> {
-    pub firmware_version: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub hardware_revision: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub serial_number: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub uptime_secs: u64,
-    pub capabilities: u32,
-    pub max_clients: u8,
+pub struct SetConfigRequestArgs {
+    pub nh3_activate_ppm: f32,
+    pub nh3_deactivate_ppm: f32,
+    pub pump_duty_percent: u8,
+    pub uvc_duty_percent: u8,
+    pub purge_duration_secs: u16,
+    pub zone: u8,
 }
-impl<'a> Default for DeviceInfoResponseArgs<'a> {
+impl<'a> Default for SetConfigRequestArgs {
   #[inline]
   fn default() -> Self {
-    DeviceInfoResponseArgs {
-      firmware_version: None,
-      hardware_revision: None,
-      serial_number: None,
-      uptime_secs: 0,
-      capabilities: 0,
-      max_clients: 0,
+    SetConfigRequestArgs {
+      nh3_activate_ppm: 0.0,
+      nh3_deactivate_ppm: 0.0,
+      pump_duty_percent: 0,
+      uvc_duty_percent: 0,
+      purge_duration_secs: 0,
+      zone: 0,
     }
   }
 }
 
-pub struct DeviceInfoResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetConfigRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DeviceInfoResponseBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetConfigRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_firmware_version(&mut self, firmware_version: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_FIRMWARE_VERSION, firmware_version);
+  pub fn add_nh3_activate_ppm(&mut self, nh3_activate_ppm: f32) {
+    self.fbb_.push_slot::<f32>(SetConfigRequest::VT_NH3_ACTIVATE_PPM, nh3_activate_ppm, 0.0);
   }
   #[inline]
-  pub fn add_hardware_revision(&mut self, hardware_revision: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_HARDWARE_REVISION, hardware_revision);
+  pub fn add_nh3_deactivate_ppm(&mut self, nh3_deactivate_ppm: f32) {
+    self.fbb_.push_slot::<f32>(SetConfigRequest::VT_NH3_DEACTIVATE_PPM, nh3_deactivate_ppm, 0.0);
   }
   #[inline]
-  pub fn add_serial_number(&mut self, serial_number: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_SERIAL_NUMBER, serial_number);
+  pub fn add_pump_duty_percent(&mut self, pump_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(SetConfigRequest::VT_PUMP_DUTY_PERCENT, pump_duty_percent, 0);
   }
   #[inline]
-  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
-    self.fbb_.push_slot::<u64>(DeviceInfoResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  pub fn add_uvc_duty_percent(&mut self, uvc_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(SetConfigRequest::VT_UVC_DUTY_PERCENT, uvc_duty_percent, 0);
   }
   #[inline]
-  pub fn add_capabilities(&mut self, capabilities: u32) {
-    self.fbb_.push_slot::<u32>(DeviceInfoResponse::VT_CAPABILITIES, capabilities, 0);
+  pub fn add_purge_duration_secs(&mut self, purge_duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(SetConfigRequest::VT_PURGE_DURATION_SECS, purge_duration_secs, 0);
   }
   #[inline]
-  pub fn add_max_clients(&mut self, max_clients: u8) {
-    self.fbb_.push_slot::<u8>(DeviceInfoResponse::VT_MAX_CLIENTS, max_clients, 0);
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(SetConfigRequest::VT_ZONE, zone, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DeviceInfoResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetConfigRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    DeviceInfoResponseBuilder {
+    SetConfigRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<DeviceInfoResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetConfigRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for DeviceInfoResponse<'_> {
+impl core::fmt::Debug for SetConfigRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("DeviceInfoResponse");
-      ds.field("firmware_version", &self.firmware_version());
-      ds.field("hardware_revision", &self.hardware_revision());
-      ds.field("serial_number", &self.serial_number());
-      ds.field("uptime_secs", &self.uptime_secs());
-      ds.field("capabilities", &self.capabilities());
-      ds.field("max_clients", &self.max_clients());
+    let mut ds = f.debug_struct("SetConfigRequest");
+      ds.field("nh3_activate_ppm", &self.nh3_activate_ppm());
+      ds.field("nh3_deactivate_ppm", &self.nh3_deactivate_ppm());
+      ds.field("pump_duty_percent", &self.pump_duty_percent());
+      ds.field("uvc_duty_percent", &self.uvc_duty_percent());
+      ds.field("purge_duration_secs", &self.purge_duration_secs());
+      ds.field("zone", &self.zone());
       ds.finish()
   }
 }
-pub enum AckResponseOffset {}
+pub enum GetConfigRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AckResponse<'a> {
+pub struct GetConfigRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AckResponse<'a> {
-  type Inner = AckResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for GetConfigRequest<'a> {
+  type Inner = GetConfigRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AckResponse<'a> {
-  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
-  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+impl<'a> GetConfigRequest<'a> {
+  pub const VT_ZONE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AckResponse { _tab: table }
+    GetConfigRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AckResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<AckResponse<'bldr>> {
-    let mut builder = AckResponseBuilder::new(_fbb);
-    if let Some(x) = args.message { builder.add_message(x); }
-    builder.add_success(args.success);
+    args: &'args GetConfigRequestArgs
+  ) -> flatbuffers::WIPOffset<GetConfigRequest<'bldr>> {
+    let mut builder = GetConfigRequestBuilder::new(_fbb);
+    builder.add_zone(args.zone);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn success(&self) -> bool {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(AckResponse::VT_SUCCESS, Some(false)).unwrap()}
-  }
-  #[inline]
-  pub fn message(&self) -> Option<&'a str> {
+  pub fn zone(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AckResponse::VT_MESSAGE, None)}
+    unsafe { self._tab.get::<u8>(GetConfigRequest::VT_ZONE, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for AckResponse<'_> {
+impl flatbuffers::Verifiable for GetConfigRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AckResponseArgs<'a> {
-    pub success: bool,
-    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct GetConfigRequestArgs {
+    pub zone: u8,
 }
-impl<'a> Default for AckResponseArgs<'a> {
+impl<'a> Default for GetConfigRequestArgs {
   #[inline]
   fn default() -> Self {
-    AckResponseArgs {
-      success: false,
-      message: None,
+    GetConfigRequestArgs {
+      zone: 0,
     }
   }
 }
 
-pub struct AckResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GetConfigRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AckResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_success(&mut self, success: bool) {
-    self.fbb_.push_slot::<bool>(AckResponse::VT_SUCCESS, success, false);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetConfigRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AckResponse::VT_MESSAGE, message);
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigRequest::VT_ZONE, zone, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AckResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetConfigRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AckResponseBuilder {
+    GetConfigRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AckResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetConfigRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AckResponse<'_> {
+impl core::fmt::Debug for GetConfigRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AckResponse");
-      ds.field("success", &self.success());
-      ds.field("message", &self.message());
+    let mut ds = f.debug_struct("GetConfigRequest");
+      ds.field("zone", &self.zone());
       ds.finish()
   }
 }
-pub enum TelemetryFrameOffset {}
+pub enum SetLedBrightnessRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TelemetryFrame<'a> {
+/// Status LED brightness, persisted with the system config.
+pub struct SetLedBrightnessRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TelemetryFrame<'a> {
-  type Inner = TelemetryFrame<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetLedBrightnessRequest<'a> {
+  type Inner = SetLedBrightnessRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> TelemetryFrame<'a> {
-  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 4;
-  pub const VT_STATE: flatbuffers::VOffsetT = 6;
-  pub const VT_NH3_PPM: flatbuffers::VOffsetT = 8;
-  pub const VT_NH3_AVG_PPM: flatbuffers::VOffsetT = 10;
-  pub const VT_FLOW_ML_PER_MIN: flatbuffers::VOffsetT = 12;
-  pub const VT_TEMPERATURE_C: flatbuffers::VOffsetT = 14;
-  pub const VT_PUMP_DUTY: flatbuffers::VOffsetT = 16;
-  pub const VT_UVC_DUTY: flatbuffers::VOffsetT = 18;
-  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 20;
-  pub const VT_WIFI_RSSI: flatbuffers::VOffsetT = 22;
+impl<'a> SetLedBrightnessRequest<'a> {
+  pub const VT_BRIGHTNESS: flatbuffers::VOffsetT = 4;
+  pub const VT_QUIET_BRIGHTNESS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    TelemetryFrame { _tab: table }
+    SetLedBrightnessRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args TelemetryFrameArgs
-  ) -> flatbuffers::WIPOffset<TelemetryFrame<'bldr>> {
-    let mut builder = TelemetryFrameBuilder::new(_fbb);
-    builder.add_timestamp_ms(args.timestamp_ms);
-    builder.add_temperature_c(args.temperature_c);
-    builder.add_flow_ml_per_min(args.flow_ml_per_min);
-    builder.add_nh3_avg_ppm(args.nh3_avg_ppm);
-    builder.add_nh3_ppm(args.nh3_ppm);
-    builder.add_wifi_rssi(args.wifi_rssi);
-    builder.add_fault_flags(args.fault_flags);
-    builder.add_uvc_duty(args.uvc_duty);
-    builder.add_pump_duty(args.pump_duty);
-    builder.add_state(args.state);
+    args: &'args SetLedBrightnessRequestArgs
+  ) -> flatbuffers::WIPOffset<SetLedBrightnessRequest<'bldr>> {
+    let mut builder = SetLedBrightnessRequestBuilder::new(_fbb);
+    builder.add_quiet_brightness(args.quiet_brightness);
+    builder.add_brightness(args.brightness);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn timestamp_ms(&self) -> u64 {
+  pub fn brightness(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(TelemetryFrame::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetLedBrightnessRequest::VT_BRIGHTNESS, Some(255)).unwrap()}
   }
   #[inline]
-  pub fn state(&self) -> DeviceState {
+  pub fn quiet_brightness(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<DeviceState>(TelemetryFrame::VT_STATE, Some(DeviceState::Idle)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetLedBrightnessRequest::VT_QUIET_BRIGHTNESS, Some(255)).unwrap()}
   }
+}
+
+impl flatbuffers::Verifiable for SetLedBrightnessRequest<'_> {
   #[inline]
-  pub fn nh3_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_NH3_PPM, Some(0.0)).unwrap()}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("brightness", Self::VT_BRIGHTNESS, false)?
+     .visit_field::<u8>("quiet_brightness", Self::VT_QUIET_BRIGHTNESS, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct SetLedBrightnessRequestArgs {
+    pub brightness: u8,
+    pub quiet_brightness: u8,
+}
+impl<'a> Default for SetLedBrightnessRequestArgs {
   #[inline]
-  pub fn nh3_avg_ppm(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_NH3_AVG_PPM, Some(0.0)).unwrap()}
+  fn default() -> Self {
+    SetLedBrightnessRequestArgs {
+      brightness: 255,
+      quiet_brightness: 255,
+    }
   }
+}
+
+pub struct SetLedBrightnessRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetLedBrightnessRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn flow_ml_per_min(&self) -> f32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_FLOW_ML_PER_MIN, Some(0.0)).unwrap()}
+  pub fn add_brightness(&mut self, brightness: u8) {
+    self.fbb_.push_slot::<u8>(SetLedBrightnessRequest::VT_BRIGHTNESS, brightness, 255);
   }
   #[inline]
-  pub fn temperature_c(&self) -> f32 {
+  pub fn add_quiet_brightness(&mut self, quiet_brightness: u8) {
+    self.fbb_.push_slot::<u8>(SetLedBrightnessRequest::VT_QUIET_BRIGHTNESS, quiet_brightness, 255);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetLedBrightnessRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetLedBrightnessRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetLedBrightnessRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetLedBrightnessRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetLedBrightnessRequest");
+      ds.field("brightness", &self.brightness());
+      ds.field("quiet_brightness", &self.quiet_brightness());
+      ds.finish()
+  }
+}
+pub enum SetLedPatternRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Override the status LED colour and pattern shown for one FSM
+/// state, persisted with the system config.
+pub struct SetLedPatternRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetLedPatternRequest<'a> {
+  type Inner = SetLedPatternRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetLedPatternRequest<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_RED: flatbuffers::VOffsetT = 6;
+  pub const VT_GREEN: flatbuffers::VOffsetT = 8;
+  pub const VT_BLUE: flatbuffers::VOffsetT = 10;
+  pub const VT_PATTERN: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetLedPatternRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetLedPatternRequestArgs
+  ) -> flatbuffers::WIPOffset<SetLedPatternRequest<'bldr>> {
+    let mut builder = SetLedPatternRequestBuilder::new(_fbb);
+    builder.add_pattern(args.pattern);
+    builder.add_blue(args.blue);
+    builder.add_green(args.green);
+    builder.add_red(args.red);
+    builder.add_state(args.state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn state(&self) -> DeviceState {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_TEMPERATURE_C, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<DeviceState>(SetLedPatternRequest::VT_STATE, Some(DeviceState::Idle)).unwrap()}
   }
   #[inline]
-  pub fn pump_duty(&self) -> u8 {
+  pub fn red(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_PUMP_DUTY, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetLedPatternRequest::VT_RED, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn uvc_duty(&self) -> u8 {
+  pub fn green(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_UVC_DUTY, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetLedPatternRequest::VT_GREEN, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn fault_flags(&self) -> u8 {
+  pub fn blue(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_FAULT_FLAGS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetLedPatternRequest::VT_BLUE, Some(0)).unwrap()}
   }
-  /// WiFi RSSI in dBm; 127 = not available (e.g. BLE-only).
   #[inline]
-  pub fn wifi_rssi(&self) -> i8 {
+  pub fn pattern(&self) -> LedPattern {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<i8>(TelemetryFrame::VT_WIFI_RSSI, Some(127)).unwrap()}
+    unsafe { self._tab.get::<LedPattern>(SetLedPatternRequest::VT_PATTERN, Some(LedPattern::Solid)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for TelemetryFrame<'_> {
+impl flatbuffers::Verifiable for SetLedPatternRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
      .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
-     .visit_field::<f32>("nh3_ppm", Self::VT_NH3_PPM, false)?
-     .visit_field::<f32>("nh3_avg_ppm", Self::VT_NH3_AVG_PPM, false)?
-     .visit_field::<f32>("flow_ml_per_min", Self::VT_FLOW_ML_PER_MIN, false)?
-     .visit_field::<f32>("temperature_c", Self::VT_TEMPERATURE_C, false)?
-     .visit_field::<u8>("pump_duty", Self::VT_PUMP_DUTY, false)?
-     .visit_field::<u8>("uvc_duty", Self::VT_UVC_DUTY, false)?
-     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
-     .visit_field::<i8>("wifi_rssi", Self::VT_WIFI_RSSI, false)?
+     .visit_field::<u8>("red", Self::VT_RED, false)?
+     .visit_field::<u8>("green", Self::VT_GREEN, false)?
+     .visit_field::<u8>("blue", Self::VT_BLUE, false)?
+     .visit_field::<LedPattern>("pattern", Self::VT_PATTERN, false)?
      .finish();
     Ok(())
   }
 }
-pub struct TelemetryFrameArgs {
-    pub timestamp_ms: u64,
+pub struct SetLedPatternRequestArgs {
     pub state: DeviceState,
-    pub nh3_ppm: f32,
-    pub nh3_avg_ppm: f32,
-    pub flow_ml_per_min: f32,
-    pub temperature_c: f32,
-    pub pump_duty: u8,
-    pub uvc_duty: u8,
-    pub fault_flags: u8,
-    pub wifi_rssi: i8,
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub pattern: LedPattern,
 }
-impl<'a> Default for TelemetryFrameArgs {
+impl<'a> Default for SetLedPatternRequestArgs {
   #[inline]
   fn default() -> Self {
-    TelemetryFrameArgs {
-      timestamp_ms: 0,
+    SetLedPatternRequestArgs {
       state: DeviceState::Idle,
-      nh3_ppm: 0.0,
-      nh3_avg_ppm: 0.0,
-      flow_ml_per_min: 0.0,
-      temperature_c: 0.0,
-      pump_duty: 0,
-      uvc_duty: 0,
-      fault_flags: 0,
-      wifi_rssi: 127,
+      red: 0,
+      green: 0,
+      blue: 0,
+      pattern: LedPattern::Solid,
     }
   }
 }
 
-pub struct TelemetryFrameBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetLedPatternRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TelemetryFrameBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
-    self.fbb_.push_slot::<u64>(TelemetryFrame::VT_TIMESTAMP_MS, timestamp_ms, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetLedPatternRequestBuilder<'a, 'b, A> {
   #[inline]
   pub fn add_state(&mut self, state: DeviceState) {
-    self.fbb_.push_slot::<DeviceState>(TelemetryFrame::VT_STATE, state, DeviceState::Idle);
-  }
-  #[inline]
-  pub fn add_nh3_ppm(&mut self, nh3_ppm: f32) {
-    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_NH3_PPM, nh3_ppm, 0.0);
-  }
-  #[inline]
-  pub fn add_nh3_avg_ppm(&mut self, nh3_avg_ppm: f32) {
-    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_NH3_AVG_PPM, nh3_avg_ppm, 0.0);
-  }
-  #[inline]
-  pub fn add_flow_ml_per_min(&mut self, flow_ml_per_min: f32) {
-    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_FLOW_ML_PER_MIN, flow_ml_per_min, 0.0);
-  }
-  #[inline]
-  pub fn add_temperature_c(&mut self, temperature_c: f32) {
-    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_TEMPERATURE_C, temperature_c, 0.0);
+    self.fbb_.push_slot::<DeviceState>(SetLedPatternRequest::VT_STATE, state, DeviceState::Idle);
   }
   #[inline]
-  pub fn add_pump_duty(&mut self, pump_duty: u8) {
-    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_PUMP_DUTY, pump_duty, 0);
+  pub fn add_red(&mut self, red: u8) {
+    self.fbb_.push_slot::<u8>(SetLedPatternRequest::VT_RED, red, 0);
   }
   #[inline]
-  pub fn add_uvc_duty(&mut self, uvc_duty: u8) {
-    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_UVC_DUTY, uvc_duty, 0);
+  pub fn add_green(&mut self, green: u8) {
+    self.fbb_.push_slot::<u8>(SetLedPatternRequest::VT_GREEN, green, 0);
   }
   #[inline]
-  pub fn add_fault_flags(&mut self, fault_flags: u8) {
-    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_FAULT_FLAGS, fault_flags, 0);
+  pub fn add_blue(&mut self, blue: u8) {
+    self.fbb_.push_slot::<u8>(SetLedPatternRequest::VT_BLUE, blue, 0);
   }
   #[inline]
-  pub fn add_wifi_rssi(&mut self, wifi_rssi: i8) {
-    self.fbb_.push_slot::<i8>(TelemetryFrame::VT_WIFI_RSSI, wifi_rssi, 127);
+  pub fn add_pattern(&mut self, pattern: LedPattern) {
+    self.fbb_.push_slot::<LedPattern>(SetLedPatternRequest::VT_PATTERN, pattern, LedPattern::Solid);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TelemetryFrameBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetLedPatternRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    TelemetryFrameBuilder {
+    SetLedPatternRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<TelemetryFrame<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetLedPatternRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for TelemetryFrame<'_> {
+impl core::fmt::Debug for SetLedPatternRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("TelemetryFrame");
-      ds.field("timestamp_ms", &self.timestamp_ms());
+    let mut ds = f.debug_struct("SetLedPatternRequest");
       ds.field("state", &self.state());
-      ds.field("nh3_ppm", &self.nh3_ppm());
-      ds.field("nh3_avg_ppm", &self.nh3_avg_ppm());
-      ds.field("flow_ml_per_min", &self.flow_ml_per_min());
-      ds.field("temperature_c", &self.temperature_c());
-      ds.field("pump_duty", &self.pump_duty());
-      ds.field("uvc_duty", &self.uvc_duty());
-      ds.field("fault_flags", &self.fault_flags());
-      ds.field("wifi_rssi", &self.wifi_rssi());
+      ds.field("red", &self.red());
+      ds.field("green", &self.green());
+      ds.field("blue", &self.blue());
+      ds.field("pattern", &self.pattern());
       ds.finish()
   }
 }
-pub enum StateChangeEventOffset {}
+pub enum ResetLedPatternsRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StateChangeEvent<'a> {
+/// Drop every per-state LED override, restoring the built-in colours.
+pub struct ResetLedPatternsRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StateChangeEvent<'a> {
-  type Inner = StateChangeEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for ResetLedPatternsRequest<'a> {
+  type Inner = ResetLedPatternsRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StateChangeEvent<'a> {
-  pub const VT_FROM_STATE: flatbuffers::VOffsetT = 4;
-  pub const VT_TO_STATE: flatbuffers::VOffsetT = 6;
-  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 8;
+impl<'a> ResetLedPatternsRequest<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StateChangeEvent { _tab: table }
+    ResetLedPatternsRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StateChangeEventArgs
-  ) -> flatbuffers::WIPOffset<StateChangeEvent<'bldr>> {
-    let mut builder = StateChangeEventBuilder::new(_fbb);
-    builder.add_timestamp_ms(args.timestamp_ms);
-    builder.add_to_state(args.to_state);
-    builder.add_from_state(args.from_state);
+    _args: &'args ResetLedPatternsRequestArgs
+  ) -> flatbuffers::WIPOffset<ResetLedPatternsRequest<'bldr>> {
+    let mut builder = ResetLedPatternsRequestBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn from_state(&self) -> DeviceState {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<DeviceState>(StateChangeEvent::VT_FROM_STATE, Some(DeviceState::Idle)).unwrap()}
-  }
-  #[inline]
-  pub fn to_state(&self) -> DeviceState {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<DeviceState>(StateChangeEvent::VT_TO_STATE, Some(DeviceState::Idle)).unwrap()}
-  }
-  #[inline]
-  pub fn timestamp_ms(&self) -> u64 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(StateChangeEvent::VT_TIMESTAMP_MS, Some(0)).unwrap()}
-  }
 }
 
-impl flatbuffers::Verifiable for StateChangeEvent<'_> {
+impl flatbuffers::Verifiable for ResetLedPatternsRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<DeviceState>("from_state", Self::VT_FROM_STATE, false)?
-     .visit_field::<DeviceState>("to_state", Self::VT_TO_STATE, false)?
-     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StateChangeEventArgs {
-    pub from_state: DeviceState,
-    pub to_state: DeviceState,
-    pub timestamp_ms: u64,
+pub struct ResetLedPatternsRequestArgs {
 }
-impl<'a> Default for StateChangeEventArgs {
+impl<'a> Default for ResetLedPatternsRequestArgs {
   #[inline]
   fn default() -> Self {
-    StateChangeEventArgs {
-      from_state: DeviceState::Idle,
-      to_state: DeviceState::Idle,
-      timestamp_ms: 0,
+    ResetLedPatternsRequestArgs {
     }
   }
 }
 
-pub struct StateChangeEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ResetLedPatternsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StateChangeEventBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_from_state(&mut self, from_state: DeviceState) {
-    self.fbb_.push_slot::<DeviceState>(StateChangeEvent::VT_FROM_STATE, from_state, DeviceState::Idle);
-  }
-  #[inline]
-  pub fn add_to_state(&mut self, to_state: DeviceState) {
-    self.fbb_.push_slot::<DeviceState>(StateChangeEvent::VT_TO_STATE, to_state, DeviceState::Idle);
-  }
-  #[inline]
-  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
-    self.fbb_.push_slot::<u64>(StateChangeEvent::VT_TIMESTAMP_MS, timestamp_ms, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResetLedPatternsRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StateChangeEventBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResetLedPatternsRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StateChangeEventBuilder {
+    ResetLedPatternsRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StateChangeEvent<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResetLedPatternsRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StateChangeEvent<'_> {
+impl core::fmt::Debug for ResetLedPatternsRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StateChangeEvent");
-      ds.field("from_state", &self.from_state());
-      ds.field("to_state", &self.to_state());
-      ds.field("timestamp_ms", &self.timestamp_ms());
+    let mut ds = f.debug_struct("ResetLedPatternsRequest");
       ds.finish()
   }
 }
-pub enum FaultEventOffset {}
+pub enum LedOverrideFbsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FaultEvent<'a> {
+pub struct LedOverrideFbs<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FaultEvent<'a> {
-  type Inner = FaultEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for LedOverrideFbs<'a> {
+  type Inner = LedOverrideFbs<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> FaultEvent<'a> {
-  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 4;
-  pub const VT_IS_CLEAR: flatbuffers::VOffsetT = 6;
-  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 8;
+impl<'a> LedOverrideFbs<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_RED: flatbuffers::VOffsetT = 6;
+  pub const VT_GREEN: flatbuffers::VOffsetT = 8;
+  pub const VT_BLUE: flatbuffers::VOffsetT = 10;
+  pub const VT_PATTERN: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    FaultEvent { _tab: table }
+    LedOverrideFbs { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args FaultEventArgs
-  ) -> flatbuffers::WIPOffset<FaultEvent<'bldr>> {
-    let mut builder = FaultEventBuilder::new(_fbb);
-    builder.add_timestamp_ms(args.timestamp_ms);
-    builder.add_is_clear(args.is_clear);
-    builder.add_fault_flags(args.fault_flags);
+    args: &'args LedOverrideFbsArgs
+  ) -> flatbuffers::WIPOffset<LedOverrideFbs<'bldr>> {
+    let mut builder = LedOverrideFbsBuilder::new(_fbb);
+    builder.add_pattern(args.pattern);
+    builder.add_blue(args.blue);
+    builder.add_green(args.green);
+    builder.add_red(args.red);
+    builder.add_state(args.state);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn fault_flags(&self) -> u8 {
+  pub fn state(&self) -> DeviceState {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(FaultEvent::VT_FAULT_FLAGS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<DeviceState>(LedOverrideFbs::VT_STATE, Some(DeviceState::Idle)).unwrap()}
   }
   #[inline]
-  pub fn is_clear(&self) -> bool {
+  pub fn red(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(FaultEvent::VT_IS_CLEAR, Some(false)).unwrap()}
+    unsafe { self._tab.get::<u8>(LedOverrideFbs::VT_RED, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn timestamp_ms(&self) -> u64 {
+  pub fn green(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(FaultEvent::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(LedOverrideFbs::VT_GREEN, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn blue(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(LedOverrideFbs::VT_BLUE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn pattern(&self) -> LedPattern {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LedPattern>(LedOverrideFbs::VT_PATTERN, Some(LedPattern::Solid)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for FaultEvent<'_> {
+impl flatbuffers::Verifiable for LedOverrideFbs<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
-     .visit_field::<bool>("is_clear", Self::VT_IS_CLEAR, false)?
-     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
+     .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
+     .visit_field::<u8>("red", Self::VT_RED, false)?
+     .visit_field::<u8>("green", Self::VT_GREEN, false)?
+     .visit_field::<u8>("blue", Self::VT_BLUE, false)?
+     .visit_field::<LedPattern>("pattern", Self::VT_PATTERN, false)?
      .finish();
     Ok(())
   }
 }
-pub struct FaultEventArgs {
-    pub fault_flags: u8,
-    pub is_clear: bool,
-    pub timestamp_ms: u64,
+pub struct LedOverrideFbsArgs {
+    pub state: DeviceState,
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub pattern: LedPattern,
 }
-impl<'a> Default for FaultEventArgs {
+impl<'a> Default for LedOverrideFbsArgs {
   #[inline]
   fn default() -> Self {
-    FaultEventArgs {
-      fault_flags: 0,
-      is_clear: false,
-      timestamp_ms: 0,
+    LedOverrideFbsArgs {
+      state: DeviceState::Idle,
+      red: 0,
+      green: 0,
+      blue: 0,
+      pattern: LedPattern::Solid,
     }
   }
 }
 
-pub struct FaultEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LedOverrideFbsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FaultEventBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LedOverrideFbsBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_fault_flags(&mut self, fault_flags: u8) {
-    self.fbb_.push_slot::<u8>(FaultEvent::VT_FAULT_FLAGS, fault_flags, 0);
+  pub fn add_state(&mut self, state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(LedOverrideFbs::VT_STATE, state, DeviceState::Idle);
   }
   #[inline]
-  pub fn add_is_clear(&mut self, is_clear: bool) {
-    self.fbb_.push_slot::<bool>(FaultEvent::VT_IS_CLEAR, is_clear, false);
+  pub fn add_red(&mut self, red: u8) {
+    self.fbb_.push_slot::<u8>(LedOverrideFbs::VT_RED, red, 0);
   }
   #[inline]
-  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
-    self.fbb_.push_slot::<u64>(FaultEvent::VT_TIMESTAMP_MS, timestamp_ms, 0);
+  pub fn add_green(&mut self, green: u8) {
+    self.fbb_.push_slot::<u8>(LedOverrideFbs::VT_GREEN, green, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FaultEventBuilder<'a, 'b, A> {
+  pub fn add_blue(&mut self, blue: u8) {
+    self.fbb_.push_slot::<u8>(LedOverrideFbs::VT_BLUE, blue, 0);
+  }
+  #[inline]
+  pub fn add_pattern(&mut self, pattern: LedPattern) {
+    self.fbb_.push_slot::<LedPattern>(LedOverrideFbs::VT_PATTERN, pattern, LedPattern::Solid);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LedOverrideFbsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    FaultEventBuilder {
+    LedOverrideFbsBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<FaultEvent<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<LedOverrideFbs<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for FaultEvent<'_> {
+impl core::fmt::Debug for LedOverrideFbs<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("FaultEvent");
-      ds.field("fault_flags", &self.fault_flags());
-      ds.field("is_clear", &self.is_clear());
-      ds.field("timestamp_ms", &self.timestamp_ms());
+    let mut ds = f.debug_struct("LedOverrideFbs");
+      ds.field("state", &self.state());
+      ds.field("red", &self.red());
+      ds.field("green", &self.green());
+      ds.field("blue", &self.blue());
+      ds.field("pattern", &self.pattern());
       ds.finish()
   }
 }
-pub enum AuthChallengeRequestOffset {}
+pub enum SetScheduleRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AuthChallengeRequest<'a> {
+pub struct SetScheduleRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AuthChallengeRequest<'a> {
-  type Inner = AuthChallengeRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetScheduleRequest<'a> {
+  type Inner = SetScheduleRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AuthChallengeRequest<'a> {
+impl<'a> SetScheduleRequest<'a> {
+  pub const VT_INTERVAL_SECS: flatbuffers::VOffsetT = 4;
+  pub const VT_DURATION_SECS: flatbuffers::VOffsetT = 6;
+  pub const VT_QUIET_START_HOUR: flatbuffers::VOffsetT = 8;
+  pub const VT_QUIET_END_HOUR: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AuthChallengeRequest { _tab: table }
+    SetScheduleRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args AuthChallengeRequestArgs
-  ) -> flatbuffers::WIPOffset<AuthChallengeRequest<'bldr>> {
-    let mut builder = AuthChallengeRequestBuilder::new(_fbb);
+    args: &'args SetScheduleRequestArgs
+  ) -> flatbuffers::WIPOffset<SetScheduleRequest<'bldr>> {
+    let mut builder = SetScheduleRequestBuilder::new(_fbb);
+    builder.add_interval_secs(args.interval_secs);
+    builder.add_duration_secs(args.duration_secs);
+    builder.add_quiet_end_hour(args.quiet_end_hour);
+    builder.add_quiet_start_hour(args.quiet_start_hour);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn interval_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SetScheduleRequest::VT_INTERVAL_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(SetScheduleRequest::VT_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_start_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SetScheduleRequest::VT_QUIET_START_HOUR, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_end_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SetScheduleRequest::VT_QUIET_END_HOUR, Some(255)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for AuthChallengeRequest<'_> {
+impl flatbuffers::Verifiable for SetScheduleRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<u32>("interval_secs", Self::VT_INTERVAL_SECS, false)?
+     .visit_field::<u16>("duration_secs", Self::VT_DURATION_SECS, false)?
+     .visit_field::<u8>("quiet_start_hour", Self::VT_QUIET_START_HOUR, false)?
+     .visit_field::<u8>("quiet_end_hour", Self::VT_QUIET_END_HOUR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AuthChallengeRequestArgs {
+pub struct SetScheduleRequestArgs {
+    pub interval_secs: u32,
+    pub duration_secs: u16,
+    pub quiet_start_hour: u8,
+    pub quiet_end_hour: u8,
 }
-impl<'a> Default for AuthChallengeRequestArgs {
+impl<'a> Default for SetScheduleRequestArgs {
   #[inline]
   fn default() -> Self {
-    AuthChallengeRequestArgs {
+    SetScheduleRequestArgs {
+      interval_secs: 0,
+      duration_secs: 0,
+      quiet_start_hour: 255,
+      quiet_end_hour: 255,
     }
   }
 }
 
-pub struct AuthChallengeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetScheduleRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthChallengeRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetScheduleRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthChallengeRequestBuilder<'a, 'b, A> {
+  pub fn add_interval_secs(&mut self, interval_secs: u32) {
+    self.fbb_.push_slot::<u32>(SetScheduleRequest::VT_INTERVAL_SECS, interval_secs, 0);
+  }
+  #[inline]
+  pub fn add_duration_secs(&mut self, duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(SetScheduleRequest::VT_DURATION_SECS, duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_quiet_start_hour(&mut self, quiet_start_hour: u8) {
+    self.fbb_.push_slot::<u8>(SetScheduleRequest::VT_QUIET_START_HOUR, quiet_start_hour, 255);
+  }
+  #[inline]
+  pub fn add_quiet_end_hour(&mut self, quiet_end_hour: u8) {
+    self.fbb_.push_slot::<u8>(SetScheduleRequest::VT_QUIET_END_HOUR, quiet_end_hour, 255);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetScheduleRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AuthChallengeRequestBuilder {
+    SetScheduleRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AuthChallengeRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetScheduleRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AuthChallengeRequest<'_> {
+impl core::fmt::Debug for SetScheduleRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AuthChallengeRequest");
+    let mut ds = f.debug_struct("SetScheduleRequest");
+      ds.field("interval_secs", &self.interval_secs());
+      ds.field("duration_secs", &self.duration_secs());
+      ds.field("quiet_start_hour", &self.quiet_start_hour());
+      ds.field("quiet_end_hour", &self.quiet_end_hour());
       ds.finish()
   }
 }
-pub enum AuthVerifyRequestOffset {}
+pub enum CancelScheduleRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AuthVerifyRequest<'a> {
+pub struct CancelScheduleRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AuthVerifyRequest<'a> {
-  type Inner = AuthVerifyRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for CancelScheduleRequest<'a> {
+  type Inner = CancelScheduleRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AuthVerifyRequest<'a> {
-  pub const VT_SESSION_ID: flatbuffers::VOffsetT = 4;
-  pub const VT_HMAC: flatbuffers::VOffsetT = 6;
+impl<'a> CancelScheduleRequest<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AuthVerifyRequest { _tab: table }
+    CancelScheduleRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AuthVerifyRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<AuthVerifyRequest<'bldr>> {
-    let mut builder = AuthVerifyRequestBuilder::new(_fbb);
-    if let Some(x) = args.hmac { builder.add_hmac(x); }
-    builder.add_session_id(args.session_id);
+    _args: &'args CancelScheduleRequestArgs
+  ) -> flatbuffers::WIPOffset<CancelScheduleRequest<'bldr>> {
+    let mut builder = CancelScheduleRequestBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn session_id(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(AuthVerifyRequest::VT_SESSION_ID, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn hmac(&self) -> Option<flatbuffers::Vector<'a, u8>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AuthVerifyRequest::VT_HMAC, None)}
-  }
 }
 
-impl flatbuffers::Verifiable for AuthVerifyRequest<'_> {
+impl flatbuffers::Verifiable for CancelScheduleRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u32>("session_id", Self::VT_SESSION_ID, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("hmac", Self::VT_HMAC, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AuthVerifyRequestArgs<'a> {
-    pub session_id: u32,
-    pub hmac: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+pub struct CancelScheduleRequestArgs {
 }
-impl<'a> Default for AuthVerifyRequestArgs<'a> {
+impl<'a> Default for CancelScheduleRequestArgs {
   #[inline]
   fn default() -> Self {
-    AuthVerifyRequestArgs {
-      session_id: 0,
-      hmac: None,
+    CancelScheduleRequestArgs {
     }
   }
 }
 
-pub struct AuthVerifyRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CancelScheduleRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthVerifyRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_session_id(&mut self, session_id: u32) {
-    self.fbb_.push_slot::<u32>(AuthVerifyRequest::VT_SESSION_ID, session_id, 0);
-  }
-  #[inline]
-  pub fn add_hmac(&mut self, hmac: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthVerifyRequest::VT_HMAC, hmac);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CancelScheduleRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthVerifyRequestBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CancelScheduleRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AuthVerifyRequestBuilder {
+    CancelScheduleRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AuthVerifyRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<CancelScheduleRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AuthVerifyRequest<'_> {
+impl core::fmt::Debug for CancelScheduleRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AuthVerifyRequest");
-      ds.field("session_id", &self.session_id());
-      ds.field("hmac", &self.hmac());
+    let mut ds = f.debug_struct("CancelScheduleRequest");
       ds.finish()
   }
 }
-pub enum AuthChallengeResponseOffset {}
+pub enum SetQuietHoursRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AuthChallengeResponse<'a> {
+/// Quiet-hours window, persisted with the system config.  The window
+/// may wrap midnight (e.g. 22 → 6).  Hours are 0–23; start != end.
+pub struct SetQuietHoursRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AuthChallengeResponse<'a> {
-  type Inner = AuthChallengeResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetQuietHoursRequest<'a> {
+  type Inner = SetQuietHoursRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AuthChallengeResponse<'a> {
-  pub const VT_NONCE: flatbuffers::VOffsetT = 4;
-  pub const VT_SESSION_ID: flatbuffers::VOffsetT = 6;
+impl<'a> SetQuietHoursRequest<'a> {
+  pub const VT_START_HOUR: flatbuffers::VOffsetT = 4;
+  pub const VT_END_HOUR: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AuthChallengeResponse { _tab: table }
+    SetQuietHoursRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AuthChallengeResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<AuthChallengeResponse<'bldr>> {
-    let mut builder = AuthChallengeResponseBuilder::new(_fbb);
-    builder.add_session_id(args.session_id);
-    if let Some(x) = args.nonce { builder.add_nonce(x); }
+    args: &'args SetQuietHoursRequestArgs
+  ) -> flatbuffers::WIPOffset<SetQuietHoursRequest<'bldr>> {
+    let mut builder = SetQuietHoursRequestBuilder::new(_fbb);
+    builder.add_end_hour(args.end_hour);
+    builder.add_start_hour(args.start_hour);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn nonce(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+  pub fn start_hour(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AuthChallengeResponse::VT_NONCE, None)}
+    unsafe { self._tab.get::<u8>(SetQuietHoursRequest::VT_START_HOUR, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn session_id(&self) -> u32 {
+  pub fn end_hour(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(AuthChallengeResponse::VT_SESSION_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(SetQuietHoursRequest::VT_END_HOUR, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for AuthChallengeResponse<'_> {
+impl flatbuffers::Verifiable for SetQuietHoursRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("nonce", Self::VT_NONCE, false)?
-     .visit_field::<u32>("session_id", Self::VT_SESSION_ID, false)?
+     .visit_field::<u8>("start_hour", Self::VT_START_HOUR, false)?
+     .visit_field::<u8>("end_hour", Self::VT_END_HOUR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AuthChallengeResponseArgs<'a> {
-    pub nonce: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
-    pub session_id: u32,
+pub struct SetQuietHoursRequestArgs {
+    pub start_hour: u8,
+    pub end_hour: u8,
 }
-impl<'a> Default for AuthChallengeResponseArgs<'a> {
+impl<'a> Default for SetQuietHoursRequestArgs {
   #[inline]
   fn default() -> Self {
-    AuthChallengeResponseArgs {
-      nonce: None,
-      session_id: 0,
+    SetQuietHoursRequestArgs {
+      start_hour: 0,
+      end_hour: 0,
     }
   }
 }
 
-pub struct AuthChallengeResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetQuietHoursRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthChallengeResponseBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetQuietHoursRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_nonce(&mut self, nonce: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthChallengeResponse::VT_NONCE, nonce);
+  pub fn add_start_hour(&mut self, start_hour: u8) {
+    self.fbb_.push_slot::<u8>(SetQuietHoursRequest::VT_START_HOUR, start_hour, 0);
   }
   #[inline]
-  pub fn add_session_id(&mut self, session_id: u32) {
-    self.fbb_.push_slot::<u32>(AuthChallengeResponse::VT_SESSION_ID, session_id, 0);
+  pub fn add_end_hour(&mut self, end_hour: u8) {
+    self.fbb_.push_slot::<u8>(SetQuietHoursRequest::VT_END_HOUR, end_hour, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthChallengeResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetQuietHoursRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AuthChallengeResponseBuilder {
+    SetQuietHoursRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AuthChallengeResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetQuietHoursRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AuthChallengeResponse<'_> {
+impl core::fmt::Debug for SetQuietHoursRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AuthChallengeResponse");
-      ds.field("nonce", &self.nonce());
-      ds.field("session_id", &self.session_id());
+    let mut ds = f.debug_struct("SetQuietHoursRequest");
+      ds.field("start_hour", &self.start_hour());
+      ds.field("end_hour", &self.end_hour());
       ds.finish()
   }
 }
-pub enum AuthVerifyResponseOffset {}
+pub enum ClearQuietHoursRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AuthVerifyResponse<'a> {
+pub struct ClearQuietHoursRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AuthVerifyResponse<'a> {
-  type Inner = AuthVerifyResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for ClearQuietHoursRequest<'a> {
+  type Inner = ClearQuietHoursRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AuthVerifyResponse<'a> {
-  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
-  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+impl<'a> ClearQuietHoursRequest<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AuthVerifyResponse { _tab: table }
+    ClearQuietHoursRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AuthVerifyResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<AuthVerifyResponse<'bldr>> {
-    let mut builder = AuthVerifyResponseBuilder::new(_fbb);
-    if let Some(x) = args.message { builder.add_message(x); }
-    builder.add_success(args.success);
+    _args: &'args ClearQuietHoursRequestArgs
+  ) -> flatbuffers::WIPOffset<ClearQuietHoursRequest<'bldr>> {
+    let mut builder = ClearQuietHoursRequestBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn success(&self) -> bool {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(AuthVerifyResponse::VT_SUCCESS, Some(false)).unwrap()}
-  }
-  #[inline]
-  pub fn message(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthVerifyResponse::VT_MESSAGE, None)}
-  }
 }
 
-impl flatbuffers::Verifiable for AuthVerifyResponse<'_> {
+impl flatbuffers::Verifiable for ClearQuietHoursRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AuthVerifyResponseArgs<'a> {
-    pub success: bool,
-    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ClearQuietHoursRequestArgs {
 }
-impl<'a> Default for AuthVerifyResponseArgs<'a> {
+impl<'a> Default for ClearQuietHoursRequestArgs {
   #[inline]
   fn default() -> Self {
-    AuthVerifyResponseArgs {
-      success: false,
-      message: None,
+    ClearQuietHoursRequestArgs {
     }
   }
 }
 
-pub struct AuthVerifyResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ClearQuietHoursRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthVerifyResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_success(&mut self, success: bool) {
-    self.fbb_.push_slot::<bool>(AuthVerifyResponse::VT_SUCCESS, success, false);
-  }
-  #[inline]
-  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthVerifyResponse::VT_MESSAGE, message);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClearQuietHoursRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthVerifyResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClearQuietHoursRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AuthVerifyResponseBuilder {
+    ClearQuietHoursRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AuthVerifyResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClearQuietHoursRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AuthVerifyResponse<'_> {
+impl core::fmt::Debug for ClearQuietHoursRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AuthVerifyResponse");
-      ds.field("success", &self.success());
-      ds.field("message", &self.message());
+    let mut ds = f.debug_struct("ClearQuietHoursRequest");
       ds.finish()
   }
 }
-pub enum ProvisionCertRequestOffset {}
+pub enum SetTimeRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// Provision X.509 certificates onto the device over an authenticated
-/// PSK session. After successful provisioning the device switches to
-/// CertOnly or PskAndCert mode.
-pub struct ProvisionCertRequest<'a> {
+/// Set the wall clock (UTC seconds since the Unix epoch) for devices
+/// that cannot reach NTP.  Rejected if earlier than 2020-01-01.
+pub struct SetTimeRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ProvisionCertRequest<'a> {
-  type Inner = ProvisionCertRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetTimeRequest<'a> {
+  type Inner = SetTimeRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ProvisionCertRequest<'a> {
-  pub const VT_CA_CERT: flatbuffers::VOffsetT = 4;
-  pub const VT_DEVICE_CERT: flatbuffers::VOffsetT = 6;
-  pub const VT_DEVICE_KEY: flatbuffers::VOffsetT = 8;
+impl<'a> SetTimeRequest<'a> {
+  pub const VT_UNIX_SECS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ProvisionCertRequest { _tab: table }
+    SetTimeRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ProvisionCertRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<ProvisionCertRequest<'bldr>> {
-    let mut builder = ProvisionCertRequestBuilder::new(_fbb);
-    if let Some(x) = args.device_key { builder.add_device_key(x); }
-    if let Some(x) = args.device_cert { builder.add_device_cert(x); }
-    if let Some(x) = args.ca_cert { builder.add_ca_cert(x); }
+    args: &'args SetTimeRequestArgs
+  ) -> flatbuffers::WIPOffset<SetTimeRequest<'bldr>> {
+    let mut builder = SetTimeRequestBuilder::new(_fbb);
+    builder.add_unix_secs(args.unix_secs);
     builder.finish()
   }
 
 
-  /// PEM-encoded CA certificate chain.
-  #[inline]
-  pub fn ca_cert(&self) -> Option<flatbuffers::Vector<'a, u8>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_CA_CERT, None)}
-  }
-  /// PEM-encoded device certificate.
-  #[inline]
-  pub fn device_cert(&self) -> Option<flatbuffers::Vector<'a, u8>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_DEVICE_CERT, None)}
-  }
-  /// PEM-encoded device private key.
   #[inline]
-  pub fn device_key(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+  pub fn unix_secs(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_DEVICE_KEY, None)}
+    unsafe { self._tab.get::<u64>(SetTimeRequest::VT_UNIX_SECS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for ProvisionCertRequest<'_> {
+impl flatbuffers::Verifiable for SetTimeRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("ca_cert", Self::VT_CA_CERT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("device_cert", Self::VT_DEVICE_CERT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("device_key", Self::VT_DEVICE_KEY, false)?
+     .visit_field::<u64>("unix_secs", Self::VT_UNIX_SECS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ProvisionCertRequestArgs<'a> {
-    pub ca_cert: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
-    pub device_cert: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
-    pub device_key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+pub struct SetTimeRequestArgs {
+    pub unix_secs: u64,
 }
-impl<'a> Default for ProvisionCertRequestArgs<'a> {
+impl<'a> Default for SetTimeRequestArgs {
   #[inline]
   fn default() -> Self {
-    ProvisionCertRequestArgs {
-      ca_cert: None,
-      device_cert: None,
-      device_key: None,
+    SetTimeRequestArgs {
+      unix_secs: 0,
     }
   }
 }
 
-pub struct ProvisionCertRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetTimeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ProvisionCertRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_ca_cert(&mut self, ca_cert: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_CA_CERT, ca_cert);
-  }
-  #[inline]
-  pub fn add_device_cert(&mut self, device_cert: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_DEVICE_CERT, device_cert);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetTimeRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_device_key(&mut self, device_key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_DEVICE_KEY, device_key);
+  pub fn add_unix_secs(&mut self, unix_secs: u64) {
+    self.fbb_.push_slot::<u64>(SetTimeRequest::VT_UNIX_SECS, unix_secs, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ProvisionCertRequestBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetTimeRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ProvisionCertRequestBuilder {
+    SetTimeRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ProvisionCertRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetTimeRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for ProvisionCertRequest<'_> {
+impl core::fmt::Debug for SetTimeRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ProvisionCertRequest");
-      ds.field("ca_cert", &self.ca_cert());
-      ds.field("device_cert", &self.device_cert());
-      ds.field("device_key", &self.device_key());
+    let mut ds = f.debug_struct("SetTimeRequest");
+      ds.field("unix_secs", &self.unix_secs());
       ds.finish()
   }
 }
-pub enum GetCertStatusRequestOffset {}
+pub enum SetMaintenanceModeRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// Query the current TLS certificate status.
-pub struct GetCertStatusRequest<'a> {
+/// Enter or leave maintenance mode, persisted with the system config.
+/// While on, the FSM stays in Idle regardless of NH3 or schedules;
+/// safety faults still force Error.
+pub struct SetMaintenanceModeRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GetCertStatusRequest<'a> {
-  type Inner = GetCertStatusRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetMaintenanceModeRequest<'a> {
+  type Inner = SetMaintenanceModeRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> GetCertStatusRequest<'a> {
+impl<'a> SetMaintenanceModeRequest<'a> {
+  pub const VT_ENABLED: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GetCertStatusRequest { _tab: table }
+    SetMaintenanceModeRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args GetCertStatusRequestArgs
-  ) -> flatbuffers::WIPOffset<GetCertStatusRequest<'bldr>> {
-    let mut builder = GetCertStatusRequestBuilder::new(_fbb);
+    args: &'args SetMaintenanceModeRequestArgs
+  ) -> flatbuffers::WIPOffset<SetMaintenanceModeRequest<'bldr>> {
+    let mut builder = SetMaintenanceModeRequestBuilder::new(_fbb);
+    builder.add_enabled(args.enabled);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn enabled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SetMaintenanceModeRequest::VT_ENABLED, Some(false)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for GetCertStatusRequest<'_> {
+impl flatbuffers::Verifiable for SetMaintenanceModeRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<bool>("enabled", Self::VT_ENABLED, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GetCertStatusRequestArgs {
+pub struct SetMaintenanceModeRequestArgs {
+    pub enabled: bool,
 }
-impl<'a> Default for GetCertStatusRequestArgs {
+impl<'a> Default for SetMaintenanceModeRequestArgs {
   #[inline]
   fn default() -> Self {
-    GetCertStatusRequestArgs {
+    SetMaintenanceModeRequestArgs {
+      enabled: false,
     }
   }
 }
 
-pub struct GetCertStatusRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetMaintenanceModeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetCertStatusRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetMaintenanceModeRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetCertStatusRequestBuilder<'a, 'b, A> {
+  pub fn add_enabled(&mut self, enabled: bool) {
+    self.fbb_.push_slot::<bool>(SetMaintenanceModeRequest::VT_ENABLED, enabled, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetMaintenanceModeRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GetCertStatusRequestBuilder {
+    SetMaintenanceModeRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GetCertStatusRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetMaintenanceModeRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for GetCertStatusRequest<'_> {
+impl core::fmt::Debug for SetMaintenanceModeRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GetCertStatusRequest");
+    let mut ds = f.debug_struct("SetMaintenanceModeRequest");
+      ds.field("enabled", &self.enabled());
       ds.finish()
   }
 }
-pub enum CertStatusResponseOffset {}
+pub enum SetPowerProfileRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// Certificate status response.
-pub struct CertStatusResponse<'a> {
+/// Select a power profile, persisted with the system config.  Takes
+/// effect at once: sleep timeouts and the control-loop interval.
+pub struct SetPowerProfileRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for CertStatusResponse<'a> {
-  type Inner = CertStatusResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetPowerProfileRequest<'a> {
+  type Inner = SetPowerProfileRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> CertStatusResponse<'a> {
-  pub const VT_MODE: flatbuffers::VOffsetT = 4;
-  pub const VT_CA_FINGERPRINT: flatbuffers::VOffsetT = 6;
-  pub const VT_DEVICE_SERIAL: flatbuffers::VOffsetT = 8;
+impl<'a> SetPowerProfileRequest<'a> {
+  pub const VT_PROFILE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    CertStatusResponse { _tab: table }
+    SetPowerProfileRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args CertStatusResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<CertStatusResponse<'bldr>> {
-    let mut builder = CertStatusResponseBuilder::new(_fbb);
-    if let Some(x) = args.device_serial { builder.add_device_serial(x); }
-    if let Some(x) = args.ca_fingerprint { builder.add_ca_fingerprint(x); }
-    builder.add_mode(args.mode);
+    args: &'args SetPowerProfileRequestArgs
+  ) -> flatbuffers::WIPOffset<SetPowerProfileRequest<'bldr>> {
+    let mut builder = SetPowerProfileRequestBuilder::new(_fbb);
+    builder.add_profile(args.profile);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn mode(&self) -> TlsMode {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<TlsMode>(CertStatusResponse::VT_MODE, Some(TlsMode::PskOnly)).unwrap()}
-  }
-  /// SHA-256 fingerprint of the installed CA certificate.
-  #[inline]
-  pub fn ca_fingerprint(&self) -> Option<flatbuffers::Vector<'a, u8>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(CertStatusResponse::VT_CA_FINGERPRINT, None)}
-  }
-  /// Serial number from the device certificate.
-  #[inline]
-  pub fn device_serial(&self) -> Option<&'a str> {
+  pub fn profile(&self) -> PowerProfile {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CertStatusResponse::VT_DEVICE_SERIAL, None)}
+    unsafe { self._tab.get::<PowerProfile>(SetPowerProfileRequest::VT_PROFILE, Some(PowerProfile::Balanced)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for CertStatusResponse<'_> {
+impl flatbuffers::Verifiable for SetPowerProfileRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<TlsMode>("mode", Self::VT_MODE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("ca_fingerprint", Self::VT_CA_FINGERPRINT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_serial", Self::VT_DEVICE_SERIAL, false)?
+     .visit_field::<PowerProfile>("profile", Self::VT_PROFILE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct CertStatusResponseArgs<'a> {
-    pub mode: TlsMode,
-    pub ca_fingerprint: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
-    pub device_serial: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct SetPowerProfileRequestArgs {
+    pub profile: PowerProfile,
 }
-impl<'a> Default for CertStatusResponseArgs<'a> {
+impl<'a> Default for SetPowerProfileRequestArgs {
   #[inline]
   fn default() -> Self {
-    CertStatusResponseArgs {
-      mode: TlsMode::PskOnly,
-      ca_fingerprint: None,
-      device_serial: None,
+    SetPowerProfileRequestArgs {
+      profile: PowerProfile::Balanced,
     }
   }
 }
 
-pub struct CertStatusResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetPowerProfileRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CertStatusResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_mode(&mut self, mode: TlsMode) {
-    self.fbb_.push_slot::<TlsMode>(CertStatusResponse::VT_MODE, mode, TlsMode::PskOnly);
-  }
-  #[inline]
-  pub fn add_ca_fingerprint(&mut self, ca_fingerprint: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CertStatusResponse::VT_CA_FINGERPRINT, ca_fingerprint);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetPowerProfileRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_device_serial(&mut self, device_serial: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CertStatusResponse::VT_DEVICE_SERIAL, device_serial);
+  pub fn add_profile(&mut self, profile: PowerProfile) {
+    self.fbb_.push_slot::<PowerProfile>(SetPowerProfileRequest::VT_PROFILE, profile, PowerProfile::Balanced);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CertStatusResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetPowerProfileRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    CertStatusResponseBuilder {
+    SetPowerProfileRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<CertStatusResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetPowerProfileRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for CertStatusResponse<'_> {
+impl core::fmt::Debug for SetPowerProfileRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("CertStatusResponse");
-      ds.field("mode", &self.mode());
-      ds.field("ca_fingerprint", &self.ca_fingerprint());
-      ds.field("device_serial", &self.device_serial());
+    let mut ds = f.debug_struct("SetPowerProfileRequest");
+      ds.field("profile", &self.profile());
       ds.finish()
   }
 }
-pub enum OtaBeginRequestOffset {}
+pub enum SetJitterCompensationRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OtaBeginRequest<'a> {
+/// Control-loop jitter compensation, persisted with the system config.
+/// Timers and the flow PID run on the measured gap between control ticks,
+/// clamped to `max_dt_ms`; the measured flow can be low-pass filtered
+/// over the same gaps.
+pub struct SetJitterCompensationRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OtaBeginRequest<'a> {
-  type Inner = OtaBeginRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetJitterCompensationRequest<'a> {
+  type Inner = SetJitterCompensationRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> OtaBeginRequest<'a> {
-  pub const VT_FIRMWARE_SIZE: flatbuffers::VOffsetT = 4;
-  pub const VT_SHA256: flatbuffers::VOffsetT = 6;
-  pub const VT_VERSION: flatbuffers::VOffsetT = 8;
+impl<'a> SetJitterCompensationRequest<'a> {
+  pub const VT_MAX_DT_MS: flatbuffers::VOffsetT = 4;
+  pub const VT_FLOW_FILTER_TAU_MS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    OtaBeginRequest { _tab: table }
+    SetJitterCompensationRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args OtaBeginRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<OtaBeginRequest<'bldr>> {
-    let mut builder = OtaBeginRequestBuilder::new(_fbb);
-    builder.add_version(args.version);
-    if let Some(x) = args.sha256 { builder.add_sha256(x); }
-    builder.add_firmware_size(args.firmware_size);
+    args: &'args SetJitterCompensationRequestArgs
+  ) -> flatbuffers::WIPOffset<SetJitterCompensationRequest<'bldr>> {
+    let mut builder = SetJitterCompensationRequestBuilder::new(_fbb);
+    builder.add_max_dt_ms(args.max_dt_ms);
+    builder.add_flow_filter_tau_ms(args.flow_filter_tau_ms);
     builder.finish()
   }
 
 
+  /// Longest gap counted per tick (ms, 0 = always use the nominal interval).
   #[inline]
-  pub fn firmware_size(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaBeginRequest::VT_FIRMWARE_SIZE, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn sha256(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+  pub fn max_dt_ms(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(OtaBeginRequest::VT_SHA256, None)}
+    unsafe { self._tab.get::<u32>(SetJitterCompensationRequest::VT_MAX_DT_MS, Some(0)).unwrap()}
   }
-  /// Monotonic firmware version for anti-rollback checks.
+  /// Flow filter time constant (ms, 0 = unfiltered).
   #[inline]
-  pub fn version(&self) -> u32 {
+  pub fn flow_filter_tau_ms(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaBeginRequest::VT_VERSION, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u16>(SetJitterCompensationRequest::VT_FLOW_FILTER_TAU_MS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for OtaBeginRequest<'_> {
+impl flatbuffers::Verifiable for SetJitterCompensationRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u32>("firmware_size", Self::VT_FIRMWARE_SIZE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("sha256", Self::VT_SHA256, false)?
-     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u32>("max_dt_ms", Self::VT_MAX_DT_MS, false)?
+     .visit_field::<u16>("flow_filter_tau_ms", Self::VT_FLOW_FILTER_TAU_MS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct OtaBeginRequestArgs<'a> {
-    pub firmware_size: u32,
-    pub sha256: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
-    pub version: u32,
+pub struct SetJitterCompensationRequestArgs {
+    pub max_dt_ms: u32,
+    pub flow_filter_tau_ms: u16,
 }
-impl<'a> Default for OtaBeginRequestArgs<'a> {
+impl<'a> Default for SetJitterCompensationRequestArgs {
   #[inline]
   fn default() -> Self {
-    OtaBeginRequestArgs {
-      firmware_size: 0,
-      sha256: None,
-      version: 0,
+    SetJitterCompensationRequestArgs {
+      max_dt_ms: 0,
+      flow_filter_tau_ms: 0,
     }
   }
 }
 
-pub struct OtaBeginRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetJitterCompensationRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaBeginRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_firmware_size(&mut self, firmware_size: u32) {
-    self.fbb_.push_slot::<u32>(OtaBeginRequest::VT_FIRMWARE_SIZE, firmware_size, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetJitterCompensationRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_sha256(&mut self, sha256: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaBeginRequest::VT_SHA256, sha256);
+  pub fn add_max_dt_ms(&mut self, max_dt_ms: u32) {
+    self.fbb_.push_slot::<u32>(SetJitterCompensationRequest::VT_MAX_DT_MS, max_dt_ms, 0);
   }
   #[inline]
-  pub fn add_version(&mut self, version: u32) {
-    self.fbb_.push_slot::<u32>(OtaBeginRequest::VT_VERSION, version, 0);
+  pub fn add_flow_filter_tau_ms(&mut self, flow_filter_tau_ms: u16) {
+    self.fbb_.push_slot::<u16>(SetJitterCompensationRequest::VT_FLOW_FILTER_TAU_MS, flow_filter_tau_ms, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaBeginRequestBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetJitterCompensationRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    OtaBeginRequestBuilder {
+    SetJitterCompensationRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<OtaBeginRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetJitterCompensationRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for OtaBeginRequest<'_> {
+impl core::fmt::Debug for SetJitterCompensationRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("OtaBeginRequest");
-      ds.field("firmware_size", &self.firmware_size());
-      ds.field("sha256", &self.sha256());
-      ds.field("version", &self.version());
+    let mut ds = f.debug_struct("SetJitterCompensationRequest");
+      ds.field("max_dt_ms", &self.max_dt_ms());
+      ds.field("flow_filter_tau_ms", &self.flow_filter_tau_ms());
       ds.finish()
   }
 }
-pub enum OtaChunkRequestOffset {}
+pub enum SetTankSourceRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OtaChunkRequest<'a> {
+/// Choose which tank scrub cycles draw from, persisted with the system
+/// config.  Takes effect at the next cycle; a tank reading low is still
+/// passed over for the other.
+pub struct SetTankSourceRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OtaChunkRequest<'a> {
-  type Inner = OtaChunkRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetTankSourceRequest<'a> {
+  type Inner = SetTankSourceRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> OtaChunkRequest<'a> {
-  pub const VT_OFFSET: flatbuffers::VOffsetT = 4;
-  pub const VT_DATA: flatbuffers::VOffsetT = 6;
+impl<'a> SetTankSourceRequest<'a> {
+  pub const VT_SOURCE: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    OtaChunkRequest { _tab: table }
+    SetTankSourceRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args OtaChunkRequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<OtaChunkRequest<'bldr>> {
-    let mut builder = OtaChunkRequestBuilder::new(_fbb);
-    if let Some(x) = args.data { builder.add_data(x); }
-    builder.add_offset(args.offset);
+    args: &'args SetTankSourceRequestArgs
+  ) -> flatbuffers::WIPOffset<SetTankSourceRequest<'bldr>> {
+    let mut builder = SetTankSourceRequestBuilder::new(_fbb);
+    builder.add_source(args.source);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn offset(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaChunkRequest::VT_OFFSET, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+  pub fn source(&self) -> TankSource {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(OtaChunkRequest::VT_DATA, None)}
+    unsafe { self._tab.get::<TankSource>(SetTankSourceRequest::VT_SOURCE, Some(TankSource::TankA)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for OtaChunkRequest<'_> {
+impl flatbuffers::Verifiable for SetTankSourceRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u32>("offset", Self::VT_OFFSET, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .visit_field::<TankSource>("source", Self::VT_SOURCE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct OtaChunkRequestArgs<'a> {
-    pub offset: u32,
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+pub struct SetTankSourceRequestArgs {
+    pub source: TankSource,
 }
-impl<'a> Default for OtaChunkRequestArgs<'a> {
+impl<'a> Default for SetTankSourceRequestArgs {
   #[inline]
   fn default() -> Self {
-    OtaChunkRequestArgs {
-      offset: 0,
-      data: None,
+    SetTankSourceRequestArgs {
+      source: TankSource::TankA,
     }
   }
 }
 
-pub struct OtaChunkRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetTankSourceRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaChunkRequestBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_offset(&mut self, offset: u32) {
-    self.fbb_.push_slot::<u32>(OtaChunkRequest::VT_OFFSET, offset, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetTankSourceRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaChunkRequest::VT_DATA, data);
+  pub fn add_source(&mut self, source: TankSource) {
+    self.fbb_.push_slot::<TankSource>(SetTankSourceRequest::VT_SOURCE, source, TankSource::TankA);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaChunkRequestBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetTankSourceRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    OtaChunkRequestBuilder {
+    SetTankSourceRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<OtaChunkRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetTankSourceRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for OtaChunkRequest<'_> {
+impl core::fmt::Debug for SetTankSourceRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("OtaChunkRequest");
-      ds.field("offset", &self.offset());
-      ds.field("data", &self.data());
+    let mut ds = f.debug_struct("SetTankSourceRequest");
+      ds.field("source", &self.source());
       ds.finish()
   }
 }
-pub enum OtaFinalizeRequestOffset {}
+pub enum SetStateTimeoutRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OtaFinalizeRequest<'a> {
+/// Set the watchdog limit for one FSM state, persisted with the system
+/// config.  A state held longer than `max_secs` takes its escape
+/// transition; 0 removes the limit.
+pub struct SetStateTimeoutRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OtaFinalizeRequest<'a> {
-  type Inner = OtaFinalizeRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetStateTimeoutRequest<'a> {
+  type Inner = SetStateTimeoutRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> OtaFinalizeRequest<'a> {
+impl<'a> SetStateTimeoutRequest<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_MAX_SECS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    OtaFinalizeRequest { _tab: table }
+    SetStateTimeoutRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args OtaFinalizeRequestArgs
-  ) -> flatbuffers::WIPOffset<OtaFinalizeRequest<'bldr>> {
-    let mut builder = OtaFinalizeRequestBuilder::new(_fbb);
+    args: &'args SetStateTimeoutRequestArgs
+  ) -> flatbuffers::WIPOffset<SetStateTimeoutRequest<'bldr>> {
+    let mut builder = SetStateTimeoutRequestBuilder::new(_fbb);
+    builder.add_max_secs(args.max_secs);
+    builder.add_state(args.state);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn state(&self) -> DeviceState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceState>(SetStateTimeoutRequest::VT_STATE, Some(DeviceState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn max_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SetStateTimeoutRequest::VT_MAX_SECS, Some(0)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for OtaFinalizeRequest<'_> {
+impl flatbuffers::Verifiable for SetStateTimeoutRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
+     .visit_field::<u32>("max_secs", Self::VT_MAX_SECS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct OtaFinalizeRequestArgs {
+pub struct SetStateTimeoutRequestArgs {
+    pub state: DeviceState,
+    pub max_secs: u32,
 }
-impl<'a> Default for OtaFinalizeRequestArgs {
+impl<'a> Default for SetStateTimeoutRequestArgs {
   #[inline]
   fn default() -> Self {
-    OtaFinalizeRequestArgs {
+    SetStateTimeoutRequestArgs {
+      state: DeviceState::Idle,
+      max_secs: 0,
     }
   }
 }
 
-pub struct OtaFinalizeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetStateTimeoutRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaFinalizeRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetStateTimeoutRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaFinalizeRequestBuilder<'a, 'b, A> {
+  pub fn add_state(&mut self, state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(SetStateTimeoutRequest::VT_STATE, state, DeviceState::Idle);
+  }
+  #[inline]
+  pub fn add_max_secs(&mut self, max_secs: u32) {
+    self.fbb_.push_slot::<u32>(SetStateTimeoutRequest::VT_MAX_SECS, max_secs, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetStateTimeoutRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    OtaFinalizeRequestBuilder {
+    SetStateTimeoutRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<OtaFinalizeRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetStateTimeoutRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for OtaFinalizeRequest<'_> {
+impl core::fmt::Debug for SetStateTimeoutRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("OtaFinalizeRequest");
+    let mut ds = f.debug_struct("SetStateTimeoutRequest");
+      ds.field("state", &self.state());
+      ds.field("max_secs", &self.max_secs());
       ds.finish()
   }
 }
-pub enum OtaResponseOffset {}
+pub enum InjectSensorRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OtaResponse<'a> {
+/// Replace the NH3 reading with `nh3_ppm` for `duration_secs` (1–600)
+/// to walk the scrub cycle on site without real ammonia.  Rejected while
+/// a safety fault is active; cancelled if one is raised.
+pub struct InjectSensorRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OtaResponse<'a> {
-  type Inner = OtaResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for InjectSensorRequest<'a> {
+  type Inner = InjectSensorRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> OtaResponse<'a> {
-  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
-  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
-  pub const VT_BYTES_WRITTEN: flatbuffers::VOffsetT = 8;
+impl<'a> InjectSensorRequest<'a> {
+  pub const VT_NH3_PPM: flatbuffers::VOffsetT = 4;
+  pub const VT_DURATION_SECS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    OtaResponse { _tab: table }
+    InjectSensorRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args OtaResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<OtaResponse<'bldr>> {
-    let mut builder = OtaResponseBuilder::new(_fbb);
-    builder.add_bytes_written(args.bytes_written);
-    if let Some(x) = args.message { builder.add_message(x); }
-    builder.add_success(args.success);
+    args: &'args InjectSensorRequestArgs
+  ) -> flatbuffers::WIPOffset<InjectSensorRequest<'bldr>> {
+    let mut builder = InjectSensorRequestBuilder::new(_fbb);
+    builder.add_nh3_ppm(args.nh3_ppm);
+    builder.add_duration_secs(args.duration_secs);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn success(&self) -> bool {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(OtaResponse::VT_SUCCESS, Some(false)).unwrap()}
-  }
-  #[inline]
-  pub fn message(&self) -> Option<&'a str> {
+  pub fn nh3_ppm(&self) -> f32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(OtaResponse::VT_MESSAGE, None)}
+    unsafe { self._tab.get::<f32>(InjectSensorRequest::VT_NH3_PPM, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn bytes_written(&self) -> u32 {
+  pub fn duration_secs(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaResponse::VT_BYTES_WRITTEN, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u16>(InjectSensorRequest::VT_DURATION_SECS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for OtaResponse<'_> {
+impl flatbuffers::Verifiable for InjectSensorRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
-     .visit_field::<u32>("bytes_written", Self::VT_BYTES_WRITTEN, false)?
+     .visit_field::<f32>("nh3_ppm", Self::VT_NH3_PPM, false)?
+     .visit_field::<u16>("duration_secs", Self::VT_DURATION_SECS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct OtaResponseArgs<'a> {
-    pub success: bool,
-    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub bytes_written: u32,
+pub struct InjectSensorRequestArgs {
+    pub nh3_ppm: f32,
+    pub duration_secs: u16,
 }
-impl<'a> Default for OtaResponseArgs<'a> {
+impl<'a> Default for InjectSensorRequestArgs {
   #[inline]
   fn default() -> Self {
-    OtaResponseArgs {
-      success: false,
-      message: None,
-      bytes_written: 0,
+    InjectSensorRequestArgs {
+      nh3_ppm: 0.0,
+      duration_secs: 0,
     }
   }
 }
 
-pub struct OtaResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct InjectSensorRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_success(&mut self, success: bool) {
-    self.fbb_.push_slot::<bool>(OtaResponse::VT_SUCCESS, success, false);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InjectSensorRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaResponse::VT_MESSAGE, message);
+  pub fn add_nh3_ppm(&mut self, nh3_ppm: f32) {
+    self.fbb_.push_slot::<f32>(InjectSensorRequest::VT_NH3_PPM, nh3_ppm, 0.0);
   }
   #[inline]
-  pub fn add_bytes_written(&mut self, bytes_written: u32) {
-    self.fbb_.push_slot::<u32>(OtaResponse::VT_BYTES_WRITTEN, bytes_written, 0);
+  pub fn add_duration_secs(&mut self, duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(InjectSensorRequest::VT_DURATION_SECS, duration_secs, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InjectSensorRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    OtaResponseBuilder {
+    InjectSensorRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<OtaResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<InjectSensorRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for OtaResponse<'_> {
+impl core::fmt::Debug for InjectSensorRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("OtaResponse");
-      ds.field("success", &self.success());
-      ds.field("message", &self.message());
-      ds.field("bytes_written", &self.bytes_written());
+    let mut ds = f.debug_struct("InjectSensorRequest");
+      ds.field("nh3_ppm", &self.nh3_ppm());
+      ds.field("duration_secs", &self.duration_secs());
       ds.finish()
   }
 }
-pub enum OtaProgressEventOffset {}
+pub enum TestFaultRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// Async OTA progress event pushed to clients during firmware upload.
-pub struct OtaProgressEvent<'a> {
+/// Raise `fault_bit`, a single `fault_flags` bit, as a synthetic safety
+/// fault for `duration_secs` (1–300) to check the Error LED and fault
+/// handling without a real hazard.  Flagged in `StatusResponse.fault_test`;
+/// refused while a real fault is present or the device is in Error.
+pub struct TestFaultRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OtaProgressEvent<'a> {
-  type Inner = OtaProgressEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for TestFaultRequest<'a> {
+  type Inner = TestFaultRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> OtaProgressEvent<'a> {
-  pub const VT_BYTES_WRITTEN: flatbuffers::VOffsetT = 4;
-  pub const VT_TOTAL_BYTES: flatbuffers::VOffsetT = 6;
-  pub const VT_PERCENT: flatbuffers::VOffsetT = 8;
+impl<'a> TestFaultRequest<'a> {
+  pub const VT_FAULT_BIT: flatbuffers::VOffsetT = 4;
+  pub const VT_DURATION_SECS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    OtaProgressEvent { _tab: table }
+    TestFaultRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args OtaProgressEventArgs
-  ) -> flatbuffers::WIPOffset<OtaProgressEvent<'bldr>> {
-    let mut builder = OtaProgressEventBuilder::new(_fbb);
-    builder.add_total_bytes(args.total_bytes);
-    builder.add_bytes_written(args.bytes_written);
-    builder.add_percent(args.percent);
+    args: &'args TestFaultRequestArgs
+  ) -> flatbuffers::WIPOffset<TestFaultRequest<'bldr>> {
+    let mut builder = TestFaultRequestBuilder::new(_fbb);
+    builder.add_duration_secs(args.duration_secs);
+    builder.add_fault_bit(args.fault_bit);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn bytes_written(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaProgressEvent::VT_BYTES_WRITTEN, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn total_bytes(&self) -> u32 {
+  pub fn fault_bit(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(OtaProgressEvent::VT_TOTAL_BYTES, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(TestFaultRequest::VT_FAULT_BIT, Some(0)).unwrap()}
   }
-  /// 0-100 percent complete.
   #[inline]
-  pub fn percent(&self) -> u8 {
+  pub fn duration_secs(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(OtaProgressEvent::VT_PERCENT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u16>(TestFaultRequest::VT_DURATION_SECS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for OtaProgressEvent<'_> {
+impl flatbuffers::Verifiable for TestFaultRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u32>("bytes_written", Self::VT_BYTES_WRITTEN, false)?
-     .visit_field::<u32>("total_bytes", Self::VT_TOTAL_BYTES, false)?
-     .visit_field::<u8>("percent", Self::VT_PERCENT, false)?
+     .visit_field::<u8>("fault_bit", Self::VT_FAULT_BIT, false)?
+     .visit_field::<u16>("duration_secs", Self::VT_DURATION_SECS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct OtaProgressEventArgs {
-    pub bytes_written: u32,
-    pub total_bytes: u32,
-    pub percent: u8,
+pub struct TestFaultRequestArgs {
+    pub fault_bit: u8,
+    pub duration_secs: u16,
 }
-impl<'a> Default for OtaProgressEventArgs {
+impl<'a> Default for TestFaultRequestArgs {
   #[inline]
   fn default() -> Self {
-    OtaProgressEventArgs {
-      bytes_written: 0,
-      total_bytes: 0,
-      percent: 0,
+    TestFaultRequestArgs {
+      fault_bit: 0,
+      duration_secs: 0,
     }
   }
 }
 
-pub struct OtaProgressEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TestFaultRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaProgressEventBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_bytes_written(&mut self, bytes_written: u32) {
-    self.fbb_.push_slot::<u32>(OtaProgressEvent::VT_BYTES_WRITTEN, bytes_written, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TestFaultRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_total_bytes(&mut self, total_bytes: u32) {
-    self.fbb_.push_slot::<u32>(OtaProgressEvent::VT_TOTAL_BYTES, total_bytes, 0);
+  pub fn add_fault_bit(&mut self, fault_bit: u8) {
+    self.fbb_.push_slot::<u8>(TestFaultRequest::VT_FAULT_BIT, fault_bit, 0);
   }
   #[inline]
-  pub fn add_percent(&mut self, percent: u8) {
-    self.fbb_.push_slot::<u8>(OtaProgressEvent::VT_PERCENT, percent, 0);
+  pub fn add_duration_secs(&mut self, duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(TestFaultRequest::VT_DURATION_SECS, duration_secs, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaProgressEventBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TestFaultRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    OtaProgressEventBuilder {
+    TestFaultRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<OtaProgressEvent<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TestFaultRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for OtaProgressEvent<'_> {
+impl core::fmt::Debug for TestFaultRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("OtaProgressEvent");
-      ds.field("bytes_written", &self.bytes_written());
-      ds.field("total_bytes", &self.total_bytes());
-      ds.field("percent", &self.percent());
+    let mut ds = f.debug_struct("TestFaultRequest");
+      ds.field("fault_bit", &self.fault_bit());
+      ds.field("duration_secs", &self.duration_secs());
       ds.finish()
   }
 }
-pub enum GetDiagnosticsRequestOffset {}
+pub enum AutoTuneRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GetDiagnosticsRequest<'a> {
+/// Drive the relay-feedback PID auto-tune of the pump flow loop.
+/// `Start` needs a running scrub; `Confirm` applies a completed
+/// suggestion to the flow PID and saves it with the system config;
+/// `Cancel` aborts a run or discards its suggestion.  Those three reply
+/// with an ack; `Status` replies with `AutoTuneResponse`.
+pub struct AutoTuneRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GetDiagnosticsRequest<'a> {
-  type Inner = GetDiagnosticsRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for AutoTuneRequest<'a> {
+  type Inner = AutoTuneRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> GetDiagnosticsRequest<'a> {
+impl<'a> AutoTuneRequest<'a> {
+  pub const VT_ACTION: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GetDiagnosticsRequest { _tab: table }
+    AutoTuneRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args GetDiagnosticsRequestArgs
-  ) -> flatbuffers::WIPOffset<GetDiagnosticsRequest<'bldr>> {
-    let mut builder = GetDiagnosticsRequestBuilder::new(_fbb);
+    args: &'args AutoTuneRequestArgs
+  ) -> flatbuffers::WIPOffset<AutoTuneRequest<'bldr>> {
+    let mut builder = AutoTuneRequestBuilder::new(_fbb);
+    builder.add_action(args.action);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn action(&self) -> AutoTuneAction {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AutoTuneAction>(AutoTuneRequest::VT_ACTION, Some(AutoTuneAction::Status)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for GetDiagnosticsRequest<'_> {
+impl flatbuffers::Verifiable for AutoTuneRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<AutoTuneAction>("action", Self::VT_ACTION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GetDiagnosticsRequestArgs {
+pub struct AutoTuneRequestArgs {
+    pub action: AutoTuneAction,
 }
-impl<'a> Default for GetDiagnosticsRequestArgs {
+impl<'a> Default for AutoTuneRequestArgs {
   #[inline]
   fn default() -> Self {
-    GetDiagnosticsRequestArgs {
+    AutoTuneRequestArgs {
+      action: AutoTuneAction::Status,
     }
   }
 }
 
-pub struct GetDiagnosticsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AutoTuneRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetDiagnosticsRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AutoTuneRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetDiagnosticsRequestBuilder<'a, 'b, A> {
+  pub fn add_action(&mut self, action: AutoTuneAction) {
+    self.fbb_.push_slot::<AutoTuneAction>(AutoTuneRequest::VT_ACTION, action, AutoTuneAction::Status);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AutoTuneRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GetDiagnosticsRequestBuilder {
+    AutoTuneRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GetDiagnosticsRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AutoTuneRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for GetDiagnosticsRequest<'_> {
+impl core::fmt::Debug for AutoTuneRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GetDiagnosticsRequest");
+    let mut ds = f.debug_struct("AutoTuneRequest");
+      ds.field("action", &self.action());
       ds.finish()
   }
 }
-pub enum ClearDiagnosticsRequestOffset {}
+pub enum AutoTuneResponseOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ClearDiagnosticsRequest<'a> {
+/// Auto-tune progress and, once `Complete`, the suggested gains.
+pub struct AutoTuneResponse<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ClearDiagnosticsRequest<'a> {
-  type Inner = ClearDiagnosticsRequest<'a>;
+impl<'a> flatbuffers::Follow<'a> for AutoTuneResponse<'a> {
+  type Inner = AutoTuneResponse<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ClearDiagnosticsRequest<'a> {
+impl<'a> AutoTuneResponse<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_ELAPSED_SECS: flatbuffers::VOffsetT = 6;
+  pub const VT_ULTIMATE_GAIN: flatbuffers::VOffsetT = 8;
+  pub const VT_ULTIMATE_PERIOD_SECS: flatbuffers::VOffsetT = 10;
+  pub const VT_KP: flatbuffers::VOffsetT = 12;
+  pub const VT_KI: flatbuffers::VOffsetT = 14;
+  pub const VT_KD: flatbuffers::VOffsetT = 16;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ClearDiagnosticsRequest { _tab: table }
+    AutoTuneResponse { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args ClearDiagnosticsRequestArgs
-  ) -> flatbuffers::WIPOffset<ClearDiagnosticsRequest<'bldr>> {
-    let mut builder = ClearDiagnosticsRequestBuilder::new(_fbb);
+    args: &'args AutoTuneResponseArgs
+  ) -> flatbuffers::WIPOffset<AutoTuneResponse<'bldr>> {
+    let mut builder = AutoTuneResponseBuilder::new(_fbb);
+    builder.add_kd(args.kd);
+    builder.add_ki(args.ki);
+    builder.add_kp(args.kp);
+    builder.add_ultimate_period_secs(args.ultimate_period_secs);
+    builder.add_ultimate_gain(args.ultimate_gain);
+    builder.add_elapsed_secs(args.elapsed_secs);
+    builder.add_state(args.state);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn state(&self) -> AutoTuneState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AutoTuneState>(AutoTuneResponse::VT_STATE, Some(AutoTuneState::Idle)).unwrap()}
+  }
+  /// Seconds into the running relay experiment.
+  #[inline]
+  pub fn elapsed_secs(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_ELAPSED_SECS, Some(0.0)).unwrap()}
+  }
+  /// Critical gain Ku measured by the relay experiment.
+  #[inline]
+  pub fn ultimate_gain(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_ULTIMATE_GAIN, Some(0.0)).unwrap()}
+  }
+  /// Critical oscillation period Tu, in seconds.
+  #[inline]
+  pub fn ultimate_period_secs(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_ULTIMATE_PERIOD_SECS, Some(0.0)).unwrap()}
+  }
+  /// Suggested Ziegler–Nichols gains, not applied until confirmed.
+  #[inline]
+  pub fn kp(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_KP, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn ki(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_KI, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn kd(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AutoTuneResponse::VT_KD, Some(0.0)).unwrap()}
+  }
 }
 
-impl flatbuffers::Verifiable for ClearDiagnosticsRequest<'_> {
+impl flatbuffers::Verifiable for AutoTuneResponse<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<AutoTuneState>("state", Self::VT_STATE, false)?
+     .visit_field::<f32>("elapsed_secs", Self::VT_ELAPSED_SECS, false)?
+     .visit_field::<f32>("ultimate_gain", Self::VT_ULTIMATE_GAIN, false)?
+     .visit_field::<f32>("ultimate_period_secs", Self::VT_ULTIMATE_PERIOD_SECS, false)?
+     .visit_field::<f32>("kp", Self::VT_KP, false)?
+     .visit_field::<f32>("ki", Self::VT_KI, false)?
+     .visit_field::<f32>("kd", Self::VT_KD, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ClearDiagnosticsRequestArgs {
+pub struct AutoTuneResponseArgs {
+    pub state: AutoTuneState,
+    pub elapsed_secs: f32,
+    pub ultimate_gain: f32,
+    pub ultimate_period_secs: f32,
+    pub kp: f32,
+    pub ki: f32,
+    pub kd: f32,
 }
-impl<'a> Default for ClearDiagnosticsRequestArgs {
+impl<'a> Default for AutoTuneResponseArgs {
   #[inline]
   fn default() -> Self {
-    ClearDiagnosticsRequestArgs {
+    AutoTuneResponseArgs {
+      state: AutoTuneState::Idle,
+      elapsed_secs: 0.0,
+      ultimate_gain: 0.0,
+      ultimate_period_secs: 0.0,
+      kp: 0.0,
+      ki: 0.0,
+      kd: 0.0,
     }
   }
 }
 
-pub struct ClearDiagnosticsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AutoTuneResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClearDiagnosticsRequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AutoTuneResponseBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClearDiagnosticsRequestBuilder<'a, 'b, A> {
+  pub fn add_state(&mut self, state: AutoTuneState) {
+    self.fbb_.push_slot::<AutoTuneState>(AutoTuneResponse::VT_STATE, state, AutoTuneState::Idle);
+  }
+  #[inline]
+  pub fn add_elapsed_secs(&mut self, elapsed_secs: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_ELAPSED_SECS, elapsed_secs, 0.0);
+  }
+  #[inline]
+  pub fn add_ultimate_gain(&mut self, ultimate_gain: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_ULTIMATE_GAIN, ultimate_gain, 0.0);
+  }
+  #[inline]
+  pub fn add_ultimate_period_secs(&mut self, ultimate_period_secs: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_ULTIMATE_PERIOD_SECS, ultimate_period_secs, 0.0);
+  }
+  #[inline]
+  pub fn add_kp(&mut self, kp: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_KP, kp, 0.0);
+  }
+  #[inline]
+  pub fn add_ki(&mut self, ki: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_KI, ki, 0.0);
+  }
+  #[inline]
+  pub fn add_kd(&mut self, kd: f32) {
+    self.fbb_.push_slot::<f32>(AutoTuneResponse::VT_KD, kd, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AutoTuneResponseBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ClearDiagnosticsRequestBuilder {
+    AutoTuneResponseBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ClearDiagnosticsRequest<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AutoTuneResponse<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for ClearDiagnosticsRequest<'_> {
+impl core::fmt::Debug for AutoTuneResponse<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ClearDiagnosticsRequest");
+    let mut ds = f.debug_struct("AutoTuneResponse");
+      ds.field("state", &self.state());
+      ds.field("elapsed_secs", &self.elapsed_secs());
+      ds.field("ultimate_gain", &self.ultimate_gain());
+      ds.field("ultimate_period_secs", &self.ultimate_period_secs());
+      ds.field("kp", &self.kp());
+      ds.field("ki", &self.ki());
+      ds.field("kd", &self.kd());
       ds.finish()
   }
 }
-pub enum CrashEntryFbsOffset {}
+pub enum RestartDeviceRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct CrashEntryFbs<'a> {
+/// Reboot after `delay_ms` (max 60 000) so the ack can drain first.
+/// Refused while an OTA transfer is in progress.
+pub struct RestartDeviceRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for CrashEntryFbs<'a> {
-  type Inner = CrashEntryFbs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RestartDeviceRequest<'a> {
+  type Inner = RestartDeviceRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> CrashEntryFbs<'a> {
-  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 4;
-  pub const VT_REASON: flatbuffers::VOffsetT = 6;
-  pub const VT_PC: flatbuffers::VOffsetT = 8;
-  pub const VT_BACKTRACE: flatbuffers::VOffsetT = 10;
+impl<'a> RestartDeviceRequest<'a> {
+  pub const VT_DELAY_MS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    CrashEntryFbs { _tab: table }
+    RestartDeviceRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args CrashEntryFbsArgs<'args>
-  ) -> flatbuffers::WIPOffset<CrashEntryFbs<'bldr>> {
-    let mut builder = CrashEntryFbsBuilder::new(_fbb);
-    builder.add_uptime_secs(args.uptime_secs);
-    if let Some(x) = args.backtrace { builder.add_backtrace(x); }
-    builder.add_pc(args.pc);
-    if let Some(x) = args.reason { builder.add_reason(x); }
+    args: &'args RestartDeviceRequestArgs
+  ) -> flatbuffers::WIPOffset<RestartDeviceRequest<'bldr>> {
+    let mut builder = RestartDeviceRequestBuilder::new(_fbb);
+    builder.add_delay_ms(args.delay_ms);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn uptime_secs(&self) -> u64 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(CrashEntryFbs::VT_UPTIME_SECS, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn reason(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CrashEntryFbs::VT_REASON, None)}
-  }
-  #[inline]
-  pub fn pc(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(CrashEntryFbs::VT_PC, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn backtrace(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+  pub fn delay_ms(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(CrashEntryFbs::VT_BACKTRACE, None)}
+    unsafe { self._tab.get::<u32>(RestartDeviceRequest::VT_DELAY_MS, Some(1000)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for CrashEntryFbs<'_> {
+impl flatbuffers::Verifiable for RestartDeviceRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reason", Self::VT_REASON, false)?
-     .visit_field::<u32>("pc", Self::VT_PC, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("backtrace", Self::VT_BACKTRACE, false)?
+     .visit_field::<u32>("delay_ms", Self::VT_DELAY_MS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct CrashEntryFbsArgs<'a> {
-    pub uptime_secs: u64,
-    pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub pc: u32,
-    pub backtrace: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct RestartDeviceRequestArgs {
+    pub delay_ms: u32,
 }
-impl<'a> Default for CrashEntryFbsArgs<'a> {
+impl<'a> Default for RestartDeviceRequestArgs {
   #[inline]
   fn default() -> Self {
-    CrashEntryFbsArgs {
-      uptime_secs: 0,
-      reason: None,
-      pc: 0,
-      backtrace: None,
+    RestartDeviceRequestArgs {
+      delay_ms: 1000,
     }
   }
 }
 
-pub struct CrashEntryFbsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RestartDeviceRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CrashEntryFbsBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
-    self.fbb_.push_slot::<u64>(CrashEntryFbs::VT_UPTIME_SECS, uptime_secs, 0);
-  }
-  #[inline]
-  pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CrashEntryFbs::VT_REASON, reason);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RestartDeviceRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_pc(&mut self, pc: u32) {
-    self.fbb_.push_slot::<u32>(CrashEntryFbs::VT_PC, pc, 0);
-  }
-  #[inline]
-  pub fn add_backtrace(&mut self, backtrace: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CrashEntryFbs::VT_BACKTRACE, backtrace);
+  pub fn add_delay_ms(&mut self, delay_ms: u32) {
+    self.fbb_.push_slot::<u32>(RestartDeviceRequest::VT_DELAY_MS, delay_ms, 1000);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CrashEntryFbsBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RestartDeviceRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    CrashEntryFbsBuilder {
+    RestartDeviceRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<CrashEntryFbs<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<RestartDeviceRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for CrashEntryFbs<'_> {
+impl core::fmt::Debug for RestartDeviceRequest<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("CrashEntryFbs");
-      ds.field("uptime_secs", &self.uptime_secs());
-      ds.field("reason", &self.reason());
-      ds.field("pc", &self.pc());
-      ds.field("backtrace", &self.backtrace());
+    let mut ds = f.debug_struct("RestartDeviceRequest");
+      ds.field("delay_ms", &self.delay_ms());
       ds.finish()
   }
 }
-pub enum DiagnosticsResponseOffset {}
+pub enum FactoryResetRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DiagnosticsResponse<'a> {
+/// Erase stored credentials and restart — the remote equivalent of the
+/// button long-press.  `confirm_serial` must match the device serial
+/// (`DeviceInfoResponse.serial_number`) or the request is refused.
+pub struct FactoryResetRequest<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for DiagnosticsResponse<'a> {
-  type Inner = DiagnosticsResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for FactoryResetRequest<'a> {
+  type Inner = FactoryResetRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> DiagnosticsResponse<'a> {
-  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 4;
-  pub const VT_CONTROL_CYCLES: flatbuffers::VOffsetT = 6;
-  pub const VT_FAULT_COUNT: flatbuffers::VOffsetT = 8;
-  pub const VT_CRASH_COUNT: flatbuffers::VOffsetT = 10;
-  pub const VT_HEAP_FREE: flatbuffers::VOffsetT = 12;
-  pub const VT_HEAP_MIN_FREE: flatbuffers::VOffsetT = 14;
-  pub const VT_WIFI_RSSI: flatbuffers::VOffsetT = 16;
-  pub const VT_NVS_FREE_ENTRIES: flatbuffers::VOffsetT = 18;
-  pub const VT_ULP_WAKE_COUNT: flatbuffers::VOffsetT = 20;
-  pub const VT_CRASH_ENTRIES: flatbuffers::VOffsetT = 22;
+impl<'a> FactoryResetRequest<'a> {
+  pub const VT_CONFIRM_SERIAL: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    DiagnosticsResponse { _tab: table }
+    FactoryResetRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DiagnosticsResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<DiagnosticsResponse<'bldr>> {
-    let mut builder = DiagnosticsResponseBuilder::new(_fbb);
-    builder.add_control_cycles(args.control_cycles);
-    builder.add_uptime_secs(args.uptime_secs);
-    if let Some(x) = args.crash_entries { builder.add_crash_entries(x); }
-    builder.add_ulp_wake_count(args.ulp_wake_count);
-    builder.add_nvs_free_entries(args.nvs_free_entries);
-    builder.add_heap_min_free(args.heap_min_free);
-    builder.add_heap_free(args.heap_free);
-    builder.add_crash_count(args.crash_count);
-    builder.add_fault_count(args.fault_count);
-    builder.add_wifi_rssi(args.wifi_rssi);
+    args: &'args FactoryResetRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<FactoryResetRequest<'bldr>> {
+    let mut builder = FactoryResetRequestBuilder::new(_fbb);
+    if let Some(x) = args.confirm_serial { builder.add_confirm_serial(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn uptime_secs(&self) -> u64 {
+  pub fn confirm_serial(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FactoryResetRequest::VT_CONFIRM_SERIAL, None)}
   }
+}
+
+impl flatbuffers::Verifiable for FactoryResetRequest<'_> {
   #[inline]
-  pub fn control_cycles(&self) -> u64 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_CONTROL_CYCLES, Some(0)).unwrap()}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("confirm_serial", Self::VT_CONFIRM_SERIAL, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct FactoryResetRequestArgs<'a> {
+    pub confirm_serial: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FactoryResetRequestArgs<'a> {
   #[inline]
-  pub fn fault_count(&self) -> u32 {
-    // Safety:
+  fn default() -> Self {
+    FactoryResetRequestArgs {
+      confirm_serial: None,
+    }
+  }
+}
+
+pub struct FactoryResetRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FactoryResetRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_confirm_serial(&mut self, confirm_serial: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FactoryResetRequest::VT_CONFIRM_SERIAL, confirm_serial);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FactoryResetRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FactoryResetRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FactoryResetRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FactoryResetRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FactoryResetRequest");
+      ds.field("confirm_serial", &self.confirm_serial());
+      ds.finish()
+  }
+}
+pub enum SetInstallIdRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Replace the persisted install id, e.g. to carry a replaced board's
+/// identity over.  `install_id` is a hyphenated UUID; anything else,
+/// and the nil UUID, is refused.
+pub struct SetInstallIdRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetInstallIdRequest<'a> {
+  type Inner = SetInstallIdRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetInstallIdRequest<'a> {
+  pub const VT_INSTALL_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetInstallIdRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetInstallIdRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<SetInstallIdRequest<'bldr>> {
+    let mut builder = SetInstallIdRequestBuilder::new(_fbb);
+    if let Some(x) = args.install_id { builder.add_install_id(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn install_id(&self) -> Option<&'a str> {
+    // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_FAULT_COUNT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetInstallIdRequest::VT_INSTALL_ID, None)}
   }
+}
+
+impl flatbuffers::Verifiable for SetInstallIdRequest<'_> {
   #[inline]
-  pub fn crash_count(&self) -> u32 {
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("install_id", Self::VT_INSTALL_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetInstallIdRequestArgs<'a> {
+    pub install_id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SetInstallIdRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SetInstallIdRequestArgs {
+      install_id: None,
+    }
+  }
+}
+
+pub struct SetInstallIdRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetInstallIdRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_install_id(&mut self, install_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetInstallIdRequest::VT_INSTALL_ID, install_id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetInstallIdRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetInstallIdRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetInstallIdRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetInstallIdRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetInstallIdRequest");
+      ds.field("install_id", &self.install_id());
+      ds.finish()
+  }
+}
+pub enum SubscribeTelemetryRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SubscribeTelemetryRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SubscribeTelemetryRequest<'a> {
+  type Inner = SubscribeTelemetryRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SubscribeTelemetryRequest<'a> {
+  pub const VT_INTERVAL_MS: flatbuffers::VOffsetT = 4;
+  pub const VT_ACCEPT_COMPRESSION: flatbuffers::VOffsetT = 6;
+  pub const VT_DELTA: flatbuffers::VOffsetT = 8;
+  pub const VT_KEYFRAME_INTERVAL: flatbuffers::VOffsetT = 10;
+  pub const VT_PACKED: flatbuffers::VOffsetT = 12;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SubscribeTelemetryRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SubscribeTelemetryRequestArgs
+  ) -> flatbuffers::WIPOffset<SubscribeTelemetryRequest<'bldr>> {
+    let mut builder = SubscribeTelemetryRequestBuilder::new(_fbb);
+    builder.add_interval_ms(args.interval_ms);
+    builder.add_keyframe_interval(args.keyframe_interval);
+    builder.add_zone(args.zone);
+    builder.add_packed(args.packed);
+    builder.add_delta(args.delta);
+    builder.add_accept_compression(args.accept_compression);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn interval_ms(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_CRASH_COUNT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(SubscribeTelemetryRequest::VT_INTERVAL_MS, Some(1000)).unwrap()}
   }
+  /// Client can inflate DEFLATE frames; telemetry may then arrive
+  /// with the frame header's compressed flag set.
   #[inline]
-  pub fn heap_free(&self) -> u32 {
+  pub fn accept_compression(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_HEAP_FREE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<bool>(SubscribeTelemetryRequest::VT_ACCEPT_COMPRESSION, Some(false)).unwrap()}
   }
+  /// After a first full frame, send only fields that changed, flagged
+  /// in `TelemetryFrame.present_fields`.
   #[inline]
-  pub fn heap_min_free(&self) -> u32 {
+  pub fn delta(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_HEAP_MIN_FREE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<bool>(SubscribeTelemetryRequest::VT_DELTA, Some(false)).unwrap()}
+  }
+  /// Delta mode: every this many frames is a full keyframe.
+  #[inline]
+  pub fn keyframe_interval(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(SubscribeTelemetryRequest::VT_KEYFRAME_INTERVAL, Some(10)).unwrap()}
+  }
+  #[inline]
+  pub fn packed(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubscribeTelemetryRequest::VT_PACKED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SubscribeTelemetryRequest::VT_ZONE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SubscribeTelemetryRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("interval_ms", Self::VT_INTERVAL_MS, false)?
+     .visit_field::<bool>("accept_compression", Self::VT_ACCEPT_COMPRESSION, false)?
+     .visit_field::<bool>("delta", Self::VT_DELTA, false)?
+     .visit_field::<u16>("keyframe_interval", Self::VT_KEYFRAME_INTERVAL, false)?
+     .visit_field::<bool>("packed", Self::VT_PACKED, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SubscribeTelemetryRequestArgs {
+    pub interval_ms: u32,
+    pub accept_compression: bool,
+    pub delta: bool,
+    pub keyframe_interval: u16,
+    pub packed: bool,
+    pub zone: u8,
+}
+impl<'a> Default for SubscribeTelemetryRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SubscribeTelemetryRequestArgs {
+      interval_ms: 1000,
+      accept_compression: false,
+      delta: false,
+      keyframe_interval: 10,
+      packed: false,
+      zone: 0,
+    }
+  }
+}
+
+pub struct SubscribeTelemetryRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SubscribeTelemetryRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_interval_ms(&mut self, interval_ms: u32) {
+    self.fbb_.push_slot::<u32>(SubscribeTelemetryRequest::VT_INTERVAL_MS, interval_ms, 1000);
+  }
+  #[inline]
+  pub fn add_accept_compression(&mut self, accept_compression: bool) {
+    self.fbb_.push_slot::<bool>(SubscribeTelemetryRequest::VT_ACCEPT_COMPRESSION, accept_compression, false);
+  }
+  #[inline]
+  pub fn add_delta(&mut self, delta: bool) {
+    self.fbb_.push_slot::<bool>(SubscribeTelemetryRequest::VT_DELTA, delta, false);
+  }
+  #[inline]
+  pub fn add_keyframe_interval(&mut self, keyframe_interval: u16) {
+    self.fbb_.push_slot::<u16>(SubscribeTelemetryRequest::VT_KEYFRAME_INTERVAL, keyframe_interval, 10);
+  }
+  #[inline]
+  pub fn add_packed(&mut self, packed: bool) {
+    self.fbb_.push_slot::<bool>(SubscribeTelemetryRequest::VT_PACKED, packed, false);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(SubscribeTelemetryRequest::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SubscribeTelemetryRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SubscribeTelemetryRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SubscribeTelemetryRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SubscribeTelemetryRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SubscribeTelemetryRequest");
+      ds.field("interval_ms", &self.interval_ms());
+      ds.field("accept_compression", &self.accept_compression());
+      ds.field("delta", &self.delta());
+      ds.field("keyframe_interval", &self.keyframe_interval());
+      ds.field("packed", &self.packed());
+      ds.field("zone", &self.zone());
+      ds.finish()
+  }
+}
+pub enum UnsubscribeTelemetryRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UnsubscribeTelemetryRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UnsubscribeTelemetryRequest<'a> {
+  type Inner = UnsubscribeTelemetryRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> UnsubscribeTelemetryRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    UnsubscribeTelemetryRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args UnsubscribeTelemetryRequestArgs
+  ) -> flatbuffers::WIPOffset<UnsubscribeTelemetryRequest<'bldr>> {
+    let mut builder = UnsubscribeTelemetryRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for UnsubscribeTelemetryRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UnsubscribeTelemetryRequestArgs {
+}
+impl<'a> Default for UnsubscribeTelemetryRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    UnsubscribeTelemetryRequestArgs {
+    }
+  }
+}
+
+pub struct UnsubscribeTelemetryRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UnsubscribeTelemetryRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> UnsubscribeTelemetryRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    UnsubscribeTelemetryRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<UnsubscribeTelemetryRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for UnsubscribeTelemetryRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("UnsubscribeTelemetryRequest");
+      ds.finish()
+  }
+}
+pub enum GetDeviceInfoRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetDeviceInfoRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetDeviceInfoRequest<'a> {
+  type Inner = GetDeviceInfoRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetDeviceInfoRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetDeviceInfoRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args GetDeviceInfoRequestArgs
+  ) -> flatbuffers::WIPOffset<GetDeviceInfoRequest<'bldr>> {
+    let mut builder = GetDeviceInfoRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for GetDeviceInfoRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetDeviceInfoRequestArgs {
+}
+impl<'a> Default for GetDeviceInfoRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetDeviceInfoRequestArgs {
+    }
+  }
+}
+
+pub struct GetDeviceInfoRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetDeviceInfoRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetDeviceInfoRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetDeviceInfoRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetDeviceInfoRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetDeviceInfoRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetDeviceInfoRequest");
+      ds.finish()
+  }
+}
+pub enum PingRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Liveness / latency probe.  `client_timestamp` is opaque to the
+/// device and echoed back unchanged, so the client can compute RTT
+/// (and, with `device_uptime_ms`, clock skew) in its own time base.
+pub struct PingRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PingRequest<'a> {
+  type Inner = PingRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PingRequest<'a> {
+  pub const VT_CLIENT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PingRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PingRequestArgs
+  ) -> flatbuffers::WIPOffset<PingRequest<'bldr>> {
+    let mut builder = PingRequestBuilder::new(_fbb);
+    builder.add_client_timestamp(args.client_timestamp);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn client_timestamp(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PingRequest::VT_CLIENT_TIMESTAMP, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PingRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("client_timestamp", Self::VT_CLIENT_TIMESTAMP, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PingRequestArgs {
+    pub client_timestamp: u64,
+}
+impl<'a> Default for PingRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    PingRequestArgs {
+      client_timestamp: 0,
+    }
+  }
+}
+
+pub struct PingRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PingRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_client_timestamp(&mut self, client_timestamp: u64) {
+    self.fbb_.push_slot::<u64>(PingRequest::VT_CLIENT_TIMESTAMP, client_timestamp, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PingRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PingRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PingRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PingRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PingRequest");
+      ds.field("client_timestamp", &self.client_timestamp());
+      ds.finish()
+  }
+}
+pub enum PongResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PongResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PongResponse<'a> {
+  type Inner = PongResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PongResponse<'a> {
+  pub const VT_CLIENT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_DEVICE_UPTIME_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PongResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PongResponseArgs
+  ) -> flatbuffers::WIPOffset<PongResponse<'bldr>> {
+    let mut builder = PongResponseBuilder::new(_fbb);
+    builder.add_device_uptime_ms(args.device_uptime_ms);
+    builder.add_client_timestamp(args.client_timestamp);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn client_timestamp(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PongResponse::VT_CLIENT_TIMESTAMP, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn device_uptime_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PongResponse::VT_DEVICE_UPTIME_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PongResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("client_timestamp", Self::VT_CLIENT_TIMESTAMP, false)?
+     .visit_field::<u64>("device_uptime_ms", Self::VT_DEVICE_UPTIME_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PongResponseArgs {
+    pub client_timestamp: u64,
+    pub device_uptime_ms: u64,
+}
+impl<'a> Default for PongResponseArgs {
+  #[inline]
+  fn default() -> Self {
+    PongResponseArgs {
+      client_timestamp: 0,
+      device_uptime_ms: 0,
+    }
+  }
+}
+
+pub struct PongResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PongResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_client_timestamp(&mut self, client_timestamp: u64) {
+    self.fbb_.push_slot::<u64>(PongResponse::VT_CLIENT_TIMESTAMP, client_timestamp, 0);
+  }
+  #[inline]
+  pub fn add_device_uptime_ms(&mut self, device_uptime_ms: u64) {
+    self.fbb_.push_slot::<u64>(PongResponse::VT_DEVICE_UPTIME_MS, device_uptime_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PongResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PongResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PongResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PongResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PongResponse");
+      ds.field("client_timestamp", &self.client_timestamp());
+      ds.field("device_uptime_ms", &self.device_uptime_ms());
+      ds.finish()
+  }
+}
+pub enum GetCapabilitiesRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Feature discovery; like `GetDeviceInfoRequest`, allowed before
+/// authentication.
+pub struct GetCapabilitiesRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetCapabilitiesRequest<'a> {
+  type Inner = GetCapabilitiesRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetCapabilitiesRequest<'a> {
+  pub const VT_SKIP: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetCapabilitiesRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetCapabilitiesRequestArgs
+  ) -> flatbuffers::WIPOffset<GetCapabilitiesRequest<'bldr>> {
+    let mut builder = GetCapabilitiesRequestBuilder::new(_fbb);
+    builder.add_skip(args.skip);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn skip(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetCapabilitiesRequest::VT_SKIP, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GetCapabilitiesRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("skip", Self::VT_SKIP, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetCapabilitiesRequestArgs {
+    pub skip: u8,
+}
+impl<'a> Default for GetCapabilitiesRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetCapabilitiesRequestArgs {
+      skip: 0,
+    }
+  }
+}
+
+pub struct GetCapabilitiesRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetCapabilitiesRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_skip(&mut self, skip: u8) {
+    self.fbb_.push_slot::<u8>(GetCapabilitiesRequest::VT_SKIP, skip, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetCapabilitiesRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetCapabilitiesRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetCapabilitiesRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetCapabilitiesRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetCapabilitiesRequest");
+      ds.field("skip", &self.skip());
+      ds.finish()
+  }
+}
+pub enum GetCapabilitiesResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Capabilities of this build as `"name:version"` strings, e.g.
+/// `"tls.cert:1"`.  A name is absent when unsupported; the version
+/// bumps when a capability's wire behaviour changes incompatibly.
+pub struct GetCapabilitiesResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetCapabilitiesResponse<'a> {
+  type Inner = GetCapabilitiesResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetCapabilitiesResponse<'a> {
+  pub const VT_CAPABILITIES: flatbuffers::VOffsetT = 4;
+  pub const VT_TOTAL: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetCapabilitiesResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetCapabilitiesResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<GetCapabilitiesResponse<'bldr>> {
+    let mut builder = GetCapabilitiesResponseBuilder::new(_fbb);
+    if let Some(x) = args.capabilities { builder.add_capabilities(x); }
+    builder.add_total(args.total);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn capabilities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(GetCapabilitiesResponse::VT_CAPABILITIES, None)}
+  }
+  #[inline]
+  pub fn total(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetCapabilitiesResponse::VT_TOTAL, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GetCapabilitiesResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("capabilities", Self::VT_CAPABILITIES, false)?
+     .visit_field::<u8>("total", Self::VT_TOTAL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetCapabilitiesResponseArgs<'a> {
+    pub capabilities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub total: u8,
+}
+impl<'a> Default for GetCapabilitiesResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GetCapabilitiesResponseArgs {
+      capabilities: None,
+      total: 0,
+    }
+  }
+}
+
+pub struct GetCapabilitiesResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetCapabilitiesResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_capabilities(&mut self, capabilities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetCapabilitiesResponse::VT_CAPABILITIES, capabilities);
+  }
+  #[inline]
+  pub fn add_total(&mut self, total: u8) {
+    self.fbb_.push_slot::<u8>(GetCapabilitiesResponse::VT_TOTAL, total, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetCapabilitiesResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetCapabilitiesResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetCapabilitiesResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetCapabilitiesResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetCapabilitiesResponse");
+      ds.field("capabilities", &self.capabilities());
+      ds.field("total", &self.total());
+      ds.finish()
+  }
+}
+pub enum StatusResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusResponse<'a> {
+  type Inner = StatusResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusResponse<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_NH3_PPM: flatbuffers::VOffsetT = 6;
+  pub const VT_NH3_AVG_PPM: flatbuffers::VOffsetT = 8;
+  pub const VT_FLOW_ML_PER_MIN: flatbuffers::VOffsetT = 10;
+  pub const VT_TEMPERATURE_C: flatbuffers::VOffsetT = 12;
+  pub const VT_TANK_A_OK: flatbuffers::VOffsetT = 14;
+  pub const VT_TANK_B_OK: flatbuffers::VOffsetT = 16;
+  pub const VT_PUMP_DUTY: flatbuffers::VOffsetT = 18;
+  pub const VT_UVC_DUTY: flatbuffers::VOffsetT = 20;
+  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 22;
+  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 24;
+  pub const VT_SCRUB_SOURCE: flatbuffers::VOffsetT = 26;
+  pub const VT_UVC_WARMING: flatbuffers::VOffsetT = 28;
+  pub const VT_QUIET_START_HOUR: flatbuffers::VOffsetT = 30;
+  pub const VT_QUIET_END_HOUR: flatbuffers::VOffsetT = 32;
+  pub const VT_PRIMING: flatbuffers::VOffsetT = 34;
+  pub const VT_ADVISORY_FLAGS: flatbuffers::VOffsetT = 36;
+  pub const VT_RECOVERING: flatbuffers::VOffsetT = 38;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 40;
+  pub const VT_MAINTENANCE_MODE: flatbuffers::VOffsetT = 42;
+  pub const VT_SENSOR_WARMING: flatbuffers::VOffsetT = 44;
+  pub const VT_NH3_INJECTED: flatbuffers::VOffsetT = 46;
+  pub const VT_SOURCE_TANK: flatbuffers::VOffsetT = 48;
+  pub const VT_CONNECTED_CLIENTS: flatbuffers::VOffsetT = 50;
+  pub const VT_FAULT_TEST: flatbuffers::VOffsetT = 52;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusResponseArgs
+  ) -> flatbuffers::WIPOffset<StatusResponse<'bldr>> {
+    let mut builder = StatusResponseBuilder::new(_fbb);
+    builder.add_uptime_secs(args.uptime_secs);
+    builder.add_temperature_c(args.temperature_c);
+    builder.add_flow_ml_per_min(args.flow_ml_per_min);
+    builder.add_nh3_avg_ppm(args.nh3_avg_ppm);
+    builder.add_nh3_ppm(args.nh3_ppm);
+    builder.add_fault_test(args.fault_test);
+    builder.add_connected_clients(args.connected_clients);
+    builder.add_source_tank(args.source_tank);
+    builder.add_nh3_injected(args.nh3_injected);
+    builder.add_sensor_warming(args.sensor_warming);
+    builder.add_maintenance_mode(args.maintenance_mode);
+    builder.add_zone(args.zone);
+    builder.add_recovering(args.recovering);
+    builder.add_advisory_flags(args.advisory_flags);
+    builder.add_priming(args.priming);
+    builder.add_quiet_end_hour(args.quiet_end_hour);
+    builder.add_quiet_start_hour(args.quiet_start_hour);
+    builder.add_uvc_warming(args.uvc_warming);
+    builder.add_scrub_source(args.scrub_source);
+    builder.add_fault_flags(args.fault_flags);
+    builder.add_uvc_duty(args.uvc_duty);
+    builder.add_pump_duty(args.pump_duty);
+    builder.add_tank_b_ok(args.tank_b_ok);
+    builder.add_tank_a_ok(args.tank_a_ok);
+    builder.add_state(args.state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn state(&self) -> DeviceState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceState>(StatusResponse::VT_STATE, Some(DeviceState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(StatusResponse::VT_NH3_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_avg_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(StatusResponse::VT_NH3_AVG_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn flow_ml_per_min(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(StatusResponse::VT_FLOW_ML_PER_MIN, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn temperature_c(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(StatusResponse::VT_TEMPERATURE_C, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn tank_a_ok(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_TANK_A_OK, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn tank_b_ok(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_TANK_B_OK, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_duty(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_PUMP_DUTY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn uvc_duty(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_UVC_DUTY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn fault_flags(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_FAULT_FLAGS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn uptime_secs(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(StatusResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn scrub_source(&self) -> CommandSource {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<CommandSource>(StatusResponse::VT_SCRUB_SOURCE, Some(CommandSource::None)).unwrap()}
+  }
+  #[inline]
+  pub fn uvc_warming(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_UVC_WARMING, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_start_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_QUIET_START_HOUR, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_end_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_QUIET_END_HOUR, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn priming(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_PRIMING, Some(false)).unwrap()}
+  }
+  /// Non-fatal advisories: bit0=no flow while pumping, bit1=scrub timed out,
+  /// bit2=critical sensor data stale, bit3=status LED degraded,
+  /// bit4=command queue overloaded, bit5=config safe mode.
+  #[inline]
+  pub fn advisory_flags(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_ADVISORY_FLAGS, Some(0)).unwrap()}
+  }
+  /// Faults have cleared; Error is waiting out the recovery cooldown.
+  #[inline]
+  pub fn recovering(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_RECOVERING, Some(false)).unwrap()}
+  }
+  /// Filter loop this status describes (0 on single-loop units).
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_ZONE, Some(0)).unwrap()}
+  }
+  /// Automatic control suspended (see SetMaintenanceModeRequest).
+  #[inline]
+  pub fn maintenance_mode(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_MAINTENANCE_MODE, Some(false)).unwrap()}
+  }
+  /// NH3 sensor still in its post-boot soak; NH3 triggers are ignored.
+  #[inline]
+  pub fn sensor_warming(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_SENSOR_WARMING, Some(false)).unwrap()}
+  }
+  /// NH3 reading is synthetic (see InjectSensorRequest), not the sensor.
+  #[inline]
+  pub fn nh3_injected(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_NH3_INJECTED, Some(false)).unwrap()}
+  }
+  /// Tank the source valve draws from: 0 = Tank A, 1 = Tank B.
+  #[inline]
+  pub fn source_tank(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_SOURCE_TANK, Some(0)).unwrap()}
+  }
+  /// Clients attached right now, TCP and BLE combined.
+  #[inline]
+  pub fn connected_clients(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StatusResponse::VT_CONNECTED_CLIENTS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn fault_test(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(StatusResponse::VT_FAULT_TEST, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
+     .visit_field::<f32>("nh3_ppm", Self::VT_NH3_PPM, false)?
+     .visit_field::<f32>("nh3_avg_ppm", Self::VT_NH3_AVG_PPM, false)?
+     .visit_field::<f32>("flow_ml_per_min", Self::VT_FLOW_ML_PER_MIN, false)?
+     .visit_field::<f32>("temperature_c", Self::VT_TEMPERATURE_C, false)?
+     .visit_field::<bool>("tank_a_ok", Self::VT_TANK_A_OK, false)?
+     .visit_field::<bool>("tank_b_ok", Self::VT_TANK_B_OK, false)?
+     .visit_field::<u8>("pump_duty", Self::VT_PUMP_DUTY, false)?
+     .visit_field::<u8>("uvc_duty", Self::VT_UVC_DUTY, false)?
+     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
+     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<CommandSource>("scrub_source", Self::VT_SCRUB_SOURCE, false)?
+     .visit_field::<bool>("uvc_warming", Self::VT_UVC_WARMING, false)?
+     .visit_field::<u8>("quiet_start_hour", Self::VT_QUIET_START_HOUR, false)?
+     .visit_field::<u8>("quiet_end_hour", Self::VT_QUIET_END_HOUR, false)?
+     .visit_field::<bool>("priming", Self::VT_PRIMING, false)?
+     .visit_field::<u8>("advisory_flags", Self::VT_ADVISORY_FLAGS, false)?
+     .visit_field::<bool>("recovering", Self::VT_RECOVERING, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .visit_field::<bool>("maintenance_mode", Self::VT_MAINTENANCE_MODE, false)?
+     .visit_field::<bool>("sensor_warming", Self::VT_SENSOR_WARMING, false)?
+     .visit_field::<bool>("nh3_injected", Self::VT_NH3_INJECTED, false)?
+     .visit_field::<u8>("source_tank", Self::VT_SOURCE_TANK, false)?
+     .visit_field::<u8>("connected_clients", Self::VT_CONNECTED_CLIENTS, false)?
+     .visit_field::<bool>("fault_test", Self::VT_FAULT_TEST, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusResponseArgs {
+    pub state: DeviceState,
+    pub nh3_ppm: f32,
+    pub nh3_avg_ppm: f32,
+    pub flow_ml_per_min: f32,
+    pub temperature_c: f32,
+    pub tank_a_ok: bool,
+    pub tank_b_ok: bool,
+    pub pump_duty: u8,
+    pub uvc_duty: u8,
+    pub fault_flags: u8,
+    pub uptime_secs: u64,
+    pub scrub_source: CommandSource,
+    pub uvc_warming: bool,
+    pub quiet_start_hour: u8,
+    pub quiet_end_hour: u8,
+    pub priming: bool,
+    pub advisory_flags: u8,
+    pub recovering: bool,
+    pub zone: u8,
+    pub maintenance_mode: bool,
+    pub sensor_warming: bool,
+    pub nh3_injected: bool,
+    pub source_tank: u8,
+    pub connected_clients: u8,
+    pub fault_test: bool,
+}
+impl<'a> Default for StatusResponseArgs {
+  #[inline]
+  fn default() -> Self {
+    StatusResponseArgs {
+      state: DeviceState::Idle,
+      nh3_ppm: 0.0,
+      nh3_avg_ppm: 0.0,
+      flow_ml_per_min: 0.0,
+      temperature_c: 0.0,
+      tank_a_ok: false,
+      tank_b_ok: false,
+      pump_duty: 0,
+      uvc_duty: 0,
+      fault_flags: 0,
+      uptime_secs: 0,
+      scrub_source: CommandSource::None,
+      uvc_warming: false,
+      quiet_start_hour: 255,
+      quiet_end_hour: 255,
+      priming: false,
+      advisory_flags: 0,
+      recovering: false,
+      zone: 0,
+      maintenance_mode: false,
+      sensor_warming: false,
+      nh3_injected: false,
+      source_tank: 0,
+      connected_clients: 0,
+      fault_test: false,
+    }
+  }
+}
+
+pub struct StatusResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_state(&mut self, state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(StatusResponse::VT_STATE, state, DeviceState::Idle);
+  }
+  #[inline]
+  pub fn add_nh3_ppm(&mut self, nh3_ppm: f32) {
+    self.fbb_.push_slot::<f32>(StatusResponse::VT_NH3_PPM, nh3_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_avg_ppm(&mut self, nh3_avg_ppm: f32) {
+    self.fbb_.push_slot::<f32>(StatusResponse::VT_NH3_AVG_PPM, nh3_avg_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_flow_ml_per_min(&mut self, flow_ml_per_min: f32) {
+    self.fbb_.push_slot::<f32>(StatusResponse::VT_FLOW_ML_PER_MIN, flow_ml_per_min, 0.0);
+  }
+  #[inline]
+  pub fn add_temperature_c(&mut self, temperature_c: f32) {
+    self.fbb_.push_slot::<f32>(StatusResponse::VT_TEMPERATURE_C, temperature_c, 0.0);
+  }
+  #[inline]
+  pub fn add_tank_a_ok(&mut self, tank_a_ok: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_TANK_A_OK, tank_a_ok, false);
+  }
+  #[inline]
+  pub fn add_tank_b_ok(&mut self, tank_b_ok: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_TANK_B_OK, tank_b_ok, false);
+  }
+  #[inline]
+  pub fn add_pump_duty(&mut self, pump_duty: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_PUMP_DUTY, pump_duty, 0);
+  }
+  #[inline]
+  pub fn add_uvc_duty(&mut self, uvc_duty: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_UVC_DUTY, uvc_duty, 0);
+  }
+  #[inline]
+  pub fn add_fault_flags(&mut self, fault_flags: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_FAULT_FLAGS, fault_flags, 0);
+  }
+  #[inline]
+  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
+    self.fbb_.push_slot::<u64>(StatusResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  }
+  #[inline]
+  pub fn add_scrub_source(&mut self, scrub_source: CommandSource) {
+    self.fbb_.push_slot::<CommandSource>(StatusResponse::VT_SCRUB_SOURCE, scrub_source, CommandSource::None);
+  }
+  #[inline]
+  pub fn add_uvc_warming(&mut self, uvc_warming: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_UVC_WARMING, uvc_warming, false);
+  }
+  #[inline]
+  pub fn add_quiet_start_hour(&mut self, quiet_start_hour: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_QUIET_START_HOUR, quiet_start_hour, 255);
+  }
+  #[inline]
+  pub fn add_quiet_end_hour(&mut self, quiet_end_hour: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_QUIET_END_HOUR, quiet_end_hour, 255);
+  }
+  #[inline]
+  pub fn add_priming(&mut self, priming: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_PRIMING, priming, false);
+  }
+  #[inline]
+  pub fn add_advisory_flags(&mut self, advisory_flags: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_ADVISORY_FLAGS, advisory_flags, 0);
+  }
+  #[inline]
+  pub fn add_recovering(&mut self, recovering: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_RECOVERING, recovering, false);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn add_maintenance_mode(&mut self, maintenance_mode: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_MAINTENANCE_MODE, maintenance_mode, false);
+  }
+  #[inline]
+  pub fn add_sensor_warming(&mut self, sensor_warming: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_SENSOR_WARMING, sensor_warming, false);
+  }
+  #[inline]
+  pub fn add_nh3_injected(&mut self, nh3_injected: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_NH3_INJECTED, nh3_injected, false);
+  }
+  #[inline]
+  pub fn add_source_tank(&mut self, source_tank: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_SOURCE_TANK, source_tank, 0);
+  }
+  #[inline]
+  pub fn add_connected_clients(&mut self, connected_clients: u8) {
+    self.fbb_.push_slot::<u8>(StatusResponse::VT_CONNECTED_CLIENTS, connected_clients, 0);
+  }
+  #[inline]
+  pub fn add_fault_test(&mut self, fault_test: bool) {
+    self.fbb_.push_slot::<bool>(StatusResponse::VT_FAULT_TEST, fault_test, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusResponse");
+      ds.field("state", &self.state());
+      ds.field("nh3_ppm", &self.nh3_ppm());
+      ds.field("nh3_avg_ppm", &self.nh3_avg_ppm());
+      ds.field("flow_ml_per_min", &self.flow_ml_per_min());
+      ds.field("temperature_c", &self.temperature_c());
+      ds.field("tank_a_ok", &self.tank_a_ok());
+      ds.field("tank_b_ok", &self.tank_b_ok());
+      ds.field("pump_duty", &self.pump_duty());
+      ds.field("uvc_duty", &self.uvc_duty());
+      ds.field("fault_flags", &self.fault_flags());
+      ds.field("uptime_secs", &self.uptime_secs());
+      ds.field("scrub_source", &self.scrub_source());
+      ds.field("uvc_warming", &self.uvc_warming());
+      ds.field("quiet_start_hour", &self.quiet_start_hour());
+      ds.field("quiet_end_hour", &self.quiet_end_hour());
+      ds.field("priming", &self.priming());
+      ds.field("advisory_flags", &self.advisory_flags());
+      ds.field("recovering", &self.recovering());
+      ds.field("zone", &self.zone());
+      ds.field("maintenance_mode", &self.maintenance_mode());
+      ds.field("sensor_warming", &self.sensor_warming());
+      ds.field("nh3_injected", &self.nh3_injected());
+      ds.field("source_tank", &self.source_tank());
+      ds.field("connected_clients", &self.connected_clients());
+      ds.field("fault_test", &self.fault_test());
+      ds.finish()
+  }
+}
+pub enum GetConfigResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Every `SystemConfig` tunable as currently applied.  Names match
+/// `SetConfigRequest` where the two overlap.
+pub struct GetConfigResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetConfigResponse<'a> {
+  type Inner = GetConfigResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetConfigResponse<'a> {
+  pub const VT_PUMP_FLOW_ML_PER_MIN: flatbuffers::VOffsetT = 4;
+  pub const VT_PUMP_DUTY_PERCENT: flatbuffers::VOffsetT = 6;
+  pub const VT_PRIME_AFTER_IDLE_SECS: flatbuffers::VOffsetT = 8;
+  pub const VT_PRIME_DURATION_SECS: flatbuffers::VOffsetT = 10;
+  pub const VT_PRIME_DUTY_PERCENT: flatbuffers::VOffsetT = 12;
+  pub const VT_NH3_ACTIVATE_PPM: flatbuffers::VOffsetT = 14;
+  pub const VT_NH3_DEACTIVATE_PPM: flatbuffers::VOffsetT = 16;
+  pub const VT_NH3_CONFIRM_DURATION_SECS: flatbuffers::VOffsetT = 18;
+  pub const VT_NH3_URGENT_PPM: flatbuffers::VOffsetT = 20;
+  pub const VT_UVC_DUTY_PERCENT: flatbuffers::VOffsetT = 22;
+  pub const VT_UVC_WARMUP_SECS: flatbuffers::VOffsetT = 24;
+  pub const VT_QUIET_START_HOUR: flatbuffers::VOffsetT = 26;
+  pub const VT_QUIET_END_HOUR: flatbuffers::VOffsetT = 28;
+  pub const VT_LED_BRIGHTNESS: flatbuffers::VOffsetT = 30;
+  pub const VT_LED_QUIET_BRIGHTNESS: flatbuffers::VOffsetT = 32;
+  pub const VT_MAX_TEMPERATURE_C: flatbuffers::VOffsetT = 34;
+  pub const VT_PURGE_DURATION_SECS: flatbuffers::VOffsetT = 36;
+  pub const VT_MAX_ACTIVE_DURATION_SECS: flatbuffers::VOffsetT = 38;
+  pub const VT_MIN_WATER_LEVEL_PERCENT: flatbuffers::VOffsetT = 40;
+  pub const VT_SENSOR_READ_INTERVAL_MS: flatbuffers::VOffsetT = 42;
+  pub const VT_CONTROL_LOOP_INTERVAL_MS: flatbuffers::VOffsetT = 44;
+  pub const VT_TELEMETRY_INTERVAL_SECS: flatbuffers::VOffsetT = 46;
+  pub const VT_STOP_LOCKOUT_SECS: flatbuffers::VOffsetT = 48;
+  pub const VT_SELF_TEST_BLOCKS_ACTIVE: flatbuffers::VOffsetT = 50;
+  pub const VT_PURGE_MODE: flatbuffers::VOffsetT = 52;
+  pub const VT_PURGE_TARGET_VOLUME_ML: flatbuffers::VOffsetT = 54;
+  pub const VT_PURGE_MAX_DURATION_SECS: flatbuffers::VOffsetT = 56;
+  pub const VT_NH3_OVERSAMPLE_COUNT: flatbuffers::VOffsetT = 58;
+  pub const VT_FAULT_RECOVERY_COOLDOWN_SECS: flatbuffers::VOffsetT = 60;
+  pub const VT_POWER_PROFILE: flatbuffers::VOffsetT = 62;
+  pub const VT_LED_OVERRIDES: flatbuffers::VOffsetT = 64;
+  pub const VT_INTERLOCK_GRACE_SECS: flatbuffers::VOffsetT = 66;
+  pub const VT_FLOW_GLITCH_FILTER_US: flatbuffers::VOffsetT = 68;
+  pub const VT_SENSOR_STALE_AFTER_SECS: flatbuffers::VOffsetT = 70;
+  pub const VT_SOFT_STOP_TICKS: flatbuffers::VOffsetT = 72;
+  pub const VT_OVER_TEMP_DEBOUNCE_SAMPLES: flatbuffers::VOffsetT = 74;
+  pub const VT_NH3_RATE_THRESHOLD_PPM_PER_SEC: flatbuffers::VOffsetT = 76;
+  pub const VT_MAINTENANCE_MODE: flatbuffers::VOffsetT = 78;
+  pub const VT_NH3_WARMUP_SECS: flatbuffers::VOffsetT = 80;
+  pub const VT_TANK_SOURCE: flatbuffers::VOffsetT = 82;
+  pub const VT_MIN_ACTIVE_SECS: flatbuffers::VOffsetT = 84;
+  pub const VT_LED_OVERLAY_PRIORITY: flatbuffers::VOffsetT = 86;
+  pub const VT_NH3_ALARM_HIGH_PPM: flatbuffers::VOffsetT = 88;
+  pub const VT_NH3_ALARM_CLEAR_PPM: flatbuffers::VOffsetT = 90;
+  pub const VT_NH3_ADC_SAMPLES: flatbuffers::VOffsetT = 92;
+  pub const VT_PUMP_DERATE_START_C: flatbuffers::VOffsetT = 94;
+  pub const VT_PUMP_DERATE_MIN_DUTY_PERCENT: flatbuffers::VOffsetT = 96;
+  pub const VT_INTERLOCK_ACTIVE_LOW: flatbuffers::VOffsetT = 98;
+  pub const VT_STATE_MAX_SECS: flatbuffers::VOffsetT = 100;
+  pub const VT_BLE_STATUS_INTERVAL_SECS: flatbuffers::VOffsetT = 102;
+  pub const VT_PUMP_PID_KP: flatbuffers::VOffsetT = 104;
+  pub const VT_PUMP_PID_KI: flatbuffers::VOffsetT = 106;
+  pub const VT_PUMP_PID_KD: flatbuffers::VOffsetT = 108;
+  pub const VT_PSK_GRACE_SECS: flatbuffers::VOffsetT = 110;
+  pub const VT_MAX_CONTROL_DT_MS: flatbuffers::VOffsetT = 112;
+  pub const VT_FLOW_FILTER_TAU_MS: flatbuffers::VOffsetT = 114;
+  pub const VT_AUTH_LOCKOUT_THRESHOLD: flatbuffers::VOffsetT = 116;
+  pub const VT_WIFI_ROAM_RSSI_DBM: flatbuffers::VOffsetT = 118;
+  pub const VT_WIFI_ROAM_SAMPLES: flatbuffers::VOffsetT = 120;
+  pub const VT_TCP_IDLE_TIMEOUT_SECS: flatbuffers::VOffsetT = 122;
+  pub const VT_PING_REQUIRES_AUTH: flatbuffers::VOffsetT = 124;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetConfigResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetConfigResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<GetConfigResponse<'bldr>> {
+    let mut builder = GetConfigResponseBuilder::new(_fbb);
+    builder.add_max_control_dt_ms(args.max_control_dt_ms);
+    builder.add_psk_grace_secs(args.psk_grace_secs);
+    builder.add_pump_pid_kd(args.pump_pid_kd);
+    builder.add_pump_pid_ki(args.pump_pid_ki);
+    builder.add_pump_pid_kp(args.pump_pid_kp);
+    if let Some(x) = args.state_max_secs { builder.add_state_max_secs(x); }
+    builder.add_pump_derate_start_c(args.pump_derate_start_c);
+    builder.add_nh3_alarm_clear_ppm(args.nh3_alarm_clear_ppm);
+    builder.add_nh3_alarm_high_ppm(args.nh3_alarm_high_ppm);
+    builder.add_nh3_rate_threshold_ppm_per_sec(args.nh3_rate_threshold_ppm_per_sec);
+    if let Some(x) = args.led_overlay_priority { builder.add_led_overlay_priority(x); }
+    if let Some(x) = args.led_overrides { builder.add_led_overrides(x); }
+    builder.add_telemetry_interval_secs(args.telemetry_interval_secs);
+    builder.add_control_loop_interval_ms(args.control_loop_interval_ms);
+    builder.add_sensor_read_interval_ms(args.sensor_read_interval_ms);
+    builder.add_max_active_duration_secs(args.max_active_duration_secs);
+    builder.add_max_temperature_c(args.max_temperature_c);
+    builder.add_nh3_urgent_ppm(args.nh3_urgent_ppm);
+    builder.add_nh3_deactivate_ppm(args.nh3_deactivate_ppm);
+    builder.add_nh3_activate_ppm(args.nh3_activate_ppm);
+    builder.add_prime_after_idle_secs(args.prime_after_idle_secs);
+    builder.add_tcp_idle_timeout_secs(args.tcp_idle_timeout_secs);
+    builder.add_flow_filter_tau_ms(args.flow_filter_tau_ms);
+    builder.add_ble_status_interval_secs(args.ble_status_interval_secs);
+    builder.add_sensor_stale_after_secs(args.sensor_stale_after_secs);
+    builder.add_flow_glitch_filter_us(args.flow_glitch_filter_us);
+    builder.add_interlock_grace_secs(args.interlock_grace_secs);
+    builder.add_fault_recovery_cooldown_secs(args.fault_recovery_cooldown_secs);
+    builder.add_purge_max_duration_secs(args.purge_max_duration_secs);
+    builder.add_purge_target_volume_ml(args.purge_target_volume_ml);
+    builder.add_stop_lockout_secs(args.stop_lockout_secs);
+    builder.add_purge_duration_secs(args.purge_duration_secs);
+    builder.add_uvc_warmup_secs(args.uvc_warmup_secs);
+    builder.add_nh3_confirm_duration_secs(args.nh3_confirm_duration_secs);
+    builder.add_prime_duration_secs(args.prime_duration_secs);
+    builder.add_min_active_secs(args.min_active_secs);
+    builder.add_nh3_warmup_secs(args.nh3_warmup_secs);
+    builder.add_pump_flow_ml_per_min(args.pump_flow_ml_per_min);
+    builder.add_ping_requires_auth(args.ping_requires_auth);
+    builder.add_wifi_roam_samples(args.wifi_roam_samples);
+    builder.add_wifi_roam_rssi_dbm(args.wifi_roam_rssi_dbm);
+    builder.add_auth_lockout_threshold(args.auth_lockout_threshold);
+    builder.add_interlock_active_low(args.interlock_active_low);
+    builder.add_pump_derate_min_duty_percent(args.pump_derate_min_duty_percent);
+    builder.add_nh3_adc_samples(args.nh3_adc_samples);
+    builder.add_tank_source(args.tank_source);
+    builder.add_maintenance_mode(args.maintenance_mode);
+    builder.add_over_temp_debounce_samples(args.over_temp_debounce_samples);
+    builder.add_soft_stop_ticks(args.soft_stop_ticks);
+    builder.add_power_profile(args.power_profile);
+    builder.add_nh3_oversample_count(args.nh3_oversample_count);
+    builder.add_purge_mode(args.purge_mode);
+    builder.add_self_test_blocks_active(args.self_test_blocks_active);
+    builder.add_min_water_level_percent(args.min_water_level_percent);
+    builder.add_led_quiet_brightness(args.led_quiet_brightness);
+    builder.add_led_brightness(args.led_brightness);
+    builder.add_quiet_end_hour(args.quiet_end_hour);
+    builder.add_quiet_start_hour(args.quiet_start_hour);
+    builder.add_uvc_duty_percent(args.uvc_duty_percent);
+    builder.add_prime_duty_percent(args.prime_duty_percent);
+    builder.add_pump_duty_percent(args.pump_duty_percent);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn pump_flow_ml_per_min(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_PUMP_FLOW_ML_PER_MIN, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_duty_percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_PUMP_DUTY_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn prime_after_idle_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_PRIME_AFTER_IDLE_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn prime_duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_PRIME_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn prime_duty_percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_PRIME_DUTY_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_activate_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_ACTIVATE_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_deactivate_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_DEACTIVATE_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_confirm_duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_NH3_CONFIRM_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_urgent_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_URGENT_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn uvc_duty_percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_UVC_DUTY_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn uvc_warmup_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_UVC_WARMUP_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_start_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_QUIET_START_HOUR, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn quiet_end_hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_QUIET_END_HOUR, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn led_brightness(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_LED_BRIGHTNESS, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn led_quiet_brightness(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_LED_QUIET_BRIGHTNESS, Some(255)).unwrap()}
+  }
+  #[inline]
+  pub fn max_temperature_c(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_MAX_TEMPERATURE_C, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn purge_duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_PURGE_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_active_duration_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_MAX_ACTIVE_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn min_water_level_percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_MIN_WATER_LEVEL_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn sensor_read_interval_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_SENSOR_READ_INTERVAL_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn control_loop_interval_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_CONTROL_LOOP_INTERVAL_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn telemetry_interval_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_TELEMETRY_INTERVAL_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn stop_lockout_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_STOP_LOCKOUT_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn self_test_blocks_active(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GetConfigResponse::VT_SELF_TEST_BLOCKS_ACTIVE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn purge_mode(&self) -> PurgeMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PurgeMode>(GetConfigResponse::VT_PURGE_MODE, Some(PurgeMode::Duration)).unwrap()}
+  }
+  #[inline]
+  pub fn purge_target_volume_ml(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_PURGE_TARGET_VOLUME_ML, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn purge_max_duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_PURGE_MAX_DURATION_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_oversample_count(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_NH3_OVERSAMPLE_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn fault_recovery_cooldown_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_FAULT_RECOVERY_COOLDOWN_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn power_profile(&self) -> PowerProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PowerProfile>(GetConfigResponse::VT_POWER_PROFILE, Some(PowerProfile::Balanced)).unwrap()}
+  }
+  #[inline]
+  pub fn led_overrides(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LedOverrideFbs<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LedOverrideFbs>>>>(GetConfigResponse::VT_LED_OVERRIDES, None)}
+  }
+  #[inline]
+  pub fn interlock_grace_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_INTERLOCK_GRACE_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn flow_glitch_filter_us(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_FLOW_GLITCH_FILTER_US, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn sensor_stale_after_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_SENSOR_STALE_AFTER_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn soft_stop_ticks(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_SOFT_STOP_TICKS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn over_temp_debounce_samples(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_OVER_TEMP_DEBOUNCE_SAMPLES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_rate_threshold_ppm_per_sec(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_RATE_THRESHOLD_PPM_PER_SEC, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn maintenance_mode(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GetConfigResponse::VT_MAINTENANCE_MODE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_warmup_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_NH3_WARMUP_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn tank_source(&self) -> TankSource {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TankSource>(GetConfigResponse::VT_TANK_SOURCE, Some(TankSource::TankA)).unwrap()}
+  }
+  #[inline]
+  pub fn min_active_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_MIN_ACTIVE_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn led_overlay_priority(&self) -> Option<flatbuffers::Vector<'a, LedOverlay>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, LedOverlay>>>(GetConfigResponse::VT_LED_OVERLAY_PRIORITY, None)}
+  }
+  #[inline]
+  pub fn nh3_alarm_high_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_ALARM_HIGH_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_alarm_clear_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_NH3_ALARM_CLEAR_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_adc_samples(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_NH3_ADC_SAMPLES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_derate_start_c(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_PUMP_DERATE_START_C, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_derate_min_duty_percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_PUMP_DERATE_MIN_DUTY_PERCENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn interlock_active_low(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GetConfigResponse::VT_INTERLOCK_ACTIVE_LOW, Some(true)).unwrap()}
+  }
+  #[inline]
+  pub fn state_max_secs(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(GetConfigResponse::VT_STATE_MAX_SECS, None)}
+  }
+  #[inline]
+  pub fn ble_status_interval_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_BLE_STATUS_INTERVAL_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_pid_kp(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_PUMP_PID_KP, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_pid_ki(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_PUMP_PID_KI, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_pid_kd(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GetConfigResponse::VT_PUMP_PID_KD, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn psk_grace_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_PSK_GRACE_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_control_dt_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetConfigResponse::VT_MAX_CONTROL_DT_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn flow_filter_tau_ms(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_FLOW_FILTER_TAU_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn auth_lockout_threshold(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_AUTH_LOCKOUT_THRESHOLD, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn wifi_roam_rssi_dbm(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(GetConfigResponse::VT_WIFI_ROAM_RSSI_DBM, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn wifi_roam_samples(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetConfigResponse::VT_WIFI_ROAM_SAMPLES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn tcp_idle_timeout_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(GetConfigResponse::VT_TCP_IDLE_TIMEOUT_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ping_requires_auth(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GetConfigResponse::VT_PING_REQUIRES_AUTH, Some(true)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GetConfigResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("pump_flow_ml_per_min", Self::VT_PUMP_FLOW_ML_PER_MIN, false)?
+     .visit_field::<u8>("pump_duty_percent", Self::VT_PUMP_DUTY_PERCENT, false)?
+     .visit_field::<u32>("prime_after_idle_secs", Self::VT_PRIME_AFTER_IDLE_SECS, false)?
+     .visit_field::<u16>("prime_duration_secs", Self::VT_PRIME_DURATION_SECS, false)?
+     .visit_field::<u8>("prime_duty_percent", Self::VT_PRIME_DUTY_PERCENT, false)?
+     .visit_field::<f32>("nh3_activate_ppm", Self::VT_NH3_ACTIVATE_PPM, false)?
+     .visit_field::<f32>("nh3_deactivate_ppm", Self::VT_NH3_DEACTIVATE_PPM, false)?
+     .visit_field::<u16>("nh3_confirm_duration_secs", Self::VT_NH3_CONFIRM_DURATION_SECS, false)?
+     .visit_field::<f32>("nh3_urgent_ppm", Self::VT_NH3_URGENT_PPM, false)?
+     .visit_field::<u8>("uvc_duty_percent", Self::VT_UVC_DUTY_PERCENT, false)?
+     .visit_field::<u16>("uvc_warmup_secs", Self::VT_UVC_WARMUP_SECS, false)?
+     .visit_field::<u8>("quiet_start_hour", Self::VT_QUIET_START_HOUR, false)?
+     .visit_field::<u8>("quiet_end_hour", Self::VT_QUIET_END_HOUR, false)?
+     .visit_field::<u8>("led_brightness", Self::VT_LED_BRIGHTNESS, false)?
+     .visit_field::<u8>("led_quiet_brightness", Self::VT_LED_QUIET_BRIGHTNESS, false)?
+     .visit_field::<f32>("max_temperature_c", Self::VT_MAX_TEMPERATURE_C, false)?
+     .visit_field::<u16>("purge_duration_secs", Self::VT_PURGE_DURATION_SECS, false)?
+     .visit_field::<u32>("max_active_duration_secs", Self::VT_MAX_ACTIVE_DURATION_SECS, false)?
+     .visit_field::<u8>("min_water_level_percent", Self::VT_MIN_WATER_LEVEL_PERCENT, false)?
+     .visit_field::<u32>("sensor_read_interval_ms", Self::VT_SENSOR_READ_INTERVAL_MS, false)?
+     .visit_field::<u32>("control_loop_interval_ms", Self::VT_CONTROL_LOOP_INTERVAL_MS, false)?
+     .visit_field::<u32>("telemetry_interval_secs", Self::VT_TELEMETRY_INTERVAL_SECS, false)?
+     .visit_field::<u16>("stop_lockout_secs", Self::VT_STOP_LOCKOUT_SECS, false)?
+     .visit_field::<bool>("self_test_blocks_active", Self::VT_SELF_TEST_BLOCKS_ACTIVE, false)?
+     .visit_field::<PurgeMode>("purge_mode", Self::VT_PURGE_MODE, false)?
+     .visit_field::<u16>("purge_target_volume_ml", Self::VT_PURGE_TARGET_VOLUME_ML, false)?
+     .visit_field::<u16>("purge_max_duration_secs", Self::VT_PURGE_MAX_DURATION_SECS, false)?
+     .visit_field::<u8>("nh3_oversample_count", Self::VT_NH3_OVERSAMPLE_COUNT, false)?
+     .visit_field::<u16>("fault_recovery_cooldown_secs", Self::VT_FAULT_RECOVERY_COOLDOWN_SECS, false)?
+     .visit_field::<PowerProfile>("power_profile", Self::VT_POWER_PROFILE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LedOverrideFbs>>>>("led_overrides", Self::VT_LED_OVERRIDES, false)?
+     .visit_field::<u16>("interlock_grace_secs", Self::VT_INTERLOCK_GRACE_SECS, false)?
+     .visit_field::<u16>("flow_glitch_filter_us", Self::VT_FLOW_GLITCH_FILTER_US, false)?
+     .visit_field::<u16>("sensor_stale_after_secs", Self::VT_SENSOR_STALE_AFTER_SECS, false)?
+     .visit_field::<u8>("soft_stop_ticks", Self::VT_SOFT_STOP_TICKS, false)?
+     .visit_field::<u8>("over_temp_debounce_samples", Self::VT_OVER_TEMP_DEBOUNCE_SAMPLES, false)?
+     .visit_field::<f32>("nh3_rate_threshold_ppm_per_sec", Self::VT_NH3_RATE_THRESHOLD_PPM_PER_SEC, false)?
+     .visit_field::<bool>("maintenance_mode", Self::VT_MAINTENANCE_MODE, false)?
+     .visit_field::<u16>("nh3_warmup_secs", Self::VT_NH3_WARMUP_SECS, false)?
+     .visit_field::<TankSource>("tank_source", Self::VT_TANK_SOURCE, false)?
+     .visit_field::<u16>("min_active_secs", Self::VT_MIN_ACTIVE_SECS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, LedOverlay>>>("led_overlay_priority", Self::VT_LED_OVERLAY_PRIORITY, false)?
+     .visit_field::<f32>("nh3_alarm_high_ppm", Self::VT_NH3_ALARM_HIGH_PPM, false)?
+     .visit_field::<f32>("nh3_alarm_clear_ppm", Self::VT_NH3_ALARM_CLEAR_PPM, false)?
+     .visit_field::<u8>("nh3_adc_samples", Self::VT_NH3_ADC_SAMPLES, false)?
+     .visit_field::<f32>("pump_derate_start_c", Self::VT_PUMP_DERATE_START_C, false)?
+     .visit_field::<u8>("pump_derate_min_duty_percent", Self::VT_PUMP_DERATE_MIN_DUTY_PERCENT, false)?
+     .visit_field::<bool>("interlock_active_low", Self::VT_INTERLOCK_ACTIVE_LOW, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("state_max_secs", Self::VT_STATE_MAX_SECS, false)?
+     .visit_field::<u16>("ble_status_interval_secs", Self::VT_BLE_STATUS_INTERVAL_SECS, false)?
+     .visit_field::<f32>("pump_pid_kp", Self::VT_PUMP_PID_KP, false)?
+     .visit_field::<f32>("pump_pid_ki", Self::VT_PUMP_PID_KI, false)?
+     .visit_field::<f32>("pump_pid_kd", Self::VT_PUMP_PID_KD, false)?
+     .visit_field::<u32>("psk_grace_secs", Self::VT_PSK_GRACE_SECS, false)?
+     .visit_field::<u32>("max_control_dt_ms", Self::VT_MAX_CONTROL_DT_MS, false)?
+     .visit_field::<u16>("flow_filter_tau_ms", Self::VT_FLOW_FILTER_TAU_MS, false)?
+     .visit_field::<u8>("auth_lockout_threshold", Self::VT_AUTH_LOCKOUT_THRESHOLD, false)?
+     .visit_field::<i8>("wifi_roam_rssi_dbm", Self::VT_WIFI_ROAM_RSSI_DBM, false)?
+     .visit_field::<u8>("wifi_roam_samples", Self::VT_WIFI_ROAM_SAMPLES, false)?
+     .visit_field::<u16>("tcp_idle_timeout_secs", Self::VT_TCP_IDLE_TIMEOUT_SECS, false)?
+     .visit_field::<bool>("ping_requires_auth", Self::VT_PING_REQUIRES_AUTH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetConfigResponseArgs<'a> {
+    pub pump_flow_ml_per_min: u16,
+    pub pump_duty_percent: u8,
+    pub prime_after_idle_secs: u32,
+    pub prime_duration_secs: u16,
+    pub prime_duty_percent: u8,
+    pub nh3_activate_ppm: f32,
+    pub nh3_deactivate_ppm: f32,
+    pub nh3_confirm_duration_secs: u16,
+    pub nh3_urgent_ppm: f32,
+    pub uvc_duty_percent: u8,
+    pub uvc_warmup_secs: u16,
+    pub quiet_start_hour: u8,
+    pub quiet_end_hour: u8,
+    pub led_brightness: u8,
+    pub led_quiet_brightness: u8,
+    pub max_temperature_c: f32,
+    pub purge_duration_secs: u16,
+    pub max_active_duration_secs: u32,
+    pub min_water_level_percent: u8,
+    pub sensor_read_interval_ms: u32,
+    pub control_loop_interval_ms: u32,
+    pub telemetry_interval_secs: u32,
+    pub stop_lockout_secs: u16,
+    pub self_test_blocks_active: bool,
+    pub purge_mode: PurgeMode,
+    pub purge_target_volume_ml: u16,
+    pub purge_max_duration_secs: u16,
+    pub nh3_oversample_count: u8,
+    pub fault_recovery_cooldown_secs: u16,
+    pub power_profile: PowerProfile,
+    pub led_overrides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LedOverrideFbs<'a>>>>>,
+    pub interlock_grace_secs: u16,
+    pub flow_glitch_filter_us: u16,
+    pub sensor_stale_after_secs: u16,
+    pub soft_stop_ticks: u8,
+    pub over_temp_debounce_samples: u8,
+    pub nh3_rate_threshold_ppm_per_sec: f32,
+    pub maintenance_mode: bool,
+    pub nh3_warmup_secs: u16,
+    pub tank_source: TankSource,
+    pub min_active_secs: u16,
+    pub led_overlay_priority: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, LedOverlay>>>,
+    pub nh3_alarm_high_ppm: f32,
+    pub nh3_alarm_clear_ppm: f32,
+    pub nh3_adc_samples: u8,
+    pub pump_derate_start_c: f32,
+    pub pump_derate_min_duty_percent: u8,
+    pub interlock_active_low: bool,
+    pub state_max_secs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ble_status_interval_secs: u16,
+    pub pump_pid_kp: f32,
+    pub pump_pid_ki: f32,
+    pub pump_pid_kd: f32,
+    pub psk_grace_secs: u32,
+    pub max_control_dt_ms: u32,
+    pub flow_filter_tau_ms: u16,
+    pub auth_lockout_threshold: u8,
+    pub wifi_roam_rssi_dbm: i8,
+    pub wifi_roam_samples: u8,
+    pub tcp_idle_timeout_secs: u16,
+    pub ping_requires_auth: bool,
+}
+impl<'a> Default for GetConfigResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GetConfigResponseArgs {
+      pump_flow_ml_per_min: 0,
+      pump_duty_percent: 0,
+      prime_after_idle_secs: 0,
+      prime_duration_secs: 0,
+      prime_duty_percent: 0,
+      nh3_activate_ppm: 0.0,
+      nh3_deactivate_ppm: 0.0,
+      nh3_confirm_duration_secs: 0,
+      nh3_urgent_ppm: 0.0,
+      uvc_duty_percent: 0,
+      uvc_warmup_secs: 0,
+      quiet_start_hour: 255,
+      quiet_end_hour: 255,
+      led_brightness: 255,
+      led_quiet_brightness: 255,
+      max_temperature_c: 0.0,
+      purge_duration_secs: 0,
+      max_active_duration_secs: 0,
+      min_water_level_percent: 0,
+      sensor_read_interval_ms: 0,
+      control_loop_interval_ms: 0,
+      telemetry_interval_secs: 0,
+      stop_lockout_secs: 0,
+      self_test_blocks_active: false,
+      purge_mode: PurgeMode::Duration,
+      purge_target_volume_ml: 0,
+      purge_max_duration_secs: 0,
+      nh3_oversample_count: 0,
+      fault_recovery_cooldown_secs: 0,
+      power_profile: PowerProfile::Balanced,
+      led_overrides: None,
+      interlock_grace_secs: 0,
+      flow_glitch_filter_us: 0,
+      sensor_stale_after_secs: 0,
+      soft_stop_ticks: 0,
+      over_temp_debounce_samples: 0,
+      nh3_rate_threshold_ppm_per_sec: 0.0,
+      maintenance_mode: false,
+      nh3_warmup_secs: 0,
+      tank_source: TankSource::TankA,
+      min_active_secs: 0,
+      led_overlay_priority: None,
+      nh3_alarm_high_ppm: 0.0,
+      nh3_alarm_clear_ppm: 0.0,
+      nh3_adc_samples: 0,
+      pump_derate_start_c: 0.0,
+      pump_derate_min_duty_percent: 0,
+      interlock_active_low: true,
+      state_max_secs: None,
+      ble_status_interval_secs: 0,
+      pump_pid_kp: 0.0,
+      pump_pid_ki: 0.0,
+      pump_pid_kd: 0.0,
+      psk_grace_secs: 0,
+      max_control_dt_ms: 0,
+      flow_filter_tau_ms: 0,
+      auth_lockout_threshold: 0,
+      wifi_roam_rssi_dbm: 0,
+      wifi_roam_samples: 0,
+      tcp_idle_timeout_secs: 0,
+      ping_requires_auth: true,
+    }
+  }
+}
+
+pub struct GetConfigResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetConfigResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_pump_flow_ml_per_min(&mut self, pump_flow_ml_per_min: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_PUMP_FLOW_ML_PER_MIN, pump_flow_ml_per_min, 0);
+  }
+  #[inline]
+  pub fn add_pump_duty_percent(&mut self, pump_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_PUMP_DUTY_PERCENT, pump_duty_percent, 0);
+  }
+  #[inline]
+  pub fn add_prime_after_idle_secs(&mut self, prime_after_idle_secs: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_PRIME_AFTER_IDLE_SECS, prime_after_idle_secs, 0);
+  }
+  #[inline]
+  pub fn add_prime_duration_secs(&mut self, prime_duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_PRIME_DURATION_SECS, prime_duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_prime_duty_percent(&mut self, prime_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_PRIME_DUTY_PERCENT, prime_duty_percent, 0);
+  }
+  #[inline]
+  pub fn add_nh3_activate_ppm(&mut self, nh3_activate_ppm: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_ACTIVATE_PPM, nh3_activate_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_deactivate_ppm(&mut self, nh3_deactivate_ppm: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_DEACTIVATE_PPM, nh3_deactivate_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_confirm_duration_secs(&mut self, nh3_confirm_duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_NH3_CONFIRM_DURATION_SECS, nh3_confirm_duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_nh3_urgent_ppm(&mut self, nh3_urgent_ppm: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_URGENT_PPM, nh3_urgent_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_uvc_duty_percent(&mut self, uvc_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_UVC_DUTY_PERCENT, uvc_duty_percent, 0);
+  }
+  #[inline]
+  pub fn add_uvc_warmup_secs(&mut self, uvc_warmup_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_UVC_WARMUP_SECS, uvc_warmup_secs, 0);
+  }
+  #[inline]
+  pub fn add_quiet_start_hour(&mut self, quiet_start_hour: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_QUIET_START_HOUR, quiet_start_hour, 255);
+  }
+  #[inline]
+  pub fn add_quiet_end_hour(&mut self, quiet_end_hour: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_QUIET_END_HOUR, quiet_end_hour, 255);
+  }
+  #[inline]
+  pub fn add_led_brightness(&mut self, led_brightness: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_LED_BRIGHTNESS, led_brightness, 255);
+  }
+  #[inline]
+  pub fn add_led_quiet_brightness(&mut self, led_quiet_brightness: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_LED_QUIET_BRIGHTNESS, led_quiet_brightness, 255);
+  }
+  #[inline]
+  pub fn add_max_temperature_c(&mut self, max_temperature_c: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_MAX_TEMPERATURE_C, max_temperature_c, 0.0);
+  }
+  #[inline]
+  pub fn add_purge_duration_secs(&mut self, purge_duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_PURGE_DURATION_SECS, purge_duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_max_active_duration_secs(&mut self, max_active_duration_secs: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_MAX_ACTIVE_DURATION_SECS, max_active_duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_min_water_level_percent(&mut self, min_water_level_percent: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_MIN_WATER_LEVEL_PERCENT, min_water_level_percent, 0);
+  }
+  #[inline]
+  pub fn add_sensor_read_interval_ms(&mut self, sensor_read_interval_ms: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_SENSOR_READ_INTERVAL_MS, sensor_read_interval_ms, 0);
+  }
+  #[inline]
+  pub fn add_control_loop_interval_ms(&mut self, control_loop_interval_ms: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_CONTROL_LOOP_INTERVAL_MS, control_loop_interval_ms, 0);
+  }
+  #[inline]
+  pub fn add_telemetry_interval_secs(&mut self, telemetry_interval_secs: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_TELEMETRY_INTERVAL_SECS, telemetry_interval_secs, 0);
+  }
+  #[inline]
+  pub fn add_stop_lockout_secs(&mut self, stop_lockout_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_STOP_LOCKOUT_SECS, stop_lockout_secs, 0);
+  }
+  #[inline]
+  pub fn add_self_test_blocks_active(&mut self, self_test_blocks_active: bool) {
+    self.fbb_.push_slot::<bool>(GetConfigResponse::VT_SELF_TEST_BLOCKS_ACTIVE, self_test_blocks_active, false);
+  }
+  #[inline]
+  pub fn add_purge_mode(&mut self, purge_mode: PurgeMode) {
+    self.fbb_.push_slot::<PurgeMode>(GetConfigResponse::VT_PURGE_MODE, purge_mode, PurgeMode::Duration);
+  }
+  #[inline]
+  pub fn add_purge_target_volume_ml(&mut self, purge_target_volume_ml: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_PURGE_TARGET_VOLUME_ML, purge_target_volume_ml, 0);
+  }
+  #[inline]
+  pub fn add_purge_max_duration_secs(&mut self, purge_max_duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_PURGE_MAX_DURATION_SECS, purge_max_duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_nh3_oversample_count(&mut self, nh3_oversample_count: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_NH3_OVERSAMPLE_COUNT, nh3_oversample_count, 0);
+  }
+  #[inline]
+  pub fn add_fault_recovery_cooldown_secs(&mut self, fault_recovery_cooldown_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_FAULT_RECOVERY_COOLDOWN_SECS, fault_recovery_cooldown_secs, 0);
+  }
+  #[inline]
+  pub fn add_power_profile(&mut self, power_profile: PowerProfile) {
+    self.fbb_.push_slot::<PowerProfile>(GetConfigResponse::VT_POWER_PROFILE, power_profile, PowerProfile::Balanced);
+  }
+  #[inline]
+  pub fn add_led_overrides(&mut self, led_overrides: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LedOverrideFbs<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetConfigResponse::VT_LED_OVERRIDES, led_overrides);
+  }
+  #[inline]
+  pub fn add_interlock_grace_secs(&mut self, interlock_grace_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_INTERLOCK_GRACE_SECS, interlock_grace_secs, 0);
+  }
+  #[inline]
+  pub fn add_flow_glitch_filter_us(&mut self, flow_glitch_filter_us: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_FLOW_GLITCH_FILTER_US, flow_glitch_filter_us, 0);
+  }
+  #[inline]
+  pub fn add_sensor_stale_after_secs(&mut self, sensor_stale_after_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_SENSOR_STALE_AFTER_SECS, sensor_stale_after_secs, 0);
+  }
+  #[inline]
+  pub fn add_soft_stop_ticks(&mut self, soft_stop_ticks: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_SOFT_STOP_TICKS, soft_stop_ticks, 0);
+  }
+  #[inline]
+  pub fn add_over_temp_debounce_samples(&mut self, over_temp_debounce_samples: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_OVER_TEMP_DEBOUNCE_SAMPLES, over_temp_debounce_samples, 0);
+  }
+  #[inline]
+  pub fn add_nh3_rate_threshold_ppm_per_sec(&mut self, nh3_rate_threshold_ppm_per_sec: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_RATE_THRESHOLD_PPM_PER_SEC, nh3_rate_threshold_ppm_per_sec, 0.0);
+  }
+  #[inline]
+  pub fn add_maintenance_mode(&mut self, maintenance_mode: bool) {
+    self.fbb_.push_slot::<bool>(GetConfigResponse::VT_MAINTENANCE_MODE, maintenance_mode, false);
+  }
+  #[inline]
+  pub fn add_nh3_warmup_secs(&mut self, nh3_warmup_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_NH3_WARMUP_SECS, nh3_warmup_secs, 0);
+  }
+  #[inline]
+  pub fn add_tank_source(&mut self, tank_source: TankSource) {
+    self.fbb_.push_slot::<TankSource>(GetConfigResponse::VT_TANK_SOURCE, tank_source, TankSource::TankA);
+  }
+  #[inline]
+  pub fn add_min_active_secs(&mut self, min_active_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_MIN_ACTIVE_SECS, min_active_secs, 0);
+  }
+  #[inline]
+  pub fn add_led_overlay_priority(&mut self, led_overlay_priority: flatbuffers::WIPOffset<flatbuffers::Vector<'b , LedOverlay>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetConfigResponse::VT_LED_OVERLAY_PRIORITY, led_overlay_priority);
+  }
+  #[inline]
+  pub fn add_nh3_alarm_high_ppm(&mut self, nh3_alarm_high_ppm: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_ALARM_HIGH_PPM, nh3_alarm_high_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_alarm_clear_ppm(&mut self, nh3_alarm_clear_ppm: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_NH3_ALARM_CLEAR_PPM, nh3_alarm_clear_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_adc_samples(&mut self, nh3_adc_samples: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_NH3_ADC_SAMPLES, nh3_adc_samples, 0);
+  }
+  #[inline]
+  pub fn add_pump_derate_start_c(&mut self, pump_derate_start_c: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_PUMP_DERATE_START_C, pump_derate_start_c, 0.0);
+  }
+  #[inline]
+  pub fn add_pump_derate_min_duty_percent(&mut self, pump_derate_min_duty_percent: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_PUMP_DERATE_MIN_DUTY_PERCENT, pump_derate_min_duty_percent, 0);
+  }
+  #[inline]
+  pub fn add_interlock_active_low(&mut self, interlock_active_low: bool) {
+    self.fbb_.push_slot::<bool>(GetConfigResponse::VT_INTERLOCK_ACTIVE_LOW, interlock_active_low, true);
+  }
+  #[inline]
+  pub fn add_state_max_secs(&mut self, state_max_secs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetConfigResponse::VT_STATE_MAX_SECS, state_max_secs);
+  }
+  #[inline]
+  pub fn add_ble_status_interval_secs(&mut self, ble_status_interval_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_BLE_STATUS_INTERVAL_SECS, ble_status_interval_secs, 0);
+  }
+  #[inline]
+  pub fn add_pump_pid_kp(&mut self, pump_pid_kp: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_PUMP_PID_KP, pump_pid_kp, 0.0);
+  }
+  #[inline]
+  pub fn add_pump_pid_ki(&mut self, pump_pid_ki: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_PUMP_PID_KI, pump_pid_ki, 0.0);
+  }
+  #[inline]
+  pub fn add_pump_pid_kd(&mut self, pump_pid_kd: f32) {
+    self.fbb_.push_slot::<f32>(GetConfigResponse::VT_PUMP_PID_KD, pump_pid_kd, 0.0);
+  }
+  #[inline]
+  pub fn add_psk_grace_secs(&mut self, psk_grace_secs: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_PSK_GRACE_SECS, psk_grace_secs, 0);
+  }
+  #[inline]
+  pub fn add_max_control_dt_ms(&mut self, max_control_dt_ms: u32) {
+    self.fbb_.push_slot::<u32>(GetConfigResponse::VT_MAX_CONTROL_DT_MS, max_control_dt_ms, 0);
+  }
+  #[inline]
+  pub fn add_flow_filter_tau_ms(&mut self, flow_filter_tau_ms: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_FLOW_FILTER_TAU_MS, flow_filter_tau_ms, 0);
+  }
+  #[inline]
+  pub fn add_auth_lockout_threshold(&mut self, auth_lockout_threshold: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_AUTH_LOCKOUT_THRESHOLD, auth_lockout_threshold, 0);
+  }
+  #[inline]
+  pub fn add_wifi_roam_rssi_dbm(&mut self, wifi_roam_rssi_dbm: i8) {
+    self.fbb_.push_slot::<i8>(GetConfigResponse::VT_WIFI_ROAM_RSSI_DBM, wifi_roam_rssi_dbm, 0);
+  }
+  #[inline]
+  pub fn add_wifi_roam_samples(&mut self, wifi_roam_samples: u8) {
+    self.fbb_.push_slot::<u8>(GetConfigResponse::VT_WIFI_ROAM_SAMPLES, wifi_roam_samples, 0);
+  }
+  #[inline]
+  pub fn add_tcp_idle_timeout_secs(&mut self, tcp_idle_timeout_secs: u16) {
+    self.fbb_.push_slot::<u16>(GetConfigResponse::VT_TCP_IDLE_TIMEOUT_SECS, tcp_idle_timeout_secs, 0);
+  }
+  #[inline]
+  pub fn add_ping_requires_auth(&mut self, ping_requires_auth: bool) {
+    self.fbb_.push_slot::<bool>(GetConfigResponse::VT_PING_REQUIRES_AUTH, ping_requires_auth, true);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetConfigResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetConfigResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetConfigResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetConfigResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetConfigResponse");
+      ds.field("pump_flow_ml_per_min", &self.pump_flow_ml_per_min());
+      ds.field("pump_duty_percent", &self.pump_duty_percent());
+      ds.field("prime_after_idle_secs", &self.prime_after_idle_secs());
+      ds.field("prime_duration_secs", &self.prime_duration_secs());
+      ds.field("prime_duty_percent", &self.prime_duty_percent());
+      ds.field("nh3_activate_ppm", &self.nh3_activate_ppm());
+      ds.field("nh3_deactivate_ppm", &self.nh3_deactivate_ppm());
+      ds.field("nh3_confirm_duration_secs", &self.nh3_confirm_duration_secs());
+      ds.field("nh3_urgent_ppm", &self.nh3_urgent_ppm());
+      ds.field("uvc_duty_percent", &self.uvc_duty_percent());
+      ds.field("uvc_warmup_secs", &self.uvc_warmup_secs());
+      ds.field("quiet_start_hour", &self.quiet_start_hour());
+      ds.field("quiet_end_hour", &self.quiet_end_hour());
+      ds.field("led_brightness", &self.led_brightness());
+      ds.field("led_quiet_brightness", &self.led_quiet_brightness());
+      ds.field("max_temperature_c", &self.max_temperature_c());
+      ds.field("purge_duration_secs", &self.purge_duration_secs());
+      ds.field("max_active_duration_secs", &self.max_active_duration_secs());
+      ds.field("min_water_level_percent", &self.min_water_level_percent());
+      ds.field("sensor_read_interval_ms", &self.sensor_read_interval_ms());
+      ds.field("control_loop_interval_ms", &self.control_loop_interval_ms());
+      ds.field("telemetry_interval_secs", &self.telemetry_interval_secs());
+      ds.field("stop_lockout_secs", &self.stop_lockout_secs());
+      ds.field("self_test_blocks_active", &self.self_test_blocks_active());
+      ds.field("purge_mode", &self.purge_mode());
+      ds.field("purge_target_volume_ml", &self.purge_target_volume_ml());
+      ds.field("purge_max_duration_secs", &self.purge_max_duration_secs());
+      ds.field("nh3_oversample_count", &self.nh3_oversample_count());
+      ds.field("fault_recovery_cooldown_secs", &self.fault_recovery_cooldown_secs());
+      ds.field("power_profile", &self.power_profile());
+      ds.field("led_overrides", &self.led_overrides());
+      ds.field("interlock_grace_secs", &self.interlock_grace_secs());
+      ds.field("flow_glitch_filter_us", &self.flow_glitch_filter_us());
+      ds.field("sensor_stale_after_secs", &self.sensor_stale_after_secs());
+      ds.field("soft_stop_ticks", &self.soft_stop_ticks());
+      ds.field("over_temp_debounce_samples", &self.over_temp_debounce_samples());
+      ds.field("nh3_rate_threshold_ppm_per_sec", &self.nh3_rate_threshold_ppm_per_sec());
+      ds.field("maintenance_mode", &self.maintenance_mode());
+      ds.field("nh3_warmup_secs", &self.nh3_warmup_secs());
+      ds.field("tank_source", &self.tank_source());
+      ds.field("min_active_secs", &self.min_active_secs());
+      ds.field("led_overlay_priority", &self.led_overlay_priority());
+      ds.field("nh3_alarm_high_ppm", &self.nh3_alarm_high_ppm());
+      ds.field("nh3_alarm_clear_ppm", &self.nh3_alarm_clear_ppm());
+      ds.field("nh3_adc_samples", &self.nh3_adc_samples());
+      ds.field("pump_derate_start_c", &self.pump_derate_start_c());
+      ds.field("pump_derate_min_duty_percent", &self.pump_derate_min_duty_percent());
+      ds.field("interlock_active_low", &self.interlock_active_low());
+      ds.field("state_max_secs", &self.state_max_secs());
+      ds.field("ble_status_interval_secs", &self.ble_status_interval_secs());
+      ds.field("pump_pid_kp", &self.pump_pid_kp());
+      ds.field("pump_pid_ki", &self.pump_pid_ki());
+      ds.field("pump_pid_kd", &self.pump_pid_kd());
+      ds.field("psk_grace_secs", &self.psk_grace_secs());
+      ds.field("max_control_dt_ms", &self.max_control_dt_ms());
+      ds.field("flow_filter_tau_ms", &self.flow_filter_tau_ms());
+      ds.field("auth_lockout_threshold", &self.auth_lockout_threshold());
+      ds.field("wifi_roam_rssi_dbm", &self.wifi_roam_rssi_dbm());
+      ds.field("wifi_roam_samples", &self.wifi_roam_samples());
+      ds.field("tcp_idle_timeout_secs", &self.tcp_idle_timeout_secs());
+      ds.field("ping_requires_auth", &self.ping_requires_auth());
+      ds.finish()
+  }
+}
+pub enum DeviceInfoResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DeviceInfoResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DeviceInfoResponse<'a> {
+  type Inner = DeviceInfoResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DeviceInfoResponse<'a> {
+  pub const VT_FIRMWARE_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_HARDWARE_REVISION: flatbuffers::VOffsetT = 6;
+  pub const VT_SERIAL_NUMBER: flatbuffers::VOffsetT = 8;
+  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 10;
+  pub const VT_CAPABILITIES: flatbuffers::VOffsetT = 12;
+  pub const VT_MAX_CLIENTS: flatbuffers::VOffsetT = 14;
+  pub const VT_RESET_CAUSE: flatbuffers::VOffsetT = 16;
+  pub const VT_WAKE_REASON: flatbuffers::VOffsetT = 18;
+  pub const VT_INSTALL_ID: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DeviceInfoResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DeviceInfoResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<DeviceInfoResponse<'bldr>> {
+    let mut builder = DeviceInfoResponseBuilder::new(_fbb);
+    builder.add_uptime_secs(args.uptime_secs);
+    if let Some(x) = args.install_id { builder.add_install_id(x); }
+    builder.add_capabilities(args.capabilities);
+    if let Some(x) = args.serial_number { builder.add_serial_number(x); }
+    if let Some(x) = args.hardware_revision { builder.add_hardware_revision(x); }
+    if let Some(x) = args.firmware_version { builder.add_firmware_version(x); }
+    builder.add_wake_reason(args.wake_reason);
+    builder.add_reset_cause(args.reset_cause);
+    builder.add_max_clients(args.max_clients);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn firmware_version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_FIRMWARE_VERSION, None)}
+  }
+  #[inline]
+  pub fn hardware_revision(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_HARDWARE_REVISION, None)}
+  }
+  #[inline]
+  pub fn serial_number(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_SERIAL_NUMBER, None)}
+  }
+  #[inline]
+  pub fn uptime_secs(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DeviceInfoResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+  }
+  /// Bitmask: bit0=compression, bit1=chunked, bit2=cert_auth, bit3=multi_client.
+  /// Legacy; new clients should use `GetCapabilitiesRequest`.
+  #[inline]
+  pub fn capabilities(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DeviceInfoResponse::VT_CAPABILITIES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_clients(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(DeviceInfoResponse::VT_MAX_CLIENTS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn reset_cause(&self) -> ResetCause {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ResetCause>(DeviceInfoResponse::VT_RESET_CAUSE, Some(ResetCause::Unknown)).unwrap()}
+  }
+  #[inline]
+  pub fn wake_reason(&self) -> WakeReason {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<WakeReason>(DeviceInfoResponse::VT_WAKE_REASON, Some(WakeReason::PowerOn)).unwrap()}
+  }
+  /// Random UUID generated on first boot and kept in NVS; unlike
+  /// `serial_number` it survives a board swap via `SetInstallIdRequest`.
+  #[inline]
+  pub fn install_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeviceInfoResponse::VT_INSTALL_ID, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for DeviceInfoResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("firmware_version", Self::VT_FIRMWARE_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("hardware_revision", Self::VT_HARDWARE_REVISION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("serial_number", Self::VT_SERIAL_NUMBER, false)?
+     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<u32>("capabilities", Self::VT_CAPABILITIES, false)?
+     .visit_field::<u8>("max_clients", Self::VT_MAX_CLIENTS, false)?
+     .visit_field::<ResetCause>("reset_cause", Self::VT_RESET_CAUSE, false)?
+     .visit_field::<WakeReason>("wake_reason", Self::VT_WAKE_REASON, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("install_id", Self::VT_INSTALL_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DeviceInfoResponseArgs<'a> {
+    pub firmware_version: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub hardware_revision: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub serial_number: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub uptime_secs: u64,
+    pub capabilities: u32,
+    pub max_clients: u8,
+    pub reset_cause: ResetCause,
+    pub wake_reason: WakeReason,
+    pub install_id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for DeviceInfoResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DeviceInfoResponseArgs {
+      firmware_version: None,
+      hardware_revision: None,
+      serial_number: None,
+      uptime_secs: 0,
+      capabilities: 0,
+      max_clients: 0,
+      reset_cause: ResetCause::Unknown,
+      wake_reason: WakeReason::PowerOn,
+      install_id: None,
+    }
+  }
+}
+
+pub struct DeviceInfoResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DeviceInfoResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_firmware_version(&mut self, firmware_version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_FIRMWARE_VERSION, firmware_version);
+  }
+  #[inline]
+  pub fn add_hardware_revision(&mut self, hardware_revision: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_HARDWARE_REVISION, hardware_revision);
+  }
+  #[inline]
+  pub fn add_serial_number(&mut self, serial_number: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_SERIAL_NUMBER, serial_number);
+  }
+  #[inline]
+  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
+    self.fbb_.push_slot::<u64>(DeviceInfoResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  }
+  #[inline]
+  pub fn add_capabilities(&mut self, capabilities: u32) {
+    self.fbb_.push_slot::<u32>(DeviceInfoResponse::VT_CAPABILITIES, capabilities, 0);
+  }
+  #[inline]
+  pub fn add_max_clients(&mut self, max_clients: u8) {
+    self.fbb_.push_slot::<u8>(DeviceInfoResponse::VT_MAX_CLIENTS, max_clients, 0);
+  }
+  #[inline]
+  pub fn add_reset_cause(&mut self, reset_cause: ResetCause) {
+    self.fbb_.push_slot::<ResetCause>(DeviceInfoResponse::VT_RESET_CAUSE, reset_cause, ResetCause::Unknown);
+  }
+  #[inline]
+  pub fn add_wake_reason(&mut self, wake_reason: WakeReason) {
+    self.fbb_.push_slot::<WakeReason>(DeviceInfoResponse::VT_WAKE_REASON, wake_reason, WakeReason::PowerOn);
+  }
+  #[inline]
+  pub fn add_install_id(&mut self, install_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeviceInfoResponse::VT_INSTALL_ID, install_id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DeviceInfoResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DeviceInfoResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DeviceInfoResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DeviceInfoResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DeviceInfoResponse");
+      ds.field("firmware_version", &self.firmware_version());
+      ds.field("hardware_revision", &self.hardware_revision());
+      ds.field("serial_number", &self.serial_number());
+      ds.field("uptime_secs", &self.uptime_secs());
+      ds.field("capabilities", &self.capabilities());
+      ds.field("max_clients", &self.max_clients());
+      ds.field("reset_cause", &self.reset_cause());
+      ds.field("wake_reason", &self.wake_reason());
+      ds.field("install_id", &self.install_id());
+      ds.finish()
+  }
+}
+pub enum AckResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AckResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AckResponse<'a> {
+  type Inner = AckResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AckResponse<'a> {
+  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AckResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AckResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<AckResponse<'bldr>> {
+    let mut builder = AckResponseBuilder::new(_fbb);
+    if let Some(x) = args.message { builder.add_message(x); }
+    builder.add_error_code(args.error_code);
+    builder.add_success(args.success);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn success(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(AckResponse::VT_SUCCESS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AckResponse::VT_MESSAGE, None)}
+  }
+  /// Machine-readable failure reason; `None` when `success` is true.
+  #[inline]
+  pub fn error_code(&self) -> AckError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AckError>(AckResponse::VT_ERROR_CODE, Some(AckError::None)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for AckResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<AckError>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AckResponseArgs<'a> {
+    pub success: bool,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error_code: AckError,
+}
+impl<'a> Default for AckResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AckResponseArgs {
+      success: false,
+      message: None,
+      error_code: AckError::None,
+    }
+  }
+}
+
+pub struct AckResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AckResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_success(&mut self, success: bool) {
+    self.fbb_.push_slot::<bool>(AckResponse::VT_SUCCESS, success, false);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AckResponse::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: AckError) {
+    self.fbb_.push_slot::<AckError>(AckResponse::VT_ERROR_CODE, error_code, AckError::None);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AckResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AckResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AckResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AckResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AckResponse");
+      ds.field("success", &self.success());
+      ds.field("message", &self.message());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+pub enum TelemetryFrameOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TelemetryFrame<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TelemetryFrame<'a> {
+  type Inner = TelemetryFrame<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TelemetryFrame<'a> {
+  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 4;
+  pub const VT_STATE: flatbuffers::VOffsetT = 6;
+  pub const VT_NH3_PPM: flatbuffers::VOffsetT = 8;
+  pub const VT_NH3_AVG_PPM: flatbuffers::VOffsetT = 10;
+  pub const VT_FLOW_ML_PER_MIN: flatbuffers::VOffsetT = 12;
+  pub const VT_TEMPERATURE_C: flatbuffers::VOffsetT = 14;
+  pub const VT_PUMP_DUTY: flatbuffers::VOffsetT = 16;
+  pub const VT_UVC_DUTY: flatbuffers::VOffsetT = 18;
+  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 20;
+  pub const VT_WIFI_RSSI: flatbuffers::VOffsetT = 22;
+  pub const VT_EFFECTIVE_INTERVAL_MS: flatbuffers::VOffsetT = 24;
+  pub const VT_PURGE_VOLUME_ML: flatbuffers::VOffsetT = 26;
+  pub const VT_DRAIN_VOLUME_ML: flatbuffers::VOffsetT = 28;
+  pub const VT_DRAIN_COMPLETE: flatbuffers::VOffsetT = 30;
+  pub const VT_POWER_PROFILE: flatbuffers::VOffsetT = 32;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 34;
+  pub const VT_CONNECTED_CLIENTS: flatbuffers::VOffsetT = 36;
+  pub const VT_PRESENT_FIELDS: flatbuffers::VOffsetT = 38;
+  pub const VT_NH3_ALARM: flatbuffers::VOffsetT = 40;
+  pub const VT_SOURCE_TANK: flatbuffers::VOffsetT = 42;
+  pub const VT_FAULT_TEST: flatbuffers::VOffsetT = 44;
+  pub const VT_NH3_INJECTED: flatbuffers::VOffsetT = 46;
+  pub const VT_SENSOR_WARMING: flatbuffers::VOffsetT = 48;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TelemetryFrame { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TelemetryFrameArgs
+  ) -> flatbuffers::WIPOffset<TelemetryFrame<'bldr>> {
+    let mut builder = TelemetryFrameBuilder::new(_fbb);
+    builder.add_timestamp_ms(args.timestamp_ms);
+    builder.add_present_fields(args.present_fields);
+    builder.add_drain_volume_ml(args.drain_volume_ml);
+    builder.add_purge_volume_ml(args.purge_volume_ml);
+    builder.add_effective_interval_ms(args.effective_interval_ms);
+    builder.add_temperature_c(args.temperature_c);
+    builder.add_flow_ml_per_min(args.flow_ml_per_min);
+    builder.add_nh3_avg_ppm(args.nh3_avg_ppm);
+    builder.add_nh3_ppm(args.nh3_ppm);
+    builder.add_sensor_warming(args.sensor_warming);
+    builder.add_nh3_injected(args.nh3_injected);
+    builder.add_fault_test(args.fault_test);
+    builder.add_source_tank(args.source_tank);
+    builder.add_nh3_alarm(args.nh3_alarm);
+    builder.add_connected_clients(args.connected_clients);
+    builder.add_zone(args.zone);
+    builder.add_power_profile(args.power_profile);
+    builder.add_drain_complete(args.drain_complete);
+    builder.add_wifi_rssi(args.wifi_rssi);
+    builder.add_fault_flags(args.fault_flags);
+    builder.add_uvc_duty(args.uvc_duty);
+    builder.add_pump_duty(args.pump_duty);
+    builder.add_state(args.state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn timestamp_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(TelemetryFrame::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn state(&self) -> DeviceState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceState>(TelemetryFrame::VT_STATE, Some(DeviceState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_NH3_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn nh3_avg_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_NH3_AVG_PPM, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn flow_ml_per_min(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_FLOW_ML_PER_MIN, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn temperature_c(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_TEMPERATURE_C, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn pump_duty(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_PUMP_DUTY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn uvc_duty(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_UVC_DUTY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn fault_flags(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_FAULT_FLAGS, Some(0)).unwrap()}
+  }
+  /// WiFi RSSI in dBm; 127 = not available (e.g. BLE-only).
+  #[inline]
+  pub fn wifi_rssi(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(TelemetryFrame::VT_WIFI_RSSI, Some(127)).unwrap()}
+  }
+  /// Interval the device is actually streaming at.  Larger than the
+  /// subscribed interval while the link is being throttled.
+  #[inline]
+  pub fn effective_interval_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TelemetryFrame::VT_EFFECTIVE_INTERVAL_MS, Some(0)).unwrap()}
+  }
+  /// Water flushed so far in the current (or last) purge, in mL.
+  #[inline]
+  pub fn purge_volume_ml(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_PURGE_VOLUME_ML, Some(0.0)).unwrap()}
+  }
+  /// Water pumped out so far in the current (or last) drain, in mL.
+  #[inline]
+  pub fn drain_volume_ml(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TelemetryFrame::VT_DRAIN_VOLUME_ML, Some(0.0)).unwrap()}
+  }
+  /// The last drain finished with both tanks empty.
+  #[inline]
+  pub fn drain_complete(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TelemetryFrame::VT_DRAIN_COMPLETE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn power_profile(&self) -> PowerProfile {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PowerProfile>(TelemetryFrame::VT_POWER_PROFILE, Some(PowerProfile::Balanced)).unwrap()}
+  }
+  /// Filter loop this frame describes (0 on single-loop units).
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_ZONE, Some(0)).unwrap()}
+  }
+  /// Clients attached right now, TCP and BLE combined.
+  #[inline]
+  pub fn connected_clients(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_CONNECTED_CLIENTS, Some(0)).unwrap()}
+  }
+  /// Fields carried by this frame, one bit per field in declaration
+  /// order from `state` (bit 0) to `connected_clients` (bit 15),
+  /// then `nh3_alarm` (bit 16), `source_tank` (bit 17), `fault_test`
+  /// (bit 18), `nh3_injected` (bit 19) and `sensor_warming` (bit 20).
+  /// All bits set on full frames; a delta frame leaves absent fields
+  /// at their defaults, to be taken from the previous frame.
+  #[inline]
+  pub fn present_fields(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TelemetryFrame::VT_PRESENT_FIELDS, Some(4294967295)).unwrap()}
+  }
+  /// Smoothed NH3 reached `nh3_alarm_high_ppm` and has not yet fallen
+  /// below `nh3_alarm_clear_ppm`.
+  #[inline]
+  pub fn nh3_alarm(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TelemetryFrame::VT_NH3_ALARM, Some(false)).unwrap()}
+  }
+  /// Tank the source valve draws from: 0 = Tank A, 1 = Tank B.
+  #[inline]
+  pub fn source_tank(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(TelemetryFrame::VT_SOURCE_TANK, Some(0)).unwrap()}
+  }
+  /// `fault_flags` come from a `TestFaultRequest`, not a real fault.
+  #[inline]
+  pub fn fault_test(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TelemetryFrame::VT_FAULT_TEST, Some(false)).unwrap()}
+  }
+  /// NH3 reading is synthetic (see InjectSensorRequest), not the sensor.
+  #[inline]
+  pub fn nh3_injected(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TelemetryFrame::VT_NH3_INJECTED, Some(false)).unwrap()}
+  }
+  /// NH3 sensor still in its post-boot soak; NH3 triggers are ignored.
+  #[inline]
+  pub fn sensor_warming(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TelemetryFrame::VT_SENSOR_WARMING, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TelemetryFrame<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
+     .visit_field::<DeviceState>("state", Self::VT_STATE, false)?
+     .visit_field::<f32>("nh3_ppm", Self::VT_NH3_PPM, false)?
+     .visit_field::<f32>("nh3_avg_ppm", Self::VT_NH3_AVG_PPM, false)?
+     .visit_field::<f32>("flow_ml_per_min", Self::VT_FLOW_ML_PER_MIN, false)?
+     .visit_field::<f32>("temperature_c", Self::VT_TEMPERATURE_C, false)?
+     .visit_field::<u8>("pump_duty", Self::VT_PUMP_DUTY, false)?
+     .visit_field::<u8>("uvc_duty", Self::VT_UVC_DUTY, false)?
+     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
+     .visit_field::<i8>("wifi_rssi", Self::VT_WIFI_RSSI, false)?
+     .visit_field::<u32>("effective_interval_ms", Self::VT_EFFECTIVE_INTERVAL_MS, false)?
+     .visit_field::<f32>("purge_volume_ml", Self::VT_PURGE_VOLUME_ML, false)?
+     .visit_field::<f32>("drain_volume_ml", Self::VT_DRAIN_VOLUME_ML, false)?
+     .visit_field::<bool>("drain_complete", Self::VT_DRAIN_COMPLETE, false)?
+     .visit_field::<PowerProfile>("power_profile", Self::VT_POWER_PROFILE, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .visit_field::<u8>("connected_clients", Self::VT_CONNECTED_CLIENTS, false)?
+     .visit_field::<u32>("present_fields", Self::VT_PRESENT_FIELDS, false)?
+     .visit_field::<bool>("nh3_alarm", Self::VT_NH3_ALARM, false)?
+     .visit_field::<u8>("source_tank", Self::VT_SOURCE_TANK, false)?
+     .visit_field::<bool>("fault_test", Self::VT_FAULT_TEST, false)?
+     .visit_field::<bool>("nh3_injected", Self::VT_NH3_INJECTED, false)?
+     .visit_field::<bool>("sensor_warming", Self::VT_SENSOR_WARMING, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TelemetryFrameArgs {
+    pub timestamp_ms: u64,
+    pub state: DeviceState,
+    pub nh3_ppm: f32,
+    pub nh3_avg_ppm: f32,
+    pub flow_ml_per_min: f32,
+    pub temperature_c: f32,
+    pub pump_duty: u8,
+    pub uvc_duty: u8,
+    pub fault_flags: u8,
+    pub wifi_rssi: i8,
+    pub effective_interval_ms: u32,
+    pub purge_volume_ml: f32,
+    pub drain_volume_ml: f32,
+    pub drain_complete: bool,
+    pub power_profile: PowerProfile,
+    pub zone: u8,
+    pub connected_clients: u8,
+    pub present_fields: u32,
+    pub nh3_alarm: bool,
+    pub source_tank: u8,
+    pub fault_test: bool,
+    pub nh3_injected: bool,
+    pub sensor_warming: bool,
+}
+impl<'a> Default for TelemetryFrameArgs {
+  #[inline]
+  fn default() -> Self {
+    TelemetryFrameArgs {
+      timestamp_ms: 0,
+      state: DeviceState::Idle,
+      nh3_ppm: 0.0,
+      nh3_avg_ppm: 0.0,
+      flow_ml_per_min: 0.0,
+      temperature_c: 0.0,
+      pump_duty: 0,
+      uvc_duty: 0,
+      fault_flags: 0,
+      wifi_rssi: 127,
+      effective_interval_ms: 0,
+      purge_volume_ml: 0.0,
+      drain_volume_ml: 0.0,
+      drain_complete: false,
+      power_profile: PowerProfile::Balanced,
+      zone: 0,
+      connected_clients: 0,
+      present_fields: 4294967295,
+      nh3_alarm: false,
+      source_tank: 0,
+      fault_test: false,
+      nh3_injected: false,
+      sensor_warming: false,
+    }
+  }
+}
+
+pub struct TelemetryFrameBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TelemetryFrameBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
+    self.fbb_.push_slot::<u64>(TelemetryFrame::VT_TIMESTAMP_MS, timestamp_ms, 0);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(TelemetryFrame::VT_STATE, state, DeviceState::Idle);
+  }
+  #[inline]
+  pub fn add_nh3_ppm(&mut self, nh3_ppm: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_NH3_PPM, nh3_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_nh3_avg_ppm(&mut self, nh3_avg_ppm: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_NH3_AVG_PPM, nh3_avg_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_flow_ml_per_min(&mut self, flow_ml_per_min: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_FLOW_ML_PER_MIN, flow_ml_per_min, 0.0);
+  }
+  #[inline]
+  pub fn add_temperature_c(&mut self, temperature_c: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_TEMPERATURE_C, temperature_c, 0.0);
+  }
+  #[inline]
+  pub fn add_pump_duty(&mut self, pump_duty: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_PUMP_DUTY, pump_duty, 0);
+  }
+  #[inline]
+  pub fn add_uvc_duty(&mut self, uvc_duty: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_UVC_DUTY, uvc_duty, 0);
+  }
+  #[inline]
+  pub fn add_fault_flags(&mut self, fault_flags: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_FAULT_FLAGS, fault_flags, 0);
+  }
+  #[inline]
+  pub fn add_wifi_rssi(&mut self, wifi_rssi: i8) {
+    self.fbb_.push_slot::<i8>(TelemetryFrame::VT_WIFI_RSSI, wifi_rssi, 127);
+  }
+  #[inline]
+  pub fn add_effective_interval_ms(&mut self, effective_interval_ms: u32) {
+    self.fbb_.push_slot::<u32>(TelemetryFrame::VT_EFFECTIVE_INTERVAL_MS, effective_interval_ms, 0);
+  }
+  #[inline]
+  pub fn add_purge_volume_ml(&mut self, purge_volume_ml: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_PURGE_VOLUME_ML, purge_volume_ml, 0.0);
+  }
+  #[inline]
+  pub fn add_drain_volume_ml(&mut self, drain_volume_ml: f32) {
+    self.fbb_.push_slot::<f32>(TelemetryFrame::VT_DRAIN_VOLUME_ML, drain_volume_ml, 0.0);
+  }
+  #[inline]
+  pub fn add_drain_complete(&mut self, drain_complete: bool) {
+    self.fbb_.push_slot::<bool>(TelemetryFrame::VT_DRAIN_COMPLETE, drain_complete, false);
+  }
+  #[inline]
+  pub fn add_power_profile(&mut self, power_profile: PowerProfile) {
+    self.fbb_.push_slot::<PowerProfile>(TelemetryFrame::VT_POWER_PROFILE, power_profile, PowerProfile::Balanced);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn add_connected_clients(&mut self, connected_clients: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_CONNECTED_CLIENTS, connected_clients, 0);
+  }
+  #[inline]
+  pub fn add_present_fields(&mut self, present_fields: u32) {
+    self.fbb_.push_slot::<u32>(TelemetryFrame::VT_PRESENT_FIELDS, present_fields, 4294967295);
+  }
+  #[inline]
+  pub fn add_nh3_alarm(&mut self, nh3_alarm: bool) {
+    self.fbb_.push_slot::<bool>(TelemetryFrame::VT_NH3_ALARM, nh3_alarm, false);
+  }
+  #[inline]
+  pub fn add_source_tank(&mut self, source_tank: u8) {
+    self.fbb_.push_slot::<u8>(TelemetryFrame::VT_SOURCE_TANK, source_tank, 0);
+  }
+  #[inline]
+  pub fn add_fault_test(&mut self, fault_test: bool) {
+    self.fbb_.push_slot::<bool>(TelemetryFrame::VT_FAULT_TEST, fault_test, false);
+  }
+  #[inline]
+  pub fn add_nh3_injected(&mut self, nh3_injected: bool) {
+    self.fbb_.push_slot::<bool>(TelemetryFrame::VT_NH3_INJECTED, nh3_injected, false);
+  }
+  #[inline]
+  pub fn add_sensor_warming(&mut self, sensor_warming: bool) {
+    self.fbb_.push_slot::<bool>(TelemetryFrame::VT_SENSOR_WARMING, sensor_warming, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TelemetryFrameBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TelemetryFrameBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TelemetryFrame<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TelemetryFrame<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TelemetryFrame");
+      ds.field("timestamp_ms", &self.timestamp_ms());
+      ds.field("state", &self.state());
+      ds.field("nh3_ppm", &self.nh3_ppm());
+      ds.field("nh3_avg_ppm", &self.nh3_avg_ppm());
+      ds.field("flow_ml_per_min", &self.flow_ml_per_min());
+      ds.field("temperature_c", &self.temperature_c());
+      ds.field("pump_duty", &self.pump_duty());
+      ds.field("uvc_duty", &self.uvc_duty());
+      ds.field("fault_flags", &self.fault_flags());
+      ds.field("wifi_rssi", &self.wifi_rssi());
+      ds.field("effective_interval_ms", &self.effective_interval_ms());
+      ds.field("purge_volume_ml", &self.purge_volume_ml());
+      ds.field("drain_volume_ml", &self.drain_volume_ml());
+      ds.field("drain_complete", &self.drain_complete());
+      ds.field("power_profile", &self.power_profile());
+      ds.field("zone", &self.zone());
+      ds.field("connected_clients", &self.connected_clients());
+      ds.field("present_fields", &self.present_fields());
+      ds.field("nh3_alarm", &self.nh3_alarm());
+      ds.field("source_tank", &self.source_tank());
+      ds.field("fault_test", &self.fault_test());
+      ds.field("nh3_injected", &self.nh3_injected());
+      ds.field("sensor_warming", &self.sensor_warming());
+      ds.finish()
+  }
+}
+pub enum GetTelemetryHistoryRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Backfill a telemetry chart from the on-device history ring.  Returns
+/// up to `max_samples` samples (capped at 16 per response) ending `skip`
+/// samples before the newest; page backwards by raising `skip`.
+pub struct GetTelemetryHistoryRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetTelemetryHistoryRequest<'a> {
+  type Inner = GetTelemetryHistoryRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetTelemetryHistoryRequest<'a> {
+  pub const VT_MAX_SAMPLES: flatbuffers::VOffsetT = 4;
+  pub const VT_SKIP: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetTelemetryHistoryRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetTelemetryHistoryRequestArgs
+  ) -> flatbuffers::WIPOffset<GetTelemetryHistoryRequest<'bldr>> {
+    let mut builder = GetTelemetryHistoryRequestBuilder::new(_fbb);
+    builder.add_skip(args.skip);
+    builder.add_max_samples(args.max_samples);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn max_samples(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetTelemetryHistoryRequest::VT_MAX_SAMPLES, Some(16)).unwrap()}
+  }
+  #[inline]
+  pub fn skip(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetTelemetryHistoryRequest::VT_SKIP, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GetTelemetryHistoryRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("max_samples", Self::VT_MAX_SAMPLES, false)?
+     .visit_field::<u8>("skip", Self::VT_SKIP, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetTelemetryHistoryRequestArgs {
+    pub max_samples: u8,
+    pub skip: u8,
+}
+impl<'a> Default for GetTelemetryHistoryRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetTelemetryHistoryRequestArgs {
+      max_samples: 16,
+      skip: 0,
+    }
+  }
+}
+
+pub struct GetTelemetryHistoryRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetTelemetryHistoryRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_max_samples(&mut self, max_samples: u8) {
+    self.fbb_.push_slot::<u8>(GetTelemetryHistoryRequest::VT_MAX_SAMPLES, max_samples, 16);
+  }
+  #[inline]
+  pub fn add_skip(&mut self, skip: u8) {
+    self.fbb_.push_slot::<u8>(GetTelemetryHistoryRequest::VT_SKIP, skip, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetTelemetryHistoryRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetTelemetryHistoryRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetTelemetryHistoryRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetTelemetryHistoryRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetTelemetryHistoryRequest");
+      ds.field("max_samples", &self.max_samples());
+      ds.field("skip", &self.skip());
+      ds.finish()
+  }
+}
+pub enum GetTelemetryHistoryResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// History page, oldest first.  `uptime_secs` is "now" on the same
+/// clock as the sample stamps, so clients can compute each sample's age.
+pub struct GetTelemetryHistoryResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetTelemetryHistoryResponse<'a> {
+  type Inner = GetTelemetryHistoryResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetTelemetryHistoryResponse<'a> {
+  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 4;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 6;
+  pub const VT_SAMPLES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetTelemetryHistoryResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetTelemetryHistoryResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<GetTelemetryHistoryResponse<'bldr>> {
+    let mut builder = GetTelemetryHistoryResponseBuilder::new(_fbb);
+    if let Some(x) = args.samples { builder.add_samples(x); }
+    builder.add_uptime_secs(args.uptime_secs);
+    builder.add_zone(args.zone);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn uptime_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GetTelemetryHistoryResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(GetTelemetryHistoryResponse::VT_ZONE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn samples(&self) -> Option<flatbuffers::Vector<'a, TelemetrySampleFbs>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, TelemetrySampleFbs>>>(GetTelemetryHistoryResponse::VT_SAMPLES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for GetTelemetryHistoryResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, TelemetrySampleFbs>>>("samples", Self::VT_SAMPLES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetTelemetryHistoryResponseArgs<'a> {
+    pub uptime_secs: u32,
+    pub zone: u8,
+    pub samples: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, TelemetrySampleFbs>>>,
+}
+impl<'a> Default for GetTelemetryHistoryResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GetTelemetryHistoryResponseArgs {
+      uptime_secs: 0,
+      zone: 0,
+      samples: None,
+    }
+  }
+}
+
+pub struct GetTelemetryHistoryResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetTelemetryHistoryResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_uptime_secs(&mut self, uptime_secs: u32) {
+    self.fbb_.push_slot::<u32>(GetTelemetryHistoryResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(GetTelemetryHistoryResponse::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn add_samples(&mut self, samples: flatbuffers::WIPOffset<flatbuffers::Vector<'b , TelemetrySampleFbs>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetTelemetryHistoryResponse::VT_SAMPLES, samples);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetTelemetryHistoryResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetTelemetryHistoryResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetTelemetryHistoryResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetTelemetryHistoryResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetTelemetryHistoryResponse");
+      ds.field("uptime_secs", &self.uptime_secs());
+      ds.field("zone", &self.zone());
+      ds.field("samples", &self.samples());
+      ds.finish()
+  }
+}
+pub enum StateChangeEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StateChangeEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StateChangeEvent<'a> {
+  type Inner = StateChangeEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StateChangeEvent<'a> {
+  pub const VT_FROM_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_TO_STATE: flatbuffers::VOffsetT = 6;
+  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 8;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StateChangeEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StateChangeEventArgs
+  ) -> flatbuffers::WIPOffset<StateChangeEvent<'bldr>> {
+    let mut builder = StateChangeEventBuilder::new(_fbb);
+    builder.add_timestamp_ms(args.timestamp_ms);
+    builder.add_zone(args.zone);
+    builder.add_to_state(args.to_state);
+    builder.add_from_state(args.from_state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn from_state(&self) -> DeviceState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceState>(StateChangeEvent::VT_FROM_STATE, Some(DeviceState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn to_state(&self) -> DeviceState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceState>(StateChangeEvent::VT_TO_STATE, Some(DeviceState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn timestamp_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(StateChangeEvent::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(StateChangeEvent::VT_ZONE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StateChangeEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<DeviceState>("from_state", Self::VT_FROM_STATE, false)?
+     .visit_field::<DeviceState>("to_state", Self::VT_TO_STATE, false)?
+     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StateChangeEventArgs {
+    pub from_state: DeviceState,
+    pub to_state: DeviceState,
+    pub timestamp_ms: u64,
+    pub zone: u8,
+}
+impl<'a> Default for StateChangeEventArgs {
+  #[inline]
+  fn default() -> Self {
+    StateChangeEventArgs {
+      from_state: DeviceState::Idle,
+      to_state: DeviceState::Idle,
+      timestamp_ms: 0,
+      zone: 0,
+    }
+  }
+}
+
+pub struct StateChangeEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StateChangeEventBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_from_state(&mut self, from_state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(StateChangeEvent::VT_FROM_STATE, from_state, DeviceState::Idle);
+  }
+  #[inline]
+  pub fn add_to_state(&mut self, to_state: DeviceState) {
+    self.fbb_.push_slot::<DeviceState>(StateChangeEvent::VT_TO_STATE, to_state, DeviceState::Idle);
+  }
+  #[inline]
+  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
+    self.fbb_.push_slot::<u64>(StateChangeEvent::VT_TIMESTAMP_MS, timestamp_ms, 0);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(StateChangeEvent::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StateChangeEventBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StateChangeEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StateChangeEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StateChangeEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StateChangeEvent");
+      ds.field("from_state", &self.from_state());
+      ds.field("to_state", &self.to_state());
+      ds.field("timestamp_ms", &self.timestamp_ms());
+      ds.field("zone", &self.zone());
+      ds.finish()
+  }
+}
+pub enum FaultEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FaultEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FaultEvent<'a> {
+  type Inner = FaultEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FaultEvent<'a> {
+  pub const VT_FAULT_FLAGS: flatbuffers::VOffsetT = 4;
+  pub const VT_IS_CLEAR: flatbuffers::VOffsetT = 6;
+  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 8;
+  pub const VT_IS_TEST: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FaultEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FaultEventArgs
+  ) -> flatbuffers::WIPOffset<FaultEvent<'bldr>> {
+    let mut builder = FaultEventBuilder::new(_fbb);
+    builder.add_timestamp_ms(args.timestamp_ms);
+    builder.add_is_test(args.is_test);
+    builder.add_is_clear(args.is_clear);
+    builder.add_fault_flags(args.fault_flags);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn fault_flags(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(FaultEvent::VT_FAULT_FLAGS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn is_clear(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FaultEvent::VT_IS_CLEAR, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn timestamp_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FaultEvent::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+  }
+  /// The fault was raised by `TestFaultRequest`, not a real condition.
+  #[inline]
+  pub fn is_test(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FaultEvent::VT_IS_TEST, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FaultEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("fault_flags", Self::VT_FAULT_FLAGS, false)?
+     .visit_field::<bool>("is_clear", Self::VT_IS_CLEAR, false)?
+     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
+     .visit_field::<bool>("is_test", Self::VT_IS_TEST, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FaultEventArgs {
+    pub fault_flags: u8,
+    pub is_clear: bool,
+    pub timestamp_ms: u64,
+    pub is_test: bool,
+}
+impl<'a> Default for FaultEventArgs {
+  #[inline]
+  fn default() -> Self {
+    FaultEventArgs {
+      fault_flags: 0,
+      is_clear: false,
+      timestamp_ms: 0,
+      is_test: false,
+    }
+  }
+}
+
+pub struct FaultEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FaultEventBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_fault_flags(&mut self, fault_flags: u8) {
+    self.fbb_.push_slot::<u8>(FaultEvent::VT_FAULT_FLAGS, fault_flags, 0);
+  }
+  #[inline]
+  pub fn add_is_clear(&mut self, is_clear: bool) {
+    self.fbb_.push_slot::<bool>(FaultEvent::VT_IS_CLEAR, is_clear, false);
+  }
+  #[inline]
+  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
+    self.fbb_.push_slot::<u64>(FaultEvent::VT_TIMESTAMP_MS, timestamp_ms, 0);
+  }
+  #[inline]
+  pub fn add_is_test(&mut self, is_test: bool) {
+    self.fbb_.push_slot::<bool>(FaultEvent::VT_IS_TEST, is_test, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FaultEventBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FaultEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FaultEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FaultEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FaultEvent");
+      ds.field("fault_flags", &self.fault_flags());
+      ds.field("is_clear", &self.is_clear());
+      ds.field("timestamp_ms", &self.timestamp_ms());
+      ds.field("is_test", &self.is_test());
+      ds.finish()
+  }
+}
+pub enum Nh3AlarmEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Broadcast to subscribed clients when the high-ammonia alarm raises
+/// or clears.
+pub struct Nh3AlarmEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Nh3AlarmEvent<'a> {
+  type Inner = Nh3AlarmEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Nh3AlarmEvent<'a> {
+  pub const VT_ACTIVE: flatbuffers::VOffsetT = 4;
+  pub const VT_NH3_AVG_PPM: flatbuffers::VOffsetT = 6;
+  pub const VT_TIMESTAMP_MS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Nh3AlarmEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args Nh3AlarmEventArgs
+  ) -> flatbuffers::WIPOffset<Nh3AlarmEvent<'bldr>> {
+    let mut builder = Nh3AlarmEventBuilder::new(_fbb);
+    builder.add_timestamp_ms(args.timestamp_ms);
+    builder.add_nh3_avg_ppm(args.nh3_avg_ppm);
+    builder.add_active(args.active);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn active(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Nh3AlarmEvent::VT_ACTIVE, Some(false)).unwrap()}
+  }
+  /// Smoothed NH3 at the edge, in ppm.
+  #[inline]
+  pub fn nh3_avg_ppm(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Nh3AlarmEvent::VT_NH3_AVG_PPM, Some(0.0)).unwrap()}
+  }
+  /// Device uptime at the edge.
+  #[inline]
+  pub fn timestamp_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Nh3AlarmEvent::VT_TIMESTAMP_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Nh3AlarmEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("active", Self::VT_ACTIVE, false)?
+     .visit_field::<f32>("nh3_avg_ppm", Self::VT_NH3_AVG_PPM, false)?
+     .visit_field::<u64>("timestamp_ms", Self::VT_TIMESTAMP_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct Nh3AlarmEventArgs {
+    pub active: bool,
+    pub nh3_avg_ppm: f32,
+    pub timestamp_ms: u64,
+}
+impl<'a> Default for Nh3AlarmEventArgs {
+  #[inline]
+  fn default() -> Self {
+    Nh3AlarmEventArgs {
+      active: false,
+      nh3_avg_ppm: 0.0,
+      timestamp_ms: 0,
+    }
+  }
+}
+
+pub struct Nh3AlarmEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Nh3AlarmEventBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_active(&mut self, active: bool) {
+    self.fbb_.push_slot::<bool>(Nh3AlarmEvent::VT_ACTIVE, active, false);
+  }
+  #[inline]
+  pub fn add_nh3_avg_ppm(&mut self, nh3_avg_ppm: f32) {
+    self.fbb_.push_slot::<f32>(Nh3AlarmEvent::VT_NH3_AVG_PPM, nh3_avg_ppm, 0.0);
+  }
+  #[inline]
+  pub fn add_timestamp_ms(&mut self, timestamp_ms: u64) {
+    self.fbb_.push_slot::<u64>(Nh3AlarmEvent::VT_TIMESTAMP_MS, timestamp_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Nh3AlarmEventBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    Nh3AlarmEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Nh3AlarmEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Nh3AlarmEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Nh3AlarmEvent");
+      ds.field("active", &self.active());
+      ds.field("nh3_avg_ppm", &self.nh3_avg_ppm());
+      ds.field("timestamp_ms", &self.timestamp_ms());
+      ds.finish()
+  }
+}
+pub enum ConfigChangedEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Broadcast to subscribed clients whenever the applied config changes,
+/// so other connected UIs can refresh without re-querying.
+pub struct ConfigChangedEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ConfigChangedEvent<'a> {
+  type Inner = ConfigChangedEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ConfigChangedEvent<'a> {
+  pub const VT_CONFIG: flatbuffers::VOffsetT = 4;
+  pub const VT_ZONE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ConfigChangedEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ConfigChangedEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<ConfigChangedEvent<'bldr>> {
+    let mut builder = ConfigChangedEventBuilder::new(_fbb);
+    if let Some(x) = args.config { builder.add_config(x); }
+    builder.add_zone(args.zone);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn config(&self) -> Option<GetConfigResponse<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<GetConfigResponse>>(ConfigChangedEvent::VT_CONFIG, None)}
+  }
+  #[inline]
+  pub fn zone(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(ConfigChangedEvent::VT_ZONE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ConfigChangedEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<GetConfigResponse>>("config", Self::VT_CONFIG, false)?
+     .visit_field::<u8>("zone", Self::VT_ZONE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ConfigChangedEventArgs<'a> {
+    pub config: Option<flatbuffers::WIPOffset<GetConfigResponse<'a>>>,
+    pub zone: u8,
+}
+impl<'a> Default for ConfigChangedEventArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ConfigChangedEventArgs {
+      config: None,
+      zone: 0,
+    }
+  }
+}
+
+pub struct ConfigChangedEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConfigChangedEventBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_config(&mut self, config: flatbuffers::WIPOffset<GetConfigResponse<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<GetConfigResponse>>(ConfigChangedEvent::VT_CONFIG, config);
+  }
+  #[inline]
+  pub fn add_zone(&mut self, zone: u8) {
+    self.fbb_.push_slot::<u8>(ConfigChangedEvent::VT_ZONE, zone, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConfigChangedEventBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ConfigChangedEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ConfigChangedEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ConfigChangedEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ConfigChangedEvent");
+      ds.field("config", &self.config());
+      ds.field("zone", &self.zone());
+      ds.finish()
+  }
+}
+pub enum AuthChallengeRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AuthChallengeRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AuthChallengeRequest<'a> {
+  type Inner = AuthChallengeRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AuthChallengeRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AuthChallengeRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args AuthChallengeRequestArgs
+  ) -> flatbuffers::WIPOffset<AuthChallengeRequest<'bldr>> {
+    let mut builder = AuthChallengeRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for AuthChallengeRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AuthChallengeRequestArgs {
+}
+impl<'a> Default for AuthChallengeRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    AuthChallengeRequestArgs {
+    }
+  }
+}
+
+pub struct AuthChallengeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthChallengeRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthChallengeRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AuthChallengeRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AuthChallengeRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AuthChallengeRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AuthChallengeRequest");
+      ds.finish()
+  }
+}
+pub enum AuthVerifyRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AuthVerifyRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AuthVerifyRequest<'a> {
+  type Inner = AuthVerifyRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AuthVerifyRequest<'a> {
+  pub const VT_SESSION_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_HMAC: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AuthVerifyRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AuthVerifyRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<AuthVerifyRequest<'bldr>> {
+    let mut builder = AuthVerifyRequestBuilder::new(_fbb);
+    if let Some(x) = args.hmac { builder.add_hmac(x); }
+    builder.add_session_id(args.session_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn session_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(AuthVerifyRequest::VT_SESSION_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn hmac(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AuthVerifyRequest::VT_HMAC, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AuthVerifyRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("session_id", Self::VT_SESSION_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("hmac", Self::VT_HMAC, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AuthVerifyRequestArgs<'a> {
+    pub session_id: u32,
+    pub hmac: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for AuthVerifyRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AuthVerifyRequestArgs {
+      session_id: 0,
+      hmac: None,
+    }
+  }
+}
+
+pub struct AuthVerifyRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthVerifyRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_session_id(&mut self, session_id: u32) {
+    self.fbb_.push_slot::<u32>(AuthVerifyRequest::VT_SESSION_ID, session_id, 0);
+  }
+  #[inline]
+  pub fn add_hmac(&mut self, hmac: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthVerifyRequest::VT_HMAC, hmac);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthVerifyRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AuthVerifyRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AuthVerifyRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AuthVerifyRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AuthVerifyRequest");
+      ds.field("session_id", &self.session_id());
+      ds.field("hmac", &self.hmac());
+      ds.finish()
+  }
+}
+pub enum SessionMigrationTokenRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Ask for a token that lets a new TCP connection take over this
+/// authenticated BLE session.  Only accepted on the BLE slot.
+pub struct SessionMigrationTokenRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SessionMigrationTokenRequest<'a> {
+  type Inner = SessionMigrationTokenRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SessionMigrationTokenRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SessionMigrationTokenRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args SessionMigrationTokenRequestArgs
+  ) -> flatbuffers::WIPOffset<SessionMigrationTokenRequest<'bldr>> {
+    let mut builder = SessionMigrationTokenRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for SessionMigrationTokenRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SessionMigrationTokenRequestArgs {
+}
+impl<'a> Default for SessionMigrationTokenRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SessionMigrationTokenRequestArgs {
+    }
+  }
+}
+
+pub struct SessionMigrationTokenRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SessionMigrationTokenRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SessionMigrationTokenRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SessionMigrationTokenRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SessionMigrationTokenRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SessionMigrationTokenRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SessionMigrationTokenRequest");
+      ds.finish()
+  }
+}
+pub enum MigrateSessionRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Authenticate a new TCP connection with a token issued over BLE,
+/// skipping the challenge-response.  Answered with `AuthVerifyResponse`.
+pub struct MigrateSessionRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MigrateSessionRequest<'a> {
+  type Inner = MigrateSessionRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MigrateSessionRequest<'a> {
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MigrateSessionRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MigrateSessionRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<MigrateSessionRequest<'bldr>> {
+    let mut builder = MigrateSessionRequestBuilder::new(_fbb);
+    if let Some(x) = args.token { builder.add_token(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(MigrateSessionRequest::VT_TOKEN, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for MigrateSessionRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MigrateSessionRequestArgs<'a> {
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for MigrateSessionRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MigrateSessionRequestArgs {
+      token: None,
+    }
+  }
+}
+
+pub struct MigrateSessionRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MigrateSessionRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MigrateSessionRequest::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MigrateSessionRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MigrateSessionRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MigrateSessionRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MigrateSessionRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MigrateSessionRequest");
+      ds.field("token", &self.token());
+      ds.finish()
+  }
+}
+pub enum SetPskGraceRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Set how long the previous PSK keeps authenticating after a rotation,
+/// persisted with the system config.  Takes effect at the next rotation.
+pub struct SetPskGraceRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetPskGraceRequest<'a> {
+  type Inner = SetPskGraceRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetPskGraceRequest<'a> {
+  pub const VT_GRACE_SECS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetPskGraceRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetPskGraceRequestArgs
+  ) -> flatbuffers::WIPOffset<SetPskGraceRequest<'bldr>> {
+    let mut builder = SetPskGraceRequestBuilder::new(_fbb);
+    builder.add_grace_secs(args.grace_secs);
+    builder.finish()
+  }
+
+
+  /// Grace period in seconds (0 = revoke the previous key at once).
+  #[inline]
+  pub fn grace_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SetPskGraceRequest::VT_GRACE_SECS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetPskGraceRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("grace_secs", Self::VT_GRACE_SECS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetPskGraceRequestArgs {
+    pub grace_secs: u32,
+}
+impl<'a> Default for SetPskGraceRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SetPskGraceRequestArgs {
+      grace_secs: 0,
+    }
+  }
+}
+
+pub struct SetPskGraceRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetPskGraceRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_grace_secs(&mut self, grace_secs: u32) {
+    self.fbb_.push_slot::<u32>(SetPskGraceRequest::VT_GRACE_SECS, grace_secs, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetPskGraceRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetPskGraceRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetPskGraceRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetPskGraceRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetPskGraceRequest");
+      ds.field("grace_secs", &self.grace_secs());
+      ds.finish()
+  }
+}
+pub enum SetAuthLockoutRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Set how many consecutive failed auth verifies lock a client out,
+/// persisted with the system config.  Applies to the next failure.
+pub struct SetAuthLockoutRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetAuthLockoutRequest<'a> {
+  type Inner = SetAuthLockoutRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetAuthLockoutRequest<'a> {
+  pub const VT_THRESHOLD: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetAuthLockoutRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetAuthLockoutRequestArgs
+  ) -> flatbuffers::WIPOffset<SetAuthLockoutRequest<'bldr>> {
+    let mut builder = SetAuthLockoutRequestBuilder::new(_fbb);
+    builder.add_threshold(args.threshold);
+    builder.finish()
+  }
+
+
+  /// Failures before the first lockout (0 = never lock out).
+  #[inline]
+  pub fn threshold(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SetAuthLockoutRequest::VT_THRESHOLD, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetAuthLockoutRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("threshold", Self::VT_THRESHOLD, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetAuthLockoutRequestArgs {
+    pub threshold: u8,
+}
+impl<'a> Default for SetAuthLockoutRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SetAuthLockoutRequestArgs {
+      threshold: 0,
+    }
+  }
+}
+
+pub struct SetAuthLockoutRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetAuthLockoutRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_threshold(&mut self, threshold: u8) {
+    self.fbb_.push_slot::<u8>(SetAuthLockoutRequest::VT_THRESHOLD, threshold, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetAuthLockoutRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetAuthLockoutRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetAuthLockoutRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetAuthLockoutRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetAuthLockoutRequest");
+      ds.field("threshold", &self.threshold());
+      ds.finish()
+  }
+}
+pub enum SetWifiRoamRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Set when the station roams to a stronger AP of the same SSID,
+/// persisted with the system config.  Applies from the next poll.
+pub struct SetWifiRoamRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetWifiRoamRequest<'a> {
+  type Inner = SetWifiRoamRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetWifiRoamRequest<'a> {
+  pub const VT_RSSI_DBM: flatbuffers::VOffsetT = 4;
+  pub const VT_SAMPLES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetWifiRoamRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetWifiRoamRequestArgs
+  ) -> flatbuffers::WIPOffset<SetWifiRoamRequest<'bldr>> {
+    let mut builder = SetWifiRoamRequestBuilder::new(_fbb);
+    builder.add_samples(args.samples);
+    builder.add_rssi_dbm(args.rssi_dbm);
+    builder.finish()
+  }
+
+
+  /// RSSI below which the current AP counts as weak (dBm).
+  #[inline]
+  pub fn rssi_dbm(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(SetWifiRoamRequest::VT_RSSI_DBM, Some(0)).unwrap()}
+  }
+  /// Consecutive weak polls before a roam (at least 1).
+  #[inline]
+  pub fn samples(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(SetWifiRoamRequest::VT_SAMPLES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetWifiRoamRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i8>("rssi_dbm", Self::VT_RSSI_DBM, false)?
+     .visit_field::<u8>("samples", Self::VT_SAMPLES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetWifiRoamRequestArgs {
+    pub rssi_dbm: i8,
+    pub samples: u8,
+}
+impl<'a> Default for SetWifiRoamRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SetWifiRoamRequestArgs {
+      rssi_dbm: 0,
+      samples: 0,
+    }
+  }
+}
+
+pub struct SetWifiRoamRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetWifiRoamRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_rssi_dbm(&mut self, rssi_dbm: i8) {
+    self.fbb_.push_slot::<i8>(SetWifiRoamRequest::VT_RSSI_DBM, rssi_dbm, 0);
+  }
+  #[inline]
+  pub fn add_samples(&mut self, samples: u8) {
+    self.fbb_.push_slot::<u8>(SetWifiRoamRequest::VT_SAMPLES, samples, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetWifiRoamRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetWifiRoamRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetWifiRoamRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetWifiRoamRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetWifiRoamRequest");
+      ds.field("rssi_dbm", &self.rssi_dbm());
+      ds.field("samples", &self.samples());
+      ds.finish()
+  }
+}
+pub enum SetTcpIdleTimeoutRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Set how long a TCP client may send and receive nothing before the
+/// server disconnects it and frees its slot.
+pub struct SetTcpIdleTimeoutRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetTcpIdleTimeoutRequest<'a> {
+  type Inner = SetTcpIdleTimeoutRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetTcpIdleTimeoutRequest<'a> {
+  pub const VT_TIMEOUT_SECS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetTcpIdleTimeoutRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetTcpIdleTimeoutRequestArgs
+  ) -> flatbuffers::WIPOffset<SetTcpIdleTimeoutRequest<'bldr>> {
+    let mut builder = SetTcpIdleTimeoutRequestBuilder::new(_fbb);
+    builder.add_timeout_secs(args.timeout_secs);
+    builder.finish()
+  }
+
+
+  /// Seconds of silence allowed; 0 = never disconnect, else 10–3600.
+  #[inline]
+  pub fn timeout_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(SetTcpIdleTimeoutRequest::VT_TIMEOUT_SECS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetTcpIdleTimeoutRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("timeout_secs", Self::VT_TIMEOUT_SECS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetTcpIdleTimeoutRequestArgs {
+    pub timeout_secs: u16,
+}
+impl<'a> Default for SetTcpIdleTimeoutRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SetTcpIdleTimeoutRequestArgs {
+      timeout_secs: 0,
+    }
+  }
+}
+
+pub struct SetTcpIdleTimeoutRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetTcpIdleTimeoutRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_timeout_secs(&mut self, timeout_secs: u16) {
+    self.fbb_.push_slot::<u16>(SetTcpIdleTimeoutRequest::VT_TIMEOUT_SECS, timeout_secs, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetTcpIdleTimeoutRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetTcpIdleTimeoutRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetTcpIdleTimeoutRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetTcpIdleTimeoutRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetTcpIdleTimeoutRequest");
+      ds.field("timeout_secs", &self.timeout_secs());
+      ds.finish()
+  }
+}
+pub enum SetPingAuthRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Set whether `PingRequest` needs an authenticated session, persisted
+/// with the system config.  Applies to the next ping.
+pub struct SetPingAuthRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetPingAuthRequest<'a> {
+  type Inner = SetPingAuthRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetPingAuthRequest<'a> {
+  pub const VT_REQUIRED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetPingAuthRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetPingAuthRequestArgs
+  ) -> flatbuffers::WIPOffset<SetPingAuthRequest<'bldr>> {
+    let mut builder = SetPingAuthRequestBuilder::new(_fbb);
+    builder.add_required(args.required);
+    builder.finish()
+  }
+
+
+  /// False lets clients ping during the handshake too.
+  #[inline]
+  pub fn required(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SetPingAuthRequest::VT_REQUIRED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetPingAuthRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("required", Self::VT_REQUIRED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetPingAuthRequestArgs {
+    pub required: bool,
+}
+impl<'a> Default for SetPingAuthRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    SetPingAuthRequestArgs {
+      required: false,
+    }
+  }
+}
+
+pub struct SetPingAuthRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetPingAuthRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_required(&mut self, required: bool) {
+    self.fbb_.push_slot::<bool>(SetPingAuthRequest::VT_REQUIRED, required, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetPingAuthRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetPingAuthRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetPingAuthRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetPingAuthRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetPingAuthRequest");
+      ds.field("required", &self.required());
+      ds.finish()
+  }
+}
+pub enum AuthChallengeResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AuthChallengeResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AuthChallengeResponse<'a> {
+  type Inner = AuthChallengeResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AuthChallengeResponse<'a> {
+  pub const VT_NONCE: flatbuffers::VOffsetT = 4;
+  pub const VT_SESSION_ID: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AuthChallengeResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AuthChallengeResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<AuthChallengeResponse<'bldr>> {
+    let mut builder = AuthChallengeResponseBuilder::new(_fbb);
+    builder.add_session_id(args.session_id);
+    if let Some(x) = args.nonce { builder.add_nonce(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nonce(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AuthChallengeResponse::VT_NONCE, None)}
+  }
+  #[inline]
+  pub fn session_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(AuthChallengeResponse::VT_SESSION_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for AuthChallengeResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("nonce", Self::VT_NONCE, false)?
+     .visit_field::<u32>("session_id", Self::VT_SESSION_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AuthChallengeResponseArgs<'a> {
+    pub nonce: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub session_id: u32,
+}
+impl<'a> Default for AuthChallengeResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AuthChallengeResponseArgs {
+      nonce: None,
+      session_id: 0,
+    }
+  }
+}
+
+pub struct AuthChallengeResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthChallengeResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_nonce(&mut self, nonce: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthChallengeResponse::VT_NONCE, nonce);
+  }
+  #[inline]
+  pub fn add_session_id(&mut self, session_id: u32) {
+    self.fbb_.push_slot::<u32>(AuthChallengeResponse::VT_SESSION_ID, session_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthChallengeResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AuthChallengeResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AuthChallengeResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AuthChallengeResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AuthChallengeResponse");
+      ds.field("nonce", &self.nonce());
+      ds.field("session_id", &self.session_id());
+      ds.finish()
+  }
+}
+pub enum AuthVerifyResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AuthVerifyResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AuthVerifyResponse<'a> {
+  type Inner = AuthVerifyResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AuthVerifyResponse<'a> {
+  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AuthVerifyResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AuthVerifyResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<AuthVerifyResponse<'bldr>> {
+    let mut builder = AuthVerifyResponseBuilder::new(_fbb);
+    if let Some(x) = args.message { builder.add_message(x); }
+    builder.add_success(args.success);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn success(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(AuthVerifyResponse::VT_SUCCESS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthVerifyResponse::VT_MESSAGE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AuthVerifyResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AuthVerifyResponseArgs<'a> {
+    pub success: bool,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for AuthVerifyResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AuthVerifyResponseArgs {
+      success: false,
+      message: None,
+    }
+  }
+}
+
+pub struct AuthVerifyResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthVerifyResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_success(&mut self, success: bool) {
+    self.fbb_.push_slot::<bool>(AuthVerifyResponse::VT_SUCCESS, success, false);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthVerifyResponse::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthVerifyResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AuthVerifyResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AuthVerifyResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AuthVerifyResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AuthVerifyResponse");
+      ds.field("success", &self.success());
+      ds.field("message", &self.message());
+      ds.finish()
+  }
+}
+pub enum SessionMigrationTokenResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Single-use migration token, redeemable once with
+/// `MigrateSessionRequest` before it expires.
+pub struct SessionMigrationTokenResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SessionMigrationTokenResponse<'a> {
+  type Inner = SessionMigrationTokenResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SessionMigrationTokenResponse<'a> {
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 4;
+  pub const VT_TTL_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SessionMigrationTokenResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SessionMigrationTokenResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<SessionMigrationTokenResponse<'bldr>> {
+    let mut builder = SessionMigrationTokenResponseBuilder::new(_fbb);
+    builder.add_ttl_ms(args.ttl_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(SessionMigrationTokenResponse::VT_TOKEN, None)}
+  }
+  /// Time left to redeem the token.
+  #[inline]
+  pub fn ttl_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SessionMigrationTokenResponse::VT_TTL_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SessionMigrationTokenResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("ttl_ms", Self::VT_TTL_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SessionMigrationTokenResponseArgs<'a> {
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub ttl_ms: u32,
+}
+impl<'a> Default for SessionMigrationTokenResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SessionMigrationTokenResponseArgs {
+      token: None,
+      ttl_ms: 0,
+    }
+  }
+}
+
+pub struct SessionMigrationTokenResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SessionMigrationTokenResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SessionMigrationTokenResponse::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_ttl_ms(&mut self, ttl_ms: u32) {
+    self.fbb_.push_slot::<u32>(SessionMigrationTokenResponse::VT_TTL_MS, ttl_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SessionMigrationTokenResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SessionMigrationTokenResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SessionMigrationTokenResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SessionMigrationTokenResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SessionMigrationTokenResponse");
+      ds.field("token", &self.token());
+      ds.field("ttl_ms", &self.ttl_ms());
+      ds.finish()
+  }
+}
+pub enum ProvisionCertRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Provision X.509 certificates onto the device over an authenticated
+/// PSK session. After successful provisioning the device switches to
+/// CertOnly or PskAndCert mode.
+pub struct ProvisionCertRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ProvisionCertRequest<'a> {
+  type Inner = ProvisionCertRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ProvisionCertRequest<'a> {
+  pub const VT_CA_CERT: flatbuffers::VOffsetT = 4;
+  pub const VT_DEVICE_CERT: flatbuffers::VOffsetT = 6;
+  pub const VT_DEVICE_KEY: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ProvisionCertRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ProvisionCertRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ProvisionCertRequest<'bldr>> {
+    let mut builder = ProvisionCertRequestBuilder::new(_fbb);
+    if let Some(x) = args.device_key { builder.add_device_key(x); }
+    if let Some(x) = args.device_cert { builder.add_device_cert(x); }
+    if let Some(x) = args.ca_cert { builder.add_ca_cert(x); }
+    builder.finish()
+  }
+
+
+  /// PEM-encoded CA certificate chain.
+  #[inline]
+  pub fn ca_cert(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_CA_CERT, None)}
+  }
+  /// PEM-encoded device certificate.
+  #[inline]
+  pub fn device_cert(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_DEVICE_CERT, None)}
+  }
+  /// PEM-encoded device private key.
+  #[inline]
+  pub fn device_key(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ProvisionCertRequest::VT_DEVICE_KEY, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ProvisionCertRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("ca_cert", Self::VT_CA_CERT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("device_cert", Self::VT_DEVICE_CERT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("device_key", Self::VT_DEVICE_KEY, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ProvisionCertRequestArgs<'a> {
+    pub ca_cert: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub device_cert: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub device_key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for ProvisionCertRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ProvisionCertRequestArgs {
+      ca_cert: None,
+      device_cert: None,
+      device_key: None,
+    }
+  }
+}
+
+pub struct ProvisionCertRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ProvisionCertRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ca_cert(&mut self, ca_cert: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_CA_CERT, ca_cert);
+  }
+  #[inline]
+  pub fn add_device_cert(&mut self, device_cert: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_DEVICE_CERT, device_cert);
+  }
+  #[inline]
+  pub fn add_device_key(&mut self, device_key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProvisionCertRequest::VT_DEVICE_KEY, device_key);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ProvisionCertRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ProvisionCertRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ProvisionCertRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ProvisionCertRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ProvisionCertRequest");
+      ds.field("ca_cert", &self.ca_cert());
+      ds.field("device_cert", &self.device_cert());
+      ds.field("device_key", &self.device_key());
+      ds.finish()
+  }
+}
+pub enum GetCertStatusRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Query the current TLS certificate status.
+pub struct GetCertStatusRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetCertStatusRequest<'a> {
+  type Inner = GetCertStatusRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetCertStatusRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetCertStatusRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args GetCertStatusRequestArgs
+  ) -> flatbuffers::WIPOffset<GetCertStatusRequest<'bldr>> {
+    let mut builder = GetCertStatusRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for GetCertStatusRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetCertStatusRequestArgs {
+}
+impl<'a> Default for GetCertStatusRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetCertStatusRequestArgs {
+    }
+  }
+}
+
+pub struct GetCertStatusRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetCertStatusRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetCertStatusRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetCertStatusRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetCertStatusRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetCertStatusRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetCertStatusRequest");
+      ds.finish()
+  }
+}
+pub enum CertStatusResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Certificate status response.
+pub struct CertStatusResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CertStatusResponse<'a> {
+  type Inner = CertStatusResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CertStatusResponse<'a> {
+  pub const VT_MODE: flatbuffers::VOffsetT = 4;
+  pub const VT_CA_FINGERPRINT: flatbuffers::VOffsetT = 6;
+  pub const VT_DEVICE_SERIAL: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CertStatusResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CertStatusResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<CertStatusResponse<'bldr>> {
+    let mut builder = CertStatusResponseBuilder::new(_fbb);
+    if let Some(x) = args.device_serial { builder.add_device_serial(x); }
+    if let Some(x) = args.ca_fingerprint { builder.add_ca_fingerprint(x); }
+    builder.add_mode(args.mode);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn mode(&self) -> TlsMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TlsMode>(CertStatusResponse::VT_MODE, Some(TlsMode::PskOnly)).unwrap()}
+  }
+  /// SHA-256 fingerprint of the installed CA certificate.
+  #[inline]
+  pub fn ca_fingerprint(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(CertStatusResponse::VT_CA_FINGERPRINT, None)}
+  }
+  /// Serial number from the device certificate.
+  #[inline]
+  pub fn device_serial(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CertStatusResponse::VT_DEVICE_SERIAL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CertStatusResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<TlsMode>("mode", Self::VT_MODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("ca_fingerprint", Self::VT_CA_FINGERPRINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_serial", Self::VT_DEVICE_SERIAL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CertStatusResponseArgs<'a> {
+    pub mode: TlsMode,
+    pub ca_fingerprint: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub device_serial: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CertStatusResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CertStatusResponseArgs {
+      mode: TlsMode::PskOnly,
+      ca_fingerprint: None,
+      device_serial: None,
+    }
+  }
+}
+
+pub struct CertStatusResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CertStatusResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_mode(&mut self, mode: TlsMode) {
+    self.fbb_.push_slot::<TlsMode>(CertStatusResponse::VT_MODE, mode, TlsMode::PskOnly);
+  }
+  #[inline]
+  pub fn add_ca_fingerprint(&mut self, ca_fingerprint: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CertStatusResponse::VT_CA_FINGERPRINT, ca_fingerprint);
+  }
+  #[inline]
+  pub fn add_device_serial(&mut self, device_serial: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CertStatusResponse::VT_DEVICE_SERIAL, device_serial);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CertStatusResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CertStatusResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CertStatusResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CertStatusResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CertStatusResponse");
+      ds.field("mode", &self.mode());
+      ds.field("ca_fingerprint", &self.ca_fingerprint());
+      ds.field("device_serial", &self.device_serial());
+      ds.finish()
+  }
+}
+pub enum AddClientCertPinRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Pin a client certificate by fingerprint.  While any are pinned,
+/// certificate clients must present one of them.  Up to 8 pins are
+/// kept, across reboots.
+pub struct AddClientCertPinRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AddClientCertPinRequest<'a> {
+  type Inner = AddClientCertPinRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AddClientCertPinRequest<'a> {
+  pub const VT_FINGERPRINT: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AddClientCertPinRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AddClientCertPinRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<AddClientCertPinRequest<'bldr>> {
+    let mut builder = AddClientCertPinRequestBuilder::new(_fbb);
+    if let Some(x) = args.fingerprint { builder.add_fingerprint(x); }
+    builder.finish()
+  }
+
+
+  /// SHA-256 of the client certificate (DER), 32 bytes.
+  #[inline]
+  pub fn fingerprint(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AddClientCertPinRequest::VT_FINGERPRINT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AddClientCertPinRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("fingerprint", Self::VT_FINGERPRINT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AddClientCertPinRequestArgs<'a> {
+    pub fingerprint: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for AddClientCertPinRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AddClientCertPinRequestArgs {
+      fingerprint: None,
+    }
+  }
+}
+
+pub struct AddClientCertPinRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AddClientCertPinRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_fingerprint(&mut self, fingerprint: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AddClientCertPinRequest::VT_FINGERPRINT, fingerprint);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AddClientCertPinRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AddClientCertPinRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AddClientCertPinRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AddClientCertPinRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AddClientCertPinRequest");
+      ds.field("fingerprint", &self.fingerprint());
+      ds.finish()
+  }
+}
+pub enum RemoveClientCertPinRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Unpin a client certificate.  Removing the last pin accepts any
+/// CA-signed client certificate again.
+pub struct RemoveClientCertPinRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RemoveClientCertPinRequest<'a> {
+  type Inner = RemoveClientCertPinRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RemoveClientCertPinRequest<'a> {
+  pub const VT_FINGERPRINT: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RemoveClientCertPinRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RemoveClientCertPinRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<RemoveClientCertPinRequest<'bldr>> {
+    let mut builder = RemoveClientCertPinRequestBuilder::new(_fbb);
+    if let Some(x) = args.fingerprint { builder.add_fingerprint(x); }
+    builder.finish()
+  }
+
+
+  /// SHA-256 of the client certificate (DER), 32 bytes.
+  #[inline]
+  pub fn fingerprint(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(RemoveClientCertPinRequest::VT_FINGERPRINT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RemoveClientCertPinRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("fingerprint", Self::VT_FINGERPRINT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RemoveClientCertPinRequestArgs<'a> {
+    pub fingerprint: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for RemoveClientCertPinRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RemoveClientCertPinRequestArgs {
+      fingerprint: None,
+    }
+  }
+}
+
+pub struct RemoveClientCertPinRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RemoveClientCertPinRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_fingerprint(&mut self, fingerprint: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RemoveClientCertPinRequest::VT_FINGERPRINT, fingerprint);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RemoveClientCertPinRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RemoveClientCertPinRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RemoveClientCertPinRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RemoveClientCertPinRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RemoveClientCertPinRequest");
+      ds.field("fingerprint", &self.fingerprint());
+      ds.finish()
+  }
+}
+pub enum OtaBeginRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OtaBeginRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaBeginRequest<'a> {
+  type Inner = OtaBeginRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaBeginRequest<'a> {
+  pub const VT_FIRMWARE_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_SHA256: flatbuffers::VOffsetT = 6;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaBeginRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OtaBeginRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<OtaBeginRequest<'bldr>> {
+    let mut builder = OtaBeginRequestBuilder::new(_fbb);
+    builder.add_version(args.version);
+    if let Some(x) = args.sha256 { builder.add_sha256(x); }
+    builder.add_firmware_size(args.firmware_size);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn firmware_size(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaBeginRequest::VT_FIRMWARE_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn sha256(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(OtaBeginRequest::VT_SHA256, None)}
+  }
+  /// Monotonic firmware version for anti-rollback checks.
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaBeginRequest::VT_VERSION, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OtaBeginRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("firmware_size", Self::VT_FIRMWARE_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("sha256", Self::VT_SHA256, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaBeginRequestArgs<'a> {
+    pub firmware_size: u32,
+    pub sha256: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub version: u32,
+}
+impl<'a> Default for OtaBeginRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OtaBeginRequestArgs {
+      firmware_size: 0,
+      sha256: None,
+      version: 0,
+    }
+  }
+}
+
+pub struct OtaBeginRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaBeginRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_firmware_size(&mut self, firmware_size: u32) {
+    self.fbb_.push_slot::<u32>(OtaBeginRequest::VT_FIRMWARE_SIZE, firmware_size, 0);
+  }
+  #[inline]
+  pub fn add_sha256(&mut self, sha256: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaBeginRequest::VT_SHA256, sha256);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(OtaBeginRequest::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaBeginRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaBeginRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaBeginRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaBeginRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaBeginRequest");
+      ds.field("firmware_size", &self.firmware_size());
+      ds.field("sha256", &self.sha256());
+      ds.field("version", &self.version());
+      ds.finish()
+  }
+}
+pub enum OtaChunkRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OtaChunkRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaChunkRequest<'a> {
+  type Inner = OtaChunkRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaChunkRequest<'a> {
+  pub const VT_OFFSET: flatbuffers::VOffsetT = 4;
+  pub const VT_DATA: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaChunkRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OtaChunkRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<OtaChunkRequest<'bldr>> {
+    let mut builder = OtaChunkRequestBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    builder.add_offset(args.offset);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn offset(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaChunkRequest::VT_OFFSET, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(OtaChunkRequest::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for OtaChunkRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("offset", Self::VT_OFFSET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaChunkRequestArgs<'a> {
+    pub offset: u32,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for OtaChunkRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OtaChunkRequestArgs {
+      offset: 0,
+      data: None,
+    }
+  }
+}
+
+pub struct OtaChunkRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaChunkRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_offset(&mut self, offset: u32) {
+    self.fbb_.push_slot::<u32>(OtaChunkRequest::VT_OFFSET, offset, 0);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaChunkRequest::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaChunkRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaChunkRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaChunkRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaChunkRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaChunkRequest");
+      ds.field("offset", &self.offset());
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+pub enum OtaFinalizeRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OtaFinalizeRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaFinalizeRequest<'a> {
+  type Inner = OtaFinalizeRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaFinalizeRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaFinalizeRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args OtaFinalizeRequestArgs
+  ) -> flatbuffers::WIPOffset<OtaFinalizeRequest<'bldr>> {
+    let mut builder = OtaFinalizeRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for OtaFinalizeRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaFinalizeRequestArgs {
+}
+impl<'a> Default for OtaFinalizeRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    OtaFinalizeRequestArgs {
+    }
+  }
+}
+
+pub struct OtaFinalizeRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaFinalizeRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaFinalizeRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaFinalizeRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaFinalizeRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaFinalizeRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaFinalizeRequest");
+      ds.finish()
+  }
+}
+pub enum OtaResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OtaResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaResponse<'a> {
+  type Inner = OtaResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaResponse<'a> {
+  pub const VT_SUCCESS: flatbuffers::VOffsetT = 4;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_BYTES_WRITTEN: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OtaResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<OtaResponse<'bldr>> {
+    let mut builder = OtaResponseBuilder::new(_fbb);
+    builder.add_bytes_written(args.bytes_written);
+    if let Some(x) = args.message { builder.add_message(x); }
+    builder.add_success(args.success);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn success(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(OtaResponse::VT_SUCCESS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(OtaResponse::VT_MESSAGE, None)}
+  }
+  #[inline]
+  pub fn bytes_written(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaResponse::VT_BYTES_WRITTEN, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OtaResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("success", Self::VT_SUCCESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<u32>("bytes_written", Self::VT_BYTES_WRITTEN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaResponseArgs<'a> {
+    pub success: bool,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub bytes_written: u32,
+}
+impl<'a> Default for OtaResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OtaResponseArgs {
+      success: false,
+      message: None,
+      bytes_written: 0,
+    }
+  }
+}
+
+pub struct OtaResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_success(&mut self, success: bool) {
+    self.fbb_.push_slot::<bool>(OtaResponse::VT_SUCCESS, success, false);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaResponse::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn add_bytes_written(&mut self, bytes_written: u32) {
+    self.fbb_.push_slot::<u32>(OtaResponse::VT_BYTES_WRITTEN, bytes_written, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaResponse");
+      ds.field("success", &self.success());
+      ds.field("message", &self.message());
+      ds.field("bytes_written", &self.bytes_written());
+      ds.finish()
+  }
+}
+pub enum OtaProgressEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Async OTA progress event pushed to clients during firmware upload.
+pub struct OtaProgressEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaProgressEvent<'a> {
+  type Inner = OtaProgressEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaProgressEvent<'a> {
+  pub const VT_BYTES_WRITTEN: flatbuffers::VOffsetT = 4;
+  pub const VT_TOTAL_BYTES: flatbuffers::VOffsetT = 6;
+  pub const VT_PERCENT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaProgressEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OtaProgressEventArgs
+  ) -> flatbuffers::WIPOffset<OtaProgressEvent<'bldr>> {
+    let mut builder = OtaProgressEventBuilder::new(_fbb);
+    builder.add_total_bytes(args.total_bytes);
+    builder.add_bytes_written(args.bytes_written);
+    builder.add_percent(args.percent);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn bytes_written(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaProgressEvent::VT_BYTES_WRITTEN, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn total_bytes(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaProgressEvent::VT_TOTAL_BYTES, Some(0)).unwrap()}
+  }
+  /// 0-100 percent complete.
+  #[inline]
+  pub fn percent(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(OtaProgressEvent::VT_PERCENT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OtaProgressEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("bytes_written", Self::VT_BYTES_WRITTEN, false)?
+     .visit_field::<u32>("total_bytes", Self::VT_TOTAL_BYTES, false)?
+     .visit_field::<u8>("percent", Self::VT_PERCENT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaProgressEventArgs {
+    pub bytes_written: u32,
+    pub total_bytes: u32,
+    pub percent: u8,
+}
+impl<'a> Default for OtaProgressEventArgs {
+  #[inline]
+  fn default() -> Self {
+    OtaProgressEventArgs {
+      bytes_written: 0,
+      total_bytes: 0,
+      percent: 0,
+    }
+  }
+}
+
+pub struct OtaProgressEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaProgressEventBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_bytes_written(&mut self, bytes_written: u32) {
+    self.fbb_.push_slot::<u32>(OtaProgressEvent::VT_BYTES_WRITTEN, bytes_written, 0);
+  }
+  #[inline]
+  pub fn add_total_bytes(&mut self, total_bytes: u32) {
+    self.fbb_.push_slot::<u32>(OtaProgressEvent::VT_TOTAL_BYTES, total_bytes, 0);
+  }
+  #[inline]
+  pub fn add_percent(&mut self, percent: u8) {
+    self.fbb_.push_slot::<u8>(OtaProgressEvent::VT_PERCENT, percent, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaProgressEventBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaProgressEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaProgressEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaProgressEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaProgressEvent");
+      ds.field("bytes_written", &self.bytes_written());
+      ds.field("total_bytes", &self.total_bytes());
+      ds.field("percent", &self.percent());
+      ds.finish()
+  }
+}
+pub enum OtaStatusRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Where the OTA transfer stands, so a client that lost its link can
+/// resume at `bytes_written` instead of starting over.
+pub struct OtaStatusRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaStatusRequest<'a> {
+  type Inner = OtaStatusRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaStatusRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaStatusRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args OtaStatusRequestArgs
+  ) -> flatbuffers::WIPOffset<OtaStatusRequest<'bldr>> {
+    let mut builder = OtaStatusRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for OtaStatusRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaStatusRequestArgs {
+}
+impl<'a> Default for OtaStatusRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    OtaStatusRequestArgs {
+    }
+  }
+}
+
+pub struct OtaStatusRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaStatusRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaStatusRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaStatusRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaStatusRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaStatusRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaStatusRequest");
+      ds.finish()
+  }
+}
+pub enum OtaStatusResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OtaStatusResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaStatusResponse<'a> {
+  type Inner = OtaStatusResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaStatusResponse<'a> {
+  pub const VT_ACTIVE: flatbuffers::VOffsetT = 4;
+  pub const VT_FIRMWARE_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_BYTES_WRITTEN: flatbuffers::VOffsetT = 8;
+  pub const VT_SHA256: flatbuffers::VOffsetT = 10;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaStatusResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OtaStatusResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<OtaStatusResponse<'bldr>> {
+    let mut builder = OtaStatusResponseBuilder::new(_fbb);
+    builder.add_version(args.version);
+    if let Some(x) = args.sha256 { builder.add_sha256(x); }
+    builder.add_bytes_written(args.bytes_written);
+    builder.add_firmware_size(args.firmware_size);
+    builder.add_active(args.active);
+    builder.finish()
+  }
+
+
+  /// A transfer is in progress and takes its next chunk at
+  /// `bytes_written`.
+  #[inline]
+  pub fn active(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(OtaStatusResponse::VT_ACTIVE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn firmware_size(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaStatusResponse::VT_FIRMWARE_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn bytes_written(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaStatusResponse::VT_BYTES_WRITTEN, Some(0)).unwrap()}
+  }
+  /// Digest and version the transfer was begun with; a client resumes
+  /// only if they match the image it holds.
+  #[inline]
+  pub fn sha256(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(OtaStatusResponse::VT_SHA256, None)}
+  }
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(OtaStatusResponse::VT_VERSION, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OtaStatusResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("active", Self::VT_ACTIVE, false)?
+     .visit_field::<u32>("firmware_size", Self::VT_FIRMWARE_SIZE, false)?
+     .visit_field::<u32>("bytes_written", Self::VT_BYTES_WRITTEN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("sha256", Self::VT_SHA256, false)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaStatusResponseArgs<'a> {
+    pub active: bool,
+    pub firmware_size: u32,
+    pub bytes_written: u32,
+    pub sha256: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub version: u32,
+}
+impl<'a> Default for OtaStatusResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OtaStatusResponseArgs {
+      active: false,
+      firmware_size: 0,
+      bytes_written: 0,
+      sha256: None,
+      version: 0,
+    }
+  }
+}
+
+pub struct OtaStatusResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaStatusResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_active(&mut self, active: bool) {
+    self.fbb_.push_slot::<bool>(OtaStatusResponse::VT_ACTIVE, active, false);
+  }
+  #[inline]
+  pub fn add_firmware_size(&mut self, firmware_size: u32) {
+    self.fbb_.push_slot::<u32>(OtaStatusResponse::VT_FIRMWARE_SIZE, firmware_size, 0);
+  }
+  #[inline]
+  pub fn add_bytes_written(&mut self, bytes_written: u32) {
+    self.fbb_.push_slot::<u32>(OtaStatusResponse::VT_BYTES_WRITTEN, bytes_written, 0);
+  }
+  #[inline]
+  pub fn add_sha256(&mut self, sha256: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OtaStatusResponse::VT_SHA256, sha256);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(OtaStatusResponse::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaStatusResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaStatusResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaStatusResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaStatusResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaStatusResponse");
+      ds.field("active", &self.active());
+      ds.field("firmware_size", &self.firmware_size());
+      ds.field("bytes_written", &self.bytes_written());
+      ds.field("sha256", &self.sha256());
+      ds.field("version", &self.version());
+      ds.finish()
+  }
+}
+pub enum OtaAbortRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Abandon the transfer in progress and drop its resume record.
+pub struct OtaAbortRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OtaAbortRequest<'a> {
+  type Inner = OtaAbortRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OtaAbortRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OtaAbortRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args OtaAbortRequestArgs
+  ) -> flatbuffers::WIPOffset<OtaAbortRequest<'bldr>> {
+    let mut builder = OtaAbortRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for OtaAbortRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OtaAbortRequestArgs {
+}
+impl<'a> Default for OtaAbortRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    OtaAbortRequestArgs {
+    }
+  }
+}
+
+pub struct OtaAbortRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OtaAbortRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OtaAbortRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OtaAbortRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OtaAbortRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OtaAbortRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OtaAbortRequest");
+      ds.finish()
+  }
+}
+pub enum GetDiagnosticsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetDiagnosticsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetDiagnosticsRequest<'a> {
+  type Inner = GetDiagnosticsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetDiagnosticsRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetDiagnosticsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args GetDiagnosticsRequestArgs
+  ) -> flatbuffers::WIPOffset<GetDiagnosticsRequest<'bldr>> {
+    let mut builder = GetDiagnosticsRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for GetDiagnosticsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetDiagnosticsRequestArgs {
+}
+impl<'a> Default for GetDiagnosticsRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetDiagnosticsRequestArgs {
+    }
+  }
+}
+
+pub struct GetDiagnosticsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetDiagnosticsRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetDiagnosticsRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetDiagnosticsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetDiagnosticsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetDiagnosticsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetDiagnosticsRequest");
+      ds.finish()
+  }
+}
+pub enum ClearDiagnosticsRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ClearDiagnosticsRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ClearDiagnosticsRequest<'a> {
+  type Inner = ClearDiagnosticsRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ClearDiagnosticsRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ClearDiagnosticsRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ClearDiagnosticsRequestArgs
+  ) -> flatbuffers::WIPOffset<ClearDiagnosticsRequest<'bldr>> {
+    let mut builder = ClearDiagnosticsRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ClearDiagnosticsRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ClearDiagnosticsRequestArgs {
+}
+impl<'a> Default for ClearDiagnosticsRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ClearDiagnosticsRequestArgs {
+    }
+  }
+}
+
+pub struct ClearDiagnosticsRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClearDiagnosticsRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClearDiagnosticsRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ClearDiagnosticsRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClearDiagnosticsRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ClearDiagnosticsRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ClearDiagnosticsRequest");
+      ds.finish()
+  }
+}
+pub enum ResetCountersRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Zero the runtime counters reported in `DiagnosticsResponse` while
+/// keeping the persisted crash log.
+pub struct ResetCountersRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResetCountersRequest<'a> {
+  type Inner = ResetCountersRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ResetCountersRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ResetCountersRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ResetCountersRequestArgs
+  ) -> flatbuffers::WIPOffset<ResetCountersRequest<'bldr>> {
+    let mut builder = ResetCountersRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ResetCountersRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResetCountersRequestArgs {
+}
+impl<'a> Default for ResetCountersRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ResetCountersRequestArgs {
+    }
+  }
+}
+
+pub struct ResetCountersRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResetCountersRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResetCountersRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResetCountersRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResetCountersRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ResetCountersRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ResetCountersRequest");
+      ds.finish()
+  }
+}
+pub enum ResetServiceFlowRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Zero the `since_service_ml` flow subtotal, e.g. after a filter
+/// change.  The lifetime total is never reset.
+pub struct ResetServiceFlowRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResetServiceFlowRequest<'a> {
+  type Inner = ResetServiceFlowRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ResetServiceFlowRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ResetServiceFlowRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ResetServiceFlowRequestArgs
+  ) -> flatbuffers::WIPOffset<ResetServiceFlowRequest<'bldr>> {
+    let mut builder = ResetServiceFlowRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ResetServiceFlowRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResetServiceFlowRequestArgs {
+}
+impl<'a> Default for ResetServiceFlowRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ResetServiceFlowRequestArgs {
+    }
+  }
+}
+
+pub struct ResetServiceFlowRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResetServiceFlowRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResetServiceFlowRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResetServiceFlowRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResetServiceFlowRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ResetServiceFlowRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ResetServiceFlowRequest");
+      ds.finish()
+  }
+}
+pub enum CrashEntryFbsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CrashEntryFbs<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CrashEntryFbs<'a> {
+  type Inner = CrashEntryFbs<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CrashEntryFbs<'a> {
+  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 4;
+  pub const VT_REASON: flatbuffers::VOffsetT = 6;
+  pub const VT_PC: flatbuffers::VOffsetT = 8;
+  pub const VT_BACKTRACE: flatbuffers::VOffsetT = 10;
+  pub const VT_LOCATION: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CrashEntryFbs { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CrashEntryFbsArgs<'args>
+  ) -> flatbuffers::WIPOffset<CrashEntryFbs<'bldr>> {
+    let mut builder = CrashEntryFbsBuilder::new(_fbb);
+    builder.add_uptime_secs(args.uptime_secs);
+    if let Some(x) = args.location { builder.add_location(x); }
+    if let Some(x) = args.backtrace { builder.add_backtrace(x); }
+    builder.add_pc(args.pc);
+    if let Some(x) = args.reason { builder.add_reason(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn uptime_secs(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(CrashEntryFbs::VT_UPTIME_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CrashEntryFbs::VT_REASON, None)}
+  }
+  #[inline]
+  pub fn pc(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CrashEntryFbs::VT_PC, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn backtrace(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(CrashEntryFbs::VT_BACKTRACE, None)}
+  }
+  /// Panic site as `file:line` (absent if unknown).
+  #[inline]
+  pub fn location(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CrashEntryFbs::VT_LOCATION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CrashEntryFbs<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reason", Self::VT_REASON, false)?
+     .visit_field::<u32>("pc", Self::VT_PC, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("backtrace", Self::VT_BACKTRACE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("location", Self::VT_LOCATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CrashEntryFbsArgs<'a> {
+    pub uptime_secs: u64,
+    pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pc: u32,
+    pub backtrace: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub location: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CrashEntryFbsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CrashEntryFbsArgs {
+      uptime_secs: 0,
+      reason: None,
+      pc: 0,
+      backtrace: None,
+      location: None,
+    }
+  }
+}
+
+pub struct CrashEntryFbsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CrashEntryFbsBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
+    self.fbb_.push_slot::<u64>(CrashEntryFbs::VT_UPTIME_SECS, uptime_secs, 0);
+  }
+  #[inline]
+  pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CrashEntryFbs::VT_REASON, reason);
+  }
+  #[inline]
+  pub fn add_pc(&mut self, pc: u32) {
+    self.fbb_.push_slot::<u32>(CrashEntryFbs::VT_PC, pc, 0);
+  }
+  #[inline]
+  pub fn add_backtrace(&mut self, backtrace: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CrashEntryFbs::VT_BACKTRACE, backtrace);
+  }
+  #[inline]
+  pub fn add_location(&mut self, location: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CrashEntryFbs::VT_LOCATION, location);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CrashEntryFbsBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CrashEntryFbsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CrashEntryFbs<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CrashEntryFbs<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CrashEntryFbs");
+      ds.field("uptime_secs", &self.uptime_secs());
+      ds.field("reason", &self.reason());
+      ds.field("pc", &self.pc());
+      ds.field("backtrace", &self.backtrace());
+      ds.field("location", &self.location());
+      ds.finish()
+  }
+}
+pub enum DiagnosticsResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DiagnosticsResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DiagnosticsResponse<'a> {
+  type Inner = DiagnosticsResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DiagnosticsResponse<'a> {
+  pub const VT_UPTIME_SECS: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTROL_CYCLES: flatbuffers::VOffsetT = 6;
+  pub const VT_FAULT_COUNT: flatbuffers::VOffsetT = 8;
+  pub const VT_CRASH_COUNT: flatbuffers::VOffsetT = 10;
+  pub const VT_HEAP_FREE: flatbuffers::VOffsetT = 12;
+  pub const VT_HEAP_MIN_FREE: flatbuffers::VOffsetT = 14;
+  pub const VT_WIFI_RSSI: flatbuffers::VOffsetT = 16;
+  pub const VT_NVS_FREE_ENTRIES: flatbuffers::VOffsetT = 18;
+  pub const VT_ULP_WAKE_COUNT: flatbuffers::VOffsetT = 20;
+  pub const VT_CRASH_ENTRIES: flatbuffers::VOffsetT = 22;
+  pub const VT_HEAP_LARGEST_BLOCK: flatbuffers::VOffsetT = 24;
+  pub const VT_TX_FRAMES: flatbuffers::VOffsetT = 26;
+  pub const VT_TX_RAW_BYTES: flatbuffers::VOffsetT = 28;
+  pub const VT_TX_SENT_BYTES: flatbuffers::VOffsetT = 30;
+  pub const VT_TX_COMPRESSION_RATIO: flatbuffers::VOffsetT = 32;
+  pub const VT_RX_DROPPED_FRAMES: flatbuffers::VOffsetT = 34;
+  pub const VT_LIFETIME_ML: flatbuffers::VOffsetT = 36;
+  pub const VT_SINCE_SERVICE_ML: flatbuffers::VOffsetT = 38;
+  pub const VT_ULP_NH3_LAST_READING: flatbuffers::VOffsetT = 40;
+  pub const VT_ULP_CYCLE_COUNT: flatbuffers::VOffsetT = 42;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DiagnosticsResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DiagnosticsResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<DiagnosticsResponse<'bldr>> {
+    let mut builder = DiagnosticsResponseBuilder::new(_fbb);
+    builder.add_since_service_ml(args.since_service_ml);
+    builder.add_lifetime_ml(args.lifetime_ml);
+    builder.add_tx_sent_bytes(args.tx_sent_bytes);
+    builder.add_tx_raw_bytes(args.tx_raw_bytes);
+    builder.add_control_cycles(args.control_cycles);
+    builder.add_uptime_secs(args.uptime_secs);
+    builder.add_ulp_cycle_count(args.ulp_cycle_count);
+    builder.add_ulp_nh3_last_reading(args.ulp_nh3_last_reading);
+    builder.add_rx_dropped_frames(args.rx_dropped_frames);
+    builder.add_tx_compression_ratio(args.tx_compression_ratio);
+    builder.add_tx_frames(args.tx_frames);
+    builder.add_heap_largest_block(args.heap_largest_block);
+    if let Some(x) = args.crash_entries { builder.add_crash_entries(x); }
+    builder.add_ulp_wake_count(args.ulp_wake_count);
+    builder.add_nvs_free_entries(args.nvs_free_entries);
+    builder.add_heap_min_free(args.heap_min_free);
+    builder.add_heap_free(args.heap_free);
+    builder.add_crash_count(args.crash_count);
+    builder.add_fault_count(args.fault_count);
+    builder.add_wifi_rssi(args.wifi_rssi);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn uptime_secs(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_UPTIME_SECS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn control_cycles(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_CONTROL_CYCLES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn fault_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_FAULT_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn crash_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_CRASH_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn heap_free(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_HEAP_FREE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn heap_min_free(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_HEAP_MIN_FREE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn wifi_rssi(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(DiagnosticsResponse::VT_WIFI_RSSI, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nvs_free_entries(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_NVS_FREE_ENTRIES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ulp_wake_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_ULP_WAKE_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn crash_entries(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs>>>>(DiagnosticsResponse::VT_CRASH_ENTRIES, None)}
+  }
+  #[inline]
+  pub fn heap_largest_block(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_HEAP_LARGEST_BLOCK, Some(0)).unwrap()}
+  }
+  /// Response frames encoded since boot (saturating).
+  #[inline]
+  pub fn tx_frames(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_TX_FRAMES, Some(0)).unwrap()}
+  }
+  /// Payload bytes before compression.
+  #[inline]
+  pub fn tx_raw_bytes(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_TX_RAW_BYTES, Some(0)).unwrap()}
+  }
+  /// Payload bytes framed after compression.
+  #[inline]
+  pub fn tx_sent_bytes(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_TX_SENT_BYTES, Some(0)).unwrap()}
+  }
+  /// tx_sent_bytes / tx_raw_bytes (1.0 = no saving).
+  #[inline]
+  pub fn tx_compression_ratio(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(DiagnosticsResponse::VT_TX_COMPRESSION_RATIO, Some(1.0)).unwrap()}
+  }
+  /// Inbound command frames dropped on a full command queue (saturating).
+  #[inline]
+  pub fn rx_dropped_frames(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_RX_DROPPED_FRAMES, Some(0)).unwrap()}
+  }
+  /// Water processed over the device's life, persisted across reboots.
+  #[inline]
+  pub fn lifetime_ml(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_LIFETIME_ML, Some(0)).unwrap()}
+  }
+  /// Water processed since the last `ResetServiceFlowRequest`.
+  #[inline]
+  pub fn since_service_ml(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(DiagnosticsResponse::VT_SINCE_SERVICE_ML, Some(0)).unwrap()}
+  }
+  /// Raw NH3 ADC count the ULP monitor last sampled before the most
+  /// recent ULP wake (0 = no ULP wake this boot).
+  #[inline]
+  pub fn ulp_nh3_last_reading(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_ULP_NH3_LAST_READING, Some(0)).unwrap()}
+  }
+  /// ULP monitoring cycles between going to sleep and that wake.
+  #[inline]
+  pub fn ulp_cycle_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_ULP_CYCLE_COUNT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DiagnosticsResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
+     .visit_field::<u64>("control_cycles", Self::VT_CONTROL_CYCLES, false)?
+     .visit_field::<u32>("fault_count", Self::VT_FAULT_COUNT, false)?
+     .visit_field::<u32>("crash_count", Self::VT_CRASH_COUNT, false)?
+     .visit_field::<u32>("heap_free", Self::VT_HEAP_FREE, false)?
+     .visit_field::<u32>("heap_min_free", Self::VT_HEAP_MIN_FREE, false)?
+     .visit_field::<i8>("wifi_rssi", Self::VT_WIFI_RSSI, false)?
+     .visit_field::<u32>("nvs_free_entries", Self::VT_NVS_FREE_ENTRIES, false)?
+     .visit_field::<u32>("ulp_wake_count", Self::VT_ULP_WAKE_COUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CrashEntryFbs>>>>("crash_entries", Self::VT_CRASH_ENTRIES, false)?
+     .visit_field::<u32>("heap_largest_block", Self::VT_HEAP_LARGEST_BLOCK, false)?
+     .visit_field::<u32>("tx_frames", Self::VT_TX_FRAMES, false)?
+     .visit_field::<u64>("tx_raw_bytes", Self::VT_TX_RAW_BYTES, false)?
+     .visit_field::<u64>("tx_sent_bytes", Self::VT_TX_SENT_BYTES, false)?
+     .visit_field::<f32>("tx_compression_ratio", Self::VT_TX_COMPRESSION_RATIO, false)?
+     .visit_field::<u32>("rx_dropped_frames", Self::VT_RX_DROPPED_FRAMES, false)?
+     .visit_field::<u64>("lifetime_ml", Self::VT_LIFETIME_ML, false)?
+     .visit_field::<u64>("since_service_ml", Self::VT_SINCE_SERVICE_ML, false)?
+     .visit_field::<u32>("ulp_nh3_last_reading", Self::VT_ULP_NH3_LAST_READING, false)?
+     .visit_field::<u32>("ulp_cycle_count", Self::VT_ULP_CYCLE_COUNT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DiagnosticsResponseArgs<'a> {
+    pub uptime_secs: u64,
+    pub control_cycles: u64,
+    pub fault_count: u32,
+    pub crash_count: u32,
+    pub heap_free: u32,
+    pub heap_min_free: u32,
+    pub wifi_rssi: i8,
+    pub nvs_free_entries: u32,
+    pub ulp_wake_count: u32,
+    pub crash_entries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs<'a>>>>>,
+    pub heap_largest_block: u32,
+    pub tx_frames: u32,
+    pub tx_raw_bytes: u64,
+    pub tx_sent_bytes: u64,
+    pub tx_compression_ratio: f32,
+    pub rx_dropped_frames: u32,
+    pub lifetime_ml: u64,
+    pub since_service_ml: u64,
+    pub ulp_nh3_last_reading: u32,
+    pub ulp_cycle_count: u32,
+}
+impl<'a> Default for DiagnosticsResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DiagnosticsResponseArgs {
+      uptime_secs: 0,
+      control_cycles: 0,
+      fault_count: 0,
+      crash_count: 0,
+      heap_free: 0,
+      heap_min_free: 0,
+      wifi_rssi: 0,
+      nvs_free_entries: 0,
+      ulp_wake_count: 0,
+      crash_entries: None,
+      heap_largest_block: 0,
+      tx_frames: 0,
+      tx_raw_bytes: 0,
+      tx_sent_bytes: 0,
+      tx_compression_ratio: 1.0,
+      rx_dropped_frames: 0,
+      lifetime_ml: 0,
+      since_service_ml: 0,
+      ulp_nh3_last_reading: 0,
+      ulp_cycle_count: 0,
+    }
+  }
+}
+
+pub struct DiagnosticsResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DiagnosticsResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  }
+  #[inline]
+  pub fn add_control_cycles(&mut self, control_cycles: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_CONTROL_CYCLES, control_cycles, 0);
+  }
+  #[inline]
+  pub fn add_fault_count(&mut self, fault_count: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_FAULT_COUNT, fault_count, 0);
+  }
+  #[inline]
+  pub fn add_crash_count(&mut self, crash_count: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_CRASH_COUNT, crash_count, 0);
+  }
+  #[inline]
+  pub fn add_heap_free(&mut self, heap_free: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_HEAP_FREE, heap_free, 0);
+  }
+  #[inline]
+  pub fn add_heap_min_free(&mut self, heap_min_free: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_HEAP_MIN_FREE, heap_min_free, 0);
+  }
+  #[inline]
+  pub fn add_wifi_rssi(&mut self, wifi_rssi: i8) {
+    self.fbb_.push_slot::<i8>(DiagnosticsResponse::VT_WIFI_RSSI, wifi_rssi, 0);
+  }
+  #[inline]
+  pub fn add_nvs_free_entries(&mut self, nvs_free_entries: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_NVS_FREE_ENTRIES, nvs_free_entries, 0);
+  }
+  #[inline]
+  pub fn add_ulp_wake_count(&mut self, ulp_wake_count: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_ULP_WAKE_COUNT, ulp_wake_count, 0);
+  }
+  #[inline]
+  pub fn add_crash_entries(&mut self, crash_entries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CrashEntryFbs<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DiagnosticsResponse::VT_CRASH_ENTRIES, crash_entries);
+  }
+  #[inline]
+  pub fn add_heap_largest_block(&mut self, heap_largest_block: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_HEAP_LARGEST_BLOCK, heap_largest_block, 0);
+  }
+  #[inline]
+  pub fn add_tx_frames(&mut self, tx_frames: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_TX_FRAMES, tx_frames, 0);
+  }
+  #[inline]
+  pub fn add_tx_raw_bytes(&mut self, tx_raw_bytes: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_TX_RAW_BYTES, tx_raw_bytes, 0);
+  }
+  #[inline]
+  pub fn add_tx_sent_bytes(&mut self, tx_sent_bytes: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_TX_SENT_BYTES, tx_sent_bytes, 0);
+  }
+  #[inline]
+  pub fn add_tx_compression_ratio(&mut self, tx_compression_ratio: f32) {
+    self.fbb_.push_slot::<f32>(DiagnosticsResponse::VT_TX_COMPRESSION_RATIO, tx_compression_ratio, 1.0);
+  }
+  #[inline]
+  pub fn add_rx_dropped_frames(&mut self, rx_dropped_frames: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_RX_DROPPED_FRAMES, rx_dropped_frames, 0);
+  }
+  #[inline]
+  pub fn add_lifetime_ml(&mut self, lifetime_ml: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_LIFETIME_ML, lifetime_ml, 0);
+  }
+  #[inline]
+  pub fn add_since_service_ml(&mut self, since_service_ml: u64) {
+    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_SINCE_SERVICE_ML, since_service_ml, 0);
+  }
+  #[inline]
+  pub fn add_ulp_nh3_last_reading(&mut self, ulp_nh3_last_reading: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_ULP_NH3_LAST_READING, ulp_nh3_last_reading, 0);
+  }
+  #[inline]
+  pub fn add_ulp_cycle_count(&mut self, ulp_cycle_count: u32) {
+    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_ULP_CYCLE_COUNT, ulp_cycle_count, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DiagnosticsResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DiagnosticsResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DiagnosticsResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DiagnosticsResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DiagnosticsResponse");
+      ds.field("uptime_secs", &self.uptime_secs());
+      ds.field("control_cycles", &self.control_cycles());
+      ds.field("fault_count", &self.fault_count());
+      ds.field("crash_count", &self.crash_count());
+      ds.field("heap_free", &self.heap_free());
+      ds.field("heap_min_free", &self.heap_min_free());
+      ds.field("wifi_rssi", &self.wifi_rssi());
+      ds.field("nvs_free_entries", &self.nvs_free_entries());
+      ds.field("ulp_wake_count", &self.ulp_wake_count());
+      ds.field("crash_entries", &self.crash_entries());
+      ds.field("heap_largest_block", &self.heap_largest_block());
+      ds.field("tx_frames", &self.tx_frames());
+      ds.field("tx_raw_bytes", &self.tx_raw_bytes());
+      ds.field("tx_sent_bytes", &self.tx_sent_bytes());
+      ds.field("tx_compression_ratio", &self.tx_compression_ratio());
+      ds.field("rx_dropped_frames", &self.rx_dropped_frames());
+      ds.field("lifetime_ml", &self.lifetime_ml());
+      ds.field("since_service_ml", &self.since_service_ml());
+      ds.field("ulp_nh3_last_reading", &self.ulp_nh3_last_reading());
+      ds.field("ulp_cycle_count", &self.ulp_cycle_count());
+      ds.finish()
+  }
+}
+pub enum GetAuditLogRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetAuditLogRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetAuditLogRequest<'a> {
+  type Inner = GetAuditLogRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetAuditLogRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetAuditLogRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args GetAuditLogRequestArgs
+  ) -> flatbuffers::WIPOffset<GetAuditLogRequest<'bldr>> {
+    let mut builder = GetAuditLogRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for GetAuditLogRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetAuditLogRequestArgs {
+}
+impl<'a> Default for GetAuditLogRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetAuditLogRequestArgs {
+    }
+  }
+}
+
+pub struct GetAuditLogRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetAuditLogRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetAuditLogRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetAuditLogRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetAuditLogRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetAuditLogRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetAuditLogRequest");
+      ds.finish()
+  }
+}
+pub enum GetAuditLogResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Recent authenticated commands that changed the device, oldest first;
+/// read-only queries are not recorded.
+pub struct GetAuditLogResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetAuditLogResponse<'a> {
+  type Inner = GetAuditLogResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetAuditLogResponse<'a> {
+  pub const VT_ENTRIES: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetAuditLogResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetAuditLogResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<GetAuditLogResponse<'bldr>> {
+    let mut builder = GetAuditLogResponseBuilder::new(_fbb);
+    if let Some(x) = args.entries { builder.add_entries(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entries(&self) -> Option<flatbuffers::Vector<'a, AuditEntryFbs>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, AuditEntryFbs>>>(GetAuditLogResponse::VT_ENTRIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for GetAuditLogResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, AuditEntryFbs>>>("entries", Self::VT_ENTRIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetAuditLogResponseArgs<'a> {
+    pub entries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, AuditEntryFbs>>>,
+}
+impl<'a> Default for GetAuditLogResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GetAuditLogResponseArgs {
+      entries: None,
+    }
+  }
+}
+
+pub struct GetAuditLogResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetAuditLogResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entries(&mut self, entries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , AuditEntryFbs>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetAuditLogResponse::VT_ENTRIES, entries);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetAuditLogResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetAuditLogResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetAuditLogResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetAuditLogResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetAuditLogResponse");
+      ds.field("entries", &self.entries());
+      ds.finish()
+  }
+}
+pub enum GetScheduleListRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetScheduleListRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetScheduleListRequest<'a> {
+  type Inner = GetScheduleListRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetScheduleListRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetScheduleListRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args GetScheduleListRequestArgs
+  ) -> flatbuffers::WIPOffset<GetScheduleListRequest<'bldr>> {
+    let mut builder = GetScheduleListRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for GetScheduleListRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetScheduleListRequestArgs {
+}
+impl<'a> Default for GetScheduleListRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    GetScheduleListRequestArgs {
+    }
+  }
+}
+
+pub struct GetScheduleListRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetScheduleListRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetScheduleListRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetScheduleListRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetScheduleListRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetScheduleListRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetScheduleListRequest");
+      ds.finish()
+  }
+}
+pub enum ScheduleEntryFbsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// One occupied scheduler slot. Fields that do not apply to `kind`
+/// are left at zero.
+pub struct ScheduleEntryFbs<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScheduleEntryFbs<'a> {
+  type Inner = ScheduleEntryFbs<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScheduleEntryFbs<'a> {
+  pub const VT_SLOT: flatbuffers::VOffsetT = 4;
+  pub const VT_LABEL: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_ENABLED: flatbuffers::VOffsetT = 10;
+  pub const VT_INTERVAL_SECS: flatbuffers::VOffsetT = 12;
+  pub const VT_DURATION_SECS: flatbuffers::VOffsetT = 14;
+  pub const VT_DELAY_SECS: flatbuffers::VOffsetT = 16;
+  pub const VT_FIRE_COUNT: flatbuffers::VOffsetT = 18;
+  pub const VT_LAST_FIRE_SECS: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScheduleEntryFbs { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScheduleEntryFbsArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScheduleEntryFbs<'bldr>> {
+    let mut builder = ScheduleEntryFbsBuilder::new(_fbb);
+    builder.add_last_fire_secs(args.last_fire_secs);
+    builder.add_fire_count(args.fire_count);
+    builder.add_delay_secs(args.delay_secs);
+    builder.add_interval_secs(args.interval_secs);
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_duration_secs(args.duration_secs);
+    builder.add_enabled(args.enabled);
+    builder.add_kind(args.kind);
+    builder.add_slot(args.slot);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn slot(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(ScheduleEntryFbs::VT_SLOT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScheduleEntryFbs::VT_LABEL, None)}
+  }
+  #[inline]
+  pub fn kind(&self) -> ScheduleKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ScheduleKind>(ScheduleEntryFbs::VT_KIND, Some(ScheduleKind::Periodic)).unwrap()}
+  }
+  #[inline]
+  pub fn enabled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScheduleEntryFbs::VT_ENABLED, Some(false)).unwrap()}
+  }
+  /// Periodic only.
+  #[inline]
+  pub fn interval_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ScheduleEntryFbs::VT_INTERVAL_SECS, Some(0)).unwrap()}
+  }
+  /// Periodic and Boost.
+  #[inline]
+  pub fn duration_secs(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(ScheduleEntryFbs::VT_DURATION_SECS, Some(0)).unwrap()}
+  }
+  /// OneShot only.
+  #[inline]
+  pub fn delay_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ScheduleEntryFbs::VT_DELAY_SECS, Some(0)).unwrap()}
+  }
+  /// Times the schedule has fired since it was added.
+  #[inline]
+  pub fn fire_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ScheduleEntryFbs::VT_FIRE_COUNT, Some(0)).unwrap()}
+  }
+  /// Scheduler uptime (seconds) at the most recent fire; 0 if never fired.
+  #[inline]
+  pub fn last_fire_secs(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ScheduleEntryFbs::VT_LAST_FIRE_SECS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ScheduleEntryFbs<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("slot", Self::VT_SLOT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<ScheduleKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<bool>("enabled", Self::VT_ENABLED, false)?
+     .visit_field::<u32>("interval_secs", Self::VT_INTERVAL_SECS, false)?
+     .visit_field::<u16>("duration_secs", Self::VT_DURATION_SECS, false)?
+     .visit_field::<u32>("delay_secs", Self::VT_DELAY_SECS, false)?
+     .visit_field::<u32>("fire_count", Self::VT_FIRE_COUNT, false)?
+     .visit_field::<u32>("last_fire_secs", Self::VT_LAST_FIRE_SECS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScheduleEntryFbsArgs<'a> {
+    pub slot: u8,
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub kind: ScheduleKind,
+    pub enabled: bool,
+    pub interval_secs: u32,
+    pub duration_secs: u16,
+    pub delay_secs: u32,
+    pub fire_count: u32,
+    pub last_fire_secs: u32,
+}
+impl<'a> Default for ScheduleEntryFbsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScheduleEntryFbsArgs {
+      slot: 0,
+      label: None,
+      kind: ScheduleKind::Periodic,
+      enabled: false,
+      interval_secs: 0,
+      duration_secs: 0,
+      delay_secs: 0,
+      fire_count: 0,
+      last_fire_secs: 0,
+    }
+  }
+}
+
+pub struct ScheduleEntryFbsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScheduleEntryFbsBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_slot(&mut self, slot: u8) {
+    self.fbb_.push_slot::<u8>(ScheduleEntryFbs::VT_SLOT, slot, 0);
+  }
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScheduleEntryFbs::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: ScheduleKind) {
+    self.fbb_.push_slot::<ScheduleKind>(ScheduleEntryFbs::VT_KIND, kind, ScheduleKind::Periodic);
+  }
+  #[inline]
+  pub fn add_enabled(&mut self, enabled: bool) {
+    self.fbb_.push_slot::<bool>(ScheduleEntryFbs::VT_ENABLED, enabled, false);
+  }
+  #[inline]
+  pub fn add_interval_secs(&mut self, interval_secs: u32) {
+    self.fbb_.push_slot::<u32>(ScheduleEntryFbs::VT_INTERVAL_SECS, interval_secs, 0);
+  }
+  #[inline]
+  pub fn add_duration_secs(&mut self, duration_secs: u16) {
+    self.fbb_.push_slot::<u16>(ScheduleEntryFbs::VT_DURATION_SECS, duration_secs, 0);
+  }
+  #[inline]
+  pub fn add_delay_secs(&mut self, delay_secs: u32) {
+    self.fbb_.push_slot::<u32>(ScheduleEntryFbs::VT_DELAY_SECS, delay_secs, 0);
+  }
+  #[inline]
+  pub fn add_fire_count(&mut self, fire_count: u32) {
+    self.fbb_.push_slot::<u32>(ScheduleEntryFbs::VT_FIRE_COUNT, fire_count, 0);
+  }
+  #[inline]
+  pub fn add_last_fire_secs(&mut self, last_fire_secs: u32) {
+    self.fbb_.push_slot::<u32>(ScheduleEntryFbs::VT_LAST_FIRE_SECS, last_fire_secs, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScheduleEntryFbsBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScheduleEntryFbsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScheduleEntryFbs<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScheduleEntryFbs<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScheduleEntryFbs");
+      ds.field("slot", &self.slot());
+      ds.field("label", &self.label());
+      ds.field("kind", &self.kind());
+      ds.field("enabled", &self.enabled());
+      ds.field("interval_secs", &self.interval_secs());
+      ds.field("duration_secs", &self.duration_secs());
+      ds.field("delay_secs", &self.delay_secs());
+      ds.field("fire_count", &self.fire_count());
+      ds.field("last_fire_secs", &self.last_fire_secs());
+      ds.finish()
+  }
+}
+pub enum GetScheduleListResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetScheduleListResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetScheduleListResponse<'a> {
+  type Inner = GetScheduleListResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetScheduleListResponse<'a> {
+  pub const VT_SCHEDULES: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetScheduleListResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GetScheduleListResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<GetScheduleListResponse<'bldr>> {
+    let mut builder = GetScheduleListResponseBuilder::new(_fbb);
+    if let Some(x) = args.schedules { builder.add_schedules(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn schedules(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScheduleEntryFbs<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScheduleEntryFbs>>>>(GetScheduleListResponse::VT_SCHEDULES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for GetScheduleListResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ScheduleEntryFbs>>>>("schedules", Self::VT_SCHEDULES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetScheduleListResponseArgs<'a> {
+    pub schedules: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScheduleEntryFbs<'a>>>>>,
+}
+impl<'a> Default for GetScheduleListResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GetScheduleListResponseArgs {
+      schedules: None,
+    }
+  }
+}
+
+pub struct GetScheduleListResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GetScheduleListResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_schedules(&mut self, schedules: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ScheduleEntryFbs<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetScheduleListResponse::VT_SCHEDULES, schedules);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GetScheduleListResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GetScheduleListResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetScheduleListResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetScheduleListResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetScheduleListResponse");
+      ds.field("schedules", &self.schedules());
+      ds.finish()
+  }
+}
+pub enum SetScheduleEnabledRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Pause (`enabled` false) or resume the schedule whose label matches
+/// `label`, keeping its slot and fire history.  The pause is saved in
+/// the config and survives a restart.  Refused if no schedule has that
+/// label.
+pub struct SetScheduleEnabledRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetScheduleEnabledRequest<'a> {
+  type Inner = SetScheduleEnabledRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetScheduleEnabledRequest<'a> {
+  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
+  pub const VT_ENABLED: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetScheduleEnabledRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SetScheduleEnabledRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<SetScheduleEnabledRequest<'bldr>> {
+    let mut builder = SetScheduleEnabledRequestBuilder::new(_fbb);
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_enabled(args.enabled);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetScheduleEnabledRequest::VT_LABEL, None)}
+  }
+  #[inline]
+  pub fn enabled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SetScheduleEnabledRequest::VT_ENABLED, Some(true)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetScheduleEnabledRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<bool>("enabled", Self::VT_ENABLED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetScheduleEnabledRequestArgs<'a> {
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub enabled: bool,
+}
+impl<'a> Default for SetScheduleEnabledRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SetScheduleEnabledRequestArgs {
+      label: None,
+      enabled: true,
+    }
+  }
+}
+
+pub struct SetScheduleEnabledRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetScheduleEnabledRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetScheduleEnabledRequest::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_enabled(&mut self, enabled: bool) {
+    self.fbb_.push_slot::<bool>(SetScheduleEnabledRequest::VT_ENABLED, enabled, true);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetScheduleEnabledRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SetScheduleEnabledRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetScheduleEnabledRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetScheduleEnabledRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetScheduleEnabledRequest");
+      ds.field("label", &self.label());
+      ds.field("enabled", &self.enabled());
+      ds.finish()
+  }
+}
+pub enum ScanWifiRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Scan for nearby access points.  Works before the station is
+/// connected, so a BLE client can offer an SSID picker.
+pub struct ScanWifiRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScanWifiRequest<'a> {
+  type Inner = ScanWifiRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScanWifiRequest<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScanWifiRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ScanWifiRequestArgs
+  ) -> flatbuffers::WIPOffset<ScanWifiRequest<'bldr>> {
+    let mut builder = ScanWifiRequestBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ScanWifiRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScanWifiRequestArgs {
+}
+impl<'a> Default for ScanWifiRequestArgs {
+  #[inline]
+  fn default() -> Self {
+    ScanWifiRequestArgs {
+    }
+  }
+}
+
+pub struct ScanWifiRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanWifiRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanWifiRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScanWifiRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanWifiRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScanWifiRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScanWifiRequest");
+      ds.finish()
+  }
+}
+pub enum WifiApFbsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WifiApFbs<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WifiApFbs<'a> {
+  type Inner = WifiApFbs<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> WifiApFbs<'a> {
+  pub const VT_SSID: flatbuffers::VOffsetT = 4;
+  pub const VT_RSSI: flatbuffers::VOffsetT = 6;
+  pub const VT_AUTH_MODE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    WifiApFbs { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args WifiApFbsArgs<'args>
+  ) -> flatbuffers::WIPOffset<WifiApFbs<'bldr>> {
+    let mut builder = WifiApFbsBuilder::new(_fbb);
+    if let Some(x) = args.ssid { builder.add_ssid(x); }
+    builder.add_auth_mode(args.auth_mode);
+    builder.add_rssi(args.rssi);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ssid(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(WifiApFbs::VT_SSID, None)}
+  }
+  #[inline]
+  pub fn rssi(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(WifiApFbs::VT_RSSI, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn auth_mode(&self) -> WifiAuthMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<WifiAuthMode>(WifiApFbs::VT_AUTH_MODE, Some(WifiAuthMode::Open)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for WifiApFbs<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("ssid", Self::VT_SSID, false)?
+     .visit_field::<i8>("rssi", Self::VT_RSSI, false)?
+     .visit_field::<WifiAuthMode>("auth_mode", Self::VT_AUTH_MODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WifiApFbsArgs<'a> {
+    pub ssid: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub rssi: i8,
+    pub auth_mode: WifiAuthMode,
+}
+impl<'a> Default for WifiApFbsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    WifiApFbsArgs {
+      ssid: None,
+      rssi: 0,
+      auth_mode: WifiAuthMode::Open,
+    }
+  }
+}
+
+pub struct WifiApFbsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WifiApFbsBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ssid(&mut self, ssid: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(WifiApFbs::VT_SSID, ssid);
+  }
+  #[inline]
+  pub fn add_rssi(&mut self, rssi: i8) {
+    self.fbb_.push_slot::<i8>(WifiApFbs::VT_RSSI, rssi, 0);
+  }
+  #[inline]
+  pub fn add_auth_mode(&mut self, auth_mode: WifiAuthMode) {
+    self.fbb_.push_slot::<WifiAuthMode>(WifiApFbs::VT_AUTH_MODE, auth_mode, WifiAuthMode::Open);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> WifiApFbsBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    WifiApFbsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<WifiApFbs<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for WifiApFbs<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("WifiApFbs");
+      ds.field("ssid", &self.ssid());
+      ds.field("rssi", &self.rssi());
+      ds.field("auth_mode", &self.auth_mode());
+      ds.finish()
+  }
+}
+pub enum ScanWifiResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScanWifiResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScanWifiResponse<'a> {
+  type Inner = ScanWifiResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScanWifiResponse<'a> {
+  pub const VT_ACCESS_POINTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScanWifiResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScanWifiResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScanWifiResponse<'bldr>> {
+    let mut builder = ScanWifiResponseBuilder::new(_fbb);
+    if let Some(x) = args.access_points { builder.add_access_points(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn access_points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<WifiApFbs<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<WifiApFbs>>>>(ScanWifiResponse::VT_ACCESS_POINTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScanWifiResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<WifiApFbs>>>>("access_points", Self::VT_ACCESS_POINTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScanWifiResponseArgs<'a> {
+    pub access_points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<WifiApFbs<'a>>>>>,
+}
+impl<'a> Default for ScanWifiResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScanWifiResponseArgs {
+      access_points: None,
+    }
+  }
+}
+
+pub struct ScanWifiResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanWifiResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_access_points(&mut self, access_points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<WifiApFbs<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanWifiResponse::VT_ACCESS_POINTS, access_points);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanWifiResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScanWifiResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanWifiResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScanWifiResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScanWifiResponse");
+      ds.field("access_points", &self.access_points());
+      ds.finish()
+  }
+}
+pub enum MessageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Message<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Message<'a> {
+  type Inner = Message<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Message<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Message { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MessageArgs
+  ) -> flatbuffers::WIPOffset<Message<'bldr>> {
+    let mut builder = MessageBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    builder.add_id(args.id);
+    builder.add_payload_type(args.payload_type);
+    builder.finish()
+  }
+
+
+  /// Monotonically increasing message ID for request/response correlation.
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Message::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn payload_type(&self) -> Payload {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Payload>(Message::VT_PAYLOAD_TYPE, Some(Payload::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Message::VT_PAYLOAD, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_status_request(&self) -> Option<GetStatusRequest<'a>> {
+    if self.payload_type() == Payload::GetStatusRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetStatusRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_start_scrub_request(&self) -> Option<StartScrubRequest<'a>> {
+    if self.payload_type() == Payload::StartScrubRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StartScrubRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_stop_scrub_request(&self) -> Option<StopScrubRequest<'a>> {
+    if self.payload_type() == Payload::StopScrubRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StopScrubRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_clear_faults_request(&self) -> Option<ClearFaultsRequest<'a>> {
+    if self.payload_type() == Payload::ClearFaultsRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ClearFaultsRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_config_request(&self) -> Option<SetConfigRequest<'a>> {
+    if self.payload_type() == Payload::SetConfigRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetConfigRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_schedule_request(&self) -> Option<SetScheduleRequest<'a>> {
+    if self.payload_type() == Payload::SetScheduleRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetScheduleRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_cancel_schedule_request(&self) -> Option<CancelScheduleRequest<'a>> {
+    if self.payload_type() == Payload::CancelScheduleRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CancelScheduleRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_subscribe_telemetry_request(&self) -> Option<SubscribeTelemetryRequest<'a>> {
+    if self.payload_type() == Payload::SubscribeTelemetryRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SubscribeTelemetryRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_unsubscribe_telemetry_request(&self) -> Option<UnsubscribeTelemetryRequest<'a>> {
+    if self.payload_type() == Payload::UnsubscribeTelemetryRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { UnsubscribeTelemetryRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_device_info_request(&self) -> Option<GetDeviceInfoRequest<'a>> {
+    if self.payload_type() == Payload::GetDeviceInfoRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetDeviceInfoRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_status_response(&self) -> Option<StatusResponse<'a>> {
+    if self.payload_type() == Payload::StatusResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StatusResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_device_info_response(&self) -> Option<DeviceInfoResponse<'a>> {
+    if self.payload_type() == Payload::DeviceInfoResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DeviceInfoResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ack_response(&self) -> Option<AckResponse<'a>> {
+    if self.payload_type() == Payload::AckResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AckResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_telemetry_frame(&self) -> Option<TelemetryFrame<'a>> {
+    if self.payload_type() == Payload::TelemetryFrame {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { TelemetryFrame::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_state_change_event(&self) -> Option<StateChangeEvent<'a>> {
+    if self.payload_type() == Payload::StateChangeEvent {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StateChangeEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_fault_event(&self) -> Option<FaultEvent<'a>> {
+    if self.payload_type() == Payload::FaultEvent {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FaultEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_auth_challenge_request(&self) -> Option<AuthChallengeRequest<'a>> {
+    if self.payload_type() == Payload::AuthChallengeRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AuthChallengeRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_auth_challenge_response(&self) -> Option<AuthChallengeResponse<'a>> {
+    if self.payload_type() == Payload::AuthChallengeResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AuthChallengeResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_auth_verify_request(&self) -> Option<AuthVerifyRequest<'a>> {
+    if self.payload_type() == Payload::AuthVerifyRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AuthVerifyRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_auth_verify_response(&self) -> Option<AuthVerifyResponse<'a>> {
+    if self.payload_type() == Payload::AuthVerifyResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AuthVerifyResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_provision_cert_request(&self) -> Option<ProvisionCertRequest<'a>> {
+    if self.payload_type() == Payload::ProvisionCertRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ProvisionCertRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_cert_status_request(&self) -> Option<GetCertStatusRequest<'a>> {
+    if self.payload_type() == Payload::GetCertStatusRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetCertStatusRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_cert_status_response(&self) -> Option<CertStatusResponse<'a>> {
+    if self.payload_type() == Payload::CertStatusResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CertStatusResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ota_begin_request(&self) -> Option<OtaBeginRequest<'a>> {
+    if self.payload_type() == Payload::OtaBeginRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OtaBeginRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ota_chunk_request(&self) -> Option<OtaChunkRequest<'a>> {
+    if self.payload_type() == Payload::OtaChunkRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OtaChunkRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ota_finalize_request(&self) -> Option<OtaFinalizeRequest<'a>> {
+    if self.payload_type() == Payload::OtaFinalizeRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OtaFinalizeRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ota_response(&self) -> Option<OtaResponse<'a>> {
+    if self.payload_type() == Payload::OtaResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OtaResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn wifi_rssi(&self) -> i8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<i8>(DiagnosticsResponse::VT_WIFI_RSSI, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn payload_as_ota_progress_event(&self) -> Option<OtaProgressEvent<'a>> {
+    if self.payload_type() == Payload::OtaProgressEvent {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OtaProgressEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn nvs_free_entries(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_NVS_FREE_ENTRIES, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_diagnostics_request(&self) -> Option<GetDiagnosticsRequest<'a>> {
+    if self.payload_type() == Payload::GetDiagnosticsRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetDiagnosticsRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn ulp_wake_count(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(DiagnosticsResponse::VT_ULP_WAKE_COUNT, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn payload_as_clear_diagnostics_request(&self) -> Option<ClearDiagnosticsRequest<'a>> {
+    if self.payload_type() == Payload::ClearDiagnosticsRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ClearDiagnosticsRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn crash_entries(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs<'a>>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs>>>>(DiagnosticsResponse::VT_CRASH_ENTRIES, None)}
+  #[allow(non_snake_case)]
+  pub fn payload_as_diagnostics_response(&self) -> Option<DiagnosticsResponse<'a>> {
+    if self.payload_type() == Payload::DiagnosticsResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DiagnosticsResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
 
-impl flatbuffers::Verifiable for DiagnosticsResponse<'_> {
   #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<u64>("uptime_secs", Self::VT_UPTIME_SECS, false)?
-     .visit_field::<u64>("control_cycles", Self::VT_CONTROL_CYCLES, false)?
-     .visit_field::<u32>("fault_count", Self::VT_FAULT_COUNT, false)?
-     .visit_field::<u32>("crash_count", Self::VT_CRASH_COUNT, false)?
-     .visit_field::<u32>("heap_free", Self::VT_HEAP_FREE, false)?
-     .visit_field::<u32>("heap_min_free", Self::VT_HEAP_MIN_FREE, false)?
-     .visit_field::<i8>("wifi_rssi", Self::VT_WIFI_RSSI, false)?
-     .visit_field::<u32>("nvs_free_entries", Self::VT_NVS_FREE_ENTRIES, false)?
-     .visit_field::<u32>("ulp_wake_count", Self::VT_ULP_WAKE_COUNT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CrashEntryFbs>>>>("crash_entries", Self::VT_CRASH_ENTRIES, false)?
-     .finish();
-    Ok(())
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_schedule_list_request(&self) -> Option<GetScheduleListRequest<'a>> {
+    if self.payload_type() == Payload::GetScheduleListRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetScheduleListRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-pub struct DiagnosticsResponseArgs<'a> {
-    pub uptime_secs: u64,
-    pub control_cycles: u64,
-    pub fault_count: u32,
-    pub crash_count: u32,
-    pub heap_free: u32,
-    pub heap_min_free: u32,
-    pub wifi_rssi: i8,
-    pub nvs_free_entries: u32,
-    pub ulp_wake_count: u32,
-    pub crash_entries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CrashEntryFbs<'a>>>>>,
-}
-impl<'a> Default for DiagnosticsResponseArgs<'a> {
+
   #[inline]
-  fn default() -> Self {
-    DiagnosticsResponseArgs {
-      uptime_secs: 0,
-      control_cycles: 0,
-      fault_count: 0,
-      crash_count: 0,
-      heap_free: 0,
-      heap_min_free: 0,
-      wifi_rssi: 0,
-      nvs_free_entries: 0,
-      ulp_wake_count: 0,
-      crash_entries: None,
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_schedule_list_response(&self) -> Option<GetScheduleListResponse<'a>> {
+    if self.payload_type() == Payload::GetScheduleListResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetScheduleListResponse::init_from_table(t) }
+     })
+    } else {
+      None
     }
   }
-}
 
-pub struct DiagnosticsResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DiagnosticsResponseBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_uptime_secs(&mut self, uptime_secs: u64) {
-    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_UPTIME_SECS, uptime_secs, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_scan_wifi_request(&self) -> Option<ScanWifiRequest<'a>> {
+    if self.payload_type() == Payload::ScanWifiRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ScanWifiRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_control_cycles(&mut self, control_cycles: u64) {
-    self.fbb_.push_slot::<u64>(DiagnosticsResponse::VT_CONTROL_CYCLES, control_cycles, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_scan_wifi_response(&self) -> Option<ScanWifiResponse<'a>> {
+    if self.payload_type() == Payload::ScanWifiResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ScanWifiResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_fault_count(&mut self, fault_count: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_FAULT_COUNT, fault_count, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_led_brightness_request(&self) -> Option<SetLedBrightnessRequest<'a>> {
+    if self.payload_type() == Payload::SetLedBrightnessRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetLedBrightnessRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_crash_count(&mut self, crash_count: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_CRASH_COUNT, crash_count, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_quiet_hours_request(&self) -> Option<SetQuietHoursRequest<'a>> {
+    if self.payload_type() == Payload::SetQuietHoursRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetQuietHoursRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_heap_free(&mut self, heap_free: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_HEAP_FREE, heap_free, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_clear_quiet_hours_request(&self) -> Option<ClearQuietHoursRequest<'a>> {
+    if self.payload_type() == Payload::ClearQuietHoursRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ClearQuietHoursRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_heap_min_free(&mut self, heap_min_free: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_HEAP_MIN_FREE, heap_min_free, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_restart_device_request(&self) -> Option<RestartDeviceRequest<'a>> {
+    if self.payload_type() == Payload::RestartDeviceRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RestartDeviceRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_wifi_rssi(&mut self, wifi_rssi: i8) {
-    self.fbb_.push_slot::<i8>(DiagnosticsResponse::VT_WIFI_RSSI, wifi_rssi, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_config_request(&self) -> Option<GetConfigRequest<'a>> {
+    if self.payload_type() == Payload::GetConfigRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetConfigRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_nvs_free_entries(&mut self, nvs_free_entries: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_NVS_FREE_ENTRIES, nvs_free_entries, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_config_response(&self) -> Option<GetConfigResponse<'a>> {
+    if self.payload_type() == Payload::GetConfigResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetConfigResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_ulp_wake_count(&mut self, ulp_wake_count: u32) {
-    self.fbb_.push_slot::<u32>(DiagnosticsResponse::VT_ULP_WAKE_COUNT, ulp_wake_count, 0);
+  #[allow(non_snake_case)]
+  pub fn payload_as_drain_water_request(&self) -> Option<DrainWaterRequest<'a>> {
+    if self.payload_type() == Payload::DrainWaterRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DrainWaterRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_crash_entries(&mut self, crash_entries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CrashEntryFbs<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DiagnosticsResponse::VT_CRASH_ENTRIES, crash_entries);
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_power_profile_request(&self) -> Option<SetPowerProfileRequest<'a>> {
+    if self.payload_type() == Payload::SetPowerProfileRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetPowerProfileRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DiagnosticsResponseBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    DiagnosticsResponseBuilder {
-      fbb_: _fbb,
-      start_: start,
+  #[allow(non_snake_case)]
+  pub fn payload_as_set_led_pattern_request(&self) -> Option<SetLedPatternRequest<'a>> {
+    if self.payload_type() == Payload::SetLedPatternRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetLedPatternRequest::init_from_table(t) }
+     })
+    } else {
+      None
     }
   }
+
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<DiagnosticsResponse<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  #[allow(non_snake_case)]
+  pub fn payload_as_reset_led_patterns_request(&self) -> Option<ResetLedPatternsRequest<'a>> {
+    if self.payload_type() == Payload::ResetLedPatternsRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ResetLedPatternsRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
 
-impl core::fmt::Debug for DiagnosticsResponse<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("DiagnosticsResponse");
-      ds.field("uptime_secs", &self.uptime_secs());
-      ds.field("control_cycles", &self.control_cycles());
-      ds.field("fault_count", &self.fault_count());
-      ds.field("crash_count", &self.crash_count());
-      ds.field("heap_free", &self.heap_free());
-      ds.field("heap_min_free", &self.heap_min_free());
-      ds.field("wifi_rssi", &self.wifi_rssi());
-      ds.field("nvs_free_entries", &self.nvs_free_entries());
-      ds.field("ulp_wake_count", &self.ulp_wake_count());
-      ds.field("crash_entries", &self.crash_entries());
-      ds.finish()
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ping_request(&self) -> Option<PingRequest<'a>> {
+    if self.payload_type() == Payload::PingRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PingRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-pub enum MessageOffset {}
-#[derive(Copy, Clone, PartialEq)]
 
-pub struct Message<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for Message<'a> {
-  type Inner = Message<'a>;
   #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  #[allow(non_snake_case)]
+  pub fn payload_as_pong_response(&self) -> Option<PongResponse<'a>> {
+    if self.payload_type() == Payload::PongResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PongResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-
-impl<'a> Message<'a> {
-  pub const VT_ID: flatbuffers::VOffsetT = 4;
-  pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 6;
-  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
 
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Message { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MessageArgs
-  ) -> flatbuffers::WIPOffset<Message<'bldr>> {
-    let mut builder = MessageBuilder::new(_fbb);
-    if let Some(x) = args.payload { builder.add_payload(x); }
-    builder.add_id(args.id);
-    builder.add_payload_type(args.payload_type);
-    builder.finish()
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_audit_log_request(&self) -> Option<GetAuditLogRequest<'a>> {
+    if self.payload_type() == Payload::GetAuditLogRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetAuditLogRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
 
-
-  /// Monotonically increasing message ID for request/response correlation.
   #[inline]
-  pub fn id(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(Message::VT_ID, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_audit_log_response(&self) -> Option<GetAuditLogResponse<'a>> {
+    if self.payload_type() == Payload::GetAuditLogResponse {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetAuditLogResponse::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn payload_type(&self) -> Payload {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Payload>(Message::VT_PAYLOAD_TYPE, Some(Payload::NONE)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn payload_as_factory_reset_request(&self) -> Option<FactoryResetRequest<'a>> {
+    if self.payload_type() == Payload::FactoryResetRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FactoryResetRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Message::VT_PAYLOAD, None)}
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_telemetry_history_request(&self) -> Option<GetTelemetryHistoryRequest<'a>> {
+    if self.payload_type() == Payload::GetTelemetryHistoryRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetTelemetryHistoryRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_get_status_request(&self) -> Option<GetStatusRequest<'a>> {
-    if self.payload_type() == Payload::GetStatusRequest {
+  pub fn payload_as_get_telemetry_history_response(&self) -> Option<GetTelemetryHistoryResponse<'a>> {
+    if self.payload_type() == Payload::GetTelemetryHistoryResponse {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { GetStatusRequest::init_from_table(t) }
+       unsafe { GetTelemetryHistoryResponse::init_from_table(t) }
      })
     } else {
       None
@@ -4453,13 +15237,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_start_scrub_request(&self) -> Option<StartScrubRequest<'a>> {
-    if self.payload_type() == Payload::StartScrubRequest {
+  pub fn payload_as_get_capabilities_request(&self) -> Option<GetCapabilitiesRequest<'a>> {
+    if self.payload_type() == Payload::GetCapabilitiesRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { StartScrubRequest::init_from_table(t) }
+       unsafe { GetCapabilitiesRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4468,13 +15252,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_stop_scrub_request(&self) -> Option<StopScrubRequest<'a>> {
-    if self.payload_type() == Payload::StopScrubRequest {
+  pub fn payload_as_get_capabilities_response(&self) -> Option<GetCapabilitiesResponse<'a>> {
+    if self.payload_type() == Payload::GetCapabilitiesResponse {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { StopScrubRequest::init_from_table(t) }
+       unsafe { GetCapabilitiesResponse::init_from_table(t) }
      })
     } else {
       None
@@ -4483,13 +15267,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_clear_faults_request(&self) -> Option<ClearFaultsRequest<'a>> {
-    if self.payload_type() == Payload::ClearFaultsRequest {
+  pub fn payload_as_set_time_request(&self) -> Option<SetTimeRequest<'a>> {
+    if self.payload_type() == Payload::SetTimeRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { ClearFaultsRequest::init_from_table(t) }
+       unsafe { SetTimeRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4498,13 +15282,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_set_config_request(&self) -> Option<SetConfigRequest<'a>> {
-    if self.payload_type() == Payload::SetConfigRequest {
+  pub fn payload_as_set_maintenance_mode_request(&self) -> Option<SetMaintenanceModeRequest<'a>> {
+    if self.payload_type() == Payload::SetMaintenanceModeRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { SetConfigRequest::init_from_table(t) }
+       unsafe { SetMaintenanceModeRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4513,13 +15297,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_set_schedule_request(&self) -> Option<SetScheduleRequest<'a>> {
-    if self.payload_type() == Payload::SetScheduleRequest {
+  pub fn payload_as_config_changed_event(&self) -> Option<ConfigChangedEvent<'a>> {
+    if self.payload_type() == Payload::ConfigChangedEvent {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { SetScheduleRequest::init_from_table(t) }
+       unsafe { ConfigChangedEvent::init_from_table(t) }
      })
     } else {
       None
@@ -4528,13 +15312,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_cancel_schedule_request(&self) -> Option<CancelScheduleRequest<'a>> {
-    if self.payload_type() == Payload::CancelScheduleRequest {
+  pub fn payload_as_reset_counters_request(&self) -> Option<ResetCountersRequest<'a>> {
+    if self.payload_type() == Payload::ResetCountersRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { CancelScheduleRequest::init_from_table(t) }
+       unsafe { ResetCountersRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4543,13 +15327,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_subscribe_telemetry_request(&self) -> Option<SubscribeTelemetryRequest<'a>> {
-    if self.payload_type() == Payload::SubscribeTelemetryRequest {
+  pub fn payload_as_inject_sensor_request(&self) -> Option<InjectSensorRequest<'a>> {
+    if self.payload_type() == Payload::InjectSensorRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { SubscribeTelemetryRequest::init_from_table(t) }
+       unsafe { InjectSensorRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4558,13 +15342,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_unsubscribe_telemetry_request(&self) -> Option<UnsubscribeTelemetryRequest<'a>> {
-    if self.payload_type() == Payload::UnsubscribeTelemetryRequest {
+  pub fn payload_as_auto_tune_request(&self) -> Option<AutoTuneRequest<'a>> {
+    if self.payload_type() == Payload::AutoTuneRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { UnsubscribeTelemetryRequest::init_from_table(t) }
+       unsafe { AutoTuneRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4573,13 +15357,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_get_device_info_request(&self) -> Option<GetDeviceInfoRequest<'a>> {
-    if self.payload_type() == Payload::GetDeviceInfoRequest {
+  pub fn payload_as_auto_tune_response(&self) -> Option<AutoTuneResponse<'a>> {
+    if self.payload_type() == Payload::AutoTuneResponse {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { GetDeviceInfoRequest::init_from_table(t) }
+       unsafe { AutoTuneResponse::init_from_table(t) }
      })
     } else {
       None
@@ -4588,13 +15372,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_status_response(&self) -> Option<StatusResponse<'a>> {
-    if self.payload_type() == Payload::StatusResponse {
+  pub fn payload_as_ota_status_request(&self) -> Option<OtaStatusRequest<'a>> {
+    if self.payload_type() == Payload::OtaStatusRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { StatusResponse::init_from_table(t) }
+       unsafe { OtaStatusRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4603,13 +15387,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_device_info_response(&self) -> Option<DeviceInfoResponse<'a>> {
-    if self.payload_type() == Payload::DeviceInfoResponse {
+  pub fn payload_as_ota_status_response(&self) -> Option<OtaStatusResponse<'a>> {
+    if self.payload_type() == Payload::OtaStatusResponse {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { DeviceInfoResponse::init_from_table(t) }
+       unsafe { OtaStatusResponse::init_from_table(t) }
      })
     } else {
       None
@@ -4618,13 +15402,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ack_response(&self) -> Option<AckResponse<'a>> {
-    if self.payload_type() == Payload::AckResponse {
+  pub fn payload_as_nh3_alarm_event(&self) -> Option<Nh3AlarmEvent<'a>> {
+    if self.payload_type() == Payload::Nh3AlarmEvent {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { AckResponse::init_from_table(t) }
+       unsafe { Nh3AlarmEvent::init_from_table(t) }
      })
     } else {
       None
@@ -4633,13 +15417,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_telemetry_frame(&self) -> Option<TelemetryFrame<'a>> {
-    if self.payload_type() == Payload::TelemetryFrame {
+  pub fn payload_as_set_schedule_enabled_request(&self) -> Option<SetScheduleEnabledRequest<'a>> {
+    if self.payload_type() == Payload::SetScheduleEnabledRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { TelemetryFrame::init_from_table(t) }
+       unsafe { SetScheduleEnabledRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4648,13 +15432,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_state_change_event(&self) -> Option<StateChangeEvent<'a>> {
-    if self.payload_type() == Payload::StateChangeEvent {
+  pub fn payload_as_set_install_id_request(&self) -> Option<SetInstallIdRequest<'a>> {
+    if self.payload_type() == Payload::SetInstallIdRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { StateChangeEvent::init_from_table(t) }
+       unsafe { SetInstallIdRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4663,13 +15447,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_fault_event(&self) -> Option<FaultEvent<'a>> {
-    if self.payload_type() == Payload::FaultEvent {
+  pub fn payload_as_test_fault_request(&self) -> Option<TestFaultRequest<'a>> {
+    if self.payload_type() == Payload::TestFaultRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { FaultEvent::init_from_table(t) }
+       unsafe { TestFaultRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4678,13 +15462,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_auth_challenge_request(&self) -> Option<AuthChallengeRequest<'a>> {
-    if self.payload_type() == Payload::AuthChallengeRequest {
+  pub fn payload_as_add_client_cert_pin_request(&self) -> Option<AddClientCertPinRequest<'a>> {
+    if self.payload_type() == Payload::AddClientCertPinRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { AuthChallengeRequest::init_from_table(t) }
+       unsafe { AddClientCertPinRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4693,13 +15477,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_auth_challenge_response(&self) -> Option<AuthChallengeResponse<'a>> {
-    if self.payload_type() == Payload::AuthChallengeResponse {
+  pub fn payload_as_remove_client_cert_pin_request(&self) -> Option<RemoveClientCertPinRequest<'a>> {
+    if self.payload_type() == Payload::RemoveClientCertPinRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { AuthChallengeResponse::init_from_table(t) }
+       unsafe { RemoveClientCertPinRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4708,13 +15492,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_auth_verify_request(&self) -> Option<AuthVerifyRequest<'a>> {
-    if self.payload_type() == Payload::AuthVerifyRequest {
+  pub fn payload_as_reset_service_flow_request(&self) -> Option<ResetServiceFlowRequest<'a>> {
+    if self.payload_type() == Payload::ResetServiceFlowRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { AuthVerifyRequest::init_from_table(t) }
+       unsafe { ResetServiceFlowRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4723,13 +15507,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_auth_verify_response(&self) -> Option<AuthVerifyResponse<'a>> {
-    if self.payload_type() == Payload::AuthVerifyResponse {
+  pub fn payload_as_session_migration_token_request(&self) -> Option<SessionMigrationTokenRequest<'a>> {
+    if self.payload_type() == Payload::SessionMigrationTokenRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { AuthVerifyResponse::init_from_table(t) }
+       unsafe { SessionMigrationTokenRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4738,13 +15522,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_provision_cert_request(&self) -> Option<ProvisionCertRequest<'a>> {
-    if self.payload_type() == Payload::ProvisionCertRequest {
+  pub fn payload_as_session_migration_token_response(&self) -> Option<SessionMigrationTokenResponse<'a>> {
+    if self.payload_type() == Payload::SessionMigrationTokenResponse {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { ProvisionCertRequest::init_from_table(t) }
+       unsafe { SessionMigrationTokenResponse::init_from_table(t) }
      })
     } else {
       None
@@ -4753,13 +15537,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_get_cert_status_request(&self) -> Option<GetCertStatusRequest<'a>> {
-    if self.payload_type() == Payload::GetCertStatusRequest {
+  pub fn payload_as_migrate_session_request(&self) -> Option<MigrateSessionRequest<'a>> {
+    if self.payload_type() == Payload::MigrateSessionRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { GetCertStatusRequest::init_from_table(t) }
+       unsafe { MigrateSessionRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4768,13 +15552,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_cert_status_response(&self) -> Option<CertStatusResponse<'a>> {
-    if self.payload_type() == Payload::CertStatusResponse {
+  pub fn payload_as_set_state_timeout_request(&self) -> Option<SetStateTimeoutRequest<'a>> {
+    if self.payload_type() == Payload::SetStateTimeoutRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { CertStatusResponse::init_from_table(t) }
+       unsafe { SetStateTimeoutRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4783,13 +15567,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ota_begin_request(&self) -> Option<OtaBeginRequest<'a>> {
-    if self.payload_type() == Payload::OtaBeginRequest {
+  pub fn payload_as_ota_abort_request(&self) -> Option<OtaAbortRequest<'a>> {
+    if self.payload_type() == Payload::OtaAbortRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { OtaBeginRequest::init_from_table(t) }
+       unsafe { OtaAbortRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4798,13 +15582,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ota_chunk_request(&self) -> Option<OtaChunkRequest<'a>> {
-    if self.payload_type() == Payload::OtaChunkRequest {
+  pub fn payload_as_set_tank_source_request(&self) -> Option<SetTankSourceRequest<'a>> {
+    if self.payload_type() == Payload::SetTankSourceRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { OtaChunkRequest::init_from_table(t) }
+       unsafe { SetTankSourceRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4813,13 +15597,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ota_finalize_request(&self) -> Option<OtaFinalizeRequest<'a>> {
-    if self.payload_type() == Payload::OtaFinalizeRequest {
+  pub fn payload_as_set_psk_grace_request(&self) -> Option<SetPskGraceRequest<'a>> {
+    if self.payload_type() == Payload::SetPskGraceRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { OtaFinalizeRequest::init_from_table(t) }
+       unsafe { SetPskGraceRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4828,13 +15612,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ota_response(&self) -> Option<OtaResponse<'a>> {
-    if self.payload_type() == Payload::OtaResponse {
+  pub fn payload_as_set_jitter_compensation_request(&self) -> Option<SetJitterCompensationRequest<'a>> {
+    if self.payload_type() == Payload::SetJitterCompensationRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { OtaResponse::init_from_table(t) }
+       unsafe { SetJitterCompensationRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4843,13 +15627,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_ota_progress_event(&self) -> Option<OtaProgressEvent<'a>> {
-    if self.payload_type() == Payload::OtaProgressEvent {
+  pub fn payload_as_set_auth_lockout_request(&self) -> Option<SetAuthLockoutRequest<'a>> {
+    if self.payload_type() == Payload::SetAuthLockoutRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { OtaProgressEvent::init_from_table(t) }
+       unsafe { SetAuthLockoutRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4858,13 +15642,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_get_diagnostics_request(&self) -> Option<GetDiagnosticsRequest<'a>> {
-    if self.payload_type() == Payload::GetDiagnosticsRequest {
+  pub fn payload_as_set_wifi_roam_request(&self) -> Option<SetWifiRoamRequest<'a>> {
+    if self.payload_type() == Payload::SetWifiRoamRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { GetDiagnosticsRequest::init_from_table(t) }
+       unsafe { SetWifiRoamRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4873,13 +15657,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_clear_diagnostics_request(&self) -> Option<ClearDiagnosticsRequest<'a>> {
-    if self.payload_type() == Payload::ClearDiagnosticsRequest {
+  pub fn payload_as_set_tcp_idle_timeout_request(&self) -> Option<SetTcpIdleTimeoutRequest<'a>> {
+    if self.payload_type() == Payload::SetTcpIdleTimeoutRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { ClearDiagnosticsRequest::init_from_table(t) }
+       unsafe { SetTcpIdleTimeoutRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4888,13 +15672,13 @@ impl<'a> Message<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn payload_as_diagnostics_response(&self) -> Option<DiagnosticsResponse<'a>> {
-    if self.payload_type() == Payload::DiagnosticsResponse {
+  pub fn payload_as_set_ping_auth_request(&self) -> Option<SetPingAuthRequest<'a>> {
+    if self.payload_type() == Payload::SetPingAuthRequest {
       self.payload().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { DiagnosticsResponse::init_from_table(t) }
+       unsafe { SetPingAuthRequest::init_from_table(t) }
      })
     } else {
       None
@@ -4944,6 +15728,57 @@ impl flatbuffers::Verifiable for Message<'_> {
           Payload::GetDiagnosticsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetDiagnosticsRequest>>("Payload::GetDiagnosticsRequest", pos),
           Payload::ClearDiagnosticsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ClearDiagnosticsRequest>>("Payload::ClearDiagnosticsRequest", pos),
           Payload::DiagnosticsResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DiagnosticsResponse>>("Payload::DiagnosticsResponse", pos),
+          Payload::GetScheduleListRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetScheduleListRequest>>("Payload::GetScheduleListRequest", pos),
+          Payload::GetScheduleListResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetScheduleListResponse>>("Payload::GetScheduleListResponse", pos),
+          Payload::ScanWifiRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScanWifiRequest>>("Payload::ScanWifiRequest", pos),
+          Payload::ScanWifiResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScanWifiResponse>>("Payload::ScanWifiResponse", pos),
+          Payload::SetLedBrightnessRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetLedBrightnessRequest>>("Payload::SetLedBrightnessRequest", pos),
+          Payload::SetQuietHoursRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetQuietHoursRequest>>("Payload::SetQuietHoursRequest", pos),
+          Payload::ClearQuietHoursRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ClearQuietHoursRequest>>("Payload::ClearQuietHoursRequest", pos),
+          Payload::RestartDeviceRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RestartDeviceRequest>>("Payload::RestartDeviceRequest", pos),
+          Payload::GetConfigRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetConfigRequest>>("Payload::GetConfigRequest", pos),
+          Payload::GetConfigResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetConfigResponse>>("Payload::GetConfigResponse", pos),
+          Payload::DrainWaterRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DrainWaterRequest>>("Payload::DrainWaterRequest", pos),
+          Payload::SetPowerProfileRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetPowerProfileRequest>>("Payload::SetPowerProfileRequest", pos),
+          Payload::SetLedPatternRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetLedPatternRequest>>("Payload::SetLedPatternRequest", pos),
+          Payload::ResetLedPatternsRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResetLedPatternsRequest>>("Payload::ResetLedPatternsRequest", pos),
+          Payload::PingRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PingRequest>>("Payload::PingRequest", pos),
+          Payload::PongResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PongResponse>>("Payload::PongResponse", pos),
+          Payload::GetAuditLogRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetAuditLogRequest>>("Payload::GetAuditLogRequest", pos),
+          Payload::GetAuditLogResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetAuditLogResponse>>("Payload::GetAuditLogResponse", pos),
+          Payload::FactoryResetRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FactoryResetRequest>>("Payload::FactoryResetRequest", pos),
+          Payload::GetTelemetryHistoryRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetTelemetryHistoryRequest>>("Payload::GetTelemetryHistoryRequest", pos),
+          Payload::GetTelemetryHistoryResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetTelemetryHistoryResponse>>("Payload::GetTelemetryHistoryResponse", pos),
+          Payload::GetCapabilitiesRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetCapabilitiesRequest>>("Payload::GetCapabilitiesRequest", pos),
+          Payload::GetCapabilitiesResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetCapabilitiesResponse>>("Payload::GetCapabilitiesResponse", pos),
+          Payload::SetTimeRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetTimeRequest>>("Payload::SetTimeRequest", pos),
+          Payload::SetMaintenanceModeRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetMaintenanceModeRequest>>("Payload::SetMaintenanceModeRequest", pos),
+          Payload::ConfigChangedEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ConfigChangedEvent>>("Payload::ConfigChangedEvent", pos),
+          Payload::ResetCountersRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResetCountersRequest>>("Payload::ResetCountersRequest", pos),
+          Payload::InjectSensorRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<InjectSensorRequest>>("Payload::InjectSensorRequest", pos),
+          Payload::AutoTuneRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AutoTuneRequest>>("Payload::AutoTuneRequest", pos),
+          Payload::AutoTuneResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AutoTuneResponse>>("Payload::AutoTuneResponse", pos),
+          Payload::OtaStatusRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OtaStatusRequest>>("Payload::OtaStatusRequest", pos),
+          Payload::OtaStatusResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OtaStatusResponse>>("Payload::OtaStatusResponse", pos),
+          Payload::Nh3AlarmEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Nh3AlarmEvent>>("Payload::Nh3AlarmEvent", pos),
+          Payload::SetScheduleEnabledRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetScheduleEnabledRequest>>("Payload::SetScheduleEnabledRequest", pos),
+          Payload::SetInstallIdRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetInstallIdRequest>>("Payload::SetInstallIdRequest", pos),
+          Payload::TestFaultRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TestFaultRequest>>("Payload::TestFaultRequest", pos),
+          Payload::AddClientCertPinRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AddClientCertPinRequest>>("Payload::AddClientCertPinRequest", pos),
+          Payload::RemoveClientCertPinRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RemoveClientCertPinRequest>>("Payload::RemoveClientCertPinRequest", pos),
+          Payload::ResetServiceFlowRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResetServiceFlowRequest>>("Payload::ResetServiceFlowRequest", pos),
+          Payload::SessionMigrationTokenRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SessionMigrationTokenRequest>>("Payload::SessionMigrationTokenRequest", pos),
+          Payload::SessionMigrationTokenResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SessionMigrationTokenResponse>>("Payload::SessionMigrationTokenResponse", pos),
+          Payload::MigrateSessionRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MigrateSessionRequest>>("Payload::MigrateSessionRequest", pos),
+          Payload::SetStateTimeoutRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetStateTimeoutRequest>>("Payload::SetStateTimeoutRequest", pos),
+          Payload::OtaAbortRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OtaAbortRequest>>("Payload::OtaAbortRequest", pos),
+          Payload::SetTankSourceRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetTankSourceRequest>>("Payload::SetTankSourceRequest", pos),
+          Payload::SetPskGraceRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetPskGraceRequest>>("Payload::SetPskGraceRequest", pos),
+          Payload::SetJitterCompensationRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetJitterCompensationRequest>>("Payload::SetJitterCompensationRequest", pos),
+          Payload::SetAuthLockoutRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetAuthLockoutRequest>>("Payload::SetAuthLockoutRequest", pos),
+          Payload::SetWifiRoamRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetWifiRoamRequest>>("Payload::SetWifiRoamRequest", pos),
+          Payload::SetTcpIdleTimeoutRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetTcpIdleTimeoutRequest>>("Payload::SetTcpIdleTimeoutRequest", pos),
+          Payload::SetPingAuthRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetPingAuthRequest>>("Payload::SetPingAuthRequest", pos),
           _ => Ok(()),
         }
      })?
@@ -5222,6 +16057,363 @@ impl core::fmt::Debug for Message<'_> {
             ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        Payload::GetScheduleListRequest => {
+          if let Some(x) = self.payload_as_get_schedule_list_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetScheduleListResponse => {
+          if let Some(x) = self.payload_as_get_schedule_list_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ScanWifiRequest => {
+          if let Some(x) = self.payload_as_scan_wifi_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ScanWifiResponse => {
+          if let Some(x) = self.payload_as_scan_wifi_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetLedBrightnessRequest => {
+          if let Some(x) = self.payload_as_set_led_brightness_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetQuietHoursRequest => {
+          if let Some(x) = self.payload_as_set_quiet_hours_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ClearQuietHoursRequest => {
+          if let Some(x) = self.payload_as_clear_quiet_hours_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::RestartDeviceRequest => {
+          if let Some(x) = self.payload_as_restart_device_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetConfigRequest => {
+          if let Some(x) = self.payload_as_get_config_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetConfigResponse => {
+          if let Some(x) = self.payload_as_get_config_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::DrainWaterRequest => {
+          if let Some(x) = self.payload_as_drain_water_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetPowerProfileRequest => {
+          if let Some(x) = self.payload_as_set_power_profile_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetLedPatternRequest => {
+          if let Some(x) = self.payload_as_set_led_pattern_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ResetLedPatternsRequest => {
+          if let Some(x) = self.payload_as_reset_led_patterns_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::PingRequest => {
+          if let Some(x) = self.payload_as_ping_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::PongResponse => {
+          if let Some(x) = self.payload_as_pong_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetAuditLogRequest => {
+          if let Some(x) = self.payload_as_get_audit_log_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetAuditLogResponse => {
+          if let Some(x) = self.payload_as_get_audit_log_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::FactoryResetRequest => {
+          if let Some(x) = self.payload_as_factory_reset_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetTelemetryHistoryRequest => {
+          if let Some(x) = self.payload_as_get_telemetry_history_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetTelemetryHistoryResponse => {
+          if let Some(x) = self.payload_as_get_telemetry_history_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetCapabilitiesRequest => {
+          if let Some(x) = self.payload_as_get_capabilities_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::GetCapabilitiesResponse => {
+          if let Some(x) = self.payload_as_get_capabilities_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetTimeRequest => {
+          if let Some(x) = self.payload_as_set_time_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetMaintenanceModeRequest => {
+          if let Some(x) = self.payload_as_set_maintenance_mode_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ConfigChangedEvent => {
+          if let Some(x) = self.payload_as_config_changed_event() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ResetCountersRequest => {
+          if let Some(x) = self.payload_as_reset_counters_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::InjectSensorRequest => {
+          if let Some(x) = self.payload_as_inject_sensor_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::AutoTuneRequest => {
+          if let Some(x) = self.payload_as_auto_tune_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::AutoTuneResponse => {
+          if let Some(x) = self.payload_as_auto_tune_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::OtaStatusRequest => {
+          if let Some(x) = self.payload_as_ota_status_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::OtaStatusResponse => {
+          if let Some(x) = self.payload_as_ota_status_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::Nh3AlarmEvent => {
+          if let Some(x) = self.payload_as_nh3_alarm_event() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetScheduleEnabledRequest => {
+          if let Some(x) = self.payload_as_set_schedule_enabled_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetInstallIdRequest => {
+          if let Some(x) = self.payload_as_set_install_id_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::TestFaultRequest => {
+          if let Some(x) = self.payload_as_test_fault_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::AddClientCertPinRequest => {
+          if let Some(x) = self.payload_as_add_client_cert_pin_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::RemoveClientCertPinRequest => {
+          if let Some(x) = self.payload_as_remove_client_cert_pin_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::ResetServiceFlowRequest => {
+          if let Some(x) = self.payload_as_reset_service_flow_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SessionMigrationTokenRequest => {
+          if let Some(x) = self.payload_as_session_migration_token_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SessionMigrationTokenResponse => {
+          if let Some(x) = self.payload_as_session_migration_token_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::MigrateSessionRequest => {
+          if let Some(x) = self.payload_as_migrate_session_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetStateTimeoutRequest => {
+          if let Some(x) = self.payload_as_set_state_timeout_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::OtaAbortRequest => {
+          if let Some(x) = self.payload_as_ota_abort_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetTankSourceRequest => {
+          if let Some(x) = self.payload_as_set_tank_source_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetPskGraceRequest => {
+          if let Some(x) = self.payload_as_set_psk_grace_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetJitterCompensationRequest => {
+          if let Some(x) = self.payload_as_set_jitter_compensation_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetAuthLockoutRequest => {
+          if let Some(x) = self.payload_as_set_auth_lockout_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetWifiRoamRequest => {
+          if let Some(x) = self.payload_as_set_wifi_roam_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetTcpIdleTimeoutRequest => {
+          if let Some(x) = self.payload_as_set_tcp_idle_timeout_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Payload::SetPingAuthRequest => {
+          if let Some(x) = self.payload_as_set_ping_auth_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("payload", &x)
@@ -2,12 +2,27 @@
 //!
 //! Runs in a dedicated thread using `edge-executor` for cooperative
 //! multi-task scheduling and `async-io-mini` for reactor-driven
-//! timers (no busy-spinning). Three concurrent futures:
+//! timers (no busy-spinning). Up to four concurrent futures:
 //!
-//! 1. **Accept** — polls `try_accept()` every 50ms via reactor timer
+//! 1. **Accept** — polls `try_accept()` every 50ms via reactor timer,
+//!    and reaps TCP clients idle beyond [`set_tcp_idle_timeout`]
 //! 2. **Read** — polls `read_client()` every 1ms via reactor timer
-//! 3. **Write** — truly async via `RESP_CHANNEL.receive().await`
-//!    (wakes instantly when control loop pushes a response)
+//! 3. **Write** — truly async via [`receive_response`]
+//!    (wakes instantly when control loop pushes a response; replies are
+//!    written before queued telemetry).  A TCP write the socket only
+//!    partly accepts is kept per client and retried every 1ms; that
+//!    client's next frames queue behind it while other clients are
+//!    served, and a client whose socket takes nothing for
+//!    [`WRITE_STALL_TIMEOUT`] is disconnected
+//! 4. **UART** — optional bench serial slot, accept + read every 1ms
+//!
+//! Slot layout: 0 = BLE, `TCP_SLOT_START..UART_SLOT` = TCP,
+//! `UART_SLOT` = bench UART.
+//!
+//! BLE advertises before the RPC engine exists, so BLE frames are held
+//! back until the control loop calls [`mark_rpc_ready`]; a few are
+//! buffered and replayed in order, the rest counted in
+//! [`ble_early_drops`].
 //!
 //! ```text
 //!  ┌────────────────────────────────────────────────────────────┐
@@ -28,10 +43,16 @@
 
 use super::auth::{ClientId, MAX_CLIENTS};
 use super::channels::{
-    CMD_CHANNEL, CommandMsg, DISCONNECT_CHANNEL, DisconnectMsg, RESP_CHANNEL, ResponseMsg,
+    CMD_CHANNEL, CommandMsg, DISCONNECT_CHANNEL, DROPPED_CHANNEL, DisconnectMsg, DroppedMsg,
+    ResponseMsg, ResponsePriority, receive_response, try_send_command, try_send_response,
 };
 use super::codec::FrameDecoder;
+use super::fb;
+use super::transport::Transport as _;
 
+use crate::adapters::cert_store::CertBundle;
+use crate::adapters::tls_transport::DEFAULT_IDLE_TIMEOUT;
+use crate::adapters::uart_transport::UartTransport;
 use crate::events::{push_event, Event};
 use core::cell::RefCell;
 use core::time::Duration;
@@ -39,7 +60,9 @@ use heapless::Vec;
 use log::{info, warn};
 use std::collections::VecDeque;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicU32, Ordering};
 use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
 
 const READ_BUF_SIZE: usize = 1024;
 
@@ -49,9 +72,40 @@ pub const BLE_SLOT: ClientId = 0;
 /// TCP clients start from slot 1.
 pub const TCP_SLOT_START: usize = 1;
 
-const BLE_DEFAULT_MTU: usize = 128;
+/// The bench UART is always assigned the last slot.
+pub const UART_SLOT: ClientId = (MAX_CLIENTS - 1) as ClientId;
+
+/// BLE outbox depth for [`ResponsePriority::Low`] frames.
 const BLE_OUTBOX_CAP: usize = 16;
 
+/// BLE outbox depth for [`ResponsePriority::High`] frames, kept apart so
+/// a telemetry backlog cannot crowd out replies.
+const BLE_OUTBOX_PRIORITY_CAP: usize = 8;
+
+/// BLE frames held while the RPC engine is still starting.
+const BLE_PENDING_CAP: usize = 4;
+
+/// Frames a TCP client may have queued behind its partly written one.
+const WRITE_QUEUE_CAP: usize = 4;
+
+/// How long a TCP client's socket may accept no bytes of a pending
+/// frame before the client is disconnected.
+const WRITE_STALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Set by [`mark_rpc_ready`] once the engine can take commands.
+static RPC_READY: AtomicBool = AtomicBool::new(false);
+
+/// BLE frames dropped because they arrived before the engine was ready
+/// and the pending buffer was full.
+static BLE_EARLY_DROPS: AtomicU32 = AtomicU32::new(0);
+
+/// TCP clients connected, as last published by the accept task.
+static TCP_CONNECTED: AtomicU8 = AtomicU8::new(0);
+
+/// Idle timeout the accept task applies to TCP clients (seconds,
+/// 0 = never).
+static TCP_IDLE_TIMEOUT_SECS: AtomicU16 = AtomicU16::new(DEFAULT_IDLE_TIMEOUT.as_secs() as u16);
+
 fn ble_transport() -> &'static Mutex<crate::adapters::ble_transport::BleTransport> {
     static BLE_TRANSPORT: OnceLock<Mutex<crate::adapters::ble_transport::BleTransport>> =
         OnceLock::new();
@@ -63,38 +117,101 @@ fn ble_slot() -> &'static Mutex<IoSlot> {
     BLE_SLOT_STATE.get_or_init(|| Mutex::new(IoSlot::new()))
 }
 
-fn ble_outbox() -> &'static Mutex<VecDeque<Vec<u8, 512>>> {
-    static BLE_OUTBOX: OnceLock<Mutex<VecDeque<Vec<u8, 512>>>> = OnceLock::new();
-    BLE_OUTBOX.get_or_init(|| Mutex::new(VecDeque::new()))
+/// BLE notifications awaiting the GATT server, one queue per
+/// [`ResponsePriority`]; `high` is always drained first.
+#[derive(Default)]
+struct BleOutbox {
+    high: VecDeque<Vec<u8, 512>>,
+    low: VecDeque<Vec<u8, 512>>,
+}
+
+fn ble_outbox() -> &'static Mutex<BleOutbox> {
+    static BLE_OUTBOX: OnceLock<Mutex<BleOutbox>> = OnceLock::new();
+    BLE_OUTBOX.get_or_init(|| Mutex::new(BleOutbox::default()))
 }
 
-fn queue_ble_response(data: Vec<u8, 512>) {
+fn queue_ble_response(data: Vec<u8, 512>, priority: ResponsePriority) -> bool {
     let Ok(mut q) = ble_outbox().lock() else {
         warn!("IO[BLE]: outbox lock poisoned");
-        return;
+        return false;
     };
-    if q.len() >= BLE_OUTBOX_CAP {
-        warn!("IO[BLE]: outbox full, dropping response");
-        return;
+    let (queue, cap) = match priority {
+        ResponsePriority::High => (&mut q.high, BLE_OUTBOX_PRIORITY_CAP),
+        ResponsePriority::Low => (&mut q.low, BLE_OUTBOX_CAP),
+    };
+    if queue.len() >= cap {
+        warn!("IO[BLE]: {:?} outbox full, dropping response", priority);
+        return false;
     }
-    q.push_back(data);
+    queue.push_back(data);
+    true
 }
 
 pub fn try_recv_ble_response() -> Option<Vec<u8, 512>> {
-    ble_outbox().lock().ok()?.pop_front()
+    let mut q = ble_outbox().lock().ok()?;
+    q.high.pop_front().or_else(|| q.low.pop_front())
 }
 
+/// Attach the BLE slot with the ATT MTU negotiated so far (clamped to
+/// 23..=517 by the transport).
 pub fn ble_set_connected(mtu: usize) {
     let Ok(mut bt) = ble_transport().lock() else {
         warn!("IO[BLE]: transport lock poisoned");
         return;
     };
-    bt.connect(BLE_SLOT, mtu.max(BLE_DEFAULT_MTU));
+    bt.connect(BLE_SLOT, mtu);
     if let Ok(mut slot) = ble_slot().lock() {
         slot.reset();
     }
 }
 
+/// Apply an MTU exchange that completed after the central connected.
+pub fn ble_set_mtu(mtu: usize) {
+    if let Ok(mut bt) = ble_transport().lock() {
+        bt.set_mtu(mtu);
+    }
+}
+
+/// Split a response frame into notifications that fit the BLE slot's
+/// negotiated ATT MTU and hand each one to `notify`.
+pub fn ble_send_fragmented<F>(
+    data: &[u8],
+    notify: F,
+) -> Result<(), crate::adapters::ble_transport::BleTransportError>
+where
+    F: FnMut(&[u8]) -> Result<(), crate::adapters::ble_transport::BleTransportError>,
+{
+    let Ok(mut bt) = ble_transport().lock() else {
+        warn!("IO[BLE]: transport lock poisoned");
+        return Err(crate::adapters::ble_transport::BleTransportError::Io);
+    };
+    bt.send_fragmented(data, notify)
+}
+
+/// Clients attached right now across TCP and BLE, for status and
+/// telemetry.  The TCP share lags a disconnect by up to one accept poll.
+pub fn connected_clients() -> u8 {
+    let ble = ble_transport().lock().is_ok_and(|bt| bt.is_connected());
+    TCP_CONNECTED.load(Ordering::Relaxed) + u8::from(ble)
+}
+
+/// Publish the TCP share of [`connected_clients`].
+fn publish_tcp_clients(t: &crate::adapters::tls_transport::TlsTransport) {
+    TCP_CONNECTED.store(t.connected_count() as u8, Ordering::Relaxed);
+}
+
+/// Set how long a TCP client may send and receive nothing before the
+/// accept task disconnects it (seconds, 0 = never).  Applies from the
+/// next accept poll.
+pub fn set_tcp_idle_timeout(secs: u16) {
+    TCP_IDLE_TIMEOUT_SECS.store(secs, Ordering::Relaxed);
+}
+
+fn tcp_idle_timeout() -> Option<Duration> {
+    let secs = TCP_IDLE_TIMEOUT_SECS.load(Ordering::Relaxed);
+    (secs > 0).then(|| Duration::from_secs(u64::from(secs)))
+}
+
 pub fn ble_set_disconnected() {
     if let Ok(mut bt) = ble_transport().lock() {
         bt.disconnect();
@@ -102,23 +219,251 @@ pub fn ble_set_disconnected() {
     if let Ok(mut slot) = ble_slot().lock() {
         slot.reset();
     }
+    if let Ok(mut pending) = ble_pending().lock() {
+        pending.clear();
+    }
 }
 
-// ── Per-client decoder state ─────────────────────────────────
+// ── RPC readiness gate ───────────────────────────────────────
+
+fn ble_pending() -> &'static Mutex<VecDeque<Vec<u8, READ_BUF_SIZE>>> {
+    static BLE_PENDING: OnceLock<Mutex<VecDeque<Vec<u8, READ_BUF_SIZE>>>> = OnceLock::new();
+    BLE_PENDING.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+/// Open the gate: replay BLE frames held during start-up, then let new
+/// ones through.  Call once the `RpcEngine` is built and its NVS logs
+/// are restored.
+///
+/// The flag is raised only after the replay, under the pending lock, so
+/// a write racing this call can neither overtake a held frame nor be
+/// parked after the drain.
+pub fn mark_rpc_ready() {
+    let Ok(mut pending) = ble_pending().lock() else {
+        warn!("IO[BLE]: pending lock poisoned");
+        return;
+    };
+    if !pending.is_empty() {
+        info!("IO[BLE]: replaying {} early frame(s)", pending.len());
+    }
+    while let Some(data) = pending.pop_front() {
+        feed_ble_slot(&data);
+    }
+    RPC_READY.store(true, Ordering::Release);
+}
+
+/// Saturating count of BLE frames dropped before [`mark_rpc_ready`].
+pub fn ble_early_drops() -> u32 {
+    BLE_EARLY_DROPS.load(Ordering::Relaxed)
+}
+
+/// Hand reassembled BLE bytes to slot 0, or hold them if the engine is
+/// not ready yet.
+fn deliver_ble_bytes(data: &[u8]) {
+    if !RPC_READY.load(Ordering::Acquire) {
+        let Ok(mut pending) = ble_pending().lock() else {
+            warn!("IO[BLE]: pending lock poisoned");
+            return;
+        };
+        // Re-check under the lock: `mark_rpc_ready` may have drained
+        // the buffer since.
+        if !RPC_READY.load(Ordering::Acquire) {
+            match Vec::from_slice(data) {
+                Ok(held) if pending.len() < BLE_PENDING_CAP => pending.push_back(held),
+                _ => {
+                    warn!("IO[BLE]: RPC not ready, dropping early frame");
+                    let _ =
+                        BLE_EARLY_DROPS.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                            Some(n.saturating_add(1))
+                        });
+                }
+            }
+            return;
+        }
+    }
+    feed_ble_slot(data);
+}
+
+fn feed_ble_slot(data: &[u8]) {
+    let Ok(mut slot) = ble_slot().lock() else {
+        warn!("IO[BLE]: slot lock poisoned");
+        return;
+    };
+    feed_slot_bytes(&mut slot, BLE_SLOT, data);
+}
+
+// ── TLS certificate hand-off ─────────────────────────────────
+
+fn pending_cert_bundle() -> &'static Mutex<Option<Box<CertBundle>>> {
+    static PENDING_CERT_BUNDLE: OnceLock<Mutex<Option<Box<CertBundle>>>> = OnceLock::new();
+    PENDING_CERT_BUNDLE.get_or_init(|| Mutex::new(None))
+}
+
+/// Hand a provisioned certificate bundle to the I/O thread, which owns
+/// the TLS server.  The accept task loads it between accepts, so the
+/// mbedTLS parse never runs on the control loop.
+pub fn request_tls_reload(bundle: CertBundle) {
+    if let Ok(mut pending) = pending_cert_bundle().lock() {
+        *pending = Some(Box::new(bundle));
+    }
+}
+
+fn take_tls_reload() -> Option<Box<CertBundle>> {
+    pending_cert_bundle().lock().ok()?.take()
+}
+
+// ── Per-client decoder and write state ───────────────────────
 
 struct IoSlot {
     decoder: FrameDecoder,
+    /// Response frame the socket has only partly accepted.
+    pending_write: Option<PendingWrite>,
+    /// Frames waiting for `pending_write` to finish, oldest first.
+    queued: VecDeque<Vec<u8, 512>>,
+    /// When the socket last took bytes of `pending_write`.
+    last_progress: Option<Instant>,
 }
 
 impl IoSlot {
     fn new() -> Self {
         Self {
             decoder: FrameDecoder::new(),
+            pending_write: None,
+            queued: VecDeque::new(),
+            last_progress: None,
         }
     }
 
     fn reset(&mut self) {
         self.decoder.reset();
+        self.pending_write = None;
+        self.queued.clear();
+        self.last_progress = None;
+    }
+
+    /// Queue `data` behind any partly written frame.  Returns `false`
+    /// and drops `data` when the queue is already full.
+    fn enqueue(&mut self, data: Vec<u8, 512>) -> bool {
+        if self.pending_write.is_none() {
+            self.pending_write = Some(PendingWrite::new(data));
+            return true;
+        }
+        if self.queued.len() >= WRITE_QUEUE_CAP {
+            return false;
+        }
+        self.queued.push_back(data);
+        true
+    }
+
+    /// Write pending and queued frames in order until the socket would
+    /// block.  Returns whether everything has now been written.
+    fn drain<E>(
+        &mut self,
+        now: Instant,
+        mut write: impl FnMut(&[u8]) -> Result<usize, E>,
+    ) -> Result<bool, E> {
+        while let Some(pending) = self.pending_write.as_mut() {
+            let before = pending.written;
+            let done = pending.advance(&mut write)?;
+            if pending.written != before || self.last_progress.is_none() {
+                self.last_progress = Some(now);
+            }
+            if !done {
+                return Ok(false);
+            }
+            self.pending_write = self.queued.pop_front().map(PendingWrite::new);
+        }
+        self.last_progress = None;
+        Ok(true)
+    }
+
+    /// Whether the socket has taken nothing for [`WRITE_STALL_TIMEOUT`].
+    fn is_stalled(&self, now: Instant) -> bool {
+        self.last_progress
+            .is_some_and(|t| now.duration_since(t) >= WRITE_STALL_TIMEOUT)
+    }
+}
+
+/// A response frame and how much of it has been written so far.
+struct PendingWrite {
+    data: Vec<u8, 512>,
+    written: usize,
+}
+
+impl PendingWrite {
+    fn new(data: Vec<u8, 512>) -> Self {
+        Self { data, written: 0 }
+    }
+
+    /// Hand the unwritten remainder to `write` until it is all out or
+    /// `write` takes nothing (`Ok(0)`, a would-block).  Returns whether
+    /// the whole frame has now been written.
+    fn advance<E>(&mut self, mut write: impl FnMut(&[u8]) -> Result<usize, E>) -> Result<bool, E> {
+        while self.written < self.data.len() {
+            match write(&self.data[self.written..])? {
+                0 => return Ok(false),
+                n => self.written += n,
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Outcome of pushing a client's pending frames at its socket.
+#[derive(Debug, PartialEq)]
+enum WriteProgress {
+    /// Nothing left to write.
+    Done,
+    /// The socket would block; retry later.
+    Blocked,
+    /// The write failed or stalled and the client was disconnected.
+    Failed,
+}
+
+/// Write as much of `cid`'s pending frames as the socket accepts.
+fn advance_pending(
+    t: &mut crate::adapters::tls_transport::TlsTransport,
+    slot: &mut IoSlot,
+    cid: ClientId,
+    now: Instant,
+) -> WriteProgress {
+    if slot.pending_write.is_none() {
+        return WriteProgress::Done;
+    }
+    match slot.drain(now, |rest| t.write_client(cid, rest)) {
+        Ok(true) => {
+            let _ = t.flush_client(cid);
+            WriteProgress::Done
+        }
+        Ok(false) if slot.is_stalled(now) => {
+            warn!("IO: client {} stopped reading, disconnecting", cid);
+            t.disconnect(cid);
+            slot.reset();
+            notify_disconnect(cid);
+            WriteProgress::Failed
+        }
+        Ok(false) => WriteProgress::Blocked,
+        Err(e) => {
+            warn!("IO: write to client {} failed: {}", cid, e);
+            t.disconnect(cid);
+            slot.reset();
+            notify_disconnect(cid);
+            WriteProgress::Failed
+        }
+    }
+}
+
+fn has_pending_writes(slots: &SharedSlots) -> bool {
+    slots.borrow().iter().any(|s| s.pending_write.is_some())
+}
+
+/// Retry every TCP client's pending frames once.
+fn advance_all_pending(transport: &SharedTransport, slots: &SharedSlots) {
+    let mut t = transport.borrow_mut();
+    let mut s = slots.borrow_mut();
+    let now = Instant::now();
+    for cid_idx in TCP_SLOT_START..UART_SLOT as usize {
+        advance_pending(&mut t, &mut s[cid_idx], cid_idx as ClientId, now);
     }
 }
 
@@ -132,15 +477,33 @@ fn feed_slot_bytes(slot: &mut IoSlot, client_id: ClientId, data: &[u8]) {
             return;
         }
         let msg = CommandMsg { client_id, frame };
-        if CMD_CHANNEL.try_send(msg).is_err() {
-            warn!("IO[{}]: command channel full, dropping frame", client_id);
-        } else {
+        if try_send_command(msg) {
             // Wake main loop immediately to dispatch inbound RPC command.
             push_event(Event::CommandReceived);
+        } else {
+            warn!("IO[{}]: command channel full, dropping frame", client_id);
+            notify_dropped(client_id, frame_bytes);
         }
     }
 }
 
+/// Ask the control loop to NAK a dropped frame.  Frames that do not
+/// parse carry no id to reply to and are only counted.
+fn notify_dropped(client_id: ClientId, frame: &[u8]) {
+    let Ok(msg) = flatbuffers::root::<fb::Message>(frame) else {
+        return;
+    };
+    let dropped = DroppedMsg {
+        client_id,
+        msg_id: msg.id(),
+    };
+    if DROPPED_CHANNEL.try_send(dropped).is_err() {
+        warn!("IO[{}]: dropped-frame channel full, no NAK", client_id);
+    } else {
+        push_event(Event::CommandReceived);
+    }
+}
+
 fn notify_disconnect(client_id: ClientId) {
     let msg = DisconnectMsg { client_id };
     if DISCONNECT_CHANNEL.try_send(msg).is_err() {
@@ -151,18 +514,32 @@ fn notify_disconnect(client_id: ClientId) {
 // ── Async I/O loop ───────────────────────────────────────────
 
 type SharedTransport = Rc<RefCell<crate::adapters::tls_transport::TlsTransport>>;
+type SharedUart = Rc<RefCell<UartTransport>>;
 type SharedSlots = Rc<RefCell<[IoSlot; MAX_CLIENTS]>>;
 
 /// Accept task — checks for new TCP connections at 50ms intervals.
 /// Lower frequency is fine since connection setup is infrequent.
+/// Idle clients are reaped first so their slots are free to accept.
 async fn accept_loop(transport: SharedTransport, slots: SharedSlots) {
     loop {
         {
             let mut t = transport.borrow_mut();
+            if let Some(bundle) = take_tls_reload() {
+                if let Err(e) = t.configure_x509(&bundle) {
+                    warn!("IO: TLS certificate reload failed: {}", e);
+                }
+            }
+            t.set_idle_timeout(tcp_idle_timeout());
+            for cid in t.reap_idle(std::time::Instant::now()) {
+                info!("IO: client {} reaped (idle)", cid);
+                slots.borrow_mut()[cid as usize].reset();
+                notify_disconnect(cid);
+            }
             if let Some(cid) = t.try_accept() {
                 info!("IO: client {} connected", cid);
                 slots.borrow_mut()[cid as usize].reset();
             }
+            publish_tcp_clients(&t);
         }
         async_io_mini::Timer::after(Duration::from_millis(50)).await;
     }
@@ -177,7 +554,7 @@ async fn read_loop(transport: SharedTransport, slots: SharedSlots) {
         {
             let mut t = transport.borrow_mut();
             let mut s = slots.borrow_mut();
-            for cid_idx in TCP_SLOT_START..MAX_CLIENTS {
+            for cid_idx in TCP_SLOT_START..UART_SLOT as usize {
                 let cid = cid_idx as ClientId;
                 if !t.is_connected(cid) {
                     continue;
@@ -205,50 +582,132 @@ async fn read_loop(transport: SharedTransport, slots: SharedSlots) {
     }
 }
 
+/// UART task — attaches the bench serial client on its first byte and
+/// feeds received bytes into the UART slot's decoder every 1ms.
+async fn uart_loop(uart: SharedUart, slots: SharedSlots) {
+    let mut read_buf = [0u8; READ_BUF_SIZE];
+    let idx = UART_SLOT as usize;
+    loop {
+        {
+            let mut u = uart.borrow_mut();
+            if u.try_accept().is_some() {
+                slots.borrow_mut()[idx].reset();
+            }
+            if u.is_connected() {
+                match u.read(&mut read_buf) {
+                    Ok(0) => {}
+                    Ok(n) => {
+                        feed_slot_bytes(&mut slots.borrow_mut()[idx], UART_SLOT, &read_buf[..n]);
+                    }
+                    Err(e) => {
+                        warn!("IO: {}, detaching", e);
+                        u.disconnect();
+                        slots.borrow_mut()[idx].reset();
+                        notify_disconnect(UART_SLOT);
+                    }
+                }
+            }
+        }
+        async_io_mini::Timer::after(Duration::from_millis(1)).await;
+    }
+}
+
 /// Write task — truly async, wakes instantly when the control loop
-/// pushes a response via `RESP_CHANNEL.try_send()`. No polling.
-async fn write_loop(transport: SharedTransport, slots: SharedSlots) {
+/// pushes a response via [`send_response`].  High-priority replies are
+/// written before any queued low-priority frame.  Only while a TCP
+/// client has a partly written frame does it also wake every 1ms to
+/// push the remainder; frames for that client queue behind it without
+/// holding up the others.
+async fn write_loop(
+    transport: Option<SharedTransport>,
+    uart: Option<SharedUart>,
+    slots: SharedSlots,
+) {
     loop {
-        let resp = RESP_CHANNEL.receive().await;
+        let next = if has_pending_writes(&slots) {
+            futures_lite::future::or(async { Some(receive_response().await) }, async {
+                async_io_mini::Timer::after(Duration::from_millis(1)).await;
+                None
+            })
+            .await
+        } else {
+            Some(receive_response().await)
+        };
+        if let Some(transport) = &transport {
+            advance_all_pending(transport, &slots);
+        }
+        let Some(resp) = next else { continue };
         let cid = resp.client_id;
 
+        if cid == UART_SLOT {
+            let Some(uart) = &uart else { continue };
+            let mut u = uart.borrow_mut();
+            if !u.is_connected() {
+                continue;
+            }
+            if let Err(e) = u.write(&resp.data).and_then(|_| u.flush()) {
+                warn!("IO: {}, detaching", e);
+                u.disconnect();
+                slots.borrow_mut()[cid as usize].reset();
+                notify_disconnect(cid);
+            }
+            continue;
+        }
+
+        let Some(transport) = &transport else {
+            continue;
+        };
+
         let mut t = transport.borrow_mut();
         if !t.is_connected(cid) {
             continue;
         }
-
-        if let Err(e) = t.write_client(cid, &resp.data) {
-            warn!("IO: write to client {} failed: {}", cid, e);
-            t.disconnect(cid);
-            slots.borrow_mut()[cid as usize].reset();
-            notify_disconnect(cid);
-        } else {
-            let _ = t.flush_client(cid);
+        let mut s = slots.borrow_mut();
+        let slot = &mut s[cid as usize];
+        // The frame goes behind any partly written one, or the two would
+        // interleave on the wire.
+        if !slot.enqueue(resp.data) {
+            warn!("IO: client {} write queue full, dropping frame", cid);
+            continue;
         }
+        advance_pending(&mut t, slot, cid, Instant::now());
     }
 }
 
 /// Entry point for the I/O thread. Sets up the executor, spawns the
-/// three async tasks, and drives them via the `async-io-mini` reactor.
-fn run_io_loop(transport: crate::adapters::tls_transport::TlsTransport) {
+/// async tasks for whichever transports are present, and drives them
+/// via the `async-io-mini` reactor.
+fn run_io_loop(
+    transport: Option<crate::adapters::tls_transport::TlsTransport>,
+    uart: Option<UartTransport>,
+) {
     let executor: edge_executor::LocalExecutor<'_, 8> = edge_executor::LocalExecutor::new();
 
-    let transport: SharedTransport = Rc::new(RefCell::new(transport));
+    let transport: Option<SharedTransport> = transport.map(|t| Rc::new(RefCell::new(t)));
+    let uart: Option<SharedUart> = uart.map(|u| Rc::new(RefCell::new(u)));
     let slots: SharedSlots = Rc::new(RefCell::new(core::array::from_fn(|_| IoSlot::new())));
 
+    if let Some(transport) = &transport {
+        executor
+            .spawn(accept_loop(transport.clone(), slots.clone()))
+            .detach();
+        executor
+            .spawn(read_loop(transport.clone(), slots.clone()))
+            .detach();
+    }
+    if let Some(uart) = &uart {
+        executor
+            .spawn(uart_loop(uart.clone(), slots.clone()))
+            .detach();
+    }
     executor
-        .spawn(accept_loop(transport.clone(), slots.clone()))
-        .detach();
-    executor
-        .spawn(read_loop(transport.clone(), slots.clone()))
-        .detach();
-    executor
-        .spawn(write_loop(transport.clone(), slots.clone()))
+        .spawn(write_loop(transport.clone(), uart.clone(), slots.clone()))
         .detach();
 
     info!(
-        "IO task started (async, reactor-driven, {} max clients)",
-        MAX_CLIENTS
+        "IO task started (async, reactor-driven, {} max clients, uart={})",
+        MAX_CLIENTS,
+        uart.is_some()
     );
 
     // async_io_mini::block_on drives the reactor (timers, I/O events)
@@ -260,18 +719,20 @@ fn run_io_loop(transport: crate::adapters::tls_transport::TlsTransport) {
 
 /// Spawn the I/O task in a dedicated thread pinned to Core 0 (PRO_CPU).
 ///
-/// Takes ownership of the TLS transport. The thread runs an async
-/// executor with three concurrent tasks: accept, read, and write.
+/// Takes ownership of the TLS transport and the optional bench UART.
+/// The thread runs an async executor with accept, read, write and
+/// (if present) UART tasks.
 /// Core 0 co-locates with lwIP/BLE for cache-local network I/O.
 pub fn spawn(
-    transport: crate::adapters::tls_transport::TlsTransport,
+    transport: Option<crate::adapters::tls_transport::TlsTransport>,
+    uart: Option<UartTransport>,
 ) -> std::thread::JoinHandle<()> {
     crate::drivers::task_pin::spawn_on_core(
         crate::drivers::task_pin::Core::Pro,
         12,
         16,
         "rpc-io\0",
-        move || run_io_loop(transport),
+        move || run_io_loop(transport, uart),
     )
 }
 
@@ -291,7 +752,7 @@ pub fn feed_ble_bytes(data: &[u8]) {
     };
 
     if !bt.is_connected() {
-        bt.connect(BLE_SLOT, BLE_DEFAULT_MTU);
+        bt.connect(BLE_SLOT, crate::adapters::ble_transport::negotiated_mtu());
     }
 
     if let Err(e) = bt.on_gatt_write(data) {
@@ -312,11 +773,7 @@ pub fn feed_ble_bytes(data: &[u8]) {
         return;
     }
 
-    let Ok(mut slot) = ble_slot().lock() else {
-        warn!("IO[BLE]: slot lock poisoned");
-        return;
-    };
-    feed_slot_bytes(&mut slot, BLE_SLOT, &buf[..n]);
+    deliver_ble_bytes(&buf[..n]);
 }
 
 // ── Channel accessors for the control loop ───────────────────
@@ -324,17 +781,23 @@ pub fn feed_ble_bytes(data: &[u8]) {
 /// Send a response frame to the I/O task for transmission to a client.
 ///
 /// When the control loop calls this, the I/O task's write future
-/// wakes instantly via `RESP_CHANNEL.receive().await` — no polling delay.
-pub fn send_response(client_id: ClientId, data: Vec<u8, 512>) {
+/// wakes instantly via [`receive_response`] — no polling delay.
+/// `High` frames (replies to requests) are written ahead of any queued
+/// `Low` ones (telemetry, broadcast events).
+///
+/// Returns `false` if the frame was dropped because the outbox or
+/// response channel is full (i.e. the link is backing up).
+pub fn send_response(client_id: ClientId, data: Vec<u8, 512>, priority: ResponsePriority) -> bool {
     if client_id == BLE_SLOT {
-        queue_ble_response(data);
-        return;
+        return queue_ble_response(data, priority);
     }
 
     let msg = ResponseMsg { client_id, data };
-    if RESP_CHANNEL.try_send(msg).is_err() {
+    if !try_send_response(msg, priority) {
         warn!("RPC: response channel full for client {}", client_id);
+        return false;
     }
+    true
 }
 
 /// Try to receive an inbound command from the I/O task.
@@ -347,18 +810,36 @@ pub fn try_recv_disconnect() -> Option<DisconnectMsg> {
     DISCONNECT_CHANNEL.try_receive().ok()
 }
 
+/// Try to receive a frame dropped on a full command channel.
+pub fn try_recv_dropped() -> Option<DroppedMsg> {
+    DROPPED_CHANNEL.try_receive().ok()
+}
+
 // ── Tests ────────────────────────────────────────────────────
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The BLE outbox is a process-wide static.
+    static BLE_OUTBOX_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    /// So is the command channel.
+    static CMD_CHANNEL_TEST_LOCK: Mutex<()> = Mutex::new(());
+
     #[test]
     fn ble_slot_constant() {
         assert_eq!(BLE_SLOT, 0);
         assert_eq!(TCP_SLOT_START, 1);
     }
 
+    #[test]
+    fn uart_slot_is_last_and_distinct() {
+        assert_eq!(UART_SLOT as usize, MAX_CLIENTS - 1);
+        assert_ne!(UART_SLOT, BLE_SLOT);
+        assert!(TCP_SLOT_START < UART_SLOT as usize, "no TCP slots left");
+    }
+
     #[test]
     fn feed_slot_bytes_no_panic_on_partial() {
         let mut slot = IoSlot::new();
@@ -367,32 +848,71 @@ mod tests {
 
     #[test]
     fn ble_response_uses_ble_outbox() {
+        let _guard = BLE_OUTBOX_TEST_LOCK.lock().unwrap();
         let mut data = Vec::<u8, 512>::new();
         data.extend_from_slice(&[0x01, 0x02, 0x03]).unwrap();
-        send_response(BLE_SLOT, data);
+        assert!(send_response(BLE_SLOT, data, ResponsePriority::High));
         let popped = try_recv_ble_response().expect("ble outbox empty");
         assert_eq!(&popped[..], &[0x01, 0x02, 0x03]);
     }
 
+    #[test]
+    fn ble_outbox_drains_high_priority_first() {
+        let frame = |b: u8| Vec::<u8, 512>::from_slice(&[b]).unwrap();
+        let _guard = BLE_OUTBOX_TEST_LOCK.lock().unwrap();
+        while try_recv_ble_response().is_some() {}
+
+        assert!(send_response(BLE_SLOT, frame(1), ResponsePriority::Low));
+        assert!(send_response(BLE_SLOT, frame(2), ResponsePriority::High));
+        assert!(send_response(BLE_SLOT, frame(3), ResponsePriority::Low));
+
+        let order: std::vec::Vec<u8> = core::iter::from_fn(try_recv_ble_response)
+            .map(|f| f[0])
+            .collect();
+        assert_eq!(order, [2, 1, 3]);
+    }
+
+    #[test]
+    fn full_low_priority_outbox_still_takes_replies() {
+        let frame = |b: u8| Vec::<u8, 512>::from_slice(&[b]).unwrap();
+        let _guard = BLE_OUTBOX_TEST_LOCK.lock().unwrap();
+        while try_recv_ble_response().is_some() {}
+
+        for _ in 0..BLE_OUTBOX_CAP {
+            assert!(send_response(BLE_SLOT, frame(1), ResponsePriority::Low));
+        }
+        assert!(!send_response(BLE_SLOT, frame(1), ResponsePriority::Low));
+        for _ in 0..BLE_OUTBOX_PRIORITY_CAP {
+            assert!(send_response(BLE_SLOT, frame(2), ResponsePriority::High));
+        }
+        assert!(!send_response(BLE_SLOT, frame(2), ResponsePriority::High));
+
+        assert_eq!(try_recv_ble_response().unwrap()[0], 2);
+        while try_recv_ble_response().is_some() {}
+    }
+
+    /// `payload` framed and wrapped as a single BLE fragment.
+    fn ble_fragment(payload: &[u8]) -> std::vec::Vec<u8> {
+        let mut frame = [0u8; 32];
+        let n = crate::rpc::codec::encode_frame(payload, &mut frame).expect("encode frame");
+
+        // BLE fragment header: [seq, flags], flags=FRAG_FIRST for single fragment.
+        let mut frag = std::vec![0, 0x02];
+        frag.extend_from_slice(&frame[..n]);
+        frag
+    }
+
     #[test]
     fn feed_ble_bytes_accepts_single_fragment_frame() {
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
         // Ensure no stale commands from prior tests.
         while try_recv_command().is_some() {}
+        mark_rpc_ready();
 
         ble_set_connected(128);
 
         let payload = [0xAAu8, 0xBB, 0xCC];
-        let mut frame = [0u8; 32];
-        let n = crate::rpc::codec::encode_frame(&payload, &mut frame).expect("encode frame");
-        let frame = &frame[..n];
-
-        // BLE fragment header: [seq, flags], flags=FRAG_FIRST for single fragment.
-        let mut frag = [0u8; 64];
-        frag[0] = 0;
-        frag[1] = 0x02;
-        frag[2..2 + frame.len()].copy_from_slice(frame);
-
-        feed_ble_bytes(&frag[..2 + frame.len()]);
+        feed_ble_bytes(&ble_fragment(&payload));
 
         let cmd = try_recv_command().expect("missing BLE command");
         assert_eq!(cmd.client_id, BLE_SLOT);
@@ -400,4 +920,302 @@ mod tests {
 
         ble_set_disconnected();
     }
+
+    #[test]
+    fn ble_frames_before_ready_are_held_then_replayed_in_order() {
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
+        while try_recv_command().is_some() {}
+        RPC_READY.store(false, Ordering::Release);
+
+        ble_set_connected(128);
+        feed_ble_bytes(&ble_fragment(&[0x01, 0x11]));
+        feed_ble_bytes(&ble_fragment(&[0x02, 0x22]));
+        assert!(try_recv_command().is_none(), "frame reached engine early");
+
+        mark_rpc_ready();
+        let first = try_recv_command().expect("first held frame");
+        let second = try_recv_command().expect("second held frame");
+        assert_eq!(first.client_id, BLE_SLOT);
+        assert_eq!(&first.frame[..], &[0x01, 0x11]);
+        assert_eq!(&second.frame[..], &[0x02, 0x22]);
+
+        // Once open, frames pass straight through.
+        feed_ble_bytes(&ble_fragment(&[0x03]));
+        assert_eq!(&try_recv_command().unwrap().frame[..], &[0x03]);
+
+        ble_set_disconnected();
+    }
+
+    #[test]
+    fn ble_frames_beyond_pending_cap_are_counted_and_dropped() {
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
+        while try_recv_command().is_some() {}
+        RPC_READY.store(false, Ordering::Release);
+
+        ble_set_connected(128);
+        let before = ble_early_drops();
+        for i in 0..=BLE_PENDING_CAP as u8 {
+            feed_ble_bytes(&ble_fragment(&[i]));
+        }
+        assert_eq!(ble_early_drops(), before + 1);
+
+        mark_rpc_ready();
+        let replayed: std::vec::Vec<u8> = core::iter::from_fn(try_recv_command)
+            .map(|c| c.frame[0])
+            .collect();
+        assert_eq!(replayed, [0, 1, 2, 3]);
+
+        ble_set_disconnected();
+    }
+
+    #[test]
+    fn ble_responses_are_fragmented_to_the_negotiated_mtu() {
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
+        let data: std::vec::Vec<u8> = (0..200u8).collect();
+        let fragments = || {
+            let mut sent = std::vec::Vec::new();
+            ble_send_fragmented(&data, |frag| {
+                sent.push(frag.len());
+                Ok(())
+            })
+            .unwrap();
+            sent
+        };
+
+        ble_set_connected(23);
+        let small = fragments();
+        assert!(small.iter().all(|&n| n <= 20), "{small:?}");
+        assert_eq!(small.len(), data.len().div_ceil(18));
+
+        ble_set_mtu(247);
+        assert_eq!(fragments(), [data.len() + 2]);
+
+        ble_set_disconnected();
+    }
+
+    #[test]
+    fn connected_clients_counts_tcp_and_ble() {
+        use crate::adapters::tls_transport::TlsTransport;
+
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
+        let mut t = TlsTransport::new(0, b"test-psk-key").unwrap();
+        let addr = t.local_addr();
+        let accept = |t: &mut TlsTransport| {
+            let c = std::net::TcpStream::connect(addr).unwrap();
+            std::thread::sleep(Duration::from_millis(50));
+            (t.try_accept().unwrap(), c)
+        };
+
+        publish_tcp_clients(&t);
+        assert_eq!(connected_clients(), 0);
+
+        let (id1, _c1) = accept(&mut t);
+        let (_id2, _c2) = accept(&mut t);
+        publish_tcp_clients(&t);
+        assert_eq!(connected_clients(), 2);
+
+        ble_set_connected(128);
+        assert_eq!(connected_clients(), 3);
+
+        t.disconnect(id1);
+        publish_tcp_clients(&t);
+        assert_eq!(connected_clients(), 2);
+
+        ble_set_disconnected();
+        assert_eq!(connected_clients(), 1);
+    }
+
+    fn get_status_frame(id: u32) -> std::vec::Vec<u8> {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let req = fb::GetStatusRequest::create(&mut fbb, &fb::GetStatusRequestArgs::default());
+        let msg = fb::Message::create(
+            &mut fbb,
+            &fb::MessageArgs {
+                id,
+                payload_type: fb::Payload::GetStatusRequest,
+                payload: Some(req.as_union_value()),
+            },
+        );
+        fbb.finish(msg, None);
+        let mut frame = [0u8; 128];
+        let n = crate::rpc::codec::encode_frame(fbb.finished_data(), &mut frame)
+            .expect("encode frame");
+        frame[..n].to_vec()
+    }
+
+    #[test]
+    fn full_command_channel_counts_drop_and_queues_nak() {
+        use super::super::channels::{CMD_DEPTH, command_drops};
+
+        let _guard = CMD_CHANNEL_TEST_LOCK.lock().unwrap();
+        while try_recv_command().is_some() {}
+        while try_recv_dropped().is_some() {}
+
+        let mut slot = IoSlot::new();
+        for id in 1..=CMD_DEPTH as u32 {
+            feed_slot_bytes(&mut slot, 1, &get_status_frame(id));
+        }
+        let before = command_drops();
+
+        let mut other = IoSlot::new();
+        feed_slot_bytes(&mut other, 2, &get_status_frame(99));
+        assert_eq!(command_drops(), before + 1);
+        let dropped = try_recv_dropped().expect("dropped frame not reported");
+        assert_eq!((dropped.client_id, dropped.msg_id), (2, 99));
+
+        // The queued frames are untouched.
+        let queued = core::iter::from_fn(try_recv_command).count();
+        assert_eq!(queued, CMD_DEPTH);
+    }
+    /// Client socket that takes at most `chunk` bytes per write and
+    /// would-block on every other call.
+    struct ThrottledSocket {
+        chunk: usize,
+        stalled: bool,
+        received: std::vec::Vec<u8>,
+    }
+
+    impl ThrottledSocket {
+        fn new(chunk: usize) -> Self {
+            Self {
+                chunk,
+                stalled: false,
+                received: std::vec::Vec::new(),
+            }
+        }
+
+        fn write(&mut self, data: &[u8]) -> usize {
+            self.stalled = !self.stalled;
+            if self.stalled {
+                return 0;
+            }
+            let n = data.len().min(self.chunk);
+            self.received.extend_from_slice(&data[..n]);
+            n
+        }
+    }
+
+    /// An encoded frame around a `len`-byte counting payload.
+    fn response_frame(len: usize) -> Vec<u8, 512> {
+        let payload: std::vec::Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let mut frame = [0u8; 512];
+        let n = crate::rpc::codec::encode_frame(&payload, &mut frame).expect("encode frame");
+        Vec::from_slice(&frame[..n]).unwrap()
+    }
+
+    #[test]
+    fn throttled_client_receives_whole_frame_across_retries() {
+        let frame = response_frame(400);
+        let mut socket = ThrottledSocket::new(64);
+        let mut pending = PendingWrite::new(frame.clone());
+
+        let mut write = |rest: &[u8]| Ok::<_, ()>(socket.write(rest));
+        let mut retries = 0;
+        while !pending.advance(&mut write).unwrap() {
+            retries += 1;
+            assert!(retries < 100, "frame never completed");
+        }
+        assert!(retries > 1, "write was never short");
+        assert_eq!(socket.received, &frame[..]);
+
+        let mut decoder = FrameDecoder::new();
+        let payload = decoder.feed(&socket.received).expect("frame incomplete");
+        assert_eq!(payload.len(), 400);
+        assert!(payload.iter().enumerate().all(|(i, b)| *b == i as u8));
+    }
+
+    #[test]
+    fn pending_write_keeps_its_place_after_an_error() {
+        let mut pending = PendingWrite::new(response_frame(100));
+        let mut calls = 0;
+        let result = pending.advance(|rest| {
+            calls += 1;
+            if calls == 1 {
+                Ok(rest.len().min(10))
+            } else {
+                Err("reset")
+            }
+        });
+        assert_eq!(result, Err("reset"));
+        assert_eq!(pending.written, 10);
+    }
+
+    #[test]
+    fn pending_frame_is_flushed_to_tcp_client_and_cleared() {
+        use crate::adapters::tls_transport::TlsTransport;
+        use std::io::Read as _;
+
+        let mut t = TlsTransport::new(0, b"test-psk-key").unwrap();
+        let mut client = std::net::TcpStream::connect(t.local_addr()).unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        let cid = t.try_accept().unwrap();
+
+        let frame = response_frame(300);
+        let mut slot = IoSlot::new();
+        slot.pending_write = Some(PendingWrite::new(frame.clone()));
+        assert_eq!(
+            advance_pending(&mut t, &mut slot, cid, std::time::Instant::now()),
+            WriteProgress::Done
+        );
+        assert!(slot.pending_write.is_none());
+
+        let mut got = std::vec![0u8; frame.len()];
+        client.read_exact(&mut got).unwrap();
+        assert_eq!(got, &frame[..]);
+    }
+
+    #[test]
+    fn frames_queue_behind_a_partial_write_in_order() {
+        let mut slot = IoSlot::new();
+        let frames: std::vec::Vec<_> = (1..=3).map(|n| response_frame(n * 50)).collect();
+        for f in &frames {
+            assert!(slot.enqueue(f.clone()));
+        }
+
+        let mut socket = ThrottledSocket::new(64);
+        let now = std::time::Instant::now();
+        let mut write = |rest: &[u8]| Ok::<_, ()>(socket.write(rest));
+        let mut retries = 0;
+        while !slot.drain(now, &mut write).unwrap() {
+            retries += 1;
+            assert!(retries < 100, "queue never drained");
+        }
+        let expected: std::vec::Vec<u8> = frames.iter().flatten().copied().collect();
+        assert_eq!(socket.received, expected);
+        assert!(slot.queued.is_empty());
+    }
+
+    #[test]
+    fn write_queue_is_bounded() {
+        let mut slot = IoSlot::new();
+        for _ in 0..=WRITE_QUEUE_CAP {
+            assert!(slot.enqueue(response_frame(10)));
+        }
+        assert!(!slot.enqueue(response_frame(10)));
+    }
+
+    #[test]
+    fn client_that_takes_nothing_stalls_after_the_deadline() {
+        let mut slot = IoSlot::new();
+        slot.enqueue(response_frame(100));
+        let start = std::time::Instant::now();
+        let blocked = |_: &[u8]| Ok::<usize, ()>(0);
+
+        assert_eq!(slot.drain(start, blocked), Ok(false));
+        let later = start + WRITE_STALL_TIMEOUT / 2;
+        assert_eq!(slot.drain(later, blocked), Ok(false));
+        assert!(!slot.is_stalled(later));
+
+        // Any progress restarts the deadline.
+        let mut calls = 0;
+        let one_byte = |_: &[u8]| {
+            calls += 1;
+            Ok::<usize, ()>(usize::from(calls == 1))
+        };
+        assert_eq!(slot.drain(later, one_byte), Ok(false));
+        let deadline = later + WRITE_STALL_TIMEOUT;
+        assert!(!slot.is_stalled(deadline.checked_sub(Duration::from_millis(1)).unwrap()));
+        assert_eq!(slot.drain(deadline, blocked), Ok(false));
+        assert!(slot.is_stalled(deadline));
+    }
 }
@@ -30,4 +30,6 @@ pub mod fb;
 pub mod io_task;
 pub mod ota;
 pub mod stream;
+pub mod telemetry_delta;
+pub mod telemetry_packed;
 pub mod transport;
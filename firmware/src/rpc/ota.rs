@@ -4,14 +4,43 @@
 //!
 //! Session must be Authenticated before OtaBegin is accepted.
 //!
+//! ## Resuming
+//!
+//! A dropped link leaves the session in place, so a reconnecting client
+//! asks for `bytes_written` (`OtaStatusRequest`) and carries on from
+//! there.  The begin parameters and progress are also checkpointed to
+//! NVS every [`RESUME_CHECKPOINT_BYTES`], so [`OtaManager::restore`] can
+//! pick the transfer back up after a reset.  On target, reopening the
+//! partition erases it, so a transfer restored after a reset restarts
+//! at offset 0 with the same size and digest.
+//!
+//! A begin announcing a different digest replaces the transfer in
+//! progress (the old image is abandoned); the same digest is refused
+//! with `AlreadyInProgress` so the client resumes instead.  A transfer
+//! that receives nothing for [`OTA_IDLE_TIMEOUT_MS`], or is cancelled
+//! with `OtaAbortRequest`, is abandoned and its resume record dropped.
+//!
 //! The `esp-ota` crate provides a safe Rust wrapper around the ESP-IDF
 //! OTA partition API, eliminating all unsafe FFI in this module.
 
 use core::fmt;
 use log::{info, warn};
 
+use crate::app::ports::StoragePort;
+
 const MAX_FIRMWARE_SIZE: u32 = 4 * 1024 * 1024; // 4 MB
 
+const RESUME_NAMESPACE: &str = "ota";
+const RESUME_KEY: &str = "resume";
+
+/// Progress is written to NVS each time the transfer crosses a multiple
+/// of this many bytes (32 writes for a 2 MB image).
+pub const RESUME_CHECKPOINT_BYTES: u32 = 64 * 1024;
+
+/// A transfer with no begin or chunk for this long is abandoned, so a
+/// client that walked away cannot hold the device in `Receiving`.
+pub const OTA_IDLE_TIMEOUT_MS: u64 = 5 * 60 * 1000;
+
 // ── Error type ────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -69,6 +98,50 @@ pub enum OtaState {
     Failed,
 }
 
+// ── Resume record ─────────────────────────────────────────────
+
+/// Begin parameters and progress of a transfer, as persisted in NVS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtaResume {
+    pub firmware_size: u32,
+    pub bytes_written: u32,
+    pub sha256: [u8; 32],
+    /// Anti-rollback version from the `OtaBeginRequest`.
+    pub version: u32,
+}
+
+impl OtaResume {
+    const ENCODED_LEN: usize = 44;
+
+    fn encode(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        out[0..4].copy_from_slice(&self.firmware_size.to_le_bytes());
+        out[4..8].copy_from_slice(&self.bytes_written.to_le_bytes());
+        out[8..40].copy_from_slice(&self.sha256);
+        out[40..44].copy_from_slice(&self.version.to_le_bytes());
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return None;
+        }
+        let word = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let mut sha256 = [0u8; 32];
+        sha256.copy_from_slice(&bytes[8..40]);
+        let rec = Self {
+            firmware_size: word(0),
+            bytes_written: word(4),
+            sha256,
+            version: word(40),
+        };
+        let valid = rec.firmware_size > 0
+            && rec.firmware_size <= MAX_FIRMWARE_SIZE
+            && rec.bytes_written <= rec.firmware_size;
+        valid.then_some(rec)
+    }
+}
+
 // ── Manager (ESP-IDF: uses esp-ota crate) ─────────────────────
 
 /// OTA firmware update manager.
@@ -82,6 +155,9 @@ pub struct OtaManager {
     state: OtaState,
     expected_sha256: [u8; 32],
     staging_buf: [u8; OTA_STAGING_BUF_SIZE],
+    /// `bytes_written` at the last NVS checkpoint (`None` before the
+    /// first one of this transfer).
+    checkpointed: Option<u32>,
     #[cfg(target_os = "espidf")]
     ota_update: Option<esp_ota::OtaUpdate>,
 }
@@ -92,6 +168,7 @@ impl OtaManager {
             state: OtaState::Idle,
             expected_sha256: [0u8; 32],
             staging_buf: [0u8; OTA_STAGING_BUF_SIZE],
+            checkpointed: None,
             #[cfg(target_os = "espidf")]
             ota_update: None,
         }
@@ -101,29 +178,58 @@ impl OtaManager {
         self.state
     }
 
+    /// Whether an image is mid-transfer (begun but not yet verified).
+    pub fn is_transfer_active(&self) -> bool {
+        matches!(
+            self.state,
+            OtaState::Receiving { .. } | OtaState::WritePending { .. } | OtaState::Verifying
+        )
+    }
+
+    /// `(expected_size, bytes_written)` of the transfer in progress.
+    pub fn progress(&self) -> Option<(u32, u32)> {
+        match self.state {
+            OtaState::Receiving {
+                expected_size,
+                bytes_written,
+            }
+            | OtaState::WritePending {
+                expected_size,
+                bytes_written,
+                ..
+            } => Some((expected_size, bytes_written)),
+            _ => None,
+        }
+    }
+
+    /// SHA-256 the image was announced with.
+    pub fn expected_sha256(&self) -> &[u8; 32] {
+        &self.expected_sha256
+    }
+
     /// Begin an OTA session. Validates size and opens the inactive partition.
+    /// A transfer still receiving a different image is abandoned first.
     pub fn begin(&mut self, firmware_size: u32, sha256: &[u8]) -> Result<(), OtaError> {
-        if self.state != OtaState::Idle {
-            return Err(OtaError::AlreadyInProgress);
-        }
         if firmware_size == 0 || firmware_size > MAX_FIRMWARE_SIZE {
             return Err(OtaError::InvalidSize);
         }
         if sha256.len() != 32 {
             return Err(OtaError::InvalidSha);
         }
-
-        self.expected_sha256.copy_from_slice(sha256);
-
-        #[cfg(target_os = "espidf")]
-        {
-            let update = esp_ota::OtaUpdate::begin().map_err(|e| {
-                warn!("esp-ota begin failed: {:?}", e);
-                OtaError::BeginFailed
-            })?;
-            self.ota_update = Some(update);
+        match self.state {
+            OtaState::Idle | OtaState::Failed => {}
+            OtaState::Receiving { .. } | OtaState::WritePending { .. }
+                if sha256 != self.expected_sha256 =>
+            {
+                info!("OTA: new image announced, replacing transfer in progress");
+                self.abort();
+            }
+            _ => return Err(OtaError::AlreadyInProgress),
         }
 
+        self.expected_sha256.copy_from_slice(sha256);
+        self.open_partition()?;
+        self.checkpointed = None;
         self.state = OtaState::Receiving {
             expected_size: firmware_size,
             bytes_written: 0,
@@ -132,6 +238,92 @@ impl OtaManager {
         Ok(())
     }
 
+    #[cfg(target_os = "espidf")]
+    fn open_partition(&mut self) -> Result<(), OtaError> {
+        let update = esp_ota::OtaUpdate::begin().map_err(|e| {
+            warn!("esp-ota begin failed: {:?}", e);
+            OtaError::BeginFailed
+        })?;
+        self.ota_update = Some(update);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    #[allow(clippy::unnecessary_wraps)] // mirrors the fallible espidf variant
+    fn open_partition(&mut self) -> Result<(), OtaError> {
+        Ok(())
+    }
+
+    /// Persist the transfer's begin parameters and progress once it has
+    /// crossed a [`RESUME_CHECKPOINT_BYTES`] boundary since the last
+    /// checkpoint (always, for the first).  Returns `true` if a record
+    /// was written.
+    pub fn checkpoint(&mut self, nvs: &mut dyn StoragePort, version: u32) -> bool {
+        let Some((firmware_size, bytes_written)) = self.progress() else {
+            return false;
+        };
+        let due = self.checkpointed.is_none_or(|at| {
+            bytes_written / RESUME_CHECKPOINT_BYTES > at / RESUME_CHECKPOINT_BYTES
+        });
+        if !due {
+            return false;
+        }
+        let rec = OtaResume {
+            firmware_size,
+            bytes_written,
+            sha256: self.expected_sha256,
+            version,
+        };
+        if let Err(e) = nvs.write(RESUME_NAMESPACE, RESUME_KEY, &rec.encode()) {
+            warn!("OTA: resume checkpoint failed: {:?}", e);
+            return false;
+        }
+        self.checkpointed = Some(bytes_written);
+        true
+    }
+
+    /// Drop the persisted resume record (transfer finished, failed or
+    /// abandoned).  No NVS access when this transfer never wrote one.
+    pub fn clear_checkpoint(&mut self, nvs: &mut dyn StoragePort) {
+        if self.checkpointed.take().is_some() {
+            let _ = nvs.delete(RESUME_NAMESPACE, RESUME_KEY);
+        }
+    }
+
+    /// Re-enter `Receiving` from the persisted resume record, if any.
+    /// The next chunk must start at the restored `bytes_written`.
+    /// Returns the record as restored.
+    pub fn restore(&mut self, nvs: &dyn StoragePort) -> Option<OtaResume> {
+        if self.state != OtaState::Idle {
+            return None;
+        }
+        let mut buf = [0u8; OtaResume::ENCODED_LEN + 1];
+        let len = nvs.read(RESUME_NAMESPACE, RESUME_KEY, &mut buf).ok()?;
+        let Some(mut rec) = OtaResume::decode(&buf[..len]) else {
+            warn!("OTA: ignoring malformed resume record");
+            return None;
+        };
+        if let Err(e) = self.open_partition() {
+            warn!("OTA: cannot reopen partition to resume: {}", e);
+            return None;
+        }
+        if cfg!(target_os = "espidf") {
+            // Reopening erased the partition: same image, from the top.
+            rec.bytes_written = 0;
+        }
+        self.expected_sha256 = rec.sha256;
+        self.checkpointed = Some(rec.bytes_written);
+        self.state = OtaState::Receiving {
+            expected_size: rec.firmware_size,
+            bytes_written: rec.bytes_written,
+        };
+        info!(
+            "OTA: resuming at {}/{} bytes",
+            rec.bytes_written, rec.firmware_size
+        );
+        Some(rec)
+    }
+
     /// Write a chunk at the given byte offset. Returns total bytes written.
     pub fn write_chunk(&mut self, offset: u32, data: &[u8]) -> Result<u32, OtaError> {
         let OtaState::Receiving {
@@ -332,6 +524,8 @@ pub fn check_rollback() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::ports::StorageError;
+    use std::collections::HashMap;
 
     fn sha() -> [u8; 32] {
         [0u8; 32]
@@ -344,6 +538,16 @@ mod tests {
         assert_eq!(ota.begin(1024, &sha()), Err(OtaError::AlreadyInProgress));
     }
 
+    #[test]
+    fn begin_with_new_digest_replaces_transfer() {
+        let mut ota = OtaManager::new();
+        ota.begin(1024, &sha()).unwrap();
+        ota.write_chunk(0, b"data").unwrap();
+        assert!(ota.begin(2048, &[0x22; 32]).is_ok());
+        assert_eq!(ota.progress(), Some((2048, 0)));
+        assert_eq!(ota.expected_sha256(), &[0x22; 32]);
+    }
+
     #[test]
     fn begin_rejects_zero_size() {
         let mut ota = OtaManager::new();
@@ -425,6 +629,117 @@ mod tests {
         assert!(ota.finalize().is_ok());
     }
 
+    #[derive(Default)]
+    struct MemStorage {
+        data: HashMap<String, Vec<u8>>,
+    }
+
+    impl StoragePort for MemStorage {
+        fn read(&self, ns: &str, key: &str, buf: &mut [u8]) -> Result<usize, StorageError> {
+            let v = self
+                .data
+                .get(&format!("{ns}::{key}"))
+                .ok_or(StorageError::NotFound)?;
+            let len = v.len().min(buf.len());
+            buf[..len].copy_from_slice(&v[..len]);
+            Ok(len)
+        }
+
+        fn write(&mut self, ns: &str, key: &str, data: &[u8]) -> Result<(), StorageError> {
+            self.data.insert(format!("{ns}::{key}"), data.to_vec());
+            Ok(())
+        }
+
+        fn delete(&mut self, ns: &str, key: &str) -> Result<(), StorageError> {
+            self.data.remove(&format!("{ns}::{key}"));
+            Ok(())
+        }
+
+        fn exists(&self, ns: &str, key: &str) -> bool {
+            self.data.contains_key(&format!("{ns}::{key}"))
+        }
+    }
+
+    /// Write `len` bytes in 4 KiB chunks, checkpointing after each.
+    fn send(ota: &mut OtaManager, nvs: &mut MemStorage, len: u32) {
+        let chunk = [0xA5u8; 4096];
+        let (_, mut at) = ota.progress().unwrap();
+        let end = at + len;
+        while at < end {
+            let n = (end - at).min(chunk.len() as u32);
+            at = ota.write_chunk(at, &chunk[..n as usize]).unwrap();
+            ota.checkpoint(nvs, 7);
+        }
+    }
+
+    #[test]
+    fn checkpoints_only_on_boundaries() {
+        let mut nvs = MemStorage::default();
+        let mut ota = OtaManager::new();
+        ota.begin(2 * 1024 * 1024, &[0x11; 32]).unwrap();
+        assert!(ota.checkpoint(&mut nvs, 7), "begin is checkpointed");
+        ota.write_chunk(0, &[0u8; 4096]).unwrap();
+        assert!(!ota.checkpoint(&mut nvs, 7));
+        send(&mut ota, &mut nvs, RESUME_CHECKPOINT_BYTES - 4096);
+        assert!(!ota.checkpoint(&mut nvs, 7), "boundary already recorded");
+    }
+
+    #[test]
+    fn resumes_at_last_checkpoint_after_interruption() {
+        let mut nvs = MemStorage::default();
+        let mut ota = OtaManager::new();
+        let size = 2 * 1024 * 1024;
+        ota.begin(size, &[0x11; 32]).unwrap();
+        ota.checkpoint(&mut nvs, 7);
+        send(&mut ota, &mut nvs, 3 * RESUME_CHECKPOINT_BYTES + 8192);
+
+        // Reset: a fresh manager restores from NVS.
+        let mut ota = OtaManager::new();
+        let rec = ota.restore(&nvs).expect("resume record");
+        assert_eq!(rec.version, 7);
+        assert_eq!(rec.sha256, [0x11; 32]);
+        let resume_at = 3 * RESUME_CHECKPOINT_BYTES;
+        assert_eq!(ota.progress(), Some((size, resume_at)));
+        assert_eq!(ota.expected_sha256(), &[0x11; 32]);
+
+        // Only the expected chunk is accepted.
+        assert_eq!(ota.write_chunk(0, b"data"), Err(OtaError::NonSequential));
+        assert_eq!(ota.write_chunk(resume_at, b"data"), Ok(resume_at + 4));
+    }
+
+    #[test]
+    fn finished_transfer_leaves_nothing_to_resume() {
+        let mut nvs = MemStorage::default();
+        let mut ota = OtaManager::new();
+        ota.begin(4, &sha()).unwrap();
+        ota.checkpoint(&mut nvs, 1);
+        ota.write_chunk(0, b"1234").unwrap();
+        ota.finalize().unwrap();
+        ota.clear_checkpoint(&mut nvs);
+        assert!(OtaManager::new().restore(&nvs).is_none());
+    }
+
+    #[test]
+    fn aborted_transfer_leaves_nothing_to_resume() {
+        let mut nvs = MemStorage::default();
+        let mut ota = OtaManager::new();
+        ota.begin(1024, &sha()).unwrap();
+        ota.checkpoint(&mut nvs, 1);
+        ota.abort();
+        ota.clear_checkpoint(&mut nvs);
+        assert!(!nvs.exists(RESUME_NAMESPACE, RESUME_KEY));
+        assert!(OtaManager::new().restore(&nvs).is_none());
+    }
+
+    #[test]
+    fn malformed_resume_record_is_ignored() {
+        let mut nvs = MemStorage::default();
+        nvs.write(RESUME_NAMESPACE, RESUME_KEY, &[1, 2, 3]).unwrap();
+        let mut ota = OtaManager::new();
+        assert!(ota.restore(&nvs).is_none());
+        assert_eq!(ota.state(), OtaState::Idle);
+    }
+
     #[test]
     fn error_display_coverage() {
         assert!(OtaError::BeginFailed.to_string().contains("begin failed"));
@@ -0,0 +1,391 @@
+//! Delta encoding for streamed telemetry.
+//!
+//! A client that subscribes with `delta` gets a full keyframe first and
+//! then only the fields that moved past their epsilon since the value
+//! it last received, flagged in `TelemetryFrame.present_fields`.
+//! Absent fields are left at their schema default so FlatBuffers omits
+//! them from the wire.  Every `keyframe_interval` frames a full frame
+//! is sent again, so a client that lost a delta resynchronises.
+//!
+//! Baselines move per field, only when that field is sent: a reading
+//! creeping up by less than its epsilon each frame is still reported
+//! once the total drift exceeds it.
+
+use super::fb;
+
+pub const FIELD_STATE: u32 = 1 << 0;
+pub const FIELD_NH3_PPM: u32 = 1 << 1;
+pub const FIELD_NH3_AVG_PPM: u32 = 1 << 2;
+pub const FIELD_FLOW_ML_PER_MIN: u32 = 1 << 3;
+pub const FIELD_TEMPERATURE_C: u32 = 1 << 4;
+pub const FIELD_PUMP_DUTY: u32 = 1 << 5;
+pub const FIELD_UVC_DUTY: u32 = 1 << 6;
+pub const FIELD_FAULT_FLAGS: u32 = 1 << 7;
+pub const FIELD_WIFI_RSSI: u32 = 1 << 8;
+pub const FIELD_EFFECTIVE_INTERVAL_MS: u32 = 1 << 9;
+pub const FIELD_PURGE_VOLUME_ML: u32 = 1 << 10;
+pub const FIELD_DRAIN_VOLUME_ML: u32 = 1 << 11;
+pub const FIELD_DRAIN_COMPLETE: u32 = 1 << 12;
+pub const FIELD_POWER_PROFILE: u32 = 1 << 13;
+pub const FIELD_ZONE: u32 = 1 << 14;
+pub const FIELD_CONNECTED_CLIENTS: u32 = 1 << 15;
+pub const FIELD_NH3_ALARM: u32 = 1 << 16;
+pub const FIELD_SOURCE_TANK: u32 = 1 << 17;
+pub const FIELD_FAULT_TEST: u32 = 1 << 18;
+pub const FIELD_NH3_INJECTED: u32 = 1 << 19;
+pub const FIELD_SENSOR_WARMING: u32 = 1 << 20;
+
+/// `present_fields` of a full frame; also the schema default, so frames
+/// from devices without delta support read as full.
+pub const ALL_FIELDS: u32 = u32::MAX;
+
+const NH3_EPSILON_PPM: f32 = 0.1;
+const FLOW_EPSILON_ML_PER_MIN: f32 = 1.0;
+const TEMPERATURE_EPSILON_C: f32 = 0.1;
+const VOLUME_EPSILON_ML: f32 = 1.0;
+
+/// The delta-able contents of one `TelemetryFrame`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetryValues {
+    pub state: fb::DeviceState,
+    pub nh3_ppm: f32,
+    pub nh3_avg_ppm: f32,
+    pub flow_ml_per_min: f32,
+    pub temperature_c: f32,
+    pub pump_duty: u8,
+    pub uvc_duty: u8,
+    pub fault_flags: u8,
+    pub wifi_rssi: i8,
+    pub effective_interval_ms: u32,
+    pub purge_volume_ml: f32,
+    pub drain_volume_ml: f32,
+    pub drain_complete: bool,
+    pub power_profile: fb::PowerProfile,
+    pub zone: u8,
+    pub connected_clients: u8,
+    pub nh3_alarm: bool,
+    /// 0 = Tank A, 1 = Tank B.
+    pub source_tank: u8,
+    /// `fault_flags` come from a test fault, not a real one.
+    pub fault_test: bool,
+    /// The NH3 reading is synthetic (an injection), not the sensor.
+    pub nh3_injected: bool,
+    /// NH3 sensor still in its post-boot soak; NH3 triggers are ignored.
+    pub sensor_warming: bool,
+}
+
+impl TelemetryValues {
+    /// Schema defaults: what a reader sees for an omitted field.
+    pub const ABSENT: Self = Self {
+        state: fb::DeviceState::Idle,
+        nh3_ppm: 0.0,
+        nh3_avg_ppm: 0.0,
+        flow_ml_per_min: 0.0,
+        temperature_c: 0.0,
+        pump_duty: 0,
+        uvc_duty: 0,
+        fault_flags: 0,
+        wifi_rssi: 127,
+        effective_interval_ms: 0,
+        purge_volume_ml: 0.0,
+        drain_volume_ml: 0.0,
+        drain_complete: false,
+        power_profile: fb::PowerProfile::Balanced,
+        zone: 0,
+        connected_clients: 0,
+        nh3_alarm: false,
+        source_tank: 0,
+        fault_test: false,
+        nh3_injected: false,
+        sensor_warming: false,
+    };
+
+    /// Fields of `self` that differ from `base` by more than their
+    /// epsilon (any change at all for discrete fields).
+    pub fn changed_since(&self, base: &Self) -> u32 {
+        let (s, b) = (self, base);
+        let moved = |a: f32, b: f32, eps: f32| (a - b).abs() > eps;
+        let mut mask = 0;
+        let mut flag = |bit: u32, changed: bool| {
+            if changed {
+                mask |= bit;
+            }
+        };
+        flag(FIELD_STATE, s.state != b.state);
+        flag(FIELD_NH3_PPM, moved(s.nh3_ppm, b.nh3_ppm, NH3_EPSILON_PPM));
+        flag(
+            FIELD_NH3_AVG_PPM,
+            moved(s.nh3_avg_ppm, b.nh3_avg_ppm, NH3_EPSILON_PPM),
+        );
+        flag(
+            FIELD_FLOW_ML_PER_MIN,
+            moved(
+                s.flow_ml_per_min,
+                b.flow_ml_per_min,
+                FLOW_EPSILON_ML_PER_MIN,
+            ),
+        );
+        flag(
+            FIELD_TEMPERATURE_C,
+            moved(s.temperature_c, b.temperature_c, TEMPERATURE_EPSILON_C),
+        );
+        flag(FIELD_PUMP_DUTY, s.pump_duty != b.pump_duty);
+        flag(FIELD_UVC_DUTY, s.uvc_duty != b.uvc_duty);
+        flag(FIELD_FAULT_FLAGS, s.fault_flags != b.fault_flags);
+        flag(FIELD_WIFI_RSSI, s.wifi_rssi != b.wifi_rssi);
+        flag(
+            FIELD_EFFECTIVE_INTERVAL_MS,
+            s.effective_interval_ms != b.effective_interval_ms,
+        );
+        flag(
+            FIELD_PURGE_VOLUME_ML,
+            moved(s.purge_volume_ml, b.purge_volume_ml, VOLUME_EPSILON_ML),
+        );
+        flag(
+            FIELD_DRAIN_VOLUME_ML,
+            moved(s.drain_volume_ml, b.drain_volume_ml, VOLUME_EPSILON_ML),
+        );
+        flag(FIELD_DRAIN_COMPLETE, s.drain_complete != b.drain_complete);
+        flag(FIELD_POWER_PROFILE, s.power_profile != b.power_profile);
+        flag(FIELD_ZONE, s.zone != b.zone);
+        flag(
+            FIELD_CONNECTED_CLIENTS,
+            s.connected_clients != b.connected_clients,
+        );
+        flag(FIELD_NH3_ALARM, s.nh3_alarm != b.nh3_alarm);
+        flag(FIELD_SOURCE_TANK, s.source_tank != b.source_tank);
+        flag(FIELD_FAULT_TEST, s.fault_test != b.fault_test);
+        flag(FIELD_NH3_INJECTED, s.nh3_injected != b.nh3_injected);
+        flag(FIELD_SENSOR_WARMING, s.sensor_warming != b.sensor_warming);
+        mask
+    }
+
+    /// Take the fields in `mask` from `from`, keeping the rest.
+    pub fn merge(&mut self, from: &Self, mask: u32) {
+        let has = |bit: u32| mask & bit != 0;
+        if has(FIELD_STATE) {
+            self.state = from.state;
+        }
+        if has(FIELD_NH3_PPM) {
+            self.nh3_ppm = from.nh3_ppm;
+        }
+        if has(FIELD_NH3_AVG_PPM) {
+            self.nh3_avg_ppm = from.nh3_avg_ppm;
+        }
+        if has(FIELD_FLOW_ML_PER_MIN) {
+            self.flow_ml_per_min = from.flow_ml_per_min;
+        }
+        if has(FIELD_TEMPERATURE_C) {
+            self.temperature_c = from.temperature_c;
+        }
+        if has(FIELD_PUMP_DUTY) {
+            self.pump_duty = from.pump_duty;
+        }
+        if has(FIELD_UVC_DUTY) {
+            self.uvc_duty = from.uvc_duty;
+        }
+        if has(FIELD_FAULT_FLAGS) {
+            self.fault_flags = from.fault_flags;
+        }
+        if has(FIELD_WIFI_RSSI) {
+            self.wifi_rssi = from.wifi_rssi;
+        }
+        if has(FIELD_EFFECTIVE_INTERVAL_MS) {
+            self.effective_interval_ms = from.effective_interval_ms;
+        }
+        if has(FIELD_PURGE_VOLUME_ML) {
+            self.purge_volume_ml = from.purge_volume_ml;
+        }
+        if has(FIELD_DRAIN_VOLUME_ML) {
+            self.drain_volume_ml = from.drain_volume_ml;
+        }
+        if has(FIELD_DRAIN_COMPLETE) {
+            self.drain_complete = from.drain_complete;
+        }
+        if has(FIELD_POWER_PROFILE) {
+            self.power_profile = from.power_profile;
+        }
+        if has(FIELD_ZONE) {
+            self.zone = from.zone;
+        }
+        if has(FIELD_CONNECTED_CLIENTS) {
+            self.connected_clients = from.connected_clients;
+        }
+        if has(FIELD_NH3_ALARM) {
+            self.nh3_alarm = from.nh3_alarm;
+        }
+        if has(FIELD_SOURCE_TANK) {
+            self.source_tank = from.source_tank;
+        }
+        if has(FIELD_FAULT_TEST) {
+            self.fault_test = from.fault_test;
+        }
+        if has(FIELD_NH3_INJECTED) {
+            self.nh3_injected = from.nh3_injected;
+        }
+        if has(FIELD_SENSOR_WARMING) {
+            self.sensor_warming = from.sensor_warming;
+        }
+    }
+
+    /// `self` with every field outside `mask` reset to [`Self::ABSENT`].
+    #[must_use]
+    pub fn masked(&self, mask: u32) -> Self {
+        let mut out = Self::ABSENT;
+        out.merge(self, mask);
+        out
+    }
+}
+
+/// Per-client delta state: the values the client holds and how long
+/// since its last keyframe.
+#[derive(Debug, Clone, Copy)]
+pub struct TelemetryDelta {
+    keyframe_interval: u16,
+    since_keyframe: u16,
+    /// What the client has reconstructed; `None` until the first
+    /// keyframe, or after a lost frame.
+    baseline: Option<TelemetryValues>,
+}
+
+impl TelemetryDelta {
+    /// `keyframe_interval` frames per keyframe; 0 or 1 sends every frame
+    /// in full.
+    pub fn new(keyframe_interval: u16) -> Self {
+        Self {
+            keyframe_interval,
+            since_keyframe: 0,
+            baseline: None,
+        }
+    }
+
+    /// Fields to send for `next`, updating the baseline as if the frame
+    /// were delivered.
+    pub fn encode(&mut self, next: &TelemetryValues) -> u32 {
+        let due = self.since_keyframe >= self.keyframe_interval;
+        let mask = match &mut self.baseline {
+            Some(base) if !due => {
+                let mask = next.changed_since(base);
+                base.merge(next, mask);
+                mask
+            }
+            _ => {
+                self.baseline = Some(*next);
+                self.since_keyframe = 0;
+                ALL_FIELDS
+            }
+        };
+        self.since_keyframe = self.since_keyframe.saturating_add(1);
+        mask
+    }
+
+    /// The last frame did not reach the client; make the next one a
+    /// keyframe.
+    pub fn force_keyframe(&mut self) {
+        self.baseline = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> TelemetryValues {
+        TelemetryValues {
+            state: fb::DeviceState::Active,
+            nh3_ppm: 12.0,
+            nh3_avg_ppm: 11.5,
+            flow_ml_per_min: 800.0,
+            temperature_c: 24.0,
+            pump_duty: 60,
+            uvc_duty: 80,
+            fault_flags: 0,
+            wifi_rssi: -55,
+            effective_interval_ms: 1000,
+            purge_volume_ml: 0.0,
+            drain_volume_ml: 0.0,
+            drain_complete: false,
+            power_profile: fb::PowerProfile::Balanced,
+            zone: 0,
+            connected_clients: 1,
+            nh3_alarm: false,
+            source_tank: 0,
+            fault_test: false,
+            nh3_injected: false,
+            sensor_warming: false,
+        }
+    }
+
+    #[test]
+    fn first_frame_is_a_keyframe_and_unchanged_fields_are_then_omitted() {
+        let mut delta = TelemetryDelta::new(10);
+        let v = values();
+        assert_eq!(delta.encode(&v), ALL_FIELDS);
+        assert_eq!(delta.encode(&v), 0);
+    }
+
+    #[test]
+    fn changes_within_epsilon_are_omitted_and_beyond_it_included() {
+        let mut delta = TelemetryDelta::new(10);
+        let mut v = values();
+        delta.encode(&v);
+
+        v.nh3_ppm += 0.05;
+        v.temperature_c += 0.05;
+        assert_eq!(delta.encode(&v), 0);
+
+        v.nh3_ppm += 0.1;
+        v.pump_duty = 40;
+        assert_eq!(delta.encode(&v), FIELD_NH3_PPM | FIELD_PUMP_DUTY);
+
+        // Two sub-epsilon steps add up against the last value sent.
+        v.temperature_c += 0.06;
+        assert_eq!(delta.encode(&v), FIELD_TEMPERATURE_C);
+    }
+
+    #[test]
+    fn keyframes_follow_the_configured_cadence() {
+        let mut delta = TelemetryDelta::new(4);
+        let v = values();
+        let keyframes: std::vec::Vec<usize> =
+            (0..12).filter(|_| delta.encode(&v) == ALL_FIELDS).collect();
+        assert_eq!(keyframes, [0, 4, 8]);
+    }
+
+    #[test]
+    fn lost_frame_forces_a_keyframe() {
+        let mut delta = TelemetryDelta::new(10);
+        let v = values();
+        delta.encode(&v);
+        delta.encode(&v);
+        delta.force_keyframe();
+        assert_eq!(delta.encode(&v), ALL_FIELDS);
+        assert_eq!(delta.encode(&v), 0);
+    }
+
+    #[test]
+    fn client_reconstructs_the_frame_from_deltas() {
+        let mut delta = TelemetryDelta::new(10);
+        let mut v = values();
+        let mut client = TelemetryValues::ABSENT;
+        client.merge(&v.masked(ALL_FIELDS), delta.encode(&v));
+
+        v.state = fb::DeviceState::Purging;
+        v.uvc_duty = 0;
+        v.purge_volume_ml = 250.0;
+        v.nh3_alarm = true;
+        let mask = delta.encode(&v);
+        client.merge(&v.masked(mask), mask);
+        assert_eq!(client, v);
+    }
+
+    #[test]
+    fn masked_resets_omitted_fields_to_schema_defaults() {
+        let m = values().masked(FIELD_NH3_PPM);
+        assert!((m.nh3_ppm - 12.0).abs() < f32::EPSILON);
+        assert_eq!(
+            TelemetryValues { nh3_ppm: 0.0, ..m },
+            TelemetryValues::ABSENT
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Fixed-layout binary telemetry.
+//!
+//! For links where even a `TelemetryFrame` envelope is too heavy, a
+//! client can subscribe with `packed` and receive each sample as a flat
+//! little-endian record instead, in a frame carrying
+//! [`FLAG_PACKED`](super::codec::FLAG_PACKED).  Every other message stays
+//! FlatBuffers.  Packed records are always full: delta and compression
+//! do not apply.
+//!
+//! Layout (version 2, 46 bytes):
+//!
+//! | Offset | Size | Field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 1    | version (= 2)                           |
+//! | 1      | 1    | state (`DeviceState`)                   |
+//! | 2      | 4    | nh3_ppm (f32)                           |
+//! | 6      | 4    | nh3_avg_ppm (f32)                       |
+//! | 10     | 4    | flow_ml_per_min (f32)                   |
+//! | 14     | 4    | temperature_c (f32)                     |
+//! | 18     | 1    | pump_duty                               |
+//! | 19     | 1    | uvc_duty                                |
+//! | 20     | 1    | fault_flags                             |
+//! | 21     | 1    | wifi_rssi (i8, 127 = not connected)     |
+//! | 22     | 4    | effective_interval_ms (u32)             |
+//! | 26     | 4    | purge_volume_ml (f32)                   |
+//! | 30     | 4    | drain_volume_ml (f32)                   |
+//! | 34     | 1    | flags (see below)                       |
+//! | 35     | 1    | power_profile (`PowerProfile`)          |
+//! | 36     | 1    | zone                                    |
+//! | 37     | 1    | connected_clients                       |
+//! | 38     | 8    | timestamp_ms (u64, device uptime)       |
+//!
+//! Flags byte: bit0 = drain_complete, bit1 = nh3_alarm, bit2 = source
+//! tank is Tank B, bit3 = fault_test, bit4 = nh3_injected, bit5 =
+//! sensor_warming.  Unassigned bits are zero.
+//!
+//! Readers check the version byte before trusting the rest.  Version 1
+//! was the first 38 bytes without the timestamp.
+
+use super::fb;
+use super::telemetry_delta::TelemetryValues;
+
+/// Layout version written in byte 0.
+pub const PACKED_TELEMETRY_VERSION: u8 = 2;
+
+/// Encoded size of a version-2 record.
+pub const PACKED_TELEMETRY_LEN: usize = 46;
+
+const FLAG_DRAIN_COMPLETE: u8 = 1 << 0;
+const FLAG_NH3_ALARM: u8 = 1 << 1;
+const FLAG_SOURCE_TANK_B: u8 = 1 << 2;
+const FLAG_FAULT_TEST: u8 = 1 << 3;
+const FLAG_NH3_INJECTED: u8 = 1 << 4;
+const FLAG_SENSOR_WARMING: u8 = 1 << 5;
+
+/// Pack `v`, sampled at `timestamp_ms`, into the version-2 layout.
+pub fn encode(v: &TelemetryValues, timestamp_ms: u64) -> [u8; PACKED_TELEMETRY_LEN] {
+    let mut out = [0u8; PACKED_TELEMETRY_LEN];
+    out[0] = PACKED_TELEMETRY_VERSION;
+    out[1] = v.state.0 as u8;
+    out[2..6].copy_from_slice(&v.nh3_ppm.to_le_bytes());
+    out[6..10].copy_from_slice(&v.nh3_avg_ppm.to_le_bytes());
+    out[10..14].copy_from_slice(&v.flow_ml_per_min.to_le_bytes());
+    out[14..18].copy_from_slice(&v.temperature_c.to_le_bytes());
+    out[18] = v.pump_duty;
+    out[19] = v.uvc_duty;
+    out[20] = v.fault_flags;
+    out[21] = v.wifi_rssi as u8;
+    out[22..26].copy_from_slice(&v.effective_interval_ms.to_le_bytes());
+    out[26..30].copy_from_slice(&v.purge_volume_ml.to_le_bytes());
+    out[30..34].copy_from_slice(&v.drain_volume_ml.to_le_bytes());
+    if v.drain_complete {
+        out[34] |= FLAG_DRAIN_COMPLETE;
+    }
+    if v.nh3_alarm {
+        out[34] |= FLAG_NH3_ALARM;
+    }
+    if v.source_tank != 0 {
+        out[34] |= FLAG_SOURCE_TANK_B;
+    }
+    if v.fault_test {
+        out[34] |= FLAG_FAULT_TEST;
+    }
+    if v.nh3_injected {
+        out[34] |= FLAG_NH3_INJECTED;
+    }
+    if v.sensor_warming {
+        out[34] |= FLAG_SENSOR_WARMING;
+    }
+    out[35] = v.power_profile.0 as u8;
+    out[36] = v.zone;
+    out[37] = v.connected_clients;
+    out[38..46].copy_from_slice(&timestamp_ms.to_le_bytes());
+    out
+}
+
+/// Unpack a record into its values and timestamp.  `None` for another
+/// version or a short buffer.
+pub fn decode(buf: &[u8]) -> Option<(TelemetryValues, u64)> {
+    if buf.len() < PACKED_TELEMETRY_LEN || buf[0] != PACKED_TELEMETRY_VERSION {
+        return None;
+    }
+    let f32_at = |at: usize| f32::from_le_bytes([buf[at], buf[at + 1], buf[at + 2], buf[at + 3]]);
+    let mut timestamp = [0u8; 8];
+    timestamp.copy_from_slice(&buf[38..46]);
+    let values = TelemetryValues {
+        state: fb::DeviceState(i8::from_le_bytes([buf[1]])),
+        nh3_ppm: f32_at(2),
+        nh3_avg_ppm: f32_at(6),
+        flow_ml_per_min: f32_at(10),
+        temperature_c: f32_at(14),
+        pump_duty: buf[18],
+        uvc_duty: buf[19],
+        fault_flags: buf[20],
+        wifi_rssi: i8::from_le_bytes([buf[21]]),
+        effective_interval_ms: u32::from_le_bytes([buf[22], buf[23], buf[24], buf[25]]),
+        purge_volume_ml: f32_at(26),
+        drain_volume_ml: f32_at(30),
+        drain_complete: buf[34] & FLAG_DRAIN_COMPLETE != 0,
+        power_profile: fb::PowerProfile(i8::from_le_bytes([buf[35]])),
+        zone: buf[36],
+        connected_clients: buf[37],
+        nh3_alarm: buf[34] & FLAG_NH3_ALARM != 0,
+        source_tank: u8::from(buf[34] & FLAG_SOURCE_TANK_B != 0),
+        fault_test: buf[34] & FLAG_FAULT_TEST != 0,
+        nh3_injected: buf[34] & FLAG_NH3_INJECTED != 0,
+        sensor_warming: buf[34] & FLAG_SENSOR_WARMING != 0,
+    };
+    Some((values, u64::from_le_bytes(timestamp)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TelemetryValues {
+        TelemetryValues {
+            state: fb::DeviceState::Active,
+            nh3_ppm: 12.5,
+            nh3_avg_ppm: 11.25,
+            flow_ml_per_min: 950.0,
+            temperature_c: 41.5,
+            pump_duty: 70,
+            uvc_duty: 100,
+            fault_flags: 0b0000_0100,
+            wifi_rssi: -61,
+            effective_interval_ms: 2000,
+            purge_volume_ml: 120.5,
+            drain_volume_ml: 3.75,
+            drain_complete: true,
+            power_profile: fb::PowerProfile::Eco,
+            zone: 2,
+            connected_clients: 3,
+            nh3_alarm: true,
+            source_tank: 1,
+            fault_test: true,
+            nh3_injected: true,
+            sensor_warming: true,
+        }
+    }
+
+    #[test]
+    fn round_trips_every_field() {
+        let v = sample();
+        let bytes = encode(&v, 86_400_123);
+        assert_eq!(bytes[0], PACKED_TELEMETRY_VERSION);
+        assert_eq!(decode(&bytes), Some((v, 86_400_123)));
+
+        let flags_clear = TelemetryValues {
+            drain_complete: false,
+            nh3_alarm: false,
+            source_tank: 0,
+            fault_test: false,
+            nh3_injected: false,
+            sensor_warming: false,
+            ..v
+        };
+        assert_eq!(decode(&encode(&flags_clear, 0)), Some((flags_clear, 0)));
+        assert_eq!(
+            decode(&encode(&TelemetryValues::ABSENT, u64::MAX)),
+            Some((TelemetryValues::ABSENT, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn byte_layout_is_stable() {
+        let bytes = encode(&sample(), 0x0102_0304_0506_0708);
+        assert_eq!(bytes[1], fb::DeviceState::Active.0 as u8);
+        assert_eq!(bytes[2..6], 12.5f32.to_le_bytes());
+        assert_eq!(bytes[21], (-61i8) as u8);
+        assert_eq!(bytes[22..26], 2000u32.to_le_bytes());
+        assert_eq!(bytes[34], 0b11_1111);
+        assert_eq!(bytes[35..38], [fb::PowerProfile::Eco.0 as u8, 2, 3]);
+        assert_eq!(bytes[38..], [8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rejects_unknown_version_and_short_buffers() {
+        let mut bytes = encode(&sample(), 0);
+        assert!(decode(&bytes[..PACKED_TELEMETRY_LEN - 1]).is_none());
+        bytes[0] = 1;
+        assert!(decode(&bytes).is_none());
+    }
+}
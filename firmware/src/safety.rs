@@ -16,15 +16,102 @@
 //!
 //! This approach supports **multiple simultaneous faults**: the system
 //! does not leave `Error` until *every* fault is resolved.
+//!
+//! ## Pure evaluation
+//!
+//! The fault conditions themselves live in [`evaluate`], a side-effect
+//! free function of the sensor snapshot, config and previous mask.  The
+//! supervisor adds only what needs memory across ticks — the pump
+//! flow-check grace period, the interlock-open grace period and the
+//! over-temperature debounce — and logs edges.  Self-tests and unit
+//! tests can call [`evaluate`] directly.
+//!
+//! ## Interlock grace
+//!
+//! An open lid cuts UVC on the very tick it is seen (the actuator layer
+//! gates UVC on the raw interlock reading), but the blocking
+//! `UvcInterlockOpen` fault is held back until the lid has stayed open
+//! for `interlock_grace_secs`.  A jostle that closes again inside the
+//! window resumes the scrub instead of forcing a trip through Error.
+//!
+//! ## Over-temperature debounce
+//!
+//! One noisy ADC conversion can read far above `max_temperature_c`.  The
+//! `OverTemperature` fault therefore sets only after
+//! `over_temp_debounce_samples` consecutive hot evaluations and, once
+//! set, clears only after as many consecutive cool ones.  The UVC lamp
+//! does not wait: `AppService` cuts it on any raw reading over the limit.
+//!
+//! ## Sensor staleness
+//!
+//! The snapshot stamps the NH3 and temperature channels with the time of
+//! their last successful conversion.  If either falls more than
+//! `sensor_stale_after_secs` behind the snapshot, the supervisor raises
+//! the non-blocking [`Advisory::SensorStale`] — a wedged driver otherwise
+//! leaves the FSM acting on old values without knowing it.
 
 use crate::config::SystemConfig;
-use crate::error::SafetyFault;
+use crate::error::{Advisory, SafetyFault};
 use crate::fsm::context::SensorSnapshot;
-use log::{error, info};
+use log::{error, info, warn};
+
+/// Compute the fault bitmask for one snapshot.
+///
+/// Water level, over-temperature and interlock are re-derived from the
+/// snapshot every call.  Water is low only once both tanks read low:
+/// while either holds water the FSM steers the source valve to it.
+/// The no-flow fault depends on pump timing the snapshot cannot see: the
+/// caller raises it, and once in `prev_flags` it persists here only while
+/// flow is still absent.
+pub fn evaluate(snapshot: &SensorSnapshot, config: &SystemConfig, prev_flags: u8) -> u8 {
+    let mut flags = 0;
+    if !snapshot.tank_a_ok && !snapshot.tank_b_ok {
+        flags |= SafetyFault::WaterLevelLow.mask();
+    }
+    if prev_flags & SafetyFault::NoFlowDetected.mask() != 0 && !snapshot.flow_detected {
+        flags |= SafetyFault::NoFlowDetected.mask();
+    }
+    if snapshot.temperature_c > config.max_temperature_c {
+        flags |= SafetyFault::OverTemperature.mask();
+    }
+    if !snapshot.uvc_interlock_closed {
+        flags |= SafetyFault::UvcInterlockOpen.mask();
+    }
+    flags
+}
+
+/// Reads a channel's last-update stamp from the snapshot.
+type UpdatedAt = fn(&SensorSnapshot) -> u64;
+
+/// Critical channels checked for staleness.
+const STALENESS_CHANNELS: [(&str, UpdatedAt); 2] = [
+    ("NH3", |s| s.nh3_updated_ms),
+    ("temperature", |s| s.temperature_updated_ms),
+];
+
+/// First critical channel whose data is older than
+/// `sensor_stale_after_secs`, or `None` if all are fresh (or the check
+/// is disabled).
+pub fn stale_channel(snapshot: &SensorSnapshot, config: &SystemConfig) -> Option<&'static str> {
+    if config.sensor_stale_after_secs == 0 {
+        return None;
+    }
+    let limit_ms = u64::from(config.sensor_stale_after_secs) * 1000;
+    STALENESS_CHANNELS
+        .iter()
+        .find(|(_, updated)| snapshot.age_ms(updated(snapshot)) > limit_ms)
+        .map(|(name, _)| *name)
+}
+
+const ALL_FAULTS: [SafetyFault; 4] = [
+    SafetyFault::WaterLevelLow,
+    SafetyFault::NoFlowDetected,
+    SafetyFault::OverTemperature,
+    SafetyFault::UvcInterlockOpen,
+];
 
 /// Safety supervisor.
 pub struct SafetySupervisor {
-    max_temp_c: f32,
     /// Latched fault bitmask.
     faults: u8,
     /// Whether the pump is currently commanded on (set by main loop).
@@ -33,20 +120,41 @@ pub struct SafetySupervisor {
     flow_grace_ticks: u32,
     /// Ticks since pump was last commanded on.
     pump_on_ticks: u32,
+    /// The pump has run past its grace period, so missing flow is a fault.
+    flow_check_armed: bool,
+    /// Measured length of the tick being evaluated (seconds).
+    tick_dt_secs: f32,
+    /// How long the interlock has read open (seconds, 0 when closed).
+    interlock_open_secs: f32,
+    /// Advisory bitmask from the last evaluation (see [`Advisory`]).
+    advisories: u8,
+    /// Consecutive evaluations whose raw over-temperature reading
+    /// disagreed with the latched fault.
+    over_temp_disagree: u8,
 }
 
 impl SafetySupervisor {
     pub fn new(config: &SystemConfig) -> Self {
         Self {
-            max_temp_c: config.max_temperature_c,
             faults: 0,
             pump_commanded: false,
             // Allow ~3 seconds for the peristaltic pump to prime.
             flow_grace_ticks: 3000 / config.control_loop_interval_ms,
             pump_on_ticks: 0,
+            flow_check_armed: false,
+            tick_dt_secs: config.power_timing().control_loop_interval_ms as f32 / 1000.0,
+            interlock_open_secs: 0.0,
+            advisories: 0,
+            over_temp_disagree: 0,
         }
     }
 
+    /// Measured length of the tick about to be evaluated; times the
+    /// interlock grace period.
+    pub fn set_tick_dt(&mut self, dt_secs: f32) {
+        self.tick_dt_secs = dt_secs;
+    }
+
     /// Inform the supervisor whether the pump is currently commanded on.
     pub fn set_pump_commanded(&mut self, on: bool) {
         if on && !self.pump_commanded {
@@ -57,32 +165,36 @@ impl SafetySupervisor {
 
     /// Evaluate all safety conditions against the latest sensor snapshot.
     /// Returns the updated fault bitmask.
-    pub fn evaluate(&mut self, snap: &SensorSnapshot) -> u8 {
-        // ── Water level ───────────────────────────────────────────
-        self.eval_fault(SafetyFault::WaterLevelLow, !snap.tank_a_ok);
-
-        // ── Flow check (only after grace period while pump on) ───
+    pub fn evaluate(&mut self, snap: &SensorSnapshot, config: &SystemConfig) -> u8 {
+        // Arm the flow check once the pump has run past its grace period;
+        // with the pump off the flow fault is not relevant.
+        let no_flow = SafetyFault::NoFlowDetected.mask();
+        let mut prev = self.faults;
         if self.pump_commanded {
             self.pump_on_ticks = self.pump_on_ticks.saturating_add(1);
-            if self.pump_on_ticks > self.flow_grace_ticks {
-                self.eval_fault(SafetyFault::NoFlowDetected, !snap.flow_detected);
-            }
+            self.flow_check_armed = self.pump_on_ticks > self.flow_grace_ticks;
         } else {
-            // Pump is off — clear flow fault (not relevant)
-            self.clear_fault(SafetyFault::NoFlowDetected);
+            prev &= !no_flow;
             self.pump_on_ticks = 0;
+            self.flow_check_armed = false;
         }
 
-        // ── Temperature ───────────────────────────────────────────
-        self.eval_fault(
-            SafetyFault::OverTemperature,
-            snap.temperature_c > self.max_temp_c,
-        );
-
-        // ── UVC interlock ─────────────────────────────────────────
-        self.eval_fault(SafetyFault::UvcInterlockOpen, !snap.uvc_interlock_closed);
+        let mut faults = evaluate(snap, config, prev);
+        if self.flow_check_armed && !snap.flow_detected {
+            faults |= no_flow;
+        }
+        faults = self.apply_interlock_grace(snap, config, faults);
+        faults = self.apply_over_temp_debounce(snap, config, faults);
+        self.log_edges(faults);
+        self.faults = faults;
+        self.check_staleness(snap, config);
+        faults
+    }
 
-        self.faults
+    /// Advisories raised by the last evaluation.  Only
+    /// [`Advisory::SensorStale`] is owned by the supervisor.
+    pub fn advisories(&self) -> u8 {
+        self.advisories
     }
 
     /// Current fault bitmask.
@@ -102,24 +214,97 @@ impl SafetySupervisor {
 
     // ── Internal ──────────────────────────────────────────────────
 
-    /// Set or clear a fault bit based on a boolean condition.
-    fn eval_fault(&mut self, fault: SafetyFault, condition: bool) {
-        if condition {
-            if self.faults & fault.mask() == 0 {
-                error!("SAFETY FAULT SET: {fault}");
+    /// Hold back the interlock fault until the lid has been open for
+    /// `interlock_grace_secs`.  Once raised it stays until the lid closes.
+    fn apply_interlock_grace(
+        &mut self,
+        snap: &SensorSnapshot,
+        config: &SystemConfig,
+        faults: u8,
+    ) -> u8 {
+        let mask = SafetyFault::UvcInterlockOpen.mask();
+        if snap.uvc_interlock_closed {
+            if self.interlock_open_secs > 0.0 && self.faults & mask == 0 {
+                info!("Interlock closed again within grace — resuming");
             }
-            self.faults |= fault.mask();
+            self.interlock_open_secs = 0.0;
+            return faults;
+        }
+
+        if self.interlock_open_secs == 0.0 && config.interlock_grace_secs > 0 {
+            warn!(
+                "Interlock open — UVC cut, faulting in {} s unless closed",
+                config.interlock_grace_secs
+            );
+        }
+        self.interlock_open_secs += self.tick_dt_secs;
+        let latched = self.faults & mask != 0;
+        if !latched && self.interlock_open_secs < f32::from(config.interlock_grace_secs) {
+            faults & !mask
         } else {
-            if self.faults & fault.mask() != 0 {
-                info!("SAFETY FAULT CLEARED: {fault}");
+            faults
+        }
+    }
+
+    /// Keep the latched over-temperature state until the raw reading has
+    /// disagreed with it for `over_temp_debounce_samples` evaluations in
+    /// a row.
+    fn apply_over_temp_debounce(
+        &mut self,
+        snap: &SensorSnapshot,
+        config: &SystemConfig,
+        faults: u8,
+    ) -> u8 {
+        let mask = SafetyFault::OverTemperature.mask();
+        if (faults ^ self.faults) & mask == 0 {
+            self.over_temp_disagree = 0;
+            return faults;
+        }
+
+        self.over_temp_disagree = self.over_temp_disagree.saturating_add(1);
+        if self.over_temp_disagree >= config.over_temp_debounce_samples {
+            self.over_temp_disagree = 0;
+            return faults;
+        }
+        if self.over_temp_disagree == 1 && self.faults & mask == 0 {
+            warn!(
+                "Temperature {:.1} °C over limit — faulting if it persists for {} samples",
+                snap.temperature_c, config.over_temp_debounce_samples
+            );
+        }
+        (faults & !mask) | (self.faults & mask)
+    }
+
+    fn check_staleness(&mut self, snap: &SensorSnapshot, config: &SystemConfig) {
+        let mask = Advisory::SensorStale.mask();
+        let was_stale = self.advisories & mask != 0;
+        if let Some(channel) = stale_channel(snap, config) {
+            if !was_stale {
+                warn!(
+                    "{channel} data stale: no update for over {} s",
+                    config.sensor_stale_after_secs
+                );
+            }
+            self.advisories |= mask;
+        } else {
+            if was_stale {
+                info!("Sensor data fresh again");
             }
-            self.faults &= !fault.mask();
+            self.advisories &= !mask;
         }
     }
 
-    /// Unconditionally clear a fault bit.
-    fn clear_fault(&mut self, fault: SafetyFault) {
-        self.faults &= !fault.mask();
+    /// Log every fault bit that changed since the last evaluation.
+    fn log_edges(&self, faults: u8) {
+        for fault in ALL_FAULTS {
+            let was = self.faults & fault.mask() != 0;
+            let is = faults & fault.mask() != 0;
+            if is && !was {
+                error!("SAFETY FAULT SET: {fault}");
+            } else if was && !is {
+                info!("SAFETY FAULT CLEARED: {fault}");
+            }
+        }
     }
 }
 
@@ -145,6 +330,47 @@ mod tests {
         }
     }
 
+    fn snapshot_with_ages(nh3_age_ms: u64, temperature_age_ms: u64) -> SensorSnapshot {
+        let now = 60_000;
+        SensorSnapshot {
+            timestamp_ms: now,
+            nh3_updated_ms: now - nh3_age_ms,
+            temperature_updated_ms: now - temperature_age_ms,
+            ..nominal_snapshot()
+        }
+    }
+
+    #[test]
+    fn stale_critical_sensor_raises_advisory_not_fault() {
+        let cfg = SystemConfig::default(); // 10 s threshold
+        let mut s = make_supervisor();
+
+        let faults = s.evaluate(&snapshot_with_ages(10_001, 0), &cfg);
+        assert_eq!(faults, 0);
+        assert_eq!(s.advisories(), Advisory::SensorStale.mask());
+        assert_eq!(
+            stale_channel(&snapshot_with_ages(0, 30_000), &cfg),
+            Some("temperature")
+        );
+
+        // Recovers once the channel updates again.
+        s.evaluate(&snapshot_with_ages(100, 100), &cfg);
+        assert_eq!(s.advisories(), 0);
+    }
+
+    #[test]
+    fn fresh_snapshot_raises_no_staleness() {
+        let mut s = make_supervisor();
+        s.evaluate(&snapshot_with_ages(10_000, 9_000), &SystemConfig::default());
+        assert_eq!(s.advisories(), 0);
+
+        let off = SystemConfig {
+            sensor_stale_after_secs: 0,
+            ..Default::default()
+        };
+        assert_eq!(stale_channel(&snapshot_with_ages(60_000, 0), &off), None);
+    }
+
     #[test]
     fn no_faults_initially() {
         let s = make_supervisor();
@@ -152,12 +378,20 @@ mod tests {
         assert_eq!(s.faults(), 0);
     }
 
+    /// Over-temperature acts on a single sample.
+    fn no_over_temp_debounce() -> SystemConfig {
+        SystemConfig {
+            over_temp_debounce_samples: 1,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn over_temperature_sets_fault() {
         let mut s = make_supervisor();
         let mut snap = nominal_snapshot();
         snap.temperature_c = 85.0;
-        s.evaluate(&snap);
+        s.evaluate(&snap, &no_over_temp_debounce());
         assert!(s.has_fault(SafetyFault::OverTemperature));
     }
 
@@ -166,11 +400,51 @@ mod tests {
         let mut s = make_supervisor();
         let mut snap = nominal_snapshot();
         snap.temperature_c = 85.0;
-        s.evaluate(&snap);
+        s.evaluate(&snap, &no_over_temp_debounce());
         assert!(s.has_fault(SafetyFault::OverTemperature));
 
         snap.temperature_c = 50.0;
-        s.evaluate(&snap);
+        s.evaluate(&snap, &no_over_temp_debounce());
+        assert!(!s.has_fault(SafetyFault::OverTemperature));
+    }
+
+    #[test]
+    fn lone_temperature_spike_is_ignored() {
+        let cfg = SystemConfig::default(); // 3 samples
+        let mut s = make_supervisor();
+        let cool = nominal_snapshot();
+        let spike = SensorSnapshot {
+            temperature_c: 150.0,
+            ..nominal_snapshot()
+        };
+
+        for snap in [&cool, &spike, &cool, &spike, &spike, &cool] {
+            assert_eq!(s.evaluate(snap, &cfg), 0);
+        }
+    }
+
+    #[test]
+    fn sustained_over_temperature_sets_and_clears_with_hysteresis() {
+        let cfg = SystemConfig::default(); // 3 samples
+        let mut s = make_supervisor();
+        let hot = SensorSnapshot {
+            temperature_c: 85.0,
+            ..nominal_snapshot()
+        };
+
+        s.evaluate(&hot, &cfg);
+        s.evaluate(&hot, &cfg);
+        assert!(!s.has_fault(SafetyFault::OverTemperature));
+        s.evaluate(&hot, &cfg);
+        assert!(s.has_fault(SafetyFault::OverTemperature));
+
+        // A single cool reading does not clear it; a run of them does.
+        s.evaluate(&nominal_snapshot(), &cfg);
+        s.evaluate(&hot, &cfg);
+        s.evaluate(&nominal_snapshot(), &cfg);
+        s.evaluate(&nominal_snapshot(), &cfg);
+        assert!(s.has_fault(SafetyFault::OverTemperature));
+        s.evaluate(&nominal_snapshot(), &cfg);
         assert!(!s.has_fault(SafetyFault::OverTemperature));
     }
 
@@ -179,8 +453,21 @@ mod tests {
         let mut s = make_supervisor();
         let mut snap = nominal_snapshot();
         snap.tank_a_ok = false;
-        s.evaluate(&snap);
+        s.evaluate(&snap, &SystemConfig::default());
+        assert!(!s.has_fault(SafetyFault::WaterLevelLow));
+        snap.tank_b_ok = false;
+        s.evaluate(&snap, &SystemConfig::default());
         assert!(s.has_fault(SafetyFault::WaterLevelLow));
+        snap.tank_a_ok = true;
+        s.evaluate(&snap, &SystemConfig::default());
+        assert!(!s.has_fault(SafetyFault::WaterLevelLow));
+    }
+
+    fn no_interlock_grace() -> SystemConfig {
+        SystemConfig {
+            interlock_grace_secs: 0,
+            ..Default::default()
+        }
     }
 
     #[test]
@@ -188,10 +475,58 @@ mod tests {
         let mut s = make_supervisor();
         let mut snap = nominal_snapshot();
         snap.uvc_interlock_closed = false;
-        s.evaluate(&snap);
+        s.evaluate(&snap, &no_interlock_grace());
         assert!(s.has_fault(SafetyFault::UvcInterlockOpen));
     }
 
+    #[test]
+    fn brief_interlock_open_within_grace_does_not_fault() {
+        let config = SystemConfig {
+            interlock_grace_secs: 3,
+            ..Default::default()
+        };
+        let mut s = make_supervisor();
+        let mut snap = nominal_snapshot();
+        snap.uvc_interlock_closed = false;
+        for _ in 0..2 {
+            s.evaluate(&snap, &config);
+            assert!(!s.has_fault(SafetyFault::UvcInterlockOpen));
+        }
+
+        snap.uvc_interlock_closed = true;
+        s.evaluate(&snap, &config);
+        assert!(!s.has_faults());
+
+        // The bounce reset the timer: a fresh open gets the full window.
+        snap.uvc_interlock_closed = false;
+        for _ in 0..2 {
+            s.evaluate(&snap, &config);
+        }
+        assert!(!s.has_fault(SafetyFault::UvcInterlockOpen));
+    }
+
+    #[test]
+    fn sustained_interlock_open_faults_after_grace() {
+        let config = SystemConfig {
+            interlock_grace_secs: 2,
+            ..Default::default()
+        };
+        let mut s = make_supervisor();
+        s.set_tick_dt(0.5);
+        let mut snap = nominal_snapshot();
+        snap.uvc_interlock_closed = false;
+        for _ in 0..3 {
+            s.evaluate(&snap, &config);
+        }
+        assert!(!s.has_fault(SafetyFault::UvcInterlockOpen));
+        s.evaluate(&snap, &config);
+        assert!(s.has_fault(SafetyFault::UvcInterlockOpen));
+
+        snap.uvc_interlock_closed = true;
+        s.evaluate(&snap, &config);
+        assert!(!s.has_fault(SafetyFault::UvcInterlockOpen));
+    }
+
     #[test]
     fn no_flow_fault_after_grace_period() {
         let mut s = make_supervisor();
@@ -201,17 +536,37 @@ mod tests {
 
         // During grace period — no fault
         for _ in 0..3 {
-            s.evaluate(&snap);
+            s.evaluate(&snap, &SystemConfig::default());
         }
         assert!(!s.has_fault(SafetyFault::NoFlowDetected));
 
         // After grace period — fault should fire
         for _ in 0..5 {
-            s.evaluate(&snap);
+            s.evaluate(&snap, &SystemConfig::default());
         }
         assert!(s.has_fault(SafetyFault::NoFlowDetected));
     }
 
+    #[test]
+    fn no_flow_fault_returns_when_flow_drops_again() {
+        let mut s = make_supervisor();
+        s.set_pump_commanded(true);
+        let mut snap = nominal_snapshot();
+        snap.flow_detected = false;
+        for _ in 0..10 {
+            s.evaluate(&snap, &SystemConfig::default());
+        }
+        assert!(s.has_fault(SafetyFault::NoFlowDetected));
+
+        // Flow back: the fault clears but the check stays armed.
+        snap.flow_detected = true;
+        s.evaluate(&snap, &SystemConfig::default());
+        assert!(!s.has_fault(SafetyFault::NoFlowDetected));
+        snap.flow_detected = false;
+        s.evaluate(&snap, &SystemConfig::default());
+        assert!(s.has_fault(SafetyFault::NoFlowDetected));
+    }
+
     #[test]
     fn no_flow_fault_clears_when_pump_off() {
         let mut s = make_supervisor();
@@ -220,12 +575,12 @@ mod tests {
         snap.flow_detected = false;
 
         for _ in 0..10 {
-            s.evaluate(&snap);
+            s.evaluate(&snap, &SystemConfig::default());
         }
         assert!(s.has_fault(SafetyFault::NoFlowDetected));
 
         s.set_pump_commanded(false);
-        s.evaluate(&snap);
+        s.evaluate(&snap, &SystemConfig::default());
         assert!(!s.has_fault(SafetyFault::NoFlowDetected));
     }
 
@@ -238,7 +593,11 @@ mod tests {
             uvc_interlock_closed: false,
             ..Default::default()
         };
-        s.evaluate(&snap);
+        let cfg = SystemConfig {
+            over_temp_debounce_samples: 1,
+            ..no_interlock_grace()
+        };
+        s.evaluate(&snap, &cfg);
         assert!(s.has_fault(SafetyFault::WaterLevelLow));
         assert!(s.has_fault(SafetyFault::OverTemperature));
         assert!(s.has_fault(SafetyFault::UvcInterlockOpen));
@@ -253,7 +612,7 @@ mod tests {
             uvc_interlock_closed: true,
             ..Default::default()
         };
-        s.evaluate(&snap);
+        s.evaluate(&snap, &no_over_temp_debounce());
         assert!(s.has_fault(SafetyFault::WaterLevelLow));
         assert!(s.has_fault(SafetyFault::OverTemperature));
 
@@ -263,10 +622,157 @@ mod tests {
             uvc_interlock_closed: true,
             ..Default::default()
         };
-        s.evaluate(&snap2);
+        s.evaluate(&snap2, &no_over_temp_debounce());
         assert!(!s.has_fault(SafetyFault::WaterLevelLow));
         assert!(s.has_fault(SafetyFault::OverTemperature));
     }
+
+    const WATER: u8 = SafetyFault::WaterLevelLow.mask();
+    const NO_FLOW: u8 = SafetyFault::NoFlowDetected.mask();
+    const OVER_TEMP: u8 = SafetyFault::OverTemperature.mask();
+    const INTERLOCK: u8 = SafetyFault::UvcInterlockOpen.mask();
+
+    #[test]
+    fn pure_evaluate_single_conditions() {
+        let config = SystemConfig::default();
+        let max = config.max_temperature_c;
+        let cases: [(&str, SensorSnapshot, u8, u8); 9] = [
+            ("nominal", nominal_snapshot(), 0, 0),
+            (
+                "tank A low",
+                SensorSnapshot {
+                    tank_a_ok: false,
+                    ..nominal_snapshot()
+                },
+                0,
+                0,
+            ),
+            (
+                "tank B low",
+                SensorSnapshot {
+                    tank_b_ok: false,
+                    ..nominal_snapshot()
+                },
+                0,
+                0,
+            ),
+            (
+                "both tanks low",
+                SensorSnapshot {
+                    tank_a_ok: false,
+                    tank_b_ok: false,
+                    ..nominal_snapshot()
+                },
+                0,
+                WATER,
+            ),
+            (
+                "temp at limit",
+                SensorSnapshot {
+                    temperature_c: max,
+                    ..nominal_snapshot()
+                },
+                0,
+                0,
+            ),
+            (
+                "temp over limit",
+                SensorSnapshot {
+                    temperature_c: max + 0.01,
+                    ..nominal_snapshot()
+                },
+                0,
+                OVER_TEMP,
+            ),
+            (
+                "interlock open",
+                SensorSnapshot {
+                    uvc_interlock_closed: false,
+                    ..nominal_snapshot()
+                },
+                0,
+                INTERLOCK,
+            ),
+            (
+                "no flow, not armed",
+                SensorSnapshot {
+                    flow_detected: false,
+                    ..nominal_snapshot()
+                },
+                0,
+                0,
+            ),
+            (
+                "no flow, latched",
+                SensorSnapshot {
+                    flow_detected: false,
+                    ..nominal_snapshot()
+                },
+                NO_FLOW,
+                NO_FLOW,
+            ),
+        ];
+        for (name, snap, prev, expected) in cases {
+            assert_eq!(evaluate(&snap, &config, prev), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn pure_evaluate_matrix() {
+        let config = SystemConfig::default();
+        let max = config.max_temperature_c;
+        let temps = [(max - 0.01, 0), (max, 0), (max + 0.01, OVER_TEMP)];
+
+        let tanks = [(true, true), (false, true), (true, false), (false, false)];
+
+        for (tank_a_ok, tank_b_ok) in tanks {
+            for flow_detected in [true, false] {
+                for uvc_interlock_closed in [true, false] {
+                    for (temperature_c, temp_bit) in temps {
+                        for prev in [0, NO_FLOW, !NO_FLOW, 0xFF] {
+                            let snap = SensorSnapshot {
+                                flow_detected,
+                                tank_a_ok,
+                                tank_b_ok,
+                                temperature_c,
+                                uvc_interlock_closed,
+                                ..nominal_snapshot()
+                            };
+                            let mut expected = temp_bit;
+                            if !tank_a_ok && !tank_b_ok {
+                                expected |= WATER;
+                            }
+                            if !uvc_interlock_closed {
+                                expected |= INTERLOCK;
+                            }
+                            if prev & NO_FLOW != 0 && !flow_detected {
+                                expected |= NO_FLOW;
+                            }
+                            assert_eq!(
+                                evaluate(&snap, &config, prev),
+                                expected,
+                                "tanks={tank_a_ok}/{tank_b_ok} flow={flow_detected} \
+                                 interlock={uvc_interlock_closed} temp={temperature_c} \
+                                 prev=0b{prev:08b}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pure_evaluate_follows_config_limit() {
+        let mut config = SystemConfig::default();
+        let snap = SensorSnapshot {
+            temperature_c: 70.0,
+            ..nominal_snapshot()
+        };
+        assert_eq!(evaluate(&snap, &config, 0), 0);
+        config.max_temperature_c = 65.0;
+        assert_eq!(evaluate(&snap, &config, 0), OVER_TEMP);
+    }
 }
 
 #[cfg(test)]
@@ -279,13 +785,14 @@ mod proptests {
     fn arb_snapshot() -> impl Strategy<Value = SensorSnapshot> {
         (
             prop::bool::ANY, // tank_a_ok
+            prop::bool::ANY, // tank_b_ok
             prop::bool::ANY, // flow_detected
             -40.0f32..150.0, // temperature_c
             prop::bool::ANY, // uvc_interlock_closed
         )
-            .prop_map(|(tank_a, flow, temp, interlock)| SensorSnapshot {
+            .prop_map(|(tank_a, tank_b, flow, temp, interlock)| SensorSnapshot {
                 tank_a_ok: tank_a,
-                tank_b_ok: true,
+                tank_b_ok: tank_b,
                 flow_detected: flow,
                 temperature_c: temp,
                 over_temperature: temp > 80.0,
@@ -297,22 +804,26 @@ mod proptests {
     proptest! {
         #[test]
         fn safety_invariants_hold(snapshots in proptest::collection::vec(arb_snapshot(), 1..50)) {
-            let config = SystemConfig::default();
+            // Without grace or debounce, faults track their inputs exactly.
+            let config = SystemConfig {
+                interlock_grace_secs: 0,
+                over_temp_debounce_samples: 1,
+                ..Default::default()
+            };
             let mut sup = SafetySupervisor::new(&config);
             sup.set_pump_commanded(true);
 
             for snap in &snapshots {
-                let faults = sup.evaluate(snap);
+                let faults = sup.evaluate(snap, &config);
 
                 // Over-temp fault must be set iff temp exceeds max
                 if snap.temperature_c > config.max_temperature_c {
                     prop_assert!(faults & SafetyFault::OverTemperature.mask() != 0);
                 }
 
-                // Water level fault must be set iff tank_a is not ok
-                if !snap.tank_a_ok {
-                    prop_assert!(faults & SafetyFault::WaterLevelLow.mask() != 0);
-                }
+                // Water level fault must be set iff both tanks are low
+                let water_low = faults & SafetyFault::WaterLevelLow.mask() != 0;
+                prop_assert_eq!(water_low, !snap.tank_a_ok && !snap.tank_b_ok);
 
                 // Interlock fault must be set iff interlock is open
                 if !snap.uvc_interlock_closed {
@@ -326,7 +837,10 @@ mod proptests {
             bad_temp in 81.0f32..150.0,
             good_temp in -40.0f32..79.0,
         ) {
-            let config = SystemConfig::default();
+            let config = SystemConfig {
+                over_temp_debounce_samples: 1,
+                ..Default::default()
+            };
             let mut sup = SafetySupervisor::new(&config);
 
             let bad_snap = SensorSnapshot {
@@ -335,7 +849,7 @@ mod proptests {
                 uvc_interlock_closed: true,
                 ..Default::default()
             };
-            sup.evaluate(&bad_snap);
+            sup.evaluate(&bad_snap, &config);
             prop_assert!(sup.has_fault(SafetyFault::OverTemperature));
 
             let good_snap = SensorSnapshot {
@@ -344,7 +858,7 @@ mod proptests {
                 uvc_interlock_closed: true,
                 ..Default::default()
             };
-            sup.evaluate(&good_snap);
+            sup.evaluate(&good_snap, &config);
             prop_assert!(!sup.has_fault(SafetyFault::OverTemperature));
         }
     }
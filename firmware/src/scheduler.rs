@@ -26,7 +26,8 @@
 //! ```
 
 use crate::app::ports::{ScheduleFiredKind, SchedulerDelegate};
-use log::info;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
 
 // ═══════════════════════════════════════════════════════════════
 //  Schedule types
@@ -58,9 +59,19 @@ pub enum ScheduleKind {
     OneShot { delay_secs: u32 },
 }
 
+/// The user's recurring scrub, persisted with the system config and
+/// installed in the scheduler as [`PERIODIC_LABEL`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeriodicSchedule {
+    /// Seconds between scrub runs.
+    pub interval_secs: u32,
+    /// Length of each run in seconds.
+    pub duration_secs: u16,
+}
+
 /// Time-of-day restriction (quiet hours).
 /// During quiet hours, periodic schedules are suppressed.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QuietHours {
     /// Start hour (0-23 inclusive).  E.g. 23 = 11 PM.
     pub start_hour: u8,
@@ -86,7 +97,17 @@ impl QuietHours {
 // ═══════════════════════════════════════════════════════════════
 
 /// Maximum number of concurrent schedules (stack-allocated).
-const MAX_SCHEDULES: usize = 4;
+pub const MAX_SCHEDULES: usize = 4;
+
+/// Longest schedule label that can be remembered as paused.
+pub const SCHEDULE_LABEL_MAX_LEN: usize = 24;
+
+/// Label of the schedule installed from [`PeriodicSchedule`].
+pub const PERIODIC_LABEL: &str = "periodic";
+
+/// Labels of schedules paused by the user, kept in the config so a
+/// pause survives a restart.
+pub type PausedLabels = heapless::Vec<heapless::String<SCHEDULE_LABEL_MAX_LEN>, MAX_SCHEDULES>;
 
 /// The scheduler engine.
 ///
@@ -99,20 +120,45 @@ pub struct Scheduler {
     schedules: [Option<ScheduleEntry>; MAX_SCHEDULES],
     /// Quiet hours restriction (applies to Periodic schedules).
     quiet_hours: Option<QuietHours>,
+    /// Labels paused by the user; schedules added under them start
+    /// disabled.
+    paused: PausedLabels,
     /// Global enable flag.
     enabled: bool,
+    /// Seconds of scheduler ticks since boot; timestamps fire history.
+    uptime_secs: f64,
 }
 
 /// Internal bookkeeping for a live schedule.
 #[derive(Debug, Clone)]
 struct ScheduleEntry {
     schedule: Schedule,
-    /// Ticks elapsed since last fire (for Periodic).
-    elapsed_ticks: u64,
-    /// Ticks remaining for Boost mode.
-    remaining_ticks: Option<u64>,
+    /// Seconds elapsed since last fire (for Periodic and OneShot).
+    elapsed_secs: f64,
+    /// Seconds remaining for Boost mode.
+    remaining_secs: Option<f64>,
     /// Whether the schedule has fired (for OneShot).
     fired: bool,
+    /// Times this entry has fired since it was added.
+    fire_count: u32,
+    /// Scheduler uptime (seconds) at the most recent fire.
+    last_fire_secs: Option<u32>,
+}
+
+impl ScheduleEntry {
+    fn record_fire(&mut self, now_secs: u32) {
+        self.fire_count = self.fire_count.saturating_add(1);
+        self.last_fire_secs = Some(now_secs);
+    }
+}
+
+/// Execution history of one schedule, reported in the schedule list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduleHistory {
+    /// Times the schedule has fired since it was added.
+    pub fire_count: u32,
+    /// Scheduler uptime (seconds) at the most recent fire.
+    pub last_fire_secs: Option<u32>,
 }
 
 impl Default for Scheduler {
@@ -126,20 +172,28 @@ impl Scheduler {
         Self {
             schedules: [None, None, None, None],
             quiet_hours: None,
+            paused: PausedLabels::new(),
             enabled: true,
+            uptime_secs: 0.0,
         }
     }
 
     /// Add a schedule.  Returns the slot index, or `None` if full.
-    pub fn add(&mut self, schedule: Schedule) -> Option<usize> {
+    /// A schedule whose label is paused is added disabled.
+    pub fn add(&mut self, mut schedule: Schedule) -> Option<usize> {
+        if self.paused.iter().any(|l| l == schedule.label) {
+            schedule.enabled = false;
+        }
         for (i, slot) in self.schedules.iter_mut().enumerate() {
             if slot.is_none() {
                 info!("Scheduler: added '{}' at slot {}", schedule.label, i);
                 *slot = Some(ScheduleEntry {
                     schedule,
-                    elapsed_ticks: 0,
-                    remaining_ticks: None,
+                    elapsed_secs: 0.0,
+                    remaining_secs: None,
                     fired: false,
+                    fire_count: 0,
+                    last_fire_secs: None,
                 });
                 return Some(i);
             }
@@ -147,7 +201,7 @@ impl Scheduler {
         None // All slots full.
     }
 
-    /// Remove a schedule by slot index.
+    /// Remove a schedule by slot index.  Its fire history goes with it.
     pub fn remove(&mut self, slot: usize) {
         if slot < MAX_SCHEDULES {
             if let Some(entry) = &self.schedules[slot] {
@@ -174,12 +228,109 @@ impl Scheduler {
         self.quiet_hours = None;
     }
 
+    /// Current quiet hours, if any.
+    pub fn quiet_hours(&self) -> Option<QuietHours> {
+        self.quiet_hours
+    }
+
+    /// Whether `current_hour` falls inside the configured quiet hours.
+    /// Always `false` without quiet hours or wall-clock time.
+    pub fn is_quiet_hour(&self, current_hour: Option<u8>) -> bool {
+        current_hour
+            .and_then(|h| self.quiet_hours.map(|q| q.is_quiet(h)))
+            .unwrap_or(false)
+    }
+
+    /// Replace the set of paused labels.  Schedules under a newly paused
+    /// label are disabled and those under a newly resumed one enabled;
+    /// the rest keep their state.
+    pub fn set_paused_labels(&mut self, paused: &PausedLabels) {
+        let old = core::mem::replace(&mut self.paused, paused.clone());
+        for label in paused.iter().filter(|l| !old.contains(l)) {
+            self.set_schedule_enabled(label, false);
+        }
+        for label in old.iter().filter(|l| !paused.contains(l)) {
+            self.set_schedule_enabled(label, true);
+        }
+    }
+
+    /// Install, replace or (with `None`) remove the persisted periodic
+    /// schedule.  An unchanged schedule keeps its countdown and history.
+    pub fn set_periodic(&mut self, periodic: Option<PeriodicSchedule>) {
+        let slot = self.schedules.iter().position(|s| {
+            s.as_ref()
+                .is_some_and(|e| e.schedule.label == PERIODIC_LABEL)
+        });
+        let current =
+            slot.and_then(|i| self.schedules[i].as_ref())
+                .and_then(|e| match e.schedule.kind {
+                    ScheduleKind::Periodic {
+                        interval_secs,
+                        duration_secs,
+                    } => Some(PeriodicSchedule {
+                        interval_secs,
+                        duration_secs,
+                    }),
+                    _ => None,
+                });
+        if current == periodic {
+            return;
+        }
+        if let Some(i) = slot {
+            self.remove(i);
+        }
+        if let Some(p) = periodic {
+            let added = self.add(Schedule {
+                label: PERIODIC_LABEL,
+                kind: ScheduleKind::Periodic {
+                    interval_secs: p.interval_secs,
+                    duration_secs: p.duration_secs,
+                },
+                enabled: true,
+            });
+            if added.is_none() {
+                warn!("Scheduler full, cannot install periodic schedule");
+            }
+        }
+    }
+
+    /// Whether any schedule is labelled `label`.
+    pub fn has_label(&self, label: &str) -> bool {
+        self.schedules
+            .iter()
+            .flatten()
+            .any(|e| e.schedule.label == label)
+    }
+
     /// Enable or disable the entire scheduler.
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
     }
 
-    /// Tick the scheduler.  Call once per control loop tick.
+    /// Pause or resume every schedule labelled `label`.  A paused
+    /// schedule keeps its slot, countdown and history, so resuming
+    /// picks up where it left off.  Returns `false` if no schedule
+    /// has that label.
+    pub fn set_schedule_enabled(&mut self, label: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for entry in self.schedules.iter_mut().flatten() {
+            if entry.schedule.label == label {
+                entry.schedule.enabled = enabled;
+                found = true;
+            }
+        }
+        if found {
+            info!(
+                "Scheduler: '{}' {}",
+                label,
+                if enabled { "resumed" } else { "paused" }
+            );
+        }
+        found
+    }
+
+    /// Tick the scheduler.  Call once per control loop tick with the
+    /// measured time since the previous call.
     ///
     /// When a schedule fires, `delegate.on_schedule_fired()` is called
     /// with the schedule label and fire kind.  The caller decides what
@@ -189,7 +340,7 @@ impl Scheduler {
     ///
     /// * `current_hour` — current hour-of-day (0-23), or `None` if
     ///   wall-clock time is unavailable (pre-NTP sync).
-    /// * `tick_secs` — duration of one tick in seconds.
+    /// * `tick_secs` — time since the previous tick, in seconds.
     /// * `delegate` — receives fire notifications.
     pub fn tick(
         &mut self,
@@ -197,13 +348,13 @@ impl Scheduler {
         tick_secs: f32,
         delegate: &mut dyn SchedulerDelegate,
     ) {
+        self.uptime_secs += tick_secs as f64;
         if !self.enabled {
             return;
         }
 
-        let is_quiet = current_hour
-            .and_then(|h| self.quiet_hours.map(|q| q.is_quiet(h)))
-            .unwrap_or(false);
+        let is_quiet = self.is_quiet_hour(current_hour);
+        let now_secs = self.uptime_secs as u32;
 
         for slot in &mut self.schedules {
             let entry = match slot {
@@ -211,49 +362,50 @@ impl Scheduler {
                 _ => continue,
             };
 
-            entry.elapsed_ticks += 1;
-            let elapsed_secs = entry.elapsed_ticks as f32 * tick_secs;
+            entry.elapsed_secs += f64::from(tick_secs);
+            let elapsed_secs = entry.elapsed_secs;
 
             match &entry.schedule.kind {
                 ScheduleKind::Periodic { interval_secs, .. } => {
                     if is_quiet {
                         continue; // Suppress during quiet hours.
                     }
-                    if elapsed_secs >= *interval_secs as f32 {
+                    if elapsed_secs >= f64::from(*interval_secs) {
                         info!(
                             "Scheduler: '{}' periodic fire (every {}s)",
                             entry.schedule.label, interval_secs
                         );
                         delegate
                             .on_schedule_fired(entry.schedule.label, ScheduleFiredKind::Periodic);
-                        entry.elapsed_ticks = 0;
+                        entry.elapsed_secs = 0.0;
+                        entry.record_fire(now_secs);
                     }
                 }
 
                 ScheduleKind::Boost { duration_secs } => {
-                    if entry.remaining_ticks.is_none() {
+                    if entry.remaining_secs.is_none() {
                         // First tick — start boost.
-                        let ticks = (*duration_secs as f32 / tick_secs) as u64;
-                        entry.remaining_ticks = Some(ticks);
+                        entry.remaining_secs = Some(f64::from(*duration_secs));
                         info!(
                             "Scheduler: '{}' boost started ({}s)",
                             entry.schedule.label, duration_secs
                         );
                         delegate.on_schedule_fired(entry.schedule.label, ScheduleFiredKind::Boost);
+                        entry.record_fire(now_secs);
                     }
 
-                    if let Some(ref mut remaining) = entry.remaining_ticks {
-                        if *remaining == 0 {
+                    if let Some(ref mut remaining) = entry.remaining_secs {
+                        if *remaining <= 0.0 {
                             info!("Scheduler: '{}' boost complete", entry.schedule.label);
                             entry.schedule.enabled = false; // Auto-disable.
                         } else {
-                            *remaining = remaining.saturating_sub(1);
+                            *remaining -= f64::from(tick_secs);
                         }
                     }
                 }
 
                 ScheduleKind::OneShot { delay_secs } => {
-                    if !entry.fired && elapsed_secs >= *delay_secs as f32 {
+                    if !entry.fired && elapsed_secs >= f64::from(*delay_secs) {
                         info!(
                             "Scheduler: '{}' one-shot fired (after {}s)",
                             entry.schedule.label, delay_secs
@@ -261,6 +413,7 @@ impl Scheduler {
                         delegate
                             .on_schedule_fired(entry.schedule.label, ScheduleFiredKind::OneShot);
                         entry.fired = true;
+                        entry.record_fire(now_secs);
                         entry.schedule.enabled = false; // Auto-disable.
                     }
                 }
@@ -268,6 +421,23 @@ impl Scheduler {
         }
     }
 
+    /// Iterate over occupied slots as `(slot_index, schedule)` pairs,
+    /// including schedules that have auto-disabled.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Schedule)> {
+        self.schedules
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.as_ref().map(|e| (i, &e.schedule)))
+    }
+
+    /// Fire history of the schedule in `slot`, if occupied.
+    pub fn history(&self, slot: usize) -> Option<ScheduleHistory> {
+        self.schedules.get(slot)?.as_ref().map(|e| ScheduleHistory {
+            fire_count: e.fire_count,
+            last_fire_secs: e.last_fire_secs,
+        })
+    }
+
     /// Number of active (enabled) schedules.
     pub fn active_count(&self) -> usize {
         self.schedules
@@ -275,6 +445,29 @@ impl Scheduler {
             .filter(|s| s.as_ref().is_some_and(|e| e.schedule.enabled))
             .count()
     }
+
+    /// Seconds until the next enabled schedule comes due, or `None` if
+    /// nothing is pending.  Quiet hours are not considered; a boost that
+    /// has not started yet is due now.
+    pub fn secs_until_next_fire(&self) -> Option<u32> {
+        if !self.enabled {
+            return None;
+        }
+        self.schedules
+            .iter()
+            .flatten()
+            .filter(|e| e.schedule.enabled)
+            .filter_map(|e| {
+                let due_in = match e.schedule.kind {
+                    ScheduleKind::Periodic { interval_secs, .. } => f64::from(interval_secs),
+                    ScheduleKind::OneShot { delay_secs } if !e.fired => f64::from(delay_secs),
+                    ScheduleKind::Boost { .. } if e.remaining_secs.is_none() => return Some(0),
+                    _ => return None,
+                };
+                Some((due_in - e.elapsed_secs).max(0.0).ceil() as u32)
+            })
+            .min()
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -302,6 +495,66 @@ mod tests {
         }
     }
 
+    fn periodic(label: &'static str, interval_secs: u32) -> Schedule {
+        Schedule {
+            label,
+            kind: ScheduleKind::Periodic {
+                interval_secs,
+                duration_secs: 60,
+            },
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn next_fire_is_the_soonest_enabled_schedule() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        assert_eq!(sched.secs_until_next_fire(), None);
+
+        sched.add(periodic("hourly", 3600));
+        sched.add(Schedule {
+            label: "later",
+            kind: ScheduleKind::OneShot { delay_secs: 900 },
+            enabled: true,
+        });
+        assert_eq!(sched.secs_until_next_fire(), Some(900));
+
+        for _ in 0..100 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(sched.secs_until_next_fire(), Some(800));
+
+        sched.set_schedule_enabled("later", false);
+        assert_eq!(sched.secs_until_next_fire(), Some(3500));
+
+        sched.set_enabled(false);
+        assert_eq!(sched.secs_until_next_fire(), None);
+    }
+
+    #[test]
+    fn next_fire_skips_spent_one_shots_and_running_boosts() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        sched.add(Schedule {
+            label: "boost",
+            kind: ScheduleKind::Boost { duration_secs: 600 },
+            enabled: true,
+        });
+        assert_eq!(sched.secs_until_next_fire(), Some(0));
+
+        sched.add(Schedule {
+            label: "soon",
+            kind: ScheduleKind::OneShot { delay_secs: 5 },
+            enabled: true,
+        });
+        for _ in 0..5 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(delegate.fires.len(), 2);
+        assert_eq!(sched.secs_until_next_fire(), None);
+    }
+
     #[test]
     fn periodic_fires_at_interval() {
         let mut sched = Scheduler::new();
@@ -329,6 +582,76 @@ mod tests {
         assert_eq!(delegate.fires[0].1, ScheduleFiredKind::Periodic);
     }
 
+    #[test]
+    fn periodic_counts_measured_time_not_ticks() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        sched.add(periodic("uneven", 10));
+
+        // Twenty ticks of uneven length adding up to 8.75 s.
+        for i in 0..20 {
+            let dt = if i % 2 == 0 { 0.25 } else { 0.625 };
+            sched.tick(None, dt, &mut delegate);
+        }
+        assert!(delegate.fires.is_empty());
+        assert_eq!(sched.secs_until_next_fire(), Some(2));
+
+        sched.tick(None, 1.25, &mut delegate);
+        assert_eq!(delegate.fires.len(), 1);
+    }
+
+    #[test]
+    fn paused_labels_hold_across_re_add_and_resume_on_removal() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        sched.add(periodic("kept", 1));
+        sched.add(periodic("paused", 1));
+
+        let mut paused = PausedLabels::new();
+        paused.push("paused".try_into().unwrap()).unwrap();
+        sched.set_paused_labels(&paused);
+        sched.tick(None, 1.0, &mut delegate);
+        assert_eq!(delegate.fires.len(), 1);
+        assert_eq!(delegate.fires[0].0, "kept");
+
+        // A schedule added under a paused label starts paused.
+        let slot = sched.add(periodic("paused", 1)).unwrap();
+        assert!(!sched.iter().any(|(i, s)| i == slot && s.enabled));
+
+        sched.set_paused_labels(&PausedLabels::new());
+        sched.tick(None, 1.0, &mut delegate);
+        assert_eq!(delegate.fires.len(), 4);
+    }
+
+    #[test]
+    fn persisted_periodic_is_installed_replaced_and_removed() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        let hourly = PeriodicSchedule {
+            interval_secs: 3600,
+            duration_secs: 300,
+        };
+        sched.set_periodic(Some(hourly));
+        assert!(sched.has_label(PERIODIC_LABEL));
+        for _ in 0..100 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+
+        // Re-applying the same config must not restart the countdown.
+        sched.set_periodic(Some(hourly));
+        assert_eq!(sched.secs_until_next_fire(), Some(3500));
+
+        sched.set_periodic(Some(PeriodicSchedule {
+            interval_secs: 600,
+            ..hourly
+        }));
+        assert_eq!(sched.iter().count(), 1);
+        assert_eq!(sched.secs_until_next_fire(), Some(600));
+
+        sched.set_periodic(None);
+        assert!(!sched.has_label(PERIODIC_LABEL));
+    }
+
     #[test]
     fn oneshot_fires_once() {
         let mut sched = Scheduler::new();
@@ -411,6 +734,76 @@ mod tests {
         assert_eq!(sched.active_count(), 0);
     }
 
+    #[test]
+    fn iter_yields_occupied_slots() {
+        let mut sched = Scheduler::new();
+        sched.add(Schedule {
+            label: "a",
+            kind: ScheduleKind::OneShot { delay_secs: 5 },
+            enabled: true,
+        });
+        sched.add(Schedule {
+            label: "b",
+            kind: ScheduleKind::Boost { duration_secs: 60 },
+            enabled: false,
+        });
+        sched.remove(0);
+
+        let slots: Vec<(usize, &str)> = sched.iter().map(|(i, s)| (i, s.label)).collect();
+        assert_eq!(slots, vec![(1, "b")]);
+    }
+
+    #[test]
+    fn periodic_fires_are_counted_with_last_fire_time() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+
+        let slot = sched
+            .add(Schedule {
+                label: "nightly",
+                kind: ScheduleKind::Periodic {
+                    interval_secs: 10,
+                    duration_secs: 5,
+                },
+                enabled: true,
+            })
+            .unwrap();
+        assert_eq!(sched.history(slot), Some(ScheduleHistory::default()));
+
+        for _ in 0..35 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(delegate.fires.len(), 3);
+        let history = sched.history(slot).unwrap();
+        assert_eq!(history.fire_count, 3);
+        assert_eq!(history.last_fire_secs, Some(30));
+    }
+
+    #[test]
+    fn removing_a_schedule_drops_its_history() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+        let periodic = Schedule {
+            label: "p",
+            kind: ScheduleKind::Periodic {
+                interval_secs: 1,
+                duration_secs: 1,
+            },
+            enabled: true,
+        };
+
+        let slot = sched.add(periodic.clone()).unwrap();
+        for _ in 0..3 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(sched.history(slot).unwrap().fire_count, 3);
+
+        sched.remove(slot);
+        assert_eq!(sched.history(slot), None);
+        assert_eq!(sched.add(periodic), Some(slot));
+        assert_eq!(sched.history(slot), Some(ScheduleHistory::default()));
+    }
+
     #[test]
     fn disabled_scheduler_does_nothing() {
         let mut sched = Scheduler::new();
@@ -431,4 +824,50 @@ mod tests {
         }
         assert!(delegate.fires.is_empty());
     }
+    #[test]
+    fn paused_schedule_stops_firing_and_resumes_by_label() {
+        let mut sched = Scheduler::new();
+        let mut delegate = RecordingDelegate::new();
+
+        sched.add(Schedule {
+            label: "nightly",
+            kind: ScheduleKind::Periodic {
+                interval_secs: 5,
+                duration_secs: 1,
+            },
+            enabled: true,
+        });
+
+        for _ in 0..5 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(delegate.fires.len(), 1);
+
+        assert!(sched.set_schedule_enabled("nightly", false));
+        assert_eq!(sched.active_count(), 0);
+        for _ in 0..20 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(delegate.fires.len(), 1);
+
+        assert!(sched.set_schedule_enabled("nightly", true));
+        for _ in 0..5 {
+            sched.tick(None, 1.0, &mut delegate);
+        }
+        assert_eq!(delegate.fires.len(), 2);
+        assert_eq!(sched.history(0).unwrap().fire_count, 2);
+    }
+
+    #[test]
+    fn unknown_label_is_not_toggled() {
+        let mut sched = Scheduler::new();
+        sched.add(Schedule {
+            label: "nightly",
+            kind: ScheduleKind::OneShot { delay_secs: 5 },
+            enabled: true,
+        });
+
+        assert!(!sched.set_schedule_enabled("weekly", false));
+        assert_eq!(sched.active_count(), 1);
+    }
 }
@@ -2,18 +2,35 @@
 //!
 //! Reads the analog voltage output through an ESP32-S3 ADC channel,
 //! applies a two-point linear calibration, and maintains a fixed-size
-//! ring buffer for a running average.
+//! ring buffer for a running average.  Calibration and averaging run on
+//! the [`Num`] backend (see `control::numeric`).
+//!
+//! ## Oversampling
+//!
+//! Between control ticks the fast sensor tick calls [`AmmoniaSensor::sample`],
+//! which takes up to `nh3_oversample_count - 1` evenly spaced samples.
+//! [`AmmoniaSensor::read`] adds the control-tick sample and reports the
+//! window's mean (which feeds the running average) and peak, so a short
+//! spike between control ticks is still seen.
+//!
+//! ## Outlier rejection
+//!
+//! Each of those samples is itself a burst of `nh3_adc_samples` ADC
+//! conversions.  The highest and lowest are discarded and the rest
+//! averaged ([`trimmed_mean`]), so a single conversion hit by switching
+//! noise on the channel does not reach the ppm conversion.
 //!
 //! ## Dual-target design
 //!
-//! On ESP-IDF: reads ADC1_CH4 via the oneshot API (initialised by hw_init).
+//! On ESP-IDF: reads ADC1_CH4 (a second zone's sensor: CH3) via the
+//! oneshot API (initialised by hw_init).
 //! On host/test: reads from a static `AtomicU16` for injection.
 
 use core::sync::atomic::AtomicU16;
 #[cfg(not(target_os = "espidf"))]
 use core::sync::atomic::Ordering;
 
-#[cfg(target_os = "espidf")]
+use crate::control::numeric::{self, ControlNum, Num};
 use crate::drivers::hw_init;
 static SIM_NH3_ADC: AtomicU16 = AtomicU16::new(0);
 
@@ -24,11 +41,40 @@ pub fn sim_set_nh3_adc(raw: u16) {
 
 const RING_BUF_CAP: usize = 30;
 
+/// Upper bound on `nh3_oversample_count` (the 10 Hz sensor tick at the
+/// default 1 s control interval).
+pub const MAX_NH3_OVERSAMPLE: u8 = 10;
+
+/// Upper bound on `nh3_adc_samples`.
+pub const MAX_NH3_ADC_SAMPLES: u8 = 9;
+
+/// Mean of `samples` with the lowest and highest dropped, rounded to
+/// the nearest count.  Fewer than three samples are averaged as-is;
+/// `None` if there are none.  Sorts `samples` in place.
+pub fn trimmed_mean(samples: &mut [u16]) -> Option<u16> {
+    samples.sort_unstable();
+    let kept = match samples.len() {
+        0 => return None,
+        1 | 2 => &samples[..],
+        n => &samples[1..n - 1],
+    };
+    let n = kept.len() as u32;
+    let sum: u32 = kept.iter().map(|&r| u32::from(r)).sum();
+    Some(((sum + n / 2) / n) as u16)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Nh3Reading {
     pub raw: u16,
+    /// Mean of the samples taken since the previous read.
     pub ppm: f32,
+    /// Highest sample taken since the previous read.
+    pub peak_ppm: f32,
     pub avg_ppm: f32,
+    /// The control-tick ADC conversion succeeded.  On failure the rest
+    /// of the reading repeats the last good one and the hub does not
+    /// count the channel as updated.
+    pub adc_ok: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -50,76 +96,329 @@ impl Default for Calibration {
 
 pub struct AmmoniaSensor {
     cal: Calibration,
-    ring: [f32; RING_BUF_CAP],
+    /// `cal.span_ppm` converted once for the per-sample scaling.
+    span_ppm: Num,
+    ring: [Num; RING_BUF_CAP],
     head: usize,
     count: usize,
     total_reads: u32,
+    /// Samples per control interval (1 = no oversampling).
+    oversample: u8,
+    /// ADC conversions per sample (1 = no outlier rejection).
+    adc_samples: u8,
+    /// Fast ticks between oversamples.
+    stride: u32,
+    /// Fast ticks since the last read.
+    fast_ticks: u32,
+    window_sum: Num,
+    window_peak: Num,
+    window_count: u8,
+    /// Most recent successful control-tick reading.
+    last: Nh3Reading,
+    #[cfg(not(target_os = "espidf"))]
+    sim_adc_ok: bool,
     _adc_gpio: i32,
+    #[cfg_attr(not(target_os = "espidf"), allow(dead_code))]
+    adc_channel: u32,
 }
 
 impl AmmoniaSensor {
     pub fn new(adc_gpio: i32) -> Self {
         Self {
             cal: Calibration::default(),
-            ring: [0.0; RING_BUF_CAP],
+            span_ppm: Num::from_f32(Calibration::default().span_ppm),
+            ring: [Num::ZERO; RING_BUF_CAP],
             head: 0,
             count: 0,
             total_reads: 0,
+            oversample: 1,
+            adc_samples: 1,
+            stride: 1,
+            fast_ticks: 0,
+            window_sum: Num::ZERO,
+            window_peak: Num::ZERO,
+            window_count: 0,
+            last: Nh3Reading {
+                raw: 0,
+                ppm: 0.0,
+                peak_ppm: 0.0,
+                avg_ppm: 0.0,
+                adc_ok: false,
+            },
+            #[cfg(not(target_os = "espidf"))]
+            sim_adc_ok: true,
             _adc_gpio: adc_gpio,
+            adc_channel: hw_init::ADC1_CH_NH3,
         }
     }
 
+    /// Convert on ADC1 `channel` instead of zone 0's (a second filter
+    /// loop's sensor).
+    #[must_use]
+    pub fn on_adc_channel(self, channel: u32) -> Self {
+        Self {
+            adc_channel: channel,
+            ..self
+        }
+    }
+
+    /// Take `samples` readings per control interval, spread over the
+    /// `fast_ticks_per_interval` sensor ticks that fall within it.
+    pub fn set_oversample(&mut self, samples: u8, fast_ticks_per_interval: u32) {
+        self.oversample = samples.clamp(1, MAX_NH3_OVERSAMPLE);
+        self.stride = (fast_ticks_per_interval / u32::from(self.oversample)).max(1);
+    }
+
+    /// Take `samples` ADC conversions per sample and reject outliers
+    /// among them.
+    pub fn set_adc_samples(&mut self, samples: u8) {
+        self.adc_samples = samples.clamp(1, MAX_NH3_ADC_SAMPLES);
+    }
+
     pub fn set_calibration(&mut self, cal: Calibration) {
         self.cal = cal;
+        self.span_ppm = Num::from_f32(cal.span_ppm);
     }
 
     pub fn is_warmed_up(&self) -> bool {
         self.total_reads >= 600
     }
 
+    /// Fast-tick oversample.  Returns the sample taken (ppm), or `None`
+    /// when this tick falls between oversampling points or the
+    /// conversion failed.
+    pub fn sample(&mut self) -> Option<f32> {
+        if !self.sample_due() {
+            return None;
+        }
+        let raw = self.read_filtered()?;
+        Some(self.accumulate(raw))
+    }
+
+    /// Advance the fast-tick count; `true` if this tick takes a sample.
+    fn sample_due(&mut self) -> bool {
+        self.fast_ticks = self.fast_ticks.saturating_add(1);
+        // The control-tick read always takes the window's last sample.
+        self.fast_ticks % self.stride == 0 && self.window_count + 1 < self.oversample
+    }
+
+    /// Control-tick read: close the oversampling window and update the
+    /// running average with its mean.  If the conversion fails the
+    /// window stays open and the last good reading is returned with
+    /// `adc_ok: false`; no 0 count reaches the average.
     pub fn read(&mut self) -> Nh3Reading {
-        self.total_reads = self.total_reads.saturating_add(1);
+        match self.read_filtered() {
+            Some(raw) => {
+                self.last = self.read_with(raw);
+                self.last
+            }
+            None => Nh3Reading {
+                adc_ok: false,
+                ..self.last
+            },
+        }
+    }
 
-        let raw = self.read_adc();
-        let ppm = self.adc_to_ppm(raw);
+    /// Make simulated conversions on this sensor fail (`false`) or succeed.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn sim_set_adc_ok(&mut self, ok: bool) {
+        self.sim_adc_ok = ok;
+    }
 
-        self.ring[self.head] = ppm;
+    fn read_with(&mut self, raw: u16) -> Nh3Reading {
+        self.accumulate(raw);
+        let mean = self.window_sum / Num::from_count(u32::from(self.window_count));
+        let peak = self.window_peak;
+        self.window_sum = Num::ZERO;
+        self.window_peak = Num::ZERO;
+        self.window_count = 0;
+        self.fast_ticks = 0;
+
+        self.ring[self.head] = mean;
         self.head = (self.head + 1) % RING_BUF_CAP;
         if self.count < RING_BUF_CAP {
             self.count += 1;
         }
 
-        let avg_ppm = self.running_average();
-        Nh3Reading { raw, ppm, avg_ppm }
+        let avg_ppm = numeric::mean(&self.ring[..self.count]).to_f32();
+        Nh3Reading {
+            raw,
+            ppm: mean.to_f32(),
+            peak_ppm: peak.to_f32(),
+            avg_ppm,
+            adc_ok: true,
+        }
+    }
+
+    /// Add one sample to the window; returns it in ppm.
+    fn accumulate(&mut self, raw: u16) -> f32 {
+        self.total_reads = self.total_reads.saturating_add(1);
+        let ppm = self.adc_to_ppm(raw);
+        self.window_sum += ppm;
+        if self.window_count == 0 || ppm > self.window_peak {
+            self.window_peak = ppm;
+        }
+        self.window_count += 1;
+        ppm.to_f32()
+    }
+
+    /// One sample: a burst of conversions, outliers rejected.  Failed
+    /// conversions are left out; `None` only if all of them failed.
+    fn read_filtered(&self) -> Option<u16> {
+        self.burst(|| self.read_adc())
+    }
+
+    fn burst(&self, mut convert: impl FnMut() -> Option<u16>) -> Option<u16> {
+        let mut samples = [0u16; MAX_NH3_ADC_SAMPLES as usize];
+        let mut taken = 0;
+        for _ in 0..self.adc_samples {
+            if let Some(raw) = convert() {
+                samples[taken] = raw;
+                taken += 1;
+            }
+        }
+        trimmed_mean(&mut samples[..taken])
+    }
+
+    /// Sample the ADC without updating the running average (self-test).
+    /// A failed conversion reads 0, which the rail check rejects.
+    pub fn read_raw(&self) -> u16 {
+        self.read_adc().unwrap_or(0)
     }
 
     #[cfg(target_os = "espidf")]
-    fn read_adc(&self) -> u16 {
-        hw_init::adc1_read(hw_init::ADC1_CH_NH3)
+    fn read_adc(&self) -> Option<u16> {
+        hw_init::adc1_try_read(self.adc_channel)
     }
 
     #[cfg(not(target_os = "espidf"))]
-    fn read_adc(&self) -> u16 {
-        SIM_NH3_ADC.load(Ordering::Relaxed)
+    fn read_adc(&self) -> Option<u16> {
+        self.sim_adc_ok.then(|| SIM_NH3_ADC.load(Ordering::Relaxed))
     }
 
-    fn adc_to_ppm(&self, raw: u16) -> f32 {
-        if raw <= self.cal.zero_adc {
-            return 0.0;
-        }
-        let range = (self.cal.span_adc - self.cal.zero_adc) as f32;
-        if range <= 0.0 {
-            return 0.0;
+    fn adc_to_ppm(&self, raw: u16) -> Num {
+        if raw <= self.cal.zero_adc || self.cal.span_adc <= self.cal.zero_adc {
+            return Num::ZERO;
         }
-        let normalised = (raw - self.cal.zero_adc) as f32 / range;
-        (normalised * self.cal.span_ppm).max(0.0)
+        let range = Num::from_count(u32::from(self.cal.span_adc - self.cal.zero_adc));
+        let normalised = Num::from_count(u32::from(raw - self.cal.zero_adc)) / range;
+        let ppm = normalised * self.span_ppm;
+        if ppm > Num::ZERO { ppm } else { Num::ZERO }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Default calibration: 50 ppm across 1800 counts above 200.
+    const BASELINE: u16 = 560; // 10 ppm
+    const SPIKE: u16 = 2720; // 70 ppm
+
+    fn sensor(samples: u8) -> AmmoniaSensor {
+        let mut s = AmmoniaSensor::new(0);
+        s.set_oversample(samples, 10);
+        s
     }
 
-    fn running_average(&self) -> f32 {
-        if self.count == 0 {
-            return 0.0;
+    /// One control interval: ten fast ticks, the `spike_at`-th of which
+    /// sees `SPIKE`, then the control-tick read at baseline.
+    fn interval(s: &mut AmmoniaSensor, spike_at: Option<u32>) -> Nh3Reading {
+        for tick in 1..=10 {
+            let raw = if Some(tick) == spike_at {
+                SPIKE
+            } else {
+                BASELINE
+            };
+            if s.sample_due() {
+                s.accumulate(raw);
+            }
         }
-        let sum: f32 = self.ring[..self.count].iter().sum();
-        sum / self.count as f32
+        s.read_with(BASELINE)
+    }
+
+    #[test]
+    fn oversampling_peak_catches_spike_between_control_ticks() {
+        let mut s = sensor(5);
+        let r = interval(&mut s, Some(4));
+        assert!((r.peak_ppm - 70.0).abs() < 0.01, "peak {}", r.peak_ppm);
+        // Mean of four 10 ppm samples and one 70 ppm sample.
+        assert!((r.ppm - 22.0).abs() < 0.01, "mean {}", r.ppm);
+        assert!((r.avg_ppm - r.ppm).abs() < 0.01);
+    }
+
+    #[test]
+    fn single_sample_misses_spike_between_control_ticks() {
+        let mut s = sensor(1);
+        let r = interval(&mut s, Some(4));
+        assert!((r.peak_ppm - 10.0).abs() < 0.01);
+        assert!((r.ppm - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn window_resets_each_control_tick() {
+        let mut s = sensor(10);
+        assert!(interval(&mut s, Some(3)).peak_ppm > 60.0);
+        let r = interval(&mut s, None);
+        assert!((r.peak_ppm - 10.0).abs() < 0.01);
+        assert_eq!(s.window_count, 0);
+    }
+
+    #[test]
+    fn oversample_count_is_clamped() {
+        let mut s = sensor(0);
+        assert_eq!(s.oversample, 1);
+        s.set_oversample(200, 10);
+        assert_eq!(s.oversample, MAX_NH3_OVERSAMPLE);
+        assert_eq!(s.stride, 1);
+    }
+
+    #[test]
+    fn trimmed_mean_drops_highest_and_lowest() {
+        // One noise spike and one dropout around a steady 560.
+        let mut raw = [560, 4095, 558, 562, 0];
+        assert_eq!(trimmed_mean(&mut raw), Some(560));
+        let mut raw = [1000, 1001, 1003, 1002];
+        assert_eq!(trimmed_mean(&mut raw), Some(1002)); // 1001.5 rounds up
+    }
+
+    #[test]
+    fn trimmed_mean_averages_short_bursts_as_is() {
+        assert_eq!(trimmed_mean(&mut []), None);
+        assert_eq!(trimmed_mean(&mut [700]), Some(700));
+        assert_eq!(trimmed_mean(&mut [700, 703]), Some(702));
+    }
+
+    #[test]
+    fn burst_rejects_outlier_conversions() {
+        let mut s = sensor(1);
+        s.set_adc_samples(5);
+        let mut seq = [BASELINE, BASELINE + 4, SPIKE, BASELINE - 4, BASELINE - 8].into_iter();
+        let raw = s.burst(|| seq.next()).unwrap();
+        assert_eq!(raw, BASELINE);
+        let r = s.read_with(raw);
+        assert!((r.ppm - 10.0).abs() < 0.01, "ppm {}", r.ppm);
+
+        // Unfiltered, the spike would have dragged the sample up.
+        s.set_adc_samples(1);
+        assert_eq!(s.burst(|| Some(SPIKE)), Some(SPIKE));
+    }
+
+    #[test]
+    fn burst_skips_failed_conversions() {
+        let mut s = sensor(1);
+        s.set_adc_samples(4);
+        let mut seq = [None, Some(600), None, Some(604)].into_iter();
+        assert_eq!(s.burst(|| seq.next().flatten()), Some(602));
+        assert_eq!(s.burst(|| None), None);
+    }
+
+    #[test]
+    fn adc_sample_count_is_clamped() {
+        let mut s = sensor(1);
+        s.set_adc_samples(0);
+        assert_eq!(s.adc_samples, 1);
+        s.set_adc_samples(50);
+        assert_eq!(s.adc_samples, MAX_NH3_ADC_SAMPLES);
     }
 }
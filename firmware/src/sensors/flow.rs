@@ -7,6 +7,12 @@
 //! Because the ISR and the main loop run on different cores (or at least
 //! different priorities), the counter uses `AtomicU32` for lock-free
 //! thread safety — the correct pattern for shared ISR state on ESP32.
+//!
+//! Pump EMI and contact bounce on the pulse line can produce edges far
+//! closer together than the sensor ever emits (~125 Hz at its 16 L/min
+//! maximum, i.e. 8 ms).  The ISR therefore drops any edge that arrives
+//! less than `min_pulse_us` after the last accepted one — the software
+//! counterpart of the hardware pin glitch filter enabled in `hw_init`.
 
 use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
@@ -15,23 +21,82 @@ use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 /// → 450 pulses/min at 1 L/min → 450 pulses per litre.
 const PULSES_PER_LITRE: f32 = 450.0;
 
-/// Global atomic counter incremented by the GPIO ISR.
-/// `static` because ISR callbacks in ESP-IDF cannot capture closures.
-static FLOW_PULSE_COUNT: AtomicU32 = AtomicU32::new(0);
-static FLOW_EVENT_LATCH: AtomicBool = AtomicBool::new(false);
+/// Pulse count and glitch-filter state of one flow sensor, shared with
+/// its GPIO ISR.  Lives in a `static` because ISR callbacks in ESP-IDF
+/// cannot capture closures.
+pub struct PulseCounter {
+    count: AtomicU32,
+    event_latch: AtomicBool,
+    /// Minimum spacing between accepted pulses (µs); 0 disables the filter.
+    min_pulse_us: AtomicU32,
+    /// Timestamp (wrapping µs) of the last accepted pulse.
+    last_pulse_us: AtomicU32,
+    pulse_seen: AtomicBool,
+}
+
+impl Default for PulseCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PulseCounter {
+    pub const fn new() -> Self {
+        Self {
+            count: AtomicU32::new(0),
+            event_latch: AtomicBool::new(false),
+            min_pulse_us: AtomicU32::new(0),
+            last_pulse_us: AtomicU32::new(0),
+            pulse_seen: AtomicBool::new(false),
+        }
+    }
 
-/// Called from the GPIO ISR on each rising edge.
+    /// Count one rising edge seen at (wrapping) `now_us`.  Returns `true`
+    /// when the caller should queue a wake event.
+    pub fn on_pulse(&self, now_us: u32) -> bool {
+        let min_us = self.min_pulse_us.load(Ordering::Relaxed);
+        if min_us > 0 {
+            let last = self.last_pulse_us.load(Ordering::Relaxed);
+            if self.pulse_seen.load(Ordering::Relaxed) && now_us.wrapping_sub(last) < min_us {
+                return false;
+            }
+            self.last_pulse_us.store(now_us, Ordering::Relaxed);
+            self.pulse_seen.store(true, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        // Only request one queued wake until main loop drains the pulse bucket.
+        !self.event_latch.swap(true, Ordering::AcqRel)
+    }
+
+    fn clear_event_latch(&self) {
+        self.event_latch.store(false, Ordering::Release);
+    }
+}
+
+/// Flow sensor of zone 0.
+pub static FLOW_PULSES: PulseCounter = PulseCounter::new();
+/// Flow sensor of the second filter loop (`pins::zone_b`).
+pub static FLOW_B_PULSES: PulseCounter = PulseCounter::new();
+
+/// Called from the GPIO ISR on each rising edge with the current
+/// (wrapping) microsecond timestamp.  Returns `true` when the caller
+/// should queue a wake event.
+///
 /// Must be `extern "C"` and `#[no_mangle]` compatible when registered
 /// via `esp_idf_hal::gpio::PinDriver::set_interrupt`.
-pub fn flow_isr_handler() -> bool {
-    FLOW_PULSE_COUNT.fetch_add(1, Ordering::Relaxed);
-    // Only request one queued wake until main loop drains the pulse bucket.
-    !FLOW_EVENT_LATCH.swap(true, Ordering::AcqRel)
+pub fn flow_isr_handler(now_us: u32) -> bool {
+    FLOW_PULSES.on_pulse(now_us)
+}
+
+/// [`flow_isr_handler`] for the second filter loop's sensor.
+pub fn flow_b_isr_handler(now_us: u32) -> bool {
+    FLOW_B_PULSES.on_pulse(now_us)
 }
 
-/// Clear the flow wake latch after the main loop consumes pending pulses.
+/// Clear the flow wake latches after the main loop consumes pending pulses.
 pub fn flow_clear_event_latch() {
-    FLOW_EVENT_LATCH.store(false, Ordering::Release);
+    FLOW_PULSES.clear_event_latch();
+    FLOW_B_PULSES.clear_event_latch();
 }
 
 /// Result of a flow measurement.
@@ -51,6 +116,8 @@ pub struct FlowSensor {
     min_flow_threshold: f32,
     /// GPIO pin number (stored for diagnostics / re-init).
     _gpio: i32,
+    /// Counter the sensor's ISR feeds.
+    pulses: &'static PulseCounter,
 }
 
 impl FlowSensor {
@@ -58,16 +125,23 @@ impl FlowSensor {
         Self {
             min_flow_threshold: 50.0,
             _gpio: gpio,
+            pulses: &FLOW_PULSES,
         }
     }
 
+    /// Read `pulses` instead of zone 0's counter.
+    #[must_use]
+    pub fn on_counter(self, pulses: &'static PulseCounter) -> Self {
+        Self { pulses, ..self }
+    }
+
     /// Sample the atomic pulse counter, reset it, and compute flow rate.
     ///
     /// `elapsed_secs` is the time since the last call (typically 0.1 s at
     /// 10 Hz or 1.0 s at 1 Hz).
     pub fn read(&mut self, elapsed_secs: f32) -> FlowReading {
         // Atomically swap the counter to zero and read the old value.
-        let count = FLOW_PULSE_COUNT.swap(0, Ordering::Relaxed);
+        let count = self.pulses.count.swap(0, Ordering::Relaxed);
 
         let pulses_per_min = if elapsed_secs > 0.0 {
             count as f32 / elapsed_secs * 60.0
@@ -90,4 +164,76 @@ impl FlowSensor {
     pub fn set_min_flow_threshold(&mut self, ml_per_min: f32) {
         self.min_flow_threshold = ml_per_min;
     }
+
+    /// Reject pulses closer than `min_pulse_us` to the previous accepted
+    /// pulse.  0 turns the filter off.
+    pub fn set_glitch_filter_us(&mut self, min_pulse_us: u16) {
+        self.pulses
+            .min_pulse_us
+            .store(u32::from(min_pulse_us), Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// The pulse counter is a process-wide static; serialise the tests
+    /// that drive it.
+    static ISR_LOCK: Mutex<()> = Mutex::new(());
+
+    fn fresh_sensor(min_pulse_us: u16) -> FlowSensor {
+        let mut s = FlowSensor::new(0);
+        s.set_glitch_filter_us(min_pulse_us);
+        FLOW_PULSES.pulse_seen.store(false, Ordering::Relaxed);
+        FLOW_PULSES.count.store(0, Ordering::Relaxed);
+        flow_clear_event_latch();
+        s
+    }
+
+    #[test]
+    fn burst_of_close_pulses_counts_once() {
+        let _guard = ISR_LOCK.lock().unwrap();
+        let mut s = fresh_sensor(1000);
+        for t in (10_000..10_900).step_by(100) {
+            flow_isr_handler(t);
+        }
+        assert_eq!(s.read(1.0).pulse_count, 1);
+    }
+
+    #[test]
+    fn spaced_pulses_count_individually() {
+        let _guard = ISR_LOCK.lock().unwrap();
+        let mut s = fresh_sensor(1000);
+        // Timestamps straddle the u32 wrap.
+        let start = u32::MAX - 2_500;
+        for i in 0..5 {
+            flow_isr_handler(start.wrapping_add(i * 8_000));
+        }
+        assert_eq!(s.read(1.0).pulse_count, 5);
+    }
+
+    #[test]
+    fn zero_width_disables_filter() {
+        let _guard = ISR_LOCK.lock().unwrap();
+        let mut s = fresh_sensor(0);
+        for _ in 0..4 {
+            flow_isr_handler(42);
+        }
+        assert_eq!(s.read(1.0).pulse_count, 4);
+    }
+
+    #[test]
+    fn second_zone_counts_its_own_pulses() {
+        let _guard = ISR_LOCK.lock().unwrap();
+        let mut a = fresh_sensor(0);
+        let mut b = FlowSensor::new(0).on_counter(&FLOW_B_PULSES);
+        FLOW_B_PULSES.count.store(0, Ordering::Relaxed);
+        flow_b_isr_handler(10);
+        flow_b_isr_handler(20);
+        flow_isr_handler(30);
+        assert_eq!(b.read(1.0).pulse_count, 2);
+        assert_eq!(a.read(1.0).pulse_count, 1);
+    }
 }
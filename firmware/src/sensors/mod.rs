@@ -10,6 +10,8 @@ pub mod water_level;
 
 use core::sync::atomic::{AtomicBool, Ordering};
 
+use crate::drivers::hw_init;
+use crate::error::SensorError;
 use crate::fsm::context::SensorSnapshot;
 use ammonia::AmmoniaSensor;
 use flow::FlowSensor;
@@ -21,12 +23,108 @@ use water_level::WaterLevelSensor;
 /// (lid magnet present = safe); `false` = open (fault condition).
 static INTERLOCK_CLOSED_ATOMIC: AtomicBool = AtomicBool::new(false);
 
+/// Interlock polarity: `true` = LOW means closed (the default wiring),
+/// `false` = HIGH means closed.  Mirrors `interlock_active_low`.
+static INTERLOCK_ACTIVE_LOW: AtomicBool = AtomicBool::new(true);
+
 /// Update the interlock state from an ISR or boot-time GPIO read.
 /// Lock-free — safe to call from interrupt context.
 pub fn set_interlock_from_isr(closed: bool) {
     INTERLOCK_CLOSED_ATOMIC.store(closed, Ordering::Release);
 }
 
+/// Whether an interlock GPIO level (`true` = HIGH) means the lid is
+/// closed under the configured polarity.  Safe in interrupt context.
+pub fn interlock_closed_at(level_high: bool) -> bool {
+    level_high != INTERLOCK_ACTIVE_LOW.load(Ordering::Acquire)
+}
+
+/// Set the interlock polarity.  On a change the cached state is
+/// re-read from the pin, since it was interpreted the other way.
+pub fn set_interlock_active_low(active_low: bool) {
+    if INTERLOCK_ACTIVE_LOW.swap(active_low, Ordering::AcqRel) != active_low {
+        let level = hw_init::gpio_read(crate::pins::UVC_INTERLOCK_GPIO);
+        set_interlock_from_isr(interlock_closed_at(level));
+    }
+}
+
+/// Raw 12-bit ADC counts at or beyond these limits mean the input is
+/// shorted to a rail or the sensor is disconnected.
+const ADC_RAIL_LOW: u16 = 16;
+const ADC_RAIL_HIGH: u16 = 4080;
+
+/// Flow pulses tolerated with the pump stopped — more means the pulse
+/// line is floating or picking up noise.
+const FLOW_IDLE_MAX_PULSES: u32 = 2;
+
+// ───────────────────────────────────────────────────────────────
+// Startup self-test
+// ───────────────────────────────────────────────────────────────
+
+/// Per-sensor outcome of [`SensorHub::self_test`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelfTestReport {
+    pub ammonia: Result<(), SensorError>,
+    pub temperature: Result<(), SensorError>,
+    pub flow: Result<(), SensorError>,
+    pub interlock: Result<(), SensorError>,
+}
+
+impl Default for SelfTestReport {
+    fn default() -> Self {
+        Self {
+            ammonia: Ok(()),
+            temperature: Ok(()),
+            flow: Ok(()),
+            interlock: Ok(()),
+        }
+    }
+}
+
+impl SelfTestReport {
+    /// `true` if every sensor passed.
+    pub fn passed(&self) -> bool {
+        self.ammonia.is_ok()
+            && self.temperature.is_ok()
+            && self.flow.is_ok()
+            && self.interlock.is_ok()
+    }
+
+    /// `true` if a sensor the safety supervisor relies on failed —
+    /// temperature (thermal shutdown) or the UVC lid interlock.
+    pub fn critical_failure(&self) -> bool {
+        self.temperature.is_err() || self.interlock.is_err()
+    }
+}
+
+/// A raw ADC sample pinned to either rail means a short or open input.
+pub fn check_adc_raw(raw: u16) -> Result<(), SensorError> {
+    if raw <= ADC_RAIL_LOW || raw >= ADC_RAIL_HIGH {
+        Err(SensorError::OutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// With the pump stopped the flow pulse line must be quiet.
+pub fn check_flow_idle(pulse_count: u32) -> Result<(), SensorError> {
+    if pulse_count > FLOW_IDLE_MAX_PULSES {
+        Err(SensorError::OutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// The ISR-cached interlock state must agree with a direct GPIO read;
+/// a mismatch means edges are not reaching the ISR.
+pub fn check_interlock(cached_closed: bool, live_closed: bool) -> Result<(), SensorError> {
+    if cached_closed == live_closed {
+        Ok(())
+    } else {
+        Err(SensorError::GpioReadFailed)
+    }
+}
+
 /// Aggregates all sensor drivers and produces a unified snapshot.
 pub struct SensorHub {
     pub ammonia: AmmoniaSensor,
@@ -36,6 +134,8 @@ pub struct SensorHub {
     /// Cached UVC interlock state (read from GPIO).
     interlock_closed: bool,
     interlock_gpio: i32,
+    nh3_updated_ms: u64,
+    temperature_updated_ms: u64,
 }
 
 impl SensorHub {
@@ -55,6 +155,8 @@ impl SensorHub {
             temperature,
             interlock_closed: false,
             interlock_gpio,
+            nh3_updated_ms: 0,
+            temperature_updated_ms: 0,
         }
     }
 
@@ -67,11 +169,26 @@ impl SensorHub {
     ///
     /// Individual read failures are logged and the previous good value is
     /// retained — a single flaky sensor must not crash the control loop.
+    /// The NH3 and temperature channels are stamped only when their ADC
+    /// conversion succeeds, so a wedged driver shows up as a stale channel.
     pub fn read_all(&mut self, elapsed_secs: f32) -> SensorSnapshot {
+        self.read_all_at(monotonic_ms(), elapsed_secs)
+    }
+
+    /// [`read_all`](Self::read_all) with the snapshot stamped `now_ms`.
+    /// The stamps come from the monotonic clock, not the sum of
+    /// `elapsed_secs`, so a late or skipped tick still ages a channel.
+    fn read_all_at(&mut self, now_ms: u64, elapsed_secs: f32) -> SensorSnapshot {
         let nh3 = self.ammonia.read();
         let flow = self.flow.read(elapsed_secs);
         let (level_a, level_b) = self.water_level.read();
         let temp = self.temperature.read();
+        if nh3.adc_ok {
+            self.nh3_updated_ms = now_ms;
+        }
+        if temp.adc_ok {
+            self.temperature_updated_ms = now_ms;
+        }
 
         // Read the interlock state from the ISR-maintained atomic.
         // set_interlock_from_isr() is called on every GPIO edge and once
@@ -80,6 +197,7 @@ impl SensorHub {
 
         SensorSnapshot {
             nh3_ppm: nh3.ppm,
+            nh3_peak_ppm: nh3.peak_ppm,
             nh3_avg_ppm: nh3.avg_ppm,
             nh3_raw: nh3.raw,
             flow_ml_per_min: flow.flow_ml_per_min,
@@ -89,6 +207,26 @@ impl SensorHub {
             temperature_c: temp.celsius,
             over_temperature: temp.over_temp,
             uvc_interlock_closed: self.interlock_closed,
+            timestamp_ms: now_ms,
+            nh3_updated_ms: self.nh3_updated_ms,
+            temperature_updated_ms: self.temperature_updated_ms,
+        }
+    }
+
+    /// Read each sensor once and check the raw values are electrically
+    /// plausible.  Run at boot, before the pump has been started.
+    pub fn self_test(&mut self) -> SelfTestReport {
+        let nh3_raw = self.ammonia.read_raw();
+        let temp_raw = self.temperature.read().raw;
+        let flow = self.flow.read(1.0);
+        let cached = INTERLOCK_CLOSED_ATOMIC.load(Ordering::Acquire);
+        let live = interlock_closed_at(hw_init::gpio_read(self.interlock_gpio));
+
+        SelfTestReport {
+            ammonia: check_adc_raw(nh3_raw),
+            temperature: check_adc_raw(temp_raw),
+            flow: check_flow_idle(flow.pulse_count),
+            interlock: check_interlock(cached, live),
         }
     }
 
@@ -97,3 +235,165 @@ impl SensorHub {
         self.interlock_closed = closed;
     }
 }
+
+/// Milliseconds on the monotonic clock since boot.
+#[cfg(target_os = "espidf")]
+fn monotonic_ms() -> u64 {
+    // SAFETY: esp_timer_get_time only reads the system timer.
+    (unsafe { esp_idf_svc::sys::esp_timer_get_time() } / 1_000) as u64
+}
+
+#[cfg(not(target_os = "espidf"))]
+fn monotonic_ms() -> u64 {
+    use std::time::Instant;
+    static START: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serialises tests that change the process-wide interlock polarity.
+    static INTERLOCK_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn adc_at_rails_fails() {
+        assert_eq!(check_adc_raw(0), Err(SensorError::OutOfRange));
+        assert_eq!(check_adc_raw(ADC_RAIL_LOW), Err(SensorError::OutOfRange));
+        assert_eq!(check_adc_raw(ADC_RAIL_HIGH), Err(SensorError::OutOfRange));
+        assert_eq!(check_adc_raw(4095), Err(SensorError::OutOfRange));
+    }
+
+    #[test]
+    fn adc_mid_range_passes() {
+        assert!(check_adc_raw(ADC_RAIL_LOW + 1).is_ok());
+        assert!(check_adc_raw(2048).is_ok());
+        assert!(check_adc_raw(ADC_RAIL_HIGH - 1).is_ok());
+    }
+
+    #[test]
+    fn flow_line_must_be_quiet_at_rest() {
+        assert!(check_flow_idle(0).is_ok());
+        assert!(check_flow_idle(FLOW_IDLE_MAX_PULSES).is_ok());
+        assert_eq!(
+            check_flow_idle(FLOW_IDLE_MAX_PULSES + 1),
+            Err(SensorError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn failed_conversions_leave_channel_stamp_behind() {
+        let mut hub = SensorHub::new(
+            AmmoniaSensor::new(0),
+            FlowSensor::new(0),
+            WaterLevelSensor::new(0, 0),
+            TemperatureSensor::new(0, 80.0),
+            0,
+        );
+        let good = hub.read_all_at(1000, 1.0);
+        hub.ammonia.sim_set_adc_ok(false);
+        hub.temperature.sim_set_adc_ok(false);
+        hub.read_all_at(2000, 1.0);
+        // The stamp follows the clock, not the reported interval.
+        let snap = hub.read_all_at(5000, 1.0);
+
+        assert_eq!(snap.timestamp_ms, 5000);
+        assert_eq!(snap.age_ms(snap.nh3_updated_ms), 4000);
+        assert_eq!(snap.age_ms(snap.temperature_updated_ms), 4000);
+        // Failed conversions hold the last good values instead of 0.
+        assert_eq!(snap.nh3_raw, good.nh3_raw);
+        assert_eq!(snap.temperature_c.to_bits(), good.temperature_c.to_bits());
+
+        hub.ammonia.sim_set_adc_ok(true);
+        let snap = hub.read_all_at(6000, 1.0);
+        assert_eq!(snap.age_ms(snap.nh3_updated_ms), 0);
+        assert_eq!(snap.age_ms(snap.temperature_updated_ms), 5000);
+    }
+
+    #[test]
+    fn interlock_mismatch_fails() {
+        assert!(check_interlock(true, true).is_ok());
+        assert!(check_interlock(false, false).is_ok());
+        assert_eq!(
+            check_interlock(false, true),
+            Err(SensorError::GpioReadFailed)
+        );
+    }
+
+    #[test]
+    fn interlock_level_follows_polarity() {
+        let _guard = INTERLOCK_TEST_LOCK.lock().unwrap();
+        assert!(interlock_closed_at(false));
+        assert!(!interlock_closed_at(true));
+
+        set_interlock_active_low(false);
+        let inverted = (interlock_closed_at(true), interlock_closed_at(false));
+        set_interlock_active_low(true);
+        assert_eq!(inverted, (true, false));
+    }
+
+    #[test]
+    fn inverted_interlock_reads_high_as_closed() {
+        use crate::config::SystemConfig;
+        use crate::error::SafetyFault;
+        use crate::safety;
+
+        let _guard = INTERLOCK_TEST_LOCK.lock().unwrap();
+        let cfg = SystemConfig::default();
+        let mut hub = SensorHub::new(
+            AmmoniaSensor::new(0),
+            FlowSensor::new(0),
+            WaterLevelSensor::new(0, 0),
+            TemperatureSensor::new(0, 80.0),
+            0,
+        );
+        let interlock_fault = |snap: &SensorSnapshot| {
+            safety::evaluate(snap, &cfg, 0) & SafetyFault::UvcInterlockOpen.mask() != 0
+        };
+
+        // The simulated pin reads HIGH: open with the default wiring ...
+        crate::drivers::hw_init::init_isr_service(true).unwrap();
+        let normal = hub.read_all(1.0);
+        assert!(!normal.uvc_interlock_closed);
+        assert!(interlock_fault(&normal));
+
+        // ... closed once inverted, with the cached state re-seeded so
+        // the self-test still agrees with the pin.
+        set_interlock_active_low(false);
+        let inverted = hub.read_all(1.0);
+        assert!(inverted.uvc_interlock_closed);
+        assert!(!interlock_fault(&inverted));
+        assert!(hub.self_test().interlock.is_ok());
+
+        set_interlock_active_low(true);
+        assert!(!hub.read_all(1.0).uvc_interlock_closed);
+        assert!(hub.self_test().interlock.is_ok());
+    }
+
+    #[test]
+    fn report_classifies_critical_failures() {
+        let ok = SelfTestReport::default();
+        assert!(ok.passed());
+        assert!(!ok.critical_failure());
+
+        let nh3_railed = SelfTestReport {
+            ammonia: check_adc_raw(4095),
+            ..Default::default()
+        };
+        assert!(!nh3_railed.passed());
+        assert!(!nh3_railed.critical_failure());
+
+        let temp_railed = SelfTestReport {
+            temperature: check_adc_raw(0),
+            ..Default::default()
+        };
+        assert!(temp_railed.critical_failure());
+
+        let interlock_stuck = SelfTestReport {
+            interlock: check_interlock(false, true),
+            ..Default::default()
+        };
+        assert!(interlock_stuck.critical_failure());
+    }
+}
@@ -34,10 +34,17 @@ pub struct TemperatureReading {
     pub raw: u16,
     pub celsius: f32,
     pub over_temp: bool,
+    /// The ADC conversion succeeded.  When it did not, the rest of the
+    /// reading repeats the last good one.
+    pub adc_ok: bool,
 }
 
 pub struct TemperatureSensor {
     threshold_c: f32,
+    /// Most recent successful reading, repeated while conversions fail.
+    last: TemperatureReading,
+    #[cfg(not(target_os = "espidf"))]
+    sim_adc_ok: bool,
     _adc_gpio: i32,
 }
 
@@ -45,28 +52,53 @@ impl TemperatureSensor {
     pub fn new(adc_gpio: i32, over_temp_threshold_c: f32) -> Self {
         Self {
             threshold_c: over_temp_threshold_c,
+            last: TemperatureReading {
+                raw: 0,
+                celsius: -40.0,
+                over_temp: false,
+                adc_ok: false,
+            },
+            #[cfg(not(target_os = "espidf"))]
+            sim_adc_ok: true,
             _adc_gpio: adc_gpio,
         }
     }
 
-    pub fn read(&self) -> TemperatureReading {
-        let raw = self.read_adc();
+    /// Convert the thermistor.  A failed conversion returns the last
+    /// good reading flagged `adc_ok: false` rather than a bogus 0 count
+    /// (which would read as -40 °C and hide an over-temperature).
+    pub fn read(&mut self) -> TemperatureReading {
+        let Some(raw) = self.read_adc() else {
+            return TemperatureReading {
+                adc_ok: false,
+                ..self.last
+            };
+        };
         let celsius = self.adc_to_celsius(raw);
-        TemperatureReading {
+        self.last = TemperatureReading {
             raw,
             celsius,
             over_temp: celsius > self.threshold_c,
-        }
+            adc_ok: true,
+        };
+        self.last
+    }
+
+    /// Make simulated conversions on this sensor fail (`false`) or succeed.
+    #[cfg(not(target_os = "espidf"))]
+    pub fn sim_set_adc_ok(&mut self, ok: bool) {
+        self.sim_adc_ok = ok;
     }
 
     #[cfg(target_os = "espidf")]
-    fn read_adc(&self) -> u16 {
-        hw_init::adc1_read(hw_init::ADC1_CH_TEMP)
+    fn read_adc(&self) -> Option<u16> {
+        hw_init::adc1_try_read(hw_init::ADC1_CH_TEMP)
     }
 
     #[cfg(not(target_os = "espidf"))]
-    fn read_adc(&self) -> u16 {
-        SIM_TEMP_ADC.load(Ordering::Relaxed)
+    fn read_adc(&self) -> Option<u16> {
+        self.sim_adc_ok
+            .then(|| SIM_TEMP_ADC.load(Ordering::Relaxed))
     }
 
     fn adc_to_celsius(&self, raw: u16) -> f32 {
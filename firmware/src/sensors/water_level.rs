@@ -14,8 +14,6 @@ use core::sync::atomic::Ordering;
 
 #[cfg(target_os = "espidf")]
 use crate::drivers::hw_init;
-#[cfg(target_os = "espidf")]
-use crate::pins;
 static SIM_WATER_A: AtomicBool = AtomicBool::new(true);
 static SIM_WATER_B: AtomicBool = AtomicBool::new(true);
 
@@ -42,8 +40,10 @@ pub struct WaterLevelReading {
 }
 
 pub struct WaterLevelSensor {
-    _gpio_a: i32,
-    _gpio_b: i32,
+    #[cfg_attr(not(target_os = "espidf"), allow(dead_code))]
+    gpio_a: i32,
+    #[cfg_attr(not(target_os = "espidf"), allow(dead_code))]
+    gpio_b: i32,
     last_a: bool,
     last_b: bool,
 }
@@ -51,8 +51,8 @@ pub struct WaterLevelSensor {
 impl WaterLevelSensor {
     pub fn new(gpio_a: i32, gpio_b: i32) -> Self {
         Self {
-            _gpio_a: gpio_a,
-            _gpio_b: gpio_b,
+            gpio_a,
+            gpio_b,
             last_a: true,
             last_b: true,
         }
@@ -76,7 +76,7 @@ impl WaterLevelSensor {
 
     #[cfg(target_os = "espidf")]
     fn read_gpio_a(&self) -> bool {
-        hw_init::gpio_read(pins::WATER_LEVEL_A_GPIO)
+        hw_init::gpio_read(self.gpio_a)
     }
 
     #[cfg(not(target_os = "espidf"))]
@@ -86,7 +86,7 @@ impl WaterLevelSensor {
 
     #[cfg(target_os = "espidf")]
     fn read_gpio_b(&self) -> bool {
-        hw_init::gpio_read(pins::WATER_LEVEL_B_GPIO)
+        hw_init::gpio_read(self.gpio_b)
     }
 
     #[cfg(not(target_os = "espidf"))]
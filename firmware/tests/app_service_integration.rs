@@ -1,6 +1,6 @@
 //! Integration tests: AppService → FSM → actuators (QA-7b/7c/7e/7f).
 
-use petfilter::app::commands::AppCommand;
+use petfilter::app::commands::{AppCommand, CommandSource};
 use petfilter::app::events::AppEvent;
 use petfilter::app::ports::{
     ActuatorPort, ConfigError, ConfigPort, EventSink, SensorPort, StorageError, StoragePort,
@@ -37,6 +37,7 @@ impl SensorPort for MockHw {
     fn read_all(&mut self, _elapsed_secs: f32) -> SensorSnapshot {
         SensorSnapshot {
             nh3_ppm: 0.0,
+            nh3_peak_ppm: 0.0,
             nh3_avg_ppm: 0.0,
             nh3_raw: 0,
             flow_ml_per_min: 100.0,
@@ -46,10 +47,13 @@ impl SensorPort for MockHw {
             temperature_c: 25.0,
             over_temperature: false,
             uvc_interlock_closed: true,
+            timestamp_ms: 0,
+            nh3_updated_ms: 0,
+            temperature_updated_ms: 0,
         }
     }
-    fn read_ammonia_fast(&mut self) -> f32 {
-        0.0
+    fn read_ammonia_fast(&mut self) -> Option<f32> {
+        None
     }
 }
 
@@ -157,7 +161,11 @@ fn make_app() -> (AppService, MockHw, LogSink) {
 fn start_scrub_leaves_idle_and_commands_actuators() {
     let (mut app, mut hw, mut sink) = make_app();
     assert_eq!(app.state(), StateId::Idle);
-    app.handle_command(AppCommand::StartScrub, &mut hw, &mut sink);
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
     assert_ne!(
         app.state(),
         StateId::Idle,
@@ -176,7 +184,7 @@ fn update_config_marks_dirty() {
         pump_duty_percent: 75,
         ..Default::default()
     };
-    app.handle_command(AppCommand::UpdateConfig(cfg), &mut hw, &mut sink);
+    app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink);
     assert!(
         app.is_config_dirty(),
         "dirty flag must be set after UpdateConfig"
@@ -192,12 +200,12 @@ fn auto_save_fires_after_dirty_timeout() {
         purge_duration_secs: 42,
         ..Default::default()
     };
-    app.handle_command(AppCommand::UpdateConfig(cfg), &mut hw, &mut sink);
+    app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink);
     assert!(app.is_config_dirty());
 
     let nvs = MockNvs::new();
     for _ in 0..65 {
-        app.tick(&mut hw, &mut sink);
+        app.tick(&mut hw, &mut sink, 1.0);
     }
     let saved = app.auto_save_if_needed(&nvs);
     assert!(
@@ -4,12 +4,25 @@
 //! chain from an incoming RPC command down to an actuator call works
 //! correctly without any real hardware.
 
-use crate::mock_hw::{LogSink, MockHardware, MockNvs};
+use crate::mock_hw::{ActuatorCall, LogSink, MockHardware, MockNvs};
 
-use petfilter::app::commands::AppCommand;
-use petfilter::app::service::AppService;
-use petfilter::config::SystemConfig;
+use petfilter::adapters::nvs::NvsAdapter;
+use petfilter::app::commands::{AppCommand, CommandSource};
+use petfilter::app::ports::ConfigPort;
+use petfilter::app::service::{
+    AppService, MAX_NH3_INJECT_SECS, MAX_TEST_FAULT_SECS, TELEMETRY_HISTORY_INTERVAL_MS,
+    TELEMETRY_HISTORY_LEN,
+};
+use petfilter::config::{SystemConfig, TankSource};
+use petfilter::control::autotune::TuneStatus;
+use petfilter::error::{Advisory, SafetyFault};
+use petfilter::events::{self, Event};
 use petfilter::fsm::StateId;
+use petfilter::fsm::context::Tank;
+use petfilter::power;
+use petfilter::rpc::engine::RpcEngine;
+use petfilter::scheduler::QuietHours;
+use petfilter::sensors::{SelfTestReport, check_adc_raw, check_interlock};
 
 fn make_app() -> (AppService, MockHardware, LogSink) {
     let config = SystemConfig::default();
@@ -28,7 +41,11 @@ fn start_scrub_transitions_to_active_and_commands_pump() {
 
     assert_eq!(app.state(), StateId::Idle, "should start in Idle");
 
-    app.handle_command(AppCommand::StartScrub, &mut hw, &mut sink);
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
 
     // The FSM should enter Active or at least move away from Idle.
     // (It may pass through Sensing first depending on config thresholds)
@@ -58,7 +75,11 @@ fn update_config_marks_config_dirty() {
         pump_duty_percent: 75,
         ..Default::default()
     };
-    app.handle_command(AppCommand::UpdateConfig(new_cfg), &mut hw, &mut sink);
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(new_cfg)),
+        &mut hw,
+        &mut sink,
+    );
 
     assert!(
         app.is_config_dirty(),
@@ -76,7 +97,7 @@ fn save_config_clears_dirty_flag() {
         pump_duty_percent: 80,
         ..Default::default()
     };
-    app.handle_command(AppCommand::UpdateConfig(cfg), &mut hw, &mut sink);
+    app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink);
     assert!(app.is_config_dirty());
 
     app.handle_command(AppCommand::SaveConfig, &mut hw, &mut sink);
@@ -90,6 +111,100 @@ fn save_config_clears_dirty_flag() {
     let _ = saved;
 }
 
+#[test]
+fn config_changes_rejected_in_config_safe_mode() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.raise_advisory(Advisory::ConfigSafeMode);
+
+    let cfg = SystemConfig {
+        pump_duty_percent: 80,
+        ..Default::default()
+    };
+    assert!(!app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink));
+    assert!(!app.handle_command(AppCommand::SaveConfig, &mut hw, &mut sink));
+    assert!(!app.is_config_dirty());
+    assert_eq!(
+        app.current_config().pump_duty_percent,
+        SystemConfig::default().pump_duty_percent
+    );
+}
+
+#[test]
+fn applied_config_is_reported_once_whatever_its_source() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(!app.take_config_changed());
+
+    let cfg = SystemConfig {
+        pump_duty_percent: 80,
+        ..Default::default()
+    };
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(cfg.clone())),
+        &mut hw,
+        &mut sink,
+    );
+    assert!(app.take_config_changed());
+    assert!(!app.take_config_changed(), "taken once");
+
+    app.raise_advisory(Advisory::ConfigSafeMode);
+    assert!(!app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink));
+    assert!(!app.take_config_changed());
+}
+
+#[test]
+fn button_state_change_is_reported_once() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert_eq!(app.take_state_change(), None);
+
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Button),
+        &mut hw,
+        &mut sink,
+    );
+    assert_eq!(
+        app.take_state_change(),
+        Some((StateId::Idle, StateId::Active))
+    );
+    assert_eq!(app.take_state_change(), None);
+
+    app.handle_command(
+        AppCommand::StopScrub(CommandSource::Button),
+        &mut hw,
+        &mut sink,
+    );
+    assert_eq!(
+        app.take_state_change(),
+        Some((StateId::Active, StateId::Idle))
+    );
+}
+
+// ── Quiet hours survive a save / reboot cycle ────────────────
+
+#[test]
+fn quiet_hours_persist_across_reboot() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let nvs = NvsAdapter::new().unwrap();
+
+    let quiet = QuietHours {
+        start_hour: 22,
+        end_hour: 6,
+    };
+    let cfg = SystemConfig {
+        quiet_hours: Some(quiet),
+        ..app.current_config()
+    };
+    app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink);
+    app.force_save_if_dirty(&nvs);
+    assert!(!app.is_config_dirty());
+
+    // "Reboot": build a fresh service from whatever NVS holds.
+    let rebooted = AppService::new(nvs.load().unwrap());
+    assert_eq!(rebooted.current_config().quiet_hours, Some(quiet));
+    assert_eq!(rebooted.scheduler().quiet_hours(), Some(quiet));
+    assert!(rebooted.scheduler().is_quiet_hour(Some(23)));
+    assert!(!rebooted.scheduler().is_quiet_hour(Some(12)));
+}
+
 // ── QA-7e: OTA state machine transitions (sim, no esp_ota) ───
 
 #[test]
@@ -161,13 +276,13 @@ fn auto_save_fires_after_dirty_timeout() {
         purge_duration_secs: 42,
         ..Default::default()
     };
-    app.handle_command(AppCommand::UpdateConfig(cfg), &mut hw, &mut sink);
+    app.handle_command(AppCommand::UpdateConfig(Box::new(cfg)), &mut hw, &mut sink);
     assert!(app.is_config_dirty());
 
     let nvs = MockNvs::new();
     // auto_save_if_needed checks tick delta; call tick() enough times to exceed threshold.
     for _ in 0..60 {
-        app.tick(&mut hw, &mut sink);
+        app.tick(&mut hw, &mut sink, 1.0);
     }
     let did_save = app.auto_save_if_needed(&nvs);
     assert!(
@@ -175,3 +290,1106 @@ fn auto_save_fires_after_dirty_timeout() {
         "auto_save should fire after sufficient ticks with dirty config"
     );
 }
+
+// ── Startup sensor self-test ──────────────────────────────────
+
+#[test]
+fn critical_self_test_failure_blocks_scrub() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.self_test_report = SelfTestReport {
+        temperature: check_adc_raw(4095),
+        ..Default::default()
+    };
+
+    let report = app.run_self_test(&mut hw, &mut sink);
+    assert!(report.critical_failure());
+    assert!(sink.events.iter().any(|e| e.starts_with("SelfTestFailed")));
+
+    assert!(!app.handle_command(
+        AppCommand::StartScrub(CommandSource::Button),
+        &mut hw,
+        &mut sink
+    ));
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn non_critical_self_test_failure_still_allows_scrub() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.self_test_report = SelfTestReport {
+        ammonia: check_adc_raw(0),
+        ..Default::default()
+    };
+
+    let report = app.run_self_test(&mut hw, &mut sink);
+    assert!(!report.passed());
+    assert!(!report.critical_failure());
+    assert!(sink.events.iter().any(|e| e.starts_with("SelfTestFailed")));
+
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Button),
+        &mut hw,
+        &mut sink
+    ));
+    assert_eq!(app.state(), StateId::Active);
+}
+
+#[test]
+fn self_test_blocking_can_be_disabled() {
+    let config = SystemConfig {
+        self_test_blocks_active: false,
+        ..Default::default()
+    };
+    let mut app = AppService::new(config);
+    let mut hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    hw.self_test_report = SelfTestReport {
+        interlock: check_interlock(false, true),
+        ..Default::default()
+    };
+
+    app.run_self_test(&mut hw, &mut sink);
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink
+    ));
+    assert_eq!(app.state(), StateId::Active);
+}
+
+#[test]
+fn passing_self_test_emits_no_event() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(app.run_self_test(&mut hw, &mut sink).passed());
+    assert!(!sink.events.iter().any(|e| e.starts_with("SelfTestFailed")));
+}
+
+// ── Brown-out: outputs off, OTA aborted, saves held ──────────
+
+#[test]
+fn brownout_warning_turns_actuators_off_and_aborts_ota() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let mut nvs = MockNvs::new();
+    let mut engine = RpcEngine::new(&[0x42; 32]);
+    engine.ota_mut().begin(4096, &[0u8; 32]).unwrap();
+    assert!(engine.ota_mut().checkpoint(&mut nvs, 1));
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink
+    ));
+    assert!(hw.pump_on());
+    app.mark_config_dirty();
+
+    assert!(power::inject_brownout());
+    assert_eq!(events::pop_event(), Some(Event::BrownoutWarning));
+    // What the main loop does on Event::BrownoutWarning.
+    assert!(engine.abort_ota(&mut nvs));
+    app.on_brownout(&mut hw, &mut sink);
+
+    assert_eq!(app.state(), StateId::Idle);
+    assert_eq!(hw.last_call(), Some(&ActuatorCall::AllOff));
+    assert!(!hw.pump_on() && !hw.uvc_on());
+    assert!(!engine.ota_mut().is_transfer_active());
+    assert!(
+        engine.ota_mut().restore(&nvs).is_none(),
+        "resume record kept"
+    );
+    assert!(!engine.abort_ota(&mut nvs), "nothing left to abort");
+
+    // Auto-save stays suspended while the hold lasts, then resumes.
+    for _ in 0..10 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert!(app.in_brownout_hold());
+    assert!(!app.auto_save_if_needed(&nvs));
+    for _ in 0..25 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert!(!app.in_brownout_hold());
+    assert!(app.auto_save_if_needed(&nvs));
+}
+
+// ── DrainWater maintenance path ───────────────────────────────
+
+#[test]
+fn drain_stops_pump_when_tanks_read_empty() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.tick(&mut hw, &mut sink, 1.0); // first sensor read
+
+    assert!(app.handle_command(AppCommand::DrainWater, &mut hw, &mut sink));
+    assert_eq!(app.state(), StateId::Draining);
+    assert_eq!(
+        hw.calls
+            .iter()
+            .rev()
+            .find(|c| matches!(c, ActuatorCall::SetPump { .. })),
+        Some(&ActuatorCall::SetPump {
+            duty: SystemConfig::default().pump_duty_percent,
+            forward: false,
+        }),
+        "drain runs the pump in reverse"
+    );
+
+    // Tank A running dry mid-drain is expected, not a fault.
+    hw.sensors.tank_a_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Draining);
+    assert!(hw.pump_on());
+
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Idle);
+    assert!(!hw.pump_on());
+    let telem = app.build_telemetry(None);
+    assert!(telem.drain_complete);
+    assert!(telem.drain_volume_ml > 0.0);
+}
+
+#[test]
+fn drain_volume_integrates_measured_tick_time() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.flow_ml_per_min = 600.0; // 10 mL/s
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(app.handle_command(AppCommand::DrainWater, &mut hw, &mut sink));
+
+    // Jittery wakes: 0.5 s, 2.0 s, then a 30 s stall clamped to 4 periods.
+    app.tick(&mut hw, &mut sink, 0.5);
+    app.tick(&mut hw, &mut sink, 2.0);
+    app.tick(&mut hw, &mut sink, 30.0);
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+
+    assert_eq!(app.state(), StateId::Idle);
+    let volume = app.build_telemetry(None).drain_volume_ml;
+    assert!((volume - 75.0).abs() < 0.01, "{volume}");
+}
+
+#[test]
+fn drain_aborts_when_interlock_opens() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.tick(&mut hw, &mut sink, 1.0); // first sensor read
+
+    assert!(app.handle_command(AppCommand::DrainWater, &mut hw, &mut sink));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(hw.pump_on());
+
+    hw.sensors.uvc_interlock_closed = false;
+    for _ in 0..=SystemConfig::default().interlock_grace_secs {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.state(), StateId::Error);
+    assert!(!hw.pump_on());
+    assert!(!app.build_telemetry(None).drain_complete);
+}
+
+// ── Interlock grace period ────────────────────────────────────
+
+#[test]
+fn brief_interlock_open_cuts_uvc_without_faulting() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.nh3_avg_ppm = 20.0; // keep the scrub running
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(hw.uvc_on());
+
+    hw.sensors.uvc_interlock_closed = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(!hw.uvc_on(), "UVC must be cut on the first open tick");
+    assert_eq!(app.state(), StateId::Active);
+    assert_eq!(app.fault_flags(), 0);
+
+    hw.sensors.uvc_interlock_closed = true;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Active);
+    assert!(hw.uvc_on(), "scrub resumes once the lid closes");
+}
+
+#[test]
+fn sustained_interlock_open_faults_after_grace() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.nh3_avg_ppm = 20.0; // keep the scrub running
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+
+    hw.sensors.uvc_interlock_closed = false;
+    let grace = SystemConfig::default().interlock_grace_secs;
+    for _ in 0..grace - 1 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        assert!(!hw.uvc_on());
+        assert_eq!(app.state(), StateId::Active);
+    }
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert_ne!(app.fault_flags(), 0);
+    assert!(!hw.uvc_on());
+}
+
+// ── Over-temperature debounce ─────────────────────────────
+
+#[test]
+fn temperature_spike_does_not_abort_scrub_but_sustained_heat_does() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.nh3_avg_ppm = 20.0; // keep the scrub running
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Active);
+
+    assert!(hw.uvc_on());
+
+    // The spike cuts the lamp at once but leaves the scrub running.
+    hw.sensors.temperature_c = 120.0;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Active);
+    assert!(!hw.uvc_on());
+    hw.sensors.temperature_c = 25.0;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Active);
+    assert!(hw.uvc_on());
+
+    hw.sensors.temperature_c = 120.0;
+    let samples = SystemConfig::default().over_temp_debounce_samples;
+    for _ in 0..samples - 1 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        assert_eq!(app.state(), StateId::Active);
+    }
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+}
+
+// ── Maintenance mode ──────────────────────────────────────
+
+#[test]
+fn maintenance_mode_holds_idle_through_nh3_but_not_faults() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let config = SystemConfig {
+        maintenance_mode: true,
+        ..SystemConfig::default()
+    };
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(config)),
+        &mut hw,
+        &mut sink,
+    );
+
+    hw.sensors.nh3_ppm = 80.0;
+    hw.sensors.nh3_avg_ppm = 80.0;
+    for _ in 0..5 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        assert_eq!(app.state(), StateId::Idle);
+    }
+    assert!(!app.handle_command(
+        AppCommand::StartScrub(CommandSource::Schedule),
+        &mut hw,
+        &mut sink,
+    ));
+    assert_eq!(app.state(), StateId::Idle);
+
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+}
+
+#[test]
+fn clearing_maintenance_mode_resumes_automatic_control() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let mut config = SystemConfig {
+        maintenance_mode: true,
+        ..SystemConfig::default()
+    };
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(config.clone())),
+        &mut hw,
+        &mut sink,
+    );
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Idle);
+
+    config.maintenance_mode = false;
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(config)),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Sensing);
+}
+
+// ── NH3 sensor warm-up ────────────────────────────────────
+
+fn warmup_app() -> (AppService, MockHardware, LogSink) {
+    let config = SystemConfig {
+        nh3_warmup_secs: 5,
+        ..SystemConfig::default()
+    };
+    (AppService::new(config), MockHardware::new(), LogSink::new())
+}
+
+#[test]
+fn cold_boot_ignores_nh3_until_sensor_warmed_up() {
+    let (mut app, mut hw, mut sink) = warmup_app();
+    app.start(&mut sink);
+    assert!(app.build_telemetry(None).sensor_warming);
+
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    for _ in 0..4 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        assert_eq!(app.state(), StateId::Idle);
+    }
+    assert!(app.build_telemetry(None).sensor_warming);
+
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Sensing);
+    assert!(!app.build_telemetry(None).sensor_warming);
+}
+
+#[test]
+fn ulp_wake_skips_sensor_warmup() {
+    let (mut app, mut hw, mut sink) = warmup_app();
+    app.start_from(StateId::Idle, &mut sink);
+    assert!(!app.build_telemetry(None).sensor_warming);
+
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Sensing);
+}
+
+// ── NH3 alarm ─────────────────────────────────────────────
+
+fn alarm_app(warmup_secs: u16) -> (AppService, MockHardware, LogSink) {
+    let config = SystemConfig {
+        nh3_alarm_high_ppm: 8.0,
+        nh3_alarm_clear_ppm: 6.0,
+        nh3_warmup_secs: warmup_secs,
+        ..SystemConfig::default()
+    };
+    let mut app = AppService::new(config);
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    (app, MockHardware::new(), sink)
+}
+
+fn tick_at(app: &mut AppService, hw: &mut MockHardware, sink: &mut LogSink, ppm: f32) {
+    hw.sensors.nh3_ppm = ppm;
+    hw.sensors.nh3_avg_ppm = ppm;
+    app.tick(hw, sink, 1.0);
+}
+
+#[test]
+fn nh3_alarm_sets_and_clears_at_its_own_band() {
+    let (mut app, mut hw, mut sink) = alarm_app(0);
+
+    // (smoothed ppm, alarm after the tick, edge reported)
+    for (ppm, active, edge) in [
+        (7.9, false, false),
+        (8.0, true, true),
+        (9.0, true, false),
+        (7.0, true, false),
+        (6.0, true, false),
+        (5.9, false, true),
+        (7.9, false, false),
+        (8.5, true, true),
+    ] {
+        tick_at(&mut app, &mut hw, &mut sink, ppm);
+        assert_eq!(app.nh3_alarm_active(), active, "ppm={ppm}");
+        assert_eq!(app.build_telemetry(None).nh3_alarm, active, "ppm={ppm}");
+        assert_eq!(app.take_nh3_alarm_changed(), edge, "ppm={ppm}");
+    }
+}
+
+#[test]
+fn nh3_alarm_is_independent_of_activation_thresholds() {
+    // Default activation is 10 ppm: the alarm raises below it and the
+    // FSM stays Idle.
+    let (mut app, mut hw, mut sink) = alarm_app(0);
+    tick_at(&mut app, &mut hw, &mut sink, 9.0);
+    assert!(app.nh3_alarm_active());
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn nh3_alarm_stays_down_during_sensor_warmup() {
+    let (mut app, mut hw, mut sink) = alarm_app(3);
+    for _ in 0..2 {
+        tick_at(&mut app, &mut hw, &mut sink, 9.0);
+        assert!(!app.nh3_alarm_active());
+        assert!(!app.take_nh3_alarm_changed());
+    }
+    tick_at(&mut app, &mut hw, &mut sink, 9.0);
+    tick_at(&mut app, &mut hw, &mut sink, 9.0);
+    assert!(app.nh3_alarm_active());
+}
+
+#[test]
+fn disabling_the_alarm_clears_it() {
+    let (mut app, mut hw, mut sink) = alarm_app(0);
+    tick_at(&mut app, &mut hw, &mut sink, 9.0);
+    assert!(app.take_nh3_alarm_changed());
+
+    let config = SystemConfig {
+        nh3_alarm_high_ppm: 0.0,
+        ..app.current_config()
+    };
+    app.handle_command(
+        AppCommand::UpdateConfig(Box::new(config)),
+        &mut hw,
+        &mut sink,
+    );
+    tick_at(&mut app, &mut hw, &mut sink, 9.0);
+    assert!(!app.nh3_alarm_active());
+    assert!(app.take_nh3_alarm_changed());
+}
+
+// ── NH3 injection (field validation) ──────────────────────
+
+fn inject(app: &mut AppService, hw: &mut MockHardware, sink: &mut LogSink, secs: u16) -> bool {
+    let cmd = AppCommand::InjectNh3 {
+        ppm: 20.0,
+        duration_secs: secs,
+    };
+    app.handle_command(cmd, hw, sink)
+}
+
+#[test]
+fn nh3_injection_drives_scrub_cycle_then_reverts_to_sensor() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(inject(&mut app, &mut hw, &mut sink, 40));
+
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Sensing);
+    let t = app.build_telemetry(None);
+    assert!(t.nh3_injected);
+    assert!((t.nh3_ppm - 20.0).abs() < f32::EPSILON);
+
+    let mut states = vec![app.state()];
+    for _ in 1..40 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        if states.last() != Some(&app.state()) {
+            states.push(app.state());
+        }
+    }
+    assert_eq!(states, [StateId::Sensing, StateId::Active]);
+    assert!(!app.build_telemetry(None).nh3_injected);
+
+    // Window over: the real (clean) reading ends the scrub.
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Purging);
+    assert!(app.build_telemetry(None).nh3_ppm.abs() < f32::EPSILON);
+}
+
+#[test]
+fn nh3_injection_refused_while_faulted_and_cancelled_by_fault() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert!(!inject(&mut app, &mut hw, &mut sink, 60));
+    assert!(!app.build_telemetry(None).nh3_injected);
+
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(inject(&mut app, &mut hw, &mut sink, 60));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(app.build_telemetry(None).nh3_injected);
+
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert!(!app.build_telemetry(None).nh3_injected);
+}
+
+#[test]
+fn nh3_injection_window_is_bounded() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(!inject(&mut app, &mut hw, &mut sink, 0));
+    assert!(!inject(&mut app, &mut hw, &mut sink, MAX_NH3_INJECT_SECS + 1));
+    assert!(inject(&mut app, &mut hw, &mut sink, MAX_NH3_INJECT_SECS));
+}
+
+// ── Test fault (Error pathway check) ──────────────────────
+
+fn test_fault(
+    app: &mut AppService,
+    hw: &mut MockHardware,
+    sink: &mut LogSink,
+    fault_bit: u8,
+    secs: u16,
+) -> bool {
+    let cmd = AppCommand::TestFault {
+        fault_bit,
+        duration_secs: secs,
+    };
+    app.handle_command(cmd, hw, sink)
+}
+
+#[test]
+fn test_fault_drives_error_then_auto_clears() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let bit = SafetyFault::UvcInterlockOpen.mask();
+    assert!(test_fault(&mut app, &mut hw, &mut sink, bit, 3));
+
+    for _ in 0..3 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        assert_eq!(app.state(), StateId::Error);
+        assert_eq!(app.fault_flags(), bit);
+        assert!(app.build_telemetry(None).fault_test);
+    }
+    assert!(
+        sink.events
+            .contains(&format!("FaultDetected {{ flags: {bit}, is_test: true }}"))
+    );
+    let red = ActuatorCall::SetLed { r: 255, g: 0, b: 0 };
+    assert!(hw.calls.contains(&red));
+
+    // Window over: the fault drops and Error waits out its cooldown.
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.fault_flags(), 0);
+    assert!(!app.build_telemetry(None).fault_test);
+    assert_eq!(app.state(), StateId::Error);
+
+    let cooldown = app.current_config().fault_recovery_cooldown_secs;
+    for _ in 1..cooldown {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn test_fault_refused_while_faulted_and_yields_to_real_fault() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    let over_temp = SafetyFault::OverTemperature.mask();
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, over_temp, 30));
+    assert!(!app.build_telemetry(None).fault_test);
+
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(test_fault(&mut app, &mut hw, &mut sink, over_temp, 30));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.fault_flags(), over_temp);
+
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.fault_flags(), SafetyFault::WaterLevelLow.mask());
+    assert!(!app.build_telemetry(None).fault_test);
+}
+
+#[test]
+fn fault_count_tallies_real_trips_only() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let bit = SafetyFault::OverTemperature.mask();
+    assert!(test_fault(&mut app, &mut hw, &mut sink, bit, 2));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert_eq!(app.fault_count(), 0);
+
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    for _ in 0..3 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.fault_count(), 1);
+
+    // Recover, then trip again.
+    hw.sensors.tank_a_ok = true;
+    hw.sensors.tank_b_ok = true;
+    let cooldown = app.current_config().fault_recovery_cooldown_secs;
+    for _ in 0..=cooldown {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_ne!(app.state(), StateId::Error);
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.fault_count(), 2);
+}
+
+#[test]
+fn test_fault_needs_one_known_bit_and_a_bounded_window() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let bit = SafetyFault::NoFlowDetected.mask();
+    let max = MAX_TEST_FAULT_SECS;
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, 0, 10));
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, 0b0000_0011, 10));
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, 0b0001_0000, 10));
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, bit, 0));
+    assert!(!test_fault(&mut app, &mut hw, &mut sink, bit, max + 1));
+    assert!(test_fault(&mut app, &mut hw, &mut sink, bit, max));
+}
+
+#[test]
+fn drain_rejected_while_scrubbing_or_interlock_open() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    assert!(!app.handle_command(AppCommand::DrainWater, &mut hw, &mut sink));
+    assert_eq!(app.state(), StateId::Active);
+
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.uvc_interlock_closed = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(!app.handle_command(AppCommand::DrainWater, &mut hw, &mut sink));
+    assert_ne!(app.state(), StateId::Draining);
+}
+
+// ── Telemetry history backfill ────────────────────────────────
+
+#[test]
+fn telemetry_history_keeps_most_recent_samples_in_order() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let interval_secs = TELEMETRY_HISTORY_INTERVAL_MS as f32 / 1000.0;
+    let total = TELEMETRY_HISTORY_LEN + 6;
+
+    for i in 0..total {
+        hw.sensors.nh3_ppm = i as f32 / 10.0; // below activation
+        app.tick(&mut hw, &mut sink, interval_secs);
+    }
+
+    let history: Vec<_> = app.telemetry_history().collect();
+    assert_eq!(history.len(), TELEMETRY_HISTORY_LEN);
+    let oldest = total - TELEMETRY_HISTORY_LEN;
+    for (k, sample) in history.iter().enumerate() {
+        let i = oldest + k;
+        assert_eq!(sample.nh3_ppm.to_bits(), (i as f32 / 10.0).to_bits());
+        assert_eq!(sample.uptime_secs as usize, (i + 1) * 5);
+        assert_eq!(sample.state, StateId::Idle);
+    }
+}
+
+#[test]
+fn telemetry_history_samples_at_interval_not_every_tick() {
+    let (mut app, mut hw, mut sink) = make_app();
+    for _ in 0..12 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    // First tick samples immediately, then every 5 s of measured time.
+    let stamps: Vec<u32> = app.telemetry_history().map(|s| s.uptime_secs).collect();
+    assert_eq!(stamps, [1, 6, 11]);
+}
+
+// ── Sensor staleness ──────────────────────────────────────────
+
+#[test]
+fn wedged_nh3_channel_raises_stale_advisory_without_faulting() {
+    let (mut app, mut hw, mut sink) = make_app();
+    let stale = 0b0000_0100; // Advisory::SensorStale
+
+    hw.sensors.timestamp_ms = 60_000;
+    hw.sensors.nh3_updated_ms = 60_000;
+    hw.sensors.temperature_updated_ms = 60_000;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.build_telemetry(None).advisory_flags & stale, 0);
+
+    // NH3 stops updating while the snapshot clock moves on.
+    hw.sensors.timestamp_ms = 75_000;
+    hw.sensors.temperature_updated_ms = 75_000;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.build_telemetry(None).advisory_flags & stale, stale);
+    assert_eq!(app.state(), StateId::Idle);
+
+    hw.sensors.nh3_updated_ms = 75_000;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.build_telemetry(None).advisory_flags & stale, 0);
+}
+
+// ── Soft stop ─────────────────────────────────────────────────
+
+fn pump_duties(hw: &MockHardware) -> Vec<u8> {
+    hw.calls
+        .iter()
+        .filter_map(|c| match c {
+            ActuatorCall::SetPump { duty, .. } => Some(*duty),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn stop_scrub_ramps_pump_down_at_the_adapter() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+    let running = *pump_duties(&hw).last().unwrap();
+    assert!(running > 0);
+
+    hw.calls.clear();
+    app.handle_command(
+        AppCommand::StopScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    for _ in 0..SystemConfig::default().soft_stop_ticks {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    let duties = pump_duties(&hw);
+    assert!(duties[0] > 0 && duties[0] < running, "{duties:?}");
+    assert!(duties.windows(2).all(|w| w[1] <= w[0]), "{duties:?}");
+    assert_eq!(*duties.last().unwrap(), 0, "{duties:?}");
+}
+
+#[test]
+fn fault_during_soft_stop_cuts_pump_immediately() {
+    let (mut app, mut hw, mut sink) = make_app();
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    app.tick(&mut hw, &mut sink, 1.0);
+    app.handle_command(
+        AppCommand::StopScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
+    assert!(
+        *pump_duties(&hw).last().unwrap() > 0,
+        "ramp should be running"
+    );
+
+    hw.calls.clear();
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert!(pump_duties(&hw).iter().all(|&d| d == 0));
+}
+
+// ── Dual-tank source selection ────────────────────────────────
+
+fn make_tank_app(tank_source: TankSource) -> (AppService, MockHardware, LogSink) {
+    let mut app = AppService::new(SystemConfig {
+        tank_source,
+        purge_duration_secs: 5,
+        ..Default::default()
+    });
+    let mut hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    app.tick(&mut hw, &mut sink, 1.0); // first sensor read
+    (app, hw, sink)
+}
+
+/// Run one manual scrub through purge back to Idle; returns the tank
+/// the cycle drew from.
+fn run_scrub_cycle(app: &mut AppService, hw: &mut MockHardware, sink: &mut LogSink) -> Tank {
+    assert!(app.handle_command(AppCommand::StartScrub(CommandSource::Button), hw, sink));
+    assert_eq!(app.state(), StateId::Active);
+    let tank = hw.source_tank;
+    assert_eq!(app.build_telemetry(None).source_tank, tank);
+    for _ in 0..30 {
+        app.tick(hw, sink, 1.0);
+        if app.state() == StateId::Idle {
+            break;
+        }
+    }
+    assert_eq!(app.state(), StateId::Idle);
+    tank
+}
+
+#[test]
+fn alternate_source_swaps_tank_every_cycle() {
+    let (mut app, mut hw, mut sink) = make_tank_app(TankSource::Alternate);
+    let tanks: Vec<Tank> = (0..4)
+        .map(|_| run_scrub_cycle(&mut app, &mut hw, &mut sink))
+        .collect();
+    assert_eq!(tanks, [Tank::A, Tank::B, Tank::A, Tank::B]);
+}
+
+#[test]
+fn low_tank_is_skipped_in_favour_of_the_other() {
+    let (mut app, mut hw, mut sink) = make_tank_app(TankSource::Alternate);
+    hw.sensors.tank_b_ok = false;
+    let tanks: Vec<Tank> = (0..3)
+        .map(|_| run_scrub_cycle(&mut app, &mut hw, &mut sink))
+        .collect();
+    assert_eq!(tanks, [Tank::A, Tank::A, Tank::A]);
+
+    hw.sensors.tank_b_ok = true;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(run_scrub_cycle(&mut app, &mut hw, &mut sink), Tank::B);
+}
+
+#[test]
+fn low_tank_a_draws_from_tank_b_without_faulting() {
+    let (mut app, mut hw, mut sink) = make_tank_app(TankSource::TankA);
+    hw.sensors.tank_a_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Idle);
+    assert_eq!(app.fault_flags(), 0);
+    assert_eq!(run_scrub_cycle(&mut app, &mut hw, &mut sink), Tank::B);
+
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert_eq!(app.fault_flags(), SafetyFault::WaterLevelLow.mask());
+}
+
+#[test]
+fn source_tank_switches_mid_cycle_when_it_runs_low() {
+    let (mut app, mut hw, mut sink) = make_tank_app(TankSource::TankB);
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Button),
+        &mut hw,
+        &mut sink
+    ));
+    assert_eq!(hw.source_tank, Tank::B);
+
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Purging);
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Purging);
+    assert_eq!(hw.source_tank, Tank::A);
+    assert_eq!(app.build_telemetry(None).source_tank, Tank::A);
+}
+
+// ── PID auto-tune ─────────────────────────────────────────────
+
+fn make_scrubbing_app() -> (AppService, MockHardware, LogSink) {
+    let mut app = AppService::new(SystemConfig {
+        prime_after_idle_secs: 0,
+        ..Default::default()
+    });
+    let mut hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink
+    ));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Active);
+    (app, hw, sink)
+}
+
+fn last_pump_duty(hw: &MockHardware) -> u8 {
+    hw.calls
+        .iter()
+        .rev()
+        .find_map(|c| match c {
+            ActuatorCall::SetPump { duty, .. } => Some(*duty),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn autotune_relay_suggests_gains_and_waits_for_confirmation() {
+    let (mut app, mut hw, mut sink) = make_scrubbing_app();
+    assert!(app.handle_command(AppCommand::StartAutoTune, &mut hw, &mut sink));
+    assert_eq!(app.autotune_status(), TuneStatus::Running);
+
+    // Flow lags halfway each second towards 12 mL/min per % duty,
+    // swinging either side of the 1000 mL/min target.
+    assert_eq!(SystemConfig::default().pump_flow_ml_per_min, 1000);
+    hw.sensors.flow_ml_per_min = 1000.0;
+    let mut duties = Vec::new();
+    for _ in 0..120 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        if app.autotune_status() != TuneStatus::Running {
+            break;
+        }
+        let duty = last_pump_duty(&hw);
+        duties.push(duty);
+        let flow = hw.sensors.flow_ml_per_min;
+        hw.sensors.flow_ml_per_min = flow + (12.0 * f32::from(duty) - flow) * 0.5;
+    }
+    let TuneStatus::Complete(result) = app.autotune_status() else {
+        panic!("auto-tune did not complete: {:?}", app.autotune_status());
+    };
+    assert!(duties.iter().all(|&d| d == 10 || d == 100), "{duties:?}");
+    assert!(duties.contains(&10) && duties.contains(&100));
+    assert!(result.ultimate_period_secs > 0.0);
+    assert!(result.gains.kp > 0.0 && result.gains.ki > 0.0 && result.gains.kd > 0.0);
+    assert_eq!(app.state(), StateId::Active);
+
+    // Suggestion stays pending until confirmed, then clears.
+    assert_eq!(app.autotune_status(), TuneStatus::Complete(result));
+    assert!(!app.is_config_dirty());
+    assert!(app.handle_command(AppCommand::ConfirmAutoTune, &mut hw, &mut sink));
+    assert_eq!(app.autotune_status(), TuneStatus::Idle);
+    assert!(!app.handle_command(AppCommand::ConfirmAutoTune, &mut hw, &mut sink));
+
+    // Confirmed gains go into the config so they are saved.
+    let cfg = app.current_config();
+    assert_eq!(
+        (cfg.pump_pid_kp, cfg.pump_pid_ki, cfg.pump_pid_kd),
+        (result.gains.kp, result.gains.ki, result.gains.kd)
+    );
+    assert!(app.is_config_dirty());
+}
+
+#[test]
+fn autotune_refused_outside_a_scrub() {
+    let (mut app, mut hw, mut sink) = make_app();
+    assert!(!app.handle_command(AppCommand::StartAutoTune, &mut hw, &mut sink));
+    assert_eq!(app.autotune_status(), TuneStatus::Idle);
+}
+
+#[test]
+fn autotune_aborts_on_fault() {
+    let (mut app, mut hw, mut sink) = make_scrubbing_app();
+    assert!(app.handle_command(AppCommand::StartAutoTune, &mut hw, &mut sink));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.autotune_status(), TuneStatus::Running);
+
+    hw.sensors.tank_a_ok = false;
+    hw.sensors.tank_b_ok = false;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Error);
+    assert_eq!(app.autotune_status(), TuneStatus::Aborted);
+    assert!(!app.handle_command(AppCommand::ConfirmAutoTune, &mut hw, &mut sink));
+}
+
+#[test]
+fn autotune_cancel_hands_the_pump_back() {
+    let (mut app, mut hw, mut sink) = make_scrubbing_app();
+    assert!(app.handle_command(AppCommand::StartAutoTune, &mut hw, &mut sink));
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(app.handle_command(AppCommand::CancelAutoTune, &mut hw, &mut sink));
+    assert_eq!(app.autotune_status(), TuneStatus::Aborted);
+    assert!(app.handle_command(AppCommand::CancelAutoTune, &mut hw, &mut sink));
+    assert_eq!(app.autotune_status(), TuneStatus::Idle);
+}
+
+// ── Thermal derating ──────────────────────────────────────────
+
+const DERATE_MIN_DUTY: u8 = 30;
+
+fn derating_app() -> (AppService, MockHardware, LogSink) {
+    let mut app = AppService::new(SystemConfig {
+        prime_after_idle_secs: 0,
+        max_temperature_c: 80.0,
+        pump_derate_start_c: 60.0,
+        pump_derate_min_duty_percent: DERATE_MIN_DUTY,
+        ..Default::default()
+    });
+    let mut hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink
+    ));
+    (app, hw, sink)
+}
+
+/// One tick at `temp`: `(reported, applied)` pump duty.
+fn duty_at(app: &mut AppService, hw: &mut MockHardware, sink: &mut LogSink, temp: f32) -> (u8, u8) {
+    hw.sensors.temperature_c = temp;
+    app.tick(hw, sink, 1.0);
+    assert_eq!(app.state(), StateId::Active, "temp={temp}");
+    assert_eq!(app.fault_flags(), 0, "temp={temp}");
+    (app.build_telemetry(None).pump_duty, last_pump_duty(hw))
+}
+
+#[test]
+fn pump_duty_is_unaffected_below_derate_start() {
+    let (mut app, mut hw, mut sink) = derating_app();
+    for temp in [25.0, 59.0, 60.0] {
+        let (reported, applied) = duty_at(&mut app, &mut hw, &mut sink, temp);
+        assert_eq!(applied, 100, "temp={temp}");
+        assert_eq!(reported, applied, "temp={temp}");
+    }
+}
+
+#[test]
+fn pump_duty_is_reduced_inside_derate_band() {
+    let (mut app, mut hw, mut sink) = derating_app();
+    // The flow loop runs flat out against a flow it cannot reach; halfway
+    // through the band it is held to half the headroom above the minimum.
+    let (reported, applied) = duty_at(&mut app, &mut hw, &mut sink, 70.0);
+    assert_eq!(applied, 100 - (100 - DERATE_MIN_DUTY) / 2);
+    assert_eq!(reported, applied);
+
+    // Cooling back below the start lifts the limit again.
+    let (reported, applied) = duty_at(&mut app, &mut hw, &mut sink, 50.0);
+    assert_eq!(applied, 100);
+    assert_eq!(reported, applied);
+}
+
+#[test]
+fn pump_duty_floors_at_configured_minimum_at_the_limit() {
+    let (mut app, mut hw, mut sink) = derating_app();
+    let (_, applied) = duty_at(&mut app, &mut hw, &mut sink, 79.9);
+    assert!(applied > DERATE_MIN_DUTY);
+    let (reported, applied) = duty_at(&mut app, &mut hw, &mut sink, 80.0);
+    assert_eq!(applied, DERATE_MIN_DUTY);
+    assert_eq!(reported, applied);
+}
+
+#[test]
+fn derating_is_off_by_default() {
+    let (mut app, mut hw, mut sink) = make_scrubbing_app();
+    hw.sensors.temperature_c = 79.0;
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(last_pump_duty(&hw), app.build_telemetry(None).pump_duty);
+}
+
+// ── Flow totalizer ───────────────────────────────────────────
+
+#[test]
+fn control_ticks_integrate_measured_flow() {
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.flow_ml_per_min = 1200.0;
+    for _ in 0..30 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.flow_totals().lifetime_ml(), 600);
+    assert_eq!(app.flow_totals().since_service_ml(), 600);
+}
+
+#[test]
+fn flow_totals_survive_a_reboot_after_reset_of_service_subtotal() {
+    let mut nvs = NvsAdapter::new().unwrap();
+    let (mut app, mut hw, mut sink) = make_app();
+    hw.sensors.flow_ml_per_min = 1200.0;
+    for _ in 0..30 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    app.flow_totals_mut().reset_since_service(&mut nvs).unwrap();
+    app.tick(&mut hw, &mut sink, 1.0);
+    app.flow_totals_mut().flush(&mut nvs);
+
+    let (mut rebooted, _, _) = make_app();
+    rebooted.flow_totals_mut().init(&nvs);
+    assert_eq!(rebooted.flow_totals().lifetime_ml(), 620);
+    assert_eq!(rebooted.flow_totals().since_service_ml(), 20);
+}
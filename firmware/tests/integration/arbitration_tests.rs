@@ -0,0 +1,215 @@
+//! Integration tests for scrub command arbitration between the button,
+//! RPC clients, and the scheduler.
+//!
+//! Policy under test (see `AppService::handle_command`):
+//! manual stop wins and locks out other sources for `stop_lockout_secs`;
+//! manual starts take over automatic scrubs; automatic stops never end a
+//! manual scrub.
+
+use crate::mock_hw::{LogSink, MockHardware};
+
+use petfilter::app::commands::{AppCommand, CommandSource};
+use petfilter::app::service::AppService;
+use petfilter::config::SystemConfig;
+use petfilter::fsm::StateId;
+
+fn make_app(config: SystemConfig) -> (AppService, MockHardware, LogSink) {
+    let mut app = AppService::new(config);
+    let hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    (app, hw, sink)
+}
+
+fn start(
+    app: &mut AppService,
+    hw: &mut MockHardware,
+    sink: &mut LogSink,
+    src: CommandSource,
+) -> bool {
+    app.handle_command(AppCommand::StartScrub(src), hw, sink)
+}
+
+fn stop(
+    app: &mut AppService,
+    hw: &mut MockHardware,
+    sink: &mut LogSink,
+    src: CommandSource,
+) -> bool {
+    app.handle_command(AppCommand::StopScrub(src), hw, sink)
+}
+
+// ── Stop wins ─────────────────────────────────────────────────
+
+#[test]
+fn rpc_stop_beats_button_start_within_lockout() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Active);
+    assert_eq!(app.scrub_source(), Some(CommandSource::Button));
+
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert_eq!(app.state(), StateId::Idle);
+    assert_eq!(app.scrub_source(), None);
+
+    // Button press racing the stop is rejected while the lockout runs.
+    assert!(!start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn button_stop_beats_rpc_start_within_lockout() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Button));
+
+    assert!(!start(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert!(!start(
+        &mut app,
+        &mut hw,
+        &mut sink,
+        CommandSource::Schedule
+    ));
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn stopping_source_may_restart_within_lockout() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert_eq!(app.state(), StateId::Active);
+    assert_eq!(app.scrub_source(), Some(CommandSource::Rpc));
+}
+
+#[test]
+fn lockout_expires_after_configured_window() {
+    let config = SystemConfig {
+        stop_lockout_secs: 3,
+        control_loop_interval_ms: 1000,
+        ..Default::default()
+    };
+    let (mut app, mut hw, mut sink) = make_app(config);
+
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    for _ in 0..2 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert!(
+        !start(&mut app, &mut hw, &mut sink, CommandSource::Button),
+        "lockout should still hold after 2 s"
+    );
+
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert!(
+        start(&mut app, &mut hw, &mut sink, CommandSource::Button),
+        "lockout should expire after 3 s"
+    );
+    assert_eq!(app.scrub_source(), Some(CommandSource::Button));
+}
+
+#[test]
+fn lockout_window_counts_measured_tick_time() {
+    let config = SystemConfig {
+        stop_lockout_secs: 3,
+        control_loop_interval_ms: 1000,
+        ..Default::default()
+    };
+    let (mut app, mut hw, mut sink) = make_app(config);
+
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    for dt in [0.3, 0.3, 2.0] {
+        app.tick(&mut hw, &mut sink, dt);
+    }
+    assert!(
+        !start(&mut app, &mut hw, &mut sink, CommandSource::Button),
+        "three short ticks are 2.6 s, not 3"
+    );
+
+    app.tick(&mut hw, &mut sink, 0.5);
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+}
+
+#[test]
+fn zero_lockout_disables_stop_lockout() {
+    let config = SystemConfig {
+        stop_lockout_secs: 0,
+        ..Default::default()
+    };
+    let (mut app, mut hw, mut sink) = make_app(config);
+
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Active);
+}
+
+// ── Manual over automatic ─────────────────────────────────────
+
+#[test]
+fn manual_start_takes_over_scheduled_scrub() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Schedule));
+    assert_eq!(app.scrub_source(), Some(CommandSource::Schedule));
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Active);
+    assert_eq!(app.scrub_source(), Some(CommandSource::Button));
+
+    // The scheduler can no longer end the scrub the user now owns.
+    assert!(!stop(&mut app, &mut hw, &mut sink, CommandSource::Schedule));
+    assert_eq!(app.state(), StateId::Active);
+}
+
+#[test]
+fn scheduled_stop_ends_scheduled_scrub_without_lockout() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Schedule));
+    assert!(stop(&mut app, &mut hw, &mut sink, CommandSource::Schedule));
+    assert_eq!(app.state(), StateId::Idle);
+
+    // Automatic stops do not lock out a user starting a scrub by hand.
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Active);
+}
+
+#[test]
+fn scheduled_start_does_not_steal_manual_scrub() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Rpc));
+    assert!(start(&mut app, &mut hw, &mut sink, CommandSource::Schedule));
+    assert_eq!(app.scrub_source(), Some(CommandSource::Rpc));
+}
+
+// ── Source surfaced in state ──────────────────────────────────
+
+#[test]
+fn telemetry_reports_scrub_source() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+    assert_eq!(app.build_telemetry(None).scrub_source, None);
+
+    start(&mut app, &mut hw, &mut sink, CommandSource::Button);
+    assert_eq!(
+        app.build_telemetry(None).scrub_source,
+        Some(CommandSource::Button)
+    );
+
+    app.handle_command(AppCommand::ForceState(StateId::Error), &mut hw, &mut sink);
+    assert_eq!(app.build_telemetry(None).scrub_source, None);
+}
+
+#[test]
+fn start_rejected_in_error_state() {
+    let (mut app, mut hw, mut sink) = make_app(SystemConfig::default());
+    app.handle_command(AppCommand::ForceState(StateId::Error), &mut hw, &mut sink);
+
+    assert!(!start(&mut app, &mut hw, &mut sink, CommandSource::Button));
+    assert_eq!(app.state(), StateId::Error);
+}
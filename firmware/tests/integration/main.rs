@@ -5,6 +5,11 @@
 //! real hardware required.
 
 mod app_service_tests;
+mod arbitration_tests;
 mod auth_tests;
 mod mock_hw;
+mod mock_transport;
 mod provisioning_flow_tests;
+mod rpc_loopback_tests;
+mod safety_harness_tests;
+mod zone_tests;
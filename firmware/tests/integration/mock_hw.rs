@@ -5,6 +5,8 @@
 
 use petfilter::app::ports::{ActuatorPort, ConfigError, ConfigPort, EventSink, StoragePort};
 use petfilter::config::SystemConfig;
+use petfilter::fsm::context::{SensorSnapshot, Tank};
+use petfilter::sensors::SelfTestReport;
 use std::collections::HashMap;
 
 // ── Actuator call record ──────────────────────────────────────
@@ -21,12 +23,38 @@ pub enum ActuatorCall {
 
 pub struct MockHardware {
     pub calls: Vec<ActuatorCall>,
+    /// Result returned from `SensorPort::self_test`.
+    pub self_test_report: SelfTestReport,
+    /// Snapshot returned from `SensorPort::read_all`.
+    pub sensors: SensorSnapshot,
+    /// Last tank passed to `ActuatorPort::select_tank`.
+    pub source_tank: Tank,
 }
 
 #[allow(dead_code)]
 impl MockHardware {
     pub fn new() -> Self {
-        Self { calls: Vec::new() }
+        Self {
+            calls: Vec::new(),
+            self_test_report: SelfTestReport::default(),
+            sensors: SensorSnapshot {
+                nh3_ppm: 0.0,
+                nh3_peak_ppm: 0.0,
+                nh3_avg_ppm: 0.0,
+                nh3_raw: 0,
+                flow_ml_per_min: 100.0,
+                flow_detected: true,
+                tank_a_ok: true,
+                tank_b_ok: true,
+                temperature_c: 25.0,
+                over_temperature: false,
+                uvc_interlock_closed: true,
+                timestamp_ms: 0,
+                nh3_updated_ms: 0,
+                temperature_updated_ms: 0,
+            },
+            source_tank: Tank::A,
+        }
     }
 
     pub fn last_call(&self) -> Option<&ActuatorCall> {
@@ -76,6 +104,10 @@ impl ActuatorPort for MockHardware {
         });
     }
 
+    fn select_tank(&mut self, tank: Tank) {
+        self.source_tank = tank;
+    }
+
     fn enable_uvc(&mut self, duty: u8) {
         self.calls.push(ActuatorCall::EnableUvc { duty });
     }
@@ -104,23 +136,16 @@ impl ActuatorPort for MockHardware {
 // ── SensorPort for MockHardware ──────────────────────────────
 
 impl petfilter::app::ports::SensorPort for MockHardware {
-    fn read_all(&mut self, _elapsed_secs: f32) -> petfilter::fsm::context::SensorSnapshot {
-        petfilter::fsm::context::SensorSnapshot {
-            nh3_ppm: 0.0,
-            nh3_avg_ppm: 0.0,
-            nh3_raw: 0,
-            flow_ml_per_min: 100.0,
-            flow_detected: true,
-            tank_a_ok: true,
-            tank_b_ok: true,
-            temperature_c: 25.0,
-            over_temperature: false,
-            uvc_interlock_closed: true,
-        }
+    fn read_all(&mut self, _elapsed_secs: f32) -> SensorSnapshot {
+        self.sensors
+    }
+
+    fn read_ammonia_fast(&mut self) -> Option<f32> {
+        None
     }
 
-    fn read_ammonia_fast(&mut self) -> f32 {
-        0.0
+    fn self_test(&mut self) -> SelfTestReport {
+        self.self_test_report
     }
 }
 
@@ -0,0 +1,73 @@
+//! Mock byte transport for integration tests.
+//!
+//! Serves injected bytes to `read` in bounded chunks (like an MTU-limited
+//! link) and captures everything written, so tests can drive the codec
+//! and engine exactly as a real transport would.
+
+use petfilter::rpc::transport::Transport;
+use std::collections::VecDeque;
+
+pub struct MockTransport {
+    /// Bytes waiting to be read by the device side.
+    rx: VecDeque<u8>,
+    /// Largest read returned at once.
+    pub read_chunk: usize,
+    /// Everything the device side has written.
+    pub written: Vec<u8>,
+    /// Number of `flush` calls.
+    pub flushes: usize,
+}
+
+#[allow(dead_code)]
+impl MockTransport {
+    pub fn new() -> Self {
+        Self {
+            rx: VecDeque::new(),
+            read_chunk: 64,
+            written: Vec::new(),
+            flushes: 0,
+        }
+    }
+
+    /// Queue bytes for the device side to read.
+    pub fn inject(&mut self, data: &[u8]) {
+        self.rx.extend(data);
+    }
+
+    /// Take (and clear) everything written so far.
+    pub fn take_written(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.written)
+    }
+}
+
+impl Default for MockTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for MockTransport {
+    type Error = ();
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, ()> {
+        let n = buf.len().min(self.read_chunk).min(self.rx.len());
+        for (dst, src) in buf.iter_mut().zip(self.rx.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<usize, ()> {
+        self.written.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        self.flushes += 1;
+        Ok(())
+    }
+
+    fn available(&self) -> bool {
+        !self.rx.is_empty()
+    }
+}
@@ -95,13 +95,68 @@ fn app_survives_scrub_after_provisioning() {
 
     // Simulating what main.rs does after successful provisioning:
     // app handles a scrub command.
-    use petfilter::app::commands::AppCommand;
-    app.handle_command(AppCommand::StartScrub, &mut hw, &mut sink);
+    use petfilter::app::commands::{AppCommand, CommandSource};
+    app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink,
+    );
 
     // Should have transitioned out of Idle
     assert_ne!(app.state(), StateId::Idle);
 }
 
+#[test]
+fn ble_status_follows_fsm_state_on_a_throttled_cadence() {
+    use petfilter::app::commands::{AppCommand, CommandSource};
+    use petfilter_ble_sim::*;
+
+    let config = SystemConfig {
+        ble_status_interval_secs: 2,
+        ..Default::default()
+    };
+    let interval = config.ble_status_interval_secs;
+    let mut app = AppService::new(config);
+    let mut hw = crate::mock_hw::MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+
+    let mut name = heapless::String::<24>::new();
+    name.push_str("pf-test").ok();
+    let mut ble = BleAdapter::new(name);
+    ble.start();
+    ble.on_central_connected();
+
+    // Mirrors the main loop: one control tick per second, status
+    // refreshed whenever the adapter says it is due.
+    let mut refreshes = 0;
+    let mut saw_scrub = false;
+    for tick in 0..10u32 {
+        app.tick(&mut hw, &mut sink, 1.0);
+        if tick == 4 {
+            app.handle_command(
+                AppCommand::StartScrub(CommandSource::Rpc),
+                &mut hw,
+                &mut sink,
+            );
+        }
+        if ble.status_refresh_due(u64::from(tick) * 1000, interval) {
+            ble.update_status(app.state_name(), 0.0, app.fault_flags(), tick);
+            refreshes += 1;
+            let expected = format!("{{\"s\":\"{}\",", app.state_name());
+            assert!(
+                ble.status_payload().starts_with(&expected),
+                "tick {tick}: {} does not report {}",
+                ble.status_payload(),
+                app.state_name()
+            );
+            saw_scrub |= app.state() != StateId::Idle;
+        }
+    }
+    assert_eq!(refreshes, 5, "one refresh per 2 s over 10 ticks");
+    assert!(saw_scrub, "status never reported the scrub");
+}
+
 // Re-export BLE adapter under a test-friendly alias to avoid
 // cfg confusion — the host-target BLE adapter uses simulation stubs.
 mod petfilter_ble_sim {
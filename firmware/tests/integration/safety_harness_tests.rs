@@ -0,0 +1,172 @@
+//! Safety-supervisor fault-injection harness.
+//!
+//! Drives an [`AppService`] on mock hardware through every combination
+//! of the [`SafetyFault`] bits by injecting hazardous sensor snapshots,
+//! and checks the resulting fault mask, the forced `Error` transition,
+//! and that pump and UVC are shut off.
+//!
+//! Debounce and grace periods are zeroed so each combination raises all
+//! of its bits on the same tick; the flow check is armed by running the
+//! pump healthy past its grace period first.
+
+use crate::mock_hw::{LogSink, MockHardware};
+
+use petfilter::app::commands::{AppCommand, CommandSource};
+use petfilter::app::service::AppService;
+use petfilter::config::SystemConfig;
+use petfilter::error::SafetyFault;
+use petfilter::fsm::StateId;
+
+const ALL_FAULTS: [SafetyFault; 4] = [
+    SafetyFault::WaterLevelLow,
+    SafetyFault::NoFlowDetected,
+    SafetyFault::OverTemperature,
+    SafetyFault::UvcInterlockOpen,
+];
+
+/// Every non-empty subset of [`ALL_FAULTS`], as a mask.
+fn fault_combinations() -> impl Iterator<Item = u8> {
+    let all = ALL_FAULTS.iter().fold(0, |m, f| m | f.mask());
+    (1..=all).filter(move |m| m & !all == 0)
+}
+
+fn harness_config() -> SystemConfig {
+    SystemConfig {
+        interlock_grace_secs: 0,
+        over_temp_debounce_samples: 1,
+        prime_after_idle_secs: 0,
+        ..Default::default()
+    }
+}
+
+/// Rewrite the snapshot so it trips exactly the faults in `mask`.
+fn inject(hw: &mut MockHardware, mask: u8) {
+    let has = |f: SafetyFault| mask & f.mask() != 0;
+    let s = &mut hw.sensors;
+    if has(SafetyFault::WaterLevelLow) {
+        s.tank_a_ok = false;
+        s.tank_b_ok = false;
+    }
+    if has(SafetyFault::NoFlowDetected) {
+        s.flow_detected = false;
+        s.flow_ml_per_min = 0.0;
+    }
+    if has(SafetyFault::OverTemperature) {
+        s.temperature_c = 95.0;
+        s.over_temperature = true;
+    }
+    if has(SafetyFault::UvcInterlockOpen) {
+        s.uvc_interlock_closed = false;
+    }
+}
+
+fn idle_app() -> (AppService, MockHardware, LogSink) {
+    let mut app = AppService::new(harness_config());
+    let mut hw = MockHardware::new();
+    let mut sink = LogSink::new();
+    app.start(&mut sink);
+    app.tick(&mut hw, &mut sink, 1.0);
+    assert_eq!(app.state(), StateId::Idle);
+    (app, hw, sink)
+}
+
+/// A scrub that has run long enough for the flow check to be armed.
+fn scrubbing_app() -> (AppService, MockHardware, LogSink) {
+    let (mut app, mut hw, mut sink) = idle_app();
+    // Enough NH3 to hold the scrub in Active.
+    hw.sensors.nh3_ppm = 20.0;
+    hw.sensors.nh3_avg_ppm = 20.0;
+    assert!(app.handle_command(
+        AppCommand::StartScrub(CommandSource::Rpc),
+        &mut hw,
+        &mut sink
+    ));
+    for _ in 0..5 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.state(), StateId::Active);
+    assert_eq!(app.fault_flags(), 0);
+    assert!(hw.pump_on() && hw.uvc_on());
+    (app, hw, sink)
+}
+
+/// One tick on the injected snapshot must fault with exactly `mask`,
+/// land in Error and leave every output off — and keep them off.
+fn assert_trips(app: &mut AppService, hw: &mut MockHardware, sink: &mut LogSink, mask: u8) {
+    inject(hw, mask);
+    app.tick(hw, sink, 1.0);
+    assert_eq!(app.fault_flags(), mask, "fault mask for 0b{mask:04b}");
+    assert_eq!(app.state(), StateId::Error, "state for 0b{mask:04b}");
+    assert!(
+        sink.events.contains(&format!(
+            "FaultDetected {{ flags: {mask}, is_test: false }}"
+        )),
+        "no FaultDetected for 0b{mask:04b}: {:?}",
+        sink.events
+    );
+    assert!(!hw.pump_on(), "pump still on for 0b{mask:04b}");
+    assert!(!hw.uvc_on(), "UVC still on for 0b{mask:04b}");
+
+    for _ in 0..3 {
+        app.tick(hw, sink, 1.0);
+        assert_eq!(app.state(), StateId::Error, "left Error for 0b{mask:04b}");
+        assert!(!hw.pump_on() && !hw.uvc_on(), "output back on for 0b{mask:04b}");
+    }
+}
+
+#[test]
+fn combinations_cover_every_fault_subset() {
+    let masks: Vec<u8> = fault_combinations().collect();
+    assert_eq!(masks.len(), (1 << ALL_FAULTS.len()) - 1);
+    for fault in ALL_FAULTS {
+        assert!(masks.contains(&fault.mask()));
+    }
+}
+
+#[test]
+fn every_fault_combination_during_scrub_forces_error_and_zeroes_outputs() {
+    for mask in fault_combinations() {
+        let (mut app, mut hw, mut sink) = scrubbing_app();
+        assert_trips(&mut app, &mut hw, &mut sink, mask);
+    }
+}
+
+#[test]
+fn every_fault_combination_at_rest_forces_error() {
+    // With the pump off a missing flow reading is not a fault, so the
+    // flow bit is injected but never expected.
+    let no_flow = SafetyFault::NoFlowDetected.mask();
+    for mask in fault_combinations().filter(|m| m & no_flow == 0) {
+        let (mut app, mut hw, mut sink) = idle_app();
+        inject(&mut hw, no_flow);
+        assert_trips(&mut app, &mut hw, &mut sink, mask);
+    }
+}
+
+#[test]
+fn missing_flow_at_rest_is_not_a_fault() {
+    let (mut app, mut hw, mut sink) = idle_app();
+    inject(&mut hw, SafetyFault::NoFlowDetected.mask());
+    for _ in 0..10 {
+        app.tick(&mut hw, &mut sink, 1.0);
+    }
+    assert_eq!(app.fault_flags(), 0);
+    assert_eq!(app.state(), StateId::Idle);
+}
+
+#[test]
+fn hazard_during_scrub_blocks_restart_until_cleared() {
+    for mask in fault_combinations() {
+        let (mut app, mut hw, mut sink) = scrubbing_app();
+        assert_trips(&mut app, &mut hw, &mut sink, mask);
+        assert!(
+            !app.handle_command(
+                AppCommand::StartScrub(CommandSource::Button),
+                &mut hw,
+                &mut sink
+            ),
+            "restart accepted while faulted with 0b{mask:04b}"
+        );
+        assert!(!hw.pump_on() && !hw.uvc_on());
+    }
+}
@@ -0,0 +1,172 @@
+//! Integration tests for multi-zone installations: one `AppService` per
+//! filter loop, each with its own config and hardware, ticked together
+//! through a `ZoneSet` that shares their safety trip.
+
+use crate::mock_hw::{ActuatorCall, LogSink, MockHardware};
+
+use petfilter::app::service::{AppService, DEFAULT_ZONE, ZoneId};
+use petfilter::app::zones::{MAX_ZONES, Zone, ZoneSet};
+use petfilter::config::SystemConfig;
+use petfilter::fsm::StateId;
+
+fn started(id: ZoneId, config: SystemConfig, sink: &mut LogSink) -> AppService {
+    let mut app = AppService::for_zone(id, config);
+    app.start(sink);
+    app
+}
+
+/// Zone 0 on defaults and zone 1 on `config_b`.
+fn two_zones(config_b: SystemConfig, sink: &mut LogSink) -> ZoneSet<MockHardware> {
+    let mut zones = ZoneSet::new(
+        started(0, SystemConfig::default(), sink),
+        MockHardware::new(),
+    );
+    assert!(
+        zones
+            .add(started(1, config_b, sink), MockHardware::new())
+            .is_ok()
+    );
+    zones
+}
+
+fn zone(zones: &mut ZoneSet<MockHardware>, id: ZoneId) -> &mut Zone<MockHardware> {
+    zones.get_mut(id).unwrap()
+}
+
+fn set_nh3(zone: &mut Zone<MockHardware>, ppm: f32) {
+    zone.hw.sensors.nh3_ppm = ppm;
+    zone.hw.sensors.nh3_avg_ppm = ppm;
+}
+
+fn set_water(zone: &mut Zone<MockHardware>, present: bool) {
+    zone.hw.sensors.tank_a_ok = present;
+    zone.hw.sensors.tank_b_ok = present;
+}
+
+fn last_pump_duty(hw: &MockHardware) -> u8 {
+    hw.calls
+        .iter()
+        .rev()
+        .find_map(|c| match c {
+            ActuatorCall::SetPump { duty, .. } => Some(*duty),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+fn tick(zones: &mut ZoneSet<MockHardware>, sink: &mut LogSink, ticks: u32) {
+    for _ in 0..ticks {
+        zones.tick(sink, 1.0);
+    }
+}
+
+fn states(zones: &ZoneSet<MockHardware>) -> Vec<StateId> {
+    zones.iter().map(|z| z.app.state()).collect()
+}
+
+#[test]
+fn zones_transition_on_their_own_nh3() {
+    let mut sink = LogSink::new();
+    let mut zones = two_zones(SystemConfig::default(), &mut sink);
+
+    set_nh3(zone(&mut zones, 0), 20.0);
+    tick(&mut zones, &mut sink, 2);
+    assert_eq!(states(&zones), [StateId::Sensing, StateId::Idle]);
+
+    tick(&mut zones, &mut sink, 40);
+    assert_eq!(states(&zones), [StateId::Active, StateId::Idle]);
+    assert!(
+        !zone(&mut zones, 1)
+            .hw
+            .calls
+            .iter()
+            .any(|c| matches!(c, ActuatorCall::SetPump { duty, .. } if *duty > 0)),
+        "idle zone's pump was run"
+    );
+
+    // An urgent spike in zone 1 and a clean reading in zone 0 move each
+    // loop without disturbing the other.
+    set_nh3(zone(&mut zones, 0), 0.0);
+    set_nh3(zone(&mut zones, 1), 60.0);
+    tick(&mut zones, &mut sink, 1);
+    assert_eq!(states(&zones), [StateId::Purging, StateId::Active]);
+}
+
+#[test]
+fn zones_apply_their_own_config() {
+    let mut sink = LogSink::new();
+    let relaxed = SystemConfig {
+        nh3_activate_threshold_ppm: 30.0,
+        ..Default::default()
+    };
+    let mut zones = two_zones(relaxed, &mut sink);
+
+    for zone in &mut zones {
+        set_nh3(zone, 20.0);
+    }
+    tick(&mut zones, &mut sink, 2);
+    assert_eq!(states(&zones), [StateId::Sensing, StateId::Idle]);
+}
+
+#[test]
+fn a_fault_in_one_zone_stops_every_zone() {
+    let mut sink = LogSink::new();
+    let mut zones = two_zones(SystemConfig::default(), &mut sink);
+    set_nh3(zone(&mut zones, 1), 60.0);
+    tick(&mut zones, &mut sink, 1);
+    assert_eq!(states(&zones), [StateId::Idle, StateId::Active]);
+
+    // Zone 0 runs dry: its own trip, then zone 1's on the next tick.
+    set_water(zone(&mut zones, 0), false);
+    tick(&mut zones, &mut sink, 2);
+    assert_eq!(states(&zones), [StateId::Error, StateId::Error]);
+    assert_eq!(zone(&mut zones, 1).app.local_fault_flags(), 0);
+    assert_ne!(zone(&mut zones, 1).app.fault_flags(), 0);
+    assert_eq!(last_pump_duty(&zone(&mut zones, 1).hw), 0);
+
+    // Both recover together once the source reads clear.
+    set_water(zone(&mut zones, 0), true);
+    let cooldown = u32::from(SystemConfig::default().fault_recovery_cooldown_secs);
+    tick(&mut zones, &mut sink, cooldown - 1);
+    assert_eq!(states(&zones), [StateId::Error, StateId::Error]);
+    tick(&mut zones, &mut sink, 3);
+    assert!(zones.iter().all(|z| z.app.state() != StateId::Error));
+    assert!(zones.iter().all(|z| z.app.fault_flags() == 0));
+}
+
+#[test]
+fn add_rejects_duplicate_zones_and_a_full_set() {
+    let mut sink = LogSink::new();
+    let mut app = |id| started(id, SystemConfig::default(), &mut sink);
+    let mut zones = ZoneSet::new(app(0), MockHardware::new());
+
+    let rejected = zones.add(app(0), MockHardware::new()).unwrap_err();
+    assert_eq!(rejected.app.zone(), 0);
+
+    for id in 1..MAX_ZONES as ZoneId {
+        assert!(zones.add(app(id), MockHardware::new()).is_ok());
+    }
+    let extra = MAX_ZONES as ZoneId;
+    assert!(zones.add(app(extra), MockHardware::new()).is_err());
+    assert_eq!(zones.iter().count(), MAX_ZONES);
+}
+
+#[test]
+fn requests_for_an_unknown_zone_route_to_the_primary() {
+    let mut sink = LogSink::new();
+    let mut zones = two_zones(SystemConfig::default(), &mut sink);
+    assert_eq!(zones.route_mut(1).app.zone(), 1);
+    assert_eq!(zones.route_mut(7).app.zone(), DEFAULT_ZONE);
+    assert_eq!(zones.primary().app.zone(), DEFAULT_ZONE);
+}
+
+#[test]
+fn telemetry_is_tagged_with_zone() {
+    let mut sink = LogSink::new();
+    let app = started(3, SystemConfig::default(), &mut sink);
+    assert_eq!(app.zone(), 3);
+    assert_eq!(app.build_telemetry(None).zone, 3);
+
+    let single = AppService::new(SystemConfig::default());
+    assert_eq!(single.build_telemetry(None).zone, DEFAULT_ZONE);
+}